@@ -10,7 +10,7 @@ use std::path::PathBuf;
 use clap::Parser;
 use error_stack::{Report, ResultExt as _};
 use futures::{StreamExt as _, channel::mpsc};
-use harpc_codec::json::JsonCodec;
+use harpc_codec::any::AnyCodec;
 use harpc_server::Server;
 use hash_codec::bytes::JsonLinesEncoder;
 use hash_graph_api::{
@@ -200,7 +200,7 @@ where
             store: dependencies.store,
             authorization_api: dependencies.authorization_api,
             temporal_client: dependencies.temporal_client,
-            codec: JsonCodec,
+            codec: AnyCodec::default(),
         },
         server.events(),
     );
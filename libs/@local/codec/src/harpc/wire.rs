@@ -206,6 +206,7 @@ mod tests {
                 procedure: ProcedureDescriptor {
                     id: ProcedureId::new(2),
                 },
+                deadline: None,
                 payload: Payload::from_static(&[1, 2, 3, 4]),
             }),
         }
@@ -0,0 +1,98 @@
+//! Web routes for reading the changes made to the graph.
+
+use alloc::sync::Arc;
+
+use axum::{Extension, Router, response::Response, routing::post};
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    change::{
+        ChangeCursor, ChangeEvent, ChangeKind, ChangeStore as _, ChangedRecord, GetChangesParams,
+        GetChangesResponse,
+    },
+    pool::StorePool,
+};
+use hash_temporal_client::TemporalClient;
+use utoipa::OpenApi;
+
+use crate::rest::{AuthenticatedUserHeader, json::Json, status::report_to_response};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_changes,
+    ),
+    components(
+        schemas(
+            ChangeKind,
+            ChangedRecord,
+            ChangeEvent,
+            ChangeCursor,
+            GetChangesParams,
+            GetChangesResponse,
+        )
+    ),
+    tags(
+        (name = "Change", description = "Change feed API")
+    )
+)]
+pub(crate) struct ChangeResource;
+
+impl ChangeResource {
+    /// Create routes for reading the change feed.
+    pub(crate) fn routes<S, A>() -> Router
+    where
+        S: StorePool + Send + Sync + 'static,
+        A: AuthorizationApiPool + Send + Sync + 'static,
+    {
+        Router::new().nest(
+            "/changes",
+            Router::new().route("/query", post(get_changes::<S, A>)),
+        )
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/changes/query",
+    request_body = GetChangesParams,
+    tag = "Change",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The changes recorded after the provided cursor", body = GetChangesResponse),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn get_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<GetChangesParams>,
+) -> Result<Json<GetChangesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_changes(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
@@ -25,15 +25,16 @@ use hash_graph_postgres_store::store::error::{EntityDoesNotExist, RaceConditionO
 use hash_graph_store::{
     account::AccountStore as _,
     entity::{
-        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityRequest, DiffEntityParams,
-        DiffEntityResult, EntityQueryCursor, EntityQueryPath, EntityQuerySorting,
-        EntityQuerySortingRecord, EntityQuerySortingToken, EntityQueryToken, EntityStore as _,
-        EntityTypesError, EntityValidationReport, EntityValidationType, GetEntitiesParams,
-        GetEntitiesResponse, GetEntitySubgraphParams, LinkDataStateError, LinkDataValidationReport,
-        LinkError, LinkTargetError, LinkValidationReport, LinkedEntityError,
-        MetadataValidationReport, PatchEntityParams, PropertyMetadataValidationReport,
-        QueryConversion, UnexpectedEntityType, UpdateEntityEmbeddingsParams,
-        ValidateEntityComponents, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, ClosedMultiEntityTypeMap,
+        CountEntitiesParams, CreateEntityRequest, DiffEntityParams, DiffEntityResult,
+        EntityAggregation, EntityAggregationFunction, EntityAggregationGroup, EntityQueryCursor,
+        EntityQueryPath, EntityQueryRanking, EntityQuerySorting, EntityQuerySortingRecord,
+        EntityQuerySortingToken, EntityQueryToken, EntityStore as _, EntityTypesError,
+        EntityValidationReport, EntityValidationType, GetEntitiesParams, GetEntitiesResponse,
+        GetEntitySubgraphParams, LinkDataStateError, LinkDataValidationReport, LinkError,
+        LinkTargetError, LinkValidationReport, LinkedEntityError, MetadataValidationReport,
+        PatchEntityParams, PropertyMetadataValidationReport, QueryConversion, UnexpectedEntityType,
+        UpdateEntityEmbeddingsParams, ValidateEntityComponents, ValidateEntityParams,
     },
     entity_type::{EntityTypeResolveDefinitions, IncludeEntityTypeOption},
     filter::Filter,
@@ -105,6 +106,7 @@ use crate::rest::{
         get_entities,
         get_entity_subgraph,
         count_entities,
+        aggregate_entities,
         patch_entity,
         update_entity_embeddings,
         diff_entity,
@@ -126,6 +128,11 @@ use crate::rest::{
             PropertyObjectWithMetadata,
             ValidateEntityParams,
             CountEntitiesParams,
+            AggregateEntitiesParams,
+            AggregateEntitiesResponse,
+            EntityAggregation,
+            EntityAggregationFunction,
+            EntityAggregationGroup,
             EntityValidationType,
             ValidateEntityComponents,
             Embedding,
@@ -155,6 +162,7 @@ use crate::rest::{
             NullOrdering,
             EntityQuerySortingRecord,
             EntityQuerySortingToken,
+            EntityQueryRanking,
             GetEntitiesResponse,
             GetEntitySubgraphResponse,
             ClosedMultiEntityTypeMap,
@@ -280,7 +288,8 @@ impl EntityResource {
                     Router::new()
                         .route("/", post(get_entities::<S, A>))
                         .route("/subgraph", post(get_entity_subgraph::<S, A>))
-                        .route("/count", post(count_entities::<S, A>)),
+                        .route("/count", post(count_entities::<S, A>))
+                        .route("/aggregate", post(aggregate_entities::<S, A>)),
                 ),
         )
     }
@@ -508,6 +517,7 @@ fn generate_sorting_paths(
     paths: Option<Vec<EntityQuerySortingRecord<'_>>>,
     limit: Option<usize>,
     cursor: Option<EntityQueryCursor<'_>>,
+    ranking: Option<EntityQueryRanking<'_>>,
     temporal_axes: &QueryTemporalAxesUnresolved,
 ) -> EntityQuerySorting<'static> {
     let temporal_axes_sorting_path = match temporal_axes {
@@ -591,6 +601,7 @@ fn generate_sorting_paths(
     EntityQuerySorting {
         paths: sorting,
         cursor: cursor.map(EntityQueryCursor::into_owned),
+        ranking: ranking.map(EntityQueryRanking::into_owned),
     }
 }
 
@@ -612,6 +623,8 @@ pub struct GetEntitiesRequest<'q, 's, 'p> {
     pub sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
     #[serde(borrow)]
     pub cursor: Option<EntityQueryCursor<'s>>,
+    #[serde(borrow, default)]
+    pub ranking: Option<EntityQueryRanking<'p>>,
     #[serde(default)]
     pub include_count: bool,
     #[serde(default)]
@@ -636,6 +649,7 @@ impl<'q, 's, 'p: 'q> From<GetEntitiesRequest<'q, 's, 'p>> for GetEntitiesParams<
                 request.sorting_paths,
                 request.limit,
                 request.cursor,
+                request.ranking,
                 &request.temporal_axes,
             ),
             limit: request.limit,
@@ -759,6 +773,8 @@ pub struct GetEntitySubgraphRequest<'q, 's, 'p> {
     pub sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
     #[serde(borrow)]
     pub cursor: Option<EntityQueryCursor<'s>>,
+    #[serde(borrow, default)]
+    pub ranking: Option<EntityQueryRanking<'p>>,
     #[serde(default)]
     pub include_count: bool,
     #[serde(default)]
@@ -783,6 +799,7 @@ impl<'q, 's, 'p: 'q> From<GetEntitySubgraphRequest<'q, 's, 'p>> for GetEntitySub
                 request.sorting_paths,
                 request.limit,
                 request.cursor,
+                request.ranking,
                 &request.temporal_axes,
             ),
             limit: request.limit,
@@ -983,6 +1000,71 @@ where
     response
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/query/aggregate",
+    request_body = AggregateEntitiesParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = AggregateEntitiesResponse,
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, request)
+)]
+async fn aggregate_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    mut query_logger: Option<Extension<QueryLogger>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<AggregateEntitiesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.capture(actor_id, OpenApiQuery::AggregateEntities(&request));
+    }
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    let response = store
+        .aggregate_entities(
+            actor_id,
+            AggregateEntitiesParams::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?,
+        )
+        .await
+        .map(Json)
+        .map_err(report_to_response);
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.send().await.map_err(report_to_response)?;
+    }
+    response
+}
+
 #[utoipa::path(
     patch,
     path = "/entities",
@@ -3,6 +3,7 @@
 //! Handler methods are grouped by routes that make up the REST API.
 
 pub mod actor_group;
+pub mod change;
 pub mod data_type;
 pub mod entity;
 pub mod entity_type;
@@ -208,6 +209,7 @@ where
         actor_group::ActorGroupResource::routes::<S, A>(),
         permissions::PermissionResource::routes::<S, A>(),
         principal::PrincipalResource::routes::<S, A>(),
+        change::ChangeResource::routes::<S, A>(),
     ]
 }
 
@@ -220,6 +222,7 @@ fn api_documentation() -> Vec<openapi::OpenApi> {
         actor_group::ActorGroupResource::openapi(),
         permissions::PermissionResource::openapi(),
         principal::PrincipalResource::openapi(),
+        change::ChangeResource::openapi(),
     ]
 }
 
@@ -327,6 +330,7 @@ pub enum OpenApiQuery<'a> {
     },
     GetEntities(&'a JsonValue),
     CountEntities(&'a JsonValue),
+    AggregateEntities(&'a JsonValue),
     GetEntitySubgraph(&'a JsonValue),
     ValidateEntity(&'a JsonValue),
     DiffEntity(&'a DiffEntityParams),
@@ -794,6 +798,18 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("containsSegment"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("TextSearchFilter"))
+                                .property(
+                                    "textSearch",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("textSearch"),
+                        )
                         .build(),
                 )
                 .into(),
@@ -842,6 +858,20 @@ impl Modify for FilterSchemaAddon {
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "EntityQueryPath".to_owned(),
+                schema::Schema::Array(
+                    ArrayBuilder::new()
+                        .items(
+                            OneOfBuilder::new()
+                                .item(Ref::from_schema_name("EntityQueryToken"))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
             components.schemas.insert(
                 "EntityQuerySortingPath".to_owned(),
                 schema::Schema::Array(
@@ -188,6 +188,16 @@ impl From<hash_graph_store::subgraph::edges::Edges> for Edges {
                             .entity_type_to_property_type
                             .into_flattened::<OntologyOutwardEdge>(),
                     )
+                    .chain(
+                        edges
+                            .entity_type_to_entity
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
+                    .chain(
+                        edges
+                            .property_type_to_entity_type
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
                     .chain(
                         edges
                             .property_type_to_property_type
@@ -198,6 +208,11 @@ impl From<hash_graph_store::subgraph::edges::Edges> for Edges {
                             .property_type_to_data_type
                             .into_flattened::<OntologyOutwardEdge>(),
                     )
+                    .chain(
+                        edges
+                            .data_type_to_property_type
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
                     .fold(HashMap::new(), collect_merge),
             ),
             knowledge_graph: KnowledgeGraphRootedEdges(
@@ -241,8 +256,10 @@ impl ToSchema<'_> for Edges {
 #[cfg(test)]
 mod tests {
     use hash_graph_store::subgraph::{
-        edges::{EdgeDirection, KnowledgeGraphEdgeKind, SharedEdgeKind},
-        identifier::{EntityIdWithInterval, EntityTypeVertexId, EntityVertexId},
+        edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, SharedEdgeKind},
+        identifier::{
+            EntityIdWithInterval, EntityTypeVertexId, EntityVertexId, PropertyTypeVertexId,
+        },
     };
     use hash_graph_temporal_versioning::{
         ClosedTemporalBound, LeftClosedTemporalInterval, OpenTemporalBound, Timestamp,
@@ -309,6 +326,60 @@ mod tests {
             .expect("should have at least a single entry");
         assert_eq!(values.len(), 1);
 
+        let (_, edges) = values
+            .first_key_value()
+            .expect("should have at least a single entry");
+        assert_eq!(edges.len(), 2);
+    }
+    #[test]
+    fn merge_incoming_ontology() {
+        let vertex_id = EntityTypeVertexId {
+            base_id: BaseUrl::new("https://example.com/entity-type/".to_owned())
+                .expect("should be valid URL"),
+            revision_id: OntologyTypeVersion::new(1),
+        };
+
+        let mut edges = hash_graph_store::subgraph::edges::Edges::default();
+
+        edges.entity_type_to_property_type.insert(
+            &vertex_id,
+            OntologyEdgeKind::ConstrainsPropertiesOn,
+            EdgeDirection::Outgoing,
+            PropertyTypeVertexId {
+                base_id: BaseUrl::new("https://example.com/property-type/".to_owned())
+                    .expect("should be valid URL"),
+                revision_id: OntologyTypeVersion::new(1),
+            },
+        );
+
+        edges.entity_type_to_entity.insert(
+            &vertex_id,
+            SharedEdgeKind::IsOfType,
+            EdgeDirection::Incoming,
+            EntityIdWithInterval {
+                entity_id: EntityId {
+                    web_id: WebId::new(Uuid::new_v4()),
+                    entity_uuid: EntityUuid::new(Uuid::new_v4()),
+                    draft_id: None,
+                },
+                interval: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(Timestamp::now()),
+                    OpenTemporalBound::Unbounded,
+                ),
+            },
+        );
+
+        let edges = Edges::from(edges);
+        assert_eq!(edges.ontology.0.len(), 1);
+
+        let (_, values) = edges
+            .ontology
+            .0
+            .iter()
+            .next()
+            .expect("should have at least a single entry");
+        assert_eq!(values.len(), 1);
+
         let (_, edges) = values
             .first_key_value()
             .expect("should have at least a single entry");
@@ -0,0 +1,388 @@
+use alloc::{borrow::Cow, collections::VecDeque, sync::Arc};
+use core::{marker::PhantomData, time::Duration};
+
+use error_stack::{Report, ResultExt as _};
+use futures::{Stream, StreamExt as _, future::Either as FutureEither, stream};
+use harpc_client::{
+    connection::Connection,
+    utils::{invoke_call_discrete, invoke_call_server_streaming},
+};
+use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use harpc_server::{
+    error::{DelegationError, Forbidden},
+    session::Session,
+    utils::{delegate_call_discrete, delegate_call_server_streaming, parse_procedure_id},
+};
+use harpc_system::delegate::SubsystemDelegate;
+use harpc_tower::{body::Body, either::Either, request::Request, response::Response};
+use harpc_types::response_kind::ResponseKind;
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    change::{ChangeEvent, ChangeStore as _, GetChangesParams, GetChangesResponse},
+    pool::StorePool,
+};
+use hash_temporal_client::TemporalClient;
+use type_system::principal::actor::ActorEntityUuid;
+
+use super::session::Account;
+
+/// The time to wait before reading the change feed again after no new changes were found.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil change request")]
+pub struct ChangeError;
+
+pub trait ChangeSystem {
+    type ExecutionScope;
+
+    /// The stream of changes returned by [`subscribe_changes`].
+    ///
+    /// [`subscribe_changes`]: Self::subscribe_changes
+    type ChangeStream: Stream<Item = Result<ChangeEvent, Report<ChangeError>>>;
+
+    /// Reads the changes recorded after the cursor in [`GetChangesParams`].
+    ///
+    /// A change is only returned once every transaction which started writing before it has
+    /// finished, so a long-running transaction delays, but never drops, later changes.
+    async fn get_changes(
+        &self,
+        scope: Self::ExecutionScope,
+        params: GetChangesParams,
+    ) -> Result<GetChangesResponse, Report<ChangeError>>;
+
+    /// Subscribes to the changes recorded after the cursor in [`GetChangesParams`].
+    ///
+    /// The returned stream does not terminate on its own, new changes are emitted once they can be
+    /// read with [`get_changes`]. The limit in [`GetChangesParams`] is the number of changes read
+    /// at once.
+    ///
+    /// [`get_changes`]: Self::get_changes
+    async fn subscribe_changes(
+        &self,
+        scope: Self::ExecutionScope,
+        params: GetChangesParams,
+    ) -> Result<Self::ChangeStream, Report<ChangeError>>;
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+pub mod meta {
+    //! The `meta` module contains the metadata for the change service.
+    //! In the future this will be automatically generated by the `harpc` crate.
+
+    use frunk::HList;
+    use harpc_system::{
+        Subsystem,
+        procedure::{Procedure, ProcedureIdentifier, ProcedureKind},
+    };
+    use harpc_types::{procedure::ProcedureId, version::Version};
+
+    use crate::rpc::GraphSubsystemId;
+
+    pub enum ChangeProcedureId {
+        GetChanges,
+        SubscribeChanges,
+    }
+
+    impl ProcedureIdentifier for ChangeProcedureId {
+        type Subsystem = ChangeSystem;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::GetChanges),
+                0x01 => Some(Self::SubscribeChanges),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::GetChanges => ProcedureId::new(0x00),
+                Self::SubscribeChanges => ProcedureId::new(0x01),
+            }
+        }
+    }
+
+    pub struct ChangeSystem;
+
+    impl Subsystem for ChangeSystem {
+        type ProcedureId = ChangeProcedureId;
+        type Procedures = HList![ProcedureGetChanges, ProcedureSubscribeChanges];
+        type SubsystemId = GraphSubsystemId;
+
+        const ID: GraphSubsystemId = GraphSubsystemId::Change;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    pub struct ProcedureGetChanges;
+
+    impl Procedure for ProcedureGetChanges {
+        type Subsystem = ChangeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = ChangeProcedureId::GetChanges;
+    }
+
+    pub struct ProcedureSubscribeChanges;
+
+    impl Procedure for ProcedureSubscribeChanges {
+        type Subsystem = ChangeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = ChangeProcedureId::SubscribeChanges;
+        const KIND: ProcedureKind = ProcedureKind::ServerStreaming;
+    }
+}
+
+#[derive(Debug)]
+#[derive_where::derive_where(Clone)]
+pub struct ChangeServer<S, A> {
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub store_pool: Arc<S>,
+}
+
+impl<S, A> ChangeServer<S, A>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    async fn store(&self) -> Result<S::Store<'static, A::Api<'static>>, Report<ChangeError>> {
+        // The store is acquired inside of the change stream, so it cannot borrow the pools.
+        let authorization_api = self
+            .authorization_api_pool
+            .acquire_owned()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(ChangeError)?;
+
+        self.store_pool
+            .acquire_owned(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(ChangeError)
+    }
+
+    fn actor(session: &Session<Account>) -> Result<ActorEntityUuid, Report<ChangeError>> {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(ChangeError));
+        };
+
+        Ok(actor_id)
+    }
+}
+
+impl<S, A> ChangeSystem for ChangeServer<S, A>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    type ExecutionScope = Session<Account>;
+
+    type ChangeStream = impl Stream<Item = Result<ChangeEvent, Report<ChangeError>>> + Send;
+
+    async fn get_changes(
+        &self,
+        scope: Session<Account>,
+        params: GetChangesParams,
+    ) -> Result<GetChangesResponse, Report<ChangeError>> {
+        self.store()
+            .await?
+            .get_changes(Self::actor(&scope)?, params)
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not read changes");
+            })
+            .change_context(ChangeError)
+    }
+
+    async fn subscribe_changes(
+        &self,
+        scope: Session<Account>,
+        params: GetChangesParams,
+    ) -> Result<Self::ChangeStream, Report<ChangeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        // The feed is read page by page, the cursor of each page is used to read the next one.
+        // Once the end of the feed has been reached, the store is polled for new changes. A store
+        // is only acquired for reading a page, so an idle subscription does not hold a connection.
+        Ok(stream::unfold(
+            Some((self.clone(), params, VecDeque::new())),
+            move |state| async move {
+                let (server, mut params, mut pending) = state?;
+
+                loop {
+                    if let Some(change) = pending.pop_front() {
+                        return Some((Ok(change), Some((server, params, pending))));
+                    }
+
+                    let response = match server.store().await {
+                        Ok(store) => store
+                            .get_changes(actor_id, params.clone())
+                            .await
+                            .inspect_err(|error| {
+                                tracing::error!(?error, "Could not read changes");
+                            })
+                            .change_context(ChangeError),
+                        Err(error) => Err(error),
+                    };
+
+                    match response {
+                        Ok(response) => {
+                            // The cursor advances past changes the actor is not allowed to view,
+                            // so the feed is read again immediately unless it did not move.
+                            if response.cursor == params.after {
+                                tokio::time::sleep(POLL_INTERVAL).await;
+                            }
+
+                            params.after = response.cursor;
+                            pending.extend(response.changes);
+                        }
+                        Err(error) => return Some((Err(error), None)),
+                    }
+                }
+            },
+        ))
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ChangeDelegate<T> {
+    inner: T,
+}
+
+impl<T> ChangeDelegate<T> {
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, C> SubsystemDelegate<C> for ChangeDelegate<T>
+where
+    T: ChangeSystem<
+            get_changes(..): Send,
+            subscribe_changes(..): Send,
+            ExecutionScope: Send,
+            ChangeStream: Send,
+        > + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+{
+    type Error = Report<DelegationError>;
+    type ExecutionScope = T::ExecutionScope;
+    type Subsystem = meta::ChangeSystem;
+
+    type Body<Source>
+        = impl Body<Control: AsRef<ResponseKind>, Error = <C as Encoder>::Error>
+    where
+        Source: Body<Control = !, Error: Send + Sync> + Send;
+
+    async fn call<B>(
+        self,
+        request: Request<B>,
+        scope: T::ExecutionScope,
+        codec: C,
+    ) -> Result<Response<Self::Body<B>>, Self::Error>
+    where
+        B: Body<Control = !, Error: Send + Sync> + Send,
+    {
+        let id = parse_procedure_id(&request)?;
+
+        match id {
+            meta::ChangeProcedureId::GetChanges => {
+                delegate_call_discrete(request, codec, |params| async move {
+                    self.inner.get_changes(scope, params).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left))
+            }
+            meta::ChangeProcedureId::SubscribeChanges => {
+                delegate_call_server_streaming(request, codec, |params| async move {
+                    // If the subscription cannot be set up, the error is sent as the only item.
+                    match self.inner.subscribe_changes(scope, params).await {
+                        Ok(changes) => FutureEither::Left(changes),
+                        Err(error) => FutureEither::Right(stream::iter([Err(error)])),
+                    }
+                })
+                .await
+                .map(|response| response.map_body(Either::Right))
+            }
+        }
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive_where::derive_where(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ChangeClient<S, C> {
+    _session: PhantomData<fn() -> *const S>,
+    _codec: PhantomData<fn() -> *const C>,
+}
+
+impl<S, C> ChangeClient<S, C> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _session: PhantomData,
+            _codec: PhantomData,
+        }
+    }
+}
+
+impl<S, C> Default for ChangeClient<S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, C> ChangeSystem for ChangeClient<S, C>
+where
+    S: harpc_client::connection::ConnectionService<C>,
+    C: harpc_client::connection::ConnectionCodec,
+{
+    type ExecutionScope = Connection<S, C>;
+
+    type ChangeStream = impl Stream<Item = Result<ChangeEvent, Report<ChangeError>>> + Send;
+
+    async fn get_changes(
+        &self,
+        scope: Connection<S, C>,
+        params: GetChangesParams,
+    ) -> Result<GetChangesResponse, Report<ChangeError>> {
+        invoke_call_discrete(scope, meta::ChangeProcedureId::GetChanges, [params])
+            .await
+            .change_context(ChangeError)
+    }
+
+    async fn subscribe_changes(
+        &self,
+        scope: Connection<S, C>,
+        params: GetChangesParams,
+    ) -> Result<Self::ChangeStream, Report<ChangeError>> {
+        let changes = invoke_call_server_streaming(
+            scope,
+            meta::ChangeProcedureId::SubscribeChanges,
+            [params],
+        )
+        .await
+        .change_context(ChangeError)?;
+
+        Ok(changes.map(|change| change.change_context(ChangeError)))
+    }
+}
@@ -1,11 +1,12 @@
 pub mod account;
 pub mod auth;
+pub mod change;
 pub mod echo;
 mod session;
 
 use alloc::sync::Arc;
 
-use harpc_codec::{decode::ReportDecoder, encode::ReportEncoder};
+use harpc_codec::{NegotiateCodec, decode::ReportDecoder, encode::ReportEncoder};
 use harpc_server::{
     route::Route,
     router::{Router, RouterBuilder},
@@ -24,6 +25,7 @@ use hash_temporal_client::TemporalClient;
 use self::{
     account::{AccountDelegate, AccountServer},
     auth::{AuthenticationDelegate, AuthenticationServer},
+    change::{ChangeDelegate, ChangeServer},
     echo::{EchoDelegate, EchoServer},
     session::Account,
 };
@@ -33,6 +35,7 @@ pub enum GraphSubsystemId {
     Echo,
     Authentication,
     Account,
+    Change,
 }
 
 impl SubsystemIdentifier for GraphSubsystemId {
@@ -44,6 +47,7 @@ impl SubsystemIdentifier for GraphSubsystemId {
             0x00 => Some(Self::Echo),
             0x01 => Some(Self::Authentication),
             0x02 => Some(Self::Account),
+            0x03 => Some(Self::Change),
             _ => None,
         }
     }
@@ -53,6 +57,7 @@ impl SubsystemIdentifier for GraphSubsystemId {
             Self::Echo => SubsystemId::new(0x00),
             Self::Authentication => SubsystemId::new(0x01),
             Self::Account => SubsystemId::new(0x02),
+            Self::Change => SubsystemId::new(0x03),
         }
     }
 }
@@ -78,9 +83,11 @@ pub fn rpc_router<S, A, C, N>(
 where
     S: StorePool + Send + Sync + 'static,
     A: AuthorizationApiPool + Send + Sync + 'static,
-    C: ReportEncoder + ReportDecoder + Clone + Send + Sync + 'static,
+    C: ReportEncoder + ReportDecoder + NegotiateCodec + Clone + Send + Sync + 'static,
     for<'p, 'a> S::Store<'p, A::Api<'a>>: PrincipalStore,
 {
+    let temporal_client = dependencies.temporal_client.map(Arc::new);
+
     let builder = RouterBuilder::new(dependencies.codec)
         .with_builder(|builder| {
             builder
@@ -89,9 +96,14 @@ where
         })
         .register(AuthenticationDelegate::new(AuthenticationServer))
         .register(AccountDelegate::new(AccountServer {
+            store_pool: Arc::clone(&dependencies.store),
+            authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+            temporal_client: temporal_client.clone(),
+        }))
+        .register(ChangeDelegate::new(ChangeServer {
             store_pool: dependencies.store,
             authorization_api_pool: dependencies.authorization_api,
-            temporal_client: dependencies.temporal_client.map(Arc::new),
+            temporal_client,
         }))
         .register(EchoDelegate::new(EchoServer));
 
@@ -0,0 +1,5 @@
+DROP INDEX ontology_temporal_metadata_archived_idx;
+DROP INDEX ontology_temporal_metadata_created_idx;
+
+DROP INDEX entity_temporal_metadata_history_idx;
+DROP INDEX entity_temporal_metadata_change_feed_idx;
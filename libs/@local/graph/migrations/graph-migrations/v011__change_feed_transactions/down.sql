@@ -0,0 +1,15 @@
+CREATE INDEX entity_temporal_metadata_change_feed_idx
+ON entity_temporal_metadata (lower(transaction_time), entity_edition_id);
+CREATE INDEX ontology_temporal_metadata_created_idx
+ON ontology_temporal_metadata (lower(transaction_time), ontology_id);
+CREATE INDEX ontology_temporal_metadata_archived_idx
+ON ontology_temporal_metadata (upper(transaction_time), ontology_id)
+WHERE NOT upper_inf(transaction_time);
+
+DROP TRIGGER ontology_change_trigger ON ontology_temporal_metadata;
+DROP FUNCTION record_ontology_change;
+DROP TRIGGER entity_edition_changes_trigger ON entity_temporal_metadata;
+DROP FUNCTION record_entity_edition_changes;
+
+DROP TABLE ontology_changes;
+DROP TABLE entity_edition_changes;
@@ -0,0 +1 @@
+DROP INDEX entity_editions_properties_text_search_idx;
@@ -0,0 +1,26 @@
+use error_stack::Report;
+use hash_graph_migrations::{Context, Migration};
+use tokio_postgres::Client;
+
+pub struct EntityPropertiesTextSearch;
+
+impl Migration for EntityPropertiesTextSearch {
+    type Context = Client;
+    type Error = tokio_postgres::Error;
+
+    async fn up(
+        self,
+        context: &mut <Self::Context as Context>::Transaction<'_>,
+    ) -> Result<(), Report<Self::Error>> {
+        context.simple_query(include_str!("up.sql")).await?;
+        Ok(())
+    }
+
+    async fn down(
+        self,
+        context: &mut <Self::Context as Context>::Transaction<'_>,
+    ) -> Result<(), Report<Self::Error>> {
+        context.simple_query(include_str!("down.sql")).await?;
+        Ok(())
+    }
+}
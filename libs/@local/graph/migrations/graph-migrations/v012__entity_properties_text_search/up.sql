@@ -0,0 +1,5 @@
+-- Text search filters and rankings over entity properties compile to `to_tsvector('simple', ...)`.
+-- The configuration has to match the `TEXT_SEARCH_CONFIGURATION` of the query compiler, otherwise
+-- the index is not used.
+CREATE INDEX entity_editions_properties_text_search_idx
+ON entity_editions USING gin (to_tsvector('simple', properties));
@@ -0,0 +1,10 @@
+CREATE INDEX entity_temporal_metadata_change_feed_idx
+ON entity_temporal_metadata (lower(transaction_time), entity_edition_id);
+CREATE INDEX entity_temporal_metadata_history_idx
+ON entity_temporal_metadata (web_id, entity_uuid, lower(transaction_time));
+
+CREATE INDEX ontology_temporal_metadata_created_idx
+ON ontology_temporal_metadata (lower(transaction_time), ontology_id);
+CREATE INDEX ontology_temporal_metadata_archived_idx
+ON ontology_temporal_metadata (upper(transaction_time), ontology_id)
+WHERE NOT upper_inf(transaction_time);
@@ -0,0 +1,83 @@
+-- The change feed is read in the order in which transactions are assigned their IDs. A change is
+-- only returned once no transaction with a lower ID is running anymore, so changes of transactions
+-- which commit late are delayed instead of skipped.
+CREATE TABLE entity_edition_changes (
+    entity_edition_id UUID PRIMARY KEY REFERENCES entity_editions ON DELETE CASCADE,
+    transaction_time TIMESTAMP WITH TIME ZONE NOT NULL,
+    transaction_id XID8 NOT NULL DEFAULT pg_current_xact_id()
+);
+CREATE INDEX entity_edition_changes_feed_idx
+ON entity_edition_changes (transaction_id, transaction_time, entity_edition_id);
+
+CREATE TABLE ontology_changes (
+    ontology_id UUID NOT NULL REFERENCES ontology_ids ON DELETE CASCADE,
+    transaction_time TIMESTAMP WITH TIME ZONE NOT NULL,
+    archived BOOLEAN NOT NULL,
+    transaction_id XID8 NOT NULL DEFAULT pg_current_xact_id(),
+    PRIMARY KEY (ontology_id, transaction_time, archived)
+);
+CREATE INDEX ontology_changes_feed_idx
+ON ontology_changes (transaction_id, transaction_time, ontology_id);
+
+-- An entity edition is recorded at the lowest transaction time of its temporal metadata. Rows
+-- which are created for an existing edition, e.g. when the decision time of an entity is split by
+-- an update, are not considered a change.
+CREATE FUNCTION record_entity_edition_changes()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO entity_edition_changes (entity_edition_id, transaction_time)
+    SELECT entity_edition_id, min(lower(transaction_time))
+    FROM inserted_entity_temporal_metadata
+    GROUP BY entity_edition_id
+    ON CONFLICT DO NOTHING;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER entity_edition_changes_trigger
+AFTER INSERT ON entity_temporal_metadata
+REFERENCING NEW TABLE AS inserted_entity_temporal_metadata
+FOR EACH STATEMENT EXECUTE FUNCTION record_entity_edition_changes();
+
+-- An ontology type is created or unarchived when its temporal metadata is inserted and archived
+-- when the transaction time of its temporal metadata is closed.
+CREATE FUNCTION record_ontology_change()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO ontology_changes (ontology_id, transaction_time, archived)
+        VALUES (NEW.ontology_id, lower(NEW.transaction_time), FALSE)
+        ON CONFLICT DO NOTHING;
+    ELSIF upper_inf(OLD.transaction_time) AND NOT upper_inf(NEW.transaction_time) THEN
+        INSERT INTO ontology_changes (ontology_id, transaction_time, archived)
+        VALUES (NEW.ontology_id, upper(NEW.transaction_time), TRUE)
+        ON CONFLICT DO NOTHING;
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER ontology_change_trigger
+AFTER INSERT OR UPDATE OF transaction_time ON ontology_temporal_metadata
+FOR EACH ROW EXECUTE FUNCTION record_ontology_change();
+
+-- Changes recorded before this migration are attributed to the transaction running it.
+INSERT INTO entity_edition_changes (entity_edition_id, transaction_time)
+SELECT entity_edition_id, min(lower(transaction_time))
+FROM entity_temporal_metadata
+GROUP BY entity_edition_id;
+
+INSERT INTO ontology_changes (ontology_id, transaction_time, archived)
+SELECT ontology_id, lower(transaction_time), FALSE
+FROM ontology_temporal_metadata
+UNION
+SELECT ontology_id, upper(transaction_time), TRUE
+FROM ontology_temporal_metadata
+WHERE NOT upper_inf(transaction_time);
+
+-- The change feed is no longer read from the temporal metadata directly.
+DROP INDEX entity_temporal_metadata_change_feed_idx;
+DROP INDEX ontology_temporal_metadata_created_idx;
+DROP INDEX ontology_temporal_metadata_archived_idx;
@@ -0,0 +1,427 @@
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    schema::{DataTypePermission, EntityPermission, EntityTypePermission, PropertyTypePermission},
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    change::{
+        ChangeCursor, ChangeEvent, ChangeKind, ChangeStore, ChangedRecord, GetChangesParams,
+        GetChangesResponse,
+    },
+    error::QueryError,
+};
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
+use tokio_postgres::GenericClient as _;
+use type_system::{
+    knowledge::entity::{EntityId, id::EntityEditionId},
+    ontology::{
+        data_type::DataTypeUuid,
+        entity_type::EntityTypeUuid,
+        id::{OntologyTypeUuid, VersionedUrl},
+        property_type::PropertyTypeUuid,
+    },
+    principal::actor::ActorEntityUuid,
+};
+
+use crate::store::postgres::{AsClient, PostgresStore};
+
+/// Only returns changes of transactions which are no longer running.
+///
+/// Transaction IDs are assigned in the order transactions start writing, not in the order they
+/// commit. A change is therefore held back until every transaction with a lower ID has finished, so
+/// reading the feed in the order of transaction IDs never skips a change which is committed later.
+/// The current transaction sees its own changes, so they are not held back.
+const HORIZON_CONDITION: &str = "
+    (
+        changes.transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+        OR changes.transaction_id = pg_current_xact_id_if_assigned()
+    )
+";
+
+/// Converts a transaction ID of a cursor to the `bigint` it is compared as.
+///
+/// Transaction IDs are 64-bit counters which never exceed `i64::MAX`, a cursor beyond that is
+/// therefore placed after every change.
+fn transaction_id_to_sql(transaction_id: u64) -> i64 {
+    i64::try_from(transaction_id).unwrap_or(i64::MAX)
+}
+
+fn transaction_id_from_sql(transaction_id: i64) -> u64 {
+    u64::try_from(transaction_id).unwrap_or_else(|error| {
+        // Transaction IDs are assigned from zero upwards, so they are never negative.
+        unreachable!("invalid transaction id: {error}")
+    })
+}
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    /// Reads the entity editions which were recorded after the provided cursor.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn read_entity_changes(
+        &self,
+        after: Option<ChangeCursor>,
+        limit: i64,
+    ) -> Result<Vec<ChangeEvent>, Report<QueryError>> {
+        Ok(self
+            .as_client()
+            .query(
+                &format!(
+                    "
+                        SELECT
+                             changes.transaction_id::text::bigint,
+                             changes.transaction_time,
+                             changes.entity_edition_id,
+                             changed.web_id,
+                             changed.entity_uuid,
+                             changed.draft_id,
+                             previous.entity_edition_id IS NULL,
+                             entity_editions.archived,
+                             previous.entity_edition_id IS NOT NULL AND ARRAY(
+                                 SELECT entity_type_ontology_id
+                                 FROM entity_is_of_type
+                                 WHERE entity_edition_id = changes.entity_edition_id
+                                   AND inheritance_depth = 0
+                                 ORDER BY entity_type_ontology_id
+                             ) <> ARRAY(
+                                 SELECT entity_type_ontology_id
+                                 FROM entity_is_of_type
+                                 WHERE entity_edition_id = previous.entity_edition_id
+                                   AND inheritance_depth = 0
+                                 ORDER BY entity_type_ontology_id
+                             )
+                        FROM entity_edition_changes AS changes
+
+                        JOIN entity_editions
+                          ON entity_editions.entity_edition_id = changes.entity_edition_id
+
+                        JOIN LATERAL (
+                            SELECT web_id, entity_uuid, draft_id
+                            FROM entity_temporal_metadata
+                            WHERE entity_edition_id = changes.entity_edition_id
+                            LIMIT 1
+                        ) AS changed ON TRUE
+
+                        LEFT JOIN LATERAL (
+                            SELECT previous.entity_edition_id
+                            FROM entity_temporal_metadata AS previous
+                            WHERE previous.web_id = changed.web_id
+                              AND previous.entity_uuid = changed.entity_uuid
+                              AND previous.draft_id IS NOT DISTINCT FROM changed.draft_id
+                              AND previous.entity_edition_id <> changes.entity_edition_id
+                              AND lower(previous.transaction_time) < changes.transaction_time
+                            ORDER BY lower(previous.transaction_time) DESC
+                            LIMIT 1
+                        ) AS previous ON TRUE
+
+                        WHERE (
+                            changes.transaction_id,
+                            changes.transaction_time,
+                            changes.entity_edition_id
+                        ) > (
+                            COALESCE($1::bigint::text, '0')::xid8,
+                            COALESCE($2, '-infinity'::timestamptz),
+                            COALESCE($3, '00000000-0000-0000-0000-000000000000'::uuid)
+                        )
+                          AND {HORIZON_CONDITION}
+                        ORDER BY
+                            changes.transaction_id,
+                            changes.transaction_time,
+                            changes.entity_edition_id
+                        LIMIT $4;
+                    "
+                ),
+                &[
+                    &after.map(|cursor| transaction_id_to_sql(cursor.transaction_id)),
+                    &after.map(|cursor| cursor.transaction_time),
+                    &after.map(|cursor| cursor.record_id),
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let transaction_time: Timestamp<TransactionTime> = row.get(1);
+                let edition_id: EntityEditionId = row.get(2);
+
+                let kind = if row.get(6) {
+                    ChangeKind::Created
+                } else if row.get(7) {
+                    ChangeKind::Archived
+                } else if row.get(8) {
+                    ChangeKind::TypeChanged
+                } else {
+                    ChangeKind::Updated
+                };
+
+                ChangeEvent {
+                    kind,
+                    record: ChangedRecord::Entity {
+                        entity_id: EntityId {
+                            web_id: row.get(3),
+                            entity_uuid: row.get(4),
+                            draft_id: row.get(5),
+                        },
+                        edition_id,
+                    },
+                    transaction_time,
+                    cursor: ChangeCursor {
+                        transaction_id: transaction_id_from_sql(row.get(0)),
+                        transaction_time,
+                        record_id: edition_id.into_uuid(),
+                    },
+                }
+            })
+            .collect())
+    }
+
+    /// Reads the ontology types which were created, archived, or unarchived after the provided
+    /// cursor.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn read_ontology_changes(
+        &self,
+        after: Option<ChangeCursor>,
+        limit: i64,
+    ) -> Result<Vec<(OntologyTypeUuid, ChangeEvent)>, Report<QueryError>> {
+        Ok(self
+            .as_client()
+            .query(
+                &format!(
+                    "
+                        SELECT
+                             changes.transaction_id::text::bigint,
+                             changes.transaction_time,
+                             ontology_ids.ontology_id,
+                             ontology_ids.base_url,
+                             ontology_ids.version,
+                             changes.archived,
+                             NOT changes.archived AND (
+                                 EXISTS (
+                                     SELECT 1
+                                     FROM ontology_changes AS earlier
+                                     WHERE earlier.ontology_id = changes.ontology_id
+                                       AND NOT earlier.archived
+                                       AND earlier.transaction_time < changes.transaction_time
+                                 ) OR EXISTS (
+                                     SELECT 1
+                                     FROM ontology_ids AS previous
+                                     WHERE previous.base_url = ontology_ids.base_url
+                                       AND previous.version < ontology_ids.version
+                                 )
+                             ),
+                             data_types.ontology_id IS NOT NULL,
+                             property_types.ontology_id IS NOT NULL
+                        FROM ontology_changes AS changes
+
+                        JOIN ontology_ids
+                          ON ontology_ids.ontology_id = changes.ontology_id
+
+                        LEFT JOIN data_types
+                          ON data_types.ontology_id = changes.ontology_id
+
+                        LEFT JOIN property_types
+                          ON property_types.ontology_id = changes.ontology_id
+
+                        WHERE (
+                            changes.transaction_id,
+                            changes.transaction_time,
+                            changes.ontology_id
+                        ) > (
+                            COALESCE($1::bigint::text, '0')::xid8,
+                            COALESCE($2, '-infinity'::timestamptz),
+                            COALESCE($3, '00000000-0000-0000-0000-000000000000'::uuid)
+                        )
+                          AND {HORIZON_CONDITION}
+                        ORDER BY
+                            changes.transaction_id,
+                            changes.transaction_time,
+                            changes.ontology_id
+                        LIMIT $4;
+                    "
+                ),
+                &[
+                    &after.map(|cursor| transaction_id_to_sql(cursor.transaction_id)),
+                    &after.map(|cursor| cursor.transaction_time),
+                    &after.map(|cursor| cursor.record_id),
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let transaction_time: Timestamp<TransactionTime> = row.get(1);
+                let ontology_id: OntologyTypeUuid = row.get(2);
+                let id = VersionedUrl {
+                    base_url: row.get(3),
+                    version: row.get(4),
+                };
+
+                let kind = if row.get(5) {
+                    ChangeKind::Archived
+                } else if row.get(6) {
+                    ChangeKind::Updated
+                } else {
+                    ChangeKind::Created
+                };
+
+                let record = if row.get(7) {
+                    ChangedRecord::DataType { data_type_id: id }
+                } else if row.get(8) {
+                    ChangedRecord::PropertyType {
+                        property_type_id: id,
+                    }
+                } else {
+                    ChangedRecord::EntityType { entity_type_id: id }
+                };
+
+                (
+                    ontology_id,
+                    ChangeEvent {
+                        kind,
+                        record,
+                        transaction_time,
+                        cursor: ChangeCursor {
+                            transaction_id: transaction_id_from_sql(row.get(0)),
+                            transaction_time,
+                            record_id: ontology_id.into_uuid(),
+                        },
+                    },
+                )
+            })
+            .collect())
+    }
+}
+
+impl<C, A> ChangeStore for PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    async fn get_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: GetChangesParams,
+    ) -> Result<GetChangesResponse, Report<QueryError>> {
+        let limit = params
+            .limit
+            .map_or(GetChangesParams::DEFAULT_LIMIT, |limit| {
+                limit.min(GetChangesParams::MAX_LIMIT)
+            });
+        let query_limit = i64::try_from(limit).unwrap_or(i64::MAX);
+
+        let mut changes = self
+            .read_entity_changes(params.after, query_limit)
+            .await?
+            .into_iter()
+            .map(|change| (None, change))
+            .chain(
+                self.read_ontology_changes(params.after, query_limit)
+                    .await?
+                    .into_iter()
+                    .map(|(ontology_id, change)| (Some(ontology_id), change)),
+            )
+            .collect::<Vec<_>>();
+
+        changes.sort_unstable_by_key(|(_, change)| change.cursor);
+        changes.truncate(limit);
+
+        // The cursor is advanced past changes which are filtered out below, otherwise a feed
+        // consisting of changes the actor is not allowed to view would never make progress.
+        let cursor = changes
+            .last()
+            .map(|(_, change)| change.cursor)
+            .or(params.after);
+
+        let mut entity_ids = Vec::new();
+        let mut data_type_ids = Vec::new();
+        let mut property_type_ids = Vec::new();
+        let mut entity_type_ids = Vec::new();
+        for (ontology_id, change) in &changes {
+            match (&change.record, ontology_id) {
+                (ChangedRecord::Entity { entity_id, .. }, _) => entity_ids.push(*entity_id),
+                (ChangedRecord::DataType { .. }, Some(ontology_id)) => {
+                    data_type_ids.push(DataTypeUuid::from(*ontology_id));
+                }
+                (ChangedRecord::PropertyType { .. }, Some(ontology_id)) => {
+                    property_type_ids.push(PropertyTypeUuid::from(*ontology_id));
+                }
+                (ChangedRecord::EntityType { .. }, Some(ontology_id)) => {
+                    entity_type_ids.push(EntityTypeUuid::from(*ontology_id));
+                }
+                (_, None) => {}
+            }
+        }
+
+        let (entity_permissions, _) = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (data_type_permissions, _) = self
+            .authorization_api
+            .check_data_types_permission(
+                actor_id,
+                DataTypePermission::View,
+                data_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (property_type_permissions, _) = self
+            .authorization_api
+            .check_property_types_permission(
+                actor_id,
+                PropertyTypePermission::View,
+                property_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (entity_type_permissions, _) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::View,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let changes = changes
+            .into_iter()
+            .filter(|(ontology_id, change)| {
+                let permitted = match (&change.record, ontology_id) {
+                    (ChangedRecord::Entity { entity_id, .. }, _) => {
+                        entity_permissions.get(&entity_id.entity_uuid)
+                    }
+                    (ChangedRecord::DataType { .. }, Some(ontology_id)) => {
+                        data_type_permissions.get(&DataTypeUuid::from(*ontology_id))
+                    }
+                    (ChangedRecord::PropertyType { .. }, Some(ontology_id)) => {
+                        property_type_permissions.get(&PropertyTypeUuid::from(*ontology_id))
+                    }
+                    (ChangedRecord::EntityType { .. }, Some(ontology_id)) => {
+                        entity_type_permissions.get(&EntityTypeUuid::from(*ontology_id))
+                    }
+                    (_, None) => None,
+                };
+
+                permitted.copied().unwrap_or(false)
+            })
+            .map(|(_, change)| change)
+            .collect();
+
+        Ok(GetChangesResponse { changes, cursor })
+    }
+}
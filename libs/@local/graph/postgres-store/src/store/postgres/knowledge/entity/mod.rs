@@ -6,6 +6,7 @@ use std::collections::{HashMap, HashSet};
 
 use error_stack::{FutureExt as _, Report, ResultExt as _, TryReportStreamExt as _, ensure};
 use futures::{StreamExt as _, TryStreamExt as _, stream};
+use hash_codec::numeric::Real;
 use hash_graph_authorization::{
     AuthorizationApi,
     backend::ModifyRelationshipOperation,
@@ -18,8 +19,9 @@ use hash_graph_authorization::{
 };
 use hash_graph_store::{
     entity::{
-        CountEntitiesParams, CreateEntityParams, EmptyEntityTypes, EntityQueryPath,
-        EntityQuerySorting, EntityStore, EntityTypeRetrieval, EntityTypesError,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EmptyEntityTypes, EntityAggregationFunction, EntityAggregationGroup,
+        EntityQueryPath, EntityQuerySorting, EntityStore, EntityTypeRetrieval, EntityTypesError,
         EntityValidationReport, EntityValidationType, GetEntitiesParams, GetEntitiesResponse,
         GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams, QueryConversion,
         UpdateEntityEmbeddingsParams, ValidateEntityComponents, ValidateEntityParams,
@@ -88,7 +90,8 @@ use crate::store::{
         ResponseCountMap, TraversalContext,
         knowledge::entity::read::EntityEdgeTraversalData,
         query::{
-            InsertStatementBuilder, ReferenceTable, SelectCompiler, Table,
+            Expression, Function, InsertStatementBuilder, PostgresType, ReferenceTable,
+            SelectCompiler, Table,
             rows::{
                 EntityDraftRow, EntityEditionRow, EntityHasLeftEntityRow, EntityHasRightEntityRow,
                 EntityIdRow, EntityIsOfTypeRow, EntityTemporalMetadataRow,
@@ -134,6 +137,7 @@ where
         actor_id: ActorEntityUuid,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
+        include_drafts: bool,
     ) -> Result<(), Report<QueryError>> {
         let variable_axis = subgraph.temporal_axes.resolved.variable_time_axis();
 
@@ -308,6 +312,7 @@ where
             actor_id,
             zookie,
             subgraph,
+            include_drafts,
         )
         .await?;
 
@@ -1468,6 +1473,7 @@ where
                 actor_id,
                 &zookie,
                 &mut subgraph,
+                params.include_drafts,
             )
             .await?;
 
@@ -1609,6 +1615,151 @@ where
             .count())
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        mut params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        // Only the identifiers of the matching editions are read to check the permissions, the
+        // entities themselves are never materialized.
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+        let web_id_idx = compiler.add_selection_path(&EntityQueryPath::WebId);
+        let entity_uuid_idx = compiler.add_selection_path(&EntityQueryPath::Uuid);
+        let draft_id_idx = compiler.add_selection_path(&EntityQueryPath::DraftId);
+        let edition_id_idx = compiler.add_selection_path(&EntityQueryPath::EditionId);
+        compiler
+            .add_filter(&params.filter)
+            .change_context(QueryError)?;
+
+        let (statement, parameters) = compiler.compile();
+        let editions = self
+            .as_client()
+            .query_raw(&statement, parameters.iter().copied())
+            .instrument(tracing::trace_span!("query"))
+            .await
+            .change_context(QueryError)?
+            .map_ok(|row| {
+                (
+                    EntityId {
+                        web_id: row.get(web_id_idx),
+                        entity_uuid: row.get(entity_uuid_idx),
+                        draft_id: row.get(draft_id_idx),
+                    },
+                    row.get::<_, EntityEditionId>(edition_id_idx),
+                )
+            })
+            .try_collect::<Vec<_>>()
+            .await
+            .change_context(QueryError)?;
+
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                editions.iter().map(|(entity_id, _)| *entity_id),
+                Consistency::FullyConsistent,
+            )
+            .instrument(tracing::trace_span!("post_filter_entities"))
+            .await
+            .change_context(QueryError)?
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
+
+        // The aggregation only operates on the editions which were permitted above, so the
+        // original filter does not need to be compiled again.
+        let edition_ids = editions
+            .into_iter()
+            .filter(|(entity_id, _)| permitted_ids.contains(&entity_id.entity_uuid))
+            .map(|(_, edition_id)| edition_id)
+            .collect::<Vec<_>>();
+        let filter = Filter::In(
+            FilterExpression::Path {
+                path: EntityQueryPath::EditionId,
+            },
+            ParameterList::EntityEditionIds(&edition_ids),
+        );
+
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+
+        let key_indices = params
+            .group_by
+            .iter()
+            .map(|path| compiler.add_grouping_path(path))
+            .collect::<Vec<_>>();
+        let count_index = compiler.add_selection_expression(Expression::Function(Function::Count(
+            Box::new(Expression::Asterisk),
+        )));
+        let value_indices = params
+            .aggregations
+            .iter()
+            .map(|aggregation| {
+                let value = compiler.compile_path_column(&aggregation.path);
+                let number = || {
+                    Box::new(Expression::Cast(
+                        Box::new(value.clone()),
+                        PostgresType::Float8,
+                    ))
+                };
+                let function = match aggregation.function {
+                    EntityAggregationFunction::Count => Function::Count(Box::new(value.clone())),
+                    EntityAggregationFunction::Sum => Function::Sum(number()),
+                    EntityAggregationFunction::Min => Function::Min(number()),
+                    EntityAggregationFunction::Max => Function::Max(number()),
+                    EntityAggregationFunction::Avg => Function::Avg(number()),
+                };
+                compiler.add_selection_expression(Expression::Cast(
+                    Box::new(Expression::Function(function)),
+                    PostgresType::Float8,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        compiler.add_filter(&filter).change_context(QueryError)?;
+
+        let (statement, parameters) = compiler.compile();
+        let groups = self
+            .as_client()
+            .query_raw(&statement, parameters.iter().copied())
+            .instrument(tracing::trace_span!("query"))
+            .await
+            .change_context(QueryError)?
+            .map(|row| row.change_context(QueryError))
+            .map_ok(|row| EntityAggregationGroup {
+                key: key_indices
+                    .iter()
+                    .map(|&index| {
+                        row.get::<_, Option<serde_json::Value>>(index)
+                            .unwrap_or(serde_json::Value::Null)
+                    })
+                    .collect(),
+                count: usize::try_from(row.get::<_, i64>(count_index))
+                    .expect("count should be non-negative"),
+                values: value_indices
+                    .iter()
+                    .map(|&index| row.get::<_, Option<Real>>(index))
+                    .collect(),
+            })
+            .try_collect()
+            .await?;
+
+        Ok(AggregateEntitiesResponse { groups })
+    }
+
     async fn get_entity_by_id(
         &self,
         actor_id: ActorEntityUuid,
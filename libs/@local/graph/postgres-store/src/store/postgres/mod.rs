@@ -1,3 +1,4 @@
+mod change;
 mod crud;
 mod knowledge;
 mod migration;
@@ -25,7 +25,7 @@ use hash_graph_store::{
     subgraph::{
         Subgraph, SubgraphRecord as _,
         edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
-        identifier::{DataTypeVertexId, GraphElementVertexId},
+        identifier::{DataTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
         temporal_axes::{
             PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
             VariableAxis, VariableTemporalAxisUnresolved,
@@ -49,6 +49,7 @@ use type_system::{
         },
         id::{BaseUrl, OntologyTypeRecordId, OntologyTypeUuid, OntologyTypeVersion, VersionedUrl},
         json_schema::OntologyTypeResolver,
+        property_type::PropertyTypeUuid,
         provenance::{OntologyEditionProvenance, OntologyOwnership, OntologyProvenance},
     },
     principal::actor::ActorEntityUuid,
@@ -266,43 +267,56 @@ where
         actor_id: ActorEntityUuid,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
+        include_drafts: bool,
     ) -> Result<(), Report<QueryError>> {
+        let mut property_type_queue = Vec::new();
+
         while !data_type_queue.is_empty() {
             let mut edges_to_traverse =
-                HashMap::<OntologyEdgeKind, OntologyTypeTraversalData>::new();
+                HashMap::<(OntologyEdgeKind, EdgeDirection), OntologyTypeTraversalData>::new();
 
             for (data_type_ontology_id, graph_resolve_depths, traversal_interval) in
                 mem::take(&mut data_type_queue)
             {
-                for edge_kind in [
-                    OntologyEdgeKind::InheritsFrom,
-                    OntologyEdgeKind::ConstrainsValuesOn,
+                for (edge_kind, edge_direction) in [
+                    (OntologyEdgeKind::InheritsFrom, EdgeDirection::Outgoing),
+                    (
+                        OntologyEdgeKind::ConstrainsValuesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (OntologyEdgeKind::InheritsFrom, EdgeDirection::Incoming),
+                    (
+                        OntologyEdgeKind::ConstrainsValuesOn,
+                        EdgeDirection::Incoming,
+                    ),
                 ] {
-                    if let Some(new_graph_resolve_depths) = graph_resolve_depths
-                        .decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+                    if let Some(new_graph_resolve_depths) =
+                        graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
                     {
-                        edges_to_traverse.entry(edge_kind).or_default().push(
-                            OntologyTypeUuid::from(data_type_ontology_id),
-                            new_graph_resolve_depths,
-                            traversal_interval,
-                        );
+                        edges_to_traverse
+                            .entry((edge_kind, edge_direction))
+                            .or_default()
+                            .push(
+                                OntologyTypeUuid::from(data_type_ontology_id),
+                                new_graph_resolve_depths,
+                                traversal_interval,
+                            );
                     }
                 }
             }
 
-            for (edge_kind, table) in [(
-                OntologyEdgeKind::InheritsFrom,
-                ReferenceTable::DataTypeInheritsFrom {
-                    // TODO: Use the resolve depths passed to the query
-                    inheritance_depth: Some(0),
-                },
-            )] {
-                if let Some(traversal_data) = edges_to_traverse.get(&edge_kind) {
+            for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                let edge_kind = OntologyEdgeKind::InheritsFrom;
+                if let Some(traversal_data) = edges_to_traverse.get(&(edge_kind, edge_direction)) {
                     data_type_queue.extend(
                         Self::filter_data_types_by_permission(
                             self.read_ontology_edges::<DataTypeVertexId, DataTypeVertexId>(
                                 traversal_data,
-                                table,
+                                ReferenceTable::DataTypeInheritsFrom {
+                                    // TODO: Use the resolve depths passed to the query
+                                    inheritance_depth: Some(0),
+                                },
+                                edge_direction,
                             )
                             .await?,
                             actor_id,
@@ -314,7 +328,7 @@ where
                             subgraph.insert_edge(
                                 &edge.left_endpoint,
                                 edge_kind,
-                                EdgeDirection::Outgoing,
+                                edge_direction,
                                 edge.right_endpoint.clone(),
                             );
 
@@ -327,6 +341,54 @@ where
                     );
                 }
             }
+
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsValuesOn,
+                EdgeDirection::Incoming,
+            )) {
+                property_type_queue.extend(
+                    Self::filter_property_types_by_permission(
+                        self.read_ontology_edges::<DataTypeVertexId, PropertyTypeVertexId>(
+                            traversal_data,
+                            ReferenceTable::PropertyTypeConstrainsValuesOn,
+                            EdgeDirection::Incoming,
+                        )
+                        .await?,
+                        actor_id,
+                        &self.authorization_api,
+                        zookie,
+                    )
+                    .await?
+                    .flat_map(|edge| {
+                        subgraph.insert_edge(
+                            &edge.left_endpoint,
+                            OntologyEdgeKind::ConstrainsValuesOn,
+                            EdgeDirection::Incoming,
+                            edge.right_endpoint.clone(),
+                        );
+
+                        traversal_context.add_property_type_id(
+                            PropertyTypeUuid::from(edge.right_endpoint_ontology_id),
+                            edge.resolve_depths,
+                            edge.traversal_interval,
+                        )
+                    }),
+                );
+            }
+        }
+
+        if !property_type_queue.is_empty() {
+            // Property types are usually resolved before data types, so incoming edges require to
+            // traverse the graph upwards again.
+            Box::pin(self.traverse_property_types(
+                property_type_queue,
+                traversal_context,
+                actor_id,
+                zookie,
+                subgraph,
+                include_drafts,
+            ))
+            .await?;
         }
 
         Ok(())
@@ -769,6 +831,7 @@ where
             actor_id,
             &zookie,
             &mut subgraph,
+            params.include_drafts,
         )
         .await?;
 
@@ -12,7 +12,8 @@ use hash_graph_authorization::{
         action::ActionName, resource::EntityTypeId, store::PolicyStore as _,
     },
     schema::{
-        EntityTypeOwnerSubject, EntityTypePermission, EntityTypeRelationAndSubject, WebPermission,
+        EntityPermission, EntityTypeOwnerSubject, EntityTypePermission,
+        EntityTypeRelationAndSubject, WebPermission,
     },
     zanzibar::{Consistency, Zookie},
 };
@@ -32,8 +33,13 @@ use hash_graph_store::{
     query::{Ordering, QueryResult as _, Read, ReadPaginated, VersionedUrlSorting},
     subgraph::{
         Subgraph, SubgraphRecord as _,
-        edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind, OutgoingEdgeResolveDepth},
-        identifier::{EntityTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
+        edges::{
+            EdgeDirection, GraphResolveDepths, OntologyEdgeKind, OutgoingEdgeResolveDepth,
+            SharedEdgeKind,
+        },
+        identifier::{
+            EntityIdWithInterval, EntityTypeVertexId, GraphElementVertexId, PropertyTypeVertexId,
+        },
         temporal_axes::{
             PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
             VariableAxis, VariableTemporalAxisUnresolved,
@@ -586,38 +592,67 @@ where
         actor_id: ActorEntityUuid,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
+        include_drafts: bool,
     ) -> Result<(), Report<QueryError>> {
         let mut property_type_queue = Vec::new();
+        let mut entity_queue = Vec::new();
 
         while !entity_type_queue.is_empty() {
             let mut edges_to_traverse =
-                HashMap::<OntologyEdgeKind, OntologyTypeTraversalData>::new();
+                HashMap::<(OntologyEdgeKind, EdgeDirection), OntologyTypeTraversalData>::new();
+            let mut shared_edges_to_traverse = Option::<OntologyTypeTraversalData>::None;
 
             #[expect(clippy::iter_with_drain, reason = "false positive, vector is reused")]
             for (entity_type_ontology_id, graph_resolve_depths, traversal_interval) in
                 entity_type_queue.drain(..)
             {
-                for edge_kind in [
-                    OntologyEdgeKind::ConstrainsPropertiesOn,
-                    OntologyEdgeKind::InheritsFrom,
-                    OntologyEdgeKind::ConstrainsLinksOn,
-                    OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                for (edge_kind, edge_direction) in [
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (OntologyEdgeKind::InheritsFrom, EdgeDirection::Outgoing),
+                    (OntologyEdgeKind::ConstrainsLinksOn, EdgeDirection::Outgoing),
+                    (
+                        OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (OntologyEdgeKind::InheritsFrom, EdgeDirection::Incoming),
+                    (OntologyEdgeKind::ConstrainsLinksOn, EdgeDirection::Incoming),
+                    (
+                        OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                        EdgeDirection::Incoming,
+                    ),
                 ] {
-                    if let Some(new_graph_resolve_depths) = graph_resolve_depths
-                        .decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+                    if let Some(new_graph_resolve_depths) =
+                        graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
                     {
-                        edges_to_traverse.entry(edge_kind).or_default().push(
-                            OntologyTypeUuid::from(entity_type_ontology_id),
-                            new_graph_resolve_depths,
-                            traversal_interval,
-                        );
+                        edges_to_traverse
+                            .entry((edge_kind, edge_direction))
+                            .or_default()
+                            .push(
+                                OntologyTypeUuid::from(entity_type_ontology_id),
+                                new_graph_resolve_depths,
+                                traversal_interval,
+                            );
                     }
                 }
+
+                if let Some(new_graph_resolve_depths) = graph_resolve_depths
+                    .decrement_depth_for_edge(SharedEdgeKind::IsOfType, EdgeDirection::Incoming)
+                {
+                    shared_edges_to_traverse.get_or_insert_default().push(
+                        OntologyTypeUuid::from(entity_type_ontology_id),
+                        new_graph_resolve_depths,
+                        traversal_interval,
+                    );
+                }
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
-            {
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsPropertiesOn,
+                EdgeDirection::Outgoing,
+            )) {
                 // TODO: Filter for entity types, which were not already added to the
                 //       subgraph to avoid unnecessary lookups.
                 property_type_queue.extend(
@@ -628,6 +663,7 @@ where
                                 // TODO: Use the resolve depths passed to the query
                                 inheritance_depth: Some(0),
                             },
+                            EdgeDirection::Outgoing,
                         )
                         .await?,
                         actor_id,
@@ -652,56 +688,124 @@ where
                 );
             }
 
-            for (edge_kind, table) in [
-                (
-                    OntologyEdgeKind::InheritsFrom,
-                    ReferenceTable::EntityTypeInheritsFrom {
-                        // TODO: Use the resolve depths passed to the query
-                        inheritance_depth: Some(0),
-                    },
-                ),
-                (
-                    OntologyEdgeKind::ConstrainsLinksOn,
-                    ReferenceTable::EntityTypeConstrainsLinksOn {
-                        // TODO: Use the resolve depths passed to the query
-                        inheritance_depth: Some(0),
-                    },
-                ),
-                (
-                    OntologyEdgeKind::ConstrainsLinkDestinationsOn,
-                    ReferenceTable::EntityTypeConstrainsLinkDestinationsOn {
-                        // TODO: Use the resolve depths passed to the query
-                        inheritance_depth: Some(0),
-                    },
-                ),
-            ] {
-                if let Some(traversal_data) = edges_to_traverse.get(&edge_kind) {
-                    entity_type_queue.extend(
-                        Self::filter_entity_types_by_permission(
-                            self.read_ontology_edges::<EntityTypeVertexId, EntityTypeVertexId>(
-                                traversal_data,
-                                table,
+            for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                for (edge_kind, table) in [
+                    (
+                        OntologyEdgeKind::InheritsFrom,
+                        ReferenceTable::EntityTypeInheritsFrom {
+                            // TODO: Use the resolve depths passed to the query
+                            inheritance_depth: Some(0),
+                        },
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsLinksOn,
+                        ReferenceTable::EntityTypeConstrainsLinksOn {
+                            // TODO: Use the resolve depths passed to the query
+                            inheritance_depth: Some(0),
+                        },
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                        ReferenceTable::EntityTypeConstrainsLinkDestinationsOn {
+                            // TODO: Use the resolve depths passed to the query
+                            inheritance_depth: Some(0),
+                        },
+                    ),
+                ] {
+                    if let Some(traversal_data) =
+                        edges_to_traverse.get(&(edge_kind, edge_direction))
+                    {
+                        entity_type_queue.extend(
+                            Self::filter_entity_types_by_permission(
+                                self.read_ontology_edges::<EntityTypeVertexId, EntityTypeVertexId>(
+                                    traversal_data,
+                                    table,
+                                    edge_direction,
+                                )
+                                .await?,
+                                actor_id,
+                                &self.authorization_api,
+                                zookie,
                             )
-                            .await?,
+                            .await?
+                            .flat_map(|edge| {
+                                subgraph.insert_edge(
+                                    &edge.left_endpoint,
+                                    edge_kind,
+                                    edge_direction,
+                                    edge.right_endpoint.clone(),
+                                );
+
+                                traversal_context.add_entity_type_id(
+                                    EntityTypeUuid::from(edge.right_endpoint_ontology_id),
+                                    edge.resolve_depths,
+                                    edge.traversal_interval,
+                                )
+                            }),
+                        );
+                    }
+                }
+            }
+
+            if let Some(traversal_data) = shared_edges_to_traverse.take() {
+                let (entity_ids, shared_edges): (Vec<_>, Vec<_>) = self
+                    .read_incoming_shared_edges(
+                        &traversal_data,
+                        subgraph.temporal_axes.resolved.pinned_timestamp(),
+                        subgraph.temporal_axes.resolved.variable_time_axis(),
+                        Some(0),
+                        include_drafts,
+                    )
+                    .await?
+                    .unzip();
+
+                if !shared_edges.is_empty() {
+                    let permissions = self
+                        .authorization_api
+                        .check_entities_permission(
                             actor_id,
-                            &self.authorization_api,
-                            zookie,
+                            EntityPermission::View,
+                            entity_ids.iter().copied(),
+                            Consistency::AtExactSnapshot(zookie),
                         )
-                        .await?
-                        .flat_map(|edge| {
-                            subgraph.insert_edge(
-                                &edge.left_endpoint,
-                                edge_kind,
-                                EdgeDirection::Outgoing,
-                                edge.right_endpoint.clone(),
-                            );
-
-                            traversal_context.add_entity_type_id(
-                                EntityTypeUuid::from(edge.right_endpoint_ontology_id),
-                                edge.resolve_depths,
-                                edge.traversal_interval,
-                            )
-                        }),
+                        .await
+                        .change_context(QueryError)?
+                        .0;
+
+                    entity_queue.extend(
+                        shared_edges
+                            .into_iter()
+                            .zip(entity_ids)
+                            .filter_map(|(edge, entity_id)| {
+                                // We can unwrap here because we checked permissions for all
+                                // entities in question.
+                                permissions
+                                    .get(&entity_id.entity_uuid)
+                                    .copied()
+                                    .unwrap_or(true)
+                                    .then_some(edge)
+                            })
+                            .flat_map(|edge| {
+                                subgraph.insert_edge(
+                                    &edge.left_endpoint,
+                                    SharedEdgeKind::IsOfType,
+                                    EdgeDirection::Incoming,
+                                    EntityIdWithInterval {
+                                        entity_id: edge.right_endpoint.base_id,
+                                        interval: edge.edge_interval,
+                                    },
+                                );
+
+                                traversal_context
+                                    .add_entity_id(
+                                        edge.right_endpoint_edition_id,
+                                        edge.resolve_depths,
+                                        edge.traversal_interval,
+                                    )
+                                    .map(move |(_, resolve_depths, interval)| {
+                                        (edge.right_endpoint, resolve_depths, interval)
+                                    })
+                            }),
                     );
                 }
             }
@@ -713,9 +817,24 @@ where
             actor_id,
             zookie,
             subgraph,
+            include_drafts,
         )
         .await?;
 
+        if !entity_queue.is_empty() {
+            // Entities are resolved before their types, so incoming edges require to traverse the
+            // graph upwards again.
+            Box::pin(self.traverse_entities(
+                entity_queue,
+                traversal_context,
+                actor_id,
+                zookie,
+                subgraph,
+                include_drafts,
+            ))
+            .await?;
+        }
+
         Ok(())
     }
 
@@ -1299,6 +1418,7 @@ where
             actor_id,
             &zookie,
             &mut subgraph,
+            params.include_drafts,
         )
         .await?;
 
@@ -24,7 +24,9 @@ use hash_graph_store::{
     subgraph::{
         Subgraph, SubgraphRecord as _,
         edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
-        identifier::{DataTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
+        identifier::{
+            DataTypeVertexId, EntityTypeVertexId, GraphElementVertexId, PropertyTypeVertexId,
+        },
         temporal_axes::{QueryTemporalAxes, VariableAxis},
     },
 };
@@ -38,6 +40,7 @@ use type_system::{
     ontology::{
         OntologyTemporalMetadata,
         data_type::DataTypeUuid,
+        entity_type::EntityTypeUuid,
         id::{OntologyTypeRecordId, OntologyTypeUuid, OntologyTypeVersion, VersionedUrl},
         property_type::{
             PropertyTypeMetadata, PropertyTypeUuid, PropertyTypeWithMetadata,
@@ -212,9 +215,12 @@ where
         actor_id: ActorEntityUuid,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
+        include_drafts: bool,
     ) -> Result<(), Report<QueryError>> {
         let mut data_type_queue = Vec::new();
-        let mut edges_to_traverse = HashMap::<OntologyEdgeKind, OntologyTypeTraversalData>::new();
+        let mut entity_type_queue = Vec::new();
+        let mut edges_to_traverse =
+            HashMap::<(OntologyEdgeKind, EdgeDirection), OntologyTypeTraversalData>::new();
 
         while !property_type_queue.is_empty() {
             edges_to_traverse.clear();
@@ -223,30 +229,45 @@ where
             for (property_type_ontology_id, graph_resolve_depths, traversal_interval) in
                 property_type_queue.drain(..)
             {
-                for edge_kind in [
-                    OntologyEdgeKind::ConstrainsValuesOn,
-                    OntologyEdgeKind::ConstrainsPropertiesOn,
+                for (edge_kind, edge_direction) in [
+                    (
+                        OntologyEdgeKind::ConstrainsValuesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Incoming,
+                    ),
                 ] {
-                    if let Some(new_graph_resolve_depths) = graph_resolve_depths
-                        .decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+                    if let Some(new_graph_resolve_depths) =
+                        graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
                     {
-                        edges_to_traverse.entry(edge_kind).or_default().push(
-                            OntologyTypeUuid::from(property_type_ontology_id),
-                            new_graph_resolve_depths,
-                            traversal_interval,
-                        );
+                        edges_to_traverse
+                            .entry((edge_kind, edge_direction))
+                            .or_default()
+                            .push(
+                                OntologyTypeUuid::from(property_type_ontology_id),
+                                new_graph_resolve_depths,
+                                traversal_interval,
+                            );
                     }
                 }
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsValuesOn)
-            {
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsValuesOn,
+                EdgeDirection::Outgoing,
+            )) {
                 data_type_queue.extend(
                     Self::filter_data_types_by_permission(
                         self.read_ontology_edges::<PropertyTypeVertexId, DataTypeVertexId>(
                             traversal_data,
                             ReferenceTable::PropertyTypeConstrainsValuesOn,
+                            EdgeDirection::Outgoing,
                         )
                         .await?,
                         actor_id,
@@ -271,14 +292,54 @@ where
                 );
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
-            {
-                property_type_queue.extend(
-                    Self::filter_property_types_by_permission(
-                        self.read_ontology_edges::<PropertyTypeVertexId, PropertyTypeVertexId>(
+            for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                if let Some(traversal_data) = edges_to_traverse
+                    .get(&(OntologyEdgeKind::ConstrainsPropertiesOn, edge_direction))
+                {
+                    property_type_queue.extend(
+                        Self::filter_property_types_by_permission(
+                            self.read_ontology_edges::<PropertyTypeVertexId, PropertyTypeVertexId>(
+                                traversal_data,
+                                ReferenceTable::PropertyTypeConstrainsPropertiesOn,
+                                edge_direction,
+                            )
+                            .await?,
+                            actor_id,
+                            &self.authorization_api,
+                            zookie,
+                        )
+                        .await?
+                        .flat_map(|edge| {
+                            subgraph.insert_edge(
+                                &edge.left_endpoint,
+                                OntologyEdgeKind::ConstrainsPropertiesOn,
+                                edge_direction,
+                                edge.right_endpoint.clone(),
+                            );
+
+                            traversal_context.add_property_type_id(
+                                PropertyTypeUuid::from(edge.right_endpoint_ontology_id),
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                        }),
+                    );
+                }
+            }
+
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsPropertiesOn,
+                EdgeDirection::Incoming,
+            )) {
+                entity_type_queue.extend(
+                    Self::filter_entity_types_by_permission(
+                        self.read_ontology_edges::<PropertyTypeVertexId, EntityTypeVertexId>(
                             traversal_data,
-                            ReferenceTable::PropertyTypeConstrainsPropertiesOn,
+                            ReferenceTable::EntityTypeConstrainsPropertiesOn {
+                                // TODO: Use the resolve depths passed to the query
+                                inheritance_depth: Some(0),
+                            },
+                            EdgeDirection::Incoming,
                         )
                         .await?,
                         actor_id,
@@ -290,12 +351,12 @@ where
                         subgraph.insert_edge(
                             &edge.left_endpoint,
                             OntologyEdgeKind::ConstrainsPropertiesOn,
-                            EdgeDirection::Outgoing,
+                            EdgeDirection::Incoming,
                             edge.right_endpoint.clone(),
                         );
 
-                        traversal_context.add_property_type_id(
-                            PropertyTypeUuid::from(edge.right_endpoint_ontology_id),
+                        traversal_context.add_entity_type_id(
+                            EntityTypeUuid::from(edge.right_endpoint_ontology_id),
                             edge.resolve_depths,
                             edge.traversal_interval,
                         )
@@ -310,9 +371,24 @@ where
             actor_id,
             zookie,
             subgraph,
+            include_drafts,
         )
         .await?;
 
+        if !entity_type_queue.is_empty() {
+            // Entity types are usually resolved before property types, so incoming edges require
+            // to traverse the graph upwards again.
+            Box::pin(self.traverse_entity_types(
+                entity_type_queue,
+                traversal_context,
+                actor_id,
+                zookie,
+                subgraph,
+                include_drafts,
+            ))
+            .await?;
+        }
+
         Ok(())
     }
 
@@ -651,6 +727,7 @@ where
             actor_id,
             &zookie,
             &mut subgraph,
+            params.include_drafts,
         )
         .await?;
 
@@ -1,4 +1,5 @@
 use alloc::borrow::Cow;
+use core::mem::swap;
 
 use error_stack::{Report, ResultExt as _};
 use futures::{Stream, StreamExt as _};
@@ -7,18 +8,24 @@ use hash_graph_store::{
     error::QueryError,
     filter::Filter,
     subgraph::{
-        edges::GraphResolveDepths,
-        temporal_axes::{QueryTemporalAxes, VariableAxis},
+        edges::{EdgeDirection, GraphResolveDepths},
+        identifier::{EntityTypeVertexId, EntityVertexId},
+        temporal_axes::{PinnedAxis, QueryTemporalAxes, VariableAxis},
     },
 };
-use hash_graph_temporal_versioning::RightBoundedTemporalInterval;
+use hash_graph_temporal_versioning::{
+    LeftClosedTemporalInterval, RightBoundedTemporalInterval, TimeAxis, Timestamp,
+};
 use postgres_types::Json;
 use tokio_postgres::GenericClient as _;
-use type_system::ontology::{
-    EntityTypeWithMetadata, OntologyTemporalMetadata,
-    entity_type::{ClosedEntityTypeWithMetadata, EntityTypeMetadata, EntityTypeUuid},
-    id::{OntologyTypeRecordId, OntologyTypeUuid, VersionedUrl},
-    provenance::OntologyProvenance,
+use type_system::{
+    knowledge::entity::id::{EntityEditionId, EntityId},
+    ontology::{
+        EntityTypeWithMetadata, OntologyTemporalMetadata,
+        entity_type::{ClosedEntityTypeWithMetadata, EntityTypeMetadata, EntityTypeUuid},
+        id::{OntologyTypeRecordId, OntologyTypeUuid, VersionedUrl},
+        provenance::OntologyProvenance,
+    },
 };
 
 use super::PostgresOntologyOwnership;
@@ -58,6 +65,16 @@ pub struct OntologyEdgeTraversal<L, R> {
     pub traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
 }
 
+/// The result of an ontology-to-entity edge traversal.
+pub struct IncomingSharedEdgeTraversal {
+    pub left_endpoint: EntityTypeVertexId,
+    pub right_endpoint: EntityVertexId,
+    pub right_endpoint_edition_id: EntityEditionId,
+    pub resolve_depths: GraphResolveDepths,
+    pub edge_interval: LeftClosedTemporalInterval<VariableAxis>,
+    pub traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+}
+
 impl<C: AsClient, A: Send + Sync> PostgresStore<C, A> {
     #[tracing::instrument(level = "trace", skip(self, filter))]
     pub(crate) async fn read_closed_schemas<'f>(
@@ -129,6 +146,7 @@ impl<C: AsClient, A: Send + Sync> PostgresStore<C, A> {
         &self,
         record_ids: &'r OntologyTypeTraversalData,
         reference_table: ReferenceTable,
+        edge_direction: EdgeDirection,
     ) -> Result<
         impl Iterator<Item = (OntologyTypeUuid, OntologyEdgeTraversal<L, R>)> + 'r,
         Report<QueryError>,
@@ -138,19 +156,25 @@ impl<C: AsClient, A: Send + Sync> PostgresStore<C, A> {
         R: From<VersionedUrl>,
     {
         let table = Table::Reference(reference_table).transpile_to_string();
-        let source =
+        let mut source =
             if let ForeignKeyReference::Single { join, .. } = reference_table.source_relation() {
                 join.to_expression(None).transpile_to_string()
             } else {
                 unreachable!("Ontology reference tables don't have multiple conditions")
             };
-        let target =
+        let mut target =
             if let ForeignKeyReference::Single { on, .. } = reference_table.target_relation() {
                 on.to_expression(None).transpile_to_string()
             } else {
                 unreachable!("Ontology reference tables don't have multiple conditions")
             };
 
+        // For incoming edges the traversed types are referenced by the target column, so the
+        // endpoints are swapped and the referencing types are returned as right endpoints.
+        if edge_direction == EdgeDirection::Incoming {
+            swap(&mut source, &mut target);
+        }
+
         let depth = reference_table
             .inheritance_depth_column()
             .and_then(|column| Some((column.as_str(), column.inheritance_depth()?)));
@@ -218,4 +242,113 @@ impl<C: AsClient, A: Send + Sync> PostgresStore<C, A> {
                 )
             }))
     }
+
+    /// Reads the entities which are of the provided entity types.
+    ///
+    /// This is the reversed direction of [`read_shared_edges`].
+    ///
+    /// [`read_shared_edges`]: Self::read_shared_edges
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) async fn read_incoming_shared_edges<'r>(
+        &self,
+        record_ids: &'r OntologyTypeTraversalData,
+        pinned_timestamp: Timestamp<PinnedAxis>,
+        variable_axis: TimeAxis,
+        depth: Option<u32>,
+        include_drafts: bool,
+    ) -> Result<
+        impl Iterator<Item = (EntityId, IncomingSharedEdgeTraversal)> + 'r,
+        Report<QueryError>,
+    > {
+        let (pinned_axis, variable_axis) = match variable_axis {
+            TimeAxis::DecisionTime => ("transaction_time", "decision_time"),
+            TimeAxis::TransactionTime => ("decision_time", "transaction_time"),
+        };
+
+        let draft_condition = if include_drafts {
+            ""
+        } else {
+            "AND target.draft_id IS NULL"
+        };
+        let where_statement = depth.map_or(Cow::Borrowed(""), |depth| {
+            Cow::Owned(format!(
+                "WHERE entity_is_of_type.inheritance_depth <= {depth}"
+            ))
+        });
+
+        Ok(self
+            .client
+            .as_client()
+            .query(
+                &format!(
+                    "
+                        SELECT
+                             filter.idx,
+                             source.base_url,
+                             source.version,
+                             target.web_id,
+                             target.entity_uuid,
+                             target.draft_id,
+                             lower(target.{variable_axis}),
+                             target.entity_edition_id,
+                             target.{variable_axis},
+                             target.{variable_axis} * filter.interval
+                        FROM unnest($1::uuid[], $2::tstzrange[])
+                             WITH ORDINALITY
+                             AS filter(id, interval, idx)
+
+                        JOIN ontology_ids AS source
+                          ON source.ontology_id = filter.id
+
+                        JOIN entity_is_of_type
+                          ON entity_is_of_type.entity_type_ontology_id = filter.id
+
+                        JOIN entity_temporal_metadata AS target
+                          ON target.entity_edition_id = entity_is_of_type.entity_edition_id
+                         AND target.{pinned_axis} @> $3::timestamptz
+                         AND target.{variable_axis} && filter.interval
+                         {draft_condition}
+
+                        {where_statement};
+                    "
+                ),
+                &[
+                    &record_ids.ontology_ids,
+                    &record_ids.traversal_intervals,
+                    &pinned_timestamp,
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let index = usize::try_from(row.get::<_, i64>(0) - 1).unwrap_or_else(|error| {
+                    // The index is always a valid `usize` because it is the index of the
+                    // `record_ids` vectors that was just passed in.
+                    unreachable!("invalid index: {error}")
+                });
+                let right_endpoint_base_id = EntityId {
+                    web_id: row.get(3),
+                    entity_uuid: row.get(4),
+                    draft_id: row.get(5),
+                };
+                (
+                    right_endpoint_base_id,
+                    IncomingSharedEdgeTraversal {
+                        left_endpoint: EntityTypeVertexId::from(VersionedUrl {
+                            base_url: row.get(1),
+                            version: row.get(2),
+                        }),
+                        right_endpoint: EntityVertexId {
+                            base_id: right_endpoint_base_id,
+                            revision_id: row.get(6),
+                        },
+                        right_endpoint_edition_id: row.get(7),
+                        resolve_depths: record_ids.resolve_depths[index],
+                        edge_interval: row.get(8),
+                        traversal_interval: row.get(9),
+                    },
+                )
+            }))
+    }
 }
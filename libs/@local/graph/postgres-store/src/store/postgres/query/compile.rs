@@ -17,9 +17,10 @@ use tracing::instrument;
 use super::expression::JoinType;
 use crate::store::postgres::query::{
     Alias, AliasedTable, Column, Condition, Distinctness, EqualityOperator, Expression, Function,
-    JoinExpression, OrderByExpression, PostgresQueryPath, PostgresRecord, SelectExpression,
-    SelectStatement, Table, Transpile as _, WhereExpression, WindowStatement, WithExpression,
-    expression::{GroupByExpression, PostgresType},
+    JoinExpression, OrderByExpression, PostgresQueryPath, PostgresRecord, PostgresType,
+    SelectExpression, SelectStatement, Table, Transpile as _, WhereExpression, WindowStatement,
+    WithExpression,
+    expression::GroupByExpression,
     statement::FromItem,
     table::{
         DataTypeEmbeddings, DatabaseColumn as _, EntityEmbeddings, EntityTemporalMetadata,
@@ -352,6 +353,65 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         }
     }
 
+    /// Adds an arbitrary expression to the selection and returns its index.
+    pub fn add_selection_expression(&mut self, expression: Expression) -> usize {
+        self.statement
+            .selects
+            .push(SelectExpression::new(expression, None));
+        self.statement.selects.len() - 1
+    }
+
+    /// Groups the result by the value of `path` and adds the value to the selection.
+    ///
+    /// As the grouped paths may refer to columns of different types, the value is selected as
+    /// `jsonb`. Returns the index of the selection.
+    pub fn add_grouping_path(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let expression = self.compile_path_column(path);
+        self.statement
+            .group_by_expression
+            .expressions
+            .push(expression.clone());
+        self.add_selection_expression(Expression::Function(Function::ToJsonb(Box::new(
+            expression,
+        ))))
+    }
+
+    /// Orders the result by the text search rank of the document at `path` for `query`.
+    ///
+    /// The rank takes precedence over any other ordering. Like the distance of a cosine distance
+    /// filter, the rank cannot be combined with a cursor.
+    pub fn add_text_search_ranking(
+        &mut self,
+        path: &'p R::QueryPath<'q>,
+        query: &'p (dyn ToSql + Sync),
+    ) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        self.artifacts.cursor_disallowed_reason =
+            Some("Cannot use text search ranking with cursor");
+
+        let document = self.compile_path_column(path);
+        let query = self.add_parameter(query);
+        let rank = Expression::Function(Function::TsRank(
+            Box::new(Expression::Function(Function::ToTsVector(Box::new(
+                document,
+            )))),
+            Box::new(Expression::Function(Function::WebSearchToTsQuery(
+                Box::new(query),
+            ))),
+        ));
+
+        self.statement
+            .order_by_expression
+            .insert_front(rank.clone(), Ordering::Descending, None);
+        self.statement.distinct.push(rank.clone());
+        self.add_selection_expression(rank)
+    }
+
     #[expect(
         clippy::return_and_then,
         reason = "False positive: the function does not return an `Option`"
@@ -657,6 +717,23 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
 
                 Condition::EndsWith(left_filter, right_filter)
             }
+            Filter::TextSearch(lhs, rhs) => {
+                // `to_tsvector` accepts JSON documents as well, so the left side is not converted
+                // to text.
+                let left_filter = self.compile_filter_expression(lhs).0;
+
+                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
+                let right_filter = if right_parameter == ParameterType::Any {
+                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
+                } else {
+                    right_filter
+                };
+
+                Condition::TextSearch(
+                    Expression::Function(Function::ToTsVector(Box::new(left_filter))),
+                    Expression::Function(Function::WebSearchToTsQuery(Box::new(right_filter))),
+                )
+            }
             Filter::ContainsSegment(lhs, rhs) => {
                 let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
                 let left_filter = if left_parameter == ParameterType::Any {
@@ -22,6 +22,8 @@ pub enum Condition {
     StartsWith(Expression, Expression),
     EndsWith(Expression, Expression),
     ContainsSegment(Expression, Expression),
+    /// Matches a `tsvector` against a `tsquery`.
+    TextSearch(Expression, Expression),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -141,6 +143,11 @@ impl Transpile for Condition {
                 rhs.transpile(fmt)?;
                 fmt.write_str(" || '%'")
             }
+            Self::TextSearch(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @@ ")?;
+                rhs.transpile(fmt)
+            }
         }
     }
 }
@@ -359,6 +366,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transpile_text_search_condition() {
+        test_condition(
+            &Filter::TextSearch(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Description,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("temperature -kelvin")),
+                    convert: None,
+                },
+            ),
+            r#"to_tsvector('simple', "data_types_0_1_0"."schema"->>'description') @@ websearch_to_tsquery('simple', $1)"#,
+            &[&"temperature -kelvin"],
+        );
+    }
+
     #[test]
     fn render_without_parameters() {
         test_condition(
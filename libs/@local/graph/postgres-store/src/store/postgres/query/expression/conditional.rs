@@ -9,10 +9,29 @@ use crate::store::postgres::query::{
     table::DatabaseColumn as _,
 };
 
+/// The text search configuration used to parse documents and queries.
+///
+/// The `simple` configuration does not apply any language specific stemming, so it works the
+/// same way for all languages.
+///
+/// The properties of entity editions are indexed using this configuration, changing it requires a
+/// migration which recreates the `entity_editions_properties_text_search_idx` index.
+pub const TEXT_SEARCH_CONFIGURATION: &str = "simple";
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Function {
     Min(Box<Expression>),
     Max(Box<Expression>),
+    Count(Box<Expression>),
+    Sum(Box<Expression>),
+    Avg(Box<Expression>),
+    ToJsonb(Box<Expression>),
+    /// Converts a text or JSON document to a `tsvector` using the [`TEXT_SEARCH_CONFIGURATION`].
+    ToTsVector(Box<Expression>),
+    /// Parses a search query in web search syntax to a `tsquery` using the
+    /// [`TEXT_SEARCH_CONFIGURATION`].
+    WebSearchToTsQuery(Box<Expression>),
+    TsRank(Box<Expression>, Box<Expression>),
     JsonExtractText(Box<Expression>),
     JsonExtractAsText(Box<Expression>, PathToken<'static>),
     JsonExtractPath(Vec<Expression>),
@@ -39,6 +58,43 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Count(expression) => {
+                fmt.write_str("COUNT(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Sum(expression) => {
+                fmt.write_str("SUM(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Avg(expression) => {
+                fmt.write_str("AVG(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::ToJsonb(expression) => {
+                fmt.write_str("to_jsonb(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::ToTsVector(expression) => {
+                write!(fmt, "to_tsvector('{TEXT_SEARCH_CONFIGURATION}', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::WebSearchToTsQuery(expression) => {
+                write!(fmt, "websearch_to_tsquery('{TEXT_SEARCH_CONFIGURATION}', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::TsRank(vector, query) => {
+                fmt.write_str("ts_rank(")?;
+                vector.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::JsonExtractPath(paths) => {
                 fmt.write_str("jsonb_extract_path(")?;
                 for (i, expression) in paths.iter().enumerate() {
@@ -140,6 +196,7 @@ pub enum PostgresType {
     Row(Table),
     Text,
     JsonPath,
+    Float8,
 }
 
 impl Transpile for PostgresType {
@@ -152,6 +209,7 @@ impl Transpile for PostgresType {
             Self::Row(table) => table.transpile(fmt),
             Self::Text => fmt.write_str("text"),
             Self::JsonPath => fmt.write_str("jsonpath"),
+            Self::Float8 => fmt.write_str("float8"),
         }
     }
 }
@@ -292,4 +350,28 @@ mod tests {
             r#"MIN("ontology_ids_1_2_3"."version")"#
         );
     }
+
+    #[test]
+    fn transpile_aggregate_expression() {
+        assert_eq!(
+            Expression::Function(Function::Sum(Box::new(Expression::Cast(
+                Box::new(Expression::ColumnReference {
+                    column: DataTypeQueryPath::Version.terminating_column().0,
+                    table_alias: Some(Alias {
+                        condition_index: 1,
+                        chain_depth: 2,
+                        number: 3
+                    })
+                }),
+                PostgresType::Float8
+            ))))
+            .transpile_to_string(),
+            r#"SUM(("ontology_ids_1_2_3"."version"::float8))"#
+        );
+        assert_eq!(
+            Expression::Function(Function::Count(Box::new(Expression::Asterisk)))
+                .transpile_to_string(),
+            "COUNT(*)"
+        );
+    }
 }
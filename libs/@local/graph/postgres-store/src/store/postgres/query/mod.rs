@@ -33,8 +33,8 @@ pub use self::{
     compile::{SelectCompiler, SelectCompilerError},
     condition::{Condition, EqualityOperator},
     expression::{
-        Constant, Expression, Function, JoinExpression, OrderByExpression, SelectExpression,
-        WhereExpression, WithExpression,
+        Constant, Expression, Function, JoinExpression, OrderByExpression, PostgresType,
+        SelectExpression, WhereExpression, WithExpression,
     },
     statement::{
         Distinctness, InsertStatementBuilder, SelectStatement, Statement, WindowStatement,
@@ -141,6 +141,10 @@ where
     where
         's: 'q,
     {
+        if let Some(ranking) = &self.ranking {
+            compiler.add_text_search_ranking(&ranking.path, &ranking.query);
+        }
+
         if let Some(cursor) = self.cursor() {
             self.paths
                 .iter()
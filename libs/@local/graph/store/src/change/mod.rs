@@ -0,0 +1,6 @@
+pub use self::store::{
+    ChangeCursor, ChangeEvent, ChangeKind, ChangeStore, ChangedRecord, GetChangesParams,
+    GetChangesResponse,
+};
+
+mod store;
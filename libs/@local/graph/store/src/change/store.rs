@@ -0,0 +1,133 @@
+use error_stack::Report;
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
+use serde::{Deserialize, Serialize};
+use type_system::{
+    knowledge::entity::{EntityId, id::EntityEditionId},
+    ontology::VersionedUrl,
+    principal::actor::ActorEntityUuid,
+};
+use uuid::Uuid;
+
+use crate::error::QueryError;
+
+/// The kind of mutation which caused a [`ChangeEvent`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// The first edition of an entity or the first version of an ontology type was created.
+    Created,
+    /// A new edition of an entity or a new version of an ontology type was created, or an
+    /// archived ontology type was unarchived.
+    Updated,
+    /// The entity edition or the ontology type was archived.
+    Archived,
+    /// A new edition of an entity was created with a different set of entity types.
+    TypeChanged,
+}
+
+/// The record which was mutated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ChangedRecord {
+    #[serde(rename_all = "camelCase")]
+    Entity {
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+    },
+    #[serde(rename_all = "camelCase")]
+    DataType { data_type_id: VersionedUrl },
+    #[serde(rename_all = "camelCase")]
+    PropertyType { property_type_id: VersionedUrl },
+    #[serde(rename_all = "camelCase")]
+    EntityType { entity_type_id: VersionedUrl },
+}
+
+/// A single mutation of the graph.
+///
+/// Events are ordered by the transaction which recorded them, see [`ChangeCursor`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeEvent {
+    pub kind: ChangeKind,
+    pub record: ChangedRecord,
+    pub transaction_time: Timestamp<TransactionTime>,
+    /// The position of this event in the change feed.
+    pub cursor: ChangeCursor,
+}
+
+/// Position in the change feed.
+///
+/// Events are ordered by the ID of the database transaction which recorded them. Within a
+/// transaction, events are ordered by their transaction time and then by the edition or ontology
+/// identifier.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChangeCursor {
+    pub transaction_id: u64,
+    pub transaction_time: Timestamp<TransactionTime>,
+    pub record_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetChangesParams {
+    /// Only changes recorded after this position are returned.
+    ///
+    /// If omitted, the feed starts at the first change recorded in the graph.
+    ///
+    /// A change is only returned once every transaction which started writing before it has
+    /// finished, so continuing from the returned cursor never skips a change.
+    #[serde(default)]
+    pub after: Option<ChangeCursor>,
+    /// The maximum number of changes to read.
+    ///
+    /// Defaults to 100 changes, at most 1000 changes are read at once.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl GetChangesParams {
+    /// The number of changes read if no limit is specified.
+    pub const DEFAULT_LIMIT: usize = 100;
+    /// The maximum number of changes read at once, larger limits are capped to this value.
+    pub const MAX_LIMIT: usize = 1000;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetChangesResponse {
+    pub changes: Vec<ChangeEvent>,
+    /// The position to continue reading the feed from.
+    ///
+    /// This may be ahead of the last returned change if changes were omitted because the actor
+    /// is not allowed to view the mutated records.
+    pub cursor: Option<ChangeCursor>,
+}
+
+/// Describes the API of a store implementation for reading the mutations of the graph.
+pub trait ChangeStore {
+    /// Reads the changes recorded after the cursor in [`GetChangesParams`].
+    ///
+    /// Changes are returned in the order of the transactions which recorded them. Only changes to
+    /// records the actor is allowed to view are returned.
+    ///
+    /// A change is only returned once every transaction which started writing before it has
+    /// finished. Reading the feed from the returned cursor therefore never skips a change, but a
+    /// long-running transaction delays all changes recorded after it started.
+    ///
+    /// # Errors
+    ///
+    /// - if the request to the database fails
+    /// - if the permission check fails
+    fn get_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: GetChangesParams,
+    ) -> impl Future<Output = Result<GetChangesResponse, Report<QueryError>>> + Send;
+}
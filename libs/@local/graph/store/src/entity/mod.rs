@@ -2,15 +2,17 @@
 pub use self::store::CreateEntityRequest;
 pub use self::{
     query::{
-        EntityQueryCursor, EntityQueryPath, EntityQuerySorting, EntityQuerySortingRecord,
-        EntityQuerySortingToken, EntityQueryToken,
+        EntityQueryCursor, EntityQueryPath, EntityQueryRanking, EntityQuerySorting,
+        EntityQuerySortingRecord, EntityQuerySortingToken, EntityQueryToken,
     },
     store::{
-        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityParams, DiffEntityParams,
-        DiffEntityResult, EntityStore, EntityValidationType, GetEntitiesParams,
-        GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
-        QueryConversion, UpdateEntityEmbeddingsParams, ValidateEntityComponents,
-        ValidateEntityError, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, ClosedMultiEntityTypeMap,
+        CountEntitiesParams, CreateEntityParams, DiffEntityParams, DiffEntityResult,
+        EntityAggregation, EntityAggregationFunction, EntityAggregationGroup, EntityStore,
+        EntityValidationType, GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams,
+        GetEntitySubgraphResponse, PatchEntityParams, QueryConversion,
+        UpdateEntityEmbeddingsParams, ValidateEntityComponents, ValidateEntityError,
+        ValidateEntityParams,
     },
     validation_report::{
         EmptyEntityTypes, EntityRetrieval, EntityTypeRetrieval, EntityTypesError,
@@ -1039,12 +1039,39 @@ impl EntityQuerySortingRecord<'_> {
     }
 }
 
-#[derive(Debug, Deserialize)]
+/// Orders entities by how well the document at `path` matches the text search `query`.
+///
+/// Entities with the highest rank are returned first. The ranking takes precedence over the
+/// sorting paths and cannot be combined with a cursor.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityQueryRanking<'s> {
+    #[serde(borrow)]
+    pub path: EntityQueryPath<'s>,
+    /// The search query in web search syntax, usually the same as in the `textSearch` filter.
+    #[serde(borrow)]
+    pub query: Cow<'s, str>,
+}
+
+impl EntityQueryRanking<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> EntityQueryRanking<'static> {
+        EntityQueryRanking {
+            path: self.path.into_owned(),
+            query: Cow::Owned(self.query.into_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
 pub struct EntityQuerySorting<'s> {
     #[serde(borrow)]
     pub paths: Vec<EntityQuerySortingRecord<'s>>,
     #[serde(borrow)]
     pub cursor: Option<EntityQueryCursor<'s>>,
+    #[serde(borrow, default)]
+    pub ranking: Option<EntityQueryRanking<'s>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
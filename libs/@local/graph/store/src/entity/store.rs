@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use error_stack::Report;
 use futures::TryFutureExt as _;
+use hash_codec::numeric::Real;
 use hash_graph_authorization::{schema::EntityRelationAndSubject, zanzibar::Consistency};
 use hash_graph_temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
 use hash_graph_types::knowledge::entity::EntityEmbedding;
@@ -30,7 +31,7 @@ use utoipa::{
 };
 
 use crate::{
-    entity::{EntityQueryCursor, EntityQuerySorting, EntityValidationReport},
+    entity::{EntityQueryCursor, EntityQueryPath, EntityQuerySorting, EntityValidationReport},
     entity_type::{EntityTypeResolveDefinitions, IncludeEntityTypeOption},
     error::{InsertionError, QueryError, UpdateError},
     filter::Filter,
@@ -300,6 +301,67 @@ pub struct CountEntitiesParams<'a> {
     pub include_drafts: bool,
 }
 
+/// The function used to aggregate the values of a path within a group of entities.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EntityAggregationFunction {
+    /// The number of entities which have a value at the path.
+    Count,
+    Sum,
+    Min,
+    Max,
+    Avg,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityAggregation<'a> {
+    pub function: EntityAggregationFunction,
+    /// The path to aggregate, the values at the path are required to be numbers.
+    #[serde(borrow)]
+    pub path: EntityQueryPath<'a>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateEntitiesParams<'a> {
+    #[serde(borrow)]
+    pub filter: Filter<'a, Entity>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+    /// The paths to group the entities by.
+    ///
+    /// If no path is provided, all matching entities form a single group.
+    #[serde(borrow, default)]
+    pub group_by: Vec<EntityQueryPath<'a>>,
+    #[serde(borrow, default)]
+    pub aggregations: Vec<EntityAggregation<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityAggregationGroup {
+    /// The values of the `groupBy` paths, in the order they were requested.
+    pub key: Vec<serde_json::Value>,
+    /// The number of entities in the group.
+    pub count: usize,
+    /// The results of the `aggregations`, in the order they were requested.
+    ///
+    /// A result is `null` if none of the entities in the group has a value at the path.
+    pub values: Vec<Option<Real>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateEntitiesResponse {
+    pub groups: Vec<EntityAggregationGroup>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -470,6 +532,21 @@ pub trait EntityStore {
         params: CountEntitiesParams<'_>,
     ) -> impl Future<Output = Result<usize, Report<QueryError>>> + Send;
 
+    /// Groups the entities matching the filter in [`AggregateEntitiesParams`] and aggregates
+    /// the requested paths for each group.
+    ///
+    /// Only entities the actor is allowed to view are taken into account.
+    ///
+    /// # Errors
+    ///
+    /// - if the request to the database fails
+    /// - if an aggregated value is not a number
+    fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: AggregateEntitiesParams<'_>,
+    ) -> impl Future<Output = Result<AggregateEntitiesResponse, Report<QueryError>>> + Send;
+
     fn get_entity_by_id(
         &self,
         actor_id: ActorEntityUuid,
@@ -133,6 +133,12 @@ pub enum Filter<'p, R: QueryRecord> {
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches the tokenized text of the left expression against the search query on the right.
+    ///
+    /// The query uses web search syntax, e.g. `"exact phrase" -excluded or alternative`. If the
+    /// left expression is a JSON value, such as the properties of an entity, all string values
+    /// in it are searched.
+    TextSearch(FilterExpression<'p, R>, FilterExpression<'p, R>),
 }
 
 impl<'p, R> Filter<'p, R>
@@ -454,7 +460,8 @@ where
             }
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::TextSearch(lhs, rhs) => {
                 lhs.apply_parameter_conversion(data_type_provider).await?;
                 rhs.apply_parameter_conversion(data_type_provider).await?;
 
@@ -11,6 +11,7 @@
 extern crate alloc;
 
 pub mod account;
+pub mod change;
 pub mod data_type;
 pub mod entity;
 pub mod entity_type;
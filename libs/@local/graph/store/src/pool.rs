@@ -6,7 +6,7 @@ use hash_graph_authorization::AuthorizationApi;
 use hash_temporal_client::TemporalClient;
 
 use crate::{
-    account::AccountStore, data_type::DataTypeStore, entity::EntityStore,
+    account::AccountStore, change::ChangeStore, data_type::DataTypeStore, entity::EntityStore,
     entity_type::EntityTypeStore, property_type::PropertyTypeStore,
 };
 
@@ -25,6 +25,7 @@ pub trait StorePool {
         + PropertyTypeStore
         + EntityTypeStore
         + EntityStore
+        + ChangeStore
         + Send
         + Sync;
 
@@ -76,6 +76,17 @@ impl EdgeKind<EntityTypeVertexId, PropertyTypeVertexId> for OntologyEdgeKind {
     }
 }
 
+impl EdgeKind<PropertyTypeVertexId, EntityTypeVertexId> for OntologyEdgeKind {
+    type EdgeSet = HashSet<EntityTypeVertexId>;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<PropertyTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.property_type_to_entity_type
+    }
+}
+
 impl EdgeKind<PropertyTypeVertexId, PropertyTypeVertexId> for OntologyEdgeKind {
     type EdgeSet = HashSet<PropertyTypeVertexId>;
 
@@ -98,6 +109,17 @@ impl EdgeKind<PropertyTypeVertexId, DataTypeVertexId> for OntologyEdgeKind {
     }
 }
 
+impl EdgeKind<DataTypeVertexId, PropertyTypeVertexId> for OntologyEdgeKind {
+    type EdgeSet = HashSet<PropertyTypeVertexId>;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<DataTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.data_type_to_property_type
+    }
+}
+
 impl EdgeKind<DataTypeVertexId, DataTypeVertexId> for OntologyEdgeKind {
     type EdgeSet = HashSet<DataTypeVertexId>;
 
@@ -159,6 +181,17 @@ impl EdgeKind<EntityVertexId, EntityTypeVertexId> for SharedEdgeKind {
     }
 }
 
+impl EdgeKind<EntityTypeVertexId, EntityIdWithInterval> for SharedEdgeKind {
+    type EdgeSet = EntityIdWithIntervalSet;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<EntityTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.entity_type_to_entity
+    }
+}
+
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[serde(deny_unknown_fields)]
@@ -180,9 +213,10 @@ impl EdgeResolveDepths {
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[serde(deny_unknown_fields)]
 pub struct OutgoingEdgeResolveDepth {
-    #[serde(default, skip)]
-    #[doc(hidden)]
-    /// This is not used yet, but will be used in the future to support incoming edges.
+    /// The number of incoming edges to resolve.
+    ///
+    /// Omitting this value is equivalent to not resolving any incoming edges.
+    #[serde(default)]
     pub incoming: u8,
     pub outgoing: u8,
 }
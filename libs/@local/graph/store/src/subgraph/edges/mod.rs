@@ -140,14 +140,20 @@ pub struct Edges {
         AdjacencyList<EntityVertexId, KnowledgeGraphEdgeKind, EntityIdWithIntervalSet>,
     pub entity_to_entity_type:
         AdjacencyList<EntityVertexId, SharedEdgeKind, HashSet<EntityTypeVertexId>>,
+    pub entity_type_to_entity:
+        AdjacencyList<EntityTypeVertexId, SharedEdgeKind, EntityIdWithIntervalSet>,
     pub entity_type_to_entity_type:
         AdjacencyList<EntityTypeVertexId, OntologyEdgeKind, HashSet<EntityTypeVertexId>>,
     pub entity_type_to_property_type:
         AdjacencyList<EntityTypeVertexId, OntologyEdgeKind, HashSet<PropertyTypeVertexId>>,
+    pub property_type_to_entity_type:
+        AdjacencyList<PropertyTypeVertexId, OntologyEdgeKind, HashSet<EntityTypeVertexId>>,
     pub property_type_to_property_type:
         AdjacencyList<PropertyTypeVertexId, OntologyEdgeKind, HashSet<PropertyTypeVertexId>>,
     pub property_type_to_data_type:
         AdjacencyList<PropertyTypeVertexId, OntologyEdgeKind, HashSet<DataTypeVertexId>>,
+    pub data_type_to_property_type:
+        AdjacencyList<DataTypeVertexId, OntologyEdgeKind, HashSet<PropertyTypeVertexId>>,
     pub data_type_to_data_type:
         AdjacencyList<DataTypeVertexId, OntologyEdgeKind, HashSet<DataTypeVertexId>>,
 }
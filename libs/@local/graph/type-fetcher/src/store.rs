@@ -31,6 +31,7 @@ use hash_graph_store::{
         CreateUserActorResponse, GetActorError, QueryWebError, TeamRetrievalError,
         WebInsertionError, WebRetrievalError,
     },
+    change::{ChangeStore, GetChangesParams, GetChangesResponse},
     data_type::{
         ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeStore,
         GetDataTypeConversionTargetsParams, GetDataTypeConversionTargetsResponse,
@@ -39,9 +40,10 @@ use hash_graph_store::{
         UpdateDataTypesParams,
     },
     entity::{
-        CountEntitiesParams, CreateEntityParams, EntityStore, EntityValidationReport,
-        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
-        PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityStore, EntityValidationReport, GetEntitiesParams,
+        GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
+        UpdateEntityEmbeddingsParams, ValidateEntityParams,
     },
     entity_type::{
         ArchiveEntityTypeParams, CountEntityTypesParams, CreateEntityTypeParams, EntityTypeStore,
@@ -958,6 +960,20 @@ where
     }
 }
 
+impl<S, A> ChangeStore for FetchingStore<S, A>
+where
+    S: ChangeStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn get_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: GetChangesParams,
+    ) -> Result<GetChangesResponse, Report<QueryError>> {
+        self.store.get_changes(actor_id, params).await
+    }
+}
+
 impl<S, A> AccountStore for FetchingStore<S, A>
 where
     S: AccountStore + Send + Sync,
@@ -1611,6 +1627,14 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
     async fn patch_entity(
         &mut self,
         actor_id: ActorEntityUuid,
@@ -5,12 +5,15 @@ use core::error::Error;
 
 use bytes::Buf;
 use error_stack::Report;
-use futures::stream;
-use harpc_codec::{decode::Decoder, encode::Encoder};
+use futures::stream::{self, BoxStream};
+use harpc_codec::{NegotiateCodec, decode::Decoder, encode::Encoder};
 use harpc_tower::{request::Request, response::Response};
 
 pub type ConnectionRequestStream<C> = stream::Iter<vec::IntoIter<<C as Encoder>::Buf>>;
 
+/// Request body of a call, whose items are encoded lazily while the request is sent.
+pub type ConnectionRequestItemStream<C> = BoxStream<'static, <C as Encoder>::Buf>;
+
 pub trait ConnectionService<C>:
     tower::Service<
         Request<stream::Iter<vec::IntoIter<C::Buf>>>,
@@ -56,6 +59,7 @@ where
 pub trait ConnectionCodec:
     Encoder<Error = Report<Self::EncoderError>, Buf: Send>
     + Decoder<Error = Report<Self::DecoderError>>
+    + NegotiateCodec
     + Clone
     + Send
     + Sync
@@ -68,6 +72,7 @@ impl<C, EncoderError, DecoderError> ConnectionCodec for C
 where
     C: Encoder<Error = Report<EncoderError>, Buf: Send>
         + Decoder<Error = Report<DecoderError>>
+        + NegotiateCodec
         + Clone
         + Send
         + Sync,
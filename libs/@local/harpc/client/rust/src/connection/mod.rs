@@ -5,7 +5,9 @@ use futures::Stream;
 use harpc_tower::request::Request;
 use tower::Service;
 
-pub use self::alias::{ConnectionCodec, ConnectionRequestStream, ConnectionService};
+pub use self::alias::{
+    ConnectionCodec, ConnectionRequestItemStream, ConnectionRequestStream, ConnectionService,
+};
 
 mod alias;
 pub mod default;
@@ -20,7 +22,14 @@ pub struct Connection<S, C> {
 }
 
 impl<S, C> Connection<S, C> {
-    pub(crate) const fn new(service: S, codec: C) -> Self {
+    /// Creates a connection from a service and the codec used to encode requests and decode
+    /// responses.
+    ///
+    /// Connections are usually created through [`Client::connect`], this allows using a custom
+    /// service instead, e.g. one that does not use the network at all.
+    ///
+    /// [`Client::connect`]: crate::Client::connect
+    pub const fn new(service: S, codec: C) -> Self {
         Self { service, codec }
     }
 
@@ -28,6 +37,20 @@ impl<S, C> Connection<S, C> {
         &self.codec
     }
 
+    /// Replaces the codec used for this connection.
+    ///
+    /// By default a connection uses the codec of the [`Client`] it has been created from, this
+    /// allows choosing a different one on a per-connection basis. The encoding of the codec is
+    /// transmitted with every request, so that the server is able to select the same codec.
+    ///
+    /// [`Client`]: crate::Client
+    pub fn with_codec<C2>(self, codec: C2) -> Connection<S, C2> {
+        Connection {
+            service: self.service,
+            codec,
+        }
+    }
+
     pub fn into_parts(self) -> (S, C) {
         (self.service, self.codec)
     }
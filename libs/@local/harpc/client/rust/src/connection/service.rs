@@ -4,13 +4,16 @@ use core::task::{Context, Poll};
 use bytes::Buf as _;
 use error_stack::Report;
 use futures::StreamExt as _;
-use harpc_net::session::error::ConnectionPartiallyClosedError;
+use harpc_net::session::{client::CallOptions, error::ConnectionPartiallyClosedError};
 use harpc_tower::{
     body::{Body, BodyExt as _},
+    layer::compression::CompressionOptions,
     net::unpack::Unpack,
-    request::Request,
+    request::{Deadline, Request},
     response::{self, Response},
 };
+use harpc_types::encoding::Encoding;
+use tokio_util::sync::CancellationToken;
 use tower::Service;
 
 use crate::TransportLayerGuard;
@@ -55,6 +58,29 @@ where
             let procedure = req.procedure();
             let session = req.session();
 
+            // the payload has already been compressed by the `CompressionService`, we only need
+            // to signal the algorithms to the server
+            let compression = req
+                .extensions()
+                .get::<CompressionOptions>()
+                .copied()
+                .unwrap_or_default();
+            let options = CallOptions {
+                compression: compression.request,
+                accept: compression.response,
+                encoding: req
+                    .extensions()
+                    .get::<Encoding>()
+                    .copied()
+                    .unwrap_or_default(),
+                deadline: req
+                    .extensions()
+                    .get::<Deadline>()
+                    .copied()
+                    .map(Deadline::remaining),
+                cancel: req.extensions().get::<CancellationToken>().cloned(),
+            };
+
             let body = req
                 .into_body()
                 .into_stream()
@@ -65,7 +91,9 @@ where
                     data.copy_to_bytes(remaining)
                 });
 
-            let value = connection.call(service, procedure, body).await?;
+            let value = connection
+                .call_with_options(service, procedure, options, body)
+                .await?;
 
             let body = Unpack::new(value);
 
@@ -17,9 +17,10 @@ use harpc_net::{
     session::client::{SessionConfig, SessionLayer},
     transport::{TransportConfig, TransportLayer},
 };
+use harpc_tower::layer::compression::{CompressionLayer, CompressionOptions};
 use multiaddr::Multiaddr;
 use tokio_util::sync::{CancellationToken, DropGuard};
-use tower::Layer;
+use tower::{Layer, ServiceBuilder};
 
 use self::connection::{
     Connection,
@@ -31,6 +32,10 @@ use self::connection::{
 pub struct ClientConfig {
     pub transport: TransportConfig,
     pub session: SessionConfig,
+    /// Compression used by connections created through [`Client::connect`].
+    ///
+    /// **Default:** no compression
+    pub compression: CompressionOptions,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
@@ -55,6 +60,7 @@ pub(crate) struct TransportLayerGuard(
 pub struct Client<C> {
     session: Arc<SessionLayer>,
     codec: C,
+    compression: CompressionOptions,
 
     guard: TransportLayerGuard,
 }
@@ -62,6 +68,10 @@ pub struct Client<C> {
 impl<C> Client<C> {
     /// Creates a new `Client` with the given configuration.
     ///
+    /// The encoding of the codec is transmitted with every request, the server must support it,
+    /// otherwise the request is rejected. The codecs supported by a server are advertised through
+    /// its introspection subsystem.
+    ///
     /// # Errors
     ///
     /// Returns a `ClientError::StartTransportLayer` if unable to start the transport layer.
@@ -78,6 +88,7 @@ impl<C> Client<C> {
         Ok(Self {
             session: Arc::new(session),
             codec,
+            compression: config.compression,
             guard: TransportLayerGuard(guard),
         })
     }
@@ -94,9 +105,11 @@ impl<C> Client<C> {
     where
         C: Clone + Sync,
     {
-        let connection = self
-            .connect_with_service(DefaultLayer::new(), target)
-            .await?;
+        let layer = ServiceBuilder::new()
+            .layer(DefaultLayer::new())
+            .layer(CompressionLayer::new(self.compression));
+
+        let connection = self.connect_with_service(layer, target).await?;
 
         Ok(Connection::new(
             default::Default::new(connection),
@@ -2,20 +2,26 @@
 
 use core::error::Error;
 
+use bytes::Buf;
 use error_stack::{Report, ResultExt as _, TryReportStreamExt as _};
-use futures::{StreamExt as _, stream};
-use harpc_codec::encode::Encoder;
+use futures::{Stream, StreamExt as _, TryStream, channel::oneshot, future, stream};
+use harpc_codec::{NegotiateCodec, encode::Encoder};
 use harpc_net::session::server::SessionId;
 use harpc_system::{Subsystem, procedure::ProcedureIdentifier};
 use harpc_tower::{
     Extensions,
     request::{self, Request},
+    response::Response,
 };
 use harpc_types::procedure::ProcedureDescriptor;
+use tokio_util::sync::CancellationToken;
 use tower::ServiceExt as _;
 
 use crate::{
-    connection::{Connection, ConnectionCodec, ConnectionRequestStream, ConnectionService},
+    connection::{
+        Connection, ConnectionCodec, ConnectionRequestItemStream, ConnectionRequestStream,
+        ConnectionService,
+    },
     error::{RemoteError, RemoteInvocationError, ResponseExpectedItemCountMismatch},
 };
 
@@ -31,27 +37,37 @@ pub async fn encode_request_iter<P, E, C>(
 ) -> Result<Request<ConnectionRequestStream<E>>, Report<[C]>>
 where
     P: ProcedureIdentifier + Send,
-    E: Encoder<Error = Report<C>, Buf: Send> + Send,
+    E: Encoder<Error = Report<C>, Buf: Send> + NegotiateCodec + Send,
     C: Error + Send + Sync + 'static,
 {
+    let mut extensions = Extensions::new();
+    extensions.insert(codec.encoding());
+
     let items: Vec<_> = codec
         .encode(stream::iter(items))
         .try_collect_reports()
         .await?;
 
     Ok(Request::from_parts(
-        request::Parts {
-            subsystem: <P::Subsystem as Subsystem>::descriptor(),
-            procedure: ProcedureDescriptor {
-                id: procedure.into_id(),
-            },
-            session: SessionId::CLIENT,
-            extensions: Extensions::new(),
-        },
+        request_parts(procedure, extensions),
         stream::iter(items),
     ))
 }
 
+fn request_parts<P>(procedure: P, extensions: Extensions) -> request::Parts
+where
+    P: ProcedureIdentifier,
+{
+    request::Parts {
+        subsystem: <P::Subsystem as Subsystem>::descriptor(),
+        procedure: ProcedureDescriptor {
+            id: procedure.into_id(),
+        },
+        session: SessionId::CLIENT,
+        extensions,
+    }
+}
+
 /// Delegates a call to a closure with a predetermined amount of inputs and outputs.
 ///
 /// # Errors
@@ -98,3 +114,154 @@ where
     data.map_err(RemoteError::new)
         .change_context(RemoteInvocationError)
 }
+
+/// Decodes every item of a response.
+///
+/// Each item is expected to be a `Result`, an error item is returned as a [`RemoteError`].
+fn decode_response_items<C, S, O>(
+    codec: C,
+    body: S,
+) -> impl Stream<Item = Result<O, Report<RemoteInvocationError>>> + Send
+where
+    C: ConnectionCodec,
+    S: TryStream<Ok: Buf> + Send,
+    O: serde::de::DeserializeOwned,
+{
+    codec.decode(body).map(
+        |item: Result<Result<O, serde_value::Value>, Report<C::DecoderError>>| {
+            item.change_context(RemoteInvocationError)?
+                .map_err(RemoteError::new)
+                .change_context(RemoteInvocationError)
+        },
+    )
+}
+
+/// Invokes a procedure with a predetermined amount of inputs, which responds with a stream of
+/// outputs.
+///
+/// Items are decoded as they are received, the server is only able to produce items as fast as
+/// the returned stream is consumed.
+///
+/// # Errors
+///
+/// This function returns a `Report<RemoteInvocationError>` in the following cases:
+/// - If encoding the request fails
+/// - If the service call fails
+///
+/// Every item of the returned stream is an error if decoding the item fails or if the remote
+/// server returns an error for the item.
+pub async fn invoke_call_server_streaming<Svc, C, O>(
+    connection: Connection<Svc, C>,
+    procedure: impl ProcedureIdentifier + Send,
+    request: impl IntoIterator<Item: serde::Serialize, IntoIter: Send> + Send,
+) -> Result<
+    impl Stream<Item = Result<O, Report<RemoteInvocationError>>> + Send,
+    Report<RemoteInvocationError>,
+>
+where
+    Svc: ConnectionService<C>,
+    C: ConnectionCodec,
+    O: serde::de::DeserializeOwned,
+{
+    let (service, codec) = connection.into_parts();
+
+    let request = encode_request_iter(codec.clone(), procedure, request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let response = service
+        .oneshot(request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let (_, body) = response.into_parts();
+
+    Ok(decode_response_items(codec, body))
+}
+
+/// Invokes a procedure with a stream of inputs, which responds with a stream of outputs.
+///
+/// Request items are encoded lazily while the request is sent, and only as fast as the
+/// connection is able to transmit them. Response items are decoded as they are received.
+///
+/// If a request item cannot be encoded, the call is cancelled and the error is returned as the
+/// last item of the returned stream.
+///
+/// # Errors
+///
+/// This function returns a `Report<RemoteInvocationError>` if the service call fails.
+///
+/// Every item of the returned stream is an error if decoding the item fails or if the remote
+/// server returns an error for the item.
+pub async fn invoke_call_bidirectional_streaming<Svc, C, O>(
+    connection: Connection<Svc, C>,
+    procedure: impl ProcedureIdentifier + Send,
+    request: impl Stream<Item: serde::Serialize> + Send + 'static,
+) -> Result<
+    impl Stream<Item = Result<O, Report<RemoteInvocationError>>> + Send,
+    Report<RemoteInvocationError>,
+>
+where
+    Svc: ConnectionService<C>
+        + tower::Service<
+            Request<ConnectionRequestItemStream<C>>,
+            Response = Response<Svc::ResponseStream>,
+            Error = Report<Svc::ServiceError>,
+            Future: Send,
+        >,
+    C: ConnectionCodec<Buf: 'static> + 'static,
+    O: serde::de::DeserializeOwned,
+{
+    let (service, codec) = connection.into_parts();
+
+    // The request body is unable to carry errors, instead the call is cancelled on the first
+    // item that cannot be encoded, and the error is forwarded to the response.
+    let cancel = CancellationToken::new();
+    let (error_tx, mut error_rx) = oneshot::channel();
+
+    let items = codec.clone().encode(request).scan(Some(error_tx), {
+        let cancel = cancel.clone();
+
+        move |error_tx, item| {
+            let buffer = match item {
+                Ok(buffer) => Some(buffer),
+                Err(report) => {
+                    if let Some(error_tx) = error_tx.take() {
+                        // the receiver is only gone once the response has been dropped, in that
+                        // case nobody is interested in the error anymore
+                        let _result = error_tx.send(report);
+                    }
+
+                    cancel.cancel();
+                    None
+                }
+            };
+
+            future::ready(buffer)
+        }
+    });
+
+    let mut extensions = Extensions::new();
+    extensions.insert(codec.encoding());
+    extensions.insert(cancel);
+
+    let request = Request::from_parts(request_parts(procedure, extensions), items.boxed());
+
+    let response = service
+        .oneshot(request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let (_, body) = response.into_parts();
+
+    let encode_error = stream::once(async move {
+        error_rx
+            .try_recv()
+            .ok()
+            .flatten()
+            .map(|report| Err(report.change_context(RemoteInvocationError)))
+    })
+    .filter_map(future::ready);
+
+    Ok(decode_response_items(codec, body).chain(encode_error))
+}
@@ -0,0 +1,228 @@
+use bytes::{Buf, Bytes};
+use error_stack::Report;
+use futures_core::{Stream, TryStream};
+use futures_util::{TryStreamExt as _, future::Either, stream::MapErr};
+use harpc_types::encoding::Encoding;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    NegotiateCodec,
+    cbor::{CborCodec, CborError},
+    decode::Decoder,
+    encode::Encoder,
+    json::{JsonCodec, JsonError},
+    msgpack::{MessagePackCodec, MessagePackError},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, thiserror::Error)]
+pub enum AnyCodecError {
+    #[error("unable to encode value")]
+    Encode,
+    #[error("unable to decode value")]
+    Decode,
+}
+
+fn encode_error<C>(report: Report<C>) -> Report<AnyCodecError> {
+    report.change_context(AnyCodecError::Encode)
+}
+
+fn decode_error<C>(report: Report<C>) -> Report<AnyCodecError> {
+    report.change_context(AnyCodecError::Decode)
+}
+
+type MapEncodeError<C, Input> =
+    MapErr<<C as Encoder>::Output<Input>, fn(<C as Encoder>::Error) -> Report<AnyCodecError>>;
+
+type MapDecodeError<C, T, Input> =
+    MapErr<<C as Decoder>::Output<T, Input>, fn(<C as Decoder>::Error) -> Report<AnyCodecError>>;
+
+/// Codec supporting every encoding of this crate.
+///
+/// The codec is used by servers, which serve clients using different codecs. For every request
+/// the codec of the encoding chosen by the client is selected through
+/// [`NegotiateCodec::negotiate`]. A client using an `AnyCodec` encodes its requests with the
+/// selected codec, which is JSON by default.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnyCodec {
+    Json(JsonCodec),
+    Cbor(CborCodec),
+    MessagePack(MessagePackCodec),
+}
+
+impl AnyCodec {
+    /// Returns the codec for the given encoding.
+    ///
+    /// Returns `None` if the encoding is unknown.
+    #[must_use]
+    pub const fn from_encoding(encoding: Encoding) -> Option<Self> {
+        match encoding {
+            Encoding::JSON => Some(Self::Json(JsonCodec)),
+            Encoding::CBOR => Some(Self::Cbor(CborCodec::new())),
+            Encoding::MESSAGE_PACK => Some(Self::MessagePack(MessagePackCodec::new())),
+            _ => None,
+        }
+    }
+}
+
+impl Default for AnyCodec {
+    fn default() -> Self {
+        Self::Json(JsonCodec)
+    }
+}
+
+impl NegotiateCodec for AnyCodec {
+    fn encoding(&self) -> Encoding {
+        match self {
+            Self::Json(_) => Encoding::JSON,
+            Self::Cbor(_) => Encoding::CBOR,
+            Self::MessagePack(_) => Encoding::MESSAGE_PACK,
+        }
+    }
+
+    fn encodings(&self) -> &'static [Encoding] {
+        &[Encoding::JSON, Encoding::CBOR, Encoding::MESSAGE_PACK]
+    }
+
+    fn negotiate(&self, encoding: Encoding) -> Option<Self> {
+        Self::from_encoding(encoding)
+    }
+}
+
+impl Encoder for AnyCodec {
+    type Buf = Bytes;
+    type Error = Report<AnyCodecError>;
+    type Output<Input>
+        = Either<
+        MapEncodeError<JsonCodec, Input>,
+        Either<MapEncodeError<CborCodec, Input>, MapEncodeError<MessagePackCodec, Input>>,
+    >
+    where
+        Input: Stream + Send;
+
+    fn encode<T, S>(self, input: S) -> Self::Output<S>
+    where
+        T: serde::Serialize,
+        S: Stream<Item = T> + Send,
+    {
+        match self {
+            Self::Json(codec) => Either::Left(
+                codec
+                    .encode(input)
+                    .map_err(encode_error::<JsonError> as fn(_) -> _),
+            ),
+            Self::Cbor(codec) => Either::Right(Either::Left(
+                codec
+                    .encode(input)
+                    .map_err(encode_error::<CborError> as fn(_) -> _),
+            )),
+            Self::MessagePack(codec) => Either::Right(Either::Right(
+                codec
+                    .encode(input)
+                    .map_err(encode_error::<MessagePackError> as fn(_) -> _),
+            )),
+        }
+    }
+}
+
+impl Decoder for AnyCodec {
+    type Error = Report<AnyCodecError>;
+    type Output<T, Input>
+        = Either<
+        MapDecodeError<JsonCodec, T, Input>,
+        Either<MapDecodeError<CborCodec, T, Input>, MapDecodeError<MessagePackCodec, T, Input>>,
+    >
+    where
+        T: DeserializeOwned,
+        Input: TryStream<Ok: Buf> + Send;
+
+    fn decode<T, S>(self, items: S) -> Self::Output<T, S>
+    where
+        T: DeserializeOwned,
+        S: TryStream<Ok: Buf> + Send,
+    {
+        match self {
+            Self::Json(codec) => Either::Left(
+                codec
+                    .decode(items)
+                    .map_err(decode_error::<JsonError> as fn(_) -> _),
+            ),
+            Self::Cbor(codec) => Either::Right(Either::Left(
+                codec
+                    .decode(items)
+                    .map_err(decode_error::<CborError> as fn(_) -> _),
+            )),
+            Self::MessagePack(codec) => Either::Right(Either::Right(
+                codec
+                    .decode(items)
+                    .map_err(decode_error::<MessagePackError> as fn(_) -> _),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::future::ready;
+    use std::io;
+
+    use bytes::{BufMut as _, Bytes, BytesMut};
+    use futures_util::{StreamExt as _, stream};
+    use harpc_types::encoding::Encoding;
+
+    use crate::{NegotiateCodec as _, any::AnyCodec, decode::Decoder as _, encode::Encoder as _};
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct Record {
+        key: String,
+    }
+
+    async fn encode(codec: AnyCodec, records: Vec<Record>) -> Bytes {
+        let mut buffer = BytesMut::new();
+        let mut encoder = codec.encode(stream::iter(records));
+
+        while let Some(chunk) = encoder.next().await {
+            buffer.put(chunk.expect("should be able to encode record"));
+        }
+
+        buffer.freeze()
+    }
+
+    #[tokio::test]
+    async fn roundtrip_every_encoding() {
+        let codec = AnyCodec::default();
+
+        for &encoding in codec.encodings() {
+            let codec = codec
+                .negotiate(encoding)
+                .expect("should support every listed encoding");
+            assert_eq!(codec.encoding(), encoding);
+
+            let bytes = encode(
+                codec,
+                vec![Record {
+                    key: "value".to_owned(),
+                }],
+            )
+            .await;
+
+            let input = stream::once(ready(Result::<_, io::Error>::Ok(bytes)));
+            let decoded: Vec<_> = codec
+                .decode::<Record, _>(input)
+                .map(|item| item.expect("should be Ok"))
+                .collect()
+                .await;
+
+            assert_eq!(
+                decoded,
+                [Record {
+                    key: "value".to_owned()
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_encoding() {
+        assert_eq!(AnyCodec::default().negotiate(Encoding::new(0xFF)), None);
+    }
+}
@@ -0,0 +1,50 @@
+use bytes::{BytesMut, buf::Writer};
+use error_stack::{Report, ResultExt as _};
+use harpc_types::encoding::Encoding;
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::framed::{FramedCodec, FramedDecoderStream, RecordFormat};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, thiserror::Error)]
+pub enum CborError {
+    #[error("unable to encode CBOR value")]
+    Encode,
+    #[error("unable to decode CBOR value")]
+    Decode,
+}
+
+/// Encodes every record as a [CBOR] value.
+///
+/// [CBOR]: https://www.rfc-editor.org/rfc/rfc8949
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cbor;
+
+impl RecordFormat for Cbor {
+    type Error = CborError;
+
+    const DECODE_ERROR: Self::Error = CborError::Decode;
+    const ENCODE_ERROR: Self::Error = CborError::Encode;
+    const ENCODING: Encoding = Encoding::CBOR;
+    const NAME: &'static str = "cbor";
+
+    fn encode_record<T>(value: &T, writer: &mut Writer<BytesMut>) -> Result<(), Report<Self::Error>>
+    where
+        T: Serialize,
+    {
+        ciborium::into_writer(value, writer).change_context(CborError::Encode)
+    }
+
+    fn decode_record<T>(record: &[u8]) -> Result<T, Report<Self::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        ciborium::from_reader(record).change_context(CborError::Decode)
+    }
+}
+
+/// Codec encoding every record as a length-delimited [CBOR] value.
+///
+/// [CBOR]: https://www.rfc-editor.org/rfc/rfc8949
+pub type CborCodec = FramedCodec<Cbor>;
+
+pub type CborDecoderStream<T, S> = FramedDecoderStream<T, S, CborError>;
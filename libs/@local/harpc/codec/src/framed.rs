@@ -0,0 +1,450 @@
+//! Length-delimited framing shared by the binary codecs.
+//!
+//! Unlike JSON, binary formats have no byte that is guaranteed to never appear inside of a value,
+//! so a separator cannot be used. Instead every record is prefixed by its length, encoded as a
+//! big-endian `u32`.
+//!
+//! The framing is implemented once by [`FramedCodec`], the binary formats only implement
+//! [`RecordFormat`] to encode and decode a single record.
+use core::{
+    error::Error,
+    fmt::Debug,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use bytes::{Buf, BufMut as _, Bytes, BytesMut, buf::Writer};
+use error_stack::Report;
+use futures_core::{Stream, TryStream};
+use futures_util::stream::{self, StreamExt as _};
+use harpc_types::encoding::Encoding;
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{NamedCodec, decode::Decoder, encode::Encoder};
+
+const LENGTH_PREFIX: usize = size_of::<u32>();
+
+/// A binary format, which encodes and decodes a single record of a [`FramedCodec`].
+pub trait RecordFormat {
+    /// The name of the codec using this format, see [`NamedCodec`].
+    const NAME: &'static str;
+    /// The encoding of the codec using this format, see [`NamedCodec`].
+    const ENCODING: Encoding;
+
+    type Error: Error + Copy + Send + Sync + 'static;
+
+    /// Context of the error returned if a record cannot be framed.
+    const ENCODE_ERROR: Self::Error;
+    /// Context of the error returned if the underlying stream of a decoder fails.
+    const DECODE_ERROR: Self::Error;
+
+    /// Writes a single record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be encoded.
+    fn encode_record<T>(
+        value: &T,
+        writer: &mut Writer<BytesMut>,
+    ) -> Result<(), Report<Self::Error>>
+    where
+        T: Serialize;
+
+    /// Reads a single record, the slice contains exactly one record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be decoded.
+    fn decode_record<T>(record: &[u8]) -> Result<T, Report<Self::Error>>
+    where
+        T: DeserializeOwned;
+}
+
+/// Codec encoding every record as a length-delimited value of the [`RecordFormat`] `F`.
+pub struct FramedCodec<F> {
+    _format: PhantomData<fn() -> F>,
+}
+
+impl<F> FramedCodec<F> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            _format: PhantomData,
+        }
+    }
+}
+
+impl<F> Debug for FramedCodec<F>
+where
+    F: RecordFormat,
+{
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.debug_tuple("FramedCodec").field(&F::NAME).finish()
+    }
+}
+
+impl<F> Default for FramedCodec<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> Clone for FramedCodec<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for FramedCodec<F> {}
+
+impl<F> PartialEq for FramedCodec<F> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<F> Eq for FramedCodec<F> {}
+
+impl<F> NamedCodec for FramedCodec<F>
+where
+    F: RecordFormat,
+{
+    const ENCODING: Encoding = F::ENCODING;
+    const NAME: &'static str = F::NAME;
+}
+
+/// Creates a buffer with space reserved for the length prefix of a frame.
+///
+/// The returned writer is to be passed to [`finish_frame`] once the record has been written.
+fn start_frame() -> Writer<BytesMut> {
+    let mut buffer = BytesMut::new();
+    buffer.put_u32(0);
+
+    buffer.writer()
+}
+
+/// Writes the length prefix into a buffer created by [`start_frame`].
+///
+/// Returns `None` if the record is larger than 4GiB and cannot be framed.
+#[expect(
+    clippy::big_endian_bytes,
+    reason = "numbers are always encoded in big-endian in our encoding scheme"
+)]
+fn finish_frame(writer: Writer<BytesMut>) -> Option<Bytes> {
+    let mut buffer = writer.into_inner();
+
+    let length = u32::try_from(buffer.len() - LENGTH_PREFIX).ok()?;
+    buffer[..LENGTH_PREFIX].copy_from_slice(&length.to_be_bytes());
+
+    Some(buffer.freeze())
+}
+
+fn encode_frame<F, T>(item: T) -> Result<Bytes, Report<F::Error>>
+where
+    F: RecordFormat,
+    T: Serialize,
+{
+    let mut writer = start_frame();
+
+    F::encode_record(&item, &mut writer)?;
+
+    finish_frame(writer)
+        .ok_or_else(|| Report::new(F::ENCODE_ERROR).attach_printable("record is larger than 4GiB"))
+}
+
+impl<F> Encoder for FramedCodec<F>
+where
+    F: RecordFormat,
+{
+    type Buf = Bytes;
+    type Error = Report<F::Error>;
+    type Output<Input>
+        = stream::Map<Input, fn(Input::Item) -> Result<Bytes, Report<F::Error>>>
+    where
+        Input: Stream + Send;
+
+    fn encode<T, S>(self, input: S) -> Self::Output<S>
+    where
+        T: Serialize,
+        S: Stream<Item = T> + Send,
+    {
+        input.map(encode_frame::<F, T>)
+    }
+}
+
+impl<F> Decoder for FramedCodec<F>
+where
+    F: RecordFormat,
+{
+    type Error = Report<F::Error>;
+    type Output<T, Input>
+        = FramedDecoderStream<T, Input, F::Error>
+    where
+        T: DeserializeOwned,
+        Input: TryStream<Ok: Buf> + Send;
+
+    fn decode<T, S>(self, items: S) -> Self::Output<T, S>
+    where
+        T: DeserializeOwned,
+        S: TryStream<Ok: Buf> + Send,
+    {
+        FramedDecoderStream::new(items, F::decode_record, F::DECODE_ERROR)
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Stream of records decoded from a length-delimited byte stream.
+    pub struct FramedDecoderStream<T, S, C> {
+        #[pin]
+        inner: Option<S>,
+        buffer: BytesMut,
+        decode: fn(&[u8]) -> Result<T, Report<C>>,
+        // Context used if the underlying stream returns an error.
+        context: C,
+    }
+}
+
+impl<T, S, C> FramedDecoderStream<T, S, C> {
+    pub(crate) fn new(inner: S, decode: fn(&[u8]) -> Result<T, Report<C>>, context: C) -> Self {
+        Self {
+            inner: Some(inner),
+            buffer: BytesMut::new(),
+            decode,
+            context,
+        }
+    }
+
+    #[expect(
+        clippy::big_endian_bytes,
+        reason = "numbers are always encoded in big-endian in our encoding scheme"
+    )]
+    fn poll_item(self: Pin<&mut Self>) -> Option<Result<T, Report<C>>> {
+        let this = self.project();
+
+        let prefix = this.buffer.first_chunk::<LENGTH_PREFIX>()?;
+        let length = u32::from_be_bytes(*prefix) as usize;
+
+        if this.buffer.len() < LENGTH_PREFIX + length {
+            return None;
+        }
+
+        let mut message = this.buffer.split_to(LENGTH_PREFIX + length);
+        message.advance(LENGTH_PREFIX);
+
+        Some((this.decode)(&message))
+    }
+}
+
+impl<T, S, C> Stream for FramedDecoderStream<T, S, C>
+where
+    S: TryStream<Ok: Buf>,
+    C: Error + Copy + Send + Sync + 'static,
+{
+    type Item = Result<T, Report<C>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // multiple records could be in the buffer at the same time, so always try to drain it
+            // before polling the underlying stream.
+            if let Some(value) = self.as_mut().poll_item() {
+                return Poll::Ready(Some(value));
+            }
+
+            let mut this = self.as_mut().project();
+            // We use an option here to avoid repeated polling of the inner stream once it has
+            // returned `None`, as that would lead to potentially undefined behavior.
+            let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+                // Any remaining bytes are a partial record, which we discard.
+                return Poll::Ready(None);
+            };
+
+            match ready!(inner.try_poll_next(cx)) {
+                Some(Ok(buf)) => this.buffer.put(buf),
+                // The error type of the underlying stream is unknown, so it cannot be attached.
+                Some(Err(_error)) => return Poll::Ready(Some(Err(Report::new(*this.context)))),
+                None => this.inner.set(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::future::ready;
+    use std::io;
+
+    use bytes::{BufMut as _, Bytes, BytesMut};
+    use futures_util::{StreamExt as _, stream};
+
+    use super::{FramedCodec, RecordFormat};
+    use crate::{decode::Decoder as _, encode::Encoder as _};
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct Record {
+        key: String,
+    }
+
+    fn record(key: &str) -> Record {
+        Record {
+            key: key.to_owned(),
+        }
+    }
+
+    async fn encode<F: RecordFormat>(records: Vec<Record>) -> Bytes {
+        let mut buffer = BytesMut::new();
+        let mut encoder = FramedCodec::<F>::new().encode(stream::iter(records));
+
+        while let Some(chunk) = encoder.next().await {
+            buffer.put(chunk.expect("should be able to encode record"));
+        }
+
+        buffer.freeze()
+    }
+
+    #[expect(
+        clippy::big_endian_bytes,
+        reason = "numbers are always encoded in big-endian in our encoding scheme"
+    )]
+    async fn encode_prefixes_length<F: RecordFormat>() {
+        let input = stream::once(ready(record("value")));
+        let encoded = FramedCodec::<F>::new()
+            .encode(input)
+            .next()
+            .await
+            .expect("should have a value")
+            .expect("should be Ok");
+
+        let length = u32::from_be_bytes(encoded[..4].try_into().expect("should have a prefix"));
+        assert_eq!(length as usize, encoded.len() - 4);
+    }
+
+    async fn roundtrip_multiple_records_in_single_chunk<F: RecordFormat>() {
+        let bytes = encode::<F>(vec![record("value1"), record("value2")]).await;
+
+        let input = stream::once(ready(Result::<_, io::Error>::Ok(bytes)));
+        let decoded: Vec<_> = FramedCodec::<F>::new()
+            .decode::<Record, _>(input)
+            .map(|item| item.expect("should be Ok"))
+            .collect()
+            .await;
+
+        assert_eq!(decoded, [record("value1"), record("value2")]);
+    }
+
+    async fn roundtrip_record_split_across_chunks<F: RecordFormat>() {
+        let bytes = encode::<F>(vec![record("value1"), record("value2")]).await;
+
+        // feed the decoder a single byte at a time, which splits both the prefix and the record
+        let input = stream::iter(
+            (0..bytes.len()).map(|index| Result::<_, io::Error>::Ok(bytes.slice(index..=index))),
+        );
+        let decoded: Vec<_> = FramedCodec::<F>::new()
+            .decode::<Record, _>(input)
+            .map(|item| item.expect("should be Ok"))
+            .collect()
+            .await;
+
+        assert_eq!(decoded, [record("value1"), record("value2")]);
+    }
+
+    async fn decode_stream_ends_with_partial_record<F: RecordFormat>() {
+        let bytes = encode::<F>(vec![record("value1"), record("value2")]).await;
+
+        let input = stream::once(ready(Result::<_, io::Error>::Ok(
+            bytes.slice(..bytes.len() - 1),
+        )));
+        let mut decoder = FramedCodec::<F>::new().decode::<Record, _>(input);
+
+        assert_eq!(
+            decoder
+                .next()
+                .await
+                .expect("should have a value")
+                .expect("should be Ok"),
+            record("value1")
+        );
+        assert!(decoder.next().await.is_none());
+    }
+
+    async fn decode_error_in_underlying_stream<F: RecordFormat>() {
+        let bytes = encode::<F>(vec![record("value1")]).await;
+
+        let input = stream::iter([Ok(bytes), Err(io::Error::other("o no!"))]);
+        let mut decoder = FramedCodec::<F>::new().decode::<Record, _>(input);
+
+        assert_eq!(
+            decoder
+                .next()
+                .await
+                .expect("should have a value")
+                .expect("should be Ok"),
+            record("value1")
+        );
+
+        let error = decoder
+            .next()
+            .await
+            .expect("should have a value")
+            .expect_err("should be an error");
+        assert_eq!(error.to_string(), F::DECODE_ERROR.to_string());
+
+        assert!(decoder.next().await.is_none());
+    }
+
+    /// Decodes a single record, which consists of a byte that is invalid in the format.
+    async fn decode_invalid_record<F: RecordFormat>(invalid: u8) {
+        let input = stream::once(ready(Result::<_, io::Error>::Ok(Bytes::from(vec![
+            0x00, 0x00, 0x00, 0x01, invalid,
+        ]))));
+        let mut decoder = FramedCodec::<F>::new().decode::<Record, _>(input);
+
+        let _report = decoder
+            .next()
+            .await
+            .expect("should have a value")
+            .expect_err("should be an error");
+        assert!(decoder.next().await.is_none());
+    }
+
+    macro_rules! suite {
+        ($name:ident : $format:ty,invalid = $invalid:literal) => {
+            mod $name {
+                #[tokio::test]
+                async fn encode_prefixes_length() {
+                    super::encode_prefixes_length::<$format>().await;
+                }
+
+                #[tokio::test]
+                async fn roundtrip_multiple_records_in_single_chunk() {
+                    super::roundtrip_multiple_records_in_single_chunk::<$format>().await;
+                }
+
+                #[tokio::test]
+                async fn roundtrip_record_split_across_chunks() {
+                    super::roundtrip_record_split_across_chunks::<$format>().await;
+                }
+
+                #[tokio::test]
+                async fn decode_stream_ends_with_partial_record() {
+                    super::decode_stream_ends_with_partial_record::<$format>().await;
+                }
+
+                #[tokio::test]
+                async fn decode_error_in_underlying_stream() {
+                    super::decode_error_in_underlying_stream::<$format>().await;
+                }
+
+                #[tokio::test]
+                async fn decode_invalid_record() {
+                    super::decode_invalid_record::<$format>($invalid).await;
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "cbor")]
+    suite!(cbor: crate::cbor::Cbor, invalid = 0xFF);
+    #[cfg(feature = "msgpack")]
+    suite!(msgpack: crate::msgpack::MessagePack, invalid = 0xC1);
+}
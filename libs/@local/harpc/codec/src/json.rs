@@ -7,9 +7,10 @@ use bytes::{Buf, BufMut as _, Bytes, BytesMut};
 use error_stack::{Report, ResultExt as _};
 use futures_core::{Stream, TryStream};
 use futures_util::stream::{self, StreamExt as _};
+use harpc_types::encoding::Encoding;
 use serde::de::DeserializeOwned;
 
-use crate::{decode::Decoder, encode::Encoder};
+use crate::{NamedCodec, decode::Decoder, encode::Encoder};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, thiserror::Error)]
 pub enum JsonError {
@@ -27,6 +28,11 @@ impl JsonCodec {
     const SEPARATOR: u8 = b'\x1E';
 }
 
+impl NamedCodec for JsonCodec {
+    const ENCODING: Encoding = Encoding::JSON;
+    const NAME: &'static str = "json";
+}
+
 impl Encoder for JsonCodec {
     type Buf = Bytes;
     type Error = Report<JsonError>;
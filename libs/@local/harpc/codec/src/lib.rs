@@ -10,19 +10,75 @@
 
 use bytes::Buf;
 use futures_core::{Stream, TryStream};
+use harpc_types::encoding::Encoding;
 
 use crate::{decode::Decoder, encode::Encoder};
 
+#[cfg(all(feature = "json", feature = "cbor", feature = "msgpack"))]
+pub mod any;
+#[cfg(feature = "cbor")]
+pub mod cbor;
 pub mod decode;
 pub mod encode;
 pub mod error;
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub mod framed;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
 
 pub trait Codec: Encoder + Decoder {}
 
 impl<T> Codec for T where T: Encoder + Decoder {}
 
+/// A codec which is identified by a well-known name and encoding.
+pub trait NamedCodec {
+    const NAME: &'static str;
+
+    /// The encoding of the payloads produced by this codec, which is transmitted with every
+    /// request.
+    const ENCODING: Encoding;
+}
+
+/// A codec that is selected by the encoding of a request.
+///
+/// A client transmits the [`Encoding`] of its codec with every request, a server uses the codec
+/// returned by [`Self::negotiate`] to decode the request and encode the response. Every
+/// [`NamedCodec`] supports exactly its own encoding, use [`AnyCodec`] to serve clients using
+/// different codecs.
+///
+/// [`AnyCodec`]: crate::any::AnyCodec
+pub trait NegotiateCodec: Sized {
+    /// The encoding of the payloads produced by this codec.
+    fn encoding(&self) -> Encoding;
+
+    /// All encodings this codec is able to negotiate.
+    fn encodings(&self) -> &'static [Encoding];
+
+    /// Returns the codec to use for a payload in the given encoding.
+    ///
+    /// Returns `None` if the encoding is not supported.
+    fn negotiate(&self, encoding: Encoding) -> Option<Self>;
+}
+
+impl<C> NegotiateCodec for C
+where
+    C: NamedCodec + Clone,
+{
+    fn encoding(&self) -> Encoding {
+        C::ENCODING
+    }
+
+    fn encodings(&self) -> &'static [Encoding] {
+        const { &[C::ENCODING] }
+    }
+
+    fn negotiate(&self, encoding: Encoding) -> Option<Self> {
+        (encoding == C::ENCODING).then(|| self.clone())
+    }
+}
+
 /// A codec that separates encoding and decoding into two separate components.
 ///
 /// This struct allows you to combine any encoder and decoder into a single codec
@@ -0,0 +1,53 @@
+use bytes::{BytesMut, buf::Writer};
+use error_stack::{Report, ResultExt as _};
+use harpc_types::encoding::Encoding;
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::framed::{FramedCodec, FramedDecoderStream, RecordFormat};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, thiserror::Error)]
+pub enum MessagePackError {
+    #[error("unable to encode MessagePack value")]
+    Encode,
+    #[error("unable to decode MessagePack value")]
+    Decode,
+}
+
+/// Encodes every record as a [MessagePack] value.
+///
+/// Structs are encoded as maps instead of arrays, so that field names are preserved on the wire and
+/// `#[serde(flatten)]` as well as `#[serde(skip_serializing_if)]` are supported.
+///
+/// [MessagePack]: https://msgpack.org
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MessagePack;
+
+impl RecordFormat for MessagePack {
+    type Error = MessagePackError;
+
+    const DECODE_ERROR: Self::Error = MessagePackError::Decode;
+    const ENCODE_ERROR: Self::Error = MessagePackError::Encode;
+    const ENCODING: Encoding = Encoding::MESSAGE_PACK;
+    const NAME: &'static str = "msgpack";
+
+    fn encode_record<T>(value: &T, writer: &mut Writer<BytesMut>) -> Result<(), Report<Self::Error>>
+    where
+        T: Serialize,
+    {
+        rmp_serde::encode::write_named(writer, value).change_context(MessagePackError::Encode)
+    }
+
+    fn decode_record<T>(record: &[u8]) -> Result<T, Report<Self::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        rmp_serde::from_slice(record).change_context(MessagePackError::Decode)
+    }
+}
+
+/// Codec encoding every record as a length-delimited [MessagePack] value.
+///
+/// [MessagePack]: https://msgpack.org
+pub type MessagePackCodec = FramedCodec<MessagePack>;
+
+pub type MessagePackDecoderStream<T, S> = FramedDecoderStream<T, S, MessagePackError>;
@@ -25,7 +25,7 @@ use tokio_util::{
 
 use self::collection::{TransactionCollection, TransactionState, TransactionStorage};
 pub use self::stream::ResponseStream;
-use super::{config::SessionConfig, transaction::TransactionTask};
+use super::{config::SessionConfig, options::CallOptions, transaction::TransactionTask};
 use crate::session::{error::ConnectionPartiallyClosedError, gc::ConnectionGarbageCollectorTask};
 
 /// Delegate requests to the respective transaction
@@ -338,6 +338,23 @@ impl Connection {
         subsystem: SubsystemDescriptor,
         procedure: ProcedureDescriptor,
         payload: impl Stream<Item = Bytes> + Send + 'static,
+    ) -> Result<ResponseStream, Report<ConnectionPartiallyClosedError>> {
+        self.call_with_options(subsystem, procedure, CallOptions::default(), payload)
+            .await
+    }
+
+    /// Call a service procedure with the given [`CallOptions`]
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if the connection is unhealthy, meaning that the underlying
+    /// connection is currently in its process of being closed.
+    pub async fn call_with_options(
+        &self,
+        subsystem: SubsystemDescriptor,
+        procedure: ProcedureDescriptor,
+        options: CallOptions,
+        payload: impl Stream<Item = Bytes> + Send + 'static,
     ) -> Result<ResponseStream, Report<ConnectionPartiallyClosedError>> {
         // While not strictly necessary (as the transaction will immediately terminate if the
         // underlying connection is closed) and the `ResponseStream` will return `None` it is a good
@@ -354,6 +371,12 @@ impl Connection {
 
         let (stream_tx, stream_rx) = mpsc::channel(1);
 
+        // a child token ensures that cancelling the `ResponseStream` only cancels this call
+        let abort = options
+            .cancel
+            .as_ref()
+            .map_or_else(CancellationToken::new, CancellationToken::child_token);
+
         // Important: the resulting stream won't be directly notified if the payload stream couldn't
         // be sent, completely (which can only happen if the `Sink` has been shutdown). This is
         // intended, as we know that `Sink` and `Stream` are both bound to each other, meaning that
@@ -364,10 +387,12 @@ impl Connection {
             permit,
             subsystem,
             procedure,
+            options,
             response_rx,
             response_tx: stream_tx,
             request_rx: payload,
             request_tx: self.tx.clone(),
+            abort: abort.clone(),
         };
 
         task.spawn(&self.tasks);
@@ -379,6 +404,6 @@ impl Connection {
         // terminated once the payload stream is exhausted.
         // This means we can allow scenarios in which the response does not matter and we only want
         // to send a request.
-        Ok(ResponseStream::new(stream_rx))
+        Ok(ResponseStream::new(stream_rx, abort))
     }
 }
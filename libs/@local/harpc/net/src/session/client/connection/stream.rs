@@ -5,23 +5,37 @@ use core::{
 
 use futures::{Stream, prelude::stream::FusedStream};
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
 use crate::session::client::{ErrorStream, ValueStream};
 
 #[derive(Debug)]
 pub struct ResponseStream {
     inner: mpsc::Receiver<Result<ValueStream, ErrorStream>>,
+    cancel: CancellationToken,
 
     terminated: bool,
 }
 
 impl ResponseStream {
-    pub(crate) const fn new(inner: mpsc::Receiver<Result<ValueStream, ErrorStream>>) -> Self {
+    pub(crate) const fn new(
+        inner: mpsc::Receiver<Result<ValueStream, ErrorStream>>,
+        cancel: CancellationToken,
+    ) -> Self {
         Self {
             inner,
+            cancel,
             terminated: false,
         }
     }
+
+    /// Cancel the call.
+    ///
+    /// Notifies the server that the response is no longer needed, after which no further
+    /// responses are delivered. Has no effect if the response has already been received.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
 }
 
 impl Stream for ResponseStream {
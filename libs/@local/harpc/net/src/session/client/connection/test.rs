@@ -602,6 +602,7 @@ impl EchoSystem {
                     subsystem,
                     procedure,
                     payload,
+                    ..
                 }) => {
                     let mut bytes = BytesMut::new();
 
@@ -621,6 +622,8 @@ impl EchoSystem {
                 RequestBody::Frame(RequestFrame { payload }) => {
                     ResponseBody::Frame(ResponseFrame { payload })
                 }
+                // the echo server does not track transactions, there is nothing to cancel
+                RequestBody::Cancel(_) => continue,
             };
 
             let mut flags = ResponseFlags::empty();
@@ -1,5 +1,6 @@
 mod config;
 mod connection;
+mod options;
 mod transaction;
 
 use error_stack::{Report, ResultExt as _};
@@ -10,6 +11,7 @@ use self::connection::ConnectionParts;
 pub use self::{
     config::SessionConfig,
     connection::{Connection, ResponseStream},
+    options::CallOptions,
     transaction::stream::{ErrorStream, TransactionStream, ValueStream},
 };
 use super::error::SessionError;
@@ -0,0 +1,51 @@
+use core::time::Duration;
+
+use harpc_types::{compression::Compression, encoding::Encoding};
+use tokio_util::sync::CancellationToken;
+
+/// Options for a single call made through a [`Connection`].
+///
+/// [`Connection`]: super::Connection
+#[derive(Debug, Clone, Default)]
+pub struct CallOptions {
+    /// Compression algorithm the request payload has been compressed with.
+    ///
+    /// The session layer does not compress the payload itself, it only signals the algorithm to
+    /// the server.
+    ///
+    /// **Default:** [`Compression::None`]
+    pub compression: Compression,
+
+    /// Compression algorithm the server should use to compress a successful response.
+    ///
+    /// Error responses are never compressed.
+    ///
+    /// **Default:** [`Compression::None`]
+    pub accept: Compression,
+
+    /// Encoding of the request payload, the server encodes the response in the same encoding.
+    ///
+    /// The session layer does not encode the payload itself, it only signals the encoding to the
+    /// server.
+    ///
+    /// **Default:** [`Encoding::JSON`]
+    pub encoding: Encoding,
+
+    /// Time the server has to complete the call.
+    ///
+    /// The deadline is transmitted with millisecond precision and enforced by the server, which
+    /// cancels the transaction and responds with an error once it has been exceeded.
+    ///
+    /// **Default:** `None`
+    pub deadline: Option<Duration>,
+
+    /// Token used to cancel the call.
+    ///
+    /// Once cancelled, the server is notified and no further response is delivered. The call can
+    /// also be cancelled through [`ResponseStream::cancel`].
+    ///
+    /// **Default:** `None`
+    ///
+    /// [`ResponseStream::cancel`]: super::ResponseStream::cancel
+    pub cancel: Option<CancellationToken>,
+}
@@ -12,7 +12,7 @@ use harpc_types::{
 };
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
-    request::{Request, id::RequestId},
+    request::{Request, deadline::RequestDeadline, id::RequestId},
     response::{
         Response, begin::ResponseBegin, body::ResponseBody, flags::ResponseFlag,
         frame::ResponseFrame,
@@ -22,9 +22,9 @@ use tokio::{pin, select, sync::mpsc};
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
 
 use self::stream::{ErrorStream, StreamState, ValueStream};
-use super::config::SessionConfig;
+use super::{config::SessionConfig, options::CallOptions};
 use crate::{
-    session::writer::{RequestContext, RequestWriter, WriterOptions},
+    session::writer::{RequestContext, RequestWriter, WriterOptions, new_cancel_request},
     stream::TerminatedChannelStream,
 };
 
@@ -44,6 +44,11 @@ pub(crate) struct TransactionReceiveTask<P> {
     rx: tachyonix::Receiver<Response>,
     tx: mpsc::Sender<Result<ValueStream, ErrorStream>>,
 
+    // Cancelled by the consumer once it is no longer interested in the response, the server is
+    // notified through `request_tx`.
+    abort: CancellationToken,
+    request_tx: mpsc::Sender<Request>,
+
     permit: Arc<P>,
 }
 
@@ -99,7 +104,24 @@ where
             // We cannot early break if tx is closed, because we might still deliver some responses
             let response = select! {
                 response = self.rx.recv() => response,
-                () = cancel.cancelled() => break
+                () = cancel.cancelled() => break,
+                () = self.abort.cancelled() => {
+                    tracing::debug!("transaction has been cancelled by the consumer");
+
+                    // stop sending any further request packets, before notifying the server
+                    cancel.cancel();
+
+                    if self
+                        .request_tx
+                        .send(new_cancel_request(self.permit.id()))
+                        .await
+                        .is_err()
+                    {
+                        tracing::info!("connection has been closed, unable to send cancellation");
+                    }
+
+                    break;
+                }
             };
 
             let Ok(response) = response else {
@@ -185,6 +207,7 @@ pub(crate) struct TransactionSendTask<S, P> {
 
     subsystem: SubsystemDescriptor,
     procedure: ProcedureDescriptor,
+    options: CallOptions,
 
     rx: S,
     tx: mpsc::Sender<Request>,
@@ -211,6 +234,14 @@ where
                 id: self.permit.id(),
                 subsystem: self.subsystem,
                 procedure: self.procedure,
+                compression: self.options.compression,
+                accept: self.options.accept,
+                encoding: self.options.encoding,
+                // a deadline that has already passed is still sent, so that the server is able to
+                // reject the request
+                deadline: self.options.deadline.map(|deadline| {
+                    RequestDeadline::from_duration(deadline).unwrap_or(RequestDeadline::MIN)
+                }),
             },
             &self.tx,
         );
@@ -252,12 +283,15 @@ pub(crate) struct TransactionTask<S, P> {
 
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
+    pub options: CallOptions,
 
     pub response_rx: tachyonix::Receiver<Response>,
     pub response_tx: mpsc::Sender<Result<ValueStream, ErrorStream>>,
 
     pub request_rx: S,
     pub request_tx: mpsc::Sender<Request>,
+
+    pub abort: CancellationToken,
 }
 
 impl<S, P> TransactionTask<S, P>
@@ -273,6 +307,8 @@ where
                 config: self.config,
                 rx: self.response_rx,
                 tx: self.response_tx,
+                abort: self.abort,
+                request_tx: self.request_tx.clone(),
                 permit: Arc::clone(&permit),
             }
             .run(),
@@ -284,6 +320,7 @@ where
 
                 subsystem: self.subsystem,
                 procedure: self.procedure,
+                options: self.options,
 
                 rx: self.request_rx,
                 tx: self.request_tx,
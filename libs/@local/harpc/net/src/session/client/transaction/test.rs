@@ -3,14 +3,14 @@ use core::{assert_matches::assert_matches, num::NonZero, time::Duration};
 
 use bytes::{Bytes, BytesMut};
 use futures::StreamExt as _;
-use harpc_types::{error_code::ErrorCode, response_kind::ResponseKind};
+use harpc_types::{encoding::Encoding, error_code::ErrorCode, response_kind::ResponseKind};
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
     payload::Payload,
     protocol::{Protocol, ProtocolVersion},
     request::{
-        Request, begin::RequestBegin, body::RequestBody, flags::RequestFlag, frame::RequestFrame,
-        id::RequestId,
+        Request, begin::RequestBegin, body::RequestBody, cancel::RequestCancel,
+        deadline::RequestDeadline, flags::RequestFlag, frame::RequestFrame, id::RequestId,
     },
     response::{
         Response,
@@ -30,7 +30,9 @@ use super::{
     ClientTransactionPermit, ErrorStream, TransactionReceiveTask, TransactionSendTask, ValueStream,
 };
 use crate::session::{
-    client::{TransactionStream as _, config::SessionConfig, transaction::StreamState},
+    client::{
+        CallOptions, TransactionStream as _, config::SessionConfig, transaction::StreamState,
+    },
     test::Descriptor,
 };
 
@@ -73,6 +75,9 @@ fn setup_recv_mapped<T>(
         config,
         rx: response_rx,
         tx: stream_tx,
+        abort: CancellationToken::new(),
+        // never used, as the transaction is never aborted
+        request_tx: mpsc::channel(1).0,
         permit: Arc::new(permit),
     };
 
@@ -722,6 +727,45 @@ async fn receive_cancel() {
         .expect("should not panic");
 }
 
+#[tokio::test]
+async fn receive_abort() {
+    let (_response_tx, response_rx) = tachyonix::channel(8);
+    let (stream_tx, _stream_rx) = mpsc::channel(8);
+    let (request_tx, mut request_rx) = mpsc::channel(8);
+
+    let abort = CancellationToken::new();
+    let cancel = CancellationToken::new();
+
+    let task = TransactionReceiveTask {
+        config: SessionConfig::default(),
+        rx: response_rx,
+        tx: stream_tx,
+        abort: abort.clone(),
+        request_tx,
+        permit: Arc::new(StaticTransactionPermit {
+            id: mock_request_id(0x00),
+            cancel: cancel.clone(),
+        }),
+    };
+
+    let handle = tokio::spawn(task.run());
+
+    abort.cancel();
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    // the send task is stopped through the permit
+    assert!(cancel.is_cancelled());
+
+    // the server is notified about the cancellation
+    let request = request_rx.recv().await.expect("able to receive request");
+    assert_eq!(request.header.request_id, mock_request_id(0x00));
+    assert_matches!(request.body, RequestBody::Cancel(RequestCancel));
+}
+
 fn setup_send_mapped<T>(
     config: SessionConfig,
     descriptor: Descriptor,
@@ -746,6 +790,7 @@ fn setup_send_mapped<T>(
         config,
         subsystem: descriptor.subsystem,
         procedure: descriptor.procedure,
+        options: CallOptions::default(),
         rx: ReceiverStream::new(bytes_rx),
         tx: request_tx,
         permit: Arc::new(permit),
@@ -791,6 +836,8 @@ async fn send_drop_sender() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            deadline: None,
+            encoding: Encoding::JSON,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -798,6 +845,92 @@ async fn send_drop_sender() {
     );
 }
 
+#[tokio::test]
+async fn send_deadline() {
+    let descriptor = Descriptor::default();
+
+    let (bytes_tx, bytes_rx) = mpsc::channel(8);
+    let (request_tx, mut request_rx) = mpsc::channel(8);
+
+    let task = TransactionSendTask {
+        config: SessionConfig::default(),
+        subsystem: descriptor.subsystem,
+        procedure: descriptor.procedure,
+        options: CallOptions {
+            deadline: Some(Duration::from_millis(1500)),
+            ..CallOptions::default()
+        },
+        rx: ReceiverStream::new(bytes_rx),
+        tx: request_tx,
+        permit: Arc::new(StaticTransactionPermit {
+            id: mock_request_id(0x00),
+            cancel: CancellationToken::new(),
+        }),
+    };
+
+    let handle = tokio::spawn(task.run());
+
+    drop(bytes_tx);
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    let request = request_rx.recv().await.expect("able to receive request");
+
+    assert_matches!(
+        request.body,
+        RequestBody::Begin(RequestBegin {
+            deadline: Some(deadline),
+            ..
+        }) if deadline == RequestDeadline::new(NonZero::new(1500).expect("infallible"))
+    );
+}
+
+#[tokio::test]
+async fn send_encoding() {
+    let descriptor = Descriptor::default();
+
+    let (bytes_tx, bytes_rx) = mpsc::channel(8);
+    let (request_tx, mut request_rx) = mpsc::channel(8);
+
+    let task = TransactionSendTask {
+        config: SessionConfig::default(),
+        subsystem: descriptor.subsystem,
+        procedure: descriptor.procedure,
+        options: CallOptions {
+            encoding: Encoding::CBOR,
+            ..CallOptions::default()
+        },
+        rx: ReceiverStream::new(bytes_rx),
+        tx: request_tx,
+        permit: Arc::new(StaticTransactionPermit {
+            id: mock_request_id(0x00),
+            cancel: CancellationToken::new(),
+        }),
+    };
+
+    let handle = tokio::spawn(task.run());
+
+    drop(bytes_tx);
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    let request = request_rx.recv().await.expect("able to receive request");
+
+    assert_matches!(
+        request.body,
+        RequestBody::Begin(RequestBegin {
+            encoding: Encoding::CBOR,
+            ..
+        })
+    );
+}
+
 #[tokio::test]
 async fn send_drop_receiver_no_delay() {
     let (tx, rx, handle) = setup_send(
@@ -880,6 +1013,8 @@ async fn send_no_delay() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            deadline: None,
+            encoding: Encoding::JSON,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -923,6 +1058,8 @@ async fn send_no_delay_flush_empty() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            deadline: None,
+            encoding: Encoding::JSON,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -969,6 +1106,8 @@ async fn send_delay() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            deadline: None,
+            encoding: Encoding::JSON,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -1021,6 +1160,8 @@ async fn send_delay_flush_partial() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            deadline: None,
+            encoding: Encoding::JSON,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -1054,6 +1195,8 @@ async fn send_delay_flush_empty() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            deadline: None,
+            encoding: Encoding::JSON,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
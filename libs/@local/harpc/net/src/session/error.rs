@@ -55,6 +55,28 @@ impl Error for TransactionLaggingError {
     }
 }
 
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    derive_more::Display,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[display("transaction has been cancelled, because it did not complete before its deadline")]
+pub struct TransactionDeadlineExceededError;
+
+impl Error for TransactionDeadlineExceededError {
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {
+        request.provide_value(ErrorCode::TRANSACTION_DEADLINE_EXCEEDED);
+    }
+}
+
 #[derive(
     Debug,
     Copy,
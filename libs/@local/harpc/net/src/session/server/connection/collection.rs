@@ -155,7 +155,7 @@ impl TransactionCollection {
         }
     }
 
-    fn cancel(&self, id: RequestId) {
+    pub(crate) fn cancel(&self, id: RequestId) {
         let guard = Guard::new();
         let Some(state) = self.storage.peek(&id, &guard) else {
             return;
@@ -9,7 +9,7 @@ use std::io;
 use error_stack::Report;
 use futures::{FutureExt as _, Sink, Stream, StreamExt as _, stream};
 use harpc_codec::error::NetworkError;
-use harpc_types::response_kind::ResponseKind;
+use harpc_types::{compression::Compression, response_kind::ResponseKind};
 use harpc_wire_protocol::{
     request::{Request, body::RequestBody, id::RequestId},
     response::Response,
@@ -90,6 +90,7 @@ impl ConnectionTask {
             ResponseContext {
                 id,
                 kind: ResponseKind::Err(code),
+                compression: Compression::None,
             },
             tx,
         );
@@ -168,6 +169,7 @@ impl ConnectionTask {
                 };
 
                 let (transaction, task) = Transaction::from_request(
+                    request.header.flags,
                     begin,
                     TransactionParts {
                         peer: self.peer,
@@ -194,6 +196,13 @@ impl ConnectionTask {
                     self.respond_error(request_id, &error, &tx).await;
                 }
             }
+            RequestBody::Cancel(_) => {
+                tracing::debug!(?request_id, "client cancelled transaction");
+
+                // Cancelling stops the transaction without a response, the client is no longer
+                // interested in the result.
+                self.transactions.cancel(request_id);
+            }
         }
 
         // We do not need to check for `EndOfRequest` here and forcefully close the channel, as the
@@ -10,6 +10,7 @@ use bytes::{Bytes, BytesMut};
 use error_stack::{Report, ResultExt as _};
 use futures::{SinkExt as _, Stream, StreamExt as _};
 use harpc_types::{
+    encoding::Encoding,
     procedure::{ProcedureDescriptor, ProcedureId},
     subsystem::{SubsystemDescriptor, SubsystemId},
     version::Version,
@@ -22,6 +23,7 @@ use harpc_wire_protocol::{
         Request,
         begin::RequestBegin,
         body::RequestBody,
+        cancel::RequestCancel,
         flags::{RequestFlag, RequestFlags},
         frame::RequestFrame,
         header::RequestHeader,
@@ -75,6 +77,8 @@ pub(crate) fn make_request_begin(
             procedure: ProcedureDescriptor {
                 id: ProcedureId::new(0x01),
             },
+            deadline: None,
+            encoding: Encoding::JSON,
             payload: Payload::new(payload),
         }),
     }
@@ -92,6 +96,13 @@ pub(crate) fn make_request_frame(
     }
 }
 
+fn make_request_cancel() -> Request {
+    Request {
+        header: make_request_header(RequestFlags::EMPTY),
+        body: RequestBody::Cancel(RequestCancel),
+    }
+}
+
 async fn session_map<T, U>(
     config: SessionConfig,
     address: Multiaddr,
@@ -335,6 +346,39 @@ async fn single_session() {
     assert_response(stream, b"hello world").await;
 }
 
+#[tokio::test]
+async fn client_cancel() {
+    let address = memory_address();
+
+    let (mut server, _server_guard) = session(SessionConfig::default(), address.clone()).await;
+    let (client, _client_guard) = layer();
+
+    let OutgoingConnection { mut sink, .. } = connect(&client, address).await;
+
+    sink.send(make_request_begin(
+        RequestFlag::BeginOfRequest,
+        b"hello" as &[_],
+    ))
+    .await
+    .expect("should be able to send");
+
+    let transaction = tokio::time::timeout(Duration::from_secs(1), server.next())
+        .await
+        .expect("should receive transaction within timeout")
+        .expect("should receive transaction");
+
+    let cancel = transaction.cancellation_token();
+    assert!(!cancel.is_cancelled());
+
+    sink.send(make_request_cancel())
+        .await
+        .expect("should be able to send");
+
+    tokio::time::timeout(Duration::from_secs(1), cancel.cancelled())
+        .await
+        .expect("transaction should be cancelled within timeout");
+}
+
 #[tokio::test]
 async fn client_disconnect() {
     // if a client spuriously disconnects, do we properly disconnect as well and can still start
@@ -11,22 +11,35 @@ use bytes::Bytes;
 use futures::{Sink, Stream, StreamExt as _, stream::FusedStream};
 use harpc_codec::error::NetworkError;
 use harpc_types::{
-    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+    compression::Compression, encoding::Encoding, procedure::ProcedureDescriptor,
+    response_kind::ResponseKind, subsystem::SubsystemDescriptor,
 };
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
-    request::{Request, begin::RequestBegin, flags::RequestFlag, id::RequestId},
+    request::{
+        Request,
+        begin::RequestBegin,
+        flags::{RequestFlag, RequestFlags},
+        id::RequestId,
+    },
     response::Response,
 };
 use libp2p::PeerId;
-use tokio::{select, sync::mpsc};
+use tokio::{
+    pin, select,
+    sync::mpsc,
+    time::{Instant, sleep_until},
+};
 use tokio_util::{
     sync::{CancellationToken, PollSendError, PollSender},
     task::TaskTracker,
 };
 
 use super::{SessionConfig, connection::collection::TransactionPermit, session_id::SessionId};
-use crate::session::writer::{ResponseContext, ResponseWriter, WriterOptions};
+use crate::session::{
+    error::TransactionDeadlineExceededError,
+    writer::{ResponseContext, ResponseWriter, WriterOptions},
+};
 
 pub(crate) trait ServerTransactionPermit: Send + Sync + 'static {
     fn id(&self) -> RequestId;
@@ -36,6 +49,11 @@ pub(crate) trait ServerTransactionPermit: Send + Sync + 'static {
 struct TransactionSendDelegateTask<P> {
     config: SessionConfig,
 
+    // Compression algorithm of successful responses, errors are never compressed.
+    compression: Compression,
+    // Point in time at which the transaction is cancelled, if it hasn't completed yet.
+    deadline: Option<Instant>,
+
     // TODO: consider switching to `tachyonix` crate for better performance (not yet tested)
     // as well as more predictable buffering behavior. `PollSender` is prone to just buffer
     // everything before sending, which might not be the best idea in this scenario.
@@ -56,6 +74,10 @@ where
     async fn run(mut self) {
         let cancel = self.permit.cancellation_token();
 
+        // `select!` requires the future to be present, even if the branch is disabled
+        let deadline = sleep_until(self.deadline.unwrap_or_else(Instant::now));
+        pin!(deadline);
+
         // we cannot simply forward here, because we want to be able to send the end of request and
         // buffer the response into the least amount of packages possible
 
@@ -66,6 +88,7 @@ where
             ResponseContext {
                 id: self.permit.id(),
                 kind: ResponseKind::Ok,
+                compression: self.compression,
             },
             &self.tx,
         );
@@ -80,6 +103,32 @@ where
                 () = cancel.cancelled() => {
                     break;
                 },
+                () = &mut deadline, if self.deadline.is_some() => {
+                    tracing::info!("transaction deadline exceeded, cancelling transaction");
+
+                    let error = NetworkError::capture_error(&TransactionDeadlineExceededError);
+                    let code = error.code();
+
+                    let mut writer = ResponseWriter::new(
+                        WriterOptions { no_delay: false },
+                        ResponseContext {
+                            id: self.permit.id(),
+                            kind: ResponseKind::Err(code),
+                            compression: Compression::None,
+                        },
+                        &self.tx,
+                    );
+                    writer.push(error.into_bytes());
+
+                    if let Err(error) = writer.flush().await {
+                        tracing::warn!(?error, "connection has been prematurely closed");
+                    }
+
+                    // notify the handler, as well as the request stream, that the transaction is
+                    // no longer needed
+                    cancel.cancel();
+                    break;
+                }
             };
 
             let Some(bytes) = bytes else {
@@ -115,6 +164,7 @@ where
                         ResponseContext {
                             id: self.permit.id(),
                             kind: ResponseKind::Err(code),
+                            compression: Compression::None,
                         },
                         &self.tx,
                     );
@@ -132,6 +182,8 @@ where
 
 pub(crate) struct TransactionTask<P> {
     config: SessionConfig,
+    compression: Compression,
+    deadline: Option<Instant>,
 
     response_rx: mpsc::Receiver<Result<Bytes, NetworkError>>,
     response_tx: mpsc::Sender<Response>,
@@ -146,6 +198,8 @@ where
     pub(super) fn start(self, tasks: &TaskTracker) {
         let send = TransactionSendDelegateTask {
             config: self.config,
+            compression: self.compression,
+            deadline: self.deadline,
 
             rx: self.response_rx,
             tx: self.response_tx,
@@ -180,6 +234,12 @@ pub struct TransactionContext {
 
     subsystem: SubsystemDescriptor,
     procedure: ProcedureDescriptor,
+
+    compression: Compression,
+    accept: Compression,
+    encoding: Encoding,
+
+    deadline: Option<Instant>,
 }
 
 impl TransactionContext {
@@ -207,6 +267,38 @@ impl TransactionContext {
     pub const fn procedure(&self) -> ProcedureDescriptor {
         self.procedure
     }
+
+    /// Compression algorithm the request payload has been compressed with.
+    #[must_use]
+    pub const fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Compression algorithm a successful response payload is expected to be compressed with.
+    ///
+    /// The session layer signals the algorithm to the client, but does not compress the payload
+    /// itself.
+    #[must_use]
+    pub const fn response_compression(&self) -> Compression {
+        self.accept
+    }
+
+    /// Encoding of the request payload chosen by the client.
+    ///
+    /// The response is expected to be encoded in the same encoding.
+    #[must_use]
+    pub const fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Point in time at which the transaction is cancelled, if the client has set a deadline.
+    ///
+    /// Once exceeded the session layer responds with a [`TransactionDeadlineExceededError`] and
+    /// cancels the transaction.
+    #[must_use]
+    pub const fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
 }
 
 pub struct Transaction {
@@ -224,6 +316,7 @@ impl Transaction {
         reason = "TransactionPermit is used to track the transaction lifetime, false-positive"
     )]
     pub(crate) fn from_request(
+        flags: RequestFlags,
         body: &RequestBegin,
         TransactionParts {
             peer,
@@ -235,6 +328,9 @@ impl Transaction {
         }: TransactionParts<TransactionPermit>,
     ) -> (Self, TransactionTask<TransactionPermit>) {
         let permit = Arc::new(permit);
+        let deadline = body
+            .deadline
+            .map(|deadline| Instant::now() + deadline.as_duration());
 
         let (response_tx, response_rx) = mpsc::channel(
             config
@@ -249,6 +345,10 @@ impl Transaction {
                 session,
                 subsystem: body.subsystem,
                 procedure: body.procedure,
+                compression: flags.compression(),
+                accept: flags.accept(),
+                encoding: body.encoding,
+                deadline,
             },
 
             request: rx,
@@ -259,6 +359,8 @@ impl Transaction {
 
         let task = TransactionTask {
             config,
+            compression: flags.accept(),
+            deadline,
 
             response_rx,
             response_tx: tx,
@@ -274,6 +376,15 @@ impl Transaction {
         &self.context
     }
 
+    /// Token, which is cancelled once the transaction has been cancelled.
+    ///
+    /// A transaction is cancelled if the client requests its cancellation, the deadline of the
+    /// transaction has been exceeded, or the connection is closed.
+    #[must_use]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.permit.cancellation_token().child_token()
+    }
+
     pub fn into_parts(self) -> (TransactionContext, TransactionSink, TransactionStream) {
         let context = self.context;
 
@@ -9,6 +9,8 @@ use core::{
 use bytes::Bytes;
 use harpc_codec::error::NetworkError;
 use harpc_types::{
+    compression::Compression,
+    encoding::Encoding,
     error_code::ErrorCode,
     procedure::{ProcedureDescriptor, ProcedureId},
     response_kind::ResponseKind,
@@ -37,14 +39,17 @@ use harpc_wire_protocol::{
     },
     test_utils::mock_request_id,
 };
-use tokio::{sync::mpsc, task::JoinHandle};
+use tokio::{sync::mpsc, task::JoinHandle, time::Instant};
 use tokio_stream::StreamExt as _;
 use tokio_util::sync::CancellationToken;
 
 use super::{ServerTransactionPermit, TransactionStream};
-use crate::session::server::{
-    SessionConfig, connection::test::make_transaction_permit,
-    transaction::TransactionSendDelegateTask,
+use crate::session::{
+    error::TransactionDeadlineExceededError,
+    server::{
+        SessionConfig, connection::test::make_transaction_permit,
+        transaction::TransactionSendDelegateTask,
+    },
 };
 
 fn config_delay() -> SessionConfig {
@@ -82,6 +87,17 @@ fn setup_send(
     mpsc::Sender<Result<Bytes, NetworkError>>,
     mpsc::Receiver<Response>,
     JoinHandle<()>,
+) {
+    setup_send_compressed(no_delay, Compression::None)
+}
+
+fn setup_send_compressed(
+    no_delay: bool,
+    compression: Compression,
+) -> (
+    mpsc::Sender<Result<Bytes, NetworkError>>,
+    mpsc::Receiver<Response>,
+    JoinHandle<()>,
 ) {
     // we choose 8 here, so that we can buffer all replies easily and not spawn an extra task
     let (bytes_tx, bytes_rx) = mpsc::channel(8);
@@ -93,6 +109,8 @@ fn setup_send(
         } else {
             config_delay()
         },
+        compression,
+        deadline: None,
         rx: bytes_rx,
         tx: response_tx,
         permit: Arc::new(StaticTransactionPermit {
@@ -589,6 +607,62 @@ async fn send_delay_error_delayed() {
     );
 }
 
+#[tokio::test]
+async fn send_delay_error_compressed() {
+    let (bytes_tx, mut response_rx, handle) = setup_send_compressed(false, Compression::Zstd);
+
+    // successful responses carry the compression flag, errors are never compressed
+    let payload_ok = Bytes::from(vec![0; Payload::MAX_SIZE + 8]);
+
+    bytes_tx
+        .send(Ok(payload_ok.clone()))
+        .await
+        .expect("should not be closed");
+
+    let code = ErrorCode::new(NonZero::new(0xFF_FF).expect("infallible"));
+
+    let error = NetworkError::capture_error(&ExampleError {
+        message: Cow::Borrowed("compressed error"),
+        code,
+    });
+
+    let payload_err = error.bytes().clone();
+
+    bytes_tx
+        .send(Err(error))
+        .await
+        .expect("should not be closed");
+
+    drop(bytes_tx);
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    let mut responses = Vec::with_capacity(4);
+    let available = response_rx.recv_many(&mut responses, 4).await;
+    assert_eq!(available, 2);
+
+    assert_begin(
+        &responses[0],
+        ExpectedBegin {
+            flags: ResponseFlags::EMPTY.with_compression(Compression::Zstd),
+            kind: ResponseKind::Ok,
+            payload: &payload_ok[..Payload::MAX_SIZE],
+        },
+    );
+
+    assert_begin(
+        &responses[1],
+        ExpectedBegin {
+            flags: ResponseFlags::from(ResponseFlag::EndOfResponse),
+            kind: ResponseKind::Err(code),
+            payload: &payload_err,
+        },
+    );
+}
+
 #[tokio::test]
 async fn send_delay_error_multiple() {
     let (bytes_tx, mut response_rx, handle) = setup_send(false);
@@ -1408,6 +1482,55 @@ async fn send_no_delay_error_split_large() {
     );
 }
 
+#[tokio::test]
+async fn send_deadline_exceeded() {
+    let (bytes_tx, bytes_rx) = mpsc::channel(8);
+    let (response_tx, mut response_rx) = mpsc::channel(8);
+    let cancel = CancellationToken::new();
+
+    let task = TransactionSendDelegateTask {
+        config: config_delay(),
+        compression: Compression::None,
+        deadline: Some(Instant::now() + Duration::from_millis(10)),
+        rx: bytes_rx,
+        tx: response_tx,
+        permit: Arc::new(StaticTransactionPermit {
+            id: mock_request_id(0),
+            cancel: cancel.clone(),
+        }),
+    };
+
+    let handle = tokio::spawn(task.run());
+
+    // the response has been started, but is never finished
+    bytes_tx
+        .send(Ok(Bytes::from_static(b"hello")))
+        .await
+        .expect("should not be closed");
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    assert!(cancel.is_cancelled());
+
+    let mut responses = Vec::with_capacity(4);
+    let available = response_rx.recv_many(&mut responses, 4).await;
+    assert_eq!(available, 1);
+
+    let error = NetworkError::capture_error(&TransactionDeadlineExceededError);
+
+    assert_begin(
+        &responses[0],
+        ExpectedBegin {
+            flags: ResponseFlags::from(ResponseFlag::EndOfResponse),
+            kind: ResponseKind::Err(ErrorCode::TRANSACTION_DEADLINE_EXCEEDED),
+            payload: error.bytes(),
+        },
+    );
+}
+
 async fn setup_recv() -> (tachyonix::Sender<Request>, TransactionStream) {
     let (permit, tx, rx) =
         make_transaction_permit(SessionConfig::default(), mock_request_id(0x00)).await;
@@ -1437,6 +1560,8 @@ fn make_begin(flags: impl Into<RequestFlags>, payload: impl Into<Bytes>) -> Requ
             procedure: ProcedureDescriptor {
                 id: ProcedureId::new(0x00),
             },
+            deadline: None,
+            encoding: Encoding::JSON,
             payload: Payload::new(payload),
         }),
     }
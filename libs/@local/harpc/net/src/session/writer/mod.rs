@@ -4,7 +4,8 @@ mod test;
 use bytes::{Buf as _, Bytes};
 use bytes_utils::SegmentedBuf;
 use harpc_types::{
-    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+    compression::Compression, encoding::Encoding, procedure::ProcedureDescriptor,
+    response_kind::ResponseKind, subsystem::SubsystemDescriptor,
 };
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
@@ -14,6 +15,8 @@ use harpc_wire_protocol::{
         Request,
         begin::RequestBegin,
         body::RequestBody,
+        cancel::RequestCancel,
+        deadline::RequestDeadline,
         flags::{RequestFlag, RequestFlags},
         frame::RequestFrame,
         header::RequestHeader,
@@ -64,6 +67,16 @@ pub(crate) struct RequestContext {
 
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
+
+    /// Compression algorithm the payload has been compressed with.
+    pub compression: Compression,
+    /// Compression algorithm the response payload should be compressed with.
+    pub accept: Compression,
+    /// Encoding of the payload.
+    pub encoding: Encoding,
+
+    /// Time the server has to complete the request.
+    pub deadline: Option<RequestDeadline>,
 }
 
 fn new_request_header(context: RequestContext) -> RequestHeader {
@@ -72,7 +85,23 @@ fn new_request_header(context: RequestContext) -> RequestHeader {
             version: ProtocolVersion::V1,
         },
         request_id: context.id,
-        flags: RequestFlags::empty(),
+        flags: RequestFlags::empty()
+            .with_compression(context.compression)
+            .with_accept(context.accept),
+    }
+}
+
+/// Create a request, which notifies the server that the transaction has been cancelled.
+pub(crate) const fn new_cancel_request(id: RequestId) -> Request {
+    Request {
+        header: RequestHeader {
+            protocol: Protocol {
+                version: ProtocolVersion::V1,
+            },
+            request_id: id,
+            flags: RequestFlags::EMPTY,
+        },
+        body: RequestBody::Cancel(RequestCancel),
     }
 }
 
@@ -85,6 +114,8 @@ impl NetworkPacket for Request {
             body: RequestBody::Begin(RequestBegin {
                 subsystem: context.subsystem,
                 procedure: context.procedure,
+                deadline: context.deadline,
+                encoding: context.encoding,
                 payload: Payload::new(bytes),
             }),
         }
@@ -108,6 +139,9 @@ impl NetworkPacket for Request {
 pub(crate) struct ResponseContext {
     pub id: RequestId,
     pub kind: ResponseKind,
+
+    /// Compression algorithm the payload has been compressed with.
+    pub compression: Compression,
 }
 
 fn new_response_header(context: ResponseContext) -> ResponseHeader {
@@ -116,7 +150,7 @@ fn new_response_header(context: ResponseContext) -> ResponseHeader {
             version: ProtocolVersion::V1,
         },
         request_id: context.id,
-        flags: ResponseFlags::empty(),
+        flags: ResponseFlags::empty().with_compression(context.compression),
     }
 }
 
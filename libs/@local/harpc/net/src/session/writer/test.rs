@@ -1,5 +1,5 @@
 use bytes::{Buf as _, Bytes};
-use harpc_types::response_kind::ResponseKind;
+use harpc_types::{compression::Compression, response_kind::ResponseKind};
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
     payload::Payload,
@@ -20,6 +20,7 @@ fn push() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -44,6 +45,7 @@ async fn write_remaining_on_empty() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -72,6 +74,7 @@ async fn write_remaining_no_delay_increments_index() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -98,6 +101,7 @@ async fn flush_calls_write() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -119,6 +123,7 @@ async fn write_calls_write_remaining_on_no_delay() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -144,6 +149,7 @@ async fn split_single() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -179,6 +185,7 @@ async fn split_multiple() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -224,6 +231,7 @@ async fn delay_push_merge_on_write() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -254,6 +262,7 @@ async fn delay_write_checks_closed_state_even_if_not_writing() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
@@ -278,6 +287,7 @@ async fn delay_write_checks_closed_state_not_if_empty_buffer() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: Compression::None,
         },
         &tx,
     );
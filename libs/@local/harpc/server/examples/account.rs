@@ -34,6 +34,7 @@ use harpc_server::{
 use harpc_system::{
     Subsystem, SubsystemIdentifier,
     delegate::SubsystemDelegate,
+    introspection::{IntrospectionProcedureId, ServerDescription},
     procedure::{Procedure, ProcedureIdentifier},
 };
 use harpc_tower::{
@@ -260,6 +261,7 @@ async fn server() {
                 .layer(HandleReportLayer::new())
                 .layer(HandleBodyReportLayer::new())
         })
+        .with_introspection()
         .register(AccountServerDelegate::new(AccountSystemImpl::new()));
 
     let task = router.background_task(server.events());
@@ -288,6 +290,13 @@ async fn client() {
         .await
         .expect("should be able to connect");
 
+    let description: ServerDescription =
+        invoke_call_discrete(connection.clone(), IntrospectionProcedureId::Describe, [()])
+            .await
+            .expect("should be able to describe the server");
+
+    println!("server: {description:?}");
+
     for _ in 0..16 {
         let now = Instant::now();
         let account_id = service
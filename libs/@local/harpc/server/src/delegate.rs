@@ -4,8 +4,10 @@ use core::{
     task::{Context, Poll},
 };
 
+use harpc_codec::NegotiateCodec;
 use harpc_system::delegate::SubsystemDelegate;
 use harpc_tower::{body::Body, request::Request, response::Response};
+use harpc_types::encoding::Encoding;
 use tower::Service;
 
 use crate::session::{RequestInfo, Session, SessionStorage};
@@ -45,7 +47,7 @@ impl<D, S, C, ReqBody> Service<Request<ReqBody>> for SubsystemDelegateService<D,
 where
     D: SubsystemDelegate<C, ExecutionScope = Session<S>> + Clone + Send,
     S: Default + Clone + Send + Sync + 'static,
-    C: Clone + Send + 'static,
+    C: NegotiateCodec + Clone + Send + 'static,
     ReqBody: Body<Control = !, Error: Send + Sync> + Send,
 {
     type Error = D::Error;
@@ -63,7 +65,17 @@ where
         let delegate = core::mem::replace(&mut self.delegate, clone);
 
         let session = Arc::clone(&self.session);
-        let codec = self.codec.clone();
+        // The router rejects requests with an unsupported encoding, the fallback is only taken if
+        // the service is used outside of a router.
+        let encoding = req
+            .extensions()
+            .get::<Encoding>()
+            .copied()
+            .unwrap_or_default();
+        let codec = self
+            .codec
+            .negotiate(encoding)
+            .unwrap_or_else(|| self.codec.clone());
 
         async move {
             let storage = session;
@@ -5,6 +5,7 @@ use core::{
 };
 
 use harpc_types::{
+    encoding::Encoding,
     error_code::ErrorCode,
     procedure::{ProcedureDescriptor, ProcedureId},
     subsystem::SubsystemDescriptor,
@@ -36,6 +37,18 @@ impl Error for ProcedureNotFound {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+#[display("encoding {encoding} is not supported")]
+pub struct UnsupportedEncoding {
+    pub encoding: Encoding,
+}
+
+impl Error for UnsupportedEncoding {
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        request.provide_value(ErrorCode::UNSUPPORTED_ENCODING);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Forbidden {
     pub subsystem: SubsystemDescriptor,
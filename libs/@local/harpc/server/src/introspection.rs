@@ -0,0 +1,145 @@
+use alloc::sync::Arc;
+use core::{
+    array,
+    future::{Ready, ready},
+};
+use std::sync::OnceLock;
+
+use bytes::Bytes;
+use frunk::HNil;
+use futures::stream;
+use harpc_codec::{
+    NegotiateCodec,
+    encode::{Encoder, ReportEncoder},
+    error::NetworkError,
+};
+use harpc_system::{
+    Subsystem as _, SubsystemIdentifier as _,
+    introspection::{
+        IntrospectionProcedureId, IntrospectionSystem, ServerDescription, SubsystemDescription,
+    },
+    procedure::ProcedureIdentifier as _,
+};
+use harpc_tower::{
+    body::{
+        Body, Frame, controlled::Controlled, encode_report::EncodeReport, full::Full,
+        stream::StreamBody,
+    },
+    either::Either,
+    request::Request,
+    response::{Parts, Response},
+};
+use harpc_types::{encoding::Encoding, response_kind::ResponseKind};
+
+use crate::{error::ProcedureNotFound, route::Route};
+
+type DescriptionBody<C> = EncodeReport<
+    Controlled<
+        ResponseKind,
+        StreamBody<
+            stream::MapOk<
+                <C as Encoder>::Output<stream::Iter<array::IntoIter<ServerDescription, 1>>>,
+                fn(<C as Encoder>::Buf) -> Frame<<C as Encoder>::Buf, !>,
+            >,
+        >,
+    >,
+>;
+
+/// Route serving the introspection subsystem.
+///
+/// The route is always the last route of a router, requests which are not addressed to the
+/// introspection subsystem are answered with a `SubsystemNotFound` error, like an empty router
+/// would.
+///
+/// The description of the served subsystems is only available once the router has been built,
+/// which is why it is shared with the [`RouterBuilder`].
+///
+/// [`RouterBuilder`]: crate::router::RouterBuilder
+#[derive(Debug, Clone)]
+pub struct IntrospectionRoute<C> {
+    subsystems: Arc<OnceLock<Vec<SubsystemDescription>>>,
+    codec: C,
+}
+
+impl<C> IntrospectionRoute<C> {
+    pub(crate) const fn new(
+        subsystems: Arc<OnceLock<Vec<SubsystemDescription>>>,
+        codec: C,
+    ) -> Self {
+        Self { subsystems, codec }
+    }
+
+    fn describe(&self) -> ServerDescription
+    where
+        C: NegotiateCodec,
+    {
+        let subsystems = self
+            .subsystems
+            .get()
+            .expect("subsystems should be set once the router has been built");
+
+        ServerDescription {
+            encodings: self.codec.encodings().to_vec(),
+            subsystems: subsystems.clone(),
+        }
+    }
+}
+
+impl<C, ReqBody> Route<ReqBody> for IntrospectionRoute<C>
+where
+    C: ReportEncoder + NegotiateCodec + Clone,
+{
+    type Future = Ready<Response<Self::ResponseBody>>;
+    type ResponseBody = Either<DescriptionBody<C>, Controlled<ResponseKind, Full<Bytes>>>;
+    type SubsystemId = !;
+
+    fn call(&self, request: Request<ReqBody>) -> Self::Future
+    where
+        ReqBody: Body<Control = !, Error: Send + Sync> + Send,
+    {
+        let subsystem = request.subsystem();
+        let requirement = IntrospectionSystem::VERSION.into_requirement();
+
+        if subsystem.id != IntrospectionSystem::ID.into_id()
+            || !requirement.compatible(subsystem.version)
+        {
+            let response = HNil.call(request).into_inner();
+
+            return ready(response.map_body(Either::Right));
+        }
+
+        let parts = Parts::new(request.session());
+        let procedure = request.procedure().id;
+
+        let response = match IntrospectionProcedureId::from_id(procedure) {
+            Some(IntrospectionProcedureId::Describe) => {
+                let encoding = request
+                    .extensions()
+                    .get::<Encoding>()
+                    .copied()
+                    .unwrap_or_default();
+                let codec = self
+                    .codec
+                    .negotiate(encoding)
+                    .unwrap_or_else(|| self.codec.clone());
+
+                let data = codec.encode(stream::iter([self.describe()]));
+
+                Response::from_ok(parts, data)
+                    .map_body(EncodeReport::new)
+                    .map_body(Either::Left)
+            }
+            None => {
+                let error = ProcedureNotFound {
+                    subsystem: IntrospectionSystem::descriptor(),
+                    procedure,
+                };
+
+                Response::from_error(parts, NetworkError::capture_error(&error))
+                    .map_body(Either::Right)
+            }
+        };
+
+        ready(response)
+    }
+}
@@ -8,6 +8,7 @@ extern crate alloc;
 
 pub mod delegate;
 pub mod error;
+pub mod introspection;
 pub mod route;
 pub mod router;
 pub mod serve;
@@ -3,22 +3,32 @@ use core::{
     future::{self, Ready},
     task::{Context, Poll},
 };
+use std::sync::OnceLock;
 
+use bytes::Bytes;
 use frunk::{HCons, HNil};
 use futures::FutureExt as _;
-use harpc_system::{Subsystem, delegate::SubsystemDelegate};
+use harpc_codec::{NegotiateCodec, error::NetworkError};
+use harpc_system::{
+    Subsystem, delegate::SubsystemDelegate, introspection::SubsystemDescription,
+    procedure::ProcedureList,
+};
 use harpc_tower::{
-    body::Body,
+    body::{Body, controlled::Controlled, full::Full},
+    either::Either,
     net::pack::{PackLayer, PackService},
     request::Request,
-    response::Response,
+    response::{Parts, Response},
 };
+use harpc_types::{encoding::Encoding, response_kind::ResponseKind};
 use tokio_util::sync::CancellationToken;
 use tower::{Layer, Service, ServiceBuilder, layer::util::Identity};
 
 use crate::{
     boxed::{BoxReqBody, BoxedRoute, BoxedRouter},
     delegate::SubsystemDelegateService,
+    error::UnsupportedEncoding,
+    introspection::IntrospectionRoute,
     route::{Handler, Route},
     session::{self, Session, SessionStorage},
 };
@@ -29,9 +39,16 @@ pub struct RouterBuilder<R, L, S, C> {
     session: Arc<SessionStorage<S>>,
     codec: C,
     cancel: CancellationToken,
+    subsystems: Vec<SubsystemDescription>,
+    introspection: Arc<OnceLock<Vec<SubsystemDescription>>>,
 }
 
 impl<C> RouterBuilder<HNil, Identity, (), C> {
+    /// Creates a new router, which decodes requests and encodes responses using `codec`.
+    ///
+    /// Clients transmit the encoding of their codec with every request, the codec used for a
+    /// request is selected through [`NegotiateCodec::negotiate`]. Requests using an encoding the
+    /// codec does not support are rejected.
     // S is part of the generics to make it easier to construct the router, but it's not strictly
     // necessary.
     pub fn new<S>(codec: C) -> RouterBuilder<HNil, Identity, S, C>
@@ -44,6 +61,8 @@ impl<C> RouterBuilder<HNil, Identity, (), C> {
             session: Arc::new(SessionStorage::new()),
             codec,
             cancel: CancellationToken::new(),
+            subsystems: Vec::new(),
+            introspection: Arc::new(OnceLock::new()),
         }
     }
 }
@@ -56,6 +75,31 @@ impl<S, C> RouterBuilder<HNil, Identity, S, C> {
     }
 }
 
+// the introspection subsystem is always the last route, so it must be enabled **before** any
+// routes are added.
+impl<L, S, C> RouterBuilder<HNil, L, S, C> {
+    /// Serves the introspection subsystem.
+    ///
+    /// The introspection subsystem describes every subsystem registered with this builder, their
+    /// versions, procedures and deprecations, as well as the encodings supported by the router.
+    ///
+    /// See [`harpc_system::introspection`] for more information.
+    pub fn with_introspection(self) -> RouterBuilder<IntrospectionRoute<C>, L, S, C>
+    where
+        C: NegotiateCodec + Clone,
+    {
+        RouterBuilder {
+            routes: IntrospectionRoute::new(Arc::clone(&self.introspection), self.codec.clone()),
+            builder: self.builder,
+            session: self.session,
+            codec: self.codec,
+            cancel: self.cancel,
+            subsystems: self.subsystems,
+            introspection: self.introspection,
+        }
+    }
+}
+
 type ServiceHandler<D, L, S, C> = Handler<
     <L as Layer<SubsystemDelegateService<D, S, C>>>::Service,
     <<D as SubsystemDelegate<C>>::Subsystem as Subsystem>::SubsystemId,
@@ -72,6 +116,8 @@ impl<R, L, S, C> RouterBuilder<R, L, S, C> {
             session: self.session,
             codec: self.codec,
             cancel: self.cancel,
+            subsystems: self.subsystems,
+            introspection: self.introspection,
         }
     }
 
@@ -83,19 +129,27 @@ impl<R, L, S, C> RouterBuilder<R, L, S, C> {
         reason = "type complexity due to verification"
     )]
     pub fn register<D>(
-        self,
+        mut self,
         delegate: D,
     ) -> RouterBuilder<HCons<ServiceHandler<D, L, S, C>, R>, L, S, C>
     where
-        D: SubsystemDelegate<C, ExecutionScope = Session<S>> + Clone + Send,
+        D: SubsystemDelegate<
+                C,
+                ExecutionScope = Session<S>,
+                Subsystem: Subsystem<Procedures: ProcedureList>,
+            > + Clone
+            + Send,
         L: Layer<SubsystemDelegateService<D, S, C>>,
         S: Default + Send + Sync + 'static,
-        C: Clone + Send + 'static,
+        C: NegotiateCodec + Clone + Send + 'static,
     {
         let service =
             SubsystemDelegateService::new(delegate, Arc::clone(&self.session), self.codec.clone());
         let service = self.builder.service(service);
 
+        self.subsystems
+            .push(SubsystemDescription::of::<D::Subsystem>());
+
         RouterBuilder {
             routes: HCons {
                 head: Handler::new::<D::Subsystem>(service),
@@ -105,6 +159,8 @@ impl<R, L, S, C> RouterBuilder<R, L, S, C> {
             session: self.session,
             codec: self.codec,
             cancel: self.cancel,
+            subsystems: self.subsystems,
+            introspection: self.introspection,
         }
     }
 }
@@ -129,15 +185,22 @@ impl<R, L, S, C> RouterBuilder<R, L, S, C> {
     pub fn build(self) -> Router<R>
     where
         R: Send + Sync + 'static,
+        C: NegotiateCodec,
     {
+        if self.introspection.set(self.subsystems).is_err() {
+            unreachable!("the builder is consumed, therefore the router can only be built once");
+        }
+
         Router {
             routes: Arc::new(self.routes),
+            encodings: self.codec.encodings(),
         }
     }
 }
 
 pub struct RouterService<R> {
     routes: Arc<R>,
+    encodings: &'static [Encoding],
 }
 
 impl<R, ReqBody> Service<Request<ReqBody>> for RouterService<R>
@@ -146,7 +209,7 @@ where
     ReqBody: Body<Control = !, Error: Send + Sync> + Send + Sync,
 {
     type Error = !;
-    type Response = Response<R::ResponseBody>;
+    type Response = Response<Either<R::ResponseBody, Controlled<ResponseKind, Full<Bytes>>>>;
 
     type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
 
@@ -155,12 +218,32 @@ where
     }
 
     fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
-        self.routes.call(req).map(Ok)
+        let encoding = req
+            .extensions()
+            .get::<Encoding>()
+            .copied()
+            .unwrap_or_default();
+
+        if !self.encodings.contains(&encoding) {
+            let error = NetworkError::capture_error(&UnsupportedEncoding { encoding });
+            let response = Response::from_error(Parts::new(req.session()), error);
+
+            return futures::future::Either::Right(future::ready(Ok(
+                response.map_body(Either::Right)
+            )));
+        }
+
+        futures::future::Either::Left(
+            self.routes
+                .call(req)
+                .map(|response| Ok(response.map_body(Either::Left))),
+        )
     }
 }
 
 pub struct Router<R> {
     routes: Arc<R>,
+    encodings: &'static [Encoding],
 }
 
 impl<R> Router<R> {
@@ -187,6 +270,7 @@ impl<R> Router<R> {
 
         Router {
             routes: Arc::new(routes),
+            encodings: self.encodings,
         }
     }
 }
@@ -205,6 +289,459 @@ impl<R> Service<()> for Router<R> {
 
         let layer = PackLayer::new();
 
-        future::ready(Ok(layer.layer(RouterService { routes })))
+        future::ready(Ok(layer.layer(RouterService {
+            routes,
+            encodings: self.encodings,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::Cow;
+    use core::{convert::Infallible, future::ready, marker::PhantomData, pin::pin};
+
+    use bytes::{BufMut as _, Bytes, BytesMut};
+    use frunk::HList;
+    use futures::{StreamExt as _, stream};
+    use harpc_codec::{decode::Decoder as _, error::NetworkError, json::JsonCodec};
+    use harpc_net::session::server::SessionId;
+    use harpc_system::{
+        Subsystem, SubsystemIdentifier,
+        delegate::SubsystemDelegate,
+        introspection::{
+            DeprecationDescription, IntrospectionProcedureId, IntrospectionSystem,
+            ProcedureDescription, ServerDescription, SubsystemDescription,
+        },
+        metadata::Deprecation,
+        procedure::{Procedure, ProcedureIdentifier, ProcedureKind},
+    };
+    use harpc_tower::{
+        Extensions,
+        body::{Body, BodyExt as _, Frame, controlled::Controlled, full::Full},
+        request::{self, Request},
+        response::{Parts, Response},
+    };
+    use harpc_types::{
+        encoding::Encoding,
+        error_code::ErrorCode,
+        procedure::{ProcedureDescriptor, ProcedureId},
+        response_kind::ResponseKind,
+        subsystem::{SubsystemDescriptor, SubsystemId},
+        version::Version,
+    };
+    use tower::Service as _;
+
+    use super::{RouterBuilder, RouterService};
+    use crate::{error::ProcedureNotFound, route::Route, session::Session};
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum System {
+        Accounts,
+        Echo,
+    }
+
+    impl SubsystemIdentifier for System {
+        fn from_id(id: SubsystemId) -> Option<Self> {
+            match id.value() {
+                0x01 => Some(Self::Accounts),
+                0x02 => Some(Self::Echo),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> SubsystemId {
+            match self {
+                Self::Accounts => SubsystemId::new(0x01),
+                Self::Echo => SubsystemId::new(0x02),
+            }
+        }
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    enum AccountsProcedureId {
+        Create,
+        Delete,
+    }
+
+    impl ProcedureIdentifier for AccountsProcedureId {
+        type Subsystem = Accounts;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::Create),
+                0x01 => Some(Self::Delete),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::Create => ProcedureId::new(0x00),
+                Self::Delete => ProcedureId::new(0x01),
+            }
+        }
+    }
+
+    struct Accounts;
+
+    impl Subsystem for Accounts {
+        type ProcedureId = AccountsProcedureId;
+        type Procedures = HList![CreateAccount, DeleteAccount];
+        type SubsystemId = System;
+
+        const ID: System = System::Accounts;
+        const VERSION: Version = Version {
+            major: 0x01,
+            minor: 0x02,
+        };
+
+        fn initial_version() -> Version {
+            Version {
+                major: 0x01,
+                minor: 0x00,
+            }
+        }
+    }
+
+    struct CreateAccount;
+
+    impl Procedure for CreateAccount {
+        type Subsystem = Accounts;
+
+        const ID: AccountsProcedureId = AccountsProcedureId::Create;
+    }
+
+    struct DeleteAccount;
+
+    impl Procedure for DeleteAccount {
+        type Subsystem = Accounts;
+
+        const ID: AccountsProcedureId = AccountsProcedureId::Delete;
+
+        fn since() -> Version {
+            Version {
+                major: 0x01,
+                minor: 0x01,
+            }
+        }
+
+        fn deprecation() -> Option<Deprecation> {
+            Some(Deprecation {
+                since: Accounts::VERSION,
+                reason: Some("accounts are archived instead"),
+            })
+        }
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    enum EchoProcedureId {
+        Echo,
+    }
+
+    impl ProcedureIdentifier for EchoProcedureId {
+        type Subsystem = Echo;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::Echo),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::Echo => ProcedureId::new(0x00),
+            }
+        }
+    }
+
+    struct Echo;
+
+    impl Subsystem for Echo {
+        type ProcedureId = EchoProcedureId;
+        type Procedures = HList![EchoStream];
+        type SubsystemId = System;
+
+        const ID: System = System::Echo;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x03,
+        };
+
+        fn deprecation() -> Option<Deprecation> {
+            Some(Deprecation {
+                since: Self::VERSION,
+                reason: None,
+            })
+        }
+    }
+
+    struct EchoStream;
+
+    impl Procedure for EchoStream {
+        type Subsystem = Echo;
+
+        const ID: EchoProcedureId = EchoProcedureId::Echo;
+        const KIND: ProcedureKind = ProcedureKind::BidirectionalStreaming;
+    }
+
+    /// Delegate answering every request with a `ProcedureNotFound` error.
+    #[derive_where::derive_where(Clone)]
+    struct Stub<S>(PhantomData<fn() -> S>);
+
+    impl<S> Stub<S> {
+        const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<S, C> SubsystemDelegate<C> for Stub<S>
+    where
+        S: Subsystem,
+    {
+        type Body<Source>
+            = Controlled<ResponseKind, Full<Bytes>>
+        where
+            Source: Body<Control = !, Error: Send + Sync> + Send;
+        type Error = !;
+        type ExecutionScope = Session<()>;
+        type Subsystem = S;
+
+        fn call<B>(
+            self,
+            request: Request<B>,
+            _: Session<()>,
+            _: C,
+        ) -> impl Future<Output = Result<Response<Self::Body<B>>, !>> + Send
+        where
+            B: Body<Control = !, Error: Send + Sync> + Send,
+        {
+            let error = ProcedureNotFound {
+                subsystem: request.subsystem(),
+                procedure: request.procedure().id,
+            };
+
+            ready(Ok(Response::from_error(
+                Parts::new(request.session()),
+                NetworkError::capture_error(&error),
+            )))
+        }
+    }
+
+    fn router() -> RouterService<impl Route<Full<Bytes>>> {
+        let router = RouterBuilder::new::<()>(JsonCodec)
+            .with_introspection()
+            .register(Stub::<Accounts>::new())
+            .register(Stub::<Echo>::new())
+            .build();
+
+        RouterService {
+            routes: router.routes,
+            encodings: router.encodings,
+        }
+    }
+
+    fn request(subsystem: SubsystemDescriptor, procedure: ProcedureId) -> Request<Full<Bytes>> {
+        Request::from_parts(
+            request::Parts {
+                subsystem,
+                procedure: ProcedureDescriptor { id: procedure },
+                session: SessionId::CLIENT,
+                extensions: Extensions::new(),
+            },
+            Full::new(Bytes::new()),
+        )
+    }
+
+    async fn call<R>(
+        router: &mut RouterService<R>,
+        request: Request<Full<Bytes>>,
+    ) -> (ResponseKind, Bytes)
+    where
+        R: Route<Full<Bytes>>,
+    {
+        let Ok(response) = router.call(request).await;
+        let mut frames = pin!(response.into_body().into_stream());
+
+        let mut kind = ResponseKind::Ok;
+        let mut data = BytesMut::new();
+
+        while let Some(Ok(frame)) = frames.next().await {
+            match frame {
+                Frame::Data(buffer) => data.put(buffer),
+                Frame::Control(control) => kind = *control.as_ref(),
+            }
+        }
+
+        (kind, data.freeze())
+    }
+
+    #[tokio::test]
+    async fn describe() {
+        let mut router = router();
+
+        let (kind, data) = call(
+            &mut router,
+            request(
+                IntrospectionSystem::descriptor(),
+                IntrospectionProcedureId::Describe.into_id(),
+            ),
+        )
+        .await;
+        assert_eq!(kind, ResponseKind::Ok);
+
+        let descriptions: Vec<ServerDescription> = JsonCodec
+            .decode(stream::iter([Result::<_, Infallible>::Ok(data)]))
+            .map(|item| item.expect("should be able to decode description"))
+            .collect()
+            .await;
+
+        assert_eq!(
+            descriptions,
+            [ServerDescription {
+                encodings: vec![Encoding::JSON],
+                subsystems: vec![
+                    SubsystemDescription {
+                        descriptor: Accounts::descriptor(),
+                        initial_version: Version {
+                            major: 0x01,
+                            minor: 0x00,
+                        },
+                        deprecation: None,
+                        procedures: vec![
+                            ProcedureDescription {
+                                descriptor: CreateAccount::descriptor(),
+                                kind: ProcedureKind::Discrete,
+                                since: Version {
+                                    major: 0x01,
+                                    minor: 0x00,
+                                },
+                                deprecation: None,
+                            },
+                            ProcedureDescription {
+                                descriptor: DeleteAccount::descriptor(),
+                                kind: ProcedureKind::Discrete,
+                                since: Version {
+                                    major: 0x01,
+                                    minor: 0x01,
+                                },
+                                deprecation: Some(DeprecationDescription {
+                                    since: Accounts::VERSION,
+                                    reason: Some(Cow::Borrowed("accounts are archived instead")),
+                                }),
+                            },
+                        ],
+                    },
+                    SubsystemDescription {
+                        descriptor: Echo::descriptor(),
+                        initial_version: Version {
+                            major: 0x00,
+                            minor: 0x00,
+                        },
+                        deprecation: Some(DeprecationDescription {
+                            since: Echo::VERSION,
+                            reason: None,
+                        }),
+                        procedures: vec![ProcedureDescription {
+                            descriptor: EchoStream::descriptor(),
+                            kind: ProcedureKind::BidirectionalStreaming,
+                            since: Version {
+                                major: 0x00,
+                                minor: 0x00,
+                            },
+                            deprecation: None,
+                        }],
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_subsystem() {
+        let mut router = router();
+
+        let (kind, _) = call(
+            &mut router,
+            request(
+                SubsystemDescriptor {
+                    id: SubsystemId::new(0x03),
+                    version: Version {
+                        major: 0x00,
+                        minor: 0x00,
+                    },
+                },
+                ProcedureId::new(0x00),
+            ),
+        )
+        .await;
+
+        assert_eq!(kind, ResponseKind::Err(ErrorCode::SUBSYSTEM_NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn incompatible_subsystem_version() {
+        let mut router = router();
+
+        let (kind, _) = call(
+            &mut router,
+            request(
+                SubsystemDescriptor {
+                    id: System::Accounts.into_id(),
+                    version: Version {
+                        major: 0x02,
+                        minor: 0x00,
+                    },
+                },
+                AccountsProcedureId::Create.into_id(),
+            ),
+        )
+        .await;
+
+        assert_eq!(kind, ResponseKind::Err(ErrorCode::SUBSYSTEM_NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn registered_subsystem() {
+        let mut router = router();
+
+        // the stub answers every request with `ProcedureNotFound`, which shows that the request
+        // has been routed to it, instead of falling through to the introspection route
+        let (kind, _) = call(
+            &mut router,
+            request(Echo::descriptor(), EchoProcedureId::Echo.into_id()),
+        )
+        .await;
+
+        assert_eq!(kind, ResponseKind::Err(ErrorCode::PROCEDURE_NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn unsupported_encoding() {
+        let mut router = router();
+
+        let mut request = request(
+            IntrospectionSystem::descriptor(),
+            IntrospectionProcedureId::Describe.into_id(),
+        );
+        request.extensions_mut().insert(Encoding::CBOR);
+
+        let (kind, _) = call(&mut router, request).await;
+
+        assert_eq!(kind, ResponseKind::Err(ErrorCode::UNSUPPORTED_ENCODING));
+    }
+
+    #[tokio::test]
+    async fn unknown_introspection_procedure() {
+        let mut router = router();
+
+        let (kind, _) = call(
+            &mut router,
+            request(IntrospectionSystem::descriptor(), ProcedureId::new(0x01)),
+        )
+        .await;
+
+        assert_eq!(kind, ResponseKind::Err(ErrorCode::PROCEDURE_NOT_FOUND));
     }
 }
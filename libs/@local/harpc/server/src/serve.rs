@@ -33,10 +33,15 @@ where
     pin!(stream);
 
     while let Some(transaction) = stream.next().await {
+        let cancel = transaction.cancellation_token();
         let (context, sink, stream) = transaction.into_parts();
 
-        let parts = request::Parts::from_transaction(&context);
-        let request = Request::from_parts(parts, RequestBody::new(stream));
+        // the handler is able to observe a cancellation by the client, or an exceeded deadline,
+        // through the `CancellationToken`
+        let mut parts = request::Parts::from_transaction(&context);
+        parts.extensions.insert(cancel);
+        let body = RequestBody::with_compression(stream, context.compression());
+        let request = Request::from_parts(parts, body);
 
         let Ok(()) = poll_fn(|cx| make_service.poll_ready(cx)).await;
         let Ok(service) = make_service.make_service(()).await;
@@ -3,11 +3,18 @@
 use core::{array, pin::pin};
 
 use error_stack::{Report, ResultExt as _};
-use futures::{StreamExt as _, stream};
-use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use futures::{Stream, StreamExt as _, stream};
+use harpc_codec::{
+    decode::{Decoder, ReportDecoder},
+    encode::Encoder,
+};
 use harpc_system::{Subsystem, procedure::ProcedureIdentifier};
 use harpc_tower::{
-    body::{Body, BodyExt as _, Frame, controlled::Controlled, stream::StreamBody},
+    body::{
+        Body, BodyExt as _, Frame,
+        controlled::Controlled,
+        stream::{BodyDataStream, StreamBody},
+    },
     request::Request,
     response::{self, Response},
 };
@@ -38,6 +45,47 @@ where
         .change_context(DelegationError)
 }
 
+/// Stream of items decoded from the body of a request.
+///
+/// Passed to the closure of [`delegate_call_bidirectional_streaming`], every item is decoded
+/// lazily, once the stream is polled.
+pub type RequestItemStream<C, B, I> = <C as Decoder>::Output<I, BodyDataStream<B>>;
+
+/// Response of a delegated call, encoding every item of `S` using the codec `C`.
+// Precise capturing of types isn't implemented yet, so we're going to painful route, as we don't
+// want to capture any unnecessary types.
+pub type DelegateResponse<C, S> = Response<
+    Controlled<
+        ResponseKind,
+        StreamBody<
+            stream::MapOk<
+                <C as Encoder>::Output<S>,
+                fn(<C as Encoder>::Buf) -> Frame<<C as Encoder>::Buf, !>,
+            >,
+        >,
+    >,
+>;
+
+/// Decodes the single item of a request.
+async fn decode_discrete<B, I, C>(body: B, codec: C) -> Result<I, Report<DelegationError>>
+where
+    B: Body<Control = !, Error: Send + Sync> + Send,
+    I: serde::de::DeserializeOwned,
+    C: ReportDecoder,
+{
+    let data = body.into_stream().into_data_stream();
+
+    let stream = codec.decode(data);
+    let mut stream = pin!(stream);
+
+    stream
+        .next()
+        .await
+        .ok_or_else(|| RequestExpectedItemCountMismatch::exactly(1))
+        .change_context(DelegationError)?
+        .change_context(DelegationError)
+}
+
 /// Delegates a call to a closure with a single input and output.
 ///
 /// # Errors
@@ -49,22 +97,7 @@ pub async fn delegate_call_discrete<B, I, O, C, Fut>(
     request: Request<B>,
     codec: C,
     closure: impl FnOnce(I) -> Fut + Send,
-) -> Result<
-    Response<
-        // Precise capturing of types isn't implemented yet, so we're going to painful
-        // route, as we don't want to capture any unnecessary types.
-        Controlled<
-            ResponseKind,
-            StreamBody<
-                stream::MapOk<
-                    <C as Encoder>::Output<stream::Iter<array::IntoIter<O, 1>>>,
-                    fn(<C as Encoder>::Buf) -> Frame<<C as Encoder>::Buf, !>,
-                >,
-            >,
-        >,
-    >,
-    Report<DelegationError>,
->
+) -> Result<DelegateResponse<C, stream::Iter<array::IntoIter<O, 1>>>, Report<DelegationError>>
 where
     B: Body<Control = !, Error: Send + Sync> + Send,
     I: serde::de::DeserializeOwned,
@@ -74,18 +107,7 @@ where
 {
     let session_id = request.session();
 
-    let body = request.into_body();
-    let data = body.into_stream().into_data_stream();
-
-    let stream = codec.clone().decode(data);
-    let mut stream = pin!(stream);
-
-    let payload = stream
-        .next()
-        .await
-        .ok_or_else(|| RequestExpectedItemCountMismatch::exactly(1))
-        .change_context(DelegationError)?
-        .change_context(DelegationError)?;
+    let payload = decode_discrete(request.into_body(), codec.clone()).await?;
 
     let response = closure(payload).await;
 
@@ -94,3 +116,445 @@ where
     // In theory we could also box this, or use `Either` if we have multiple responses
     Ok(Response::from_ok(response::Parts::new(session_id), data))
 }
+
+/// Delegates a call to a closure with a single input and a stream of outputs.
+///
+/// Items are encoded and sent as they are produced by the stream. The stream is only polled once
+/// the transport is able to accept more data, which propagates backpressure to the producer.
+///
+/// Errors of individual items should be part of the item itself, e.g. by using a stream of
+/// `Result<T, Report<E>>`, which is encoded like the output of [`delegate_call_discrete`].
+///
+/// # Errors
+///
+/// This function returns a `Report<DelegationError>` in the following cases:
+/// - If decoding the request fails
+/// - If the request does not contain exactly one item
+pub async fn delegate_call_server_streaming<B, I, O, C, Fut>(
+    request: Request<B>,
+    codec: C,
+    closure: impl FnOnce(I) -> Fut + Send,
+) -> Result<DelegateResponse<C, O>, Report<DelegationError>>
+where
+    B: Body<Control = !, Error: Send + Sync> + Send,
+    I: serde::de::DeserializeOwned,
+    O: Stream<Item: serde::Serialize> + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+    Fut: Future<Output = O> + Send,
+{
+    let session_id = request.session();
+
+    let payload = decode_discrete(request.into_body(), codec.clone()).await?;
+
+    let items = closure(payload).await;
+
+    let data = codec.encode(items);
+
+    Ok(Response::from_ok(response::Parts::new(session_id), data))
+}
+
+/// Delegates a call to a closure with a stream of inputs and a stream of outputs.
+///
+/// The closure receives the items of the request as a [`RequestItemStream`], which decodes each
+/// item once it has been received. Backpressure is applied in both directions: the request is
+/// only read as fast as the input stream is polled, and the output stream is only polled once the
+/// transport is able to accept more data.
+///
+/// Errors of individual items should be part of the item itself, e.g. by using a stream of
+/// `Result<T, Report<E>>`, which is encoded like the output of [`delegate_call_discrete`].
+pub async fn delegate_call_bidirectional_streaming<B, I, O, C, Fut>(
+    request: Request<B>,
+    codec: C,
+    closure: impl FnOnce(RequestItemStream<C, B, I>) -> Fut + Send,
+) -> DelegateResponse<C, O>
+where
+    B: Body<Control = !, Error: Send + Sync> + Send,
+    I: serde::de::DeserializeOwned,
+    O: Stream<Item: serde::Serialize> + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+    Fut: Future<Output = O> + Send,
+{
+    let session_id = request.session();
+
+    let data = request.into_body().into_stream().into_data_stream();
+    let items = codec.clone().decode(data);
+
+    let items = closure(items).await;
+
+    let data = codec.encode(items);
+
+    Response::from_ok(response::Parts::new(session_id), data)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use core::{
+        convert::Infallible,
+        pin::{Pin, pin},
+        sync::atomic::{AtomicUsize, Ordering},
+        task::{Context, Poll},
+    };
+    use std::sync::{Mutex, OnceLock};
+
+    use bytes::Bytes;
+    use error_stack::Report;
+    use futures::{
+        FutureExt as _, Stream, StreamExt as _,
+        future::BoxFuture,
+        stream::{self, BoxStream},
+    };
+    use harpc_client::{
+        connection::Connection,
+        error::RemoteError,
+        utils::{invoke_call_bidirectional_streaming, invoke_call_server_streaming},
+    };
+    use harpc_codec::json::{JsonCodec, JsonError};
+    use harpc_system::{Subsystem, SubsystemIdentifier, procedure::ProcedureIdentifier};
+    use harpc_tower::{
+        body::{Body, BodyExt as _, Frame, stream::StreamBody},
+        request::Request,
+        response::Response,
+    };
+    use harpc_types::{procedure::ProcedureId, subsystem::SubsystemId, version::Version};
+    use serde::ser::Error as _;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{
+        RequestItemStream, delegate_call_bidirectional_streaming, delegate_call_server_streaming,
+    };
+
+    #[derive(Debug, Copy, Clone)]
+    enum System {
+        Numbers,
+    }
+
+    impl SubsystemIdentifier for System {
+        fn from_id(id: SubsystemId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::Numbers),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> SubsystemId {
+            match self {
+                Self::Numbers => SubsystemId::new(0x00),
+            }
+        }
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    enum NumbersProcedureId {
+        Count,
+        Double,
+    }
+
+    impl ProcedureIdentifier for NumbersProcedureId {
+        type Subsystem = Numbers;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0 => Some(Self::Count),
+                1 => Some(Self::Double),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::Count => ProcedureId::new(0),
+                Self::Double => ProcedureId::new(1),
+            }
+        }
+    }
+
+    struct Numbers;
+
+    impl Subsystem for Numbers {
+        type ProcedureId = NumbersProcedureId;
+        type Procedures = ();
+        type SubsystemId = System;
+
+        const ID: System = System::Numbers;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("the value is odd")]
+    struct OddValue;
+
+    /// An item of a request, which fails to encode if it is invalid.
+    #[derive(Debug)]
+    enum Item {
+        Value(u32),
+        Invalid,
+    }
+
+    impl serde::Serialize for Item {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match self {
+                Self::Value(value) => serializer.serialize_u32(*value),
+                Self::Invalid => Err(S::Error::custom("the item is invalid")),
+            }
+        }
+    }
+
+    /// Request body as received by the server.
+    type LoopbackBody = StreamBody<BoxStream<'static, Result<Frame<Bytes, !>, !>>>;
+
+    /// Response body as received by the client.
+    ///
+    /// The body produced by the server is read directly, so that the server is only polled once
+    /// the client polls the response.
+    struct ResponseStream(Mutex<BoxStream<'static, Result<Bytes, Report<JsonError>>>>);
+
+    impl ResponseStream {
+        fn new<B>(response: Response<B>) -> Response<Self>
+        where
+            B: Body<Data = Bytes, Error = Report<JsonError>> + Send + 'static,
+        {
+            response
+                .map_body(|body| Self(Mutex::new(body.into_stream().into_data_stream().boxed())))
+        }
+    }
+
+    impl Stream for ResponseStream {
+        type Item = Result<Bytes, Report<JsonError>>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.get_mut()
+                .0
+                .get_mut()
+                .expect("lock should not be poisoned")
+                .poll_next_unpin(cx)
+        }
+    }
+
+    type Handler = Arc<
+        dyn Fn(Request<LoopbackBody>) -> BoxFuture<'static, Response<ResponseStream>> + Send + Sync,
+    >;
+
+    /// Service passing every request to a server-side handler, without a network in between.
+    #[derive(Clone)]
+    struct Loopback(Handler);
+
+    impl<St> tower::Service<Request<St>> for Loopback
+    where
+        St: Stream<Item = Bytes> + Send + 'static,
+    {
+        type Error = Report<Infallible>;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+        type Response = Response<ResponseStream>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: Request<St>) -> Self::Future {
+            let request = req
+                .map_body(|body| StreamBody::new(body.map(|data| Ok(Frame::Data(data))).boxed()));
+
+            (self.0)(request).map(Ok).boxed()
+        }
+    }
+
+    fn connect<F, Fut>(handler: F) -> Connection<Loopback, JsonCodec>
+    where
+        F: Fn(Request<LoopbackBody>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response<ResponseStream>> + Send + 'static,
+    {
+        Connection::new(
+            Loopback(Arc::new(move |request| handler(request).boxed())),
+            JsonCodec,
+        )
+    }
+
+    /// Responds with the values from `0` to the requested count, odd values are errors.
+    fn count(produced: &Arc<AtomicUsize>) -> Connection<Loopback, JsonCodec> {
+        let produced = Arc::clone(produced);
+
+        connect(move |request| {
+            let produced = Arc::clone(&produced);
+
+            async move {
+                let response =
+                    delegate_call_server_streaming(request, JsonCodec, |count: u32| async move {
+                        stream::iter(0..count).map(move |value| {
+                            produced.fetch_add(1, Ordering::SeqCst);
+
+                            if value.is_multiple_of(2) {
+                                Ok(value)
+                            } else {
+                                Err(Report::new(OddValue))
+                            }
+                        })
+                    })
+                    .await
+                    .expect("should be able to delegate call");
+
+                ResponseStream::new(response)
+            }
+        })
+    }
+
+    /// Responds with every value of the request doubled.
+    fn double(cancel: &Arc<OnceLock<CancellationToken>>) -> Connection<Loopback, JsonCodec> {
+        let cancel = Arc::clone(cancel);
+
+        connect(move |request| {
+            if let Some(token) = request.extensions().get::<CancellationToken>() {
+                let _: Result<(), _> = cancel.set(token.clone());
+            }
+
+            async move {
+                let response = delegate_call_bidirectional_streaming(
+                    request,
+                    JsonCodec,
+                    |items: RequestItemStream<JsonCodec, LoopbackBody, u32>| async move {
+                        items.map(|item| item.expect("should be able to decode item") * 2)
+                    },
+                )
+                .await;
+
+                ResponseStream::new(response)
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn server_streaming_backpressure() {
+        let produced = Arc::new(AtomicUsize::new(0));
+
+        let items = invoke_call_server_streaming::<_, _, u32>(
+            count(&produced),
+            NumbersProcedureId::Count,
+            [8_u32],
+        )
+        .await
+        .expect("should be able to invoke call");
+        let mut items = pin!(items);
+
+        assert_eq!(produced.load(Ordering::SeqCst), 0);
+
+        for expected in (0..8).step_by(2) {
+            let item = items.next().await.expect("should have an item");
+            assert_eq!(item.expect("should be Ok"), expected);
+            // the server only produces an item once the client has asked for it
+            assert_eq!(produced.load(Ordering::SeqCst), expected as usize + 1);
+
+            let _error = items.next().await.expect("should have an item");
+            assert_eq!(produced.load(Ordering::SeqCst), expected as usize + 2);
+        }
+
+        assert!(items.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn server_streaming_error_items() {
+        let produced = Arc::new(AtomicUsize::new(0));
+
+        let items: Vec<_> = invoke_call_server_streaming::<_, _, u32>(
+            count(&produced),
+            NumbersProcedureId::Count,
+            [3_u32],
+        )
+        .await
+        .expect("should be able to invoke call")
+        .collect()
+        .await;
+
+        let [first, second, third] = <[_; 3]>::try_from(items).expect("should have three items");
+
+        assert_eq!(first.expect("should be Ok"), 0);
+
+        // the error does not end the stream, it is returned as the error of the item
+        let report = second.expect_err("should be an error");
+        assert!(report.contains::<RemoteError>());
+
+        assert_eq!(third.expect("should be Ok"), 2);
+    }
+
+    #[tokio::test]
+    async fn bidirectional_streaming_backpressure() {
+        let requested = Arc::new(AtomicUsize::new(0));
+        let cancel = Arc::new(OnceLock::new());
+
+        let request = stream::iter(0..4_u32).map({
+            let requested = Arc::clone(&requested);
+
+            move |value| {
+                requested.fetch_add(1, Ordering::SeqCst);
+                Item::Value(value)
+            }
+        });
+
+        let items = invoke_call_bidirectional_streaming::<_, _, u32>(
+            double(&cancel),
+            NumbersProcedureId::Double,
+            request,
+        )
+        .await
+        .expect("should be able to invoke call");
+        let mut items = pin!(items);
+
+        assert_eq!(requested.load(Ordering::SeqCst), 0);
+
+        for value in 0..4 {
+            let item = items.next().await.expect("should have an item");
+            assert_eq!(item.expect("should be Ok"), value * 2);
+            // request items are only encoded once the server asks for them
+            assert_eq!(requested.load(Ordering::SeqCst), value as usize + 1);
+        }
+
+        assert!(items.next().await.is_none());
+
+        let cancel = cancel
+            .get()
+            .expect("request should carry a cancellation token");
+        assert!(!cancel.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn bidirectional_streaming_encode_error() {
+        let cancel = Arc::new(OnceLock::new());
+
+        let request = stream::iter([
+            Item::Value(1),
+            Item::Value(2),
+            Item::Invalid,
+            Item::Value(3),
+        ]);
+
+        let items: Vec<_> = invoke_call_bidirectional_streaming::<_, _, u32>(
+            double(&cancel),
+            NumbersProcedureId::Double,
+            request,
+        )
+        .await
+        .expect("should be able to invoke call")
+        .collect()
+        .await;
+
+        // the request ends at the invalid item, the error is returned as the last item
+        let [first, second, third] = <[_; 3]>::try_from(items).expect("should have three items");
+
+        assert_eq!(first.expect("should be Ok"), 2);
+        assert_eq!(second.expect("should be Ok"), 4);
+
+        let report = third.expect_err("should be an error");
+        assert!(report.contains::<JsonError>());
+        assert!(!report.contains::<RemoteError>());
+
+        let cancel = cancel
+            .get()
+            .expect("request should carry a cancellation token");
+        assert!(cancel.is_cancelled());
+    }
+}
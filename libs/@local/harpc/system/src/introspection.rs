@@ -0,0 +1,177 @@
+//! Runtime description of the subsystems served by a server.
+//!
+//! The introspection subsystem uses the reserved subsystem identifier `0xF000` and is available on
+//! every server that has it enabled, independent of the subsystems it serves. It allows generic
+//! tooling, such as command line clients or health checks, to discover which subsystems,
+//! versions and procedures a server provides.
+
+use alloc::borrow::Cow;
+
+use frunk::HList;
+use harpc_types::{
+    encoding::Encoding,
+    procedure::{ProcedureDescriptor, ProcedureId},
+    subsystem::{SubsystemDescriptor, SubsystemId},
+    version::Version,
+};
+
+use crate::{
+    Subsystem, SubsystemIdentifier, SubsystemInformation,
+    metadata::Deprecation,
+    procedure::{
+        Procedure, ProcedureIdentifier, ProcedureInformation, ProcedureKind, ProcedureList,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct DeprecationDescription {
+    pub since: Version,
+    pub reason: Option<Cow<'static, str>>,
+}
+
+impl From<Deprecation> for DeprecationDescription {
+    fn from(Deprecation { since, reason }: Deprecation) -> Self {
+        Self {
+            since,
+            reason: reason.map(Cow::Borrowed),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ProcedureDescription {
+    pub descriptor: ProcedureDescriptor,
+    pub kind: ProcedureKind,
+    /// The version at which the procedure was introduced.
+    pub since: Version,
+    pub deprecation: Option<DeprecationDescription>,
+}
+
+impl From<ProcedureInformation> for ProcedureDescription {
+    fn from(
+        ProcedureInformation {
+            descriptor,
+            kind,
+            since,
+            deprecation,
+        }: ProcedureInformation,
+    ) -> Self {
+        Self {
+            descriptor,
+            kind,
+            since,
+            deprecation: deprecation.map(From::from),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct SubsystemDescription {
+    pub descriptor: SubsystemDescriptor,
+    /// The initial version this subsystem was introduced in.
+    pub initial_version: Version,
+    pub deprecation: Option<DeprecationDescription>,
+    pub procedures: Vec<ProcedureDescription>,
+}
+
+impl SubsystemDescription {
+    /// Describes the subsystem `S` and all of its procedures.
+    #[must_use]
+    pub fn of<S>() -> Self
+    where
+        S: Subsystem<Procedures: ProcedureList>,
+    {
+        let SubsystemInformation {
+            descriptor,
+            deprecation,
+            initial_version,
+        } = S::information();
+
+        Self {
+            descriptor,
+            initial_version,
+            deprecation: deprecation.map(From::from),
+            procedures: S::Procedures::information()
+                .into_iter()
+                .map(From::from)
+                .collect(),
+        }
+    }
+}
+
+/// Description of a server, as returned by [`ProcedureDescribe`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ServerDescription {
+    /// The encodings supported by the server, every request must use one of them.
+    pub encodings: Vec<Encoding>,
+    /// The subsystems served, excluding the introspection subsystem itself.
+    pub subsystems: Vec<SubsystemDescription>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IntrospectionSubsystemId {
+    Introspection,
+}
+
+impl IntrospectionSubsystemId {
+    const ID: SubsystemId = SubsystemId::new(0xF000);
+}
+
+impl SubsystemIdentifier for IntrospectionSubsystemId {
+    fn from_id(id: SubsystemId) -> Option<Self> {
+        (id == Self::ID).then_some(Self::Introspection)
+    }
+
+    fn into_id(self) -> SubsystemId {
+        match self {
+            Self::Introspection => Self::ID,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IntrospectionProcedureId {
+    Describe,
+}
+
+impl ProcedureIdentifier for IntrospectionProcedureId {
+    type Subsystem = IntrospectionSystem;
+
+    fn from_id(id: ProcedureId) -> Option<Self> {
+        match id.value() {
+            0x00 => Some(Self::Describe),
+            _ => None,
+        }
+    }
+
+    fn into_id(self) -> ProcedureId {
+        match self {
+            Self::Describe => ProcedureId::new(0x00),
+        }
+    }
+}
+
+pub struct IntrospectionSystem;
+
+impl Subsystem for IntrospectionSystem {
+    type ProcedureId = IntrospectionProcedureId;
+    type Procedures = HList![ProcedureDescribe];
+    type SubsystemId = IntrospectionSubsystemId;
+
+    const ID: IntrospectionSubsystemId = IntrospectionSubsystemId::Introspection;
+    const VERSION: Version = Version {
+        major: 0x00,
+        minor: 0x00,
+    };
+}
+
+/// Describes the server, returning a [`ServerDescription`].
+///
+/// The request payload is ignored.
+pub struct ProcedureDescribe;
+
+impl Procedure for ProcedureDescribe {
+    type Subsystem = IntrospectionSystem;
+
+    const ID: <Self::Subsystem as Subsystem>::ProcedureId = IntrospectionProcedureId::Describe;
+}
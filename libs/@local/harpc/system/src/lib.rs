@@ -4,6 +4,8 @@
 #![cfg_attr(doc, doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd"))]
 #![feature(never_type, marker_trait_attr)]
 
+extern crate alloc;
+
 use harpc_types::{
     subsystem::{SubsystemDescriptor, SubsystemId},
     version::Version,
@@ -12,6 +14,7 @@ use harpc_types::{
 use self::{metadata::Deprecation, procedure::ProcedureIdentifier};
 
 pub mod delegate;
+pub mod introspection;
 pub mod metadata;
 pub mod procedure;
 
@@ -1,4 +1,4 @@
-use frunk::HCons;
+use frunk::{HCons, HNil};
 use harpc_types::{
     procedure::{ProcedureDescriptor, ProcedureId},
     version::Version,
@@ -16,6 +16,39 @@ pub trait IncludesProcedure<P> {}
 impl<Head, Tail> IncludesProcedure<Head> for HCons<Head, Tail> where Head: Procedure {}
 impl<Head, Tail, P> IncludesProcedure<P> for HCons<Head, Tail> where Tail: IncludesProcedure<P> {}
 
+/// A heterogeneous list of procedures, which can be enumerated at runtime.
+///
+/// Implemented for the [`Subsystem::Procedures`] of every subsystem, allowing the procedures of a
+/// subsystem to be described, e.g. by the introspection subsystem.
+pub trait ProcedureList {
+    /// Appends the information of every procedure in the list to `information`.
+    fn extend_information(information: &mut Vec<ProcedureInformation>);
+
+    /// Returns the information of every procedure in the list, in declaration order.
+    #[must_use]
+    fn information() -> Vec<ProcedureInformation> {
+        let mut information = Vec::new();
+        Self::extend_information(&mut information);
+
+        information
+    }
+}
+
+impl ProcedureList for HNil {
+    fn extend_information(_: &mut Vec<ProcedureInformation>) {}
+}
+
+impl<Head, Tail> ProcedureList for HCons<Head, Tail>
+where
+    Head: Procedure,
+    Tail: ProcedureList,
+{
+    fn extend_information(information: &mut Vec<ProcedureInformation>) {
+        information.push(Head::information());
+        Tail::extend_information(information);
+    }
+}
+
 pub trait ProcedureIdentifier: Sized {
     type Subsystem: Subsystem;
 
@@ -23,10 +56,27 @@ pub trait ProcedureIdentifier: Sized {
     fn into_id(self) -> ProcedureId;
 }
 
+/// The shape of the items exchanged during a call to a procedure.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+pub enum ProcedureKind {
+    /// A single request item is answered with a single response item.
+    #[default]
+    Discrete,
+    /// A single request item is answered with a stream of response items.
+    ServerStreaming,
+    /// A stream of request items is answered with a stream of response items.
+    BidirectionalStreaming,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct ProcedureInformation {
     pub descriptor: ProcedureDescriptor,
 
+    /// The shape of the items exchanged during a call.
+    pub kind: ProcedureKind,
+
     /// The version at which the procedure was introduced.
     pub since: Version,
 
@@ -39,6 +89,11 @@ pub trait Procedure: Sized {
 
     const ID: <Self::Subsystem as Subsystem>::ProcedureId;
 
+    /// The shape of the items exchanged during a call.
+    ///
+    /// By default, a procedure is [`ProcedureKind::Discrete`].
+    const KIND: ProcedureKind = ProcedureKind::Discrete;
+
     /// Returns the descriptor for this procedure.
     #[must_use]
     fn descriptor() -> ProcedureDescriptor {
@@ -67,12 +122,13 @@ pub trait Procedure: Sized {
 
     /// Returns comprehensive information about the procedure.
     ///
-    /// This method aggregates the descriptor, introduction version, and deprecation status
+    /// This method aggregates the descriptor, kind, introduction version, and deprecation status
     /// of the procedure into a single `ProcedureInformation` struct.
     #[must_use]
     fn information() -> ProcedureInformation {
         ProcedureInformation {
             descriptor: Self::descriptor(),
+            kind: Self::KIND,
             since: Self::since(),
             deprecation: Self::deprecation(),
         }
@@ -0,0 +1,590 @@
+//! Compression of body data.
+//!
+//! Every data frame is compressed into a self-contained block, which is prefixed by the length of
+//! the compressed data, encoded as a big-endian `u32`. The network layer is free to split or merge
+//! blocks, which is why [`Decompress`] buffers the incoming data until a block is complete.
+//!
+//! A block holds at most [`MAX_BLOCK_SIZE`] bytes of uncompressed data, larger data frames are
+//! split into multiple blocks. Blocks exceeding this limit are rejected by [`Decompress`], before
+//! they are buffered or decompressed.
+//!
+//! Only the data of successful responses is compressed, errors are always transmitted
+//! uncompressed, so that they can be decoded regardless of the negotiated algorithm.
+use core::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use bytes::{Buf, BufMut as _, Bytes, BytesMut};
+use harpc_types::{compression::Compression, response_kind::ResponseKind};
+
+use super::{Body, BodyFrameResult, BodyState, Frame, SizeHint};
+
+const LENGTH_PREFIX: usize = size_of::<u32>();
+
+/// The maximum amount of uncompressed data in a single block.
+pub const MAX_BLOCK_SIZE: usize = 16 * 1024 * 1024;
+
+/// The maximum length of a compressed block, excluding its length prefix.
+fn max_block_length(compression: Compression) -> usize {
+    match compression {
+        Compression::None => MAX_BLOCK_SIZE,
+        Compression::Zstd => zstd::zstd_safe::compress_bound(MAX_BLOCK_SIZE),
+        Compression::Lz4 => {
+            LENGTH_PREFIX + lz4_flex::block::get_maximum_output_size(MAX_BLOCK_SIZE)
+        }
+    }
+}
+
+fn is_ok_segment<C>(control: &C) -> bool
+where
+    C: AsRef<ResponseKind>,
+{
+    matches!(control.as_ref(), ResponseKind::Ok)
+}
+
+/// Compresses the data into one or more blocks, each holding at most [`MAX_BLOCK_SIZE`] bytes.
+#[expect(
+    clippy::big_endian_bytes,
+    reason = "numbers are always encoded in big-endian in our encoding scheme"
+)]
+fn compress_blocks(compression: Compression, data: &[u8]) -> Bytes {
+    if compression.is_none() {
+        return Bytes::copy_from_slice(data);
+    }
+
+    let mut buffer = BytesMut::new();
+
+    for chunk in data.chunks(MAX_BLOCK_SIZE) {
+        let block = match compression {
+            Compression::None => unreachable!("uncompressed data is not split into blocks"),
+            Compression::Zstd => zstd::bulk::compress(chunk, zstd::DEFAULT_COMPRESSION_LEVEL)
+                .unwrap_or_else(|error| {
+                    unreachable!("compressing an in-memory buffer does not fail: {error}")
+                }),
+            Compression::Lz4 => lz4_flex::block::compress_prepend_size(chunk),
+        };
+
+        let length = u32::try_from(block.len())
+            .expect("blocks are limited to `MAX_BLOCK_SIZE` and therefore smaller than 4GiB");
+
+        buffer.reserve(LENGTH_PREFIX + block.len());
+        buffer.put_slice(&length.to_be_bytes());
+        buffer.put_slice(&block);
+    }
+
+    buffer.freeze()
+}
+
+/// Decompresses a single block, returns `None` if the block is corrupted or holds more than
+/// [`MAX_BLOCK_SIZE`] bytes of data.
+fn decompress_block(compression: Compression, block: &[u8]) -> Option<Bytes> {
+    let data = match compression {
+        Compression::None => return Some(Bytes::copy_from_slice(block)),
+        Compression::Zstd => zstd::bulk::decompress(block, MAX_BLOCK_SIZE).ok()?,
+        Compression::Lz4 => {
+            // The size prefix is untrusted, it determines the size of the allocated buffer
+            let (size, block) = lz4_flex::block::uncompressed_size(block).ok()?;
+            if size > MAX_BLOCK_SIZE {
+                return None;
+            }
+
+            lz4_flex::block::decompress(block, size).ok()?
+        }
+    };
+
+    Some(Bytes::from(data))
+}
+
+pin_project_lite::pin_project! {
+    /// A body that compresses every data frame using the given [`Compression`].
+    ///
+    /// If the algorithm is [`Compression::None`], data frames are passed through unchanged.
+    #[derive(Debug)]
+    pub struct Compress<B>
+    where
+        B: Body,
+    {
+        #[pin]
+        inner: B,
+
+        compression: Compression,
+
+        // Whether or not data frames are currently compressed, updated on every control frame.
+        active: bool,
+        is_active: fn(&B::Control) -> bool,
+    }
+}
+
+impl<B> Compress<B>
+where
+    B: Body<Control = !>,
+{
+    /// Compress every data frame of a request body.
+    pub fn request(inner: B, compression: Compression) -> Self {
+        Self {
+            inner,
+            compression,
+            active: true,
+            is_active: |never| match *never {},
+        }
+    }
+}
+
+impl<B> Compress<B>
+where
+    B: Body<Control: AsRef<ResponseKind>>,
+{
+    /// Compress every data frame of a successful response segment.
+    ///
+    /// Data frames following an error control frame are passed through unchanged.
+    pub fn response(inner: B, compression: Compression) -> Self {
+        Self {
+            inner,
+            compression,
+            active: true,
+            is_active: is_ok_segment,
+        }
+    }
+}
+
+impl<B> Compress<B>
+where
+    B: Body,
+{
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Body for Compress<B>
+where
+    B: Body,
+{
+    type Control = B::Control;
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<BodyFrameResult<Self>>> {
+        let this = self.project();
+
+        let Some(result) = ready!(this.inner.poll_frame(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        let frame = match result {
+            Ok(Frame::Data(mut data)) => {
+                let data = data.copy_to_bytes(data.remaining());
+
+                if *this.active && !this.compression.is_none() && !data.is_empty() {
+                    Frame::new_data(compress_blocks(*this.compression, &data))
+                } else {
+                    Frame::new_data(data)
+                }
+            }
+            Ok(Frame::Control(control)) => {
+                *this.active = (this.is_active)(&control);
+
+                Frame::new_control(control)
+            }
+            Err(error) => return Poll::Ready(Some(Err(error))),
+        };
+
+        Poll::Ready(Some(Ok(frame)))
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.compression.is_none() {
+            self.inner.size_hint()
+        } else {
+            // the size of the compressed data cannot be known in advance
+            SizeHint::default()
+        }
+    }
+}
+
+enum Block {
+    // The buffer does not yet contain a complete block.
+    Pending,
+    Ready(Bytes),
+    Corrupted,
+}
+
+pin_project_lite::pin_project! {
+    /// A body that decompresses the blocks created by [`Compress`].
+    ///
+    /// If a block cannot be decompressed the body is terminated and its state is set to
+    /// [`BodyState::Incomplete`].
+    #[derive(Debug)]
+    pub struct Decompress<B>
+    where
+        B: Body,
+    {
+        #[pin]
+        inner: B,
+
+        compression: Compression,
+        buffer: BytesMut,
+        corrupted: bool,
+
+        active: bool,
+        is_active: fn(&B::Control) -> bool,
+    }
+}
+
+impl<B> Decompress<B>
+where
+    B: Body<Control = !>,
+{
+    /// Decompress the data of a request body.
+    pub fn request(inner: B, compression: Compression) -> Self {
+        Self {
+            inner,
+            compression,
+            buffer: BytesMut::new(),
+            corrupted: false,
+            active: true,
+            is_active: |never| match *never {},
+        }
+    }
+}
+
+impl<B> Decompress<B>
+where
+    B: Body<Control: AsRef<ResponseKind>>,
+{
+    /// Decompress the data of every successful response segment.
+    pub fn response(inner: B, compression: Compression) -> Self {
+        Self {
+            inner,
+            compression,
+            buffer: BytesMut::new(),
+            corrupted: false,
+            active: true,
+            is_active: is_ok_segment,
+        }
+    }
+}
+
+impl<B> Decompress<B>
+where
+    B: Body,
+{
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    #[expect(
+        clippy::big_endian_bytes,
+        reason = "numbers are always encoded in big-endian in our encoding scheme"
+    )]
+    fn poll_block(self: Pin<&mut Self>) -> Block {
+        let this = self.project();
+
+        let Some(prefix) = this.buffer.first_chunk::<LENGTH_PREFIX>() else {
+            return Block::Pending;
+        };
+        let length = u32::from_be_bytes(*prefix) as usize;
+
+        // Reject oversized blocks before buffering them
+        if length > max_block_length(*this.compression) {
+            return Block::Corrupted;
+        }
+
+        if this.buffer.len() < LENGTH_PREFIX + length {
+            return Block::Pending;
+        }
+
+        let mut block = this.buffer.split_to(LENGTH_PREFIX + length);
+        block.advance(LENGTH_PREFIX);
+
+        decompress_block(*this.compression, &block).map_or(Block::Corrupted, Block::Ready)
+    }
+
+    fn is_decompressing(&self) -> bool {
+        self.active && !self.compression.is_none()
+    }
+}
+
+impl<B> Body for Decompress<B>
+where
+    B: Body,
+{
+    type Control = B::Control;
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<BodyFrameResult<Self>>> {
+        loop {
+            if self.corrupted {
+                return Poll::Ready(None);
+            }
+
+            if self.is_decompressing() {
+                match self.as_mut().poll_block() {
+                    Block::Ready(data) => return Poll::Ready(Some(Ok(Frame::new_data(data)))),
+                    Block::Corrupted => {
+                        *self.as_mut().project().corrupted = true;
+                        return Poll::Ready(None);
+                    }
+                    Block::Pending => {}
+                }
+            }
+
+            let this = self.as_mut().project();
+
+            let Some(result) = ready!(this.inner.poll_frame(cx)) else {
+                if !this.buffer.is_empty() {
+                    // any remaining bytes are a partial block, which we cannot decompress
+                    *this.corrupted = true;
+                }
+
+                return Poll::Ready(None);
+            };
+
+            match result {
+                Ok(Frame::Data(mut data)) => {
+                    if *this.active && !this.compression.is_none() {
+                        this.buffer.put(data);
+                    } else {
+                        let data = data.copy_to_bytes(data.remaining());
+                        return Poll::Ready(Some(Ok(Frame::new_data(data))));
+                    }
+                }
+                Ok(Frame::Control(control)) => {
+                    if !this.buffer.is_empty() {
+                        // a segment must only ever contain complete blocks
+                        *this.corrupted = true;
+                    }
+
+                    *this.active = (this.is_active)(&control);
+
+                    return Poll::Ready(Some(Ok(Frame::new_control(control))));
+                }
+                Err(error) => return Poll::Ready(Some(Err(error))),
+            }
+        }
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        if self.corrupted {
+            Some(BodyState::Incomplete)
+        } else {
+            self.inner.state()
+        }
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if self.compression.is_none() {
+            self.inner.size_hint()
+        } else {
+            SizeHint::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{assert_matches::assert_matches, task::Poll};
+
+    use bytes::Bytes;
+    use futures::stream;
+    use harpc_types::{
+        compression::Compression, error_code::ErrorCode, response_kind::ResponseKind,
+    };
+
+    use super::{Compress, Decompress, MAX_BLOCK_SIZE};
+    use crate::body::{Body, BodyState, Frame, stream::StreamBody, test::poll_frame_unpin};
+
+    const EXPECTED: &[u8] = b"hello, world, hello, world, hello, world";
+
+    fn iter_body<I>(iter: I) -> impl Body<Data = Bytes, Control = !, Error = !> + Unpin
+    where
+        I: IntoIterator<Item: Into<Bytes>>,
+    {
+        StreamBody::new(stream::iter(
+            iter.into_iter().map(Into::into).map(Frame::Data).map(Ok),
+        ))
+    }
+
+    fn collect_data<B>(body: &mut B) -> Vec<u8>
+    where
+        B: Body<Data = Bytes, Error = !> + Unpin,
+    {
+        let mut output = Vec::new();
+
+        while let Poll::Ready(Some(Ok(frame))) = poll_frame_unpin(body) {
+            if let Frame::Data(data) = frame {
+                output.extend_from_slice(&data);
+            }
+        }
+
+        output
+    }
+
+    #[test]
+    fn roundtrip() {
+        for compression in [Compression::None, Compression::Zstd, Compression::Lz4] {
+            let compress = Compress::request(iter_body([EXPECTED, EXPECTED]), compression);
+            let mut body = Decompress::request(compress, compression);
+
+            let output = collect_data(&mut body);
+            assert_eq!(output, [EXPECTED, EXPECTED].concat(), "{compression}");
+            assert_eq!(body.state(), Some(BodyState::Complete));
+        }
+    }
+
+    #[test]
+    fn roundtrip_split_blocks() {
+        for compression in [Compression::Zstd, Compression::Lz4] {
+            let mut compress = Compress::request(iter_body([EXPECTED, EXPECTED]), compression);
+            let compressed = collect_data(&mut compress);
+
+            // the network layer may split the blocks at arbitrary positions
+            let chunks: Vec<_> = compressed.chunks(3).map(Bytes::copy_from_slice).collect();
+
+            let mut body = Decompress::request(iter_body(chunks), compression);
+
+            let output = collect_data(&mut body);
+            assert_eq!(output, [EXPECTED, EXPECTED].concat(), "{compression}");
+            assert_eq!(body.state(), Some(BodyState::Complete));
+        }
+    }
+
+    #[test]
+    fn none_passes_through() {
+        let mut body = Compress::request(iter_body([EXPECTED]), Compression::None);
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data.as_ref() == EXPECTED);
+    }
+
+    #[test]
+    fn decompress_corrupted() {
+        let mut body = Decompress::request(
+            iter_body([b"\x00\x00\x00\x04\xFF\xFF\xFF\xFF" as &[_]]),
+            Compression::Zstd,
+        );
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(None));
+        assert_eq!(body.state(), Some(BodyState::Incomplete));
+    }
+
+    #[test]
+    fn decompress_partial_block() {
+        let mut compress = Compress::request(iter_body([EXPECTED]), Compression::Lz4);
+        let compressed = collect_data(&mut compress);
+
+        let mut body = Decompress::request(
+            iter_body([Bytes::copy_from_slice(&compressed[..compressed.len() - 1])]),
+            Compression::Lz4,
+        );
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(None));
+        assert_eq!(body.state(), Some(BodyState::Incomplete));
+    }
+
+    #[test]
+    fn roundtrip_large_frame() {
+        let data = vec![0xAB; MAX_BLOCK_SIZE + 1];
+
+        for compression in [Compression::Zstd, Compression::Lz4] {
+            let compress = Compress::request(iter_body([data.clone()]), compression);
+            let mut body = Decompress::request(compress, compression);
+
+            let output = collect_data(&mut body);
+            assert_eq!(output, data, "{compression}");
+            assert_eq!(body.state(), Some(BodyState::Complete));
+        }
+    }
+
+    #[test]
+    fn decompress_oversized_block_length() {
+        for compression in [Compression::Zstd, Compression::Lz4] {
+            // the block is rejected from its length prefix alone, before any of it is received
+            let mut body =
+                Decompress::request(iter_body([b"\xFF\xFF\xFF\xFF" as &[_]]), compression);
+
+            let frame = poll_frame_unpin(&mut body);
+            assert_matches!(frame, Poll::Ready(None));
+            assert_eq!(body.state(), Some(BodyState::Incomplete), "{compression}");
+        }
+    }
+
+    #[expect(
+        clippy::big_endian_bytes,
+        reason = "numbers are always encoded in big-endian in our encoding scheme"
+    )]
+    #[expect(
+        clippy::little_endian_bytes,
+        reason = "lz4 prefixes the size in little-endian"
+    )]
+    #[test]
+    fn decompress_oversized_block_data() {
+        let data = vec![0; MAX_BLOCK_SIZE + 1];
+
+        let zstd = zstd::bulk::compress(&data, zstd::DEFAULT_COMPRESSION_LEVEL)
+            .expect("should be able to compress");
+        // lz4 trusts the size prefix to allocate the output buffer
+        let lz4 = [
+            &u32::try_from(MAX_BLOCK_SIZE + 1)
+                .expect("should fit into u32")
+                .to_le_bytes() as &[_],
+            &lz4_flex::block::compress(&[0; 16]),
+        ]
+        .concat();
+
+        for (compression, block) in [(Compression::Zstd, zstd), (Compression::Lz4, lz4)] {
+            let length = u32::try_from(block.len()).expect("should fit into u32");
+            let frame = [&length.to_be_bytes() as &[_], &block].concat();
+
+            let mut body = Decompress::request(iter_body([frame]), compression);
+
+            let frame = poll_frame_unpin(&mut body);
+            assert_matches!(frame, Poll::Ready(None));
+            assert_eq!(body.state(), Some(BodyState::Incomplete), "{compression}");
+        }
+    }
+
+    #[test]
+    fn response_error_uncompressed() {
+        let error = Bytes::from_static(b"\x00\x00\x00\x05error");
+
+        let iter = stream::iter([
+            Result::<_, !>::Ok(Frame::Control(ResponseKind::Ok)),
+            Ok(Frame::Data(Bytes::from_static(EXPECTED))),
+            Ok(Frame::Control(ResponseKind::Err(
+                ErrorCode::INTERNAL_SERVER_ERROR,
+            ))),
+            Ok(Frame::Data(error.clone())),
+        ]);
+
+        let mut compress = Compress::response(StreamBody::new(iter), Compression::Zstd);
+
+        assert_matches!(
+            poll_frame_unpin(&mut compress),
+            Poll::Ready(Some(Ok(Frame::Control(ResponseKind::Ok))))
+        );
+        assert_matches!(
+            poll_frame_unpin(&mut compress),
+            Poll::Ready(Some(Ok(Frame::Data(data)))) if data.as_ref() != EXPECTED
+        );
+        assert_matches!(
+            poll_frame_unpin(&mut compress),
+            Poll::Ready(Some(Ok(Frame::Control(ResponseKind::Err(_)))))
+        );
+        assert_matches!(
+            poll_frame_unpin(&mut compress),
+            Poll::Ready(Some(Ok(Frame::Data(data)))) if data == error
+        );
+    }
+}
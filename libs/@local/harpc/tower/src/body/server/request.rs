@@ -6,22 +6,16 @@ use core::{
 use bytes::Bytes;
 use futures::StreamExt as _;
 use harpc_net::session::server::transaction::TransactionStream;
+use harpc_types::compression::Compression;
 
-use crate::body::{Body, BodyFrameResult, BodyState, Frame};
+use crate::body::{Body, BodyFrameResult, BodyState, Frame, compression::Decompress};
 
 #[derive(Debug)]
-pub struct RequestBody {
+struct TransactionBody {
     inner: TransactionStream,
 }
 
-impl RequestBody {
-    #[must_use]
-    pub const fn new(inner: TransactionStream) -> Self {
-        Self { inner }
-    }
-}
-
-impl Body for RequestBody {
+impl Body for TransactionBody {
     type Control = !;
     type Data = Bytes;
     type Error = !;
@@ -47,3 +41,40 @@ impl Body for RequestBody {
         })
     }
 }
+
+#[derive(Debug)]
+pub struct RequestBody {
+    inner: Decompress<TransactionBody>,
+}
+
+impl RequestBody {
+    #[must_use]
+    pub fn new(inner: TransactionStream) -> Self {
+        Self::with_compression(inner, Compression::None)
+    }
+
+    /// Create a request body, which decompresses the payload using the given [`Compression`].
+    #[must_use]
+    pub fn with_compression(inner: TransactionStream, compression: Compression) -> Self {
+        Self {
+            inner: Decompress::request(TransactionBody { inner }, compression),
+        }
+    }
+}
+
+impl Body for RequestBody {
+    type Control = !;
+    type Data = Bytes;
+    type Error = !;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<BodyFrameResult<Self>>> {
+        Pin::new(&mut self.inner).poll_frame(cx)
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+}
@@ -0,0 +1,182 @@
+use core::task::{Context, Poll};
+
+use futures::TryFutureExt as _;
+use harpc_types::{compression::Compression, response_kind::ResponseKind};
+use tower::{Layer, Service};
+
+use crate::{
+    body::{
+        Body,
+        compression::{Compress, Decompress},
+    },
+    request::Request,
+    response::Response,
+};
+
+/// Compression algorithms negotiated for a single transaction.
+///
+/// On the client this is inserted as an extension into every request passing through the
+/// [`CompressionService`], a request may carry the extension beforehand to override the defaults
+/// of the layer. On the server this is inserted into every incoming request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CompressionOptions {
+    /// Compression algorithm of the request payload.
+    pub request: Compression,
+    /// Compression algorithm of successful response payloads.
+    pub response: Compression,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CompressionLayer {
+    options: CompressionOptions,
+}
+
+impl CompressionLayer {
+    #[must_use]
+    pub const fn new(options: CompressionOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<S> Layer<S> for CompressionLayer {
+    type Service = CompressionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CompressionService {
+            inner,
+            options: self.options,
+        }
+    }
+}
+
+/// Client service, which compresses the request and decompresses the response.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CompressionService<S> {
+    inner: S,
+    options: CompressionOptions,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for CompressionService<S>
+where
+    S: Service<Request<Compress<ReqBody>>, Response = Response<ResBody>>,
+    ReqBody: Body<Control = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
+{
+    type Error = S::Error;
+    type Response = Response<Decompress<ResBody>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let options = *req.extensions_mut().get_or_insert(self.options);
+
+        let req = req.map_body(|body| Compress::request(body, options.request));
+
+        self.inner.call(req).map_ok(move |response| {
+            response.map_body(|body| Decompress::response(body, options.response))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::assert_matches::assert_matches;
+
+    use bytes::Bytes;
+    use harpc_types::{compression::Compression, response_kind::ResponseKind};
+    use tower::{Layer as _, ServiceExt as _, service_fn};
+
+    use super::{CompressionLayer, CompressionOptions};
+    use crate::{
+        body::{
+            BodyExt as _, Frame,
+            compression::{Compress, Decompress},
+            full::Full,
+        },
+        layer::error::test::{BODY, request},
+        request::Request,
+        response::{self, Response},
+        test::StaticBody,
+    };
+
+    const OPTIONS: CompressionOptions = CompressionOptions {
+        request: Compression::Zstd,
+        response: Compression::Lz4,
+    };
+
+    #[tokio::test]
+    async fn roundtrip() {
+        let service = CompressionLayer::new(OPTIONS).layer(service_fn(
+            |req: Request<Compress<Full<Bytes>>>| async move {
+                assert_eq!(req.extensions().get::<CompressionOptions>(), Some(&OPTIONS));
+
+                let session = req.session();
+                let mut body = Decompress::request(req.into_body(), Compression::Zstd);
+
+                let frame = body.frame().await;
+                assert_matches!(frame, Some(Ok(Frame::Data(data))) if data.as_ref() == BODY);
+
+                let body = StaticBody::<Bytes, ResponseKind, !>::new([
+                    Ok(Frame::new_control(ResponseKind::Ok)),
+                    Ok(Frame::new_data(Bytes::from_static(BODY))),
+                ]);
+
+                Ok::<_, !>(Response::from_parts(
+                    response::Parts::new(session),
+                    Compress::response(body, Compression::Lz4),
+                ))
+            },
+        ));
+
+        let Ok(response) = service.oneshot(request()).await;
+        let mut body = response.into_body();
+
+        let frame = body.frame().await;
+        assert_matches!(frame, Some(Ok(Frame::Control(ResponseKind::Ok))));
+
+        let frame = body.frame().await;
+        assert_matches!(frame, Some(Ok(Frame::Data(data))) if data.as_ref() == BODY);
+
+        let frame = body.frame().await;
+        assert_matches!(frame, None);
+    }
+
+    #[tokio::test]
+    async fn extension_overrides_options() {
+        let options = CompressionOptions {
+            request: Compression::None,
+            response: Compression::None,
+        };
+
+        let service = CompressionLayer::new(OPTIONS).layer(service_fn(
+            move |req: Request<Compress<Full<Bytes>>>| async move {
+                assert_eq!(req.extensions().get::<CompressionOptions>(), Some(&options));
+
+                let session = req.session();
+                let mut body = req.into_body();
+
+                // no compression has been applied to the request
+                let frame = body.frame().await;
+                assert_matches!(frame, Some(Ok(Frame::Data(data))) if data.as_ref() == BODY);
+
+                Ok::<_, !>(Response::from_parts(
+                    response::Parts::new(session),
+                    StaticBody::<Bytes, ResponseKind, !>::new([]),
+                ))
+            },
+        ));
+
+        let mut request = request();
+        request.extensions_mut().insert(options);
+
+        let Ok(response) = service.oneshot(request).await;
+        let mut body = response.into_body();
+
+        let frame = body.frame().await;
+        assert_matches!(frame, None);
+    }
+}
@@ -12,7 +12,8 @@ use harpc_types::{error_code::ErrorCode, response_kind::ResponseKind};
 use tower::{Layer, Service};
 
 use crate::{
-    body::{Body, Frame},
+    body::{Body, Frame, compression::Compress},
+    layer::compression::CompressionOptions,
     request::Request,
     response::Response,
 };
@@ -186,9 +187,10 @@ pub struct PackService<S> {
 impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for PackService<S>
 where
     S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
 {
     type Error = S::Error;
-    type Response = Pack<ResBody>;
+    type Response = Pack<Compress<ResBody>>;
 
     type Future = impl Future<Output = Result<Self::Response, S::Error>>;
 
@@ -197,9 +199,16 @@ where
     }
 
     fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
-        self.inner
-            .call(req)
-            .map(|result| result.map(|response| Pack::new(response.into_body())))
+        // successful responses are compressed using the algorithm requested by the client
+        let compression = req
+            .extensions()
+            .get::<CompressionOptions>()
+            .map(|options| options.response)
+            .unwrap_or_default();
+
+        self.inner.call(req).map(move |result| {
+            result.map(|response| Pack::new(Compress::response(response.into_body(), compression)))
+        })
     }
 }
 
@@ -1,7 +1,41 @@
+use core::time::Duration;
+
 use harpc_net::session::server::{SessionId, transaction::TransactionContext};
 use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use tokio::time::Instant;
+
+use crate::{extensions::Extensions, layer::compression::CompressionOptions};
+
+/// Point in time at which a request is cancelled, if it hasn't completed yet.
+///
+/// On the server this is inserted as an extension into every request for which the client has set
+/// a deadline. On the client a request may carry the extension to set the deadline of the call.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline(Instant);
 
-use crate::extensions::Extensions;
+impl Deadline {
+    #[must_use]
+    pub const fn new(instant: Instant) -> Self {
+        Self(instant)
+    }
+
+    /// Create a deadline, which is exceeded after the given duration has elapsed.
+    #[must_use]
+    pub fn after(duration: Duration) -> Self {
+        Self(Instant::now() + duration)
+    }
+
+    #[must_use]
+    pub const fn instant(self) -> Instant {
+        self.0
+    }
+
+    /// Time remaining until the deadline is exceeded, zero if it already has been.
+    #[must_use]
+    pub fn remaining(self) -> Duration {
+        self.0.saturating_duration_since(Instant::now())
+    }
+}
 
 /// Component parts of a harpc `Request`.
 #[derive(Debug, Clone)]
@@ -15,13 +49,30 @@ pub struct Parts {
 }
 
 impl Parts {
+    /// Create the parts of a request from a transaction.
+    ///
+    /// The negotiated [`CompressionOptions`] and the [`Encoding`] of the request are inserted as
+    /// extensions, as well as the [`Deadline`] of the transaction, if the client has set one.
+    ///
+    /// [`Encoding`]: harpc_types::encoding::Encoding
     #[must_use]
     pub fn from_transaction(context: &TransactionContext) -> Self {
+        let mut extensions = Extensions::new();
+        extensions.insert(CompressionOptions {
+            request: context.compression(),
+            response: context.response_compression(),
+        });
+        extensions.insert(context.encoding());
+
+        if let Some(deadline) = context.deadline() {
+            extensions.insert(Deadline::new(deadline));
+        }
+
         Self {
             subsystem: context.subsystem(),
             procedure: context.procedure(),
             session: context.session(),
-            extensions: Extensions::new(),
+            extensions,
         }
     }
 }
@@ -0,0 +1,29 @@
+use core::fmt::{self, Display, Formatter};
+
+/// Compression algorithm applied to the payload of a transaction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub enum Compression {
+    #[default]
+    None,
+    Zstd,
+    Lz4,
+}
+
+impl Compression {
+    #[must_use]
+    pub const fn is_none(self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => fmt.write_str("none"),
+            Self::Zstd => fmt.write_str("zstd"),
+            Self::Lz4 => fmt.write_str("lz4"),
+        }
+    }
+}
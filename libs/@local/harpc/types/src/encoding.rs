@@ -0,0 +1,48 @@
+use core::fmt::{self, Display, Formatter};
+
+/// Encoding of the payload of a transaction.
+///
+/// The encoding is chosen by the client for every request and determines the codec used to
+/// decode the request and encode the response. Encodings are identified by a single byte on the
+/// wire, values that are not known to this version are retained, so that a server is able to
+/// reject them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct Encoding(u8);
+
+impl Encoding {
+    /// Length-delimited CBOR values.
+    pub const CBOR: Self = Self(0x01);
+    /// Records separated by the ASCII record separator, each record is a JSON value.
+    pub const JSON: Self = Self(0x00);
+    /// Length-delimited MessagePack values.
+    pub const MESSAGE_PACK: Self = Self(0x02);
+
+    #[must_use]
+    pub const fn new(value: u8) -> Self {
+        Self(value)
+    }
+
+    #[must_use]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for Encoding {
+    fn default() -> Self {
+        Self::JSON
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::JSON => fmt.write_str("json"),
+            Self::CBOR => fmt.write_str("cbor"),
+            Self::MESSAGE_PACK => fmt.write_str("msgpack"),
+            Self(value) => write!(fmt, "unknown ({value:#04X})"),
+        }
+    }
+}
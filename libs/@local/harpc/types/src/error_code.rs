@@ -99,7 +99,11 @@ define_error_code_consts! {
         /// The HTTP equivalent is 403 Forbidden.
         FORBIDDEN,
         /// The amount of items in the request stream does not match the expected amount.
-        REQUEST_EXPECTED_ITEM_COUNT_MISMATCH
+        REQUEST_EXPECTED_ITEM_COUNT_MISMATCH,
+        /// The encoding of the request is not supported by the server.
+        ///
+        /// The HTTP equivalent is 415 Unsupported Media Type.
+        UNSUPPORTED_ENCODING
     ],
     // 0xFF_xx = server errors
     /// Errors that occur in a session and are issued by the server.
@@ -122,7 +126,12 @@ define_error_code_consts! {
         ///
         /// The client sent too many packets that haven't been processed by the server yet,
         /// which lead to packets dropping and the transaction being cancelled.
-        TRANSACTION_LAGGING
+        TRANSACTION_LAGGING,
+        /// Transaction deadline exceeded.
+        ///
+        /// The transaction did not complete before the deadline set by the client and has been
+        /// cancelled.
+        TRANSACTION_DEADLINE_EXCEEDED
     ],
     /// Errors that occur due to malformed payloads in the tower layer.
     0xFF_10 => [
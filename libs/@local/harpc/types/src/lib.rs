@@ -4,6 +4,8 @@
 #![cfg_attr(doc, doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd"))]
 #![feature(macro_metavar_expr, never_type)]
 
+pub mod compression;
+pub mod encoding;
 pub mod error_code;
 pub mod procedure;
 pub mod response_kind;
@@ -53,6 +53,7 @@
 //! - request-header (encode only)
 //! - request-begin (encode only)
 //! - request-frame (encode only)
+//! - request-cancel (encode only)
 //! - request (encode only)
 //! - response-header (decode only)
 //! - response-begin (decode only)
@@ -67,7 +68,10 @@ use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
 use bytes::{Bytes, BytesMut};
 use harpc_wire_protocol::{
     codec::{Buffer, Decode, Encode},
-    request::{Request, begin::RequestBegin, frame::RequestFrame, header::RequestHeader},
+    request::{
+        Request, begin::RequestBegin, cancel::RequestCancel, frame::RequestFrame,
+        header::RequestHeader,
+    },
     response::{
         Response, begin::ResponseBegin, body::ResponseBody, frame::ResponseFrame,
         header::ResponseHeader,
@@ -130,7 +134,7 @@ fn main() {
         "encode" => {
             let r#type = args.pop().expect(
                 "second argument should be one of request-header, request-begin, request-frame, \
-                 or request",
+                 request-cancel, or request",
             );
 
             match &*r#type.to_lowercase() {
@@ -143,6 +147,9 @@ fn main() {
                 "request-frame" => {
                     validate_encode::<RequestFrame>(&mut args);
                 }
+                "request-cancel" => {
+                    validate_encode::<RequestCancel>(&mut args);
+                }
                 // We cannot test the `request-body` separately as it needs context from the
                 // `request` to work.
                 "request" => {
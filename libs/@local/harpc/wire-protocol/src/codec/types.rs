@@ -1,6 +1,8 @@
 use bytes::{Buf, BufMut};
 use error_stack::Report;
-use harpc_types::{procedure::ProcedureId, subsystem::SubsystemId, version::Version};
+use harpc_types::{
+    encoding::Encoding, procedure::ProcedureId, subsystem::SubsystemId, version::Version,
+};
 
 use super::{Buffer, BufferError, Decode};
 use crate::codec::Encode;
@@ -80,11 +82,34 @@ impl Decode for SubsystemId {
     }
 }
 
+impl Encode for Encoding {
+    type Error = BufferError;
+
+    fn encode<B>(&self, buffer: &mut Buffer<B>) -> Result<(), Report<Self::Error>>
+    where
+        B: BufMut,
+    {
+        self.value().encode(buffer)
+    }
+}
+
+impl Decode for Encoding {
+    type Context = ();
+    type Error = BufferError;
+
+    fn decode<B>(buffer: &mut Buffer<B>, (): ()) -> Result<Self, Report<Self::Error>>
+    where
+        B: Buf,
+    {
+        u8::decode(buffer, ()).map(Self::new)
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![expect(clippy::needless_raw_strings)]
     use expect_test::expect;
-    use harpc_types::{subsystem::SubsystemId, version::Version};
+    use harpc_types::{encoding::Encoding, subsystem::SubsystemId, version::Version};
 
     use crate::codec::test::{assert_codec, assert_decode, assert_encode};
 
@@ -132,6 +157,27 @@ mod test {
         assert_codec(&id, ());
     }
 
+    #[test]
+    fn encode_encoding() {
+        assert_encode(
+            &Encoding::CBOR,
+            expect![[r#"
+                0x01
+            "#]],
+        );
+    }
+
+    #[test]
+    fn decode_encoding() {
+        assert_decode(&[0x02_u8] as &[_], &Encoding::MESSAGE_PACK, ());
+    }
+
+    #[test_strategy::proptest]
+    #[cfg_attr(miri, ignore)]
+    fn codec_encoding(encoding: Encoding) {
+        assert_codec(&encoding, ());
+    }
+
     #[test_strategy::proptest]
     #[cfg_attr(miri, ignore)]
     fn codec_version(version: Version) {
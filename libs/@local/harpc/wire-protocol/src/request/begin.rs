@@ -1,7 +1,10 @@
 use bytes::{Buf, BufMut};
 use error_stack::{Report, ResultExt as _};
-use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use harpc_types::{
+    encoding::Encoding, procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor,
+};
 
+use super::deadline::RequestDeadline;
 use crate::{
     codec::{Buffer, BufferError, Decode, Encode},
     payload::Payload,
@@ -18,6 +21,10 @@ pub struct RequestBegin {
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
 
+    pub deadline: Option<RequestDeadline>,
+    /// Encoding of the payload of the request, the response is encoded in the same encoding.
+    pub encoding: Encoding,
+
     pub payload: Payload,
 }
 
@@ -36,9 +43,17 @@ impl Encode for RequestBegin {
             .encode(buffer)
             .change_context(RequestBeginEncodeError)?;
 
-        // write 13 empty bytes (reserved for future use)
+        self.deadline
+            .encode(buffer)
+            .change_context(RequestBeginEncodeError)?;
+
+        self.encoding
+            .encode(buffer)
+            .change_context(RequestBeginEncodeError)?;
+
+        // write 8 empty bytes (reserved for future use)
         buffer
-            .push_repeat(0, 13)
+            .push_repeat(0, 8)
             .change_context(RequestBeginEncodeError)?;
 
         self.payload
@@ -58,14 +73,19 @@ impl Decode for RequestBegin {
         let subsystem = SubsystemDescriptor::decode(buffer, ())?;
         let procedure = ProcedureDescriptor::decode(buffer, ())?;
 
-        // skip 13 bytes (reserved for future use)
-        buffer.discard(13)?;
+        let deadline = Option::<RequestDeadline>::decode(buffer, ())?;
+        let encoding = Encoding::decode(buffer, ())?;
+
+        // skip 8 bytes (reserved for future use)
+        buffer.discard(8)?;
 
         let payload = Payload::decode(buffer, ())?;
 
         Ok(Self {
             subsystem,
             procedure,
+            deadline,
+            encoding,
             payload,
         })
     }
@@ -73,8 +93,11 @@ impl Decode for RequestBegin {
 
 #[cfg(test)]
 mod test {
+    use core::num::NonZero;
+
     use expect_test::expect;
     use harpc_types::{
+        encoding::Encoding,
         procedure::{ProcedureDescriptor, ProcedureId},
         subsystem::{SubsystemDescriptor, SubsystemId},
         version::Version,
@@ -83,7 +106,7 @@ mod test {
     use crate::{
         codec::test::{assert_codec, assert_decode, assert_encode},
         payload::Payload,
-        request::begin::RequestBegin,
+        request::{begin::RequestBegin, deadline::RequestDeadline},
     };
 
     static EXAMPLE_REQUEST: RequestBegin = RequestBegin {
@@ -97,6 +120,8 @@ mod test {
         procedure: ProcedureDescriptor {
             id: ProcedureId::new(0x05_06),
         },
+        deadline: None,
+        encoding: Encoding::JSON,
         payload: Payload::from_static(b"Hello, world!"),
     };
 
@@ -136,12 +161,90 @@ mod test {
                 procedure: ProcedureDescriptor {
                     id: ProcedureId::new(0x05_06),
                 },
+                deadline: None,
+                encoding: Encoding::JSON,
                 payload: Payload::from_static(b"Hello, world!"),
             },
             (),
         );
     }
 
+    #[test]
+    fn encode_deadline() {
+        assert_encode(
+            &RequestBegin {
+                deadline: NonZero::new(0x01_F4).map(RequestDeadline::new),
+                ..EXAMPLE_REQUEST.clone()
+            },
+            expect![[r"
+                0x01 0x02 0x03 0x04 0x05 0x06 0x00 0x00 0x01 0xF4 0x00 0x00 0x00 0x00 0x00 0x00
+                0x00 0x00 0x00 0x00 '\r' b'H' b'e' b'l' b'l' b'o' b',' b' ' b'w' b'o' b'r' b'l'
+                b'd' b'!'
+            "]],
+        );
+    }
+
+    #[test]
+    fn decode_deadline() {
+        #[rustfmt::skip]
+        let bytes: &[u8] = &[
+            0x01, 0x02, // subsystem id
+            0x03, 0x04, // subsystem version
+            0x05, 0x06, // procedure id
+            0x00, 0x00, 0x01, 0xF4, // deadline
+            0x00, // encoding
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // reserved
+            0x00, 0x0D, b'H', b'e', b'l', b'l', b'o', b',', b' ', b'w', b'o', b'r', b'l', b'd', b'!',
+        ];
+
+        assert_decode(
+            bytes,
+            &RequestBegin {
+                deadline: NonZero::new(0x01_F4).map(RequestDeadline::new),
+                ..EXAMPLE_REQUEST.clone()
+            },
+            (),
+        );
+    }
+
+    #[test]
+    fn encode_encoding() {
+        assert_encode(
+            &RequestBegin {
+                encoding: Encoding::MESSAGE_PACK,
+                ..EXAMPLE_REQUEST.clone()
+            },
+            expect![[r"
+                0x01 0x02 0x03 0x04 0x05 0x06 0x00 0x00 0x00 0x00 0x02 0x00 0x00 0x00 0x00 0x00
+                0x00 0x00 0x00 0x00 '\r' b'H' b'e' b'l' b'l' b'o' b',' b' ' b'w' b'o' b'r' b'l'
+                b'd' b'!'
+            "]],
+        );
+    }
+
+    #[test]
+    fn decode_encoding() {
+        #[rustfmt::skip]
+        let bytes: &[u8] = &[
+            0x01, 0x02, // subsystem id
+            0x03, 0x04, // subsystem version
+            0x05, 0x06, // procedure id
+            0x00, 0x00, 0x00, 0x00, // deadline
+            0x01, // encoding
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // reserved
+            0x00, 0x0D, b'H', b'e', b'l', b'l', b'o', b',', b' ', b'w', b'o', b'r', b'l', b'd', b'!',
+        ];
+
+        assert_decode(
+            bytes,
+            &RequestBegin {
+                encoding: Encoding::CBOR,
+                ..EXAMPLE_REQUEST.clone()
+            },
+            (),
+        );
+    }
+
     #[test_strategy::proptest]
     #[cfg_attr(miri, ignore)]
     fn codec(request: RequestBegin) {
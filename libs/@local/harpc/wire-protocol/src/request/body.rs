@@ -3,6 +3,7 @@ use error_stack::{Report, ResultExt as _};
 
 use super::{
     begin::RequestBegin,
+    cancel::{self, RequestCancel},
     flags::{RequestFlag, RequestFlags},
     frame::RequestFrame,
 };
@@ -22,6 +23,7 @@ pub struct RequestBodyEncodeError;
 pub enum RequestBody {
     Begin(RequestBegin),
     Frame(RequestFrame),
+    Cancel(RequestCancel),
 }
 
 impl RequestBody {
@@ -29,6 +31,7 @@ impl RequestBody {
         match self {
             Self::Begin(begin) => &begin.payload,
             Self::Frame(frame) => &frame.payload,
+            Self::Cancel(_) => &cancel::EMPTY_PAYLOAD,
         }
     }
 
@@ -36,6 +39,7 @@ impl RequestBody {
         match self {
             Self::Begin(begin) => begin.payload,
             Self::Frame(frame) => frame.payload,
+            Self::Cancel(_) => cancel::EMPTY_PAYLOAD.clone(),
         }
     }
 }
@@ -50,6 +54,7 @@ impl Encode for RequestBody {
         match self {
             Self::Begin(body) => body.encode(buffer).change_context(RequestBodyEncodeError),
             Self::Frame(body) => body.encode(buffer).change_context(RequestBodyEncodeError),
+            Self::Cancel(body) => body.encode(buffer).change_context(RequestBodyEncodeError),
         }
     }
 }
@@ -58,6 +63,7 @@ impl Encode for RequestBody {
 pub enum RequestVariant {
     Begin,
     Frame,
+    Cancel,
 }
 
 impl From<&RequestBody> for RequestVariant {
@@ -65,6 +71,7 @@ impl From<&RequestBody> for RequestVariant {
         match body {
             RequestBody::Begin(_) => Self::Begin,
             RequestBody::Frame(_) => Self::Frame,
+            RequestBody::Cancel(_) => Self::Cancel,
         }
     }
 }
@@ -78,6 +85,8 @@ impl RequestBodyContext {
     pub(super) fn from_flags(flags: RequestFlags) -> Self {
         let variant = if flags.contains(RequestFlag::BeginOfRequest) {
             RequestVariant::Begin
+        } else if flags.contains(RequestFlag::CancelRequest) {
+            RequestVariant::Cancel
         } else {
             RequestVariant::Frame
         };
@@ -100,6 +109,7 @@ impl Decode for RequestBody {
         match context.variant {
             RequestVariant::Begin => RequestBegin::decode(buffer, ()).map(RequestBody::Begin),
             RequestVariant::Frame => RequestFrame::decode(buffer, ()).map(RequestBody::Frame),
+            RequestVariant::Cancel => RequestCancel::decode(buffer, ()).map(RequestBody::Cancel),
         }
     }
 }
@@ -109,6 +119,7 @@ mod test {
     #![expect(clippy::needless_raw_strings)]
     use expect_test::expect;
     use harpc_types::{
+        encoding::Encoding,
         procedure::{ProcedureDescriptor, ProcedureId},
         subsystem::{SubsystemDescriptor, SubsystemId},
         version::Version,
@@ -118,7 +129,9 @@ mod test {
     use crate::{
         codec::test::{assert_codec, assert_decode, assert_encode, encode_value},
         payload::Payload,
-        request::{begin::RequestBegin, body::RequestVariant, frame::RequestFrame},
+        request::{
+            begin::RequestBegin, body::RequestVariant, cancel::RequestCancel, frame::RequestFrame,
+        },
     };
 
     static EXAMPLE_BEGIN: RequestBegin = RequestBegin {
@@ -132,6 +145,8 @@ mod test {
         procedure: ProcedureDescriptor {
             id: ProcedureId::new(0x0506),
         },
+        deadline: None,
+        encoding: Encoding::JSON,
         payload: Payload::from_static(&[0x07, 0x08]),
     };
 
@@ -161,6 +176,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn encode_cancel() {
+        assert_encode(
+            &RequestBody::Cancel(RequestCancel),
+            expect![[r#"
+            0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00
+            0x00 0x00 0x00 0x00 0x00
+        "#]],
+        );
+    }
+
     #[test]
     fn decode_begin() {
         let bytes = encode_value(&EXAMPLE_BEGIN);
@@ -183,6 +209,17 @@ mod test {
         assert_decode(bytes, &RequestBody::Frame(EXAMPLE_FRAME.clone()), context);
     }
 
+    #[test]
+    fn decode_cancel() {
+        let bytes = encode_value(&RequestCancel);
+
+        let context = RequestBodyContext {
+            variant: RequestVariant::Cancel,
+        };
+
+        assert_decode(bytes, &RequestBody::Cancel(RequestCancel), context);
+    }
+
     #[test_strategy::proptest]
     #[cfg_attr(miri, ignore)]
     fn codec(body: RequestBody) {
@@ -0,0 +1,98 @@
+use bytes::{Buf, BufMut};
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    codec::{Buffer, BufferError, Decode, Encode},
+    payload::Payload,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[error("unable to encode request cancel frame")]
+pub struct RequestCancelEncodeError;
+
+/// Request the cancellation of a transaction.
+///
+/// The packet does not carry any payload, any payload sent is ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(test, derive(test_strategy::Arbitrary))]
+pub struct RequestCancel;
+
+pub(super) static EMPTY_PAYLOAD: Payload = Payload::from_static(&[]);
+
+impl Encode for RequestCancel {
+    type Error = RequestCancelEncodeError;
+
+    fn encode<B>(&self, buffer: &mut Buffer<B>) -> Result<(), Report<Self::Error>>
+    where
+        B: BufMut,
+    {
+        // write 19 empty bytes (reserved for future use)
+        buffer
+            .push_repeat(0, 19)
+            .change_context(RequestCancelEncodeError)?;
+
+        EMPTY_PAYLOAD
+            .encode(buffer)
+            .change_context(RequestCancelEncodeError)
+    }
+}
+
+impl Decode for RequestCancel {
+    type Context = ();
+    type Error = BufferError;
+
+    fn decode<B>(buffer: &mut Buffer<B>, (): ()) -> Result<Self, Report<Self::Error>>
+    where
+        B: Buf,
+    {
+        // skip 19 bytes (reserved for future use)
+        buffer.discard(19)?;
+
+        // the payload is ignored, but still needs to be consumed
+        let _payload = Payload::decode(buffer, ())?;
+
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![expect(clippy::needless_raw_strings)]
+    use expect_test::expect;
+
+    use crate::{
+        codec::test::{assert_codec, assert_decode, assert_encode},
+        request::cancel::RequestCancel,
+    };
+
+    #[test]
+    fn encode() {
+        assert_encode(
+            &RequestCancel,
+            expect![[r#"
+                0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00
+                0x00 0x00 0x00 0x00 0x00
+            "#]],
+        );
+    }
+
+    #[test]
+    fn decode() {
+        assert_decode(
+            &[
+                0x00_u8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // reserved
+                0x00, 0x02, 0x01, 0x02, // ignored payload
+            ] as &[_],
+            &RequestCancel,
+            (),
+        );
+    }
+
+    #[test_strategy::proptest]
+    #[cfg_attr(miri, ignore)]
+    fn codec(cancel: RequestCancel) {
+        assert_codec(&cancel, ());
+    }
+}
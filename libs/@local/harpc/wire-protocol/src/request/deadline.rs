@@ -0,0 +1,137 @@
+use core::{num::NonZero, time::Duration};
+
+use bytes::{Buf, BufMut};
+use error_stack::Report;
+
+use crate::codec::{Buffer, BufferError, Decode, Encode};
+
+/// Time the client is willing to wait for a request to complete.
+///
+/// The deadline is relative to the moment the server receives the `Begin` packet of the request
+/// and is transmitted in milliseconds, a value of `0` indicates that no deadline has been set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(test, derive(test_strategy::Arbitrary))]
+pub struct RequestDeadline(NonZero<u32>);
+
+impl RequestDeadline {
+    /// The shortest possible deadline of a single millisecond.
+    pub const MIN: Self = Self(NonZero::<u32>::MIN);
+
+    #[must_use]
+    pub const fn new(milliseconds: NonZero<u32>) -> Self {
+        Self(milliseconds)
+    }
+
+    /// Converts a duration into a deadline.
+    ///
+    /// The duration is rounded up to the next millisecond and saturates at [`u32::MAX`]
+    /// milliseconds. Returns `None` if the duration is zero.
+    #[must_use]
+    pub fn from_duration(duration: Duration) -> Option<Self> {
+        let milliseconds = duration.as_nanos().div_ceil(1_000_000);
+        let milliseconds = u32::try_from(milliseconds).unwrap_or(u32::MAX);
+
+        NonZero::new(milliseconds).map(Self)
+    }
+
+    #[must_use]
+    pub const fn as_millis(self) -> NonZero<u32> {
+        self.0
+    }
+
+    #[must_use]
+    pub fn as_duration(self) -> Duration {
+        Duration::from_millis(u64::from(self.0.get()))
+    }
+}
+
+impl Encode for Option<RequestDeadline> {
+    type Error = BufferError;
+
+    fn encode<B>(&self, buffer: &mut Buffer<B>) -> Result<(), Report<Self::Error>>
+    where
+        B: BufMut,
+    {
+        self.map_or(0, |deadline| deadline.0.get()).encode(buffer)
+    }
+}
+
+impl Decode for Option<RequestDeadline> {
+    type Context = ();
+    type Error = BufferError;
+
+    fn decode<B>(buffer: &mut Buffer<B>, (): ()) -> Result<Self, Report<Self::Error>>
+    where
+        B: Buf,
+    {
+        u32::decode(buffer, ()).map(|value| NonZero::new(value).map(RequestDeadline))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![expect(clippy::needless_raw_strings)]
+    use core::{num::NonZero, time::Duration};
+
+    use expect_test::expect;
+
+    use crate::{
+        codec::test::{assert_codec, assert_decode, assert_encode},
+        request::deadline::RequestDeadline,
+    };
+
+    #[test]
+    fn encode() {
+        assert_encode(
+            &Some(RequestDeadline::new(
+                NonZero::new(0x01_02_03_04).expect("is not zero"),
+            )),
+            expect![[r#"
+                0x01 0x02 0x03 0x04
+            "#]],
+        );
+
+        assert_encode(
+            &None::<RequestDeadline>,
+            expect![[r#"
+                0x00 0x00 0x00 0x00
+            "#]],
+        );
+    }
+
+    #[test]
+    fn decode() {
+        assert_decode(
+            &[0x00_u8, 0x00, 0x01, 0x00] as &[_],
+            &Some(RequestDeadline::new(
+                NonZero::new(0x01_00).expect("is not zero"),
+            )),
+            (),
+        );
+
+        assert_decode(&[0x00_u8, 0x00, 0x00, 0x00] as &[_], &None, ());
+    }
+
+    #[test]
+    fn from_duration() {
+        assert_eq!(RequestDeadline::from_duration(Duration::ZERO), None);
+
+        let deadline =
+            RequestDeadline::from_duration(Duration::from_micros(1)).expect("should be set");
+        assert_eq!(deadline.as_duration(), Duration::from_millis(1));
+
+        let deadline =
+            RequestDeadline::from_duration(Duration::from_millis(1500)).expect("should be set");
+        assert_eq!(deadline.as_duration(), Duration::from_millis(1500));
+
+        let deadline = RequestDeadline::from_duration(Duration::MAX).expect("should be set");
+        assert_eq!(deadline.as_millis().get(), u32::MAX);
+    }
+
+    #[test_strategy::proptest]
+    #[cfg_attr(miri, ignore)]
+    fn codec(deadline: Option<RequestDeadline>) {
+        assert_codec(&deadline, ());
+    }
+}
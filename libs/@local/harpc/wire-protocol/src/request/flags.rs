@@ -1,6 +1,7 @@
 use bytes::{Buf, BufMut};
 use enumflags2::BitFlags;
 use error_stack::Report;
+use harpc_types::compression::Compression;
 
 use super::body::RequestBody;
 use crate::{
@@ -14,8 +15,17 @@ use crate::{
 pub enum RequestFlag {
     // Computed flags
     BeginOfRequest = 0b1000_0000,
+    CancelRequest = 0b0000_1000,
     // Controlled flags
     EndOfRequest = 0b0000_0001,
+    /// The payload of the request is compressed using zstd.
+    ZstdPayload = 0b0000_0010,
+    /// The payload of the request is compressed using lz4.
+    Lz4Payload = 0b0000_0100,
+    /// The client accepts a response payload compressed using zstd.
+    AcceptZstd = 0b0001_0000,
+    /// The client accepts a response payload compressed using lz4.
+    AcceptLz4 = 0b0010_0000,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -33,6 +43,50 @@ impl RequestFlags {
             RequestFlag::BeginOfRequest,
             matches!(body, RequestBody::Begin(_)),
         )
+        .set(
+            RequestFlag::CancelRequest,
+            matches!(body, RequestBody::Cancel(_)),
+        )
+    }
+
+    /// Compression algorithm used for the payload of the request.
+    ///
+    /// If multiple algorithms are set, zstd takes precedence.
+    #[must_use]
+    pub fn compression(self) -> Compression {
+        if self.contains(RequestFlag::ZstdPayload) {
+            Compression::Zstd
+        } else if self.contains(RequestFlag::Lz4Payload) {
+            Compression::Lz4
+        } else {
+            Compression::None
+        }
+    }
+
+    #[must_use]
+    pub fn with_compression(self, compression: Compression) -> Self {
+        self.set(RequestFlag::ZstdPayload, compression == Compression::Zstd)
+            .set(RequestFlag::Lz4Payload, compression == Compression::Lz4)
+    }
+
+    /// Compression algorithm the client would like the response payload to be compressed with.
+    ///
+    /// If multiple algorithms are accepted, zstd takes precedence.
+    #[must_use]
+    pub fn accept(self) -> Compression {
+        if self.contains(RequestFlag::AcceptZstd) {
+            Compression::Zstd
+        } else if self.contains(RequestFlag::AcceptLz4) {
+            Compression::Lz4
+        } else {
+            Compression::None
+        }
+    }
+
+    #[must_use]
+    pub fn with_accept(self, compression: Compression) -> Self {
+        self.set(RequestFlag::AcceptZstd, compression == Compression::Zstd)
+            .set(RequestFlag::AcceptLz4, compression == Compression::Lz4)
     }
 }
 
@@ -89,6 +143,7 @@ impl Decode for RequestFlags {
 mod test {
     #![expect(clippy::needless_raw_strings)]
     use expect_test::expect;
+    use harpc_types::compression::Compression;
 
     use crate::{
         codec::test::{assert_codec, assert_decode, assert_encode},
@@ -150,6 +205,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn compression() {
+        for compression in [Compression::None, Compression::Zstd, Compression::Lz4] {
+            let flags = RequestFlags::from(RequestFlag::EndOfRequest)
+                .with_compression(compression)
+                .with_accept(Compression::Lz4);
+
+            assert_eq!(flags.compression(), compression);
+            assert_eq!(flags.accept(), Compression::Lz4);
+            assert!(flags.contains(RequestFlag::EndOfRequest));
+        }
+
+        assert_encode(
+            &RequestFlags::EMPTY
+                .with_compression(Compression::Zstd)
+                .with_accept(Compression::Zstd),
+            expect![[r#"
+                0x12
+            "#]],
+        );
+    }
+
     #[test_strategy::proptest]
     #[cfg_attr(miri, ignore)]
     fn codec(flags: RequestFlags) {
@@ -10,6 +10,8 @@ use crate::codec::{Buffer, Decode, Encode};
 
 pub mod begin;
 pub mod body;
+pub mod cancel;
+pub mod deadline;
 pub mod flags;
 pub mod frame;
 pub mod header;
@@ -44,7 +46,7 @@ pub struct RequestDecodeError;
 /// 0                   1                   2                   3
 /// 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
 /// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
-/// |  Magic  |P|Reque. |F|S. |S. |P. |        Reserved         |P. |
+/// |  Magic  |P|Reque. |F|S. |S. |P. |Deadl. |    Reserved     |P. |
 /// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
 /// |                              ...                              |
 /// +                            Payload                            +
@@ -58,7 +60,8 @@ pub struct RequestDecodeError;
 /// * Subsystem Id (2 bytes)
 /// * Subsystem Version (2 bytes)
 /// * Procedure Id (2 bytes)
-/// * Reserved (13 bytes)
+/// * Deadline (4 bytes)
+/// * Reserved (9 bytes)
 /// * Payload Length (2 bytes)
 /// * Payload (up to 65504 bytes)
 /// total 32 bytes to 64 KiB
@@ -67,6 +70,10 @@ pub struct RequestDecodeError;
 /// The payload is of variable size and specified by the `Payload Length` field.
 /// Packets need to set the `BeginOfRequest` bit in the `Flags` field.
 ///
+/// The `Deadline` field specifies the time in milliseconds the client is willing to wait for the
+/// request to complete, starting from the moment the server receives the packet. A value of `0`
+/// indicates that no deadline has been set.
+///
 /// # `Frame` Packet
 ///
 /// The layout of a `Frame` packet is as follows:
@@ -91,6 +98,21 @@ pub struct RequestDecodeError;
 /// * Payload (up to 65504 bytes)
 /// total 32 bytes to 64 KiB
 /// ```
+///
+/// # `Cancel` Packet
+///
+/// The layout of a `Cancel` packet is identical to the one of a `Frame` packet, any payload is
+/// ignored. Packets need to set the `CancelRequest` bit in the `Flags` field.
+///
+/// Once received the server cancels the transaction, no further packets for the request are
+/// processed.
+///
+/// # Compression
+///
+/// The `Flags` field of every packet carries the compression algorithm of the request payload
+/// (`ZstdPayload`/`Lz4Payload`) as well as the algorithm the client would like the response payload
+/// to be compressed with (`AcceptZstd`/`AcceptLz4`). The server is free to ignore the latter and
+/// states the algorithm actually used in the flags of the response.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(test, derive(test_strategy::Arbitrary))]
@@ -137,6 +159,7 @@ mod test {
     #![expect(clippy::needless_raw_strings)]
     use expect_test::expect;
     use harpc_types::{
+        encoding::Encoding,
         procedure::{ProcedureDescriptor, ProcedureId},
         subsystem::{SubsystemDescriptor, SubsystemId},
         version::Version,
@@ -152,6 +175,7 @@ mod test {
             Request,
             begin::RequestBegin,
             body::RequestBody,
+            cancel::RequestCancel,
             flags::{RequestFlag, RequestFlags},
             frame::RequestFrame,
             header::RequestHeader,
@@ -213,6 +237,9 @@ mod test {
                     procedure: ProcedureDescriptor {
                         id: ProcedureId::new(0x05_06),
                     },
+                    deadline: None,
+
+                    encoding: Encoding::JSON,
 
                     payload: Payload::from_static(b"hello world"),
                 }),
@@ -241,6 +268,9 @@ mod test {
                     procedure: ProcedureDescriptor {
                         id: ProcedureId::new(0x05_06),
                     },
+                    deadline: None,
+
+                    encoding: Encoding::JSON,
 
                     payload: Payload::from_static(b"hello world"),
                 }),
@@ -290,6 +320,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn encode_cancel() {
+        assert_encode(
+            &Request {
+                header: EXAMPLE_HEADER,
+                body: RequestBody::Cancel(RequestCancel),
+            },
+            expect![[r#"
+                b'h' b'a' b'r' b'p' b'c' 0x01 0x89 0xAB 0xCD 0xEF 0x08 0x00 0x00 0x00 0x00 0x00
+                0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00
+            "#]],
+        );
+    }
+
     #[test]
     fn decode_begin() {
         assert_decode(
@@ -310,6 +354,9 @@ mod test {
                     procedure: ProcedureDescriptor {
                         id: ProcedureId::new(0x05_06),
                     },
+                    deadline: None,
+
+                    encoding: Encoding::JSON,
 
                     payload: Payload::from_static(b"hello world"),
                 }),
@@ -1,6 +1,7 @@
 use bytes::{Buf, BufMut};
 use enumflags2::BitFlags;
 use error_stack::Report;
+use harpc_types::compression::Compression;
 
 use super::body::ResponseBody;
 use crate::{
@@ -16,6 +17,10 @@ pub enum ResponseFlag {
     BeginOfResponse = 0b1000_0000,
     // Controlled flags
     EndOfResponse = 0b0000_0001,
+    /// The payload of the response is compressed using zstd.
+    ZstdPayload = 0b0000_0010,
+    /// The payload of the response is compressed using lz4.
+    Lz4Payload = 0b0000_0100,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -34,6 +39,26 @@ impl ResponseFlags {
             matches!(body, ResponseBody::Begin(_)),
         )
     }
+
+    /// Compression algorithm used for the payload of the response.
+    ///
+    /// If multiple algorithms are set, zstd takes precedence.
+    #[must_use]
+    pub fn compression(self) -> Compression {
+        if self.contains(ResponseFlag::ZstdPayload) {
+            Compression::Zstd
+        } else if self.contains(ResponseFlag::Lz4Payload) {
+            Compression::Lz4
+        } else {
+            Compression::None
+        }
+    }
+
+    #[must_use]
+    pub fn with_compression(self, compression: Compression) -> Self {
+        self.set(ResponseFlag::ZstdPayload, compression == Compression::Zstd)
+            .set(ResponseFlag::Lz4Payload, compression == Compression::Lz4)
+    }
 }
 
 impl BitFlagsOp for ResponseFlags {
@@ -89,6 +114,7 @@ impl Decode for ResponseFlags {
 mod test {
     #![expect(clippy::needless_raw_strings)]
     use expect_test::expect;
+    use harpc_types::compression::Compression;
 
     use super::ResponseFlags;
     use crate::{
@@ -121,6 +147,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn compression() {
+        for compression in [Compression::None, Compression::Zstd, Compression::Lz4] {
+            let flags =
+                ResponseFlags::from(ResponseFlag::EndOfResponse).with_compression(compression);
+
+            assert_eq!(flags.compression(), compression);
+            assert!(flags.contains(ResponseFlag::EndOfResponse));
+        }
+    }
+
     #[test_strategy::proptest]
     #[cfg_attr(miri, ignore)]
     fn codec(flags: ResponseFlags) {
@@ -90,6 +90,11 @@ pub enum ResponseDecodeError {
 /// * Payload (up to 65504 bytes)
 /// total 32 bytes to 64 KiB
 /// ```
+///
+/// # Compression
+///
+/// The `Flags` field of every packet carries the compression algorithm of the response payload
+/// (`ZstdPayload`/`Lz4Payload`). Error responses are never compressed.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(test, derive(test_strategy::Arbitrary))]
@@ -528,6 +528,7 @@ impl SyntaxDump for ExprKind<'_> {
                 write_header(fmt, depth, "ExprKind", None, None, Some("Underscore"))
             }
             Self::Dummy => write_header(fmt, depth, "ExprKind", None, None, Some("Dummy")),
+            Self::Error => write_header(fmt, depth, "ExprKind", None, None, Some("Error")),
         }
     }
 }
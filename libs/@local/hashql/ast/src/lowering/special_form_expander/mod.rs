@@ -266,6 +266,8 @@ impl<'heap> SpecialFormExpander<'heap> {
                 span: expr.span,
                 kind: TypeKind::Infer,
             }),
+            // The error has already been reported while parsing
+            ExprKind::Error => None,
             kind @ (ExprKind::Dict(_)
             | ExprKind::List(_)
             | ExprKind::Literal(_)
@@ -1265,6 +1267,17 @@ impl<'heap> Visitor<'heap> for SpecialFormExpander<'heap> {
             return;
         }
 
+        // A special form cannot be expanded if one of its arguments could not be parsed, the
+        // error has already been reported, so we skip it without any additional diagnostics.
+        if call
+            .arguments
+            .iter()
+            .any(|argument| matches!(argument.value.kind, ExprKind::Error))
+        {
+            expr.kind = ExprKind::Error;
+            return;
+        }
+
         // Anything below here means that we're dealing with a special form, therefore anytime we
         // error out we replace the kind with a dummy expression. This allows us to continue
         // processing the rest of the expression tree during the different phases of lowering.
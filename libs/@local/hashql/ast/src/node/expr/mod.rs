@@ -532,6 +532,21 @@ pub enum ExprKind<'heap> {
     /// indicates an error in the transformation process, and will produce a compilation error on
     /// lowering into the HIR.
     Dummy,
+
+    /// An expression that could not be parsed.
+    ///
+    /// Frontends that recover from syntax errors replace the offending expression with an `Error`
+    /// node, which allows them to return a partial tree alongside every diagnostic encountered,
+    /// instead of stopping at the first one. The span of the node covers the source that has been
+    /// skipped during recovery.
+    ///
+    /// # Implementation Note
+    ///
+    /// The diagnostic describing the error has already been reported by the frontend, lowering
+    /// passes therefore skip `Error` nodes without reporting any additional diagnostics. Special
+    /// forms with an `Error` node as one of their arguments are replaced by an `Error` node as a
+    /// whole.
+    Error,
 }
 
 /// An expression node in the HashQL Abstract Syntax Tree.
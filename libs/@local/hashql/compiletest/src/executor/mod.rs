@@ -3,15 +3,25 @@ mod trial;
 mod trial_group;
 
 use core::error;
-use std::{self, io, thread};
+use std::{
+    self, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+};
 
 use ariadne::Source;
 use error_stack::Report;
 use guppy::graph::PackageGraph;
 use hashql_diagnostics::{
-    Diagnostic, category::DiagnosticCategory, config::ReportConfig, span::AbsoluteDiagnosticSpan,
+    Diagnostic,
+    category::DiagnosticCategory,
+    config::ReportConfig,
+    sarif::{Artifact, SarifLogBuilder},
+    span::AbsoluteDiagnosticSpan,
 };
 use nextest_filtering::{CompiledExpr, EvalContext, Filterset, FiltersetKind, ParseContext};
+use sarif::schema::SarifLog;
 
 pub(crate) use self::trial::TrialDescription;
 use self::trial_group::TrialGroup;
@@ -80,9 +90,61 @@ where
     Some(output.join("\n\n"))
 }
 
+/// Collects the diagnostics of all trials into a single SARIF log.
+#[derive(Debug)]
+pub(crate) struct SarifCollector {
+    root: PathBuf,
+    builder: Mutex<SarifLogBuilder>,
+}
+
+impl SarifCollector {
+    pub(crate) fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            builder: Mutex::new(SarifLogBuilder::new("hashql")),
+        }
+    }
+
+    pub(crate) fn push<'a, C>(
+        &self,
+        path: &Path,
+        source: &str,
+        diagnostics: impl IntoIterator<Item = &'a Diagnostic<C, AbsoluteDiagnosticSpan>>,
+    ) where
+        C: DiagnosticCategory + 'a,
+    {
+        // URIs are relative to the workspace root, so that the log is independent of the checkout
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        let uri = relative
+            .iter()
+            .map(|component| component.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let artifact = Artifact::new(uri, source).with_uri_base_id("SRCROOT");
+
+        let mut builder = self
+            .builder
+            .lock()
+            .expect("should be able to lock SARIF log");
+
+        for diagnostic in diagnostics {
+            builder.push(&artifact, diagnostic);
+        }
+    }
+
+    pub(crate) fn finish(self) -> SarifLog<'static> {
+        self.builder
+            .into_inner()
+            .expect("should be able to lock SARIF log")
+            .finish()
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct TrialContext {
     pub bless: bool,
+    pub sarif: Option<SarifCollector>,
 }
 
 pub(crate) struct TrialSet<'graph> {
@@ -78,7 +78,9 @@ pub(crate) struct TrialDescription {
 }
 
 pub(crate) struct Trial {
-    pub suite: &'static dyn Suite,
+    /// The suite the trial is run with, or `None` if the suite has not been compiled in, in which
+    /// case the trial is ignored.
+    pub suite: Option<&'static dyn Suite>,
     pub path: PathBuf,
     pub namespace: Vec<String>,
     pub ignore: bool,
@@ -88,7 +90,7 @@ pub(crate) struct Trial {
 
 impl Trial {
     pub(crate) fn from_test(case: TestCase, statistics: &Statistics) -> Self {
-        let suite = find_suite(&case.spec.suite).expect("suite should be available");
+        let suite = find_suite(&case.spec.suite);
 
         let file = File::open_buffered(&case.path).expect("should be able to open file");
         let mut annotations = FileAnnotations::new(
@@ -107,7 +109,7 @@ impl Trial {
             suite,
             path: case.path,
             namespace: case.namespace,
-            ignore: matches!(annotations.directive.run, RunMode::Skip { .. }),
+            ignore: suite.is_none() || matches!(annotations.directive.run, RunMode::Skip { .. }),
             annotations,
             statistics: statistics.clone(),
         }
@@ -218,6 +220,10 @@ impl Trial {
 
         let (received_stdout, diagnostics) = self.run_suite(&spans, &heap, expr)?;
 
+        if let Some(sarif) = &context.sarif {
+            sarif.push(&self.path, &source, &diagnostics);
+        }
+
         let mut sink = ReportSink::new_armed();
 
         verify_annotations(
@@ -265,7 +271,8 @@ impl Trial {
     ) -> Result<(Option<String>, Vec<ResolvedSuiteDiagnostic>), Report<TrialError>> {
         let mut diagnostics = vec![];
 
-        let result = self.suite.run(heap, expr, &mut diagnostics);
+        let suite = self.suite.expect("ignored trials should not be run");
+        let result = suite.run(heap, expr, &mut diagnostics);
 
         if self.annotations.directive.run == RunMode::Pass && result.is_err() {
             return Err(Report::new(TrialError::TrialShouldPass));
@@ -24,7 +24,7 @@ use guppy::{
 
 use self::{
     annotation::file::FileAnnotations,
-    executor::{TrialContext, TrialSet},
+    executor::{SarifCollector, TrialContext, TrialSet},
     reporter::{Reporter, Statistics, Summary, setup_progress_header},
     suite::Suite,
 };
@@ -60,8 +60,18 @@ struct EntryPoint<'graph> {
     pub metadata: PackageMetadata<'graph>,
 }
 
+/// The format in which diagnostics of a run are emitted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Failures are reported to stderr in a human readable form.
+    Human,
+    /// In addition to the human readable failures, the diagnostics of all trials are written to
+    /// stdout as a SARIF log.
+    Sarif,
+}
+
 pub enum Command {
-    Run { bless: bool },
+    Run { bless: bool, format: OutputFormat },
     List,
 }
 
@@ -101,15 +111,25 @@ impl Options {
         }
 
         match self.command {
-            Command::Run { bless } => {
+            Command::Run { bless, format } => {
                 let total = trials.len();
                 let ignored = trials.ignored();
 
                 setup_progress_header!(reporter, Summary { total, ignored }, statistics);
 
-                let reports = trials.run(&TrialContext { bless });
+                let sarif = (format == OutputFormat::Sarif)
+                    .then(|| SarifCollector::new(graph.workspace().root().as_std_path()));
+
+                let context = TrialContext { bless, sarif };
+
+                let reports = trials.run(&context);
                 let failures = reports.len();
 
+                if let Some(sarif) = context.sarif {
+                    serde_json::to_writer_pretty(stdout(), &sarif.finish())
+                        .expect("should be able to write SARIF log to stdout");
+                }
+
                 tracing::info!(
                     success = total - ignored - failures,
                     failures = failures,
@@ -149,7 +169,10 @@ pub fn nextest_bridge(package: &str) {
     let options = Options {
         filter: Some(format!("package({package})")),
         quick_filter: true,
-        command: Command::Run { bless: false },
+        command: Command::Run {
+            bless: false,
+            format: OutputFormat::Human,
+        },
     };
 
     options.run();
@@ -50,14 +50,42 @@ enum Command {
         /// discrepancies.
         #[clap(long, short, default_value_t = false)]
         bless: bool,
+
+        /// The format in which diagnostics are emitted.
+        ///
+        /// With `sarif`, the diagnostics of all tests are written to stdout as a SARIF 2.1.0 log,
+        /// which can be consumed by code scanning tools. Failures are still reported to stderr.
+        #[clap(long, value_enum, default_value_t = Format::Human)]
+        format: Format,
     },
 }
 
+/// Output formats of the `run` command.
+#[derive(Debug, Copy, Clone, clap::ValueEnum)]
+enum Format {
+    /// Human readable output.
+    Human,
+    /// A SARIF 2.1.0 log of all emitted diagnostics.
+    Sarif,
+}
+
+impl From<Format> for hashql_compiletest::OutputFormat {
+    fn from(value: Format) -> Self {
+        match value {
+            Format::Human => Self::Human,
+            Format::Sarif => Self::Sarif,
+        }
+    }
+}
+
 impl From<Command> for hashql_compiletest::Command {
     fn from(value: Command) -> Self {
         match value {
             Command::List => Self::List,
-            Command::Run { bless } => Self::Run { bless },
+            Command::Run { bless, format } => Self::Run {
+                bless,
+                format: format.into(),
+            },
         }
     }
 }
@@ -0,0 +1,57 @@
+use hash_graph_store::subgraph::temporal_axes::QueryTemporalAxesUnresolved;
+use hashql_ast::{lowering::lower, node::expr::Expr};
+use hashql_core::{
+    heap::Heap, module::ModuleRegistry, span::SpanId, r#type::environment::Environment,
+};
+use hashql_eval::{graph::GraphCompiler, interpret::Inputs};
+use hashql_hir::{intern::Interner, node::Node};
+
+use super::{Suite, SuiteDiagnostic, common::process_diagnostics};
+
+pub(crate) struct EvalGraphReadSuite;
+
+impl Suite for EvalGraphReadSuite {
+    fn name(&self) -> &'static str {
+        "eval/graph/read"
+    }
+
+    fn run<'heap>(
+        &self,
+        heap: &'heap Heap,
+        mut expr: Expr<'heap>,
+        diagnostics: &mut Vec<SuiteDiagnostic>,
+    ) -> Result<String, SuiteDiagnostic> {
+        let environment = Environment::new(SpanId::SYNTHETIC, heap);
+        let registry = ModuleRegistry::new(&environment);
+
+        let (types, lower_diagnostics) = lower(
+            heap.intern_symbol("::main"),
+            &mut expr,
+            &environment,
+            &registry,
+        );
+
+        process_diagnostics(diagnostics, lower_diagnostics)?;
+
+        let interner = Interner::new(heap);
+        let (node, reify_diagnostics) = Node::from_ast(expr, &environment, &interner, &types);
+        process_diagnostics(diagnostics, reify_diagnostics)?;
+
+        let node = node.expect("should be `Some` if there are non-fatal errors");
+
+        let inputs = Inputs::default();
+        let compiler = GraphCompiler::new(&environment, &types.locals, &inputs);
+
+        let (query, compile_diagnostics) = compiler.compile(node);
+        process_diagnostics(diagnostics, compile_diagnostics)?;
+
+        let query = query.expect("should be `Some` if there are non-fatal errors");
+
+        let axis = match query.temporal_axes {
+            QueryTemporalAxesUnresolved::DecisionTime { .. } => "decision time",
+            QueryTemporalAxesUnresolved::TransactionTime { .. } => "transaction time",
+        };
+
+        Ok(format!("temporal axis: {axis}\n\n{:#?}", query.filter))
+    }
+}
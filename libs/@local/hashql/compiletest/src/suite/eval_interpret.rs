@@ -0,0 +1,61 @@
+use hashql_ast::{lowering::lower, node::expr::Expr};
+use hashql_core::{
+    heap::Heap,
+    module::ModuleRegistry,
+    pretty::{PrettyOptions, PrettyPrint as _},
+    span::SpanId,
+    r#type::environment::Environment,
+};
+use hashql_eval::{
+    error::EvalDiagnosticCategory,
+    interpret::{Inputs, Interpreter},
+};
+use hashql_hir::{intern::Interner, node::Node};
+
+use super::{Suite, SuiteDiagnostic, common::process_diagnostics};
+
+pub(crate) struct EvalInterpretSuite;
+
+impl Suite for EvalInterpretSuite {
+    fn name(&self) -> &'static str {
+        "eval/interpret"
+    }
+
+    fn run<'heap>(
+        &self,
+        heap: &'heap Heap,
+        mut expr: Expr<'heap>,
+        diagnostics: &mut Vec<SuiteDiagnostic>,
+    ) -> Result<String, SuiteDiagnostic> {
+        let environment = Environment::new(SpanId::SYNTHETIC, heap);
+        let registry = ModuleRegistry::new(&environment);
+
+        let (types, lower_diagnostics) = lower(
+            heap.intern_symbol("::main"),
+            &mut expr,
+            &environment,
+            &registry,
+        );
+
+        process_diagnostics(diagnostics, lower_diagnostics)?;
+
+        let interner = Interner::new(heap);
+        let (node, reify_diagnostics) = Node::from_ast(expr, &environment, &interner, &types);
+        process_diagnostics(diagnostics, reify_diagnostics)?;
+
+        let node = node.expect("should be `Some` if there are non-fatal errors");
+
+        let inputs = Inputs::default();
+        let interpreter = Interpreter::new(&environment, &types.locals, &inputs);
+
+        let value = interpreter.run(node).map_err(|diagnostic| {
+            diagnostic
+                .map_category(EvalDiagnosticCategory::Interpret)
+                .boxed()
+        })?;
+
+        Ok(value
+            .pretty_print(&environment, PrettyOptions::default().without_color())
+            .to_string())
+    }
+}
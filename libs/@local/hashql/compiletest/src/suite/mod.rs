@@ -8,6 +8,9 @@ mod ast_lowering_special_form_expander;
 mod ast_lowering_type_definition_extractor;
 mod ast_lowering_type_extractor;
 pub(crate) mod common;
+#[cfg(feature = "graph")]
+mod eval_graph_read;
+mod eval_interpret;
 mod hir_lower_alias_replacement;
 mod hir_reify;
 mod parse_syntax_dump;
@@ -16,6 +19,8 @@ use hashql_ast::node::expr::Expr;
 use hashql_core::{heap::Heap, span::SpanId};
 use hashql_diagnostics::{Diagnostic, category::DiagnosticCategory, span::AbsoluteDiagnosticSpan};
 
+#[cfg(feature = "graph")]
+use self::eval_graph_read::EvalGraphReadSuite;
 use self::{
     ast_lowering_import_resolver::AstLoweringImportResolverSuite,
     ast_lowering_import_resolver_continue::AstLoweringImportResolverContinueSuite,
@@ -25,7 +30,7 @@ use self::{
     ast_lowering_sanitizer::AstLoweringSanitizerSuite,
     ast_lowering_special_form_expander::AstLoweringSpecialFormExpanderSuite,
     ast_lowering_type_definition_extractor::AstLoweringTypeDefinitionExtractorSuite,
-    ast_lowering_type_extractor::AstLoweringTypeExtractorSuite,
+    ast_lowering_type_extractor::AstLoweringTypeExtractorSuite, eval_interpret::EvalInterpretSuite,
     hir_lower_alias_replacement::HirLowerAliasReplacementSuite, hir_reify::HirReifySuite,
     parse_syntax_dump::ParseSyntaxDumpSuite,
 };
@@ -55,6 +60,9 @@ const SUITES: &[&dyn Suite] = &[
     &AstLoweringSpecialFormExpanderSuite,
     &AstLoweringTypeDefinitionExtractorSuite,
     &AstLoweringTypeExtractorSuite,
+    #[cfg(feature = "graph")]
+    &EvalGraphReadSuite,
+    &EvalInterpretSuite,
     &HirLowerAliasReplacementSuite,
     &HirReifySuite,
     &ParseSyntaxDumpSuite,
@@ -421,8 +421,9 @@ impl<'env, 'heap> ModuleNamespace<'env, 'heap> {
         }
     }
 
-    #[cfg(test)]
-    pub(crate) fn imports_as_slice(&self) -> &[Import<'heap>] {
+    /// Returns every import of the namespace, in the order in which they have been imported.
+    #[must_use]
+    pub fn imports_as_slice(&self) -> &[Import<'heap>] {
         &self.imports
     }
 }
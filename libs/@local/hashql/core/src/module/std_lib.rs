@@ -431,11 +431,99 @@ impl<'env, 'heap> StandardLibrary<'env, 'heap> {
         })
     }
 
+    fn graph_submodule(
+        &self,
+        parent: ModuleId,
+        name: &'static str,
+        values: &[&'static str],
+    ) -> ModuleId {
+        self.registry.intern_module(|id| {
+            let id = id.value();
+
+            let items: Vec<_> = values
+                .iter()
+                .map(|&value| self.alloc_intrinsic_value(id, value, None))
+                .collect();
+
+            PartialModule {
+                name: self.heap.intern_symbol(name),
+                parent,
+                items: self.registry.intern_items(&items),
+            }
+        })
+    }
+
+    fn graph_module_types(&self, parent: ModuleId, items: &mut Vec<Item<'heap>>) {
+        // The shape of the graph types is not yet finalized, which is why they are opaque over
+        // `Unknown` for now.
+        let unknown = self.alloc_type(TypeKind::Unknown);
+
+        let graph = self.alloc_type(TypeKind::Opaque(OpaqueType {
+            name: self.heap.intern_symbol("::graph::Graph"),
+            repr: unknown,
+        }));
+
+        let time_axis = self.alloc_type(TypeKind::Opaque(OpaqueType {
+            name: self.heap.intern_symbol("::graph::TimeAxis"),
+            repr: unknown,
+        }));
+
+        let entity = self.alloc_type(TypeKind::Opaque(OpaqueType {
+            name: self.heap.intern_symbol("::graph::Entity"),
+            repr: unknown,
+        }));
+
+        items.extend_from_slice(&[
+            self.alloc_type_item(parent, "Graph", graph, &[]),
+            self.alloc_type_item(parent, "TimeAxis", time_axis, &[]),
+            self.alloc_type_item(parent, "Entity", entity, &[]),
+        ]);
+    }
+
+    fn graph_module(&self) -> ModuleId {
+        self.registry.intern_module(|id| {
+            let id = id.value();
+
+            let mut items = Vec::with_capacity(8);
+            self.graph_module_types(id, &mut items);
+
+            // A graph query is a pipeline, which starts with a head (selecting the vertices to
+            // query), followed by any number of body operations (such as filters) and terminated
+            // by a tail (which determines the shape of the result).
+            let modules: [(&'static str, &[&'static str]); 4] = [
+                ("head", &["::graph::head::entities"]),
+                ("body", &["::graph::body::filter"]),
+                ("tail", &["::graph::tail::collect"]),
+                // Temporal axes are temporary until we have proper support for timestamps and
+                // intervals.
+                (
+                    "tmp",
+                    &[
+                        "::graph::tmp::decision_time_now",
+                        "::graph::tmp::transaction_time_now",
+                    ],
+                ),
+            ];
+
+            for (name, values) in modules {
+                items.push(Item {
+                    module: id,
+                    name: self.heap.intern_symbol(name),
+                    kind: ItemKind::Module(self.graph_submodule(id, name, values)),
+                });
+            }
+
+            PartialModule {
+                name: self.heap.intern_symbol("graph"),
+                parent: ModuleId::ROOT,
+                items: self.registry.intern_items(&items),
+            }
+        })
+    }
+
     pub(super) fn register(&self) {
         self.registry.register(self.kernel_module());
         self.registry.register(self.math_module());
-
-        // TODO: The graph module is not yet added (Primarily due to the fact that we're not yet
-        // sure about the shape of some of the types involved).
+        self.registry.register(self.graph_module());
     }
 }
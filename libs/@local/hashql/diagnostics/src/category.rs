@@ -69,7 +69,8 @@ where
     CanonicalDiagnosticCategoryName(category)
 }
 
-pub(crate) fn category_display_name(mut category: &dyn DiagnosticCategory) -> Cow<str> {
+/// Returns the name of the most specific subcategory of the given category.
+pub fn category_display_name(mut category: &dyn DiagnosticCategory) -> Cow<str> {
     while let Some(child) = category.subcategory() {
         category = child;
     }
@@ -15,6 +15,8 @@ pub mod error;
 pub mod help;
 pub mod label;
 pub mod note;
+#[cfg(feature = "sarif")]
+pub mod sarif;
 pub mod severity;
 pub mod span;
 
@@ -0,0 +1,389 @@
+//! Conversion of diagnostics into the [SARIF] format.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+use alloc::borrow::Cow;
+use core::fmt::Write as _;
+
+use sarif::{
+    builder::RunBuilder,
+    schema::{
+        ArtifactLocation, Location, Message, PhysicalLocation, Region, ReportingDescriptor, Result,
+        ResultLevel, SarifLog, ToolComponent,
+    },
+};
+use text_size::TextSize;
+
+use crate::{
+    Diagnostic, Severity,
+    category::{DiagnosticCategory, canonical_category_id, category_display_name},
+    label::Label,
+    span::AbsoluteDiagnosticSpan,
+};
+
+/// A source file against which diagnostics are reported.
+///
+/// Used to translate the spans of a diagnostic into the line and column based regions of SARIF.
+#[derive(Debug, Clone)]
+pub struct Artifact<'source> {
+    location: ArtifactLocation<'static>,
+    source: &'source str,
+    /// Byte offset of the start of every line.
+    lines: Vec<usize>,
+}
+
+impl<'source> Artifact<'source> {
+    /// Creates a new artifact located at `uri` with the given content.
+    pub fn new(uri: impl Into<Cow<'static, str>>, source: &'source str) -> Self {
+        let lines = core::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self {
+            location: ArtifactLocation::new(uri),
+            source,
+            lines,
+        }
+    }
+
+    /// Sets the base URI against which the URI of the artifact is resolved, such as `SRCROOT`.
+    #[must_use]
+    pub fn with_uri_base_id(mut self, uri_base_id: impl Into<Cow<'static, str>>) -> Self {
+        self.location = self.location.with_uri_base_id(uri_base_id);
+        self
+    }
+
+    /// Returns the 1-based line and column of the offset.
+    ///
+    /// Columns are counted in UTF-16 code units, which is the default column kind of SARIF.
+    fn position(&self, offset: usize) -> (u64, u64) {
+        let line = self.lines.partition_point(|&start| start <= offset) - 1;
+        let start = self.lines[line];
+
+        let column = self
+            .source
+            .get(start..offset)
+            .map_or(offset - start, |text| text.encode_utf16().count());
+
+        (line as u64 + 1, column as u64 + 1)
+    }
+
+    fn region(&self, span: AbsoluteDiagnosticSpan) -> Region<'static> {
+        let length = TextSize::of(self.source);
+        let range = span.range();
+
+        let start = usize::from(range.start().min(length));
+        let end = usize::from(range.end().min(length));
+
+        let (start_line, start_column) = self.position(start);
+        let (end_line, end_column) = self.position(end);
+
+        Region::new()
+            .with_start(start_line, start_column)
+            .with_end(end_line, end_column)
+            .with_byte_range(start as u64, (end - start) as u64)
+    }
+
+    fn location(&self, label: &Label<AbsoluteDiagnosticSpan>) -> Location<'static> {
+        Location::new()
+            .with_physical_location(
+                PhysicalLocation::new(self.location.clone())
+                    .with_region(self.region(*label.span())),
+            )
+            .with_message(Message::new(label.message().to_owned()))
+    }
+}
+
+impl Severity {
+    /// Returns the SARIF level corresponding to this severity.
+    #[must_use]
+    pub const fn sarif_level(self) -> ResultLevel {
+        match self {
+            Self::Bug | Self::Fatal | Self::Error => ResultLevel::Error,
+            Self::Warning => ResultLevel::Warning,
+            Self::Note => ResultLevel::Note,
+            Self::Debug => ResultLevel::None,
+        }
+    }
+}
+
+/// Creates the SARIF rule describing the given category.
+///
+/// The identifier of the rule is the canonical identifier of the category, e.g.
+/// `parser::syntax::unexpected`.
+pub fn sarif_rule<C>(category: &C) -> ReportingDescriptor<'static>
+where
+    C: DiagnosticCategory,
+{
+    ReportingDescriptor::new(canonical_category_id(category).to_string())
+        .with_name(category_display_name(category).into_owned())
+}
+
+impl<C> Diagnostic<C, AbsoluteDiagnosticSpan>
+where
+    C: DiagnosticCategory,
+{
+    /// Converts the diagnostic into a SARIF result located in the given artifact.
+    ///
+    /// The first label is the location of the result, all other labels are reported as related
+    /// locations. Notes and help messages are appended to the message of the result.
+    pub fn sarif(&self, artifact: &Artifact) -> Result<'static> {
+        let mut message = self
+            .message
+            .clone()
+            .unwrap_or_else(|| category_display_name(&self.category))
+            .into_owned();
+
+        for note in self.notes.iter().chain(self.severity.notes()) {
+            let _ = write!(message, "\n\nnote: {}", note.message());
+        }
+
+        for help in self.help.iter().chain(self.severity.help()) {
+            let _ = write!(message, "\n\nhelp: {}", help.message());
+        }
+
+        let mut result = Result::new(Message::new(message))
+            .with_rule_id(canonical_category_id(&self.category).to_string())
+            .with_level(self.severity.sarif_level());
+
+        let mut labels = self.labels.iter();
+
+        result = match labels.next() {
+            Some(label) => result.with_location(artifact.location(label)),
+            // Diagnostics without any label still refer to the artifact as a whole
+            None => result.with_location(
+                Location::new()
+                    .with_physical_location(PhysicalLocation::new(artifact.location.clone())),
+            ),
+        };
+
+        result.with_related_locations(
+            labels
+                .zip(0..)
+                .map(|(label, id)| artifact.location(label).with_id(id)),
+        )
+    }
+}
+
+/// Collects diagnostics of one or more artifacts into a single [`SarifLog`].
+///
+/// Every category is registered as a rule of the tool, results refer to their rule by index.
+#[derive(Debug, Clone)]
+pub struct SarifLogBuilder {
+    run: RunBuilder<'static>,
+}
+
+impl SarifLogBuilder {
+    /// Creates a new builder, whose results are reported by the tool of the given name.
+    pub fn new(tool: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            run: RunBuilder::new(ToolComponent::new(tool)),
+        }
+    }
+
+    /// Adds the diagnostic, which is located in the given artifact, to the log.
+    pub fn push<C>(
+        &mut self,
+        artifact: &Artifact,
+        diagnostic: &Diagnostic<C, AbsoluteDiagnosticSpan>,
+    ) where
+        C: DiagnosticCategory,
+    {
+        let rule = self.run.register_rule(sarif_rule(&diagnostic.category));
+
+        self.run
+            .push(diagnostic.sarif(artifact).with_rule_index(rule.index()));
+    }
+
+    /// Finishes the log, which consists of a single run.
+    #[must_use]
+    pub fn finish(self) -> SarifLog<'static> {
+        self.run.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sarif::schema::ResultLevel;
+    use text_size::TextRange;
+
+    use super::{Artifact, SarifLogBuilder};
+    use crate::{
+        Diagnostic, Help, Note, Severity, category::TerminalDiagnosticCategory, label::Label,
+        span::AbsoluteDiagnosticSpan,
+    };
+
+    const UNKNOWN_VARIABLE: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+        id: "unknown-variable",
+        name: "Unknown variable",
+    };
+
+    const SOURCE: &str = "[\"let\", \"x\",\n  { \"#literal\": 1 },\n  \"y\"]";
+
+    fn span(needle: &str) -> AbsoluteDiagnosticSpan {
+        let start = SOURCE.find(needle).expect("needle should be in source");
+
+        AbsoluteDiagnosticSpan::from_range(TextRange::at(
+            u32::try_from(start).expect("source should be small").into(),
+            u32::try_from(needle.len())
+                .expect("source should be small")
+                .into(),
+        ))
+    }
+
+    type TestDiagnostic = Diagnostic<TerminalDiagnosticCategory, AbsoluteDiagnosticSpan>;
+
+    fn diagnostic() -> TestDiagnostic {
+        let mut diagnostic = TestDiagnostic::new(UNKNOWN_VARIABLE, Severity::Error);
+        diagnostic
+            .labels
+            .push(Label::new(span("\"y\""), "`y` is not defined"));
+        diagnostic
+            .labels
+            .push(Label::new(span("\"x\""), "did you mean `x`?"));
+        diagnostic.add_note(Note::new("variables must be bound before use"));
+        diagnostic.add_help(Help::new("bind `y` using `let`"));
+
+        diagnostic
+    }
+
+    #[test]
+    fn result() {
+        let artifact = Artifact::new("main.jsonc", SOURCE);
+        let result = diagnostic().sarif(&artifact);
+
+        assert_eq!(result.rule_id.as_deref(), Some("unknown-variable"));
+        assert_eq!(result.level, Some(ResultLevel::Error));
+        assert_eq!(
+            result.message.text.as_deref(),
+            Some(
+                "Unknown variable\n\nnote: variables must be bound before use\n\nhelp: bind `y` \
+                 using `let`"
+            )
+        );
+
+        let [location] = &*result.locations else {
+            panic!("expected a single location, got {:?}", result.locations);
+        };
+        let physical = location
+            .physical_location
+            .as_ref()
+            .expect("location should have a physical location");
+        assert_eq!(
+            physical
+                .artifact_location
+                .as_ref()
+                .and_then(|artifact| artifact.uri.as_deref()),
+            Some("main.jsonc")
+        );
+
+        let region = physical
+            .region
+            .as_ref()
+            .expect("location should have a region");
+        assert_eq!((region.start_line, region.start_column), (Some(3), Some(3)));
+        assert_eq!((region.end_line, region.end_column), (Some(3), Some(6)));
+        assert_eq!(region.byte_offset, Some(36));
+        assert_eq!(region.byte_length, Some(3));
+        assert_eq!(
+            location
+                .message
+                .as_ref()
+                .and_then(|message| message.text.as_deref()),
+            Some("`y` is not defined")
+        );
+
+        let [related] = &*result.related_locations else {
+            panic!("expected a single related location");
+        };
+        assert_eq!(related.id, Some(0));
+
+        let region = related
+            .physical_location
+            .as_ref()
+            .and_then(|location| location.region.as_ref())
+            .expect("related location should have a region");
+        assert_eq!((region.start_line, region.start_column), (Some(1), Some(9)));
+    }
+
+    #[test]
+    fn columns_are_utf16() {
+        let source = "\"\u{fc}\u{1d11e}\" \"x\"";
+        let artifact = Artifact::new("main.jsonc", source);
+
+        let mut diagnostic = TestDiagnostic::new(UNKNOWN_VARIABLE, Severity::Warning);
+        diagnostic.labels.push(Label::new(
+            AbsoluteDiagnosticSpan::from_range(TextRange::at(9.into(), 3.into())),
+            "here",
+        ));
+
+        let result = diagnostic.sarif(&artifact);
+        let region = result.locations[0]
+            .physical_location
+            .as_ref()
+            .and_then(|location| location.region.as_ref())
+            .expect("location should have a region");
+
+        // `U+00FC` is a single UTF-16 code unit, `U+1D11E` is a surrogate pair
+        assert_eq!(region.start_column, Some(7));
+        assert_eq!(region.byte_offset, Some(9));
+        assert_eq!(result.level, Some(ResultLevel::Warning));
+    }
+
+    #[test]
+    fn without_labels() {
+        let artifact = Artifact::new("main.jsonc", SOURCE);
+        let result = TestDiagnostic::new(UNKNOWN_VARIABLE, Severity::Note).sarif(&artifact);
+
+        let [location] = &*result.locations else {
+            panic!("expected a single location");
+        };
+        let physical = location
+            .physical_location
+            .as_ref()
+            .expect("location should have a physical location");
+
+        assert_eq!(physical.region, None);
+        assert_eq!(result.level, Some(ResultLevel::Note));
+    }
+
+    #[test]
+    fn log() {
+        let main = Artifact::new("main.jsonc", SOURCE).with_uri_base_id("SRCROOT");
+        let other = Artifact::new("other.jsonc", SOURCE);
+
+        let mut builder = SarifLogBuilder::new("hashql");
+        builder.push(&main, &diagnostic());
+        builder.push(&other, &diagnostic());
+
+        let log = builder.finish();
+        let [run] = &*log.runs.expect("log should have runs") else {
+            panic!("expected a single run");
+        };
+
+        assert_eq!(run.tool.driver.name, "hashql");
+        assert_eq!(run.tool.driver.rules.len(), 1);
+        assert_eq!(run.tool.driver.rules[0].id, "unknown-variable");
+        assert_eq!(
+            run.tool.driver.rules[0].name.as_deref(),
+            Some("Unknown variable")
+        );
+
+        let results = run.results.as_ref().expect("run should have results");
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|result| result.rule_index == Some(0)));
+
+        let uri = |index: usize| {
+            let location = results[index].locations[0]
+                .physical_location
+                .as_ref()
+                .expect("location should have a physical location");
+
+            location
+                .artifact_location
+                .as_ref()
+                .expect("location should have an artifact location")
+        };
+        assert_eq!(uri(0).uri_base_id.as_deref(), Some("SRCROOT"));
+        assert_eq!(uri(1).uri.as_deref(), Some("other.jsonc"));
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::borrow::Cow;
+
+use hashql_core::span::SpanId;
+use hashql_diagnostics::{Diagnostic, category::DiagnosticCategory};
+
+#[cfg(feature = "graph")]
+use crate::graph::error::GraphDiagnosticCategory;
+use crate::interpret::error::InterpretDiagnosticCategory;
+
+pub type EvalDiagnostic = Diagnostic<EvalDiagnosticCategory, SpanId>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EvalDiagnosticCategory {
+    Interpret(InterpretDiagnosticCategory),
+    #[cfg(feature = "graph")]
+    Graph(GraphDiagnosticCategory),
+}
+
+impl DiagnosticCategory for EvalDiagnosticCategory {
+    fn id(&self) -> Cow<'_, str> {
+        Cow::Borrowed("eval")
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Eval")
+    }
+
+    fn subcategory(&self) -> Option<&dyn DiagnosticCategory> {
+        match self {
+            Self::Interpret(interpret) => Some(interpret),
+            #[cfg(feature = "graph")]
+            Self::Graph(graph) => Some(graph),
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use alloc::borrow::Cow;
+
+use hashql_core::span::SpanId;
+use hashql_diagnostics::{
+    Diagnostic,
+    category::{DiagnosticCategory, TerminalDiagnosticCategory},
+    help::Help,
+    label::Label,
+    note::Note,
+    severity::Severity,
+};
+
+pub type GraphDiagnostic = Diagnostic<GraphDiagnosticCategory, SpanId>;
+
+const EXPECTED_GRAPH_READ: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "expected-graph-read",
+    name: "Expected a graph read",
+};
+
+const UNSUPPORTED_TEMPORAL_AXIS: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unsupported-temporal-axis",
+    name: "Unsupported temporal axis",
+};
+
+const INVALID_FILTER: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-filter",
+    name: "Invalid filter",
+};
+
+const NON_BOOLEAN_FILTER: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "non-boolean-filter",
+    name: "Filter does not evaluate to a boolean",
+};
+
+const UNSUPPORTED_FILTER_EXPRESSION: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unsupported-filter-expression",
+    name: "Expression cannot be used in a filter",
+};
+
+const UNKNOWN_ENTITY_PATH: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unknown-entity-path",
+    name: "Unknown entity path",
+};
+
+const UNSUPPORTED_PARAMETER: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unsupported-parameter",
+    name: "Value cannot be used as a query parameter",
+};
+
+const NULL_COMPARISON: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "null-comparison",
+    name: "Ordering comparison with null",
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GraphDiagnosticCategory {
+    ExpectedGraphRead,
+    UnsupportedTemporalAxis,
+    InvalidFilter,
+    NonBooleanFilter,
+    UnsupportedFilterExpression,
+    UnknownEntityPath,
+    UnsupportedParameter,
+    NullComparison,
+}
+
+impl DiagnosticCategory for GraphDiagnosticCategory {
+    fn id(&self) -> Cow<'_, str> {
+        Cow::Borrowed("graph")
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Graph")
+    }
+
+    fn subcategory(&self) -> Option<&dyn DiagnosticCategory> {
+        match *self {
+            Self::ExpectedGraphRead => Some(&EXPECTED_GRAPH_READ),
+            Self::UnsupportedTemporalAxis => Some(&UNSUPPORTED_TEMPORAL_AXIS),
+            Self::InvalidFilter => Some(&INVALID_FILTER),
+            Self::NonBooleanFilter => Some(&NON_BOOLEAN_FILTER),
+            Self::UnsupportedFilterExpression => Some(&UNSUPPORTED_FILTER_EXPRESSION),
+            Self::UnknownEntityPath => Some(&UNKNOWN_ENTITY_PATH),
+            Self::UnsupportedParameter => Some(&UNSUPPORTED_PARAMETER),
+            Self::NullComparison => Some(&NULL_COMPARISON),
+        }
+    }
+}
+
+/// Creates a diagnostic for a program that does not evaluate to a graph read.
+pub(crate) fn expected_graph_read(span: SpanId) -> GraphDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(GraphDiagnosticCategory::ExpectedGraphRead, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "expected a graph read here").with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "Only programs that end in a graph read, such as `::graph::tail::collect(...)`, can be \
+         compiled into a query. Values may be bound with `let` before the graph read.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a temporal axis that cannot be translated into query temporal axes.
+pub(crate) fn unsupported_temporal_axis(span: SpanId) -> GraphDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        GraphDiagnosticCategory::UnsupportedTemporalAxis,
+        Severity::Error,
+    );
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "unsupported temporal axis").with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "Call `::graph::tmp::decision_time_now()` or `::graph::tmp::transaction_time_now()` \
+         directly as the argument of the graph head.",
+    ));
+
+    diagnostic.add_note(Note::new(
+        "Querying the graph at arbitrary points in time is not supported yet, only the current \
+         point in time along either the decision time or the transaction time axis can be \
+         selected.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a filter argument that is not a closure.
+pub(crate) fn expected_filter_closure(span: SpanId, actual: &str) -> GraphDiagnostic {
+    let mut diagnostic = Diagnostic::new(GraphDiagnosticCategory::InvalidFilter, Severity::Error);
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("expected a closure, found a value of type `{actual}`"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic.add_help(Help::new(
+        "Filters must be closures defined in the program, which take the vertex as their only \
+         argument and return a boolean.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a filter closure that does not take exactly one parameter.
+pub(crate) fn filter_parameter_count(span: SpanId, actual: usize) -> GraphDiagnostic {
+    let mut diagnostic = Diagnostic::new(GraphDiagnosticCategory::InvalidFilter, Severity::Error);
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("this closure takes {actual} parameter(s), but filters take exactly 1"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic.add_help(Help::new(
+        "Declare a single parameter, which is bound to the vertex that is being filtered.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a filter expression that evaluates to a value other than a boolean.
+pub(crate) fn non_boolean_filter(span: SpanId, actual: &str) -> GraphDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(GraphDiagnosticCategory::NonBooleanFilter, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("expected `Boolean`, found `{actual}`")).with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "The body of a filter must evaluate to `true` for every vertex that should be retained.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for an expression that depends on the filtered vertex, but cannot be
+/// translated into a filter.
+pub(crate) fn unsupported_filter_expression(span: SpanId) -> GraphDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        GraphDiagnosticCategory::UnsupportedFilterExpression,
+        Severity::Error,
+    );
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "this expression cannot be translated into a filter").with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "Filters may combine comparisons (`==`, `!=`, `<`, `<=`, `>` and `>=`) of fields of the \
+         vertex using `::math::and`, `::math::or`, `::math::not` and `if` expressions.",
+    ));
+
+    diagnostic.add_note(Note::new(
+        "Filters are executed by the graph store, and not by HashQL itself. Expressions which do \
+         not depend on the vertex are evaluated before the query is executed, and may use the \
+         full language.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for an access path on an entity that does not correspond to a queryable
+/// field.
+pub(crate) fn unknown_entity_path(span: SpanId, path: &str) -> GraphDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(GraphDiagnosticCategory::UnknownEntityPath, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("`{path}` cannot be queried")).with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "Queryable fields are the entity id (`metadata.record_id.entity_id.web_id`, \
+         `.entity_uuid` and `.draft_id`), `metadata.record_id.edition_id`, `metadata.archived`, \
+         `metadata.confidence`, `metadata.provenance`, `metadata.properties`, `properties` and \
+         the endpoints of `link_data`.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a value that cannot be passed to the graph store as a parameter.
+pub(crate) fn unsupported_parameter(span: SpanId, actual: &str) -> GraphDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        GraphDiagnosticCategory::UnsupportedParameter,
+        Severity::Error,
+    );
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("a value of type `{actual}` cannot be compared in a filter"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic.add_help(Help::new(
+        "Only `Null`, `Boolean`, `Integer`, `Number` and `String` values, or opaque values \
+         wrapping one of them, can be compared to fields of the vertex.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a number that cannot be represented as a query parameter, such as
+/// `NaN` or an infinite value.
+pub(crate) fn unrepresentable_number(span: SpanId, value: f64) -> GraphDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        GraphDiagnosticCategory::UnsupportedParameter,
+        Severity::Error,
+    );
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("`{value}` cannot be represented as a decimal number"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic.add_help(Help::new(
+        "Numbers passed to the graph store must be finite.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for an ordering comparison where one of the operands is `null`.
+pub(crate) fn null_comparison(span: SpanId) -> GraphDiagnostic {
+    let mut diagnostic = Diagnostic::new(GraphDiagnosticCategory::NullComparison, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "`null` has no ordering").with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "Use `==` or `!=` to check whether a field is `null`.",
+    ));
+
+    diagnostic
+}
@@ -0,0 +1,409 @@
+//! Translation of filter closures into [`Filter`]s.
+//!
+//! The body of a filter closure is translated structurally:
+//!
+//! - `::math::and`, `::math::or` and `::math::not` are translated into [`Filter::All`],
+//!   [`Filter::Any`] and [`Filter::Not`] respectively.
+//! - Comparisons (both the binary operators and their `::math` counterparts) are translated into
+//!   the corresponding comparison filters, comparing with `null` is translated into a check for the
+//!   absence of a value.
+//! - `if` expressions with an `else` branch are translated into a disjunction of both branches.
+//! - Field and index accesses on the closure parameter are translated into [`EntityQueryPath`]s, an
+//!   access on its own is true if the field is `true`.
+//!
+//! Any expression which does not reference the closure parameter is evaluated ahead of time by
+//! the interpreter, and is either used as a parameter of the comparison it is part of, or, if it
+//! is a boolean, as a constant filter.
+use alloc::borrow::Cow;
+
+use hash_codec::numeric::Real;
+use hash_graph_store::{
+    entity::EntityQueryPath,
+    filter::{Filter, FilterExpression, Parameter, PathToken},
+};
+use hashql_core::{span::SpanId, symbol::Symbol};
+use hashql_hir::{
+    node::{
+        Node,
+        access::{Access, AccessKind, field::FieldAccess, index::IndexAccess},
+        branch::{Branch, BranchKind, If},
+        call::Call,
+        kind::NodeKind,
+        r#let::Let,
+        operation::{BinaryOperation, Operation, OperationKind},
+        variable::{LocalVariable, Variable, VariableKind},
+    },
+    visit::Visitor,
+};
+use type_system::knowledge::Entity;
+
+use super::{
+    GraphCompiler,
+    error::{
+        non_boolean_filter, null_comparison, unknown_entity_path, unrepresentable_number,
+        unsupported_filter_expression, unsupported_parameter,
+    },
+    path, qualified_path,
+};
+use crate::interpret::{intrinsic::Intrinsic, scope::Scope, value::Value};
+
+/// Determines whether an expression references the given variable.
+struct References<'heap> {
+    name: Symbol<'heap>,
+    found: bool,
+}
+
+impl<'heap> Visitor<'heap> for References<'heap> {
+    fn visit_local_variable(&mut self, variable: &'heap LocalVariable<'heap>) {
+        self.found |= variable.name.value == self.name;
+    }
+}
+
+/// An operand of a comparison.
+enum Operand {
+    /// The operand is `null`, comparisons with `null` check for the absence of a value.
+    Null,
+    Expression(FilterExpression<'static, Entity>),
+}
+
+impl Operand {
+    fn into_expression(self) -> Option<FilterExpression<'static, Entity>> {
+        match self {
+            Self::Null => None,
+            Self::Expression(expression) => Some(expression),
+        }
+    }
+}
+
+/// Compiles the body of a filter closure, whose parameter is bound to the entity.
+pub(crate) struct FilterCompiler<'compiler, 'env, 'heap> {
+    compiler: &'compiler mut GraphCompiler<'env, 'heap>,
+    entity: Symbol<'heap>,
+}
+
+impl<'compiler, 'env, 'heap> FilterCompiler<'compiler, 'env, 'heap> {
+    pub(crate) const fn new(
+        compiler: &'compiler mut GraphCompiler<'env, 'heap>,
+        entity: Symbol<'heap>,
+    ) -> Self {
+        Self { compiler, entity }
+    }
+
+    fn depends_on_entity(&self, node: &'heap Node<'heap>) -> bool {
+        let mut references = References {
+            name: self.entity,
+            found: false,
+        };
+
+        references.visit_node(node);
+        references.found
+    }
+
+    pub(crate) fn compile(
+        &mut self,
+        scope: &Scope<'heap>,
+        node: &'heap Node<'heap>,
+    ) -> Option<Filter<'static, Entity>> {
+        if !self.depends_on_entity(node) {
+            return self.compile_constant(scope, node);
+        }
+
+        match node.kind {
+            NodeKind::Let(Let {
+                span: _,
+                name,
+                value,
+                body,
+            }) => {
+                if self.depends_on_entity(value) {
+                    self.compiler
+                        .report(unsupported_filter_expression(value.span));
+                    return None;
+                }
+
+                let value = self.compiler.evaluate(scope, value)?;
+                self.compile(&scope.bind(name.value, value), body)
+            }
+            NodeKind::Operation(Operation {
+                span: _,
+                kind:
+                    OperationKind::Binary(BinaryOperation {
+                        span,
+                        op,
+                        left,
+                        right,
+                    }),
+            }) => {
+                self.compile_comparison(scope, *span, Intrinsic::from_bin_op(op.kind), left, right)
+            }
+            NodeKind::Call(call) => self.compile_call(scope, node, call),
+            NodeKind::Branch(Branch {
+                span: _,
+                kind:
+                    BranchKind::If(If {
+                        span: _,
+                        test,
+                        then,
+                        r#else: Some(r#else),
+                    }),
+            }) => {
+                let test = self.compile(scope, test);
+                let then = self.compile(scope, then);
+                let r#else = self.compile(scope, r#else);
+                let (test, then, r#else) = (test?, then?, r#else?);
+
+                Some(Filter::Any(vec![
+                    Filter::All(vec![test.clone(), then]),
+                    Filter::All(vec![Filter::Not(Box::new(test)), r#else]),
+                ]))
+            }
+            NodeKind::Access(_) => {
+                let path = self.compile_path(scope, node)?;
+
+                Some(Filter::Equal(
+                    Some(FilterExpression::Path { path }),
+                    Some(FilterExpression::Parameter {
+                        parameter: Parameter::Boolean(true),
+                        convert: None,
+                    }),
+                ))
+            }
+            _ => {
+                self.compiler
+                    .report(unsupported_filter_expression(node.span));
+                None
+            }
+        }
+    }
+
+    fn compile_constant(
+        &mut self,
+        scope: &Scope<'heap>,
+        node: &'heap Node<'heap>,
+    ) -> Option<Filter<'static, Entity>> {
+        match self.compiler.evaluate(scope, node)? {
+            // An empty conjunction is always true, an empty disjunction is always false.
+            Value::Boolean(true) => Some(Filter::All(Vec::new())),
+            Value::Boolean(false) => Some(Filter::Any(Vec::new())),
+            value => {
+                self.compiler
+                    .report(non_boolean_filter(node.span, value.kind_name()));
+                None
+            }
+        }
+    }
+
+    fn compile_call(
+        &mut self,
+        scope: &Scope<'heap>,
+        node: &'heap Node<'heap>,
+        Call {
+            span,
+            function,
+            arguments,
+        }: &'heap Call<'heap>,
+    ) -> Option<Filter<'static, Entity>> {
+        let intrinsic = qualified_path(function)
+            .as_deref()
+            .and_then(Intrinsic::from_path);
+
+        match (intrinsic, arguments.0) {
+            (Some(intrinsic @ (Intrinsic::And | Intrinsic::Or)), [left, right]) => {
+                let left = self.compile(scope, &left.value);
+                let right = self.compile(scope, &right.value);
+                let operands = vec![left?, right?];
+
+                Some(if intrinsic == Intrinsic::And {
+                    Filter::All(operands)
+                } else {
+                    Filter::Any(operands)
+                })
+            }
+            (Some(Intrinsic::Not), [operand]) => self
+                .compile(scope, &operand.value)
+                .map(|filter| Filter::Not(Box::new(filter))),
+            (Some(intrinsic), [left, right]) => {
+                self.compile_comparison(scope, *span, intrinsic, &left.value, &right.value)
+            }
+            _ => {
+                self.compiler
+                    .report(unsupported_filter_expression(node.span));
+                None
+            }
+        }
+    }
+
+    fn compile_comparison(
+        &mut self,
+        scope: &Scope<'heap>,
+        span: SpanId,
+        intrinsic: Intrinsic,
+        left: &'heap Node<'heap>,
+        right: &'heap Node<'heap>,
+    ) -> Option<Filter<'static, Entity>> {
+        let left = self.compile_expression(scope, left);
+        let right = self.compile_expression(scope, right);
+        let (left, right) = (left?.into_expression(), right?.into_expression());
+
+        let filter = match (intrinsic, left, right) {
+            (Intrinsic::Eq, left, right) => Filter::Equal(left, right),
+            (Intrinsic::Ne, left, right) => Filter::NotEqual(left, right),
+            (Intrinsic::Lt, Some(left), Some(right)) => Filter::Less(left, right),
+            (Intrinsic::Lte, Some(left), Some(right)) => Filter::LessOrEqual(left, right),
+            (Intrinsic::Gt, Some(left), Some(right)) => Filter::Greater(left, right),
+            (Intrinsic::Gte, Some(left), Some(right)) => Filter::GreaterOrEqual(left, right),
+            (Intrinsic::Lt | Intrinsic::Lte | Intrinsic::Gt | Intrinsic::Gte, ..) => {
+                self.compiler.report(null_comparison(span));
+                return None;
+            }
+            _ => {
+                self.compiler.report(unsupported_filter_expression(span));
+                return None;
+            }
+        };
+
+        Some(filter)
+    }
+
+    /// Compiles an operand of a comparison.
+    fn compile_expression(
+        &mut self,
+        scope: &Scope<'heap>,
+        node: &'heap Node<'heap>,
+    ) -> Option<Operand> {
+        if self.depends_on_entity(node) {
+            let path = self.compile_path(scope, node)?;
+            return Some(Operand::Expression(FilterExpression::Path { path }));
+        }
+
+        let value = self.compiler.evaluate(scope, node)?;
+        self.compile_parameter(node.span, &value)
+    }
+
+    /// Converts a value into a parameter of the query.
+    ///
+    /// Opaque values are represented by the value they wrap.
+    fn compile_parameter(&mut self, span: SpanId, value: &Value<'heap>) -> Option<Operand> {
+        let parameter = match value {
+            Value::Null => return Some(Operand::Null),
+            &Value::Boolean(boolean) => Parameter::Boolean(boolean),
+            &Value::Integer(integer) => Parameter::Decimal(Real::from(integer)),
+            &Value::Number(number) => {
+                let Ok(number) = Real::try_from(number) else {
+                    self.compiler.report(unrepresentable_number(span, number));
+                    return None;
+                };
+
+                Parameter::Decimal(number)
+            }
+            Value::String(string) => Parameter::Text(Cow::Owned(string.to_string())),
+            Value::Opaque(_, value) => return self.compile_parameter(span, value),
+            Value::Struct(_)
+            | Value::Tuple(_)
+            | Value::List(_)
+            | Value::Dict(_)
+            | Value::Function(_) => {
+                self.compiler
+                    .report(unsupported_parameter(span, value.kind_name()));
+                return None;
+            }
+        };
+
+        Some(Operand::Expression(FilterExpression::Parameter {
+            parameter,
+            convert: None,
+        }))
+    }
+
+    fn compile_path_token(
+        &mut self,
+        node: &'heap Node<'heap>,
+        value: &Value<'heap>,
+    ) -> Option<PathToken<'static>> {
+        match value {
+            Value::String(string) => Some(PathToken::Field(Cow::Owned(string.to_string()))),
+            &Value::Integer(integer) => {
+                if let Ok(index) = usize::try_from(integer) {
+                    Some(PathToken::Index(index))
+                } else {
+                    self.compiler
+                        .report(unsupported_parameter(node.span, value.kind_name()));
+                    None
+                }
+            }
+            Value::Opaque(_, value) => self.compile_path_token(node, value),
+            _ => {
+                self.compiler
+                    .report(unsupported_parameter(node.span, value.kind_name()));
+                None
+            }
+        }
+    }
+
+    /// Compiles a chain of field and index accesses on the entity into a query path.
+    fn compile_path(
+        &mut self,
+        scope: &Scope<'heap>,
+        node: &'heap Node<'heap>,
+    ) -> Option<EntityQueryPath<'static>> {
+        let mut tokens = Vec::new();
+        let mut current = node;
+
+        loop {
+            match current.kind {
+                NodeKind::Access(Access {
+                    span: _,
+                    kind:
+                        AccessKind::Field(FieldAccess {
+                            span: _,
+                            expr,
+                            field,
+                        }),
+                }) => {
+                    tokens.push(PathToken::Field(Cow::Owned(
+                        field.value.as_str().to_owned(),
+                    )));
+                    current = expr;
+                }
+                NodeKind::Access(Access {
+                    span: _,
+                    kind:
+                        AccessKind::Index(IndexAccess {
+                            span: _,
+                            expr,
+                            index,
+                        }),
+                }) => {
+                    if self.depends_on_entity(index) {
+                        self.compiler
+                            .report(unsupported_filter_expression(index.span));
+                        return None;
+                    }
+
+                    let key = self.compiler.evaluate(scope, index)?;
+                    tokens.push(self.compile_path_token(index, &key)?);
+                    current = expr;
+                }
+                NodeKind::Variable(Variable {
+                    span: _,
+                    kind: VariableKind::Local(LocalVariable { name, .. }),
+                }) if name.value == self.entity => break,
+                _ => {
+                    self.compiler
+                        .report(unsupported_filter_expression(current.span));
+                    return None;
+                }
+            }
+        }
+
+        tokens.reverse();
+
+        let path = path::resolve(&tokens);
+        if path.is_none() {
+            self.compiler
+                .report(unknown_entity_path(node.span, &path::display(&tokens)));
+        }
+
+        path
+    }
+}
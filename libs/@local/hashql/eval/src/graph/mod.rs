@@ -0,0 +1,285 @@
+//! Compilation of graph operations into queries against the graph store.
+//!
+//! The interpreter is unable to evaluate graph operations, as these require access to the graph.
+//! Instead, a program ending in a graph read is compiled into an [`EntityQuery`], which consists
+//! of a [`Filter`] and the temporal axes it is applied on, and can be executed through
+//! [`EntityStore::get_entities`].
+//!
+//! Values bound with `let` before the graph read are evaluated by the [`Interpreter`] and may be
+//! used inside of filters. See the [`filter`] module for the expressions supported inside of
+//! filters.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let compiler = GraphCompiler::new(&environment, &types.locals, &inputs);
+//! let (Some(query), diagnostics) = compiler.compile(node) else {
+//!     // report diagnostics
+//! };
+//!
+//! let response = store
+//!     .get_entities(
+//!         actor_id,
+//!         GetEntitiesParams {
+//!             filter: query.filter,
+//!             temporal_axes: query.temporal_axes,
+//!             ..
+//!         },
+//!     )
+//!     .await?;
+//! ```
+//!
+//! [`EntityStore::get_entities`]: hash_graph_store::entity::EntityStore::get_entities
+pub mod error;
+pub mod filter;
+mod path;
+
+use hash_graph_store::{
+    filter::Filter,
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use hashql_core::{module::locals::TypeLocals, r#type::environment::Environment};
+use hashql_hir::node::{
+    Node,
+    call::Call,
+    closure::Closure as ClosureNode,
+    graph::{
+        Graph, GraphKind,
+        read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+    },
+    kind::NodeKind,
+    r#let::Let,
+    variable::{QualifiedVariable, Variable, VariableKind},
+};
+use type_system::knowledge::Entity;
+
+use self::{
+    error::{
+        GraphDiagnostic, expected_filter_closure, expected_graph_read, filter_parameter_count,
+        unsupported_temporal_axis,
+    },
+    filter::FilterCompiler,
+};
+use crate::{
+    error::{EvalDiagnostic, EvalDiagnosticCategory},
+    interpret::{
+        Inputs, Interpreter,
+        scope::Scope,
+        value::{Closure, Function, Value},
+    },
+};
+
+/// Returns the absolute path of a qualified variable, or `None` if the node is not a qualified
+/// variable.
+fn qualified_path(node: &Node<'_>) -> Option<String> {
+    let NodeKind::Variable(Variable {
+        span: _,
+        kind: VariableKind::Qualified(QualifiedVariable { path, .. }),
+    }) = node.kind
+    else {
+        return None;
+    };
+
+    Some(
+        path.0
+            .iter()
+            .flat_map(|ident| ["::", ident.value.as_str()])
+            .collect(),
+    )
+}
+
+/// A query for entities, compiled from a graph read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityQuery<'p> {
+    /// The filter every returned entity satisfies.
+    pub filter: Filter<'p, Entity>,
+    /// The temporal axes the filter is applied on.
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+}
+
+/// Compiles graph reads into queries against the graph store.
+#[derive(Debug)]
+pub struct GraphCompiler<'env, 'heap> {
+    interpreter: Interpreter<'env, 'heap>,
+    diagnostics: Vec<EvalDiagnostic>,
+}
+
+impl<'env, 'heap> GraphCompiler<'env, 'heap> {
+    #[must_use]
+    pub const fn new(
+        env: &'env Environment<'heap>,
+        locals: &'env TypeLocals<'heap>,
+        inputs: &'env Inputs<'heap>,
+    ) -> Self {
+        Self {
+            interpreter: Interpreter::new(env, locals, inputs),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, diagnostic: GraphDiagnostic) {
+        self.diagnostics
+            .push(diagnostic.map_category(EvalDiagnosticCategory::Graph));
+    }
+
+    fn evaluate(&mut self, scope: &Scope<'heap>, node: &Node<'heap>) -> Option<Value<'heap>> {
+        match self.interpreter.eval(scope, *node) {
+            Ok(value) => Some(value),
+            Err(diagnostic) => {
+                self.diagnostics
+                    .push(diagnostic.map_category(EvalDiagnosticCategory::Interpret));
+                None
+            }
+        }
+    }
+
+    /// Compiles a program, which evaluates to a graph read, into an [`EntityQuery`].
+    ///
+    /// Compilation continues after an error has been encountered, so that all errors are
+    /// reported at once. The query is only returned if no error has been encountered.
+    #[must_use]
+    pub fn compile(
+        mut self,
+        node: Node<'heap>,
+    ) -> (Option<EntityQuery<'static>>, Vec<EvalDiagnostic>) {
+        let query = self.compile_program(&Scope::default(), node);
+
+        (query, self.diagnostics)
+    }
+
+    fn compile_program(
+        &mut self,
+        scope: &Scope<'heap>,
+        node: Node<'heap>,
+    ) -> Option<EntityQuery<'static>> {
+        match node.kind {
+            NodeKind::Let(Let {
+                span: _,
+                name,
+                value,
+                body,
+            }) => {
+                let value = self.evaluate(scope, value)?;
+
+                self.compile_program(&scope.bind(name.value, value), *body)
+            }
+            NodeKind::Graph(Graph {
+                span: _,
+                kind: GraphKind::Read(read),
+            }) => self.compile_read(scope, read),
+            _ => {
+                self.report(expected_graph_read(node.span));
+                None
+            }
+        }
+    }
+
+    fn compile_read(
+        &mut self,
+        scope: &Scope<'heap>,
+        GraphRead {
+            span: _,
+            head,
+            body,
+            tail,
+        }: &'heap GraphRead<'heap>,
+    ) -> Option<EntityQuery<'static>> {
+        let GraphReadHead::Entity { axis } = head;
+        let temporal_axes = self.compile_temporal_axes(axis);
+
+        let filters: Vec<_> = body
+            .0
+            .iter()
+            .map(|body| match body {
+                GraphReadBody::Filter(filter) => self.compile_filter(scope, filter),
+            })
+            .collect();
+
+        // `collect` returns every entity matching the filter, which is exactly what the query
+        // does, so the tail does not influence the query.
+        match tail {
+            GraphReadTail::Collect => {}
+        }
+
+        let temporal_axes = temporal_axes?;
+        let mut filters = filters.into_iter().collect::<Option<Vec<_>>>()?;
+
+        let filter = if filters.len() == 1 {
+            filters.pop()?
+        } else {
+            Filter::All(filters)
+        };
+
+        Some(EntityQuery {
+            filter,
+            temporal_axes,
+        })
+    }
+
+    fn compile_temporal_axes(
+        &mut self,
+        axis: &'heap Node<'heap>,
+    ) -> Option<QueryTemporalAxesUnresolved> {
+        let NodeKind::Call(Call {
+            span: _,
+            function,
+            arguments,
+        }) = axis.kind
+        else {
+            self.report(unsupported_temporal_axis(axis.span));
+            return None;
+        };
+
+        // Unset bounds are resolved to the point in time the query is executed.
+        let temporal_axes = match qualified_path(function).as_deref() {
+            Some("::graph::tmp::decision_time_now") if arguments.is_empty() => {
+                QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                }
+            }
+            Some("::graph::tmp::transaction_time_now") if arguments.is_empty() => {
+                QueryTemporalAxesUnresolved::TransactionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                }
+            }
+            _ => {
+                self.report(unsupported_temporal_axis(axis.span));
+                return None;
+            }
+        };
+
+        Some(temporal_axes)
+    }
+
+    fn compile_filter(
+        &mut self,
+        scope: &Scope<'heap>,
+        node: &'heap Node<'heap>,
+    ) -> Option<Filter<'static, Entity>> {
+        let value = self.evaluate(scope, node)?;
+
+        let Value::Function(Function::Closure(Closure {
+            node: closure,
+            scope,
+        })) = &value
+        else {
+            self.report(expected_filter_closure(node.span, value.kind_name()));
+            return None;
+        };
+
+        let [param] = closure.signature.params.0 else {
+            self.report(filter_parameter_count(
+                closure.span,
+                closure.signature.params.len(),
+            ));
+            return None;
+        };
+
+        let closure: &'heap ClosureNode<'heap> = closure;
+        FilterCompiler::new(self, param.name.value).compile(scope, &closure.body)
+    }
+}
@@ -0,0 +1,117 @@
+//! Resolution of field accesses on an entity to [`EntityQueryPath`]s.
+//!
+//! The fields follow the structure of [`Entity`], the fields of properties and their metadata
+//! are translated into a [`JsonPath`].
+//!
+//! [`Entity`]: type_system::knowledge::Entity
+use hash_graph_store::{
+    entity::EntityQueryPath,
+    filter::{JsonPath, PathToken},
+    subgraph::edges::{EdgeDirection, KnowledgeGraphEdgeKind},
+};
+
+fn field<'a>(token: &'a PathToken<'_>) -> Option<&'a str> {
+    match token {
+        PathToken::Field(field) => Some(field),
+        PathToken::Index(_) => None,
+    }
+}
+
+fn json_path(tokens: &[PathToken<'static>]) -> Option<JsonPath<'static>> {
+    (!tokens.is_empty()).then(|| JsonPath::from_path_tokens(tokens.to_vec()))
+}
+
+fn resolve_entity_id(tokens: &[PathToken<'_>]) -> Option<EntityQueryPath<'static>> {
+    let [token] = tokens else {
+        return None;
+    };
+
+    match field(token)? {
+        "web_id" => Some(EntityQueryPath::WebId),
+        "entity_uuid" => Some(EntityQueryPath::Uuid),
+        "draft_id" => Some(EntityQueryPath::DraftId),
+        _ => None,
+    }
+}
+
+fn resolve_metadata(tokens: &[PathToken<'static>]) -> Option<EntityQueryPath<'static>> {
+    let (first, rest) = tokens.split_first()?;
+
+    match (field(first)?, rest) {
+        ("record_id", [entity_id, rest @ ..]) if field(entity_id) == Some("entity_id") => {
+            resolve_entity_id(rest)
+        }
+        ("record_id", [edition_id]) if field(edition_id) == Some("edition_id") => {
+            Some(EntityQueryPath::EditionId)
+        }
+        ("archived", []) => Some(EntityQueryPath::Archived),
+        ("confidence", []) => Some(EntityQueryPath::EntityConfidence),
+        ("provenance", [edition, rest @ ..]) if field(edition) == Some("edition") => {
+            Some(EntityQueryPath::EditionProvenance(json_path(rest)))
+        }
+        ("provenance", rest) => Some(EntityQueryPath::Provenance(json_path(rest))),
+        ("properties", rest) => Some(EntityQueryPath::PropertyMetadata(json_path(rest))),
+        _ => None,
+    }
+}
+
+fn resolve_link_data(tokens: &[PathToken<'_>]) -> Option<EntityQueryPath<'static>> {
+    let (first, rest) = tokens.split_first()?;
+
+    let edge = |edge_kind, path| EntityQueryPath::EntityEdge {
+        edge_kind,
+        path: Box::new(path),
+        direction: EdgeDirection::Outgoing,
+    };
+
+    match (field(first)?, rest) {
+        ("left_entity_id", rest) => {
+            resolve_entity_id(rest).map(|path| edge(KnowledgeGraphEdgeKind::HasLeftEntity, path))
+        }
+        ("right_entity_id", rest) => {
+            resolve_entity_id(rest).map(|path| edge(KnowledgeGraphEdgeKind::HasRightEntity, path))
+        }
+        ("left_entity_confidence", []) => Some(EntityQueryPath::LeftEntityConfidence),
+        ("right_entity_confidence", []) => Some(EntityQueryPath::RightEntityConfidence),
+        ("left_entity_provenance", []) => Some(EntityQueryPath::LeftEntityProvenance),
+        ("right_entity_provenance", []) => Some(EntityQueryPath::RightEntityProvenance),
+        _ => None,
+    }
+}
+
+/// Resolves the path of fields accessed on an entity to the corresponding query path.
+///
+/// Returns `None` if the path does not correspond to a queryable field of the entity.
+pub(crate) fn resolve(tokens: &[PathToken<'static>]) -> Option<EntityQueryPath<'static>> {
+    let (first, rest) = tokens.split_first()?;
+
+    match field(first)? {
+        "properties" => Some(EntityQueryPath::Properties(json_path(rest))),
+        "metadata" => resolve_metadata(rest),
+        "link_data" => resolve_link_data(rest),
+        _ => None,
+    }
+}
+
+/// Formats the path of fields accessed on an entity for use in diagnostics.
+pub(crate) fn display(tokens: &[PathToken<'_>]) -> String {
+    let mut output = String::new();
+
+    for token in tokens {
+        match token {
+            PathToken::Field(field) => {
+                if !output.is_empty() {
+                    output.push('.');
+                }
+                output.push_str(field);
+            }
+            PathToken::Index(index) => {
+                output.push('[');
+                output.push_str(&index.to_string());
+                output.push(']');
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,382 @@
+use alloc::borrow::Cow;
+
+use hashql_core::span::SpanId;
+use hashql_diagnostics::{
+    Diagnostic,
+    category::{DiagnosticCategory, TerminalDiagnosticCategory},
+    help::Help,
+    label::Label,
+    note::Note,
+    severity::Severity,
+};
+
+pub type InterpretDiagnostic = Diagnostic<InterpretDiagnosticCategory, SpanId>;
+
+const MISSING_INPUT: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "missing-input",
+    name: "Missing input value",
+};
+
+const INPUT_TYPE_MISMATCH: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "input-type-mismatch",
+    name: "Input value does not match its declared type",
+};
+
+const TYPE_ASSERTION_FAILED: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "type-assertion-failed",
+    name: "Value does not match asserted type",
+};
+
+const TYPE_MISMATCH: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "type-mismatch",
+    name: "Unexpected value type",
+};
+
+const UNKNOWN_FIELD: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unknown-field",
+    name: "Unknown field",
+};
+
+const INDEX_OUT_OF_BOUNDS: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "index-out-of-bounds",
+    name: "Index out of bounds",
+};
+
+const KEY_NOT_FOUND: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "key-not-found",
+    name: "Key not found",
+};
+
+const ARGUMENT_COUNT_MISMATCH: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "argument-count-mismatch",
+    name: "Wrong number of arguments",
+};
+
+const DIVISION_BY_ZERO: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "division-by-zero",
+    name: "Division by zero",
+};
+
+const INTEGER_OVERFLOW: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "integer-overflow",
+    name: "Integer overflow",
+};
+
+const UNSUPPORTED_INTRINSIC: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unsupported-intrinsic",
+    name: "Intrinsic cannot be evaluated",
+};
+
+const GRAPH_OPERATION: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "graph-operation",
+    name: "Graph operation cannot be evaluated",
+};
+
+const UNBOUND_VARIABLE: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unbound-variable",
+    name: "Unbound variable",
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum InterpretDiagnosticCategory {
+    MissingInput,
+    InputTypeMismatch,
+    TypeAssertionFailed,
+    TypeMismatch,
+    UnknownField,
+    IndexOutOfBounds,
+    KeyNotFound,
+    ArgumentCountMismatch,
+    DivisionByZero,
+    IntegerOverflow,
+    UnsupportedIntrinsic,
+    GraphOperation,
+    UnboundVariable,
+}
+
+impl DiagnosticCategory for InterpretDiagnosticCategory {
+    fn id(&self) -> Cow<'_, str> {
+        Cow::Borrowed("interpret")
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Interpreter")
+    }
+
+    fn subcategory(&self) -> Option<&dyn DiagnosticCategory> {
+        match *self {
+            Self::MissingInput => Some(&MISSING_INPUT),
+            Self::InputTypeMismatch => Some(&INPUT_TYPE_MISMATCH),
+            Self::TypeAssertionFailed => Some(&TYPE_ASSERTION_FAILED),
+            Self::TypeMismatch => Some(&TYPE_MISMATCH),
+            Self::UnknownField => Some(&UNKNOWN_FIELD),
+            Self::IndexOutOfBounds => Some(&INDEX_OUT_OF_BOUNDS),
+            Self::KeyNotFound => Some(&KEY_NOT_FOUND),
+            Self::ArgumentCountMismatch => Some(&ARGUMENT_COUNT_MISMATCH),
+            Self::DivisionByZero => Some(&DIVISION_BY_ZERO),
+            Self::IntegerOverflow => Some(&INTEGER_OVERFLOW),
+            Self::UnsupportedIntrinsic => Some(&UNSUPPORTED_INTRINSIC),
+            Self::GraphOperation => Some(&GRAPH_OPERATION),
+            Self::UnboundVariable => Some(&UNBOUND_VARIABLE),
+        }
+    }
+}
+
+/// Creates a diagnostic for an input that has neither been provided by the caller nor has a
+/// default value.
+pub(crate) fn missing_input(span: SpanId, name: &str) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::MissingInput, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("no value provided for input `{name}`")).with_order(0));
+
+    diagnostic.add_help(Help::new(format!(
+        "Provide a value for `{name}` when running the query, or declare a default value for the \
+         input."
+    )));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for an input value provided by the caller that does not conform to the
+/// type declared by the input expression.
+pub(crate) fn input_type_mismatch(
+    span: SpanId,
+    name: &str,
+    expected: &str,
+    found: &str,
+) -> InterpretDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        InterpretDiagnosticCategory::InputTypeMismatch,
+        Severity::Error,
+    );
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("input `{name}` is declared as `{expected}`, but a `{found}` was provided"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic.add_help(Help::new(format!(
+        "Make sure the value provided for `{name}` matches the declared type `{expected}`."
+    )));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a value that does not match the type it is asserted to have.
+pub(crate) fn type_assertion_failed(
+    span: SpanId,
+    expected: &str,
+    found: &str,
+) -> InterpretDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        InterpretDiagnosticCategory::TypeAssertionFailed,
+        Severity::Error,
+    );
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("expected a value of type `{expected}`, but found `{found}`"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic.add_note(Note::new(
+        "Type assertions are checked against the runtime value during evaluation. The value \
+         produced by the expression must structurally conform to the asserted type.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a value that has a different kind than required by an operation.
+pub(crate) fn type_mismatch(span: SpanId, expected: &[&str], found: &str) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::TypeMismatch, Severity::Error);
+
+    let expected = expected.join("` or `");
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("expected `{expected}`, found `{found}`")).with_order(0));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for accessing a field that does not exist on a value.
+pub(crate) fn unknown_field(
+    span: SpanId,
+    field: &str,
+    available: impl IntoIterator<Item: AsRef<str>>,
+) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::UnknownField, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("no field named `{field}`")).with_order(0));
+
+    let available: Vec<_> = available
+        .into_iter()
+        .map(|name| format!("`{}`", name.as_ref()))
+        .collect();
+
+    if !available.is_empty() {
+        diagnostic.add_help(Help::new(format!(
+            "The available fields are: {}",
+            available.join(", ")
+        )));
+    }
+
+    diagnostic
+}
+
+/// Creates a diagnostic for indexing a list outside of its bounds.
+pub(crate) fn index_out_of_bounds(span: SpanId, index: i128, length: usize) -> InterpretDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        InterpretDiagnosticCategory::IndexOutOfBounds,
+        Severity::Error,
+    );
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("index `{index}` is out of bounds for a list of length {length}"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic
+}
+
+/// Creates a diagnostic for indexing a dictionary with a key that is not present.
+pub(crate) fn key_not_found(span: SpanId, key: &str) -> InterpretDiagnostic {
+    let mut diagnostic = Diagnostic::new(InterpretDiagnosticCategory::KeyNotFound, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("key `{key}` does not exist in the dict")).with_order(0));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for calling a function with the wrong number of arguments.
+pub(crate) fn argument_count_mismatch(
+    span: SpanId,
+    expected: usize,
+    found: usize,
+) -> InterpretDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        InterpretDiagnosticCategory::ArgumentCountMismatch,
+        Severity::Error,
+    );
+
+    let plural = if expected == 1 { "" } else { "s" };
+    let verb = if found == 1 { "was" } else { "were" };
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            format!("expected {expected} argument{plural}, but {found} {verb} provided"),
+        )
+        .with_order(0),
+    );
+
+    diagnostic
+}
+
+/// Creates a diagnostic for dividing by zero.
+pub(crate) fn division_by_zero(span: SpanId) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::DivisionByZero, Severity::Error);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "attempted to divide by zero").with_order(0));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for an integer operation whose result cannot be represented.
+pub(crate) fn integer_overflow(span: SpanId, operation: &str) -> InterpretDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        InterpretDiagnosticCategory::IntegerOverflow,
+        Severity::Error,
+    );
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("attempted to {operation} with overflow")).with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "Integers are represented as 128-bit signed values during evaluation. Convert the \
+         operands to `Number` if the result does not need to be exact.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a qualified path that the interpreter is unable to evaluate.
+pub(crate) fn unsupported_intrinsic(span: SpanId, path: &str) -> InterpretDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        InterpretDiagnosticCategory::UnsupportedIntrinsic,
+        Severity::Error,
+    );
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("`{path}` cannot be evaluated")).with_order(0));
+
+    diagnostic.add_note(Note::new(
+        "The interpreter only supports the value constructors of the standard library and the \
+         functions in the `::math` module. Other items are only available at the type level or \
+         require a connection to the graph.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a graph operation, which requires a graph store to be evaluated.
+pub(crate) fn graph_operation(span: SpanId) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::GraphOperation, Severity::Error);
+
+    diagnostic.labels.push(
+        Label::new(
+            span,
+            "graph operations cannot be evaluated by the interpreter",
+        )
+        .with_order(0),
+    );
+
+    diagnostic.add_help(Help::new(
+        "Compile the graph operation into a query using the `graph` module of this crate and \
+         execute it against a graph store instead.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a local variable that is not bound in the current scope.
+#[coverage(off)] // compiler bugs should never be hit
+pub(crate) fn unbound_variable(span: SpanId, name: &str) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::UnboundVariable, Severity::Bug);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("`{name}` is not bound in this scope")).with_order(0));
+
+    diagnostic.add_note(Note::new(
+        "Name resolution should have rejected any reference to an unbound variable before \
+         evaluation. This indicates a bug in an earlier compilation phase.",
+    ));
+
+    diagnostic
+}
@@ -0,0 +1,386 @@
+//! Functions of the standard library that are implemented natively by the interpreter.
+use core::cmp::Ordering;
+
+use hashql_core::span::SpanId;
+use hashql_hir::node::operation::binary::BinOpKind;
+
+use super::{
+    error::{
+        InterpretDiagnostic, argument_count_mismatch, division_by_zero, integer_overflow,
+        type_mismatch,
+    },
+    value::Value,
+};
+
+/// An argument passed to a function, together with the span of the expression that produced
+/// it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Argument<'heap> {
+    pub span: SpanId,
+    pub value: Value<'heap>,
+}
+
+/// A function of the standard library, which is natively implemented by the interpreter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Intrinsic {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Mod,
+    Pow,
+    Sqrt,
+    Cbrt,
+    Root,
+    BitAnd,
+    BitOr,
+    BitXor,
+    BitNot,
+    BitShl,
+    BitShr,
+    Gt,
+    Lt,
+    Gte,
+    Lte,
+    Eq,
+    Ne,
+    Not,
+    And,
+    Or,
+}
+
+impl Intrinsic {
+    /// Looks up the intrinsic with the given absolute path.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let intrinsic = match path {
+            "::math::add" => Self::Add,
+            "::math::sub" => Self::Sub,
+            "::math::mul" => Self::Mul,
+            "::math::div" => Self::Div,
+            "::math::rem" => Self::Rem,
+            "::math::mod" => Self::Mod,
+            "::math::pow" => Self::Pow,
+            "::math::sqrt" => Self::Sqrt,
+            "::math::cbrt" => Self::Cbrt,
+            "::math::root" => Self::Root,
+            "::math::bit_and" => Self::BitAnd,
+            "::math::bit_or" => Self::BitOr,
+            "::math::bit_xor" => Self::BitXor,
+            "::math::bit_not" => Self::BitNot,
+            "::math::bit_shl" => Self::BitShl,
+            "::math::bit_shr" => Self::BitShr,
+            "::math::gt" => Self::Gt,
+            "::math::lt" => Self::Lt,
+            "::math::gte" => Self::Gte,
+            "::math::lte" => Self::Lte,
+            "::math::eq" => Self::Eq,
+            "::math::ne" => Self::Ne,
+            "::math::not" => Self::Not,
+            "::math::and" => Self::And,
+            "::math::or" => Self::Or,
+            _ => return None,
+        };
+
+        Some(intrinsic)
+    }
+
+    /// The intrinsic a binary operator desugars to.
+    #[must_use]
+    pub const fn from_bin_op(op: BinOpKind) -> Self {
+        match op {
+            BinOpKind::Eq => Self::Eq,
+            BinOpKind::Ne => Self::Ne,
+            BinOpKind::Lt => Self::Lt,
+            BinOpKind::Lte => Self::Lte,
+            BinOpKind::Ge => Self::Gte,
+            BinOpKind::Gte => Self::Gt,
+        }
+    }
+
+    /// The absolute path under which the intrinsic is registered in the standard library.
+    #[must_use]
+    pub const fn path(self) -> &'static str {
+        match self {
+            Self::Add => "::math::add",
+            Self::Sub => "::math::sub",
+            Self::Mul => "::math::mul",
+            Self::Div => "::math::div",
+            Self::Rem => "::math::rem",
+            Self::Mod => "::math::mod",
+            Self::Pow => "::math::pow",
+            Self::Sqrt => "::math::sqrt",
+            Self::Cbrt => "::math::cbrt",
+            Self::Root => "::math::root",
+            Self::BitAnd => "::math::bit_and",
+            Self::BitOr => "::math::bit_or",
+            Self::BitXor => "::math::bit_xor",
+            Self::BitNot => "::math::bit_not",
+            Self::BitShl => "::math::bit_shl",
+            Self::BitShr => "::math::bit_shr",
+            Self::Gt => "::math::gt",
+            Self::Lt => "::math::lt",
+            Self::Gte => "::math::gte",
+            Self::Lte => "::math::lte",
+            Self::Eq => "::math::eq",
+            Self::Ne => "::math::ne",
+            Self::Not => "::math::not",
+            Self::And => "::math::and",
+            Self::Or => "::math::or",
+        }
+    }
+
+    /// The number of arguments the intrinsic expects.
+    #[must_use]
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::Sqrt | Self::Cbrt | Self::BitNot | Self::Not => 1,
+            Self::Add
+            | Self::Sub
+            | Self::Mul
+            | Self::Div
+            | Self::Rem
+            | Self::Mod
+            | Self::Pow
+            | Self::Root
+            | Self::BitAnd
+            | Self::BitOr
+            | Self::BitXor
+            | Self::BitShl
+            | Self::BitShr
+            | Self::Gt
+            | Self::Lt
+            | Self::Gte
+            | Self::Lte
+            | Self::Eq
+            | Self::Ne
+            | Self::And
+            | Self::Or => 2,
+        }
+    }
+
+    #[expect(
+        clippy::float_arithmetic,
+        clippy::modulo_arithmetic,
+        reason = "the arithmetic intrinsics operate on numbers"
+    )]
+    pub(crate) fn apply<'heap>(
+        self,
+        span: SpanId,
+        arguments: &[Argument<'heap>],
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match (self, arguments) {
+            (Self::Add, [lhs, rhs]) => {
+                arithmetic(span, lhs, rhs, "add", i128::checked_add, |lhs, rhs| {
+                    lhs + rhs
+                })
+            }
+            (Self::Sub, [lhs, rhs]) => {
+                arithmetic(span, lhs, rhs, "subtract", i128::checked_sub, |lhs, rhs| {
+                    lhs - rhs
+                })
+            }
+            (Self::Mul, [lhs, rhs]) => {
+                arithmetic(span, lhs, rhs, "multiply", i128::checked_mul, |lhs, rhs| {
+                    lhs * rhs
+                })
+            }
+            (Self::Div, [lhs, rhs]) => {
+                let dividend = Numeric::try_from(lhs)?.as_f64();
+                let divisor = Numeric::try_from(rhs)?.as_f64();
+
+                if divisor == 0.0 {
+                    return Err(division_by_zero(rhs.span));
+                }
+
+                Ok(Value::Number(dividend / divisor))
+            }
+            (Self::Rem, [lhs, rhs]) => {
+                remainder(span, lhs, rhs, i128::checked_rem, |lhs, rhs| lhs % rhs)
+            }
+            (Self::Mod, [lhs, rhs]) => {
+                remainder(span, lhs, rhs, i128::checked_rem_euclid, f64::rem_euclid)
+            }
+            (Self::Pow, [lhs, rhs]) => match (Numeric::try_from(lhs)?, Numeric::try_from(rhs)?) {
+                (Numeric::Integer(base), Numeric::Integer(exponent)) if exponent >= 0 => {
+                    let Some(value) = u32::try_from(exponent)
+                        .ok()
+                        .and_then(|exponent| base.checked_pow(exponent))
+                    else {
+                        return Err(integer_overflow(span, "exponentiate"));
+                    };
+
+                    Ok(Value::Integer(value))
+                }
+                (base, exponent) => Ok(Value::Number(base.as_f64().powf(exponent.as_f64()))),
+            },
+            (Self::Sqrt, [value]) => Ok(Value::Number(Numeric::try_from(value)?.as_f64().sqrt())),
+            (Self::Cbrt, [value]) => Ok(Value::Number(Numeric::try_from(value)?.as_f64().cbrt())),
+            (Self::Root, [value, degree]) => {
+                let value = Numeric::try_from(value)?.as_f64();
+                let degree = Numeric::try_from(degree)?.as_f64();
+
+                Ok(Value::Number(value.powf(degree.recip())))
+            }
+            (Self::BitAnd, [lhs, rhs]) => Ok(Value::Integer(integer(lhs)? & integer(rhs)?)),
+            (Self::BitOr, [lhs, rhs]) => Ok(Value::Integer(integer(lhs)? | integer(rhs)?)),
+            (Self::BitXor, [lhs, rhs]) => Ok(Value::Integer(integer(lhs)? ^ integer(rhs)?)),
+            (Self::BitNot, [value]) => Ok(Value::Integer(!integer(value)?)),
+            (Self::BitShl, [lhs, rhs]) => shift(span, lhs, rhs, "shift left", i128::checked_shl),
+            (Self::BitShr, [lhs, rhs]) => shift(span, lhs, rhs, "shift right", i128::checked_shr),
+            (Self::Gt, [lhs, rhs]) => Ok(Value::Boolean(
+                compare(lhs, rhs)?.is_some_and(Ordering::is_gt),
+            )),
+            (Self::Lt, [lhs, rhs]) => Ok(Value::Boolean(
+                compare(lhs, rhs)?.is_some_and(Ordering::is_lt),
+            )),
+            (Self::Gte, [lhs, rhs]) => Ok(Value::Boolean(
+                compare(lhs, rhs)?.is_some_and(Ordering::is_ge),
+            )),
+            (Self::Lte, [lhs, rhs]) => Ok(Value::Boolean(
+                compare(lhs, rhs)?.is_some_and(Ordering::is_le),
+            )),
+            (Self::Eq, [lhs, rhs]) => Ok(Value::Boolean(lhs.value == rhs.value)),
+            (Self::Ne, [lhs, rhs]) => Ok(Value::Boolean(lhs.value != rhs.value)),
+            (Self::Not, [value]) => Ok(Value::Boolean(!boolean(value)?)),
+            (Self::And, [lhs, rhs]) => Ok(Value::Boolean(boolean(lhs)? && boolean(rhs)?)),
+            (Self::Or, [lhs, rhs]) => Ok(Value::Boolean(boolean(lhs)? || boolean(rhs)?)),
+            _ => Err(argument_count_mismatch(span, self.arity(), arguments.len())),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Numeric {
+    Integer(i128),
+    Number(f64),
+}
+
+impl Numeric {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "integers are a refinement of numbers, precision loss is expected"
+    )]
+    const fn as_f64(self) -> f64 {
+        match self {
+            Self::Integer(integer) => integer as f64,
+            Self::Number(number) => number,
+        }
+    }
+}
+
+impl TryFrom<&Argument<'_>> for Numeric {
+    type Error = InterpretDiagnostic;
+
+    fn try_from(argument: &Argument<'_>) -> Result<Self, Self::Error> {
+        match argument.value {
+            Value::Integer(integer) => Ok(Self::Integer(integer)),
+            Value::Number(number) => Ok(Self::Number(number)),
+            ref value => Err(type_mismatch(argument.span, &["Number"], value.kind_name())),
+        }
+    }
+}
+
+fn integer(argument: &Argument<'_>) -> Result<i128, InterpretDiagnostic> {
+    match argument.value {
+        Value::Integer(integer) => Ok(integer),
+        ref value => Err(type_mismatch(
+            argument.span,
+            &["Integer"],
+            value.kind_name(),
+        )),
+    }
+}
+
+fn boolean(argument: &Argument<'_>) -> Result<bool, InterpretDiagnostic> {
+    match argument.value {
+        Value::Boolean(boolean) => Ok(boolean),
+        ref value => Err(type_mismatch(
+            argument.span,
+            &["Boolean"],
+            value.kind_name(),
+        )),
+    }
+}
+
+fn arithmetic<'heap>(
+    span: SpanId,
+    lhs: &Argument<'heap>,
+    rhs: &Argument<'heap>,
+    operation: &str,
+    on_integer: fn(i128, i128) -> Option<i128>,
+    on_number: fn(f64, f64) -> f64,
+) -> Result<Value<'heap>, InterpretDiagnostic> {
+    match (Numeric::try_from(lhs)?, Numeric::try_from(rhs)?) {
+        (Numeric::Integer(lhs), Numeric::Integer(rhs)) => on_integer(lhs, rhs)
+            .map(Value::Integer)
+            .ok_or_else(|| integer_overflow(span, operation)),
+        (lhs, rhs) => Ok(Value::Number(on_number(lhs.as_f64(), rhs.as_f64()))),
+    }
+}
+
+fn remainder<'heap>(
+    span: SpanId,
+    lhs: &Argument<'heap>,
+    rhs: &Argument<'heap>,
+    on_integer: fn(i128, i128) -> Option<i128>,
+    on_number: fn(f64, f64) -> f64,
+) -> Result<Value<'heap>, InterpretDiagnostic> {
+    let dividend = Numeric::try_from(lhs)?;
+    let divisor = Numeric::try_from(rhs)?;
+
+    if divisor.as_f64() == 0.0 {
+        return Err(division_by_zero(rhs.span));
+    }
+
+    match (dividend, divisor) {
+        (Numeric::Integer(dividend), Numeric::Integer(divisor)) => on_integer(dividend, divisor)
+            .map(Value::Integer)
+            .ok_or_else(|| integer_overflow(span, "calculate the remainder")),
+        (dividend, divisor) => Ok(Value::Number(on_number(
+            dividend.as_f64(),
+            divisor.as_f64(),
+        ))),
+    }
+}
+
+fn shift<'heap>(
+    span: SpanId,
+    lhs: &Argument<'heap>,
+    rhs: &Argument<'heap>,
+    operation: &str,
+    on_integer: fn(i128, u32) -> Option<i128>,
+) -> Result<Value<'heap>, InterpretDiagnostic> {
+    let value = integer(lhs)?;
+    let amount = integer(rhs)?;
+
+    let Some(value) = u32::try_from(amount)
+        .ok()
+        .and_then(|amount| on_integer(value, amount))
+    else {
+        return Err(integer_overflow(span, operation));
+    };
+
+    Ok(Value::Integer(value))
+}
+
+/// Compares two values, returning [`None`] if the values are unordered (e.g. `NaN`).
+///
+/// Numbers are compared by their numeric value, strings lexicographically.
+fn compare(
+    lhs: &Argument<'_>,
+    rhs: &Argument<'_>,
+) -> Result<Option<Ordering>, InterpretDiagnostic> {
+    match (&lhs.value, &rhs.value) {
+        (Value::Integer(lhs), Value::Integer(rhs)) => Ok(Some(lhs.cmp(rhs))),
+        (Value::String(lhs), Value::String(rhs)) => Ok(Some(lhs.cmp(rhs))),
+        (Value::String(_), value) => Err(type_mismatch(rhs.span, &["String"], value.kind_name())),
+        _ => {
+            let lhs = Numeric::try_from(lhs)?;
+            let rhs = Numeric::try_from(rhs)?;
+
+            Ok(lhs.as_f64().partial_cmp(&rhs.as_f64()))
+        }
+    }
+}
@@ -0,0 +1,758 @@
+//! Tree-walking interpreter for the HashQL HIR.
+//!
+//! The interpreter evaluates a [`Node`] directly, without requiring a connection to the graph.
+//! This makes it suitable to test the logic of a query in isolation.
+//!
+//! Values of `input` expressions are supplied by the caller through [`Inputs`]. If an input has
+//! not been supplied the default value of the input is evaluated instead. Supplied inputs are
+//! checked against the type declared by the input expression.
+//!
+//! Evaluation stops at the first runtime error, which is reported as a diagnostic pointing at the
+//! expression that caused it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut inputs = Inputs::default();
+//! inputs.insert(heap.intern_symbol("limit"), Value::Integer(10));
+//!
+//! let interpreter = Interpreter::new(&environment, &types.locals, &inputs);
+//! let value = interpreter.run(node)?;
+//! ```
+pub mod error;
+pub mod intrinsic;
+pub(crate) mod scope;
+pub mod value;
+
+use alloc::rc::Rc;
+
+use hashql_core::{
+    collection::{FastHashMap, TinyVec},
+    literal::LiteralKind,
+    module::locals::TypeLocals,
+    pretty::{PrettyOptions, PrettyPrint as _},
+    span::SpanId,
+    symbol::Symbol,
+    r#type::{
+        TypeId,
+        environment::Environment,
+        kind::{
+            Apply, Generic, IntersectionType, IntrinsicType, OpaqueType, PrimitiveType, StructType,
+            TupleType, TypeKind, UnionType,
+            intrinsic::{DictType, ListType},
+        },
+    },
+};
+use hashql_hir::node::{
+    Node,
+    access::{Access, AccessKind, field::FieldAccess, index::IndexAccess},
+    branch::{Branch, BranchKind, If},
+    call::Call,
+    data::{Data, DataKind, Literal},
+    input::Input,
+    kind::NodeKind,
+    r#let::Let,
+    operation::{
+        BinaryOperation, Operation, OperationKind, TypeOperation,
+        r#type::{TypeAssertion, TypeConstructor, TypeOperationKind},
+    },
+    variable::{LocalVariable, QualifiedVariable, Variable, VariableKind},
+};
+
+use self::{
+    error::{
+        InterpretDiagnostic, argument_count_mismatch, graph_operation, index_out_of_bounds,
+        input_type_mismatch, key_not_found, missing_input, type_assertion_failed, type_mismatch,
+        unbound_variable, unknown_field, unsupported_intrinsic,
+    },
+    intrinsic::{Argument, Intrinsic},
+    scope::Scope,
+    value::{Closure, Function, Value},
+};
+
+/// Values for the `input` expressions of a program, keyed by the name of the input.
+pub type Inputs<'heap> = FastHashMap<Symbol<'heap>, Value<'heap>>;
+
+/// The value constructors of the standard library, which produce opaque values of the type of
+/// the same name.
+const CONSTRUCTORS: &[&str] = &[
+    "::kernel::type::Some",
+    "::kernel::type::None",
+    "::kernel::type::Ok",
+    "::kernel::type::Err",
+    "::kernel::type::Url",
+    "::kernel::type::BaseUrl",
+];
+
+/// Evaluates HIR nodes to values.
+///
+/// The interpreter requires access to the type locals of the program, which are used to resolve
+/// the value constructors of locally defined newtypes.
+#[derive(Debug, Copy, Clone)]
+pub struct Interpreter<'env, 'heap> {
+    env: &'env Environment<'heap>,
+    locals: &'env TypeLocals<'heap>,
+    inputs: &'env Inputs<'heap>,
+}
+
+impl<'env, 'heap> Interpreter<'env, 'heap> {
+    #[must_use]
+    pub const fn new(
+        env: &'env Environment<'heap>,
+        locals: &'env TypeLocals<'heap>,
+        inputs: &'env Inputs<'heap>,
+    ) -> Self {
+        Self {
+            env,
+            locals,
+            inputs,
+        }
+    }
+
+    /// Evaluates the given node to a value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a diagnostic pointing at the offending expression if evaluation fails, for
+    /// example if an input is missing, a value has an unexpected type or an arithmetic operation
+    /// overflows.
+    pub fn run(&self, node: Node<'heap>) -> Result<Value<'heap>, InterpretDiagnostic> {
+        self.eval(&Scope::default(), node)
+    }
+
+    fn pretty_type(&self, id: TypeId) -> String {
+        self.env
+            .r#type(id)
+            .kind
+            .pretty_print(self.env, PrettyOptions::default().without_color())
+            .to_string()
+    }
+
+    fn pretty_value(&self, value: &Value<'heap>) -> String {
+        value
+            .pretty_print(self.env, PrettyOptions::default().without_color())
+            .to_string()
+    }
+
+    /// Checks if the value is an inhabitant of the given type.
+    ///
+    /// Generic parameters and inference variables are not resolved, and accept any value.
+    fn conforms(&self, value: &Value<'heap>, id: TypeId) -> bool {
+        self.conforms_guarded(value, id, &mut TinyVec::new())
+    }
+
+    /// Checks if the value is an inhabitant of the given type.
+    ///
+    /// `visited` contains the types that have been visited without descending into the value,
+    /// which is used to detect types that are recursive without being contractive.
+    fn conforms_guarded(
+        &self,
+        value: &Value<'heap>,
+        id: TypeId,
+        visited: &mut TinyVec<TypeId>,
+    ) -> bool {
+        if visited.contains(&id) {
+            return false;
+        }
+
+        let r#type = self.env.r#type(id);
+
+        let (TypeKind::Union(UnionType { variants: types })
+        | TypeKind::Intersection(IntersectionType { variants: types })) = r#type.kind
+        else {
+            return match (r#type.kind, value) {
+                (TypeKind::Unknown | TypeKind::Param(_) | TypeKind::Infer(_), _)
+                | (TypeKind::Closure(_), Value::Function(_)) => true,
+                (
+                    &(TypeKind::Apply(Apply { base, .. })
+                    | TypeKind::Generic(Generic { base, .. })),
+                    _,
+                ) => {
+                    visited.push(id);
+                    let conforms = self.conforms_guarded(value, base, visited);
+                    visited.pop();
+
+                    conforms
+                }
+                (TypeKind::Primitive(primitive), value) => matches!(
+                    (primitive, value),
+                    (PrimitiveType::Null, Value::Null)
+                        | (PrimitiveType::Boolean, Value::Boolean(_))
+                        | (PrimitiveType::Integer, Value::Integer(_))
+                        | (PrimitiveType::Number, Value::Integer(_) | Value::Number(_))
+                        | (PrimitiveType::String, Value::String(_))
+                ),
+                (
+                    &TypeKind::Opaque(OpaqueType { name, repr }),
+                    Value::Opaque(value_name, value),
+                ) => name == *value_name && self.conforms(value, repr),
+                (
+                    &TypeKind::Intrinsic(IntrinsicType::List(ListType { element })),
+                    Value::List(elements),
+                ) => elements.iter().all(|value| self.conforms(value, element)),
+                (
+                    &TypeKind::Intrinsic(IntrinsicType::Dict(DictType {
+                        key,
+                        value: value_type,
+                    })),
+                    Value::Dict(entries),
+                ) => entries.iter().all(|(entry_key, entry_value)| {
+                    self.conforms(entry_key, key) && self.conforms(entry_value, value_type)
+                }),
+                (TypeKind::Struct(StructType { fields }), Value::Struct(value_fields)) => {
+                    fields.len() == value_fields.len()
+                        && fields.iter().all(|field| {
+                            value
+                                .field(field.name)
+                                .is_some_and(|value| self.conforms(value, field.value))
+                        })
+                }
+                (TypeKind::Tuple(TupleType { fields }), Value::Tuple(value_fields)) => {
+                    fields.len() == value_fields.len()
+                        && fields
+                            .iter()
+                            .zip(value_fields.iter())
+                            .all(|(&field, value)| self.conforms(value, field))
+                }
+                _ => false,
+            };
+        };
+
+        visited.push(id);
+        let conforms = if matches!(r#type.kind, TypeKind::Union(_)) {
+            types
+                .iter()
+                .any(|&variant| self.conforms_guarded(value, variant, visited))
+        } else {
+            types
+                .iter()
+                .all(|&variant| self.conforms_guarded(value, variant, visited))
+        };
+        visited.pop();
+
+        conforms
+    }
+
+    /// Returns the name of the opaque type that the given type constructs, if any.
+    fn opaque_name(&self, id: TypeId) -> Option<Symbol<'heap>> {
+        match *self.env.r#type(id).kind {
+            TypeKind::Opaque(OpaqueType { name, .. }) => Some(name),
+            TypeKind::Apply(Apply { base, .. }) | TypeKind::Generic(Generic { base, .. }) => {
+                self.opaque_name(base)
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn eval(
+        &self,
+        scope: &Scope<'heap>,
+        node: Node<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match node.kind {
+            NodeKind::Data(data) => self.eval_data(scope, data),
+            NodeKind::Variable(variable) => self.eval_variable(scope, variable),
+            NodeKind::Let(r#let) => self.eval_let(scope, r#let),
+            NodeKind::Input(input) => self.eval_input(scope, input),
+            NodeKind::Operation(operation) => self.eval_operation(scope, operation),
+            NodeKind::Access(access) => self.eval_access(scope, access),
+            NodeKind::Call(call) => self.eval_call(scope, call),
+            NodeKind::Branch(branch) => self.eval_branch(scope, branch),
+            NodeKind::Closure(closure) => Ok(Value::Function(Function::Closure(Closure {
+                node: closure,
+                scope: scope.clone(),
+            }))),
+            NodeKind::Graph(graph) => Err(graph_operation(graph.span)),
+        }
+    }
+
+    fn eval_nodes(
+        &self,
+        scope: &Scope<'heap>,
+        nodes: &[Node<'heap>],
+    ) -> Result<Rc<[Value<'heap>]>, InterpretDiagnostic> {
+        nodes.iter().map(|&node| self.eval(scope, node)).collect()
+    }
+
+    fn eval_data(
+        &self,
+        scope: &Scope<'heap>,
+        data: &Data<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match &data.kind {
+            DataKind::Struct(r#struct) => r#struct
+                .fields
+                .iter()
+                .map(|field| Ok((field.name.value, self.eval(scope, field.value)?)))
+                .collect::<Result<_, _>>()
+                .map(Value::Struct),
+            DataKind::Dict(dict) => dict
+                .fields
+                .iter()
+                .map(|field| Ok((self.eval(scope, field.key)?, self.eval(scope, field.value)?)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::dict),
+            DataKind::Tuple(tuple) => self.eval_nodes(scope, &tuple.fields).map(Value::Tuple),
+            DataKind::List(list) => self.eval_nodes(scope, &list.elements).map(Value::List),
+            DataKind::Literal(literal) => Ok(Self::eval_literal(literal)),
+        }
+    }
+
+    fn eval_literal(literal: &Literal<'heap>) -> Value<'heap> {
+        match literal.kind {
+            LiteralKind::Null => Value::Null,
+            LiteralKind::Boolean(boolean) => Value::Boolean(boolean),
+            LiteralKind::Integer(integer) => integer
+                .as_i128()
+                .map_or_else(|| Value::Number(integer.as_f64()), Value::Integer),
+            LiteralKind::Float(float) => Value::Number(float.as_f64()),
+            LiteralKind::String(string) => Value::String(Rc::from(string.as_str())),
+        }
+    }
+
+    fn eval_variable(
+        &self,
+        scope: &Scope<'heap>,
+        variable: &Variable<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match &variable.kind {
+            VariableKind::Local(LocalVariable { span, name, .. }) => {
+                if let Some(value) = scope.get(name.value) {
+                    return Ok(value.clone());
+                }
+
+                // Locally defined newtypes are not bound in the scope, but their constructors
+                // are available as values.
+                let Some(local) = self.locals.get(name.value) else {
+                    return Err(unbound_variable(*span, name.value.as_str()));
+                };
+
+                self.opaque_name(local.value.id)
+                    .map(|name| Value::Function(Function::Constructor(name)))
+                    .ok_or_else(|| unbound_variable(*span, name.value.as_str()))
+            }
+            VariableKind::Qualified(QualifiedVariable { span, path, .. }) => {
+                let path: String = path
+                    .0
+                    .iter()
+                    .flat_map(|ident| ["::", ident.value.as_str()])
+                    .collect();
+
+                if let Some(intrinsic) = Intrinsic::from_path(&path) {
+                    return Ok(Value::Function(Function::Intrinsic(intrinsic)));
+                }
+
+                if CONSTRUCTORS.contains(&path.as_str()) {
+                    return Ok(Value::Function(Function::Constructor(
+                        self.env.heap.intern_symbol(&path),
+                    )));
+                }
+
+                Err(unsupported_intrinsic(*span, &path))
+            }
+        }
+    }
+
+    fn eval_let(
+        &self,
+        scope: &Scope<'heap>,
+        Let {
+            span: _,
+            name,
+            value,
+            body,
+        }: &Let<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        let value = self.eval(scope, *value)?;
+
+        self.eval(&scope.bind(name.value, value), *body)
+    }
+
+    fn eval_input(
+        &self,
+        scope: &Scope<'heap>,
+        Input {
+            span,
+            name,
+            r#type,
+            default,
+        }: &Input<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        if let Some(value) = self.inputs.get(&name.value) {
+            if !self.conforms(value, *r#type) {
+                return Err(input_type_mismatch(
+                    *span,
+                    name.value.as_str(),
+                    &self.pretty_type(*r#type),
+                    value.kind_name(),
+                ));
+            }
+
+            return Ok(value.clone());
+        }
+
+        let Some(default) = default else {
+            return Err(missing_input(*span, name.value.as_str()));
+        };
+
+        self.eval(scope, *default)
+    }
+
+    fn eval_operation(
+        &self,
+        scope: &Scope<'heap>,
+        operation: &Operation<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match &operation.kind {
+            OperationKind::Type(TypeOperation {
+                span: _,
+                kind:
+                    TypeOperationKind::Assertion(TypeAssertion {
+                        span,
+                        value,
+                        r#type,
+                        force,
+                    }),
+            }) => {
+                let value = self.eval(scope, *value)?;
+
+                if !*force && !self.conforms(&value, *r#type) {
+                    return Err(type_assertion_failed(
+                        *span,
+                        &self.pretty_type(*r#type),
+                        value.kind_name(),
+                    ));
+                }
+
+                Ok(value)
+            }
+            OperationKind::Type(TypeOperation {
+                span: _,
+                kind:
+                    TypeOperationKind::Constructor(TypeConstructor {
+                        span: _,
+                        value,
+                        r#type,
+                    }),
+            }) => {
+                let value = self.eval(scope, *value)?;
+
+                Ok(match self.opaque_name(*r#type) {
+                    Some(name) => Value::Opaque(name, Rc::new(value)),
+                    None => value,
+                })
+            }
+            OperationKind::Binary(BinaryOperation {
+                span,
+                op,
+                left,
+                right,
+            }) => {
+                let intrinsic = Intrinsic::from_bin_op(op.kind);
+
+                let arguments = [
+                    Argument {
+                        span: left.span,
+                        value: self.eval(scope, *left)?,
+                    },
+                    Argument {
+                        span: right.span,
+                        value: self.eval(scope, *right)?,
+                    },
+                ];
+
+                intrinsic.apply(*span, &arguments)
+            }
+        }
+    }
+
+    fn eval_access(
+        &self,
+        scope: &Scope<'heap>,
+        access: &Access<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match &access.kind {
+            AccessKind::Field(FieldAccess { span, expr, field }) => {
+                let value = self.eval(scope, *expr)?;
+
+                match &value {
+                    Value::Struct(fields) => value.field(field.value).cloned().ok_or_else(|| {
+                        unknown_field(
+                            *span,
+                            field.value.as_str(),
+                            fields.iter().map(|(name, _)| name.as_str()),
+                        )
+                    }),
+                    Value::Tuple(fields) => {
+                        let Some(value) = field
+                            .value
+                            .as_str()
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|index| fields.get(index))
+                        else {
+                            return Err(unknown_field(
+                                *span,
+                                field.value.as_str(),
+                                (0..fields.len()).map(|index| index.to_string()),
+                            ));
+                        };
+
+                        Ok(value.clone())
+                    }
+                    _ => Err(type_mismatch(
+                        expr.span,
+                        &["Struct", "Tuple"],
+                        value.kind_name(),
+                    )),
+                }
+            }
+            AccessKind::Index(IndexAccess { span, expr, index }) => {
+                let value = self.eval(scope, *expr)?;
+                let key = self.eval(scope, *index)?;
+
+                match &value {
+                    Value::List(elements) => {
+                        let Value::Integer(position) = key else {
+                            return Err(type_mismatch(index.span, &["Integer"], key.kind_name()));
+                        };
+
+                        let Some(value) = usize::try_from(position)
+                            .ok()
+                            .and_then(|position| elements.get(position))
+                        else {
+                            return Err(index_out_of_bounds(*span, position, elements.len()));
+                        };
+
+                        Ok(value.clone())
+                    }
+                    Value::Dict(entries) => entries
+                        .iter()
+                        .find(|(entry, _)| *entry == key)
+                        .map(|(_, value)| value.clone())
+                        .ok_or_else(|| key_not_found(*span, &self.pretty_value(&key))),
+                    _ => Err(type_mismatch(
+                        expr.span,
+                        &["List", "Dict"],
+                        value.kind_name(),
+                    )),
+                }
+            }
+        }
+    }
+
+    fn eval_call(
+        &self,
+        scope: &Scope<'heap>,
+        Call {
+            span,
+            function,
+            arguments,
+        }: &Call<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        let callee = self.eval(scope, *function)?;
+
+        let arguments = arguments
+            .iter()
+            .map(|argument| {
+                Ok(Argument {
+                    span: argument.span,
+                    value: self.eval(scope, argument.value)?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let Value::Function(callee) = callee else {
+            return Err(type_mismatch(
+                function.span,
+                &["Function"],
+                callee.kind_name(),
+            ));
+        };
+
+        self.apply(*span, &callee, arguments)
+    }
+
+    fn apply(
+        &self,
+        span: SpanId,
+        function: &Function<'heap>,
+        mut arguments: Vec<Argument<'heap>>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match function {
+            Function::Closure(Closure { node, scope }) => {
+                let params = &node.signature.params;
+
+                if params.len() != arguments.len() {
+                    return Err(argument_count_mismatch(span, params.len(), arguments.len()));
+                }
+
+                let scope = params
+                    .iter()
+                    .zip(arguments)
+                    .fold(scope.clone(), |scope, (param, argument)| {
+                        scope.bind(param.name.value, argument.value)
+                    });
+
+                self.eval(&scope, node.body)
+            }
+            Function::Intrinsic(intrinsic) => intrinsic.apply(span, &arguments),
+            &Function::Constructor(name) => {
+                // Constructors of opaque types that are represented by `Null` may be called
+                // without any arguments.
+                if arguments.len() > 1 {
+                    return Err(argument_count_mismatch(span, 1, arguments.len()));
+                }
+
+                let value = arguments
+                    .pop()
+                    .map_or(Value::Null, |argument| argument.value);
+
+                Ok(Value::Opaque(name, Rc::new(value)))
+            }
+        }
+    }
+
+    fn eval_branch(
+        &self,
+        scope: &Scope<'heap>,
+        branch: &Branch<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnostic> {
+        match &branch.kind {
+            BranchKind::If(If {
+                span: _,
+                test,
+                then,
+                r#else,
+            }) => {
+                let condition = self.eval(scope, *test)?;
+
+                let Value::Boolean(condition) = condition else {
+                    return Err(type_mismatch(
+                        test.span,
+                        &["Boolean"],
+                        condition.kind_name(),
+                    ));
+                };
+
+                if condition {
+                    self.eval(scope, *then)
+                } else if let Some(r#else) = r#else {
+                    self.eval(scope, *r#else)
+                } else {
+                    Ok(Value::Null)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use hashql_ast::lowering::lower;
+    use hashql_core::{
+        heap::Heap,
+        module::ModuleRegistry,
+        span::{SpanId, storage::SpanStorage},
+        r#type::environment::Environment,
+    };
+    use hashql_hir::{intern::Interner, node::Node};
+    use hashql_syntax_jexpr::Parser;
+
+    use super::{Inputs, Interpreter, error::InterpretDiagnosticCategory, value::Value};
+
+    fn evaluate<'heap>(
+        heap: &'heap Heap,
+        source: &str,
+        inputs: &Inputs<'heap>,
+    ) -> Result<Value<'heap>, InterpretDiagnosticCategory> {
+        let parser = Parser::new(heap, Arc::new(SpanStorage::new()));
+        let mut expr = parser
+            .parse_expr(source.as_bytes())
+            .expect("should parse the source");
+
+        let environment = Environment::new(SpanId::SYNTHETIC, heap);
+        let registry = ModuleRegistry::new(&environment);
+
+        let (types, diagnostics) = lower(
+            heap.intern_symbol("::main"),
+            &mut expr,
+            &environment,
+            &registry,
+        );
+        assert!(diagnostics.is_empty(), "should lower without diagnostics");
+
+        let interner = Interner::new(heap);
+        let (node, diagnostics) = Node::from_ast(expr, &environment, &interner, &types);
+        assert!(diagnostics.is_empty(), "should reify without diagnostics");
+        let node = node.expect("should reify the expression");
+
+        Interpreter::new(&environment, &types.locals, inputs)
+            .run(node)
+            .map_err(|diagnostic| diagnostic.category)
+    }
+
+    #[test]
+    fn supplied_input() {
+        let heap = Heap::new();
+
+        let mut inputs = Inputs::default();
+        inputs.insert(heap.intern_symbol("limit"), Value::Integer(3));
+
+        let value = evaluate(&heap, r#"["input", "limit", "Integer"]"#, &inputs)
+            .expect("should evaluate the supplied input");
+
+        assert_eq!(value, Value::Integer(3));
+    }
+
+    #[test]
+    fn supplied_input_overrides_default() {
+        let heap = Heap::new();
+
+        let mut inputs = Inputs::default();
+        inputs.insert(heap.intern_symbol("limit"), Value::Integer(3));
+
+        let source =
+            r##"["*", ["input", "limit", "Integer", { "#literal": 10 }], { "#literal": 2 }]"##;
+
+        let value = evaluate(&heap, source, &inputs).expect("should evaluate the supplied input");
+        assert_eq!(value, Value::Integer(6));
+
+        let value =
+            evaluate(&heap, source, &Inputs::default()).expect("should evaluate the default value");
+        assert_eq!(value, Value::Integer(20));
+    }
+
+    #[test]
+    fn missing_input() {
+        let heap = Heap::new();
+
+        let category = evaluate(
+            &heap,
+            r#"["input", "limit", "Integer"]"#,
+            &Inputs::default(),
+        )
+        .expect_err("should reject a missing input");
+
+        assert_eq!(category, InterpretDiagnosticCategory::MissingInput);
+    }
+
+    #[test]
+    fn input_type_mismatch() {
+        let heap = Heap::new();
+
+        let mut inputs = Inputs::default();
+        inputs.insert(heap.intern_symbol("limit"), Value::String("ten".into()));
+
+        let category = evaluate(
+            &heap,
+            r##"["input", "limit", "Integer", { "#literal": 10 }]"##,
+            &inputs,
+        )
+        .expect_err("should reject an input of the wrong type");
+
+        assert_eq!(category, InterpretDiagnosticCategory::InputTypeMismatch);
+    }
+}
@@ -0,0 +1,54 @@
+use alloc::rc::Rc;
+
+use hashql_core::symbol::Symbol;
+
+use super::value::Value;
+
+#[derive(Debug)]
+struct Binding<'heap> {
+    name: Symbol<'heap>,
+    value: Value<'heap>,
+    parent: Scope<'heap>,
+}
+
+/// A persistent set of local bindings.
+///
+/// Scopes form a singly linked list, so that closures are able to cheaply capture the scope they
+/// have been created in. Binding a new value never modifies an existing scope, but instead
+/// returns a new scope, which has the existing scope as its parent.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Scope<'heap>(Option<Rc<Binding<'heap>>>);
+
+impl<'heap> Scope<'heap> {
+    pub(crate) fn bind(&self, name: Symbol<'heap>, value: Value<'heap>) -> Self {
+        Self(Some(Rc::new(Binding {
+            name,
+            value,
+            parent: self.clone(),
+        })))
+    }
+
+    pub(crate) fn get(&self, name: Symbol<'heap>) -> Option<&Value<'heap>> {
+        let mut current = self.0.as_deref();
+
+        while let Some(binding) = current {
+            if binding.name == name {
+                return Some(&binding.value);
+            }
+
+            current = binding.parent.0.as_deref();
+        }
+
+        None
+    }
+}
+
+impl PartialEq for Scope<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (None, None) => true,
+            (Some(lhs), Some(rhs)) => Rc::ptr_eq(lhs, rhs),
+            _ => false,
+        }
+    }
+}
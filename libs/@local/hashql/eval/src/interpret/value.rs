@@ -0,0 +1,259 @@
+//! Runtime values produced by the HashQL interpreter.
+//!
+//! Values are immutable and cheap to clone, compound values share their contents through
+//! reference counting. Every value corresponds to an inhabitant of a HashQL type: primitives map
+//! to their respective variants, structs, tuples, lists and dicts keep their structure, and opaque
+//! types (such as `::kernel::type::Some` or user-defined newtypes) retain the name of the type
+//! they were constructed with.
+use alloc::rc::Rc;
+
+use hashql_core::{
+    pretty::{PrettyPrint, PrettyRecursionBoundary},
+    symbol::Symbol,
+    r#type::environment::Environment,
+};
+use hashql_diagnostics::color::Style;
+use hashql_hir::node::closure::Closure as ClosureNode;
+use pretty::{DocAllocator as _, DocBuilder, RcAllocator, RcDoc};
+
+use super::{intrinsic::Intrinsic, scope::Scope};
+
+/// A closure value, which captures the scope it has been created in.
+#[derive(Debug, Clone, PartialEq)]
+#[expect(clippy::field_scoped_visibility_modifiers)]
+pub struct Closure<'heap> {
+    pub(crate) node: &'heap ClosureNode<'heap>,
+    pub(crate) scope: Scope<'heap>,
+}
+
+impl<'heap> Closure<'heap> {
+    #[must_use]
+    pub const fn node(&self) -> &'heap ClosureNode<'heap> {
+        self.node
+    }
+}
+
+/// A value that can be called with arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Function<'heap> {
+    /// A closure defined in the program.
+    Closure(Closure<'heap>),
+    /// A function provided by the standard library.
+    Intrinsic(Intrinsic),
+    /// The value constructor of the opaque type with the given name.
+    Constructor(Symbol<'heap>),
+}
+
+/// A value produced by evaluating a HashQL expression.
+///
+/// `Integer` and `Number` values compare equal if they represent the same numeric value, as
+/// `Integer` is a refinement of `Number`.
+///
+/// Names of struct fields and opaque types are stored as [`Symbol`]s, values that are
+/// constructed outside of the interpreter (such as inputs) must intern their names on the same
+/// heap as the program that is evaluated.
+#[derive(Debug, Clone)]
+pub enum Value<'heap> {
+    Null,
+    Boolean(bool),
+    Integer(i128),
+    Number(f64),
+    String(Rc<str>),
+    Struct(Rc<[(Symbol<'heap>, Self)]>),
+    Tuple(Rc<[Self]>),
+    List(Rc<[Self]>),
+    Dict(Rc<[(Self, Self)]>),
+    Opaque(Symbol<'heap>, Rc<Self>),
+    Function(Function<'heap>),
+}
+
+impl<'heap> Value<'heap> {
+    /// Returns a human readable name of the kind of the value.
+    ///
+    /// For opaque values this is the name of the opaque type.
+    #[must_use]
+    pub const fn kind_name(&self) -> &str {
+        match self {
+            Self::Null => "Null",
+            Self::Boolean(_) => "Boolean",
+            Self::Integer(_) => "Integer",
+            Self::Number(_) => "Number",
+            Self::String(_) => "String",
+            Self::Struct(_) => "Struct",
+            Self::Tuple(_) => "Tuple",
+            Self::List(_) => "List",
+            Self::Dict(_) => "Dict",
+            Self::Opaque(name, _) => name.as_str(),
+            Self::Function(_) => "Function",
+        }
+    }
+
+    /// Returns the value of the field with the given name, if the value is a struct.
+    #[must_use]
+    pub fn field(&self, name: Symbol<'heap>) -> Option<&Self> {
+        let Self::Struct(fields) = self else {
+            return None;
+        };
+
+        fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Creates a dict value from the given entries.
+    ///
+    /// If a key occurs multiple times, the last occurrence takes precedence, while retaining the
+    /// position of the first occurrence.
+    pub fn dict(entries: impl IntoIterator<Item = (Self, Self)>) -> Self {
+        let mut dict: Vec<(Self, Self)> = Vec::new();
+
+        for (key, value) in entries {
+            if let Some(entry) = dict.iter_mut().find(|(existing, _)| *existing == key) {
+                entry.1 = value;
+            } else {
+                dict.push((key, value));
+            }
+        }
+
+        Self::Dict(dict.into())
+    }
+}
+
+impl PartialEq for Value<'_> {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "integers are compared to numbers by their floating point value"
+    )]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null) => true,
+            (Self::Boolean(lhs), Self::Boolean(rhs)) => lhs == rhs,
+            (Self::Integer(lhs), Self::Integer(rhs)) => lhs == rhs,
+            (Self::Number(lhs), Self::Number(rhs)) => lhs == rhs,
+            (Self::Integer(lhs), Self::Number(rhs)) | (Self::Number(rhs), Self::Integer(lhs)) => {
+                *lhs as f64 == *rhs
+            }
+            (Self::String(lhs), Self::String(rhs)) => lhs == rhs,
+            (Self::Struct(lhs), Self::Struct(rhs)) => {
+                lhs.len() == rhs.len()
+                    && lhs.iter().all(|(name, value)| {
+                        rhs.iter()
+                            .any(|(other_name, other)| name == other_name && value == other)
+                    })
+            }
+            (Self::Tuple(lhs), Self::Tuple(rhs)) | (Self::List(lhs), Self::List(rhs)) => lhs == rhs,
+            (Self::Dict(lhs), Self::Dict(rhs)) => {
+                lhs.len() == rhs.len()
+                    && lhs.iter().all(|(key, value)| {
+                        rhs.iter()
+                            .any(|(other_key, other)| key == other_key && value == other)
+                    })
+            }
+            (Self::Opaque(lhs_name, lhs), Self::Opaque(rhs_name, rhs)) => {
+                lhs_name == rhs_name && lhs == rhs
+            }
+            (Self::Function(lhs), Self::Function(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+}
+
+fn pretty_sequence<'heap>(
+    values: impl IntoIterator<Item = RcDoc<'heap, Style>>,
+) -> DocBuilder<'heap, RcAllocator, Style> {
+    RcAllocator
+        .intersperse(values, RcDoc::text(",").append(RcDoc::softline()))
+        .nest(1)
+        .group()
+}
+
+impl<'heap> PrettyPrint<'heap> for Function<'heap> {
+    fn pretty(
+        &self,
+        _: &Environment<'heap>,
+        _: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        match self {
+            Self::Closure(closure) => RcDoc::text("#fn").append(
+                pretty_sequence(
+                    closure
+                        .node
+                        .signature
+                        .params
+                        .iter()
+                        .map(|param| RcDoc::text(param.name.value.unwrap())),
+                )
+                .parens()
+                .group()
+                .into_doc(),
+            ),
+            Self::Intrinsic(intrinsic) => RcDoc::text(intrinsic.path()),
+            Self::Constructor(name) => RcDoc::text(name.unwrap()),
+        }
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for Value<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        match self {
+            Self::Null => RcDoc::text("null"),
+            Self::Boolean(true) => RcDoc::text("true"),
+            Self::Boolean(false) => RcDoc::text("false"),
+            Self::Integer(integer) => RcDoc::text(integer.to_string()),
+            Self::Number(number) => RcDoc::text(format!("{number:?}")),
+            Self::String(string) => RcDoc::text(format!(r#""{}""#, string.escape_debug())),
+            Self::Struct(fields) if fields.is_empty() => RcDoc::text("(:)"),
+            Self::Struct(fields) => pretty_sequence(fields.iter().map(|(name, value)| {
+                RcDoc::text(name.unwrap())
+                    .append(":")
+                    .group()
+                    .append(RcDoc::softline())
+                    .append(value.pretty(env, boundary).group())
+                    .group()
+            }))
+            .parens()
+            .group()
+            .into_doc(),
+            Self::Tuple(fields) => match fields.as_ref() {
+                [] => RcDoc::text("()"),
+                [field] => RcDoc::text("(")
+                    .append(field.pretty(env, boundary).group())
+                    .append(",)")
+                    .group(),
+                fields => pretty_sequence(fields.iter().map(|field| field.pretty(env, boundary)))
+                    .parens()
+                    .group()
+                    .into_doc(),
+            },
+            Self::List(elements) => {
+                pretty_sequence(elements.iter().map(|element| element.pretty(env, boundary)))
+                    .brackets()
+                    .group()
+                    .into_doc()
+            }
+            Self::Dict(entries) => pretty_sequence(entries.iter().map(|(key, value)| {
+                key.pretty(env, boundary)
+                    .group()
+                    .append(":")
+                    .group()
+                    .append(RcDoc::softline())
+                    .append(value.pretty(env, boundary).group())
+                    .group()
+            }))
+            .braces()
+            .group()
+            .into_doc(),
+            Self::Opaque(name, value) => RcDoc::text(name.unwrap())
+                .append("(")
+                .append(value.pretty(env, boundary).group())
+                .append(")")
+                .group(),
+            Self::Function(function) => function.pretty(env, boundary),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! # HashQL Eval
+//!
+//! ## Workspace dependencies
+#![feature(never_type, exhaustive_patterns, coverage_attribute)]
+#![cfg_attr(doc, doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd"))]
+
+extern crate alloc;
+
+pub mod error;
+#[cfg(feature = "graph")]
+pub mod graph;
+pub mod interpret;
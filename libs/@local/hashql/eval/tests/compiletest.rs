@@ -0,0 +1,3 @@
+use hashql_compiletest::compiletest_main;
+
+compiletest_main!();
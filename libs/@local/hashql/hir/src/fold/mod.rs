@@ -64,11 +64,17 @@ use crate::{
     node::{
         HirId, Node, PartialNode,
         access::{Access, AccessKind, field::FieldAccess, index::IndexAccess},
-        branch::Branch,
+        branch::{Branch, BranchKind, If},
         call::{Call, CallArgument},
         closure::{Closure, ClosureParam, ClosureSignature},
-        data::{Data, DataKind, Literal},
-        graph::Graph,
+        data::{
+            Data, DataKind, Dict, List, Literal, Struct, Tuple, dict::DictField,
+            r#struct::StructField,
+        },
+        graph::{
+            Graph, GraphKind,
+            read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+        },
         input::Input,
         kind::NodeKind,
         r#let::Let,
@@ -200,6 +206,44 @@ pub trait Fold<'heap> {
         walk_data(self, data)
     }
 
+    fn fold_struct(&mut self, r#struct: Struct<'heap>) -> Self::Output<Struct<'heap>> {
+        walk_struct(self, r#struct)
+    }
+
+    fn fold_struct_field(&mut self, field: StructField<'heap>) -> Self::Output<StructField<'heap>> {
+        walk_struct_field(self, field)
+    }
+
+    fn fold_struct_fields(
+        &mut self,
+        fields: Interned<'heap, [StructField<'heap>]>,
+    ) -> Self::Output<Interned<'heap, [StructField<'heap>]>> {
+        walk_struct_fields(self, fields)
+    }
+
+    fn fold_dict(&mut self, dict: Dict<'heap>) -> Self::Output<Dict<'heap>> {
+        walk_dict(self, dict)
+    }
+
+    fn fold_dict_field(&mut self, field: DictField<'heap>) -> Self::Output<DictField<'heap>> {
+        walk_dict_field(self, field)
+    }
+
+    fn fold_dict_fields(
+        &mut self,
+        fields: Interned<'heap, [DictField<'heap>]>,
+    ) -> Self::Output<Interned<'heap, [DictField<'heap>]>> {
+        walk_dict_fields(self, fields)
+    }
+
+    fn fold_tuple(&mut self, tuple: Tuple<'heap>) -> Self::Output<Tuple<'heap>> {
+        walk_tuple(self, tuple)
+    }
+
+    fn fold_list(&mut self, list: List<'heap>) -> Self::Output<List<'heap>> {
+        walk_list(self, list)
+    }
+
     fn fold_literal(&mut self, literal: Literal<'heap>) -> Self::Output<Literal<'heap>> {
         walk_literal(self, literal)
     }
@@ -309,6 +353,10 @@ pub trait Fold<'heap> {
         walk_branch(self, branch)
     }
 
+    fn fold_if(&mut self, r#if: If<'heap>) -> Self::Output<If<'heap>> {
+        walk_if(self, r#if)
+    }
+
     fn fold_closure(&mut self, closure: Closure<'heap>) -> Self::Output<Closure<'heap>> {
         walk_closure(self, closure)
     }
@@ -337,6 +385,35 @@ pub trait Fold<'heap> {
     fn fold_graph(&mut self, graph: Graph<'heap>) -> Self::Output<Graph<'heap>> {
         walk_graph(self, graph)
     }
+
+    fn fold_graph_read(&mut self, read: GraphRead<'heap>) -> Self::Output<GraphRead<'heap>> {
+        walk_graph_read(self, read)
+    }
+
+    fn fold_graph_read_head(
+        &mut self,
+        head: GraphReadHead<'heap>,
+    ) -> Self::Output<GraphReadHead<'heap>> {
+        walk_graph_read_head(self, head)
+    }
+
+    fn fold_graph_read_body(
+        &mut self,
+        body: GraphReadBody<'heap>,
+    ) -> Self::Output<GraphReadBody<'heap>> {
+        walk_graph_read_body(self, body)
+    }
+
+    fn fold_graph_read_bodies(
+        &mut self,
+        bodies: Interned<'heap, [GraphReadBody<'heap>]>,
+    ) -> Self::Output<Interned<'heap, [GraphReadBody<'heap>]>> {
+        walk_graph_read_bodies(self, bodies)
+    }
+
+    fn fold_graph_read_tail(&mut self, tail: GraphReadTail) -> Self::Output<GraphReadTail> {
+        walk_graph_read_tail(self, tail)
+    }
 }
 
 pub fn walk_ident<'heap, T: Fold<'heap> + ?Sized>(
@@ -432,12 +509,104 @@ pub fn walk_data<'heap, T: Fold<'heap> + ?Sized>(
     let span = visitor.fold_span(span)?;
 
     let kind = match kind {
+        DataKind::Struct(r#struct) => DataKind::Struct(visitor.fold_struct(r#struct)?),
+        DataKind::Dict(dict) => DataKind::Dict(visitor.fold_dict(dict)?),
+        DataKind::Tuple(tuple) => DataKind::Tuple(visitor.fold_tuple(tuple)?),
+        DataKind::List(list) => DataKind::List(visitor.fold_list(list)?),
         DataKind::Literal(literal) => DataKind::Literal(visitor.fold_literal(literal)?),
     };
 
     Try::from_output(Data { span, kind })
 }
 
+pub fn walk_struct<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    Struct { span, fields }: Struct<'heap>,
+) -> T::Output<Struct<'heap>> {
+    let span = visitor.fold_span(span)?;
+    let fields = visitor.fold_struct_fields(fields)?;
+
+    Try::from_output(Struct { span, fields })
+}
+
+pub fn walk_struct_field<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    StructField { name, value }: StructField<'heap>,
+) -> T::Output<StructField<'heap>> {
+    let name = visitor.fold_ident(name)?;
+    let value = visitor.fold_nested_node(value)?;
+
+    Try::from_output(StructField { name, value })
+}
+
+pub fn walk_struct_fields<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    fields: Interned<'heap, [StructField<'heap>]>,
+) -> T::Output<Interned<'heap, [StructField<'heap>]>> {
+    if fields.is_empty() {
+        return Try::from_output(fields);
+    }
+
+    let mut fields = Beef::new(fields);
+    fields.try_map::<_, T::Output<()>>(|field| visitor.fold_struct_field(field))?;
+
+    Try::from_output(fields.finish(&visitor.interner().struct_fields))
+}
+
+pub fn walk_dict<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    Dict { span, fields }: Dict<'heap>,
+) -> T::Output<Dict<'heap>> {
+    let span = visitor.fold_span(span)?;
+    let fields = visitor.fold_dict_fields(fields)?;
+
+    Try::from_output(Dict { span, fields })
+}
+
+pub fn walk_dict_field<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    DictField { key, value }: DictField<'heap>,
+) -> T::Output<DictField<'heap>> {
+    let key = visitor.fold_nested_node(key)?;
+    let value = visitor.fold_nested_node(value)?;
+
+    Try::from_output(DictField { key, value })
+}
+
+pub fn walk_dict_fields<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    fields: Interned<'heap, [DictField<'heap>]>,
+) -> T::Output<Interned<'heap, [DictField<'heap>]>> {
+    if fields.is_empty() {
+        return Try::from_output(fields);
+    }
+
+    let mut fields = Beef::new(fields);
+    fields.try_map::<_, T::Output<()>>(|field| visitor.fold_dict_field(field))?;
+
+    Try::from_output(fields.finish(&visitor.interner().dict_fields))
+}
+
+pub fn walk_tuple<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    Tuple { span, fields }: Tuple<'heap>,
+) -> T::Output<Tuple<'heap>> {
+    let span = visitor.fold_span(span)?;
+    let fields = visitor.fold_nodes(fields)?;
+
+    Try::from_output(Tuple { span, fields })
+}
+
+pub fn walk_list<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    List { span, elements }: List<'heap>,
+) -> T::Output<List<'heap>> {
+    let span = visitor.fold_span(span)?;
+    let elements = visitor.fold_nodes(elements)?;
+
+    Try::from_output(List { span, elements })
+}
+
 pub fn walk_literal<'heap, T: Fold<'heap> + ?Sized>(
     visitor: &mut T,
     Literal { span, kind }: Literal<'heap>,
@@ -742,15 +911,43 @@ pub fn walk_call_arguments<'heap, T: Fold<'heap> + ?Sized>(
 
 pub fn walk_branch<'heap, T: Fold<'heap> + ?Sized>(
     visitor: &mut T,
-    Branch {
-        span,
-        kind,
-        _marker: _,
-    }: Branch<'heap>,
+    Branch { span, kind }: Branch<'heap>,
 ) -> T::Output<Branch<'heap>> {
-    let _span = visitor.fold_span(span)?;
+    let span = visitor.fold_span(span)?;
+
+    let kind = match kind {
+        BranchKind::If(r#if) => BranchKind::If(visitor.fold_if(r#if)?),
+    };
 
-    match kind {}
+    Try::from_output(Branch { span, kind })
+}
+
+pub fn walk_if<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    If {
+        span,
+        test,
+        then,
+        r#else,
+    }: If<'heap>,
+) -> T::Output<If<'heap>> {
+    let span = visitor.fold_span(span)?;
+
+    let test = visitor.fold_nested_node(test)?;
+    let then = visitor.fold_nested_node(then)?;
+
+    let r#else = if let Some(r#else) = r#else {
+        Some(visitor.fold_nested_node(r#else)?)
+    } else {
+        None
+    };
+
+    Try::from_output(If {
+        span,
+        test,
+        then,
+        r#else,
+    })
 }
 
 pub fn walk_closure<'heap, T: Fold<'heap> + ?Sized>(
@@ -822,13 +1019,81 @@ pub fn walk_closure_params<'heap, T: Fold<'heap> + ?Sized>(
 
 pub fn walk_graph<'heap, T: Fold<'heap> + ?Sized>(
     visitor: &mut T,
-    Graph {
-        span,
-        kind,
-        _marker: _,
-    }: Graph<'heap>,
+    Graph { span, kind }: Graph<'heap>,
 ) -> T::Output<Graph<'heap>> {
-    let _span = visitor.fold_span(span)?;
+    let span = visitor.fold_span(span)?;
+
+    let kind = match kind {
+        GraphKind::Read(read) => GraphKind::Read(visitor.fold_graph_read(read)?),
+    };
+
+    Try::from_output(Graph { span, kind })
+}
+
+pub fn walk_graph_read<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    GraphRead {
+        span,
+        head,
+        body,
+        tail,
+    }: GraphRead<'heap>,
+) -> T::Output<GraphRead<'heap>> {
+    let span = visitor.fold_span(span)?;
+
+    let head = visitor.fold_graph_read_head(head)?;
+    let body = visitor.fold_graph_read_bodies(body)?;
+    let tail = visitor.fold_graph_read_tail(tail)?;
+
+    Try::from_output(GraphRead {
+        span,
+        head,
+        body,
+        tail,
+    })
+}
+
+pub fn walk_graph_read_head<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    head: GraphReadHead<'heap>,
+) -> T::Output<GraphReadHead<'heap>> {
+    let head = match head {
+        GraphReadHead::Entity { axis } => GraphReadHead::Entity {
+            axis: visitor.fold_nested_node(axis)?,
+        },
+    };
+
+    Try::from_output(head)
+}
+
+pub fn walk_graph_read_bodies<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    bodies: Interned<'heap, [GraphReadBody<'heap>]>,
+) -> T::Output<Interned<'heap, [GraphReadBody<'heap>]>> {
+    if bodies.is_empty() {
+        return Try::from_output(bodies);
+    }
+
+    let mut bodies = Beef::new(bodies);
+    bodies.try_map::<_, T::Output<()>>(|body| visitor.fold_graph_read_body(body))?;
+
+    Try::from_output(bodies.finish(&visitor.interner().graph_read_body))
+}
+
+pub fn walk_graph_read_body<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    body: GraphReadBody<'heap>,
+) -> T::Output<GraphReadBody<'heap>> {
+    let body = match body {
+        GraphReadBody::Filter(node) => GraphReadBody::Filter(visitor.fold_nested_node(node)?),
+    };
+
+    Try::from_output(body)
+}
 
-    match kind {}
+pub fn walk_graph_read_tail<'heap, T: Fold<'heap> + ?Sized>(
+    _visitor: &mut T,
+    tail: GraphReadTail,
+) -> T::Output<GraphReadTail> {
+    Try::from_output(tail)
 }
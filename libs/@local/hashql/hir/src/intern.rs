@@ -5,7 +5,13 @@ use hashql_core::{
     r#type::kind::generic::GenericArgumentReference,
 };
 
-use crate::node::{Node, PartialNode, call::CallArgument, closure::ClosureParam};
+use crate::node::{
+    Node, PartialNode,
+    call::CallArgument,
+    closure::ClosureParam,
+    data::{dict::DictField, r#struct::StructField},
+    graph::read::GraphReadBody,
+};
 
 #[derive(Debug)]
 pub struct Interner<'heap> {
@@ -14,6 +20,9 @@ pub struct Interner<'heap> {
     pub closure_generics: InternSet<'heap, [GenericArgumentReference<'heap>]>,
     pub closure_params: InternSet<'heap, [ClosureParam<'heap>]>,
     pub call_arguments: InternSet<'heap, [CallArgument<'heap>]>,
+    pub struct_fields: InternSet<'heap, [StructField<'heap>]>,
+    pub dict_fields: InternSet<'heap, [DictField<'heap>]>,
+    pub graph_read_body: InternSet<'heap, [GraphReadBody<'heap>]>,
 
     pub node: InternMap<'heap, Node<'heap>>,
 }
@@ -26,6 +35,9 @@ impl<'heap> Interner<'heap> {
             closure_generics: InternSet::new(heap),
             closure_params: InternSet::new(heap),
             call_arguments: InternSet::new(heap),
+            struct_fields: InternSet::new(heap),
+            dict_fields: InternSet::new(heap),
+            graph_read_body: InternSet::new(heap),
 
             node: InternMap::new(heap),
         }
@@ -60,6 +72,27 @@ impl<'heap> Interner<'heap> {
         self.call_arguments.intern_slice(call_args)
     }
 
+    pub fn intern_struct_fields(
+        &self,
+        fields: &[StructField<'heap>],
+    ) -> Interned<'heap, [StructField<'heap>]> {
+        self.struct_fields.intern_slice(fields)
+    }
+
+    pub fn intern_dict_fields(
+        &self,
+        fields: &[DictField<'heap>],
+    ) -> Interned<'heap, [DictField<'heap>]> {
+        self.dict_fields.intern_slice(fields)
+    }
+
+    pub fn intern_graph_read_body(
+        &self,
+        body: &[GraphReadBody<'heap>],
+    ) -> Interned<'heap, [GraphReadBody<'heap>]> {
+        self.graph_read_body.intern_slice(body)
+    }
+
     pub fn intern_node(&self, node: PartialNode<'heap>) -> Node<'heap> {
         self.node.intern_partial(node)
     }
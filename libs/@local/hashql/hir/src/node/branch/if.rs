@@ -0,0 +1,17 @@
+use hashql_core::span::SpanId;
+
+use crate::node::Node;
+
+/// A conditional expression in the HashQL HIR.
+///
+/// Evaluates the `test` expression and, depending on its boolean result, evaluates
+/// either the `then` or the `else` expression. The `else` branch is optional; an `if`
+/// without an `else` branch evaluates to `null` if the test fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct If<'heap> {
+    pub span: SpanId,
+
+    pub test: Node<'heap>,
+    pub then: Node<'heap>,
+    pub r#else: Option<Node<'heap>>,
+}
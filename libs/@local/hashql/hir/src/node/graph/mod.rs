@@ -3,19 +3,19 @@
 //! This module represents operations specific to HashQL's graph querying capabilities.
 //! It provides structures for interacting with the underlying graph database,
 //! including traversals, pattern matching, and data retrieval operations.
-//! Currently implemented as a placeholder for future implementation.
-use core::marker::PhantomData;
+pub mod read;
 
 use hashql_core::span::SpanId;
 
+pub use self::read::GraphRead;
+
 /// The different kinds of graph operations in the HashQL HIR.
 ///
 /// This enum represents the various graph-specific operations available in HashQL.
-/// Currently defined as a placeholder with only a Never variant using the never type.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum GraphKind {
-    /// Placeholder variant (currently unimplemented)
-    Never(!),
+pub enum GraphKind<'heap> {
+    /// Reading vertices from the graph
+    Read(GraphRead<'heap>),
 }
 
 /// A graph operation node in the HashQL HIR.
@@ -23,14 +23,11 @@ pub enum GraphKind {
 /// Represents operations specific to HashQL's graph querying capabilities,
 /// such as traversals, pattern matching, and data retrieval from the graph.
 ///
-/// This is currently implemented as a placeholder structure awaiting full implementation.
-/// When completed, graph nodes will be central to HashQL's graph querying functionality,
-/// allowing for powerful and expressive graph operations.
+/// Graph operations are constructed from calls to the `::graph` module of the standard library,
+/// once the complete pipeline of an operation is known.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Graph<'heap> {
     pub span: SpanId,
 
-    pub kind: GraphKind,
-
-    pub _marker: PhantomData<&'heap ()>,
+    pub kind: GraphKind<'heap>,
 }
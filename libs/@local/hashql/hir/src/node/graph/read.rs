@@ -0,0 +1,48 @@
+use hashql_core::{intern::Interned, span::SpanId};
+
+use crate::node::Node;
+
+/// The vertices a graph read starts from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GraphReadHead<'heap> {
+    /// Selects all entities in the graph, as seen along the given temporal axis.
+    Entity { axis: Node<'heap> },
+}
+
+/// An operation applied to the vertices of a graph read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GraphReadBody<'heap> {
+    /// Retains only the vertices for which the closure returns `true`.
+    Filter(Node<'heap>),
+}
+
+/// Determines the shape of the result of a graph read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GraphReadTail {
+    /// Collects all vertices into a list.
+    Collect,
+}
+
+/// A read from the graph in the HashQL HIR.
+///
+/// Represents a complete graph query pipeline, as written through the `::graph` module:
+///
+/// ```text
+/// ::graph::tail::collect(
+///     ::graph::body::filter(
+///         ::graph::head::entities(::graph::tmp::decision_time_now()),
+///         fn(entity) -> ...
+///     )
+/// )
+/// ```
+///
+/// The `body` operations are stored in the order they are applied, starting with the operation
+/// directly following the `head`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GraphRead<'heap> {
+    pub span: SpanId,
+
+    pub head: GraphReadHead<'heap>,
+    pub body: Interned<'heap, [GraphReadBody<'heap>]>,
+    pub tail: GraphReadTail,
+}
@@ -10,11 +10,17 @@ use crate::{
     node::{
         Node,
         access::{Access, AccessKind, field::FieldAccess, index::IndexAccess},
-        branch::Branch,
+        branch::{Branch, BranchKind, If},
         call::Call,
         closure::Closure,
-        data::{Data, DataKind, Literal},
-        graph::Graph,
+        data::{
+            Data, DataKind, Dict, List, Literal, Struct, Tuple, dict::DictField,
+            r#struct::StructField,
+        },
+        graph::{
+            Graph, GraphKind,
+            read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+        },
         input::Input,
         kind::NodeKind,
         r#let::Let,
@@ -47,6 +53,127 @@ impl<'heap> PrettyPrint<'heap> for Literal<'heap> {
     }
 }
 
+impl<'heap> PrettyPrint<'heap> for StructField<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        RcDoc::text(self.name.value.unwrap())
+            .append(":")
+            .group()
+            .append(RcDoc::softline())
+            .append(self.value.pretty(env, boundary).group())
+            .group()
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for Struct<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        if self.fields.is_empty() {
+            return RcDoc::text("(:)");
+        }
+
+        RcAllocator
+            .intersperse(
+                self.fields.iter().map(|field| field.pretty(env, boundary)),
+                RcDoc::text(",").append(RcDoc::softline()),
+            )
+            .nest(1)
+            .group()
+            .parens()
+            .group()
+            .into_doc()
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for DictField<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        self.key
+            .pretty(env, boundary)
+            .group()
+            .append(":")
+            .group()
+            .append(RcDoc::softline())
+            .append(self.value.pretty(env, boundary).group())
+            .group()
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for Dict<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        RcAllocator
+            .intersperse(
+                self.fields.iter().map(|field| field.pretty(env, boundary)),
+                RcDoc::text(",").append(RcDoc::softline()),
+            )
+            .nest(1)
+            .group()
+            .braces()
+            .group()
+            .into_doc()
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for Tuple<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        match self.fields.as_ref() {
+            [] => RcDoc::text("()"),
+            [field] => RcDoc::text("(")
+                .append(field.pretty(env, boundary).group())
+                .append(",)")
+                .group(),
+            fields => RcAllocator
+                .intersperse(
+                    fields.iter().map(|field| field.pretty(env, boundary)),
+                    RcDoc::text(",").append(RcDoc::softline()),
+                )
+                .nest(1)
+                .group()
+                .parens()
+                .group()
+                .into_doc(),
+        }
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for List<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        RcAllocator
+            .intersperse(
+                self.elements
+                    .iter()
+                    .map(|element| element.pretty(env, boundary)),
+                RcDoc::text(",").append(RcDoc::softline()),
+            )
+            .nest(1)
+            .group()
+            .brackets()
+            .group()
+            .into_doc()
+    }
+}
+
 impl<'heap> PrettyPrint<'heap> for Data<'heap> {
     fn pretty(
         &self,
@@ -54,6 +181,10 @@ impl<'heap> PrettyPrint<'heap> for Data<'heap> {
         boundary: &mut PrettyRecursionBoundary,
     ) -> RcDoc<'heap, Style> {
         match &self.kind {
+            DataKind::Struct(r#struct) => r#struct.pretty(env, boundary),
+            DataKind::Dict(dict) => dict.pretty(env, boundary),
+            DataKind::Tuple(tuple) => tuple.pretty(env, boundary),
+            DataKind::List(list) => list.pretty(env, boundary),
             DataKind::Literal(literal) => literal.pretty(env, boundary),
         }
     }
@@ -346,13 +477,44 @@ impl<'heap> PrettyPrint<'heap> for Call<'heap> {
     }
 }
 
+impl<'heap> PrettyPrint<'heap> for If<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        let mut doc = RcDoc::text("#if")
+            .append("(")
+            .group()
+            .append(self.test.pretty(env, boundary))
+            .append(",")
+            .group()
+            .append(RcDoc::softline())
+            .append("then: ")
+            .append(self.then.pretty(env, boundary).group());
+
+        if let Some(r#else) = &self.r#else {
+            doc = doc
+                .append(",")
+                .group()
+                .append(RcDoc::softline())
+                .append("else: ")
+                .append(r#else.pretty(env, boundary).group());
+        }
+
+        doc.group().append(")").group()
+    }
+}
+
 impl<'heap> PrettyPrint<'heap> for Branch<'heap> {
     fn pretty(
         &self,
-        _: &Environment<'heap>,
-        _: &mut PrettyRecursionBoundary,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
     ) -> RcDoc<'heap, Style> {
-        match self.kind {}
+        match &self.kind {
+            BranchKind::If(r#if) => r#if.pretty(env, boundary),
+        }
     }
 }
 
@@ -434,13 +596,83 @@ impl<'heap> PrettyPrint<'heap> for Closure<'heap> {
     }
 }
 
-impl<'heap> PrettyPrint<'heap> for Graph<'heap> {
+impl<'heap> PrettyPrint<'heap> for GraphReadHead<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        match self {
+            GraphReadHead::Entity { axis } => RcDoc::text("entities")
+                .append(RcDoc::text("("))
+                .append(axis.pretty(env, boundary))
+                .append(RcDoc::text(")")),
+        }
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for GraphReadBody<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        match self {
+            GraphReadBody::Filter(node) => RcDoc::text("filter")
+                .append(RcDoc::text("("))
+                .append(node.pretty(env, boundary))
+                .append(RcDoc::text(")")),
+        }
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for GraphReadTail {
     fn pretty(
         &self,
         _: &Environment<'heap>,
         _: &mut PrettyRecursionBoundary,
     ) -> RcDoc<'heap, Style> {
-        match self.kind {}
+        match self {
+            Self::Collect => RcDoc::text("collect"),
+        }
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for GraphRead<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        let head = self.head.pretty(env, boundary);
+
+        let mut operations: Vec<_> = self
+            .body
+            .iter()
+            .map(|body| body.pretty(env, boundary))
+            .collect();
+        operations.push(self.tail.pretty(env, boundary));
+
+        let operations = operations
+            .into_iter()
+            .map(|operation| RcDoc::line().append("|> ").append(operation));
+
+        RcDoc::text("#graph::read::")
+            .append(head)
+            .append(RcDoc::concat(operations).nest(4))
+            .group()
+    }
+}
+
+impl<'heap> PrettyPrint<'heap> for Graph<'heap> {
+    fn pretty(
+        &self,
+        env: &Environment<'heap>,
+        boundary: &mut PrettyRecursionBoundary,
+    ) -> RcDoc<'heap, Style> {
+        match &self.kind {
+            GraphKind::Read(read) => read.pretty(env, boundary),
+        }
     }
 }
 
@@ -12,11 +12,6 @@ use hashql_diagnostics::{
 
 pub type ReificationDiagnostic = Diagnostic<ReificationDiagnosticCategory, SpanId>;
 
-const UNSUPPORTED_CONSTRUCT: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
-    id: "unsupported-construct",
-    name: "Unsupported language construct",
-};
-
 const UNHANDLED_ERROR: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
     id: "unhandled-error",
     name: "Unhandled error from previous compilation phase",
@@ -37,13 +32,18 @@ const UNDERSCORE_EXPRESSION: TerminalDiagnosticCategory = TerminalDiagnosticCate
     name: "Invalid use of underscore expression",
 };
 
+const INVALID_GRAPH_PIPELINE: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-graph-pipeline",
+    name: "Invalid graph pipeline",
+};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ReificationDiagnosticCategory {
-    UnsupportedConstruct,
     UnhandledError,
     UnprocessedExpression,
     InternalError,
     UnderscoreExpression,
+    InvalidGraphPipeline,
 }
 
 impl DiagnosticCategory for ReificationDiagnosticCategory {
@@ -57,47 +57,15 @@ impl DiagnosticCategory for ReificationDiagnosticCategory {
 
     fn subcategory(&self) -> Option<&dyn DiagnosticCategory> {
         match *self {
-            Self::UnsupportedConstruct => Some(&UNSUPPORTED_CONSTRUCT),
             Self::UnhandledError => Some(&UNHANDLED_ERROR),
             Self::UnprocessedExpression => Some(&UNPROCESSED_EXPRESSION),
             Self::InternalError => Some(&INTERNAL_ERROR),
             Self::UnderscoreExpression => Some(&UNDERSCORE_EXPRESSION),
+            Self::InvalidGraphPipeline => Some(&INVALID_GRAPH_PIPELINE),
         }
     }
 }
 
-/// Creates a diagnostic for an unsupported language construct.
-///
-/// This is a temporary error for features that are not yet implemented
-/// but will be supported in the near future.
-pub(crate) fn unsupported_construct(
-    span: SpanId,
-    construct_name: &str,
-    issue_url: &str,
-) -> ReificationDiagnostic {
-    let mut diagnostic = Diagnostic::new(
-        ReificationDiagnosticCategory::UnsupportedConstruct,
-        Severity::Error,
-    );
-
-    diagnostic
-        .labels
-        .push(Label::new(span, format!("`{construct_name}` not supported yet")).with_order(0));
-
-    diagnostic.add_help(Help::new(format!(
-        "The {construct_name} syntax is valid HashQL code, but support for this feature is still \
-         in development. For now, you'll need to use alternative approaches to achieve the same \
-         result. Check issue {issue_url} for implementation status and updates."
-    )));
-
-    diagnostic.add_note(Note::new(format!(
-        "This is a temporary limitation. We're actively working on supporting {construct_name} \
-         constructs and other advanced features to make HashQL more expressive and powerful."
-    )));
-
-    diagnostic
-}
-
 /// Creates a diagnostic for an unhandled error from a previous phase.
 ///
 /// This indicates that a fatal error occurred in a previous compilation phase,
@@ -212,3 +180,32 @@ pub(crate) fn underscore_expression(span: SpanId) -> ReificationDiagnostic {
 
     diagnostic
 }
+
+/// Creates a diagnostic for a graph pipeline that does not have the expected shape.
+///
+/// Graph pipelines must start with a head, followed by any number of body operations, and be
+/// terminated by a tail.
+pub(crate) fn invalid_graph_pipeline(span: SpanId, message: &str) -> ReificationDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        ReificationDiagnosticCategory::InvalidGraphPipeline,
+        Severity::Error,
+    );
+
+    diagnostic
+        .labels
+        .push(Label::new(span, message.to_owned()).with_order(0));
+
+    diagnostic.add_help(Help::new(
+        "A graph pipeline starts with a head (such as `::graph::head::entities`), followed by any \
+         number of body operations (such as `::graph::body::filter`), and ends with a tail (such \
+         as `::graph::tail::collect`). Each operation takes the result of the previous operation \
+         as its first argument.",
+    ));
+
+    diagnostic.add_note(Note::new(
+        "Graph pipelines must be written as a single expression, the intermediate graph cannot be \
+         bound to a variable or passed to a function.",
+    ));
+
+    diagnostic
+}
@@ -6,8 +6,9 @@ use hashql_ast::{
     lowering::ExtractedTypes,
     node::{
         expr::{
-            CallExpr, ClosureExpr, Expr, ExprKind, FieldExpr, IndexExpr, InputExpr, IsExpr,
-            LetExpr, LiteralExpr, call::Argument, closure,
+            CallExpr, ClosureExpr, DictExpr, Expr, ExprKind, FieldExpr, IfExpr, IndexExpr,
+            InputExpr, IsExpr, LetExpr, ListExpr, LiteralExpr, StructExpr, TupleExpr,
+            call::Argument, closure,
         },
         path::{Path, PathSegmentArgument},
         r#type::Type,
@@ -22,17 +23,25 @@ use hashql_core::{
 };
 
 use self::error::{
-    ReificationDiagnostic, dummy_expression, internal_error, underscore_expression,
-    unprocessed_expression, unsupported_construct,
+    ReificationDiagnostic, dummy_expression, internal_error, invalid_graph_pipeline,
+    underscore_expression, unprocessed_expression,
 };
 use crate::{
     intern::Interner,
     node::{
         Node, PartialNode,
         access::{Access, AccessKind, field::FieldAccess, index::IndexAccess},
+        branch::{Branch, BranchKind, If},
         call::{Call, CallArgument},
         closure::{Closure, ClosureParam, ClosureSignature},
-        data::{Data, DataKind, Literal},
+        data::{
+            Data, DataKind, Dict, List, Literal, Struct, Tuple, dict::DictField,
+            r#struct::StructField,
+        },
+        graph::{
+            Graph, GraphKind,
+            read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+        },
         input::Input,
         kind::NodeKind,
         r#let::Let,
@@ -45,6 +54,56 @@ use crate::{
     path::QualifiedPath,
 };
 
+/// The intrinsics of the `::graph` module, that are used to construct graph operations.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum GraphIntrinsic {
+    Entities,
+    Filter,
+    Collect,
+}
+
+impl GraphIntrinsic {
+    const fn path(self) -> &'static str {
+        match self {
+            Self::Entities => "::graph::head::entities",
+            Self::Filter => "::graph::body::filter",
+            Self::Collect => "::graph::tail::collect",
+        }
+    }
+
+    const fn arity(self) -> usize {
+        match self {
+            Self::Entities | Self::Collect => 1,
+            Self::Filter => 2,
+        }
+    }
+
+    fn from_node(node: Node<'_>) -> Option<Self> {
+        let NodeKind::Variable(Variable {
+            kind: VariableKind::Qualified(QualifiedVariable { path, .. }),
+            ..
+        }) = node.kind
+        else {
+            return None;
+        };
+
+        let [module, submodule, name] = &*path.0 else {
+            return None;
+        };
+
+        match (
+            module.value.as_str(),
+            submodule.value.as_str(),
+            name.value.as_str(),
+        ) {
+            ("graph", "head", "entities") => Some(Self::Entities),
+            ("graph", "body", "filter") => Some(Self::Filter),
+            ("graph", "tail", "collect") => Some(Self::Collect),
+            _ => None,
+        }
+    }
+}
+
 // TODO: we might want to contemplate moving this into a separate crate, to completely separate
 // HashQL's AST and HIR. (like done in rustc)
 #[derive(Debug, Clone)]
@@ -105,6 +164,10 @@ impl<'heap> ReificationContext<'_, 'heap> {
 
         let (function, arguments) = Option::zip(function, arguments)?;
 
+        if GraphIntrinsic::from_node(function) == Some(GraphIntrinsic::Collect) {
+            return self.graph_read(span, arguments);
+        }
+
         Some(NodeKind::Call(Call {
             span,
             function,
@@ -112,6 +175,97 @@ impl<'heap> ReificationContext<'_, 'heap> {
         }))
     }
 
+    /// Returns the arguments of a call to the given graph intrinsic.
+    ///
+    /// Reports a diagnostic if the number of arguments does not match the arity of the intrinsic.
+    fn graph_arguments(
+        &mut self,
+        span: SpanId,
+        intrinsic: GraphIntrinsic,
+        arguments: Interned<'heap, [CallArgument<'heap>]>,
+    ) -> Option<Interned<'heap, [CallArgument<'heap>]>> {
+        if arguments.len() != intrinsic.arity() {
+            self.diagnostics.push(invalid_graph_pipeline(
+                span,
+                &format!(
+                    "`{}` expects {} argument(s), but {} were provided",
+                    intrinsic.path(),
+                    intrinsic.arity(),
+                    arguments.len()
+                ),
+            ));
+
+            return None;
+        }
+
+        Some(arguments)
+    }
+
+    /// Constructs a graph read from a call to `::graph::tail::collect`.
+    ///
+    /// The pipeline is unwound from the tail, through the body operations, until the head is
+    /// reached.
+    fn graph_read(
+        &mut self,
+        span: SpanId,
+        arguments: Interned<'heap, [CallArgument<'heap>]>,
+    ) -> Option<NodeKind<'heap>> {
+        let arguments = self.graph_arguments(span, GraphIntrinsic::Collect, arguments)?;
+
+        let mut body = SmallVec::new();
+        let mut current = arguments[0].value;
+
+        let head = loop {
+            let NodeKind::Call(call) = current.kind else {
+                self.diagnostics.push(invalid_graph_pipeline(
+                    current.span,
+                    "expected a graph pipeline here",
+                ));
+
+                return None;
+            };
+
+            match GraphIntrinsic::from_node(call.function) {
+                Some(GraphIntrinsic::Entities) => {
+                    let arguments =
+                        self.graph_arguments(call.span, GraphIntrinsic::Entities, call.arguments)?;
+
+                    break GraphReadHead::Entity {
+                        axis: arguments[0].value,
+                    };
+                }
+                Some(GraphIntrinsic::Filter) => {
+                    let arguments =
+                        self.graph_arguments(call.span, GraphIntrinsic::Filter, call.arguments)?;
+
+                    body.push(GraphReadBody::Filter(arguments[1].value));
+                    current = arguments[0].value;
+                }
+                Some(GraphIntrinsic::Collect) | None => {
+                    self.diagnostics.push(invalid_graph_pipeline(
+                        current.span,
+                        "expected a graph pipeline here",
+                    ));
+
+                    return None;
+                }
+            }
+        };
+
+        // The body has been collected from the tail towards the head
+        body.reverse();
+
+        Some(NodeKind::Graph(Graph {
+            span,
+            kind: GraphKind::Read(GraphRead {
+                span,
+                head,
+                body: self.interner.intern_graph_read_body(&body),
+                tail: GraphReadTail::Collect,
+            }),
+        }))
+    }
+
     fn wrap_type_assertion(
         &self,
         span: SpanId,
@@ -153,6 +307,156 @@ impl<'heap> ReificationContext<'_, 'heap> {
         self.wrap_type_assertion(span, kind, r#type)
     }
 
+    fn struct_expr(
+        &mut self,
+        StructExpr {
+            id: _,
+            span,
+            entries,
+            r#type,
+        }: StructExpr<'heap>,
+    ) -> Option<NodeKind<'heap>> {
+        let mut incomplete = false;
+        let mut fields = SmallVec::with_capacity(entries.len());
+
+        for entry in entries {
+            let Some(value) = self.expr(*entry.value) else {
+                incomplete = true;
+                continue;
+            };
+
+            fields.push(StructField {
+                name: entry.key,
+                value,
+            });
+        }
+
+        if incomplete {
+            return None;
+        }
+
+        let kind = NodeKind::Data(Data {
+            span,
+            kind: DataKind::Struct(Struct {
+                span,
+                fields: self.interner.intern_struct_fields(&fields),
+            }),
+        });
+
+        Some(self.wrap_type_assertion(span, kind, r#type))
+    }
+
+    fn dict_expr(
+        &mut self,
+        DictExpr {
+            id: _,
+            span,
+            entries,
+            r#type,
+        }: DictExpr<'heap>,
+    ) -> Option<NodeKind<'heap>> {
+        let mut incomplete = false;
+        let mut fields = SmallVec::with_capacity(entries.len());
+
+        for entry in entries {
+            let key = self.expr(*entry.key);
+            let value = self.expr(*entry.value);
+
+            let Some((key, value)) = Option::zip(key, value) else {
+                incomplete = true;
+                continue;
+            };
+
+            fields.push(DictField { key, value });
+        }
+
+        if incomplete {
+            return None;
+        }
+
+        let kind = NodeKind::Data(Data {
+            span,
+            kind: DataKind::Dict(Dict {
+                span,
+                fields: self.interner.intern_dict_fields(&fields),
+            }),
+        });
+
+        Some(self.wrap_type_assertion(span, kind, r#type))
+    }
+
+    fn tuple_expr(
+        &mut self,
+        TupleExpr {
+            id: _,
+            span,
+            elements,
+            r#type,
+        }: TupleExpr<'heap>,
+    ) -> Option<NodeKind<'heap>> {
+        let mut incomplete = false;
+        let mut fields = SmallVec::with_capacity(elements.len());
+
+        for element in elements {
+            let Some(value) = self.expr(*element.value) else {
+                incomplete = true;
+                continue;
+            };
+
+            fields.push(value);
+        }
+
+        if incomplete {
+            return None;
+        }
+
+        let kind = NodeKind::Data(Data {
+            span,
+            kind: DataKind::Tuple(Tuple {
+                span,
+                fields: self.interner.intern_nodes(&fields),
+            }),
+        });
+
+        Some(self.wrap_type_assertion(span, kind, r#type))
+    }
+
+    fn list_expr(
+        &mut self,
+        ListExpr {
+            id: _,
+            span,
+            elements,
+            r#type,
+        }: ListExpr<'heap>,
+    ) -> Option<NodeKind<'heap>> {
+        let mut incomplete = false;
+        let mut values = SmallVec::with_capacity(elements.len());
+
+        for element in elements {
+            let Some(value) = self.expr(*element.value) else {
+                incomplete = true;
+                continue;
+            };
+
+            values.push(value);
+        }
+
+        if incomplete {
+            return None;
+        }
+
+        let kind = NodeKind::Data(Data {
+            span,
+            kind: DataKind::List(List {
+                span,
+                elements: self.interner.intern_nodes(&values),
+            }),
+        });
+
+        Some(self.wrap_type_assertion(span, kind, r#type))
+    }
+
     fn path_segment_arguments(
         &mut self,
         arguments: heap::Vec<'heap, PathSegmentArgument<'heap>>,
@@ -328,6 +632,37 @@ impl<'heap> ReificationContext<'_, 'heap> {
         }))
     }
 
+    fn if_expr(
+        &mut self,
+        IfExpr {
+            id: _,
+            span,
+            test,
+            then,
+            r#else,
+        }: IfExpr<'heap>,
+    ) -> Option<NodeKind<'heap>> {
+        let test = self.expr(*test);
+        let then = self.expr(*then);
+        let r#else = r#else.map(|r#else| self.expr(*r#else));
+
+        let (test, then) = Option::zip(test, then)?;
+        let r#else = match r#else {
+            Some(r#else) => Some(r#else?),
+            None => None,
+        };
+
+        Some(NodeKind::Branch(Branch {
+            span,
+            kind: BranchKind::If(If {
+                span,
+                test,
+                then,
+                r#else,
+            }),
+        }))
+    }
+
     fn field_expr(
         &mut self,
         FieldExpr {
@@ -401,42 +736,10 @@ impl<'heap> ReificationContext<'_, 'heap> {
     fn expr(&mut self, expr: Expr<'heap>) -> Option<Node<'heap>> {
         let kind = match expr.kind {
             ExprKind::Call(call) => self.call_expr(call)?,
-            ExprKind::Struct(_) => {
-                self.diagnostics.push(unsupported_construct(
-                    expr.span,
-                    "struct literal",
-                    "https://linear.app/hash/issue/H-4602/enable-struct-literal-construct",
-                ));
-
-                return None;
-            }
-            ExprKind::Dict(_) => {
-                self.diagnostics.push(unsupported_construct(
-                    expr.span,
-                    "dict literal",
-                    "https://linear.app/hash/issue/H-4603/enable-dict-literal-construct",
-                ));
-
-                return None;
-            }
-            ExprKind::Tuple(_) => {
-                self.diagnostics.push(unsupported_construct(
-                    expr.span,
-                    "tuple literal",
-                    "https://linear.app/hash/issue/H-4604/enable-tuple-literal-construct",
-                ));
-
-                return None;
-            }
-            ExprKind::List(_) => {
-                self.diagnostics.push(unsupported_construct(
-                    expr.span,
-                    "list literal",
-                    "https://linear.app/hash/issue/H-4605/enable-list-literal-construct",
-                ));
-
-                return None;
-            }
+            ExprKind::Struct(r#struct) => self.struct_expr(r#struct)?,
+            ExprKind::Dict(dict) => self.dict_expr(dict)?,
+            ExprKind::Tuple(tuple) => self.tuple_expr(tuple)?,
+            ExprKind::List(list) => self.list_expr(list)?,
             ExprKind::Literal(literal) => self.literal_expr(literal),
             ExprKind::Path(path) => self.path(path)?,
             ExprKind::Let(r#let) => self.let_expr(r#let)?,
@@ -468,14 +771,7 @@ impl<'heap> ReificationContext<'_, 'heap> {
             }
             ExprKind::Input(input) => self.input_expr(input)?,
             ExprKind::Closure(closure) => self.closure_expr(closure)?,
-            ExprKind::If(_) => {
-                self.diagnostics.push(unsupported_construct(
-                    expr.span,
-                    "if expression",
-                    "https://linear.app/hash/issue/H-4606/implement-if-expressions",
-                ));
-                return None;
-            }
+            ExprKind::If(r#if) => self.if_expr(r#if)?,
             ExprKind::Field(field) => self.field_expr(field)?,
             ExprKind::Index(index) => self.index_expr(index)?,
             ExprKind::Is(is) => self.is_expr(is)?,
@@ -488,6 +784,8 @@ impl<'heap> ReificationContext<'_, 'heap> {
                 self.diagnostics.push(dummy_expression(expr.span));
                 return None;
             }
+            // The error has already been reported while parsing
+            ExprKind::Error => return None,
         };
 
         Some(self.interner.intern_node(PartialNode {
@@ -62,11 +62,17 @@ use crate::{
     node::{
         HirId, Node,
         access::{Access, AccessKind, field::FieldAccess, index::IndexAccess},
-        branch::{Branch, BranchKind},
+        branch::{Branch, BranchKind, If},
         call::{Call, CallArgument},
         closure::{Closure, ClosureParam, ClosureSignature},
-        data::{Data, DataKind, Literal},
-        graph::{Graph, GraphKind},
+        data::{
+            Data, DataKind, Dict, List, Literal, Struct, Tuple, dict::DictField,
+            r#struct::StructField,
+        },
+        graph::{
+            Graph, GraphKind,
+            read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+        },
         input::Input,
         kind::NodeKind,
         r#let::Let,
@@ -151,6 +157,30 @@ pub trait Visitor<'heap> {
         walk_data(self, data);
     }
 
+    fn visit_struct(&mut self, r#struct: &'heap Struct<'heap>) {
+        walk_struct(self, r#struct);
+    }
+
+    fn visit_struct_field(&mut self, field: &'heap StructField<'heap>) {
+        walk_struct_field(self, field);
+    }
+
+    fn visit_dict(&mut self, dict: &'heap Dict<'heap>) {
+        walk_dict(self, dict);
+    }
+
+    fn visit_dict_field(&mut self, field: &'heap DictField<'heap>) {
+        walk_dict_field(self, field);
+    }
+
+    fn visit_tuple(&mut self, tuple: &'heap Tuple<'heap>) {
+        walk_tuple(self, tuple);
+    }
+
+    fn visit_list(&mut self, list: &'heap List<'heap>) {
+        walk_list(self, list);
+    }
+
     fn visit_literal(&mut self, literal: &'heap Literal<'heap>) {
         walk_literal(self, literal);
     }
@@ -223,6 +253,10 @@ pub trait Visitor<'heap> {
         walk_branch(self, branch);
     }
 
+    fn visit_if(&mut self, r#if: &'heap If<'heap>) {
+        walk_if(self, r#if);
+    }
+
     fn visit_closure(&mut self, closure: &'heap Closure<'heap>) {
         walk_closure(self, closure);
     }
@@ -238,6 +272,22 @@ pub trait Visitor<'heap> {
     fn visit_graph(&mut self, graph: &'heap Graph<'heap>) {
         walk_graph(self, graph);
     }
+
+    fn visit_graph_read(&mut self, read: &'heap GraphRead<'heap>) {
+        walk_graph_read(self, read);
+    }
+
+    fn visit_graph_read_head(&mut self, head: &'heap GraphReadHead<'heap>) {
+        walk_graph_read_head(self, head);
+    }
+
+    fn visit_graph_read_body(&mut self, body: &'heap GraphReadBody<'heap>) {
+        walk_graph_read_body(self, body);
+    }
+
+    fn visit_graph_read_tail(&mut self, tail: &'heap GraphReadTail) {
+        walk_graph_read_tail(self, tail);
+    }
 }
 
 pub fn walk_ident<'heap, T: Visitor<'heap> + ?Sized>(
@@ -288,10 +338,74 @@ pub fn walk_data<'heap, T: Visitor<'heap> + ?Sized>(
     visitor.visit_span(*span);
 
     match kind {
+        DataKind::Struct(r#struct) => visitor.visit_struct(r#struct),
+        DataKind::Dict(dict) => visitor.visit_dict(dict),
+        DataKind::Tuple(tuple) => visitor.visit_tuple(tuple),
+        DataKind::List(list) => visitor.visit_list(list),
         DataKind::Literal(literal) => visitor.visit_literal(literal),
     }
 }
 
+pub fn walk_struct<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    Struct { span, fields }: &'heap Struct<'heap>,
+) {
+    visitor.visit_span(*span);
+
+    for field in fields {
+        visitor.visit_struct_field(field);
+    }
+}
+
+pub fn walk_struct_field<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    StructField { name, value }: &'heap StructField<'heap>,
+) {
+    visitor.visit_ident(name);
+    visitor.visit_node(value);
+}
+
+pub fn walk_dict<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    Dict { span, fields }: &'heap Dict<'heap>,
+) {
+    visitor.visit_span(*span);
+
+    for field in fields {
+        visitor.visit_dict_field(field);
+    }
+}
+
+pub fn walk_dict_field<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    DictField { key, value }: &'heap DictField<'heap>,
+) {
+    visitor.visit_node(key);
+    visitor.visit_node(value);
+}
+
+pub fn walk_tuple<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    Tuple { span, fields }: &'heap Tuple<'heap>,
+) {
+    visitor.visit_span(*span);
+
+    for field in fields {
+        visitor.visit_node(field);
+    }
+}
+
+pub fn walk_list<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    List { span, elements }: &'heap List<'heap>,
+) {
+    visitor.visit_span(*span);
+
+    for element in elements {
+        visitor.visit_node(element);
+    }
+}
+
 pub fn walk_literal<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
     Literal { span, kind: _ }: &'heap Literal<'heap>,
@@ -508,16 +622,31 @@ pub fn walk_call_argument<'heap, T: Visitor<'heap> + ?Sized>(
 
 pub fn walk_branch<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
-    Branch {
-        span,
-        kind,
-        _marker: _,
-    }: &'heap Branch<'heap>,
+    Branch { span, kind }: &'heap Branch<'heap>,
 ) {
     visitor.visit_span(*span);
 
     match kind {
-        BranchKind::Never(_) => unreachable!(),
+        BranchKind::If(r#if) => visitor.visit_if(r#if),
+    }
+}
+
+pub fn walk_if<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    If {
+        span,
+        test,
+        then,
+        r#else,
+    }: &'heap If<'heap>,
+) {
+    visitor.visit_span(*span);
+
+    visitor.visit_node(test);
+    visitor.visit_node(then);
+
+    if let Some(r#else) = r#else {
+        visitor.visit_node(r#else);
     }
 }
 
@@ -566,15 +695,58 @@ pub fn walk_closure_param<'heap, T: Visitor<'heap> + ?Sized>(
 
 pub fn walk_graph<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
-    Graph {
-        span,
-        kind,
-        _marker: _,
-    }: &'heap Graph<'heap>,
+    Graph { span, kind }: &'heap Graph<'heap>,
 ) {
     visitor.visit_span(*span);
 
     match kind {
-        GraphKind::Never(_) => unreachable!(),
+        GraphKind::Read(read) => visitor.visit_graph_read(read),
+    }
+}
+
+pub fn walk_graph_read<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    GraphRead {
+        span,
+        head,
+        body,
+        tail,
+    }: &'heap GraphRead<'heap>,
+) {
+    visitor.visit_span(*span);
+
+    visitor.visit_graph_read_head(head);
+
+    for body in body {
+        visitor.visit_graph_read_body(body);
+    }
+
+    visitor.visit_graph_read_tail(tail);
+}
+
+pub fn walk_graph_read_head<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    head: &'heap GraphReadHead<'heap>,
+) {
+    match head {
+        GraphReadHead::Entity { axis } => visitor.visit_node(axis),
+    }
+}
+
+pub fn walk_graph_read_body<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    body: &'heap GraphReadBody<'heap>,
+) {
+    match body {
+        GraphReadBody::Filter(node) => visitor.visit_node(node),
+    }
+}
+
+pub const fn walk_graph_read_tail<'heap, T: Visitor<'heap> + ?Sized>(
+    _visitor: &mut T,
+    tail: &'heap GraphReadTail,
+) {
+    match tail {
+        GraphReadTail::Collect => {}
     }
 }
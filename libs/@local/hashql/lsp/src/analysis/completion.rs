@@ -0,0 +1,155 @@
+use hashql_core::{
+    heap::Heap,
+    module::{
+        ModuleRegistry, Universe,
+        item::{IntrinsicItem, Item, ItemKind},
+        namespace::ModuleNamespace,
+    },
+    span::SpanId,
+    symbol::Symbol,
+    r#type::environment::Environment,
+};
+use lsp_types::CompletionItemKind;
+use text_size::{TextRange, TextSize};
+
+use super::Analysis;
+
+/// A name that can be inserted at the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Completion {
+    pub label: String,
+    pub kind: CompletionItemKind,
+    /// The absolute path of the item, if the name refers to an item of a module.
+    pub detail: Option<String>,
+}
+
+impl Completion {
+    fn item<'heap>(
+        registry: &ModuleRegistry<'heap>,
+        name: Symbol<'heap>,
+        item: Item<'heap>,
+    ) -> Self {
+        let kind = match item.kind {
+            ItemKind::Module(_) => CompletionItemKind::MODULE,
+            ItemKind::Type(..)
+            | ItemKind::Intrinsic(IntrinsicItem {
+                name: _,
+                universe: Universe::Type,
+            }) => CompletionItemKind::CLASS,
+            ItemKind::Intrinsic(IntrinsicItem {
+                name,
+                universe: Universe::Value,
+            }) if name.starts_with("::kernel::special_form::") => CompletionItemKind::KEYWORD,
+            ItemKind::Intrinsic(IntrinsicItem {
+                name: _,
+                universe: Universe::Value,
+            }) => CompletionItemKind::FUNCTION,
+        };
+
+        Self {
+            label: name.as_str().to_owned(),
+            kind,
+            detail: Some(
+                item.absolute_path(registry)
+                    .flat_map(|segment| ["::", segment.unwrap()])
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Returns the part of the path in front of the offset.
+///
+/// Paths are written as strings in J-Expr, the prefix is therefore the content of the string the
+/// offset is in, up to the offset. Returns `None` if the offset is not inside of a string.
+fn prefix(source: &str, offset: TextSize) -> Option<&str> {
+    let before = source.get(..usize::from(offset))?;
+    let start = before.rfind(['"', '\n'])?;
+
+    let prefix = before.get(start..)?.strip_prefix('"')?;
+
+    if prefix
+        .contains(|char: char| char.is_whitespace() || matches!(char, '[' | ']' | '{' | '}' | ','))
+    {
+        return None;
+    }
+
+    Some(prefix)
+}
+
+/// Returns the completions for the path in front of the offset, alongside the range they replace.
+///
+/// If the path has a module prefix, such as `::graph::head::`, the items of that module are
+/// returned, otherwise the items of the prelude and the bindings in scope are returned.
+pub(crate) fn complete(
+    analysis: &Analysis,
+    source: &str,
+    offset: TextSize,
+) -> Option<(TextRange, Vec<Completion>)> {
+    let prefix = prefix(source, offset)?;
+
+    let (module, partial) = prefix
+        .rsplit_once("::")
+        .map_or((None, prefix), |(module, partial)| (Some(module), partial));
+    let range = TextRange::new(offset - TextSize::of(partial), offset);
+
+    let heap = Heap::new();
+    let environment = Environment::new(SpanId::SYNTHETIC, &heap);
+    let registry = ModuleRegistry::new(&environment);
+
+    let mut namespace = ModuleNamespace::new(&registry);
+    namespace.import_prelude();
+
+    let imports = namespace
+        .imports_as_slice()
+        .iter()
+        .map(|import| (import.name, import.item));
+
+    let mut completions = Vec::new();
+
+    if let Some(module) = module {
+        // Root modules are part of the prelude, therefore absolute and relative paths are
+        // resolved the same way.
+        let mut items: Vec<_> = imports
+            .filter(|(_, item)| matches!(item.kind, ItemKind::Module(_)))
+            .collect();
+
+        for segment in module.split("::").filter(|segment| !segment.is_empty()) {
+            let Some(ItemKind::Module(id)) = items
+                .iter()
+                .find(|(name, _)| name.as_str() == segment)
+                .map(|(_, item)| item.kind)
+            else {
+                return Some((range, Vec::new()));
+            };
+
+            items = registry
+                .modules
+                .index(id)
+                .items
+                .iter()
+                .map(|item| (item.name, *item))
+                .collect();
+        }
+
+        completions.extend(
+            items
+                .into_iter()
+                .map(|(name, item)| Completion::item(&registry, name, item)),
+        );
+    } else {
+        completions.extend(analysis.bindings(offset).map(|binding| Completion {
+            label: binding.name.clone(),
+            kind: binding.kind,
+            detail: None,
+        }));
+
+        completions.extend(imports.map(|(name, item)| Completion::item(&registry, name, item)));
+    }
+
+    // Bindings shadow the items of the prelude, the sort is stable and keeps them first
+    completions.sort_by(|lhs, rhs| lhs.label.cmp(&rhs.label));
+    completions.dedup_by(|next, previous| next.label == previous.label);
+
+    Some((range, completions))
+}
@@ -0,0 +1,76 @@
+use core::fmt::Write as _;
+
+use hashql_core::span::{SpanId, storage::SpanStorage};
+use hashql_diagnostics::{
+    Diagnostic, Severity,
+    category::{DiagnosticCategory, canonical_category_id, category_display_name},
+    span::AbsoluteDiagnosticSpan,
+};
+use hashql_syntax_jexpr::span::Span;
+use text_size::TextRange;
+
+/// A diagnostic, whose spans have been resolved to ranges in the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AnalysisDiagnostic {
+    pub severity: Severity,
+    pub code: String,
+    pub message: String,
+    pub range: TextRange,
+    /// The secondary labels of the diagnostic.
+    pub related: Vec<(TextRange, String)>,
+}
+
+impl AnalysisDiagnostic {
+    /// Resolves the labels of a diagnostic and flattens its message, notes and help into a single
+    /// message.
+    ///
+    /// The first label is the primary label, which determines the range of the diagnostic. Labels
+    /// whose span cannot be resolved are dropped.
+    pub(crate) fn resolve<C>(diagnostic: Diagnostic<C, SpanId>, spans: &SpanStorage<Span>) -> Self
+    where
+        C: DiagnosticCategory,
+    {
+        let mut message = diagnostic
+            .message
+            .unwrap_or_else(|| category_display_name(&diagnostic.category))
+            .into_owned();
+
+        let mut labels = diagnostic.labels.iter().filter_map(|label| {
+            let span = AbsoluteDiagnosticSpan::new(label.span(), &mut &*spans).ok()?;
+
+            Some((span.range(), label.message()))
+        });
+
+        let range = match labels.next() {
+            Some((range, label)) => {
+                if !label.is_empty() {
+                    message.push('\n');
+                    message.push_str(label);
+                }
+
+                range
+            }
+            None => TextRange::default(),
+        };
+
+        let related = labels
+            .map(|(range, label)| (range, label.to_owned()))
+            .collect();
+
+        for note in &diagnostic.notes {
+            let _: Result<(), _> = write!(message, "\n\nnote: {}", note.message());
+        }
+
+        for help in &diagnostic.help {
+            let _: Result<(), _> = write!(message, "\n\nhelp: {}", help.message());
+        }
+
+        Self {
+            severity: diagnostic.severity,
+            code: canonical_category_id(&diagnostic.category).to_string(),
+            message,
+            range,
+            related,
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use core::mem;
+
+use hashql_ast::{
+    lowering::ExtractedTypes,
+    node::{
+        expr::{ClosureExpr, ExprKind, InputExpr, LetExpr, NewTypeExpr, TypeExpr},
+        id::NodeId,
+        path::Path,
+        r#type::Type,
+    },
+    visit::{
+        Visitor, walk_closure_expr, walk_input_expr, walk_let_expr, walk_newtype_expr, walk_path,
+        walk_type, walk_type_expr,
+    },
+};
+use hashql_core::{
+    collection::FastHashMap,
+    module::{
+        ModuleRegistry, Universe,
+        item::{IntrinsicItem, Item, ItemKind},
+        locals::{TypeDef, TypeLocals},
+    },
+    pretty::{PrettyOptions, PrettyPrint},
+    span::{SpanId, storage::SpanStorage},
+    symbol::{Ident, Symbol},
+    r#type::{TypeId, environment::Environment, kind::generic::GenericArgument},
+};
+use hashql_diagnostics::span::AbsoluteDiagnosticSpan;
+use hashql_syntax_jexpr::span::Span;
+use lsp_types::CompletionItemKind;
+use text_size::TextRange;
+
+/// A range of the source, which refers to something the language server knows about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Occurrence {
+    pub range: TextRange,
+    /// Markdown shown when hovering over the occurrence.
+    pub hover: Option<String>,
+    /// The definition the occurrence refers to.
+    pub definition: Option<Definition>,
+}
+
+/// The definition of a name, to which the client can navigate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Definition {
+    /// A binding, defined at the given range of the document.
+    Binding(TextRange),
+    /// An item of a module, identified by its absolute path.
+    Item(String),
+}
+
+/// A name bound by `let`, `type`, `newtype` or a closure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Binding {
+    /// The name as written in the source.
+    pub name: String,
+    pub kind: CompletionItemKind,
+    pub range: TextRange,
+    /// The range of the source in which the name is in scope.
+    pub scope: TextRange,
+    pub hover: Option<String>,
+}
+
+/// Removes the suffix added by the name mangler, which makes bindings unique.
+fn demangle(symbol: Symbol<'_>) -> &str {
+    let name = symbol.unwrap();
+
+    match name.rsplit_once(':') {
+        Some((original, suffix))
+            if !original.is_empty() && suffix.bytes().all(|byte| byte.is_ascii_digit()) =>
+        {
+            original
+        }
+        _ => name,
+    }
+}
+
+fn code(text: &str) -> String {
+    format!("```\n{text}\n```")
+}
+
+/// Collects the occurrences and bindings of a lowered expression.
+///
+/// The name mangler has made every binding unique within its universe, and the import resolver
+/// has replaced every reference to an item of a module with its absolute path, which means that
+/// every path can be resolved without having to keep track of scopes.
+pub(crate) struct Indexer<'env, 'heap> {
+    env: &'env Environment<'heap>,
+    registry: &'env ModuleRegistry<'heap>,
+    spans: &'env SpanStorage<Span>,
+
+    locals: TypeLocals<'heap>,
+    anonymous: FastHashMap<NodeId, TypeId>,
+    signatures: FastHashMap<NodeId, TypeDef<'heap>>,
+
+    universe: Universe,
+    lookup: FastHashMap<(Universe, Symbol<'heap>), usize>,
+
+    occurrences: Vec<Occurrence>,
+    bindings: Vec<Binding>,
+}
+
+impl<'env, 'heap> Indexer<'env, 'heap> {
+    pub(crate) fn new(
+        env: &'env Environment<'heap>,
+        registry: &'env ModuleRegistry<'heap>,
+        spans: &'env SpanStorage<Span>,
+        types: ExtractedTypes<'heap>,
+    ) -> Self {
+        Self {
+            env,
+            registry,
+            spans,
+            locals: types.locals,
+            anonymous: types.anonymous.into_iter().collect(),
+            signatures: types.signatures.into_iter().collect(),
+            universe: Universe::Value,
+            lookup: FastHashMap::default(),
+            occurrences: Vec::new(),
+            bindings: Vec::new(),
+        }
+    }
+
+    pub(crate) fn finish(self) -> (Vec<Occurrence>, Vec<Binding>) {
+        (self.occurrences, self.bindings)
+    }
+
+    fn range(&self, span: SpanId) -> Option<TextRange> {
+        AbsoluteDiagnosticSpan::new(&span, &mut &*self.spans)
+            .ok()
+            .map(AbsoluteDiagnosticSpan::range)
+    }
+
+    fn pretty(&self, value: &impl PrettyPrint<'heap>) -> String {
+        value
+            .pretty_print(self.env, PrettyOptions::default().without_color())
+            .to_string()
+    }
+
+    fn pretty_type(&self, id: TypeId) -> String {
+        self.pretty(&self.env.r#type(id))
+    }
+
+    /// Returns the types of the parameters of a closure signature.
+    fn closure_params(&self, signature: NodeId) -> Option<&'heap [TypeId]> {
+        let def = self.signatures.get(&signature)?;
+
+        let mut r#type = self.env.r#type(def.id);
+        if let Some(generic) = r#type.kind.generic() {
+            r#type = self.env.r#type(generic.base);
+        }
+
+        r#type.kind.closure().map(|closure| &*closure.params)
+    }
+
+    fn bind(
+        &mut self,
+        universe: Universe,
+        kind: CompletionItemKind,
+        ident: &Ident<'heap>,
+        scope: Option<TextRange>,
+        hover: Option<String>,
+    ) {
+        let Some(range) = self.range(ident.span) else {
+            return;
+        };
+
+        self.lookup
+            .insert((universe, ident.value), self.bindings.len());
+
+        self.occurrences.push(Occurrence {
+            range,
+            hover: hover.clone(),
+            definition: Some(Definition::Binding(range)),
+        });
+
+        self.bindings.push(Binding {
+            name: demangle(ident.value).to_owned(),
+            kind,
+            range,
+            scope: scope.unwrap_or(range),
+            hover,
+        });
+    }
+
+    fn describe(&self, item: Item<'heap>, path: &str) -> String {
+        match item.kind {
+            ItemKind::Module(_) => code(&format!("module {path}")),
+            ItemKind::Type(id, arguments) => {
+                let def = TypeDef {
+                    id,
+                    arguments: arguments
+                        .iter()
+                        .map(GenericArgument::as_reference)
+                        .collect(),
+                };
+
+                format!(
+                    "{}\n\n`{path}`",
+                    code(&format!("type {}{}", item.name.as_str(), self.pretty(&def)))
+                )
+            }
+            ItemKind::Intrinsic(IntrinsicItem {
+                name: _,
+                universe: Universe::Type,
+            }) => code(&format!("type {path}")),
+            ItemKind::Intrinsic(IntrinsicItem {
+                name: _,
+                universe: Universe::Value,
+            }) => code(&path),
+        }
+    }
+
+    fn reference(&mut self, path: &Path<'heap>) {
+        let Some(range) = self.range(path.span) else {
+            return;
+        };
+
+        let occurrence = if path.rooted {
+            let segments = path.segments.iter().map(|segment| segment.name.value);
+
+            let Ok(item) = self.registry.resolve(segments, self.universe) else {
+                return;
+            };
+
+            let path: String = item
+                .absolute_path(self.registry)
+                .flat_map(|segment| ["::", segment.unwrap()])
+                .collect();
+
+            Occurrence {
+                range,
+                hover: Some(self.describe(item, &path)),
+                definition: Some(Definition::Item(path)),
+            }
+        } else {
+            let [segment] = &*path.segments else {
+                return;
+            };
+
+            let Some(&index) = self.lookup.get(&(self.universe, segment.name.value)) else {
+                return;
+            };
+
+            let binding = &self.bindings[index];
+
+            Occurrence {
+                range,
+                hover: binding.hover.clone(),
+                definition: Some(Definition::Binding(binding.range)),
+            }
+        };
+
+        self.occurrences.push(occurrence);
+    }
+
+    fn type_definition(&self, keyword: &str, name: Symbol<'heap>) -> Option<String> {
+        let local = self.locals.get(name)?;
+
+        Some(code(&format!(
+            "{keyword} {}{}",
+            demangle(name),
+            self.pretty(&local.value)
+        )))
+    }
+}
+
+impl<'heap> Visitor<'heap> for Indexer<'_, 'heap> {
+    fn visit_path(&mut self, path: &mut Path<'heap>) {
+        self.reference(path);
+
+        walk_path(self, path);
+    }
+
+    fn visit_type(&mut self, r#type: &mut Type<'heap>) {
+        if let Some(&id) = self.anonymous.get(&r#type.id)
+            && let Some(range) = self.range(r#type.span)
+        {
+            self.occurrences.push(Occurrence {
+                range,
+                hover: Some(code(&self.pretty_type(id))),
+                definition: None,
+            });
+        }
+
+        let universe = mem::replace(&mut self.universe, Universe::Type);
+        walk_type(self, r#type);
+        self.universe = universe;
+    }
+
+    fn visit_let_expr(&mut self, expr: &mut LetExpr<'heap>) {
+        let name = demangle(expr.name.value);
+
+        let hover = if let ExprKind::Closure(closure) = &expr.value.kind {
+            self.signatures
+                .get(&closure.signature.id)
+                .map(|def| code(&format!("{name}{}", self.pretty(def))))
+        } else {
+            expr.r#type
+                .as_ref()
+                .and_then(|r#type| self.anonymous.get(&r#type.id))
+                .map(|&id| code(&format!("{name}: {}", self.pretty_type(id))))
+        };
+
+        let scope = self.range(expr.body.span);
+        self.bind(
+            Universe::Value,
+            CompletionItemKind::VARIABLE,
+            &expr.name,
+            scope,
+            hover,
+        );
+
+        walk_let_expr(self, expr);
+    }
+
+    fn visit_type_expr(&mut self, expr: &mut TypeExpr<'heap>) {
+        let scope = self.range(expr.span);
+
+        let hover = self.type_definition("type", expr.name.value);
+        self.bind(
+            Universe::Type,
+            CompletionItemKind::CLASS,
+            &expr.name,
+            scope,
+            hover,
+        );
+
+        for constraint in &expr.constraints {
+            self.bind(
+                Universe::Type,
+                CompletionItemKind::TYPE_PARAMETER,
+                &constraint.name,
+                scope,
+                None,
+            );
+        }
+
+        walk_type_expr(self, expr);
+    }
+
+    fn visit_newtype_expr(&mut self, expr: &mut NewTypeExpr<'heap>) {
+        let scope = self.range(expr.span);
+
+        let hover = self.type_definition("newtype", expr.name.value);
+        self.bind(
+            Universe::Type,
+            CompletionItemKind::CLASS,
+            &expr.name,
+            scope,
+            hover,
+        );
+
+        // Newtypes also bring their constructor into scope, which shares the mangled name
+        if let Some(&index) = self.lookup.get(&(Universe::Type, expr.name.value)) {
+            self.lookup
+                .insert((Universe::Value, expr.name.value), index);
+        }
+
+        for constraint in &expr.constraints {
+            self.bind(
+                Universe::Type,
+                CompletionItemKind::TYPE_PARAMETER,
+                &constraint.name,
+                scope,
+                None,
+            );
+        }
+
+        walk_newtype_expr(self, expr);
+    }
+
+    fn visit_closure_expr(&mut self, expr: &mut ClosureExpr<'heap>) {
+        let scope = self.range(expr.span);
+
+        for param in &expr.signature.generics.params {
+            self.bind(
+                Universe::Type,
+                CompletionItemKind::TYPE_PARAMETER,
+                &param.name,
+                scope,
+                None,
+            );
+        }
+
+        let params = self.closure_params(expr.signature.id);
+
+        for (index, param) in expr.signature.inputs.iter().enumerate() {
+            let hover = params.and_then(|params| params.get(index)).map(|&id| {
+                code(&format!(
+                    "{}: {}",
+                    demangle(param.name.value),
+                    self.pretty_type(id)
+                ))
+            });
+
+            self.bind(
+                Universe::Value,
+                CompletionItemKind::VARIABLE,
+                &param.name,
+                scope,
+                hover,
+            );
+        }
+
+        walk_closure_expr(self, expr);
+    }
+
+    fn visit_input_expr(&mut self, expr: &mut InputExpr<'heap>) {
+        if let Some(&id) = self.anonymous.get(&expr.r#type.id)
+            && let Some(range) = self.range(expr.name.span)
+        {
+            self.occurrences.push(Occurrence {
+                range,
+                hover: Some(code(&format!(
+                    "input {}: {}",
+                    expr.name.value.as_str(),
+                    self.pretty_type(id)
+                ))),
+                definition: None,
+            });
+        }
+
+        walk_input_expr(self, expr);
+    }
+}
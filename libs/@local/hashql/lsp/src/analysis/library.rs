@@ -0,0 +1,127 @@
+use core::fmt::Write as _;
+
+use hashql_core::{
+    collection::FastHashMap,
+    heap::Heap,
+    module::{
+        ModuleId, ModuleRegistry, Universe,
+        item::{IntrinsicItem, Item, ItemKind},
+        locals::TypeDef,
+        namespace::ModuleNamespace,
+    },
+    pretty::{PrettyOptions, PrettyPrint as _},
+    span::SpanId,
+    r#type::{environment::Environment, kind::generic::GenericArgument},
+};
+use lsp_types::{Position, Range};
+
+/// A listing of every item of the standard library, one item per line.
+///
+/// Items of modules are not defined in any document, going to their definition instead opens
+/// this listing at the line of the item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Library {
+    pub text: String,
+    /// The range of the absolute path of each item in the listing.
+    ranges: FastHashMap<String, Range>,
+}
+
+impl Library {
+    pub(crate) fn new() -> Self {
+        let heap = Heap::new();
+        let environment = Environment::new(SpanId::SYNTHETIC, &heap);
+        let registry = ModuleRegistry::new(&environment);
+
+        let mut namespace = ModuleNamespace::new(&registry);
+        namespace.import_prelude();
+
+        // Root modules are part of the prelude, they are sorted to keep the listing stable
+        let mut modules: Vec<_> = namespace
+            .imports_as_slice()
+            .iter()
+            .map(|import| import.item)
+            .filter(|item| {
+                item.module == ModuleId::ROOT && matches!(item.kind, ItemKind::Module(_))
+            })
+            .collect();
+        modules.sort_by(|lhs, rhs| lhs.name.as_str().cmp(rhs.name.as_str()));
+        modules.dedup();
+
+        let mut this = Self {
+            text: String::from("// The items of the HashQL standard library\n"),
+            ranges: FastHashMap::default(),
+        };
+
+        for module in modules {
+            this.push(&environment, &registry, module, 0);
+        }
+
+        this
+    }
+
+    /// Returns the range of the absolute path of the item in the listing.
+    pub(crate) fn range(&self, path: &str) -> Option<Range> {
+        self.ranges.get(path).copied()
+    }
+
+    fn push<'heap>(
+        &mut self,
+        env: &Environment<'heap>,
+        registry: &ModuleRegistry<'heap>,
+        item: Item<'heap>,
+        depth: usize,
+    ) {
+        let path: String = item
+            .absolute_path(registry)
+            .flat_map(|segment| ["::", segment.unwrap()])
+            .collect();
+
+        let (keyword, signature) = match item.kind {
+            ItemKind::Module(_) => ("module ", String::new()),
+            ItemKind::Type(id, arguments) => {
+                let def = TypeDef {
+                    id,
+                    arguments: arguments
+                        .iter()
+                        .map(GenericArgument::as_reference)
+                        .collect(),
+                };
+
+                (
+                    "type ",
+                    def.pretty_print(env, PrettyOptions::default().without_color())
+                        .to_string(),
+                )
+            }
+            ItemKind::Intrinsic(IntrinsicItem {
+                name: _,
+                universe: Universe::Type,
+            }) => ("type ", String::new()),
+            ItemKind::Intrinsic(IntrinsicItem {
+                name: _,
+                universe: Universe::Value,
+            }) => ("", String::new()),
+        };
+
+        let line = u32::try_from(self.text.lines().count()).unwrap_or(u32::MAX);
+        let indent = "    ".repeat(depth);
+
+        // Positions are counted in UTF-16 code units
+        let start = u32::try_from(indent.len() + keyword.len()).unwrap_or(u32::MAX);
+        let end =
+            start.saturating_add(u32::try_from(path.encode_utf16().count()).unwrap_or(u32::MAX));
+
+        // Newtypes define a type and a constructor of the same name, the first one is kept
+        self.ranges
+            .entry(path.clone())
+            .or_insert_with(|| Range::new(Position::new(line, start), Position::new(line, end)));
+
+        let _: Result<(), _> = writeln!(self.text, "{indent}{keyword}{path}{signature}");
+
+        if let ItemKind::Module(id) = item.kind {
+            for &child in registry.modules.index(id).items {
+                self.push(env, registry, child, depth + 1);
+            }
+        }
+    }
+}
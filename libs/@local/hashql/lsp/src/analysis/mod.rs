@@ -0,0 +1,124 @@
+//! Analysis of HashQL documents.
+//!
+//! The AST borrows from the heap it has been allocated on, the analysis therefore runs the
+//! compiler from scratch on every change, and only retains the information required to answer
+//! requests of the client.
+mod completion;
+mod diagnostic;
+mod index;
+mod library;
+
+use alloc::sync::Arc;
+
+use hashql_ast::{lowering::lower, visit::Visitor as _};
+use hashql_core::{
+    heap::Heap,
+    module::ModuleRegistry,
+    span::{SpanId, storage::SpanStorage},
+    r#type::environment::Environment,
+};
+use hashql_syntax_jexpr::Parser;
+use text_size::{TextRange, TextSize};
+
+use self::index::{Binding, Indexer, Occurrence};
+pub(crate) use self::{
+    completion::complete, diagnostic::AnalysisDiagnostic, index::Definition, library::Library,
+};
+
+/// The result of compiling a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Analysis {
+    pub diagnostics: Vec<AnalysisDiagnostic>,
+    occurrences: Vec<Occurrence>,
+    bindings: Vec<Binding>,
+}
+
+impl Analysis {
+    pub(crate) fn new(source: &str) -> Self {
+        let heap = Heap::new();
+        let spans = Arc::new(SpanStorage::new());
+
+        let parser = Parser::new(&heap, Arc::clone(&spans));
+        let (mut expr, parse_diagnostics) = parser.parse_expr_recovering(source.as_bytes());
+
+        let mut diagnostics: Vec<_> = parse_diagnostics
+            .into_iter()
+            .map(|diagnostic| AnalysisDiagnostic::resolve(diagnostic, &spans))
+            .collect();
+
+        // Lowering skips every expression that could not be parsed, so that errors in one part
+        // of the document do not hide the errors in the rest of it.
+        let environment = Environment::new(SpanId::SYNTHETIC, &heap);
+        let registry = ModuleRegistry::new(&environment);
+
+        let (types, lower_diagnostics) = lower(
+            heap.intern_symbol("::main"),
+            &mut expr,
+            &environment,
+            &registry,
+        );
+
+        diagnostics.extend(
+            lower_diagnostics
+                .into_iter()
+                .map(|diagnostic| AnalysisDiagnostic::resolve(diagnostic, &spans)),
+        );
+
+        let mut indexer = Indexer::new(&environment, &registry, &spans, types);
+        indexer.visit_expr(&mut expr);
+        let (occurrences, bindings) = indexer.finish();
+
+        Self {
+            diagnostics,
+            occurrences,
+            bindings,
+        }
+    }
+
+    /// Returns the innermost occurrence at the given offset.
+    ///
+    /// If multiple occurrences share the same range, the one recorded last is returned, as it
+    /// has been recorded for a node nested inside of the other ones.
+    fn occurrence(&self, offset: TextSize) -> Option<&Occurrence> {
+        self.occurrences
+            .iter()
+            .rev()
+            .filter(|occurrence| occurrence.range.contains_inclusive(offset))
+            .min_by_key(|occurrence| occurrence.range.len())
+    }
+
+    /// Returns the hover text at the given offset, alongside the range it applies to.
+    pub(crate) fn hover(&self, offset: TextSize) -> Option<(TextRange, &str)> {
+        let occurrence = self.occurrence(offset)?;
+
+        occurrence
+            .hover
+            .as_deref()
+            .map(|hover| (occurrence.range, hover))
+    }
+
+    /// Returns the definition referred to at the given offset.
+    pub(crate) fn definition(&self, offset: TextSize) -> Option<&Definition> {
+        self.occurrence(offset)?.definition.as_ref()
+    }
+
+    /// Returns the bindings in scope at the given offset.
+    ///
+    /// If a name is shadowed, only the innermost binding is returned.
+    fn bindings(&self, offset: TextSize) -> impl Iterator<Item = &Binding> {
+        let mut bindings: Vec<&Binding> = self
+            .bindings
+            .iter()
+            .filter(|binding| binding.scope.contains_inclusive(offset))
+            .collect();
+
+        bindings.sort_by(|lhs, rhs| {
+            lhs.name
+                .cmp(&rhs.name)
+                .then_with(|| lhs.scope.len().cmp(&rhs.scope.len()))
+        });
+        bindings.dedup_by(|next, previous| next.name == previous.name);
+
+        bindings.into_iter()
+    }
+}
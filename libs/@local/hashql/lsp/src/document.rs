@@ -0,0 +1,107 @@
+use hashql_diagnostics::Severity;
+use line_index::{LineIndex, WideEncoding, WideLineCol};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString,
+    Position, Range, Uri,
+};
+use text_size::{TextRange, TextSize};
+
+use crate::analysis::{Analysis, AnalysisDiagnostic};
+
+/// A document opened by the client, alongside the analysis of its current content.
+#[derive(Debug)]
+pub(crate) struct Document {
+    pub uri: Uri,
+    pub version: i32,
+    pub text: String,
+    pub analysis: Analysis,
+    line_index: LineIndex,
+}
+
+impl Document {
+    pub(crate) fn new(uri: Uri, version: i32, text: String) -> Self {
+        let analysis = Analysis::new(&text);
+        let line_index = LineIndex::new(&text);
+
+        Self {
+            uri,
+            version,
+            text,
+            analysis,
+            line_index,
+        }
+    }
+
+    /// Converts an offset into a position, whose character is counted in UTF-16 code units.
+    pub(crate) fn position(&self, offset: TextSize) -> Position {
+        let offset = offset.min(TextSize::of(self.text.as_str()));
+
+        let line_col = self.line_index.line_col(offset);
+        let WideLineCol { line, col } = self
+            .line_index
+            .to_wide(WideEncoding::Utf16, line_col)
+            .unwrap_or(WideLineCol {
+                line: line_col.line,
+                col: line_col.col,
+            });
+
+        Position::new(line, col)
+    }
+
+    pub(crate) fn range(&self, range: TextRange) -> Range {
+        Range::new(self.position(range.start()), self.position(range.end()))
+    }
+
+    /// Converts a position into an offset, returns `None` if the position is outside of the
+    /// document.
+    pub(crate) fn offset(&self, position: Position) -> Option<TextSize> {
+        let line_col = self.line_index.to_utf8(
+            WideEncoding::Utf16,
+            WideLineCol {
+                line: position.line,
+                col: position.character,
+            },
+        )?;
+
+        self.line_index
+            .offset(line_col)
+            .filter(|&offset| offset <= TextSize::of(self.text.as_str()))
+    }
+
+    fn diagnostic(&self, diagnostic: &AnalysisDiagnostic) -> Diagnostic {
+        let severity = match diagnostic.severity {
+            Severity::Bug | Severity::Fatal | Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Note => DiagnosticSeverity::INFORMATION,
+            Severity::Debug => DiagnosticSeverity::HINT,
+        };
+
+        let related = diagnostic
+            .related
+            .iter()
+            .map(|(range, message)| DiagnosticRelatedInformation {
+                location: Location::new(self.uri.clone(), self.range(*range)),
+                message: message.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        Diagnostic {
+            range: self.range(diagnostic.range),
+            severity: Some(severity),
+            code: Some(NumberOrString::String(diagnostic.code.clone())),
+            source: Some("hashql".to_owned()),
+            message: diagnostic.message.clone(),
+            related_information: (!related.is_empty()).then_some(related),
+            ..Diagnostic::default()
+        }
+    }
+
+    /// Returns the diagnostics of the current content of the document.
+    pub(crate) fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| self.diagnostic(diagnostic))
+            .collect()
+    }
+}
@@ -0,0 +1,13 @@
+use core::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum ServerError {
+    #[display("the connection could not be initialized")]
+    Initialize,
+    #[display("the connection to the client has been closed")]
+    Disconnected,
+    #[display("the communication with the client failed")]
+    Io,
+}
+
+impl Error for ServerError {}
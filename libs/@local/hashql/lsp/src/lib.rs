@@ -0,0 +1,50 @@
+//! # HashQL language server
+//!
+//! A language server for J-Expr HashQL, communicating with editors through the Language Server
+//! Protocol.
+//!
+//! Whenever a document is opened or changed, it is parsed using the error-recovering J-Expr
+//! parser and run through the AST lowering passes. The results are used to provide:
+//!
+//! - diagnostics reported by the parser and the lowering passes,
+//! - hover information, showing the types extracted during lowering,
+//! - go-to-definition for bindings introduced by `let`, `type`, `newtype` and closures, and for
+//!   items of the standard library, which are resolved through the module registry and located in a
+//!   listing of the standard library written to a temporary file, and
+//! - completions for the items of the standard library and the bindings in scope.
+//!
+//! The server is started through [`run`], the `hashql-lsp` binary runs it over stdio.
+//!
+//! ## Workspace dependencies
+#![cfg_attr(doc, doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd"))]
+
+extern crate alloc;
+
+pub mod error;
+
+mod analysis;
+mod document;
+mod server;
+
+use error_stack::{Report, ResultExt as _};
+use lsp_server::Connection;
+
+use self::{error::ServerError, server::Server};
+
+/// Runs the language server on the given connection until the client requests a shutdown.
+///
+/// # Errors
+///
+/// - [`ServerError::Initialize`] if the initialization handshake with the client fails
+/// - [`ServerError::Disconnected`] if the connection is closed before the client requested a
+///   shutdown
+pub fn run(connection: &Connection) -> Result<(), Report<ServerError>> {
+    let capabilities =
+        serde_json::to_value(server::capabilities()).change_context(ServerError::Initialize)?;
+
+    connection
+        .initialize(capabilities)
+        .change_context(ServerError::Initialize)?;
+
+    Server::new(connection).run()
+}
@@ -0,0 +1,18 @@
+//! ## Workspace dependencies
+#![cfg_attr(doc, doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd"))]
+use error_stack::{Report, ResultExt as _};
+use hashql_lsp::error::ServerError;
+use lsp_server::Connection;
+
+/// Runs the HashQL language server, communicating with the client over stdin and stdout.
+fn main() -> Result<(), Report<ServerError>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    hashql_lsp::run(&connection)?;
+
+    // The writer thread only exits once every sender has been dropped
+    drop(connection);
+    io_threads.join().change_context(ServerError::Io)?;
+
+    Ok(())
+}
@@ -0,0 +1,306 @@
+use core::{cell::OnceCell, fmt::Write as _};
+use std::{fs, path::Path};
+
+use error_stack::{Report, ResultExt as _};
+use hashql_core::collection::FastHashMap;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    CompletionItem, CompletionOptions, CompletionParams, CompletionResponse, CompletionTextEdit,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams,
+    HoverProviderCapability, Location, MarkupContent, MarkupKind, OneOf, PublishDiagnosticsParams,
+    ServerCapabilities, TextDocumentItem, TextDocumentPositionParams, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextEdit, Uri,
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as NotificationKind, PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as RequestKind},
+};
+use tempfile::TempDir;
+use text_size::TextSize;
+
+use crate::{
+    analysis::{Definition, Library, complete},
+    document::Document,
+    error::ServerError,
+};
+
+/// The capabilities announced to the client during initialization.
+pub(crate) fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        // Documents are small enough that re-analyzing them from scratch is cheaper than keeping
+        // track of incremental changes
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["\"".to_owned(), ":".to_owned()]),
+            ..CompletionOptions::default()
+        }),
+        ..ServerCapabilities::default()
+    }
+}
+
+pub(crate) struct Server<'conn> {
+    connection: &'conn Connection,
+    documents: FastHashMap<Uri, Document>,
+    library: OnceCell<Option<(TempDir, Uri, Library)>>,
+}
+
+/// Converts an absolute path into a `file` URI.
+fn file_uri(path: &Path) -> Option<Uri> {
+    let path = path.to_str()?.replace('\\', "/");
+
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        uri.push('/');
+    }
+
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-._~:".contains(&byte) {
+            uri.push(char::from(byte));
+        } else {
+            let _: Result<(), _> = write!(uri, "%{byte:02X}");
+        }
+    }
+
+    uri.parse().ok()
+}
+
+impl<'conn> Server<'conn> {
+    pub(crate) fn new(connection: &'conn Connection) -> Self {
+        Self {
+            connection,
+            documents: FastHashMap::default(),
+            library: OnceCell::new(),
+        }
+    }
+
+    /// Handles messages of the client until it requests a shutdown.
+    pub(crate) fn run(mut self) -> Result<(), Report<ServerError>> {
+        let connection = self.connection;
+
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection
+                        .handle_shutdown(&request)
+                        .change_context(ServerError::Disconnected)?
+                    {
+                        return Ok(());
+                    }
+
+                    let response = self.handle_request(request);
+                    self.send(response.into())?;
+                }
+                Message::Notification(notification) => {
+                    self.handle_notification(notification)?;
+                }
+                // The server does not send any requests to the client
+                Message::Response(_) => {}
+            }
+        }
+
+        Err(Report::new(ServerError::Disconnected))
+    }
+
+    fn send(&self, message: Message) -> Result<(), Report<ServerError>> {
+        self.connection
+            .sender
+            .send(message)
+            .map_err(|_error| Report::new(ServerError::Disconnected))
+    }
+
+    fn publish(&self, params: PublishDiagnosticsParams) -> Result<(), Report<ServerError>> {
+        self.send(Notification::new(PublishDiagnostics::METHOD.to_owned(), params).into())
+    }
+
+    fn open(&mut self, document: Document) -> Result<(), Report<ServerError>> {
+        let params = PublishDiagnosticsParams::new(
+            document.uri.clone(),
+            document.diagnostics(),
+            Some(document.version),
+        );
+
+        self.documents.insert(document.uri.clone(), document);
+        self.publish(params)
+    }
+
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Result<(), Report<ServerError>> {
+        // Notifications cannot be answered, malformed ones are therefore ignored
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = parameters::<DidOpenTextDocument>(notification) else {
+                    return Ok(());
+                };
+
+                let TextDocumentItem {
+                    uri,
+                    language_id: _,
+                    version,
+                    text,
+                } = params.text_document;
+
+                self.open(Document::new(uri, version, text))
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(mut params) = parameters::<DidChangeTextDocument>(notification) else {
+                    return Ok(());
+                };
+
+                // The document is synchronized in full, the last change contains the content
+                let Some(change) = params.content_changes.pop() else {
+                    return Ok(());
+                };
+
+                self.open(Document::new(
+                    params.text_document.uri,
+                    params.text_document.version,
+                    change.text,
+                ))
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = parameters::<DidCloseTextDocument>(notification) else {
+                    return Ok(());
+                };
+
+                self.documents.remove(&params.text_document.uri);
+
+                // Diagnostics of closed documents are cleared
+                self.publish(PublishDiagnosticsParams::new(
+                    params.text_document.uri,
+                    Vec::new(),
+                    None,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            HoverRequest::METHOD => self.respond::<HoverRequest>(request, Self::hover),
+            GotoDefinition::METHOD => self.respond::<GotoDefinition>(request, Self::definition),
+            Completion::METHOD => self.respond::<Completion>(request, Self::completion),
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request `{}`", request.method),
+            ),
+        }
+    }
+
+    fn respond<R>(
+        &self,
+        request: Request,
+        handler: impl FnOnce(&Self, &R::Params) -> R::Result,
+    ) -> Response
+    where
+        R: RequestKind,
+    {
+        match serde_json::from_value(request.params) {
+            Ok(params) => Response::new_ok(request.id, handler(self, &params)),
+            Err(error) => Response::new_err(
+                request.id,
+                ErrorCode::InvalidParams as i32,
+                error.to_string(),
+            ),
+        }
+    }
+
+    fn locate(&self, params: &TextDocumentPositionParams) -> Option<(&Document, TextSize)> {
+        let document = self.documents.get(&params.text_document.uri)?;
+        let offset = document.offset(params.position)?;
+
+        Some((document, offset))
+    }
+
+    fn hover(&self, params: &HoverParams) -> Option<Hover> {
+        let (document, offset) = self.locate(&params.text_document_position_params)?;
+        let (range, hover) = document.analysis.hover(offset)?;
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: hover.to_owned(),
+            }),
+            range: Some(document.range(range)),
+        })
+    }
+
+    /// Returns the listing of the standard library, alongside the URI of the file it has been
+    /// written to.
+    ///
+    /// The listing is written the first time it is requested, `None` is returned if that failed.
+    /// It is placed in a newly created directory which only the current user can access, so other
+    /// users can neither replace it nor redirect the write elsewhere. The directory is removed
+    /// once the server shuts down.
+    fn library(&self) -> Option<(&Uri, &Library)> {
+        self.library
+            .get_or_init(|| {
+                let library = Library::new();
+
+                let directory = tempfile::Builder::new()
+                    .prefix("hashql-lsp-")
+                    .tempdir()
+                    .ok()?;
+                let path = directory.path().join("std.txt");
+
+                fs::write(&path, &library.text).ok()?;
+
+                Some((directory, file_uri(&path)?, library))
+            })
+            .as_ref()
+            .map(|(_, uri, library)| (uri, library))
+    }
+
+    fn definition(&self, params: &GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let (document, offset) = self.locate(&params.text_document_position_params)?;
+
+        let location = match document.analysis.definition(offset)? {
+            &Definition::Binding(range) => {
+                Location::new(document.uri.clone(), document.range(range))
+            }
+            Definition::Item(path) => {
+                let (uri, library) = self.library()?;
+
+                Location::new(uri.clone(), library.range(path)?)
+            }
+        };
+
+        Some(GotoDefinitionResponse::Scalar(location))
+    }
+
+    fn completion(&self, params: &CompletionParams) -> Option<CompletionResponse> {
+        let (document, offset) = self.locate(&params.text_document_position)?;
+        let (range, completions) = complete(&document.analysis, &document.text, offset)?;
+
+        let range = document.range(range);
+
+        Some(CompletionResponse::Array(
+            completions
+                .into_iter()
+                .map(|completion| CompletionItem {
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                        range,
+                        completion.label.clone(),
+                    ))),
+                    label: completion.label,
+                    kind: Some(completion.kind),
+                    detail: completion.detail,
+                    ..CompletionItem::default()
+                })
+                .collect(),
+        ))
+    }
+}
+
+fn parameters<N>(notification: Notification) -> Option<N::Params>
+where
+    N: NotificationKind,
+{
+    serde_json::from_value(notification.params).ok()
+}
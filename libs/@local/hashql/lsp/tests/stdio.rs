@@ -0,0 +1,400 @@
+//! Drives the `hashql-lsp` binary over stdio, the same way an editor would.
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+use serde_json::{Value, json};
+
+const URI: &str = "file:///main.jsonc";
+
+struct Client {
+    server: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    /// Notifications received while waiting for a response.
+    notifications: VecDeque<Value>,
+    next_id: u64,
+}
+
+impl Client {
+    /// Spawns the server and performs the initialization handshake.
+    fn initialize() -> Self {
+        let mut server = Command::new(env!("CARGO_BIN_EXE_hashql-lsp"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("should be able to spawn the language server");
+
+        let stdin = server.stdin.take().expect("stdin should be piped");
+        let stdout = BufReader::new(server.stdout.take().expect("stdout should be piped"));
+
+        let mut client = Self {
+            server,
+            stdin,
+            stdout,
+            notifications: VecDeque::new(),
+            next_id: 0,
+        };
+
+        let result = client.request("initialize", &json!({ "capabilities": {} }));
+        assert_eq!(result["capabilities"]["hoverProvider"], json!(true));
+        client.notify("initialized", &json!({}));
+
+        client
+    }
+
+    fn send(&mut self, message: &Value) {
+        let body = message.to_string();
+
+        write!(self.stdin, "Content-Length: {}\r\n\r\n{body}", body.len())
+            .expect("should be able to write to the server");
+        self.stdin
+            .flush()
+            .expect("should be able to write to the server");
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut length = None;
+
+        loop {
+            let mut header = String::new();
+            self.stdout
+                .read_line(&mut header)
+                .expect("should be able to read from the server");
+
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some(value) = header.strip_prefix("Content-Length: ") {
+                length = Some(value.parse().expect("content length should be a number"));
+            }
+        }
+
+        let mut body = vec![0; length.expect("message should have a content length")];
+        self.stdout
+            .read_exact(&mut body)
+            .expect("should be able to read from the server");
+
+        serde_json::from_slice(&body).expect("message should be valid JSON")
+    }
+
+    fn request(&mut self, method: &str, params: &Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.send(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+
+        loop {
+            let message = self.receive();
+
+            if message["id"] == json!(id) {
+                assert!(message["error"].is_null(), "request failed: {message}");
+                return message["result"].clone();
+            }
+
+            self.notifications.push_back(message);
+        }
+    }
+
+    fn notify(&mut self, method: &str, params: &Value) {
+        self.send(&json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    fn notification(&mut self, method: &str) -> Value {
+        if let Some(index) = self
+            .notifications
+            .iter()
+            .position(|notification| notification["method"] == method)
+        {
+            return self
+                .notifications
+                .remove(index)
+                .expect("index should be in bounds")["params"]
+                .clone();
+        }
+
+        loop {
+            let message = self.receive();
+
+            if message["method"] == method {
+                return message["params"].clone();
+            }
+
+            self.notifications.push_back(message);
+        }
+    }
+
+    /// Opens the document and returns the diagnostics published for it.
+    fn open(&mut self, text: &str) -> Vec<Value> {
+        self.notify(
+            "textDocument/didOpen",
+            &json!({
+                "textDocument": { "uri": URI, "languageId": "hashql", "version": 1, "text": text }
+            }),
+        );
+
+        let params = self.notification("textDocument/publishDiagnostics");
+        assert_eq!(params["uri"], URI);
+
+        params["diagnostics"]
+            .as_array()
+            .expect("diagnostics should be an array")
+            .clone()
+    }
+
+    fn position_request(&mut self, method: &str, position: &Value) -> Value {
+        self.request(
+            method,
+            &json!({ "textDocument": { "uri": URI }, "position": position }),
+        )
+    }
+
+    fn shutdown(mut self) {
+        self.request("shutdown", &Value::Null);
+        self.notify("exit", &Value::Null);
+
+        let status = self
+            .server
+            .wait()
+            .expect("should be able to wait for the server");
+        assert!(status.success(), "server exited with {status}");
+    }
+}
+
+/// Returns the position of the `nth` occurrence of `needle` in `text`, offset by `offset` bytes.
+fn position(text: &str, needle: &str, nth: usize, offset: usize) -> Value {
+    let (index, _) = text
+        .match_indices(needle)
+        .nth(nth)
+        .unwrap_or_else(|| panic!("`{needle}` should occur {} times", nth + 1));
+
+    let before = text
+        .get(..index + offset)
+        .expect("offset should be at a character boundary");
+    let line = before.matches('\n').count();
+    let character = before.rsplit('\n').next().map_or(0, str::len);
+
+    json!({ "line": line, "character": character })
+}
+
+const PROGRAM: &str = r##"// adds two integers
+["let", "add",
+  ["fn", { "#tuple": [] }, { "#struct": { "lhs": "Integer", "rhs": "Integer" } }, "Integer",
+    ["+", "lhs", "rhs"]
+  ],
+  ["add", { "#literal": 1 }, { "#literal": 2 }]
+]
+"##;
+
+#[test]
+fn diagnostics() {
+    let mut client = Client::initialize();
+
+    assert_eq!(client.open(PROGRAM), Vec::<Value>::new());
+
+    // Changes replace the whole document
+    client.notify(
+        "textDocument/didChange",
+        &json!({
+            "textDocument": { "uri": URI, "version": 2 },
+            "contentChanges": [{ "text": "[\"let\", \"x\", { \"#literal\": 1 },, \"y\"]" }]
+        }),
+    );
+
+    let params = client.notification("textDocument/publishDiagnostics");
+    assert_eq!(params["version"], 2);
+
+    let diagnostics = params["diagnostics"]
+        .as_array()
+        .expect("diagnostics should be an array");
+
+    // Both the parser and the lowering passes report diagnostics for the same document
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+        diagnostics[0]["range"]["start"],
+        json!({ "line": 0, "character": 31 })
+    );
+    assert_eq!(
+        diagnostics[1]["range"]["start"],
+        json!({ "line": 0, "character": 34 })
+    );
+
+    for diagnostic in diagnostics {
+        assert_eq!(diagnostic["severity"], 1);
+        assert_eq!(diagnostic["source"], "hashql");
+        assert!(diagnostic["code"].is_string());
+    }
+
+    client.notify(
+        "textDocument/didClose",
+        &json!({ "textDocument": { "uri": URI } }),
+    );
+    let params = client.notification("textDocument/publishDiagnostics");
+    assert_eq!(params["diagnostics"], json!([]));
+
+    client.shutdown();
+}
+
+#[test]
+fn hover() {
+    let mut client = Client::initialize();
+    client.open(PROGRAM);
+
+    let hover = client.position_request("textDocument/hover", &position(PROGRAM, "\"add\"", 1, 2));
+    let contents = hover["contents"]["value"]
+        .as_str()
+        .expect("hover should be markdown");
+    assert!(contents.contains("add = fn(Integer, Integer) -> Integer"));
+    assert_eq!(hover["range"]["start"], position(PROGRAM, "\"add\"", 1, 1));
+
+    let hover = client.position_request("textDocument/hover", &position(PROGRAM, "\"lhs\"", 1, 2));
+    assert!(
+        hover["contents"]["value"]
+            .as_str()
+            .expect("hover should be markdown")
+            .contains("lhs: Integer")
+    );
+
+    let hover = client.position_request("textDocument/hover", &position(PROGRAM, "\"+\"", 0, 1));
+    assert!(
+        hover["contents"]["value"]
+            .as_str()
+            .expect("hover should be markdown")
+            .contains("::math::add")
+    );
+
+    // Comments have nothing to show
+    let hover = client.position_request("textDocument/hover", &position(PROGRAM, "adds", 0, 0));
+    assert_eq!(hover, Value::Null);
+
+    client.shutdown();
+}
+
+#[test]
+fn definition() {
+    let mut client = Client::initialize();
+    client.open(PROGRAM);
+
+    let location = client.position_request(
+        "textDocument/definition",
+        &position(PROGRAM, "\"rhs\"", 1, 2),
+    );
+    assert_eq!(location["uri"], URI);
+    assert_eq!(
+        location["range"]["start"],
+        position(PROGRAM, "\"rhs\"", 0, 1)
+    );
+
+    let location = client.position_request(
+        "textDocument/definition",
+        &position(PROGRAM, "\"add\"", 1, 2),
+    );
+    assert_eq!(
+        location["range"]["start"],
+        position(PROGRAM, "\"add\"", 0, 1)
+    );
+
+    // Items of the standard library are defined in a listing written by the server
+    let location =
+        client.position_request("textDocument/definition", &position(PROGRAM, "\"+\"", 0, 1));
+    assert_eq!(library_text(&location), "::math::add");
+
+    let location = client.position_request(
+        "textDocument/definition",
+        &position(PROGRAM, "\"Integer\"", 0, 2),
+    );
+    assert!(library_text(&location).ends_with("::Integer"));
+
+    client.shutdown();
+}
+
+/// Returns the text at the location in the listing of the standard library.
+fn library_text(location: &Value) -> String {
+    let path = location["uri"]
+        .as_str()
+        .and_then(|uri| uri.strip_prefix("file://"))
+        .expect("location should refer to a file");
+    assert!(path.ends_with("/std.txt"), "unexpected listing at `{path}`");
+
+    let listing = std::fs::read_to_string(path).expect("listing should be readable");
+
+    let range = &location["range"];
+    let line = range["start"]["line"]
+        .as_u64()
+        .expect("line should be a number");
+    assert_eq!(range["end"]["line"], line);
+
+    let line = listing
+        .lines()
+        .nth(usize::try_from(line).expect("line should fit into usize"))
+        .expect("line should be part of the listing");
+
+    let [start, end] = [&range["start"], &range["end"]].map(|position| {
+        usize::try_from(
+            position["character"]
+                .as_u64()
+                .expect("character should be a number"),
+        )
+        .expect("character should fit into usize")
+    });
+
+    line.get(start..end)
+        .expect("range should be within the line")
+        .to_owned()
+}
+
+fn labels(completions: &Value) -> Vec<&str> {
+    completions
+        .as_array()
+        .expect("completions should be an array")
+        .iter()
+        .map(|completion| {
+            completion["label"]
+                .as_str()
+                .expect("label should be a string")
+        })
+        .collect()
+}
+
+#[test]
+fn completion() {
+    let mut client = Client::initialize();
+
+    let text = r##"["let", "value", { "#literal": 1 }, ["::math::", "val"]]"##;
+    client.open(text);
+
+    let completions =
+        client.position_request("textDocument/completion", &position(text, "::\"", 0, 2));
+    let labels_in_module = labels(&completions);
+    assert!(labels_in_module.contains(&"add"));
+    assert!(labels_in_module.contains(&"sqrt"));
+    assert!(!labels_in_module.contains(&"value"));
+
+    let add = completions
+        .as_array()
+        .expect("completions should be an array")
+        .iter()
+        .find(|completion| completion["label"] == "add")
+        .expect("`add` should be completed");
+    assert_eq!(add["detail"], "::math::add");
+    assert_eq!(
+        add["textEdit"]["range"]["start"],
+        position(text, "::\"", 0, 2)
+    );
+
+    let completions =
+        client.position_request("textDocument/completion", &position(text, "\"val\"", 0, 4));
+    let labels_in_scope = labels(&completions);
+    assert!(labels_in_scope.contains(&"value"));
+    assert!(labels_in_scope.contains(&"let"));
+    assert!(labels_in_scope.contains(&"math"));
+
+    client.shutdown();
+}
@@ -68,14 +68,45 @@ impl<'heap> Parser<'heap> {
 
         Ok(expr)
     }
+
+    /// Parse an expression from a byte slice, recovering from errors.
+    ///
+    /// Unlike [`Self::parse_expr`], parsing does not stop at the first error. Every expression
+    /// that cannot be parsed is replaced by an [`ExprKind::Error`] node, and parsing continues
+    /// after it. Returns the partial expression alongside every diagnostic that has been
+    /// encountered, the expression is only free of error nodes if no diagnostics are returned.
+    ///
+    /// [`ExprKind::Error`]: hashql_ast::node::expr::ExprKind::Error
+    #[must_use]
+    pub fn parse_expr_recovering(&self, source: &[u8]) -> (Expr<'heap>, Vec<JExprDiagnostic>) {
+        let lexer = lexer::Lexer::new(source, Arc::clone(&self.spans));
+
+        let mut state = ParserState::new(self.heap, lexer, Arc::clone(&self.spans)).with_recovery();
+
+        let expr = parser::expr::parse_expr(&mut state)
+            .unwrap_or_else(|_error| unreachable!("errors are recorded while recovering"));
+
+        let diagnostics = state
+            .finish_recovering()
+            .into_iter()
+            .map(|diagnostic| diagnostic.map_category(JExprDiagnosticCategory::Parser))
+            .collect();
+
+        (expr, diagnostics)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use alloc::sync::Arc;
 
-    use hashql_ast::format::SyntaxDump as _;
-    use hashql_core::{heap::Heap, span::storage::SpanStorage};
+    use hashql_ast::{format::SyntaxDump as _, lowering::lower, node::expr::ExprKind};
+    use hashql_core::{
+        heap::Heap,
+        module::ModuleRegistry,
+        span::{SpanId, storage::SpanStorage},
+        r#type::environment::Environment,
+    };
     use insta::{assert_snapshot, with_settings};
 
     use crate::{Parser, test::render_diagnostic};
@@ -151,6 +182,25 @@ mod tests {
         });
     }
 
+    /// Parse an input string while recovering from errors, and format the partial syntax tree
+    /// alongside every diagnostic
+    fn parse_input_recovering(input: &'static str) -> String {
+        let heap = Heap::new();
+        let spans = Arc::new(SpanStorage::new());
+        let parser = Parser::new(&heap, Arc::clone(&spans));
+
+        let (expr, diagnostics) = parser.parse_expr_recovering(input.as_bytes());
+
+        let mut output = expr.syntax_dump_to_string();
+
+        for diagnostic in diagnostics {
+            output.push('\n');
+            output.push_str(&render_diagnostic(input, diagnostic, &spans));
+        }
+
+        output
+    }
+
     #[test]
     fn parse_incomplete_expression() {
         // Another error case focusing on unexpected EOF
@@ -162,4 +212,119 @@ mod tests {
             assert_snapshot!(insta::_macro_support::AutoName, error);
         });
     }
+
+    #[test]
+    fn recover_valid_expression() {
+        // Recovery must not change the result of a valid expression
+        let output = parse_input_recovering(r##"["add", {"#literal": 1}, {"#literal": 2}]"##);
+
+        with_settings!({
+            description => "Recovering parser returns the same tree for valid input"
+        }, {
+            assert_snapshot!(insta::_macro_support::AutoName, output);
+        });
+    }
+
+    #[test]
+    fn recover_multiple_errors() {
+        // Every invalid argument is replaced by an error node
+        let output = parse_input_recovering(
+            r##"["add", {"#literal": 1, "extra": 2}, "1invalid", ["sub", {"#literal": 2}]]"##,
+        );
+
+        with_settings!({
+            description => "Recovering parser reports every invalid argument"
+        }, {
+            assert_snapshot!(insta::_macro_support::AutoName, output);
+        });
+    }
+
+    #[test]
+    fn recover_separators() {
+        // Missing, trailing and consecutive commas do not discard the surrounding expression
+        let output = parse_input_recovering(
+            r##"["add", {"#literal": 1} {"#literal": 2},, {"#struct": {"a": "b",}},]"##,
+        );
+
+        with_settings!({
+            description => "Recovering parser recovers from invalid separators"
+        }, {
+            assert_snapshot!(insta::_macro_support::AutoName, output);
+        });
+    }
+
+    #[test]
+    fn recover_nested_error() {
+        // An error in a nested array only discards the nested array
+        let output = parse_input_recovering(r#"["let", "x", ["add", "y" : "z"], "x"]"#);
+
+        with_settings!({
+            description => "Recovering parser skips to the end of the invalid nested array"
+        }, {
+            assert_snapshot!(insta::_macro_support::AutoName, output);
+        });
+    }
+
+    #[test]
+    fn recover_unexpected_eof() {
+        // The end of input is only reported once, instead of once per unclosed array
+        let output = parse_input_recovering(r#"["add", ["sub", "x""#);
+
+        with_settings!({
+            description => "Recovering parser reports the end of input once"
+        }, {
+            assert_snapshot!(insta::_macro_support::AutoName, output);
+        });
+    }
+
+    #[test]
+    fn recover_trailing_content() {
+        // Content after the expression is reported, but does not discard the expression
+        let output = parse_input_recovering(r#"["add", "x", "y"] "z""#);
+
+        with_settings!({
+            description => "Recovering parser reports content after the expression"
+        }, {
+            assert_snapshot!(insta::_macro_support::AutoName, output);
+        });
+    }
+
+    #[test]
+    fn lower_recovered_expression() {
+        // Lowering skips error nodes without reporting them a second time
+        let heap = Heap::new();
+        let spans = Arc::new(SpanStorage::new());
+        let parser = Parser::new(&heap, Arc::clone(&spans));
+
+        let (mut expr, diagnostics) = parser
+            .parse_expr_recovering(br#"["+", ["let", "x", ["+", "y" : "z"], "x"], "1invalid"]"#);
+        assert_eq!(diagnostics.len(), 2);
+
+        let environment = Environment::new(SpanId::SYNTHETIC, &heap);
+        let registry = ModuleRegistry::new(&environment);
+
+        let (_, lower_diagnostics) = lower(
+            heap.intern_symbol("::main"),
+            &mut expr,
+            &environment,
+            &registry,
+        );
+        assert!(lower_diagnostics.is_empty(), "{lower_diagnostics:?}");
+
+        let ExprKind::Call(call) = &expr.kind else {
+            panic!(
+                "expected the outer call to be preserved, got {:?}",
+                expr.kind
+            );
+        };
+
+        // The `let` special form is replaced as a whole, as one of its arguments is an error
+        assert!(
+            call.arguments
+                .iter()
+                .all(|argument| matches!(argument.value.kind, ExprKind::Error)),
+            "{:?}",
+            call.arguments
+        );
+    }
 }
@@ -14,6 +14,7 @@ use crate::{
             ArrayDiagnosticCategory, consecutive_commas, leading_commas, trailing_commas,
         },
         complex::{VerifyState, verify_no_repeat},
+        expr::PARSE_EXPR_KINDS,
     },
     span::Span,
 };
@@ -49,10 +50,19 @@ where
     loop {
         let next = state
             .peek_expect(SyntaxKindSet::COMPLETE)
-            .change_category(ArrayDiagnosticCategory::Lexer)
-            .change_category(C::from)?;
-
-        let (next_kind, next_span) = (next.kind.syntax(), next.span);
+            .map(|next| (next.kind.syntax(), next.span))
+            .change_category(ArrayDiagnosticCategory::Lexer);
+
+        let (next_kind, next_span) = match next {
+            Ok(next) => next,
+            Err(diagnostic) if state.is_exhausted() => {
+                // when recovering, the array is closed at the end of input, so that the parsed
+                // elements are retained
+                state.recover(diagnostic).change_category(C::from)?;
+                break;
+            }
+            Err(diagnostic) => return Err(diagnostic.map_category(C::from)),
+        };
 
         if next_kind == SyntaxKind::RBracket {
             state
@@ -65,14 +75,19 @@ where
         }
 
         if index == 0 {
-            verify_no_repeat(
+            if let Err(diagnostic) = verify_no_repeat(
                 state,
                 SyntaxKindSet::from_slice(&[SyntaxKind::Comma]),
                 SyntaxKindSet::EMPTY,
                 |_, spans, _| leading_commas(&spans),
-            )
-            .change_category(C::from)?;
-        } else if index != 0 {
+            ) {
+                state.recover(diagnostic).change_category(C::from)?;
+
+                // the leading commas have been skipped, the next token is either the first
+                // element or closes the array
+                continue;
+            }
+        } else {
             // we need to check if the next token is a comma
             // in case it isn't we error out
             if next_kind == SyntaxKind::Comma {
@@ -82,20 +97,30 @@ where
                     .change_category(ArrayDiagnosticCategory::Lexer)
                     .change_category(C::from)?;
 
-                verify_no_repeat(
+                let mut trailing = false;
+
+                if let Err(diagnostic) = verify_no_repeat(
                     state,
                     SyntaxKindSet::from_slice(&[SyntaxKind::Comma]),
                     SyntaxKindSet::from_slice(&[SyntaxKind::RBracket]),
                     |state, mut spans, verify| match verify {
                         VerifyState::Trailing => {
+                            trailing = true;
+
                             // if trailing comma is found, then the first comma is also affected
                             spans.insert(0, state.insert_range(next_span));
                             trailing_commas(&spans)
                         }
                         VerifyState::Consecutive => consecutive_commas(&spans),
                     },
-                )
-                .change_category(C::from)?;
+                ) {
+                    state.recover(diagnostic).change_category(C::from)?;
+
+                    if trailing {
+                        // the trailing commas have been skipped, the next token closes the array
+                        continue;
+                    }
+                }
             } else {
                 let span = state.insert_span(Span {
                     range: next_span,
@@ -103,10 +128,17 @@ where
                     parent_id: None,
                 });
 
-                // do not consume the token, so that we can do recoverable parsing (in the future)
-                return Err(unexpected_token(span, next_kind, EXPECTED_ARRAY_SEP)
-                    .map_category(ArrayDiagnosticCategory::Lexer)
-                    .map_category(C::from));
+                // do not consume the token, so that we can recover from the missing separator
+                let diagnostic = unexpected_token(span, next_kind, EXPECTED_ARRAY_SEP)
+                    .map_category(ArrayDiagnosticCategory::Lexer);
+
+                // we only recover if the token starts the next element, otherwise the rest of
+                // the array is skipped by the enclosing expression
+                if !PARSE_EXPR_KINDS.contains(next_kind) {
+                    return Err(diagnostic.map_category(C::from));
+                }
+
+                state.recover(diagnostic).change_category(C::from)?;
             }
         }
 
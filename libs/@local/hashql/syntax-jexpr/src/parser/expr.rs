@@ -1,4 +1,8 @@
-use hashql_ast::node::expr::Expr;
+use hashql_ast::node::{
+    expr::{Expr, ExprKind},
+    id::NodeId,
+};
+use text_size::TextRange;
 
 use super::{
     array::parse_array,
@@ -12,13 +16,13 @@ use crate::{
     lexer::{syntax_kind::SyntaxKind, syntax_kind_set::SyntaxKindSet},
 };
 
-const PARSE_EXPR_KINDS: SyntaxKindSet = SyntaxKindSet::from_slice(&[
+pub(crate) const PARSE_EXPR_KINDS: SyntaxKindSet = SyntaxKindSet::from_slice(&[
     SyntaxKind::String, //
     SyntaxKind::LBracket,
     SyntaxKind::LBrace,
 ]);
 
-pub(crate) fn parse_expr<'heap>(
+fn parse_expr_kind<'heap>(
     state: &mut ParserState<'heap, '_>,
 ) -> Result<Expr<'heap>, ParserDiagnostic> {
     let token = state
@@ -36,3 +40,40 @@ pub(crate) fn parse_expr<'heap>(
         }
     }
 }
+
+/// Parses an expression.
+///
+/// If the parser recovers from errors, an expression that cannot be parsed is replaced by an
+/// [`ExprKind::Error`] node, which spans every token up to the next `,`, `]` or `}` of the
+/// enclosing array or object.
+pub(crate) fn parse_expr<'heap>(
+    state: &mut ParserState<'heap, '_>,
+) -> Result<Expr<'heap>, ParserDiagnostic> {
+    let depth = state.depth();
+
+    // Peek before advancing, so that a token that cannot start an expression isn't consumed, and
+    // the enclosing array or object can continue from it.
+    let (start, result) = match state.peek_expect(PARSE_EXPR_KINDS) {
+        Ok(token) => (token.span.start(), parse_expr_kind(state)),
+        Err(diagnostic) => (
+            state.position(),
+            Err(diagnostic.map_category(ParserDiagnosticCategory::Lexer)),
+        ),
+    };
+
+    let diagnostic = match result {
+        Ok(expr) => return Ok(expr),
+        Err(diagnostic) => diagnostic,
+    };
+
+    state.recover(diagnostic)?;
+    state.synchronize(depth);
+
+    let span = state.insert_range(TextRange::new(start, state.position().max(start)));
+
+    Ok(Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Error,
+    })
+}
@@ -56,13 +56,15 @@ where
         .change_category(ObjectDiagnosticCategory::Lexer)
         .change_category(C::from)?;
 
-    verify_no_repeat(
+    if let Err(diagnostic) = verify_no_repeat(
         state,
         SyntaxKindSet::from_slice(&[SyntaxKind::Colon]),
         SyntaxKindSet::EMPTY,
         |_, spans, _| consecutive_colons(&spans),
-    )
-    .change_category(C::from)?;
+    ) {
+        // the consecutive colons have been skipped, the next token is the value
+        state.recover(diagnostic).change_category(C::from)?;
+    }
 
     let key = Key {
         value: key,
@@ -101,10 +103,19 @@ where
     loop {
         let next = state
             .peek_expect(SyntaxKindSet::COMPLETE)
-            .change_category(ObjectDiagnosticCategory::Lexer)
-            .change_category(C::from)?;
-
-        let (next_kind, next_span) = (next.kind.syntax(), next.span);
+            .map(|next| (next.kind.syntax(), next.span))
+            .change_category(ObjectDiagnosticCategory::Lexer);
+
+        let (next_kind, next_span) = match next {
+            Ok(next) => next,
+            Err(diagnostic) if state.is_exhausted() => {
+                // when recovering, the object is closed at the end of input, so that the parsed
+                // entries are retained
+                state.recover(diagnostic).change_category(C::from)?;
+                break;
+            }
+            Err(diagnostic) => return Err(diagnostic.map_category(C::from)),
+        };
 
         if next_kind == SyntaxKind::RBrace {
             state
@@ -117,36 +128,52 @@ where
         }
 
         if index == 0 {
-            verify_no_repeat(
+            if let Err(diagnostic) = verify_no_repeat(
                 state,
                 SyntaxKindSet::from_slice(&[SyntaxKind::Comma]),
                 SyntaxKindSet::EMPTY,
                 |_, spans, _| leading_commas(&spans),
-            )
-            .change_category(C::from)?;
+            ) {
+                state.recover(diagnostic).change_category(C::from)?;
+
+                // the leading commas have been skipped, the next token is either the first
+                // entry or closes the object
+                continue;
+            }
         } else {
             // we need to check if the next token is a comma
             // in case it isn't we error out
             if next_kind == SyntaxKind::Comma {
+                // advance the cursor to the next token and continue as it nothing has happened
                 state
                     .advance(SyntaxKindSet::COMPLETE)
                     .change_category(ObjectDiagnosticCategory::Lexer)
                     .change_category(C::from)?;
 
-                verify_no_repeat(
+                let mut trailing = false;
+
+                if let Err(diagnostic) = verify_no_repeat(
                     state,
                     SyntaxKindSet::from_slice(&[SyntaxKind::Comma]),
                     SyntaxKindSet::from_slice(&[SyntaxKind::RBrace]),
                     |state, mut spans, verify| match verify {
                         VerifyState::Trailing => {
+                            trailing = true;
+
                             // if trailing comma is found, then the first comma is also affected
                             spans.insert(0, state.insert_range(next_span));
                             trailing_commas(&spans)
                         }
                         VerifyState::Consecutive => consecutive_commas(&spans),
                     },
-                )
-                .change_category(C::from)?;
+                ) {
+                    state.recover(diagnostic).change_category(C::from)?;
+
+                    if trailing {
+                        // the trailing commas have been skipped, the next token closes the object
+                        continue;
+                    }
+                }
             } else {
                 let span = state.insert_span(Span {
                     range: next_span,
@@ -154,10 +181,17 @@ where
                     parent_id: None,
                 });
 
-                // do not consume the token, so that we can do recoverable parsing (in the future)
-                return Err(unexpected_token(span, next_kind, EXPECTED_OBJECT_SEP)
-                    .map_category(ObjectDiagnosticCategory::Lexer)
-                    .map_category(C::from));
+                // do not consume the token, so that we can recover from the missing separator
+                let diagnostic = unexpected_token(span, next_kind, EXPECTED_OBJECT_SEP)
+                    .map_category(ObjectDiagnosticCategory::Lexer);
+
+                // we only recover if the token starts the next entry, otherwise the rest of
+                // the object is skipped by the enclosing expression
+                if next_kind != SyntaxKind::String {
+                    return Err(diagnostic.map_category(C::from));
+                }
+
+                state.recover(diagnostic).change_category(C::from)?;
             }
         }
 
@@ -6,7 +6,8 @@ use hashql_core::{
     span::{SpanId, storage::SpanStorage},
     symbol::Symbol,
 };
-use text_size::TextRange;
+use hashql_diagnostics::Diagnostic;
+use text_size::{TextRange, TextSize};
 
 use super::error::{ParserDiagnostic, ParserDiagnosticCategory, expected_eof};
 use crate::{
@@ -27,9 +28,20 @@ enum LookaheadStatus {
     BufferFilled,
 }
 
+/// Tokens at which the parser synchronizes after an error, these are the tokens that continue or
+/// close the enclosing array or object.
+const SYNCHRONIZE: SyntaxKindSet =
+    SyntaxKindSet::from_slice(&[SyntaxKind::Comma, SyntaxKind::RBracket, SyntaxKind::RBrace]);
+
 struct LookaheadLexer<'source> {
     buffer: CircularBuffer<4, Token<'source>>,
     lexer: Lexer<'source>,
+
+    // Number of unclosed brackets and braces consumed
+    depth: usize,
+    // End of the last consumed token
+    position: TextSize,
+    exhausted: bool,
 }
 
 impl<'source> LookaheadLexer<'source> {
@@ -44,7 +56,10 @@ impl<'source> LookaheadLexer<'source> {
                     self.buffer.push_back(token);
                 }
                 Some(Err(error)) => return Err(error),
-                None => return Ok(LookaheadStatus::EndOfInput),
+                None => {
+                    self.exhausted = true;
+                    return Ok(LookaheadStatus::EndOfInput);
+                }
             }
         }
 
@@ -72,11 +87,31 @@ impl<'source> LookaheadLexer<'source> {
     }
 
     fn advance(&mut self) -> Option<Result<Token<'source>, LexerDiagnostic>> {
-        if let Some(token) = self.buffer.pop_front() {
-            return Some(Ok(token));
+        let token = match self.buffer.pop_front() {
+            Some(token) => token,
+            None => match self.lexer.advance() {
+                Some(Ok(token)) => token,
+                Some(Err(error)) => return Some(Err(error)),
+                None => {
+                    self.exhausted = true;
+                    return None;
+                }
+            },
+        };
+
+        match token.kind.syntax() {
+            SyntaxKind::LBracket | SyntaxKind::LBrace => self.depth += 1,
+            SyntaxKind::RBracket | SyntaxKind::RBrace => self.depth = self.depth.saturating_sub(1),
+            _ => {}
         }
 
-        self.lexer.advance()
+        self.position = token.span.end();
+
+        Some(Ok(token))
+    }
+
+    const fn is_exhausted(&self) -> bool {
+        self.exhausted && self.buffer.is_empty()
     }
 
     fn span(&self) -> TextRange {
@@ -172,11 +207,19 @@ impl From<SyntaxKind> for Expected {
     }
 }
 
+/// Diagnostics recorded while recovering from errors.
+struct Recovery {
+    diagnostics: Vec<ParserDiagnostic>,
+    // Whether a diagnostic has been recorded once the input has been exhausted
+    exhausted: bool,
+}
+
 pub(crate) struct ParserState<'heap, 'source> {
     heap: &'heap Heap,
     lexer: LookaheadLexer<'source>,
 
     context: ParserContext,
+    recovery: Option<Recovery>,
 }
 
 impl<'heap, 'source> ParserState<'heap, 'source> {
@@ -190,14 +233,31 @@ impl<'heap, 'source> ParserState<'heap, 'source> {
             lexer: LookaheadLexer {
                 buffer: CircularBuffer::new(),
                 lexer,
+                depth: 0,
+                position: TextSize::new(0),
+                exhausted: false,
             },
             context: ParserContext {
                 spans,
                 stack: Vec::new(),
             },
+            recovery: None,
         }
     }
 
+    /// Enables recovery from errors.
+    ///
+    /// Once enabled, [`Self::recover`] records diagnostics instead of returning them, which are
+    /// returned by [`Self::finish_recovering`].
+    pub(crate) fn with_recovery(mut self) -> Self {
+        self.recovery = Some(Recovery {
+            diagnostics: Vec::new(),
+            exhausted: false,
+        });
+
+        self
+    }
+
     pub(crate) fn intern_symbol(&self, value: impl AsRef<str>) -> Symbol<'heap> {
         self.heap.intern_symbol(value.as_ref())
     }
@@ -278,7 +338,85 @@ impl<'heap, 'source> ParserState<'heap, 'source> {
         result
     }
 
-    pub(crate) fn finish(mut self) -> Result<(), ParserDiagnostic> {
+    /// Records a diagnostic if recovering from errors.
+    ///
+    /// Once the input has been exhausted, only the first diagnostic is recorded, as every
+    /// following diagnostic is a consequence of the missing input.
+    ///
+    /// # Errors
+    ///
+    /// Returns the diagnostic unchanged if recovery is not enabled.
+    pub(crate) fn recover<C>(
+        &mut self,
+        diagnostic: Diagnostic<C, SpanId>,
+    ) -> Result<(), Diagnostic<C, SpanId>>
+    where
+        C: Into<ParserDiagnosticCategory>,
+    {
+        if self.recovery.is_none() {
+            return Err(diagnostic);
+        }
+
+        self.record(diagnostic.map_category(Into::into));
+        Ok(())
+    }
+
+    fn record(&mut self, diagnostic: ParserDiagnostic) {
+        let exhausted = self.lexer.is_exhausted();
+
+        let Some(recovery) = &mut self.recovery else {
+            return;
+        };
+
+        if exhausted {
+            if recovery.exhausted {
+                return;
+            }
+
+            recovery.exhausted = true;
+        }
+
+        recovery.diagnostics.push(diagnostic);
+    }
+
+    /// Skips tokens until the next `,`, `]` or `}` at the given nesting depth, or the end of input.
+    ///
+    /// The token at which the parser synchronizes is not consumed, so that the enclosing array or
+    /// object can continue from it. Lexer errors encountered while skipping are recorded.
+    pub(crate) fn synchronize(&mut self, depth: usize) {
+        loop {
+            if self.lexer.depth < depth {
+                break;
+            }
+
+            if self.lexer.depth == depth {
+                let next = self
+                    .lexer
+                    .peek_n(0)
+                    .map(|token| token.map(|token| token.kind.syntax()));
+
+                match next {
+                    Ok(None) => break,
+                    Ok(Some(kind)) if SYNCHRONIZE.contains(kind) => break,
+                    Ok(Some(_)) => {}
+                    Err(diagnostic) => {
+                        self.record(diagnostic.map_category(ParserDiagnosticCategory::Lexer));
+                        continue;
+                    }
+                }
+            }
+
+            match self.lexer.advance() {
+                None => break,
+                Some(Ok(_)) => {}
+                Some(Err(diagnostic)) => {
+                    self.record(diagnostic.map_category(ParserDiagnosticCategory::Lexer));
+                }
+            }
+        }
+    }
+
+    fn expect_eof(&mut self) -> Result<(), ParserDiagnostic> {
         if let Some(token) = self.lexer.advance() {
             let token = token.change_category(ParserDiagnosticCategory::Lexer)?;
 
@@ -290,6 +428,36 @@ impl<'heap, 'source> ParserState<'heap, 'source> {
         Ok(())
     }
 
+    pub(crate) fn finish(mut self) -> Result<(), ParserDiagnostic> {
+        self.expect_eof()
+    }
+
+    /// Finishes parsing while recovering from errors, returning every recorded diagnostic.
+    pub(crate) fn finish_recovering(mut self) -> Vec<ParserDiagnostic> {
+        if let Err(diagnostic) = self.expect_eof() {
+            self.record(diagnostic);
+        }
+
+        self.recovery
+            .map(|recovery| recovery.diagnostics)
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if every token has been consumed.
+    pub(crate) const fn is_exhausted(&self) -> bool {
+        self.lexer.is_exhausted()
+    }
+
+    /// Returns the number of unclosed arrays and objects.
+    pub(crate) const fn depth(&self) -> usize {
+        self.lexer.depth
+    }
+
+    /// Returns the end of the last consumed token.
+    pub(crate) const fn position(&self) -> TextSize {
+        self.lexer.position
+    }
+
     pub(crate) fn current_pointer(&self) -> jsonptr::PointerBuf {
         self.context.current_pointer()
     }
@@ -68,6 +68,17 @@ pub fn assert_tokens_with_context_error<'de, T>(
     let mut de = Deserializer::new(tokens, context);
     let received = T::deserialize(&mut de).expect_err("value of type T should fail serialization");
 
+    assert_error(error, received);
+}
+
+/// Asserts that the errors of a report match the expected errors.
+///
+/// This is used to check the errors of deserializers other than the one of this crate.
+///
+/// # Panics
+///
+/// if error could not be serialized
+pub fn assert_error(error: &ErrorVec, received: Report<DeserializeError>) {
     let received = received.export();
     let received = to_value(received).expect("error should serialize");
     let errors = ErrorVec::from_value(&received).expect("well-formed error object");
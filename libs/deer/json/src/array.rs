@@ -2,14 +2,10 @@ use deer::{
     Context, Deserialize, Deserializer as _,
     error::{ArrayAccessError, ArrayLengthError, DeserializerError, Error, Variant as _},
 };
-use error_stack::{Report, ReportSink, ResultExt as _};
+use error_stack::{Report, ReportSink, ResultExt as _, TryReportTupleExt as _};
 use justjson::parser::{PeekableTokenKind, Token};
 
-use crate::{
-    deserializer::Deserializer,
-    error::{Position, SyntaxError},
-    skip::skip_tokens,
-};
+use crate::{deserializer::Deserializer, error::SyntaxError, skip::skip_tokens};
 
 pub(crate) struct ArrayAccess<'a, 'b, 'de: 'a> {
     deserializer: &'a mut Deserializer<'b, 'de>,
@@ -56,18 +52,18 @@ impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
             // we parse in a way where every subsequent invocation (except the first one)
             // needs to parse the `,` that is the token, if that token is not present we will error
             // out, but(!) will still attempt deserialization, as we can tolerate that error.
+            let peek_key = self.deserializer.peek();
+
+            // the closing bracket (or EOF) is not preceded by a comma, we're "done"
+            if peek_key.is_none() || peek_key == Some(PeekableTokenKind::ArrayEnd) {
+                return None;
+            }
 
-            // the statement after this _will_ fail and return the visitor, therefore we don't
-            // need to check for EOF
             if let Err(error) = self.try_skip_comma() {
                 errors.append(error);
             }
         }
 
-        if let Err(error) = errors.finish() {
-            return Some(Err(error.change_context(ArrayAccessError)));
-        }
-
         self.dirty = true;
 
         let peek_key = self.deserializer.peek();
@@ -75,14 +71,23 @@ impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
         // we check for `is_none` here because we could be EOF, in that case we're "done", we will
         // error out at `.end()`
         if peek_key.is_none() || peek_key == Some(PeekableTokenKind::ArrayEnd) {
-            return None;
+            return errors
+                .finish()
+                .err()
+                .map(|error| Err(error.change_context(ArrayAccessError)));
         }
 
         self.expected += 1;
 
-        let value = T::deserialize(&mut *self.deserializer);
+        let value = T::deserialize(&mut *self.deserializer).change_context(ArrayAccessError);
+        let comma = errors.finish().change_context(ArrayAccessError);
 
-        Some(value.change_context(ArrayAccessError))
+        Some(
+            (value, comma)
+                .try_collect()
+                .map(|(value, ())| value)
+                .change_context(ArrayAccessError),
+        )
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -94,7 +99,7 @@ impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
 
         let result = match self.deserializer.peek() {
             None => Err(Report::new(SyntaxError::UnexpectedEof.into_error())
-                .attach(Position::new(self.deserializer.offset()))),
+                .attach(self.deserializer.position())),
             Some(PeekableTokenKind::ArrayEnd) => Ok(()),
             Some(_) => Err(ArrayLengthError::new(&self, self.expected)),
         };
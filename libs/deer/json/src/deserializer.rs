@@ -70,6 +70,8 @@ impl Stack {
 #[expect(clippy::field_scoped_visibility_modifiers)]
 pub struct Deserializer<'a, 'de> {
     pub(crate) tokenizer: Tokenizer<'de, false>,
+    input: &'de [u8],
+    origin: Position,
 
     context: &'a Context,
     pub(crate) stack: Stack,
@@ -78,12 +80,21 @@ pub struct Deserializer<'a, 'de> {
 impl<'a, 'de> Deserializer<'a, 'de> {
     #[must_use]
     pub fn new(slice: &'de [u8], context: &'a Context) -> Self {
+        Self::new_at(slice, context, Position::START)
+    }
+
+    /// Creates a deserializer for a slice which starts at `origin` of a larger input.
+    ///
+    /// All positions reported in errors are relative to the larger input.
+    pub(crate) fn new_at(slice: &'de [u8], context: &'a Context, origin: Position) -> Self {
         let limit = context
             .request_ref::<StackLimit>()
             .map_or(usize::MAX, |limit| limit.limit());
 
         Self {
             tokenizer: Tokenizer::for_json_bytes(slice),
+            input: slice,
+            origin,
             context,
             stack: Stack::new(limit),
         }
@@ -93,12 +104,12 @@ impl<'a, 'de> Deserializer<'a, 'de> {
         let offset = self.tokenizer.offset();
         let Some(token) = self.tokenizer.next() else {
             return Err(Report::new(SyntaxError::UnexpectedEof.into_error())
-                .attach(Position::new(offset))
+                .attach(self.position_at(offset))
                 .change_context(DeserializerError));
         };
 
         token
-            .map_err(|error| convert_tokenizer_error(&error))
+            .map_err(|error| convert_tokenizer_error(&error, self.position_at(error.offset())))
             .change_context(DeserializerError)
     }
 
@@ -119,14 +130,14 @@ impl<'a, 'de> Deserializer<'a, 'de> {
     pub(crate) fn skip(&mut self) -> Range<usize> {
         // `.next()` will only error out if a string or number is malformed
         // we can safely skip those as they do not affect how we skip
-        let start = self.tokenizer.offset();
+        let start = self.offset();
         let next = self.tokenizer.next();
 
         if let Some(Ok(token)) = next {
             skip_tokens(&mut self.tokenizer, &token);
         }
 
-        start..self.tokenizer.offset()
+        start..self.offset()
     }
 
     pub(crate) fn skip_if(&mut self, token: PeekableTokenKind) -> Option<Range<usize>> {
@@ -141,7 +152,7 @@ impl<'a, 'de> Deserializer<'a, 'de> {
         error: SyntaxError,
     ) -> Result<(), Report<Error>> {
         if self.skip_if(token).is_none() {
-            Err(Report::new(error.into_error()).attach(Position::new(self.offset())))
+            Err(Report::new(error.into_error()).attach(self.position()))
         } else {
             Ok(())
         }
@@ -152,7 +163,29 @@ impl<'a, 'de> Deserializer<'a, 'de> {
     }
 
     pub(crate) const fn offset(&self) -> usize {
-        self.tokenizer.offset()
+        self.origin.offset() + self.tokenizer.offset()
+    }
+
+    pub(crate) fn position(&self) -> Position {
+        self.position_at(self.tokenizer.offset())
+    }
+
+    /// Resolves the line and column of `offset`, this is linear in `offset` and therefore only done
+    /// once an error occurs.
+    fn position_at(&self, offset: usize) -> Position {
+        let consumed = self.input.get(..offset).unwrap_or(self.input);
+
+        self.origin.advanced(consumed)
+    }
+
+    /// Ensures that only whitespace follows the value that has been deserialized.
+    #[cfg(feature = "std")]
+    pub(crate) fn end(&mut self) -> Result<(), Report<Error>> {
+        if self.peek().is_some() {
+            Err(Report::new(SyntaxError::TrailingCharacters.into_error()).attach(self.position()))
+        } else {
+            Ok(())
+        }
     }
 
     pub(crate) fn try_stack_push(
@@ -331,7 +364,7 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
         let offset = self.tokenizer.offset();
         match self.tokenizer.peek() {
             None => Err(Report::new(SyntaxError::UnexpectedEof.into_error())
-                .attach(Position::new(offset))
+                .attach(self.position_at(offset))
                 .change_context(DeserializerError)),
             Some(PeekableTokenKind::Null) => {
                 // we know the value will be `null`, therefore we can just discard the next token
@@ -358,7 +391,7 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
             Some(_) => false,
             None => {
                 return Err(Report::new(SyntaxError::UnexpectedEof.into_error())
-                    .attach(Position::new(self.offset()))
+                    .attach(self.position())
                     .change_context(DeserializerError));
             }
         };
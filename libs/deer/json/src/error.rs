@@ -103,19 +103,59 @@ impl Variant for NumberError {
     }
 }
 
+/// Location of a byte in the input.
+///
+/// `line` and `column` are one-based, `column` is counted in bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize)]
 pub(crate) struct Position {
     offset: usize,
+    line: usize,
+    column: usize,
 }
 
 impl Position {
-    pub(crate) const fn new(offset: usize) -> Self {
-        Self { offset }
+    pub(crate) const START: Self = Self {
+        offset: 0,
+        line: 1,
+        column: 1,
+    };
+
+    pub(crate) const fn offset(self) -> usize {
+        self.offset
+    }
+
+    pub(crate) const fn advance_byte(&mut self, byte: u8) {
+        self.offset += 1;
+
+        if byte == b'\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+    }
+
+    pub(crate) fn advance(&mut self, bytes: &[u8]) {
+        self.offset += bytes.len();
+
+        match memchr::memrchr(b'\n', bytes) {
+            Some(last) => {
+                self.line += memchr::memchr_iter(b'\n', bytes).count();
+                self.column = bytes.len() - last;
+            }
+            None => self.column += bytes.len(),
+        }
+    }
+
+    pub(crate) fn advanced(mut self, bytes: &[u8]) -> Self {
+        self.advance(bytes);
+        self
     }
 }
 
 impl ErrorProperty for Position {
     type Value<'a>
-        = Option<usize>
+        = Option<&'a Self>
     where
         Self: 'a;
 
@@ -124,7 +164,7 @@ impl ErrorProperty for Position {
     }
 
     fn value<'a>(mut stack: impl Iterator<Item = &'a Self>) -> Self::Value<'a> {
-        stack.next().map(|Self { offset }| *offset)
+        stack.next()
     }
 }
 
@@ -170,8 +210,8 @@ pub(crate) enum SyntaxError {
     ObjectKeyMustBeString,
     InvalidHexadecimal,
     InvalidEscape,
-    // TODO: trailing non whitespace?
     UnclosedString,
+    TrailingCharacters,
 }
 
 impl Display for SyntaxError {
@@ -197,6 +237,7 @@ impl Display for SyntaxError {
             }
             Self::InvalidEscape => fmt.write_str("invalid escape character"),
             Self::UnclosedString => fmt.write_str(r#"expected end of string (`"`)"#),
+            Self::TrailingCharacters => fmt.write_str("trailing characters after value"),
         }
     }
 }
@@ -216,8 +257,8 @@ impl Variant for SyntaxError {
         let span = properties.2;
 
         // TODO: context via codespan -> Property (if fancy)
-        if let Some(position) = position {
-            fmt.write_fmt(format_args!("{self} at {position}"))
+        if let Some(Position { line, column, .. }) = position {
+            fmt.write_fmt(format_args!("{self} at line {line}, column {column}"))
         } else if let Some(span) = span {
             let Range { start, end } = span;
             fmt.write_fmt(format_args!("{self} at {start}..{end}"))
@@ -227,6 +268,37 @@ impl Variant for SyntaxError {
     }
 }
 
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub(crate) struct ReadError;
+
+#[cfg(feature = "std")]
+impl Display for ReadError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt.write_str("unable to read input")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Variant for ReadError {
+    type Properties = (Location, Position);
+
+    const ID: Id = id!["read"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        if let Some(Position { line, column, .. }) = properties.1 {
+            fmt.write_fmt(format_args!("{self} at line {line}, column {column}"))
+        } else {
+            Display::fmt(self, fmt)
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct NativeError(justjson::ErrorKind);
 
@@ -251,9 +323,10 @@ impl Variant for NativeError {
     }
 }
 
-pub(crate) fn convert_tokenizer_error(error: &justjson::Error) -> Report<deer::error::Error> {
-    let offset = error.offset();
-
+pub(crate) fn convert_tokenizer_error(
+    error: &justjson::Error,
+    position: Position,
+) -> Report<deer::error::Error> {
     let error = match error.kind() {
         ErrorKind::Utf8 => SyntaxError::InvalidUtf8Sequence.into_error(),
         ErrorKind::UnexpectedEof => SyntaxError::UnexpectedEof.into_error(),
@@ -270,5 +343,5 @@ pub(crate) fn convert_tokenizer_error(error: &justjson::Error) -> Report<deer::e
         kind => NativeError(kind.clone()).into_error(),
     };
 
-    Report::new(error).attach(Position::new(offset))
+    Report::new(error).attach(position)
 }
@@ -9,6 +9,8 @@ mod error;
 mod number;
 mod object;
 mod skip;
+#[cfg(feature = "std")]
+mod stream;
 mod token;
 
 extern crate alloc;
@@ -17,6 +19,10 @@ use deer::{Context, Deserialize, error::DeserializeError};
 use error_stack::Report;
 
 pub use crate::deserializer::{Deserializer, StackLimit};
+#[cfg(feature = "tokio")]
+pub use crate::stream::AsyncStreamDeserializer;
+#[cfg(feature = "std")]
+pub use crate::stream::StreamDeserializer;
 
 pub fn from_slice<'de, T>(
     slice: &'de [u8],
@@ -7,7 +7,7 @@ use justjson::parser::{PeekableTokenKind, Token};
 
 use crate::{
     deserializer::Deserializer,
-    error::{Position, Span, SyntaxError},
+    error::{Span, SyntaxError},
     skip::skip_tokens,
 };
 
@@ -156,7 +156,7 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
 
         let result = match self.deserializer.peek() {
             None => Err(Report::new(SyntaxError::UnexpectedEof.into_error())
-                .attach(Position::new(self.deserializer.offset()))),
+                .attach(self.deserializer.position())),
             Some(PeekableTokenKind::ObjectEnd) => Ok(()),
             Some(_) => Err(ObjectLengthError::new(&self, self.expected)),
         };
@@ -0,0 +1,347 @@
+use alloc::vec::Vec;
+
+use deer::error::{Error, Variant as _};
+use error_stack::Report;
+
+use crate::error::{Position, SyntaxError};
+
+/// How values are delimited in a stream.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Framing {
+    /// Every non-empty line is a value ([JSON Lines](https://jsonlines.org/)).
+    Lines,
+    /// Every element of a single top-level array is a value.
+    Array,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum State {
+    /// Before the first value, for arrays this is before the opening bracket.
+    Start,
+    /// After the opening bracket, expecting a value or the closing bracket.
+    Open,
+    /// After a comma, expecting a value.
+    Comma,
+    /// After a value, expecting a comma or the closing bracket.
+    Next,
+    /// Inside a number or literal, which is terminated by whitespace, a comma or a bracket.
+    Scalar,
+    /// Inside a string, which is terminated by an unescaped quote.
+    String { escape: bool },
+    /// Inside an array or object, which is terminated by the matching bracket.
+    Container {
+        depth: usize,
+        string: bool,
+        escape: bool,
+    },
+    /// After the closing bracket, only whitespace may follow.
+    Closed,
+    /// The input has been exhausted or cannot be recovered from.
+    Finished,
+}
+
+enum Step {
+    /// The byte has been consumed, the value continues.
+    Consume,
+    /// The byte has been consumed and completes the value.
+    Complete,
+    /// The byte terminates the value, but is not part of it.
+    Yield,
+    Fail(SyntaxError),
+}
+
+const fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Splits a stream of bytes into individual values, without parsing them.
+///
+/// The framer is fed chunks of the input through [`Self::feed`] until a value is complete, the
+/// value is then available through [`Self::record`] until the next call to [`Self::feed`]. Only
+/// the value that is currently framed is kept in memory.
+pub(crate) struct Framer {
+    framing: Framing,
+    state: State,
+
+    record: Vec<u8>,
+    ready: bool,
+    index: usize,
+
+    origin: Position,
+    position: Position,
+}
+
+impl Framer {
+    pub(crate) const fn new(framing: Framing) -> Self {
+        Self {
+            framing,
+            state: State::Start,
+            record: Vec::new(),
+            ready: false,
+            index: 0,
+            origin: Position::START,
+            position: Position::START,
+        }
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.state == State::Finished
+    }
+
+    /// Position of the next byte that will be fed.
+    pub(crate) const fn position(&self) -> Position {
+        self.position
+    }
+
+    /// The value that has been completed last.
+    pub(crate) fn record(&self) -> &[u8] {
+        &self.record
+    }
+
+    /// Position of the first byte of [`Self::record`].
+    pub(crate) const fn origin(&self) -> Position {
+        self.origin
+    }
+
+    /// Index of [`Self::record`] in the stream.
+    pub(crate) const fn index(&self) -> usize {
+        self.index.saturating_sub(1)
+    }
+
+    /// Marks the stream as unrecoverable, e.g. because the underlying reader failed.
+    pub(crate) fn abort(&mut self) {
+        self.state = State::Finished;
+    }
+
+    fn reset(&mut self) {
+        if self.ready {
+            self.record.clear();
+            self.ready = false;
+        }
+    }
+
+    fn complete(&mut self) {
+        self.ready = true;
+        self.index += 1;
+    }
+
+    fn fail(&mut self, error: SyntaxError) -> Report<Error> {
+        self.state = State::Finished;
+        self.record.clear();
+
+        Report::new(error.into_error()).attach(self.position)
+    }
+
+    /// Consumes bytes from `bytes` until a value has been completed or `bytes` is exhausted.
+    ///
+    /// Returns the amount of bytes consumed and, if a value has been completed, if framing was
+    /// successful.
+    pub(crate) fn feed(&mut self, bytes: &[u8]) -> (usize, Option<Result<(), Report<Error>>>) {
+        self.reset();
+
+        if self.state == State::Finished {
+            return (bytes.len(), None);
+        }
+
+        match self.framing {
+            Framing::Lines => self.feed_lines(bytes),
+            Framing::Array => self.feed_array(bytes),
+        }
+    }
+
+    fn feed_lines(&mut self, bytes: &[u8]) -> (usize, Option<Result<(), Report<Error>>>) {
+        if self.record.is_empty() {
+            self.origin = self.position;
+        }
+
+        let Some(end) = memchr::memchr(b'\n', bytes) else {
+            self.record.extend_from_slice(bytes);
+            self.position.advance(bytes);
+
+            return (bytes.len(), None);
+        };
+
+        self.record.extend_from_slice(&bytes[..end]);
+        self.position.advance(&bytes[..=end]);
+
+        if self.record.iter().copied().all(is_whitespace) {
+            // empty lines are permitted and skipped
+            self.record.clear();
+
+            return (end + 1, None);
+        }
+
+        self.complete();
+        (end + 1, Some(Ok(())))
+    }
+
+    fn feed_array(&mut self, bytes: &[u8]) -> (usize, Option<Result<(), Report<Error>>>) {
+        for (index, &byte) in bytes.iter().enumerate() {
+            let step = self.step(byte);
+
+            match step {
+                Step::Consume => self.position.advance_byte(byte),
+                Step::Complete => {
+                    self.position.advance_byte(byte);
+                    self.state = State::Next;
+                    self.complete();
+
+                    return (index + 1, Some(Ok(())));
+                }
+                Step::Yield => {
+                    self.state = State::Next;
+                    self.complete();
+
+                    return (index, Some(Ok(())));
+                }
+                Step::Fail(error) => return (index, Some(Err(self.fail(error)))),
+            }
+        }
+
+        (bytes.len(), None)
+    }
+
+    fn start(&mut self, byte: u8) -> Step {
+        self.origin = self.position;
+        self.record.push(byte);
+
+        self.state = match byte {
+            b'"' => State::String { escape: false },
+            b'[' | b'{' => State::Container {
+                depth: 1,
+                string: false,
+                escape: false,
+            },
+            _ => State::Scalar,
+        };
+
+        Step::Consume
+    }
+
+    fn step(&mut self, byte: u8) -> Step {
+        match self.state {
+            State::Start => match byte {
+                b'[' => {
+                    self.state = State::Open;
+                    Step::Consume
+                }
+                byte if is_whitespace(byte) => Step::Consume,
+                byte => Step::Fail(SyntaxError::UnexpectedByte(byte)),
+            },
+            State::Open | State::Comma => match byte {
+                b']' if self.state == State::Open => {
+                    self.state = State::Closed;
+                    Step::Consume
+                }
+                byte if is_whitespace(byte) => Step::Consume,
+                b',' | b']' => Step::Fail(SyntaxError::UnexpectedByte(byte)),
+                byte => self.start(byte),
+            },
+            State::Next => match byte {
+                b',' => {
+                    self.state = State::Comma;
+                    Step::Consume
+                }
+                b']' => {
+                    self.state = State::Closed;
+                    Step::Consume
+                }
+                byte if is_whitespace(byte) => Step::Consume,
+                _ => Step::Fail(SyntaxError::ExpectedComma),
+            },
+            State::Scalar => {
+                if is_whitespace(byte) || byte == b',' || byte == b']' {
+                    Step::Yield
+                } else {
+                    self.record.push(byte);
+                    Step::Consume
+                }
+            }
+            State::String { escape } => {
+                self.record.push(byte);
+
+                if escape {
+                    self.state = State::String { escape: false };
+                    Step::Consume
+                } else if byte == b'\\' {
+                    self.state = State::String { escape: true };
+                    Step::Consume
+                } else if byte == b'"' {
+                    Step::Complete
+                } else {
+                    Step::Consume
+                }
+            }
+            State::Container {
+                depth,
+                string,
+                escape,
+            } => {
+                self.record.push(byte);
+
+                let (depth, string, escape) = match (string, escape, byte) {
+                    (true, true, _) => (depth, true, false),
+                    (true, false, b'\\') => (depth, true, true),
+                    (true, false, b'"') | (false, _, b'"') => (depth, !string, false),
+                    (false, _, b'[' | b'{') => (depth + 1, false, false),
+                    (false, _, b']' | b'}') if depth == 1 => return Step::Complete,
+                    (false, _, b']' | b'}') => (depth - 1, false, false),
+                    _ => (depth, string, escape),
+                };
+
+                self.state = State::Container {
+                    depth,
+                    string,
+                    escape,
+                };
+                Step::Consume
+            }
+            State::Closed => {
+                if is_whitespace(byte) {
+                    Step::Consume
+                } else {
+                    Step::Fail(SyntaxError::TrailingCharacters)
+                }
+            }
+            State::Finished => Step::Consume,
+        }
+    }
+
+    /// Signals that the input has been exhausted.
+    ///
+    /// Completes the value that is currently framed, if any, every subsequent call returns
+    /// `None`.
+    pub(crate) fn finish(&mut self) -> Option<Result<(), Report<Error>>> {
+        self.reset();
+
+        match (self.framing, self.state) {
+            (_, State::Finished) => None,
+            (Framing::Lines, _) => {
+                self.state = State::Finished;
+
+                if self.record.iter().copied().all(is_whitespace) {
+                    self.record.clear();
+
+                    None
+                } else {
+                    self.complete();
+                    Some(Ok(()))
+                }
+            }
+            (Framing::Array, State::Scalar) => {
+                // the last value might still be valid, the missing bracket is reported on the
+                // next call
+                self.state = State::Next;
+                self.complete();
+
+                Some(Ok(()))
+            }
+            (Framing::Array, State::Closed) => {
+                self.state = State::Finished;
+
+                None
+            }
+            (Framing::Array, _) => Some(Err(self.fail(SyntaxError::UnexpectedEof))),
+        }
+    }
+}
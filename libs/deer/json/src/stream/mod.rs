@@ -0,0 +1,35 @@
+//! Incremental deserialization of a sequence of values, see [`StreamDeserializer`].
+
+use deer::{
+    Context, DeserializeOwned,
+    error::{DeserializeError, Location},
+};
+use error_stack::{Report, ResultExt as _};
+
+use self::frame::Framer;
+use crate::Deserializer;
+
+mod frame;
+mod read;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+pub use self::read::StreamDeserializer;
+#[cfg(feature = "tokio")]
+pub use self::tokio::AsyncStreamDeserializer;
+
+fn deserialize<T>(framer: &Framer, context: &Context) -> Result<T, Report<DeserializeError>>
+where
+    T: DeserializeOwned,
+{
+    let mut deserializer = Deserializer::new_at(framer.record(), context, framer.origin());
+
+    let value = T::deserialize(&mut deserializer).attach(Location::Array(framer.index()))?;
+
+    deserializer
+        .end()
+        .change_context(DeserializeError)
+        .attach(Location::Array(framer.index()))?;
+
+    Ok(value)
+}
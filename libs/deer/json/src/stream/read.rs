@@ -0,0 +1,132 @@
+use core::marker::PhantomData;
+use std::io::{BufRead as _, BufReader, ErrorKind, Read};
+
+use deer::{
+    Context, DeserializeOwned,
+    error::{DeserializeError, Variant as _},
+};
+use error_stack::Report;
+
+use super::{
+    deserialize,
+    frame::{Framer, Framing},
+};
+use crate::error::ReadError;
+
+/// Deserializes a sequence of values from an [`io::Read`].
+///
+/// The input is read in chunks and split into individual values, either one value per line
+/// ([JSON Lines](https://jsonlines.org/)) or one value per element of a top-level array. Only the
+/// value that is currently being deserialized is kept in memory, which means that inputs larger
+/// than the available memory can be processed.
+///
+/// Errors refer to the whole input, not just the value they occurred in: positions are the byte
+/// offset, line and column in the input, and the index of the value in the stream is added as the
+/// outermost [`Location::Array`], so that the `location` of an error is the path from the root of
+/// the input.
+///
+/// A value that fails to deserialize does not end the stream, the next value is deserialized
+/// independently. The stream only ends early if the reader fails, or if the input is malformed in
+/// a way that makes it impossible to find the start of the next value, e.g. a missing comma
+/// between two array elements.
+///
+/// # Example
+///
+/// ```
+/// use deer::Context;
+/// use deer_json::StreamDeserializer;
+///
+/// let input = b"1\n2\n\n3\n";
+/// let context = Context::new();
+///
+/// let values =
+///     StreamDeserializer::<_, u8>::lines(&input[..], &context).collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(values, [1, 2, 3]);
+/// # Ok::<(), error_stack::Report<deer::error::DeserializeError>>(())
+/// ```
+///
+/// [`io::Read`]: std::io::Read
+/// [`Location::Array`]: deer::error::Location::Array
+pub struct StreamDeserializer<'a, R, T> {
+    reader: BufReader<R>,
+    framer: Framer,
+    context: &'a Context,
+
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, R, T> StreamDeserializer<'a, R, T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    fn new(reader: R, context: &'a Context, framing: Framing) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            framer: Framer::new(framing),
+            context,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Deserializes one value per line, empty lines are skipped.
+    #[must_use]
+    pub fn lines(reader: R, context: &'a Context) -> Self {
+        Self::new(reader, context, Framing::Lines)
+    }
+
+    /// Deserializes every element of a top-level array.
+    #[must_use]
+    pub fn array(reader: R, context: &'a Context) -> Self {
+        Self::new(reader, context, Framing::Array)
+    }
+
+    /// Amount of bytes that have been consumed from the reader.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.framer.position().offset()
+    }
+}
+
+impl<R, T> Iterator for StreamDeserializer<'_, R, T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, Report<DeserializeError>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.framer.is_finished() {
+            let buffer = match self.reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.framer.abort();
+
+                    return Some(Err(Report::new(error)
+                        .change_context(ReadError.into_error())
+                        .attach(self.framer.position())
+                        .change_context(DeserializeError)));
+                }
+            };
+
+            let framed = if buffer.is_empty() {
+                self.framer.finish()
+            } else {
+                let (consumed, framed) = self.framer.feed(buffer);
+                self.reader.consume(consumed);
+
+                framed
+            };
+
+            match framed {
+                Some(Ok(())) => return Some(deserialize(&self.framer, self.context)),
+                Some(Err(error)) => return Some(Err(error.change_context(DeserializeError))),
+                None => {}
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,120 @@
+use core::marker::PhantomData;
+use std::io::ErrorKind;
+
+use deer::{
+    Context, DeserializeOwned,
+    error::{DeserializeError, Variant as _},
+};
+use error_stack::Report;
+use tokio::io::{AsyncBufReadExt as _, AsyncRead, BufReader};
+
+use super::{
+    deserialize,
+    frame::{Framer, Framing},
+};
+use crate::error::ReadError;
+
+/// Deserializes a sequence of values from an [`AsyncRead`].
+///
+/// This is the asynchronous counterpart to [`StreamDeserializer`], which describes the supported
+/// formats and how errors are reported.
+///
+/// # Example
+///
+/// ```
+/// use deer::Context;
+/// use deer_json::AsyncStreamDeserializer;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let input = b"[1, 2, 3]";
+/// let context = Context::new();
+///
+/// let mut stream = AsyncStreamDeserializer::<_, u8>::array(&input[..], &context);
+///
+/// while let Some(value) = stream.next().await {
+///     println!("{}", value?);
+/// }
+/// # Ok::<(), error_stack::Report<deer::error::DeserializeError>>(())
+/// # }).unwrap();
+/// ```
+///
+/// [`StreamDeserializer`]: crate::StreamDeserializer
+pub struct AsyncStreamDeserializer<'a, R, T> {
+    reader: BufReader<R>,
+    framer: Framer,
+    context: &'a Context,
+
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, R, T> AsyncStreamDeserializer<'a, R, T>
+where
+    R: AsyncRead + Unpin,
+    T: DeserializeOwned,
+{
+    fn new(reader: R, context: &'a Context, framing: Framing) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            framer: Framer::new(framing),
+            context,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Deserializes one value per line, empty lines are skipped.
+    #[must_use]
+    pub fn lines(reader: R, context: &'a Context) -> Self {
+        Self::new(reader, context, Framing::Lines)
+    }
+
+    /// Deserializes every element of a top-level array.
+    #[must_use]
+    pub fn array(reader: R, context: &'a Context) -> Self {
+        Self::new(reader, context, Framing::Array)
+    }
+
+    /// Amount of bytes that have been consumed from the reader.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.framer.position().offset()
+    }
+
+    /// Deserializes the next value, returns `None` once the input is exhausted.
+    #[expect(
+        clippy::future_not_send,
+        reason = "`Context` is not `Sync`, the future is bound to the thread it was created on"
+    )]
+    pub async fn next(&mut self) -> Option<Result<T, Report<DeserializeError>>> {
+        while !self.framer.is_finished() {
+            let buffer = match self.reader.fill_buf().await {
+                Ok(buffer) => buffer,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.framer.abort();
+
+                    return Some(Err(Report::new(error)
+                        .change_context(ReadError.into_error())
+                        .attach(self.framer.position())
+                        .change_context(DeserializeError)));
+                }
+            };
+
+            let framed = if buffer.is_empty() {
+                self.framer.finish()
+            } else {
+                let (consumed, framed) = self.framer.feed(buffer);
+                self.reader.consume(consumed);
+
+                framed
+            };
+
+            match framed {
+                Some(Ok(())) => return Some(deserialize(&self.framer, self.context)),
+                Some(Err(error)) => return Some(Err(error.change_context(DeserializeError))),
+                None => {}
+            }
+        }
+
+        None
+    }
+}
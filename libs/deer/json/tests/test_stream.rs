@@ -0,0 +1,181 @@
+use core::fmt::Debug;
+
+use deer::{
+    Context, DeserializeOwned,
+    error::{DeserializeError, ReportExt as _},
+};
+use deer_json::StreamDeserializer;
+use error_stack::Report;
+use serde_json::{Value, json};
+
+fn lines<T: DeserializeOwned>(input: &str) -> Vec<Result<T, Report<DeserializeError>>> {
+    let context = Context::new();
+
+    StreamDeserializer::lines(input.as_bytes(), &context).collect()
+}
+
+fn array<T: DeserializeOwned>(input: &str) -> Vec<Result<T, Report<DeserializeError>>> {
+    let context = Context::new();
+
+    StreamDeserializer::array(input.as_bytes(), &context).collect()
+}
+
+fn export<T: Debug>(value: Option<Result<T, Report<DeserializeError>>>) -> Value {
+    let report = value
+        .expect("should yield a value")
+        .expect_err("should fail to deserialize");
+
+    serde_json::to_value(report.export()).expect("error should serialize")
+}
+
+fn value<T: Debug>(value: Option<Result<T, Report<DeserializeError>>>) -> T {
+    value
+        .expect("should yield a value")
+        .expect("should be able to deserialize")
+}
+
+#[test]
+fn lines_ok() {
+    let values = lines::<u8>("1\n2\r\n\n  \n3")
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("should be able to deserialize");
+
+    assert_eq!(values, [1, 2, 3]);
+}
+
+#[test]
+fn lines_err_continues() {
+    let mut values = lines::<u8>("1\n\"a\"\n3\n").into_iter();
+
+    assert_eq!(value(values.next()), 1);
+    let error = export(values.next());
+    assert_eq!(value(values.next()), 3);
+    assert!(values.next().is_none());
+
+    assert_eq!(error[0]["id"], json!(["type"]));
+    assert_eq!(
+        error[0]["properties"]["location"],
+        json!([{"type": "array", "value": 1}])
+    );
+}
+
+#[test]
+fn lines_syntax_err_position() {
+    let mut values = lines::<Vec<u8>>("[1]\n\n[1, 2\n").into_iter();
+
+    assert_eq!(value(values.next()), [1]);
+    let error = export(values.next());
+    assert!(values.next().is_none());
+
+    assert_eq!(error[0]["namespace"], json!("deer-json"));
+    assert_eq!(error[0]["id"], json!(["syntax"]));
+    assert_eq!(
+        error[0]["properties"]["position"],
+        json!({"offset": 10, "line": 3, "column": 6})
+    );
+}
+
+#[test]
+fn lines_trailing_err() {
+    let error = export(lines::<u8>("1 2\n").into_iter().next());
+
+    assert_eq!(error[0]["id"], json!(["syntax"]));
+    assert_eq!(
+        error[0]["properties"]["position"],
+        json!({"offset": 2, "line": 1, "column": 3})
+    );
+}
+
+#[test]
+fn array_ok() {
+    let values = array::<Vec<String>>(" [\n [\"a,]\", \"b\\\"]\"],\n [],[\"c\"] ]\n")
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("should be able to deserialize");
+
+    assert_eq!(
+        values,
+        [
+            vec!["a,]".to_owned(), "b\"]".to_owned()],
+            vec![],
+            vec!["c".to_owned()]
+        ]
+    );
+}
+
+#[test]
+fn array_empty() {
+    assert!(array::<u8>("[ ]").is_empty());
+}
+
+#[test]
+fn array_err_location() {
+    let mut values = array::<Vec<u8>>("[[1], [2, \"a\"]]").into_iter();
+
+    assert_eq!(value(values.next()), [1]);
+    let error = export(values.next());
+    assert!(values.next().is_none());
+
+    assert_eq!(error[0]["id"], json!(["type"]));
+    assert_eq!(
+        error[0]["properties"]["location"],
+        json!([{"type": "array", "value": 1}, {"type": "array", "value": 1}])
+    );
+}
+
+#[test]
+fn array_missing_comma_err() {
+    let mut values = array::<u8>("[1\n 2]").into_iter();
+
+    assert_eq!(value(values.next()), 1);
+    let error = export(values.next());
+    assert!(values.next().is_none());
+
+    assert_eq!(error[0]["id"], json!(["syntax"]));
+    assert_eq!(
+        error[0]["properties"]["position"],
+        json!({"offset": 4, "line": 2, "column": 2})
+    );
+}
+
+#[test]
+fn array_eof_err() {
+    let mut values = array::<u8>("[1, 2").into_iter();
+
+    assert_eq!(value(values.next()), 1);
+    assert_eq!(value(values.next()), 2);
+    let error = export(values.next());
+    assert!(values.next().is_none());
+
+    assert_eq!(error[0]["id"], json!(["syntax"]));
+}
+
+#[test]
+fn array_not_array_err() {
+    let mut values = array::<u8>("{}").into_iter();
+
+    let error = export(values.next());
+    assert!(values.next().is_none());
+
+    assert_eq!(error[0]["id"], json!(["syntax"]));
+    assert_eq!(
+        error[0]["properties"]["position"],
+        json!({"offset": 0, "line": 1, "column": 1})
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn async_lines_ok() {
+    let context = Context::new();
+    let mut stream = deer_json::AsyncStreamDeserializer::<_, u8>::lines(&b"1\n2\n"[..], &context);
+
+    let mut values = Vec::new();
+    while let Some(value) = stream.next().await {
+        values.push(value.expect("should be able to deserialize"));
+    }
+
+    assert_eq!(values, [1, 2]);
+    assert_eq!(stream.offset(), 4);
+}
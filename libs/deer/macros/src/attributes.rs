@@ -0,0 +1,105 @@
+use syn::{Attribute, Ident, LitStr, Result};
+
+/// How the variant of an enum is encoded.
+pub(crate) enum Tagging {
+    /// `{"Variant": <value>}` or `"Variant"` for unit variants, this is the default.
+    External,
+    /// `{"<tag>": "Variant", ...fields}`, selected through `#[deer(tag = "...")]`.
+    Internal { tag: String },
+    /// `{"<tag>": "Variant", "<content>": <value>}`, selected through
+    /// `#[deer(tag = "...", content = "...")]`.
+    Adjacent { tag: String, content: String },
+}
+
+/// Attributes of the type the derive is invoked on.
+pub(crate) struct ContainerAttributes {
+    pub(crate) tagging: Tagging,
+}
+
+impl ContainerAttributes {
+    pub(crate) fn parse(attributes: &[Attribute]) -> Result<Self> {
+        let mut tag = None;
+        let mut content = None;
+
+        for attribute in attributes
+            .iter()
+            .filter(|attr| attr.path().is_ident("deer"))
+        {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    tag = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else if meta.path.is_ident("content") {
+                    content = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta
+                        .error("unknown `deer` container attribute, expected `tag` or `content`"))
+                }
+            })?;
+        }
+
+        let tagging = match (tag, content) {
+            (None, None) => Tagging::External,
+            (Some(tag), None) => Tagging::Internal { tag: tag.value() },
+            (Some(tag), Some(content)) => {
+                if tag.value() == content.value() {
+                    return Err(syn::Error::new(
+                        content.span(),
+                        "`tag` and `content` must be different",
+                    ));
+                }
+
+                Tagging::Adjacent {
+                    tag: tag.value(),
+                    content: content.value(),
+                }
+            }
+            (None, Some(content)) => {
+                return Err(syn::Error::new(
+                    content.span(),
+                    "`content` can only be used together with `tag`",
+                ));
+            }
+        };
+
+        Ok(Self { tagging })
+    }
+}
+
+/// Attributes of a field or an enum variant.
+pub(crate) struct NameAttributes {
+    /// The name used during deserialization
+    pub(crate) name: String,
+}
+
+impl NameAttributes {
+    pub(crate) fn parse(ident: &Ident, attributes: &[Attribute]) -> Result<Self> {
+        let mut name = None;
+
+        for attribute in attributes
+            .iter()
+            .filter(|attr| attr.path().is_ident("deer"))
+        {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `deer` attribute, expected `rename`"))
+                }
+            })?;
+        }
+
+        let name = name.unwrap_or_else(|| {
+            let ident = ident.to_string();
+
+            // raw identifiers, like `r#type`, are deserialized without their prefix
+            ident
+                .strip_prefix("r#")
+                .map_or_else(|| ident.clone(), ToOwned::to_owned)
+        });
+
+        Ok(Self { name })
+    }
+}
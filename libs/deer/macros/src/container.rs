@@ -0,0 +1,140 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{GenericParam, Generics, Ident, Result};
+
+/// Which bounds are required for the type parameters of the container.
+#[derive(Copy, Clone)]
+pub(crate) enum Bounds {
+    /// Every type parameter must implement `Deserialize<'de>`.
+    Deserialize,
+    /// Every type parameter must implement `Deserialize<'de>`, additionally the container must
+    /// implement `Reflection`, which is used by visitors to describe what they expect.
+    Visitor,
+    /// Every type parameter must implement `Deserialize<'static>` and be `'static`, which is
+    /// required to get the reflection of a field.
+    Reflection,
+}
+
+/// The type the derive is invoked on.
+///
+/// Every helper type generated carries the generics of the container, this type provides the
+/// pieces needed to declare and implement those helpers.
+pub(crate) struct Container<'a> {
+    pub(crate) ident: &'a Ident,
+    generics: &'a Generics,
+}
+
+impl<'a> Container<'a> {
+    pub(crate) fn new(ident: &'a Ident, generics: &'a Generics) -> Result<Self> {
+        if let Some(lifetime) = generics.lifetimes().next() {
+            return Err(syn::Error::new_spanned(
+                lifetime,
+                "`deer` only deserializes owned data, lifetime parameters are not supported",
+            ));
+        }
+
+        Ok(Self { ident, generics })
+    }
+
+    /// The type of the container, including its generic arguments.
+    pub(crate) fn ty(&self) -> TokenStream {
+        let ident = self.ident;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        quote!(#ident #ty_generics)
+    }
+
+    /// The generic parameters of the container including their bounds, but without defaults.
+    ///
+    /// Every parameter is followed by a comma.
+    pub(crate) fn params(&self) -> TokenStream {
+        self.generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Type(param) => {
+                    let mut param = param.clone();
+                    param.eq_token = None;
+                    param.default = None;
+
+                    quote!(#param,)
+                }
+                GenericParam::Const(param) => {
+                    let mut param = param.clone();
+                    param.eq_token = None;
+                    param.default = None;
+
+                    quote!(#param,)
+                }
+                GenericParam::Lifetime(param) => quote!(#param,),
+            })
+            .collect()
+    }
+
+    /// The generic arguments of the container, every argument is followed by a comma.
+    pub(crate) fn args(&self) -> TokenStream {
+        self.generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Type(param) => {
+                    let ident = &param.ident;
+                    quote!(#ident,)
+                }
+                GenericParam::Const(param) => {
+                    let ident = &param.ident;
+                    quote!(#ident,)
+                }
+                GenericParam::Lifetime(param) => {
+                    let lifetime = &param.lifetime;
+                    quote!(#lifetime,)
+                }
+            })
+            .collect()
+    }
+
+    /// A `PhantomData` of the container, used by helper types to carry the generics.
+    pub(crate) fn marker(&self) -> TokenStream {
+        let ty = self.ty();
+
+        quote!(::core::marker::PhantomData<fn() -> *const #ty>)
+    }
+
+    /// The where clause of the container, optionally extended with the given bounds.
+    ///
+    /// The returned tokens always start with `where`.
+    pub(crate) fn where_clause(&self, bounds: Option<Bounds>) -> TokenStream {
+        let predicates = self
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|clause| &clause.predicates);
+
+        let types: Vec<_> = self
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect();
+
+        let bounds = match bounds {
+            None => TokenStream::new(),
+            Some(Bounds::Deserialize | Bounds::Visitor) if types.is_empty() => TokenStream::new(),
+            Some(Bounds::Deserialize) => quote! {
+                #(#types: ::deer::Deserialize<'de>,)*
+            },
+            Some(Bounds::Visitor) => {
+                let ty = self.ty();
+
+                quote! {
+                    #(#types: ::deer::Deserialize<'de>,)*
+                    #ty: ::deer::Reflection,
+                }
+            }
+            Some(Bounds::Reflection) => quote! {
+                #(#types: ::deer::Deserialize<'static> + 'static,)*
+            },
+        };
+
+        quote!(where #(#predicates,)* #bounds)
+    }
+}
@@ -0,0 +1,761 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataEnum, Ident, Result, Type};
+
+use crate::{
+    attributes::{NameAttributes, Tagging},
+    container::{Bounds, Container},
+    fields::{self, Expand, Field, Fields},
+    identifier::{Identifier, Kind},
+};
+
+enum Style<'a> {
+    Unit,
+    Newtype(&'a Type),
+    Tuple(Vec<Field<'a>>),
+    Struct(Vec<Field<'a>>),
+}
+
+struct Variant<'a> {
+    ident: &'a Ident,
+    name: String,
+    style: Style<'a>,
+    /// Prefix of all items generated for the variant
+    prefix: String,
+}
+
+impl<'a> Variant<'a> {
+    fn parse(index: usize, variant: &'a syn::Variant) -> Result<Self> {
+        let name = NameAttributes::parse(&variant.ident, &variant.attrs)?.name;
+
+        let style = match Fields::parse(&variant.fields)? {
+            Fields::Unit => Style::Unit,
+            Fields::Unnamed(fields) if fields.len() == 1 => Style::Newtype(fields[0].ty),
+            Fields::Unnamed(fields) => Style::Tuple(fields),
+            Fields::Named(fields) => Style::Struct(fields),
+        };
+
+        Ok(Self {
+            ident: &variant.ident,
+            name,
+            style,
+            prefix: format!("Variant{index}"),
+        })
+    }
+
+    fn item(&self, name: &str) -> Ident {
+        format_ident!("__{}{name}", self.prefix)
+    }
+
+    fn expand_fields<'b>(&'b self, container: &'b Container<'b>) -> Option<Expand<'a, 'b>> {
+        let ident = container.ident;
+        let variant = self.ident;
+
+        let fields = match &self.style {
+            Style::Unit | Style::Newtype(_) => return None,
+            Style::Tuple(fields) | Style::Struct(fields) => fields,
+        };
+
+        Some(Expand {
+            container,
+            prefix: &self.prefix,
+            constructor: quote!(#ident::#variant),
+            fields,
+        })
+    }
+}
+
+/// Generates a `Visitor` for every tuple and struct variant, which deserializes the value of the
+/// variant.
+fn variant_visitor(container: &Container, variant: &Variant) -> TokenStream {
+    let Some(expand) = variant.expand_fields(container) else {
+        return TokenStream::new();
+    };
+
+    let params = container.params();
+    let args = container.args();
+    let ty = container.ty();
+    let marker = container.marker();
+    let where_clause = container.where_clause(None);
+    let visitor_where_clause = container.where_clause(Some(Bounds::Visitor));
+
+    let visitor = variant.item("Visitor");
+
+    let visit = match &variant.style {
+        Style::Unit | Style::Newtype(_) => unreachable!("only variants with fields are expanded"),
+        Style::Tuple(_) => {
+            let function = expand.function("visit_array");
+
+            quote! {
+                fn visit_array<__A>(
+                    self,
+                    array: __A,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                >
+                where
+                    __A: ::deer::ArrayAccess<'de>,
+                {
+                    #function(array)
+                }
+            }
+        }
+        Style::Struct(_) => {
+            let function = expand.function("visit_object");
+
+            quote! {
+                fn visit_object<__A>(
+                    self,
+                    object: __A,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                >
+                where
+                    __A: ::deer::ObjectAccess<'de>,
+                {
+                    #function(object)
+                }
+            }
+        }
+    };
+
+    quote! {
+        struct #visitor<#params>(#marker) #where_clause;
+
+        impl<'de, #params> ::deer::Visitor<'de> for #visitor<#args> #visitor_where_clause {
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                <Self::Value as ::deer::Reflection>::document()
+            }
+
+            #visit
+        }
+    }
+}
+
+/// Generates `__EnumVisitor`, which deserializes the value of a variant, given its discriminant.
+///
+/// This is the representation of externally tagged enums and is used for the content of adjacently
+/// tagged enums.
+fn enum_visitor(container: &Container, variants: &[Variant]) -> TokenStream {
+    let ident = container.ident;
+    let params = container.params();
+    let args = container.args();
+    let ty = container.ty();
+    let marker = container.marker();
+    let where_clause = container.where_clause(None);
+    let visitor_where_clause = container.where_clause(Some(Bounds::Visitor));
+
+    let arms = variants.iter().map(|variant| {
+        let Variant {
+            ident: variant_ident,
+            name,
+            style,
+            ..
+        } = variant;
+
+        let value = match style {
+            // `Option<()>` allows both `none` and `null`, which means that the content of a unit
+            // variant can be omitted or be explicitly `null`
+            Style::Unit => quote! {
+                <::core::option::Option<()> as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .map(|_| #ident::#variant_ident)
+            },
+            Style::Newtype(field) => quote! {
+                <#field as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .map(#ident::#variant_ident)
+            },
+            Style::Tuple(_) => {
+                let visitor = variant.item("Visitor");
+
+                quote! {
+                    deserializer.deserialize_array(#visitor::<#args>(::core::marker::PhantomData))
+                }
+            }
+            Style::Struct(_) => {
+                let visitor = variant.item("Visitor");
+
+                quote! {
+                    deserializer.deserialize_object(#visitor::<#args>(::core::marker::PhantomData))
+                }
+            }
+        };
+
+        quote! {
+            __Discriminant::#variant_ident => #value
+                .attach(::deer::error::Location::Variant(#name))
+                .change_context(::deer::error::VisitorError),
+        }
+    });
+
+    quote! {
+        struct __EnumVisitor<#params>(#marker) #where_clause;
+
+        impl<'de, #params> ::deer::EnumVisitor<'de> for __EnumVisitor<#args> #visitor_where_clause {
+            type Discriminant = __Discriminant;
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                <Self::Value as ::deer::Reflection>::document()
+            }
+
+            fn visit_value<__D>(
+                self,
+                discriminant: Self::Discriminant,
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                match discriminant {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+}
+
+/// Generates `__TagFieldVisitor`, which deserializes the discriminant from the field `tag`.
+///
+/// The tag must be the first field of the object, any other field is reported as unknown.
+fn tag_visitor(tag: &str) -> TokenStream {
+    let variant = Ident::new("Tag", Span::call_site());
+    let identifier = Identifier {
+        ident: format_ident!("__Tag"),
+        kind: Kind::Field,
+        variants: vec![(&variant, tag)],
+    }
+    .expand();
+
+    quote! {
+        #identifier
+
+        struct __TagFieldVisitor;
+
+        impl<'de> ::deer::FieldVisitor<'de> for __TagFieldVisitor {
+            type Key = __Tag;
+            type Value = __Discriminant;
+
+            fn visit_value<__D>(
+                self,
+                _: Self::Key,
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                <__Discriminant as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .attach(::deer::error::Location::Field(#tag))
+                    .change_context(::deer::error::VisitorError)
+            }
+        }
+    }
+}
+
+/// Reads the discriminant from the first field of `object`, returns early if that is not
+/// possible.
+fn visit_tag(tag: &str) -> TokenStream {
+    quote! {
+        let discriminant = match object.field(__TagFieldVisitor) {
+            Some(discriminant) => discriminant.change_context(::deer::error::VisitorError),
+            None => <__Discriminant as ::deer::Deserialize<'de>>::deserialize(
+                ::deer::value::NoneDeserializer::new(object.context()),
+            )
+            .attach(::deer::error::Location::Field(#tag))
+            .change_context(::deer::error::VisitorError),
+        };
+
+        let discriminant = match discriminant {
+            Ok(discriminant) => discriminant,
+            Err(error) => {
+                // Without the discriminant the remaining fields cannot be interpreted, they are
+                // consumed, but not reported.
+                let _: ::core::result::Result<(), _> = object.end();
+
+                return Err(error);
+            }
+        };
+    }
+}
+
+/// Generates the `Visitor` for internally tagged enums.
+///
+/// The tag is the first field of the object, the remaining fields are the fields of the variant.
+fn internal_visitor(container: &Container, variants: &[Variant], tag: &str) -> Result<TokenStream> {
+    let ident = container.ident;
+    let params = container.params();
+    let args = container.args();
+    let ty = container.ty();
+    let marker = container.marker();
+    let where_clause = container.where_clause(None);
+    let visitor_where_clause = container.where_clause(Some(Bounds::Visitor));
+
+    let arms = variants
+        .iter()
+        .map(|variant| {
+            let Variant {
+                ident: variant_ident,
+                name,
+                style,
+                ..
+            } = variant;
+
+            let value = match style {
+                Style::Unit => quote! {
+                    object
+                        .end()
+                        .map(|()| #ident::#variant_ident)
+                        .change_context(::deer::error::VisitorError)
+                },
+                Style::Struct(_) => {
+                    let function = variant
+                        .expand_fields(container)
+                        .map(|expand| expand.function("visit_object"));
+
+                    quote!(#function(object))
+                }
+                Style::Newtype(_) | Style::Tuple(_) => {
+                    return Err(syn::Error::new_spanned(
+                        variant_ident,
+                        "internally tagged enums only support unit and struct variants",
+                    ));
+                }
+            };
+
+            Ok(quote! {
+                __Discriminant::#variant_ident => #value
+                    .attach(::deer::error::Location::Variant(#name)),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let tag_visitor = tag_visitor(tag);
+    let visit_tag = visit_tag(tag);
+
+    Ok(quote! {
+        #tag_visitor
+
+        struct __Visitor<#params>(#marker) #where_clause;
+
+        impl<'de, #params> ::deer::Visitor<'de> for __Visitor<#args> #visitor_where_clause {
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                <Self::Value as ::deer::Reflection>::document()
+            }
+
+            fn visit_object<__A>(
+                self,
+                mut object: __A,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __A: ::deer::ObjectAccess<'de>,
+            {
+                #visit_tag
+
+                match discriminant {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Generates the `Visitor` for adjacently tagged enums.
+///
+/// The tag is the first field of the object, followed by the content, which is deserialized
+/// through `__EnumVisitor`. The content may be omitted for unit variants.
+fn adjacent_visitor(container: &Container, tag: &str, content: &str) -> TokenStream {
+    let params = container.params();
+    let args = container.args();
+    let ty = container.ty();
+    let marker = container.marker();
+    let where_clause = container.where_clause(None);
+    let visitor_where_clause = container.where_clause(Some(Bounds::Visitor));
+
+    let variant = Ident::new("Content", Span::call_site());
+    let content_identifier = Identifier {
+        ident: format_ident!("__Content"),
+        kind: Kind::Field,
+        variants: vec![(&variant, content)],
+    }
+    .expand();
+
+    let tag_visitor = tag_visitor(tag);
+    let visit_tag = visit_tag(tag);
+
+    quote! {
+        #tag_visitor
+
+        #content_identifier
+
+        struct __ContentFieldVisitor<#params> #where_clause {
+            discriminant: __Discriminant,
+            __marker: #marker,
+        }
+
+        impl<'de, #params> ::deer::FieldVisitor<'de> for __ContentFieldVisitor<#args>
+        #visitor_where_clause
+        {
+            type Key = __Content;
+            type Value = #ty;
+
+            fn visit_value<__D>(
+                self,
+                _: Self::Key,
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                ::deer::EnumVisitor::visit_value(
+                    __EnumVisitor::<#args>(::core::marker::PhantomData),
+                    self.discriminant,
+                    deserializer,
+                )
+                .attach(::deer::error::Location::Field(#content))
+            }
+        }
+
+        struct __Visitor<#params>(#marker) #where_clause;
+
+        impl<'de, #params> ::deer::Visitor<'de> for __Visitor<#args> #visitor_where_clause {
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                <Self::Value as ::deer::Reflection>::document()
+            }
+
+            fn visit_object<__A>(
+                self,
+                mut object: __A,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __A: ::deer::ObjectAccess<'de>,
+            {
+                #visit_tag
+
+                let value = match object.try_field(__ContentFieldVisitor {
+                    discriminant,
+                    __marker: ::core::marker::PhantomData,
+                }) {
+                    Ok(value) => value.change_context(::deer::error::VisitorError),
+                    // the content is missing, which is only valid for unit variants
+                    Err(visitor) => ::deer::EnumVisitor::visit_value(
+                        __EnumVisitor::<#args>(::core::marker::PhantomData),
+                        visitor.discriminant,
+                        ::deer::value::NoneDeserializer::new(object.context()),
+                    )
+                    .attach(::deer::error::Location::Field(#content)),
+                };
+
+                (value, object.end().change_context(::deer::error::VisitorError))
+                    .try_collect()
+                    .map(|(value, ())| value)
+                    .change_context(::deer::error::VisitorError)
+            }
+        }
+    }
+}
+
+/// The schema of an object with the given properties, uses `doc` as the document.
+fn object_schema(properties: &[TokenStream], required: &[&str]) -> TokenStream {
+    quote! {
+        ::deer::Schema::new("object")
+            .with("properties", ::deer::helpers::Properties([#(#properties),*]))
+            .with("required", [#(#required),*])
+            .with("additionalProperties", false)
+    }
+}
+
+/// Generates the types describing a single variant.
+///
+/// Returns the generated items and the expression adding the schema of the variant to `doc`.
+fn variant_reflection(
+    container: &Container,
+    variant: &Variant,
+    tagging: &Tagging,
+) -> (TokenStream, TokenStream) {
+    let params = container.params();
+    let args = container.args();
+    let marker = container.marker();
+    let where_clause = container.where_clause(None);
+    let reflection_where_clause = container.where_clause(Some(Bounds::Reflection));
+
+    let Variant { name, style, .. } = variant;
+
+    // the schema of the discriminant of this variant
+    let tag = variant.item("Tag");
+    let mut items = quote! {
+        struct #tag;
+
+        impl ::deer::Reflection for #tag {
+            fn schema(_: &mut ::deer::Document) -> ::deer::Schema {
+                ::deer::Schema::new("string").with("const", #name)
+            }
+        }
+    };
+
+    if matches!((tagging, style), (Tagging::External, Style::Unit)) {
+        return (items, quote!(doc.add::<#tag>()));
+    }
+
+    // the schema of the value of this variant, if it is not inlined
+    let value = match style {
+        Style::Unit => None,
+        Style::Newtype(field) => Some(fields::reflection(field)),
+        Style::Tuple(_) | Style::Struct(_) => {
+            let Some(expand) = variant.expand_fields(container) else {
+                unreachable!("variants with fields are always expanded");
+            };
+
+            let schema = if matches!(style, Style::Tuple(_)) {
+                expand.array_schema()
+            } else {
+                expand.object_schema()
+            };
+
+            let fields = variant.item("Fields");
+            items.extend(quote! {
+                struct #fields<#params>(#marker) #where_clause;
+
+                impl<#params> ::deer::Reflection for #fields<#args> #reflection_where_clause {
+                    fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                        #schema
+                    }
+                }
+            });
+
+            Some(quote!(#fields<#args>))
+        }
+    };
+
+    let schema = match tagging {
+        Tagging::External => {
+            let value = value.unwrap_or_else(|| unreachable!("unit variants are a `const`"));
+
+            object_schema(&[quote!((#name, doc.add::<#value>()))], &[name])
+        }
+        Tagging::Internal { tag: tag_name } => {
+            let mut properties = vec![quote!((#tag_name, doc.add::<#tag>()))];
+
+            if let Some(expand) = variant.expand_fields(container) {
+                properties.extend(expand.properties());
+            }
+
+            object_schema(&properties, &[tag_name])
+        }
+        Tagging::Adjacent {
+            tag: tag_name,
+            content,
+        } => {
+            let mut properties = vec![quote!((#tag_name, doc.add::<#tag>()))];
+            let mut required = vec![tag_name.as_str()];
+
+            // the content of unit variants may be omitted
+            if let Some(value) = value {
+                properties.push(quote!((#content, doc.add::<#value>())));
+                required.push(content);
+            }
+
+            object_schema(&properties, &required)
+        }
+    };
+
+    let reflection = variant.item("");
+    items.extend(quote! {
+        struct #reflection<#params>(#marker) #where_clause;
+
+        impl<#params> ::deer::Reflection for #reflection<#args> #reflection_where_clause {
+            fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                #schema
+            }
+        }
+    });
+
+    (items, quote!(doc.add::<#reflection<#args>>()))
+}
+
+/// Generates the reflection of the enum, every variant is described by its own schema, which are
+/// then combined using `oneOf`.
+///
+/// Externally tagged enums, which only consist of unit variants, are described as a string
+/// instead.
+fn reflection(container: &Container, variants: &[Variant], tagging: &Tagging) -> TokenStream {
+    let params = container.params();
+    let ty = container.ty();
+    let reflection_where_clause = container.where_clause(Some(Bounds::Reflection));
+
+    let (items, references): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .map(|variant| variant_reflection(container, variant, tagging))
+        .unzip();
+
+    let unit_only = variants
+        .iter()
+        .all(|variant| matches!(variant.style, Style::Unit));
+
+    let length = variants.len();
+    let schema = if matches!(tagging, Tagging::External) && unit_only {
+        let names = variants.iter().map(|variant| &variant.name);
+
+        quote! {
+            let names: [&str; #length] = [#(#names),*];
+
+            ::deer::Schema::new("string").with("enum", names)
+        }
+    } else {
+        quote! {
+            let variants: [::deer::schema::Reference; #length] = [#(#references),*];
+
+            ::deer::Schema::new("object").with("oneOf", variants)
+        }
+    };
+
+    quote! {
+        #(#items)*
+
+        #[automatically_derived]
+        impl<#params> ::deer::Reflection for #ty #reflection_where_clause {
+            fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                #schema
+            }
+        }
+    }
+}
+
+pub(crate) fn expand(
+    container: &Container,
+    data: &DataEnum,
+    tagging: &Tagging,
+) -> Result<TokenStream> {
+    let variants = data
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| Variant::parse(index, variant))
+        .collect::<Result<Vec<_>>>()?;
+
+    for (index, variant) in variants.iter().enumerate() {
+        if variants[..index]
+            .iter()
+            .any(|other| other.name == variant.name)
+        {
+            return Err(syn::Error::new_spanned(
+                variant.ident,
+                format!("duplicate variant name `{}`", variant.name),
+            ));
+        }
+    }
+
+    let params = container.params();
+    let args = container.args();
+    let ty = container.ty();
+    let visitor_where_clause = container.where_clause(Some(Bounds::Visitor));
+
+    let discriminant = Identifier {
+        ident: format_ident!("__Discriminant"),
+        kind: Kind::Variant,
+        variants: variants
+            .iter()
+            .map(|variant| (variant.ident, variant.name.as_str()))
+            .collect(),
+    }
+    .expand();
+
+    let fields = variants.iter().filter_map(|variant| {
+        let expand = variant.expand_fields(container)?;
+
+        Some(match &variant.style {
+            Style::Struct(_) => expand.visit_object(),
+            _ => expand.visit_array(),
+        })
+    });
+
+    let reflection = reflection(container, &variants, tagging);
+
+    let (visitors, deserialize) = match tagging {
+        Tagging::External => {
+            let variant_visitors = variants
+                .iter()
+                .map(|variant| variant_visitor(container, variant));
+            let enum_visitor = enum_visitor(container, &variants);
+
+            (
+                quote! {
+                    #(#variant_visitors)*
+
+                    #enum_visitor
+                },
+                quote!(deserializer.deserialize_enum(__EnumVisitor::<#args>(::core::marker::PhantomData))),
+            )
+        }
+        Tagging::Internal { tag } => (
+            internal_visitor(container, &variants, tag)?,
+            quote!(deserializer.deserialize_object(__Visitor::<#args>(::core::marker::PhantomData))),
+        ),
+        Tagging::Adjacent { tag, content } => {
+            let variant_visitors = variants
+                .iter()
+                .map(|variant| variant_visitor(container, variant));
+            let enum_visitor = enum_visitor(container, &variants);
+            let adjacent_visitor = adjacent_visitor(container, tag, content);
+
+            (
+                quote! {
+                    #(#variant_visitors)*
+
+                    #enum_visitor
+
+                    #adjacent_visitor
+                },
+                quote!(deserializer.deserialize_object(__Visitor::<#args>(::core::marker::PhantomData))),
+            )
+        }
+    };
+
+    Ok(quote! {
+        #discriminant
+
+        #(#fields)*
+
+        #visitors
+
+        #reflection
+
+        #[automatically_derived]
+        impl<'de, #params> ::deer::Deserialize<'de> for #ty #visitor_where_clause {
+            type Reflection = Self;
+
+            fn deserialize<__D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self,
+                ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                #deserialize.change_context(::deer::error::DeserializeError)
+            }
+        }
+    })
+}
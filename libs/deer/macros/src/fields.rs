@@ -0,0 +1,438 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Index, Member, Result, Type};
+
+use crate::{
+    attributes::NameAttributes,
+    container::{Bounds, Container},
+    identifier::{Identifier, Kind},
+};
+
+/// A single field of a struct or an enum variant.
+pub(crate) struct Field<'a> {
+    member: Member,
+    /// The name of the field in the input, only used for named fields
+    name: String,
+    /// The identifier of the local variable holding the value of the field
+    local: Ident,
+    pub(crate) ty: &'a Type,
+}
+
+/// The fields of a struct or an enum variant.
+pub(crate) enum Fields<'a> {
+    Unit,
+    Named(Vec<Field<'a>>),
+    Unnamed(Vec<Field<'a>>),
+}
+
+impl<'a> Fields<'a> {
+    pub(crate) fn parse(fields: &'a syn::Fields) -> Result<Self> {
+        let parse = |(index, field): (usize, &'a syn::Field)| {
+            let local = format_ident!("__field{index}");
+
+            if let Some(ident) = &field.ident {
+                return Ok(Field {
+                    member: Member::Named(ident.clone()),
+                    name: NameAttributes::parse(ident, &field.attrs)?.name,
+                    local,
+                    ty: &field.ty,
+                });
+            }
+
+            if let Some(attribute) = field.attrs.iter().find(|attr| attr.path().is_ident("deer")) {
+                return Err(syn::Error::new_spanned(
+                    attribute,
+                    "`deer` attributes are not supported on unnamed fields",
+                ));
+            }
+
+            Ok(Field {
+                member: Member::Unnamed(Index::from(index)),
+                name: index.to_string(),
+                local,
+                ty: &field.ty,
+            })
+        };
+
+        match fields {
+            syn::Fields::Unit => Ok(Self::Unit),
+            syn::Fields::Named(fields) => {
+                let fields = fields
+                    .named
+                    .iter()
+                    .enumerate()
+                    .map(parse)
+                    .collect::<Result<Vec<_>>>()?;
+
+                for (index, field) in fields.iter().enumerate() {
+                    if fields[..index].iter().any(|other| other.name == field.name) {
+                        return Err(syn::Error::new_spanned(
+                            &field.member,
+                            format!("duplicate field name `{}`", field.name),
+                        ));
+                    }
+                }
+
+                Ok(Self::Named(fields))
+            }
+            syn::Fields::Unnamed(fields) => fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(parse)
+                .collect::<Result<_>>()
+                .map(Self::Unnamed),
+        }
+    }
+}
+
+/// The reflection of a field, only available if the type parameters are bound by
+/// [`Bounds::Reflection`].
+pub(crate) fn reflection(ty: &Type) -> TokenStream {
+    quote!(<#ty as ::deer::Deserialize<'static>>::Reflection)
+}
+
+/// Generates the code to deserialize the fields of a struct or an enum variant.
+///
+/// Every item generated is prefixed with `prefix`, so that multiple sets of fields can be
+/// generated in the same scope.
+pub(crate) struct Expand<'a, 'b> {
+    pub(crate) container: &'b Container<'b>,
+    pub(crate) prefix: &'b str,
+    /// The path used to construct the value, either the type or the enum variant
+    pub(crate) constructor: TokenStream,
+    pub(crate) fields: &'b [Field<'a>],
+}
+
+impl Expand<'_, '_> {
+    pub(crate) fn ident(&self, name: &str) -> Ident {
+        format_ident!("__{}{name}", self.prefix)
+    }
+
+    pub(crate) fn function(&self, name: &str) -> Ident {
+        if self.prefix.is_empty() {
+            format_ident!("__{name}")
+        } else {
+            format_ident!("__{}_{name}", self.prefix.to_lowercase())
+        }
+    }
+
+    /// Constructs the value from the local variables of all fields.
+    fn construct(&self) -> TokenStream {
+        let Self {
+            constructor,
+            fields,
+            ..
+        } = self;
+
+        let members = fields.iter().map(|field| &field.member);
+        let locals = fields.iter().map(|field| &field.local);
+
+        quote!(#constructor { #(#members: #locals),* })
+    }
+
+    /// Ensures that every local variable of all fields has a value, it is expected that `errors`
+    /// has an error for every variable that is `None`.
+    fn unwrap(&self) -> TokenStream {
+        if self.fields.is_empty() {
+            return TokenStream::new();
+        }
+
+        let locals: Vec<_> = self.fields.iter().map(|field| &field.local).collect();
+
+        quote! {
+            let (#(::core::option::Option::Some(#locals),)*) = (#(#locals,)*) else {
+                unreachable!("every missing value has been reported as an error");
+            };
+        }
+    }
+
+    /// The property of every field, used in the `properties` of an object schema.
+    pub(crate) fn properties(&self) -> Vec<TokenStream> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let name = &field.name;
+                let reflection = reflection(field.ty);
+
+                quote!((#name, doc.add::<#reflection>()))
+            })
+            .collect()
+    }
+
+    /// The schema of the fields if deserialized from an object, uses `doc` as the document.
+    pub(crate) fn object_schema(&self) -> TokenStream {
+        let properties = self.properties();
+
+        quote! {
+            ::deer::Schema::new("object")
+                .with("properties", ::deer::helpers::Properties([#(#properties),*]))
+                .with("additionalProperties", false)
+        }
+    }
+
+    /// The schema of the fields if deserialized from an array, uses `doc` as the document.
+    pub(crate) fn array_schema(&self) -> TokenStream {
+        let length = self.fields.len();
+        let items = self.fields.iter().map(|field| {
+            let reflection = reflection(field.ty);
+
+            quote!(doc.add::<#reflection>())
+        });
+
+        quote! {{
+            let items: [::deer::schema::Reference; #length] = [#(#items),*];
+
+            ::deer::Schema::new("array")
+                .with("prefixItems", items)
+                .with("items", false)
+        }}
+    }
+
+    /// Generates a function, named `visit_array`, which deserializes the fields from an array.
+    ///
+    /// Missing items are deserialized from `none`, which allows for optional values.
+    pub(crate) fn visit_array(&self) -> TokenStream {
+        let Self {
+            container, fields, ..
+        } = self;
+
+        let function = self.function("visit_array");
+        let params = container.params();
+        let ty = container.ty();
+        let where_clause = container.where_clause(Some(Bounds::Deserialize));
+
+        let length = fields.len();
+        let items = fields.iter().enumerate().map(|(index, field)| {
+            let Field { local, ty, .. } = field;
+
+            quote! {
+                let #local = errors.attempt(
+                    array
+                        .next()
+                        .unwrap_or_else(|| {
+                            <#ty as ::deer::Deserialize<'de>>::deserialize(
+                                ::deer::value::NoneDeserializer::new(array.context()),
+                            )
+                            .change_context(::deer::error::ArrayAccessError)
+                        })
+                        .attach(::deer::error::Location::Tuple(#index))
+                        .change_context(::deer::error::VisitorError),
+                );
+            }
+        });
+
+        let unwrap = self.unwrap();
+        let construct = self.construct();
+
+        quote! {
+            fn #function<'de, __A, #params>(
+                array: __A,
+            ) -> ::core::result::Result<
+                #ty,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            #where_clause
+                __A: ::deer::ArrayAccess<'de>,
+            {
+                let mut array = array
+                    .into_bound(#length)
+                    .change_context(::deer::error::VisitorError)?;
+
+                let mut errors =
+                    ::deer::export::error_stack::ReportSink::<::deer::error::VisitorError>::new();
+
+                #(#items)*
+
+                if let Err(error) = array.end() {
+                    errors.append(error.change_context(::deer::error::VisitorError));
+                }
+
+                errors.finish().change_context(::deer::error::VisitorError)?;
+
+                #unwrap
+
+                Ok(#construct)
+            }
+        }
+    }
+
+    /// The identifiers of all named fields.
+    fn members(&self) -> Vec<&Ident> {
+        self.fields
+            .iter()
+            .map(|field| match &field.member {
+                Member::Named(ident) => ident,
+                Member::Unnamed(_) => unreachable!("only named fields are visited as object"),
+            })
+            .collect()
+    }
+
+    /// Generates the identifier of all fields and a `FieldVisitor`, which stores the value of
+    /// every field in the referenced local variable.
+    ///
+    /// A field that has already been seen is reported as duplicate.
+    fn field_visitor(&self) -> TokenStream {
+        let Self {
+            container, fields, ..
+        } = self;
+
+        let identifier = self.ident("Identifier");
+        let field_visitor = self.ident("FieldVisitor");
+
+        let params = container.params();
+        let args = container.args();
+        let marker = container.marker();
+        let where_clause = container.where_clause(None);
+        let de_where_clause = container.where_clause(Some(Bounds::Deserialize));
+
+        let members = self.members();
+        let names: Vec<_> = fields.iter().map(|field| field.name.as_str()).collect();
+        let types: Vec<_> = fields.iter().map(|field| field.ty).collect();
+
+        let identifier_enum = Identifier {
+            ident: identifier.clone(),
+            kind: Kind::Field,
+            variants: members.iter().copied().zip(names.iter().copied()).collect(),
+        }
+        .expand();
+
+        quote! {
+            #identifier_enum
+
+            struct #field_visitor<'__a, #params> #where_clause {
+                #(#members: &'__a mut ::core::option::Option<::core::option::Option<#types>>,)*
+                __marker: #marker,
+            }
+
+            impl<'__a, 'de, #params> ::deer::FieldVisitor<'de> for #field_visitor<'__a, #args>
+            #de_where_clause
+            {
+                type Key = #identifier;
+                type Value = ();
+
+                fn visit_value<__D>(
+                    self,
+                    key: Self::Key,
+                    deserializer: __D,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                >
+                where
+                    __D: ::deer::Deserializer<'de>,
+                {
+                    match key {
+                        #(#identifier::#members => {
+                            let value = <#types as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                                .attach(::deer::error::Location::Field(#names))
+                                .change_context(::deer::error::VisitorError);
+
+                            if self.#members.is_some() {
+                                value?;
+
+                                return Err(::deer::export::error_stack::Report::new(
+                                    ::deer::error::Variant::into_error(
+                                        ::deer::error::DuplicateFieldError,
+                                    ),
+                                )
+                                .attach(::deer::error::DuplicateField::new(#names))
+                                .change_context(::deer::error::VisitorError));
+                            }
+
+                            // a value that failed to deserialize is still marked as seen, so that
+                            // it isn't reported as missing
+                            let (value, result) = match value {
+                                Ok(value) => (::core::option::Option::Some(value), Ok(())),
+                                Err(error) => (::core::option::Option::None, Err(error)),
+                            };
+
+                            *self.#members = ::core::option::Option::Some(value);
+
+                            result
+                        })*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates a function, named `visit_object`, which deserializes the named fields from an
+    /// object.
+    ///
+    /// Every missing, unknown and duplicate field is reported. Missing fields are deserialized from
+    /// `none`, which allows for optional values.
+    pub(crate) fn visit_object(&self) -> TokenStream {
+        let Self {
+            container, fields, ..
+        } = self;
+
+        let field_visitor = self.ident("FieldVisitor");
+        let function = self.function("visit_object");
+
+        let params = container.params();
+        let args = container.args();
+        let ty = container.ty();
+        let de_where_clause = container.where_clause(Some(Bounds::Deserialize));
+
+        let members = self.members();
+        let names: Vec<_> = fields.iter().map(|field| field.name.as_str()).collect();
+        let locals: Vec<_> = fields.iter().map(|field| &field.local).collect();
+        let types: Vec<_> = fields.iter().map(|field| field.ty).collect();
+
+        let field_visitor_impl = self.field_visitor();
+        let unwrap = self.unwrap();
+        let construct = self.construct();
+
+        quote! {
+            #field_visitor_impl
+
+            fn #function<'de, __A, #params>(
+                mut object: __A,
+            ) -> ::core::result::Result<
+                #ty,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            #de_where_clause
+                __A: ::deer::ObjectAccess<'de>,
+            {
+                #(let mut #locals = ::core::option::Option::None;)*
+
+                let mut errors =
+                    ::deer::export::error_stack::ReportSink::<::deer::error::VisitorError>::new();
+
+                while let Some(field) = object.field(#field_visitor::<#args> {
+                    #(#members: &mut #locals,)*
+                    __marker: ::core::marker::PhantomData,
+                }) {
+                    if let Err(error) = field {
+                        errors.append(error.change_context(::deer::error::VisitorError));
+                    }
+                }
+
+                #(
+                    let #locals = match #locals {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => errors.attempt(
+                            <#types as ::deer::Deserialize<'de>>::deserialize(
+                                ::deer::value::NoneDeserializer::new(object.context()),
+                            )
+                            .attach(::deer::error::Location::Field(#names))
+                            .change_context(::deer::error::VisitorError),
+                        ),
+                    };
+                )*
+
+                if let Err(error) = object.end() {
+                    errors.append(error.change_context(::deer::error::VisitorError));
+                }
+
+                errors.finish().change_context(::deer::error::VisitorError)?;
+
+                #unwrap
+
+                Ok(#construct)
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::Ident;
+
+/// What an identifier is used for, this determines the error reported for unknown values.
+#[derive(Copy, Clone)]
+pub(crate) enum Kind {
+    Field,
+    Variant,
+}
+
+impl Kind {
+    fn errors(self) -> (TokenStream, TokenStream, TokenStream) {
+        match self {
+            Self::Field => (
+                quote!(::deer::error::UnknownFieldError),
+                quote!(::deer::error::ExpectedField),
+                quote!(::deer::error::ReceivedField),
+            ),
+            Self::Variant => (
+                quote!(::deer::error::UnknownVariantError),
+                quote!(::deer::error::ExpectedVariant),
+                quote!(::deer::error::ReceivedVariant),
+            ),
+        }
+    }
+}
+
+/// An enum with one unit variant for every name, which can be deserialized from a string.
+///
+/// Unknown names are reported as unknown field or variant respectively, including all names
+/// which would have been valid.
+pub(crate) struct Identifier<'a> {
+    pub(crate) ident: Ident,
+    pub(crate) kind: Kind,
+    /// The variants of the generated enum and the name they are deserialized from
+    pub(crate) variants: Vec<(&'a Ident, &'a str)>,
+}
+
+impl Identifier<'_> {
+    pub(crate) fn expand(&self) -> TokenStream {
+        let Self {
+            ident,
+            kind,
+            variants,
+        } = self;
+
+        let visitor = format_ident!("{ident}Visitor");
+        let (error, expected, received) = kind.errors();
+
+        let length = variants.len();
+        let idents: Vec<_> = variants.iter().map(|(ident, _)| *ident).collect();
+        let names: Vec<_> = variants.iter().map(|(_, name)| *name).collect();
+        let bytes: Vec<_> = names
+            .iter()
+            .map(|name| Literal::byte_string(name.as_bytes()))
+            .collect();
+
+        let unknown = quote! {
+            ::deer::export::error_stack::Report::new(::deer::error::Variant::into_error(#error))
+                #(.attach(#expected::new(#names)))*
+        };
+
+        quote! {
+            #[allow(non_camel_case_types)]
+            enum #ident {
+                #(#idents,)*
+            }
+
+            impl ::deer::Reflection for #ident {
+                fn schema(_: &mut ::deer::Document) -> ::deer::Schema {
+                    let names: [&str; #length] = [#(#names),*];
+
+                    ::deer::Schema::new("string").with("enum", names)
+                }
+            }
+
+            struct #visitor;
+
+            impl ::deer::Visitor<'_> for #visitor {
+                type Value = #ident;
+
+                fn expecting(&self) -> ::deer::Document {
+                    <#ident as ::deer::Reflection>::document()
+                }
+
+                fn visit_str(
+                    self,
+                    value: &str,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                > {
+                    match value {
+                        #(#names => Ok(#ident::#idents),)*
+                        _ => Err(#unknown
+                            .attach(#received::new(value))
+                            .change_context(::deer::error::VisitorError)),
+                    }
+                }
+
+                fn visit_bytes(
+                    self,
+                    value: &[u8],
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                > {
+                    match value {
+                        #(#bytes => Ok(#ident::#idents),)*
+                        _ => {
+                            let mut error = #unknown;
+
+                            if let Ok(value) = ::core::str::from_utf8(value) {
+                                error = error.attach(#received::new(value));
+                            }
+
+                            Err(error.change_context(::deer::error::VisitorError))
+                        }
+                    }
+                }
+            }
+
+            impl<'de> ::deer::Deserialize<'de> for #ident {
+                type Reflection = Self;
+
+                fn deserialize<__D>(
+                    deserializer: __D,
+                ) -> ::core::result::Result<
+                    Self,
+                    ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+                >
+                where
+                    __D: ::deer::Deserializer<'de>,
+                {
+                    deserializer
+                        .deserialize_str(#visitor)
+                        .change_context(::deer::error::DeserializeError)
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,40 @@
-//! Intentionally left blank for now!
+//! Derive macros for `deer`.
+//!
+//! `#[derive(Deserialize)]` implements `Deserialize` and `Reflection` for structs and enums.
+//! Like every other implementation in `deer` the generated code is fail-slow: every missing,
+//! unknown and duplicate field is reported, instead of only the first one.
+//!
+//! # Structs
+//!
+//! * unit structs are deserialized like `()`
+//! * tuple structs with a single field are deserialized like the field
+//! * tuple structs with multiple fields are deserialized from an array
+//! * structs with named fields are deserialized from an object or an array
+//!
+//! Missing fields are deserialized from `none`, which means that fields of type `Option<T>` are
+//! optional.
+//!
+//! # Enums
+//!
+//! By default enums are externally tagged (`{"variant": value}`), unit variants can also be
+//! deserialized from just the name of the variant (`"variant"`). The representation can be
+//! changed using container attributes:
+//!
+//! * `#[deer(tag = "type")]`: internally tagged, `{"type": "variant", ...fields}`, only unit and
+//!   struct variants are supported
+//! * `#[deer(tag = "type", content = "value")]`: adjacently tagged, `{"type": "variant", "value":
+//!   value}`, the content may be omitted for unit variants
+//!
+//! Internally and adjacently tagged enums require the tag to be the first field of the object.
+//!
+//! # Attributes
+//!
+//! * `#[deer(rename = "name")]` on a named field or a variant changes the name it is deserialized
+//!   from.
+//!
+//! # Limitations
+//!
+//! `deer` only deserializes owned data, types with lifetime parameters are not supported.
 
 #![warn(
     missing_docs,
@@ -8,3 +44,84 @@
     clippy::mod_module_files
 )]
 #![forbid(unsafe_code)]
+#![expect(
+    clippy::redundant_pub_crate,
+    reason = "`unreachable_pub` requires `pub(crate)` for crate internal items"
+)]
+#![expect(
+    clippy::field_scoped_visibility_modifiers,
+    reason = "code generation helpers are plain data, accessors would only add noise"
+)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Result, parse_macro_input};
+
+use crate::{
+    attributes::{ContainerAttributes, Tagging},
+    container::Container,
+};
+
+mod attributes;
+mod container;
+mod enums;
+mod fields;
+mod identifier;
+mod structs;
+
+fn expand(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let container = Container::new(&input.ident, &input.generics)?;
+    let attributes = ContainerAttributes::parse(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if !matches!(attributes.tagging, Tagging::External) {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`tag` and `content` are only supported on enums",
+                ));
+            }
+
+            structs::expand(&container, data)?
+        }
+        Data::Enum(data) => enums::expand(&container, data, &attributes.tagging)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`Deserialize` cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(
+            non_camel_case_types,
+            non_snake_case,
+            unreachable_code,
+            clippy::allow_attributes,
+            clippy::min_ident_chars,
+            clippy::too_many_lines
+        )]
+        const _: () = {
+            use ::deer::{
+                ArrayAccess as _, Deserializer as _, ObjectAccess as _,
+                export::error_stack::{ResultExt as _, TryReportTupleExt as _},
+            };
+
+            #body
+        };
+    })
+}
+
+/// Implements `Deserialize` and `Reflection` for a struct or enum.
+///
+/// See the [crate documentation](crate) for the supported representations and attributes.
+#[proc_macro_derive(Deserialize, attributes(deer))]
+pub fn derive_deserialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,234 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataStruct, Result, Type};
+
+use crate::{
+    container::{Bounds, Container},
+    fields::{Expand, Fields},
+};
+
+/// A struct without any fields, it is deserialized the same way as `()`.
+fn expand_unit(container: &Container) -> TokenStream {
+    let ident = container.ident;
+    let params = container.params();
+    let ty = container.ty();
+    let where_clause = container.where_clause(Some(Bounds::Deserialize));
+
+    quote! {
+        #[automatically_derived]
+        impl<'de, #params> ::deer::Deserialize<'de> for #ty #where_clause {
+            type Reflection = <() as ::deer::Deserialize<'de>>::Reflection;
+
+            fn deserialize<__D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self,
+                ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                <() as ::deer::Deserialize<'de>>::deserialize(deserializer).map(|()| #ident {})
+            }
+        }
+    }
+}
+
+/// A struct with a single unnamed field, it is deserialized the same way as the field.
+fn expand_newtype(container: &Container, field: &Type) -> TokenStream {
+    let ident = container.ident;
+    let params = container.params();
+    let ty = container.ty();
+    let where_clause = container.where_clause(Some(Bounds::Deserialize));
+
+    quote! {
+        #[automatically_derived]
+        impl<'de, #params> ::deer::Deserialize<'de> for #ty #where_clause {
+            type Reflection = <#field as ::deer::Deserialize<'de>>::Reflection;
+
+            fn deserialize<__D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self,
+                ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                <#field as ::deer::Deserialize<'de>>::deserialize(deserializer).map(#ident)
+            }
+        }
+    }
+}
+
+/// A struct with named fields, which is deserialized from either an object or an array.
+fn expand_named(container: &Container, expand: &Expand) -> TokenStream {
+    let params = container.params();
+    let args = container.args();
+    let ty = container.ty();
+    let marker = container.marker();
+    let where_clause = container.where_clause(None);
+    let visitor_where_clause = container.where_clause(Some(Bounds::Visitor));
+    let reflection_where_clause = container.where_clause(Some(Bounds::Reflection));
+
+    let visit_object = expand.visit_object();
+    let visit_object_fn = expand.function("visit_object");
+    let visit_array = expand.visit_array();
+    let visit_array_fn = expand.function("visit_array");
+    let schema = expand.object_schema();
+
+    quote! {
+        #visit_object
+
+        #visit_array
+
+        struct __Visitor<#params>(#marker) #where_clause;
+
+        impl<'de, #params> ::deer::StructVisitor<'de> for __Visitor<#args> #visitor_where_clause {
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                <Self::Value as ::deer::Reflection>::document()
+            }
+
+            fn visit_array<__A>(
+                self,
+                array: __A,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __A: ::deer::ArrayAccess<'de>,
+            {
+                #visit_array_fn(array)
+            }
+
+            fn visit_object<__A>(
+                self,
+                object: __A,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __A: ::deer::ObjectAccess<'de>,
+            {
+                #visit_object_fn(object)
+            }
+        }
+
+        #[automatically_derived]
+        impl<#params> ::deer::Reflection for #ty #reflection_where_clause {
+            fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                #schema
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de, #params> ::deer::Deserialize<'de> for #ty #visitor_where_clause {
+            type Reflection = Self;
+
+            fn deserialize<__D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self,
+                ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                deserializer
+                    .deserialize_struct(__Visitor::<#args>(::core::marker::PhantomData))
+                    .change_context(::deer::error::DeserializeError)
+            }
+        }
+    }
+}
+
+/// A struct with multiple unnamed fields, which is deserialized from an array.
+fn expand_tuple(container: &Container, expand: &Expand) -> TokenStream {
+    let params = container.params();
+    let args = container.args();
+    let ty = container.ty();
+    let marker = container.marker();
+    let where_clause = container.where_clause(None);
+    let visitor_where_clause = container.where_clause(Some(Bounds::Visitor));
+    let reflection_where_clause = container.where_clause(Some(Bounds::Reflection));
+
+    let visit_array = expand.visit_array();
+    let visit_array_fn = expand.function("visit_array");
+    let schema = expand.array_schema();
+
+    quote! {
+        #visit_array
+
+        struct __Visitor<#params>(#marker) #where_clause;
+
+        impl<'de, #params> ::deer::Visitor<'de> for __Visitor<#args> #visitor_where_clause {
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                <Self::Value as ::deer::Reflection>::document()
+            }
+
+            fn visit_array<__A>(
+                self,
+                array: __A,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                __A: ::deer::ArrayAccess<'de>,
+            {
+                #visit_array_fn(array)
+            }
+        }
+
+        #[automatically_derived]
+        impl<#params> ::deer::Reflection for #ty #reflection_where_clause {
+            fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                #schema
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de, #params> ::deer::Deserialize<'de> for #ty #visitor_where_clause {
+            type Reflection = Self;
+
+            fn deserialize<__D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<
+                Self,
+                ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+            >
+            where
+                __D: ::deer::Deserializer<'de>,
+            {
+                deserializer
+                    .deserialize_array(__Visitor::<#args>(::core::marker::PhantomData))
+                    .change_context(::deer::error::DeserializeError)
+            }
+        }
+    }
+}
+
+pub(crate) fn expand(container: &Container, data: &DataStruct) -> Result<TokenStream> {
+    let fields = Fields::parse(&data.fields)?;
+    let ident = container.ident;
+
+    let expand = |fields| Expand {
+        container,
+        prefix: "",
+        constructor: quote!(#ident),
+        fields,
+    };
+
+    Ok(match &fields {
+        Fields::Unit => expand_unit(container),
+        Fields::Unnamed(unnamed) if unnamed.len() == 1 => expand_newtype(container, unnamed[0].ty),
+        Fields::Unnamed(unnamed) => expand_tuple(container, &expand(unnamed)),
+        Fields::Named(named) => expand_named(container, &expand(named)),
+    })
+}
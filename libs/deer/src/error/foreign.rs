@@ -0,0 +1,48 @@
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{format, string::String};
+use core::fmt::{self, Display, Formatter};
+
+use super::{ErrorProperties, Id, Location, NAMESPACE, Namespace, Variant};
+use crate::id;
+
+/// Error reported by a data format which is not implemented in `deer`, like any `serde` data
+/// format used through [`SerdeDeserializer`].
+///
+/// The original error cannot be retained, as foreign errors are neither required to be `Send` nor
+/// `Sync`, instead only the message is kept.
+///
+/// [`SerdeDeserializer`]: crate::serde::SerdeDeserializer
+#[derive(Debug)]
+pub struct ForeignError {
+    message: String,
+}
+
+impl ForeignError {
+    #[must_use]
+    pub fn new(error: &impl Display) -> Self {
+        Self {
+            message: format!("{error}"),
+        }
+    }
+}
+
+impl Display for ForeignError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.message)
+    }
+}
+
+impl Variant for ForeignError {
+    type Properties = (Location,);
+
+    const ID: Id = id!["foreign"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
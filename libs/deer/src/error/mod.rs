@@ -70,6 +70,7 @@ pub use extra::{
     ArrayLengthError, ExpectedLength, ObjectItemsExtraError, ObjectLengthError, ReceivedKey,
     ReceivedLength,
 };
+pub use foreign::ForeignError;
 pub use internal::BoundedContractViolationError;
 pub use location::Location;
 use serde::ser::SerializeMap;
@@ -84,6 +85,7 @@ use crate::error::serialize::{Export, impl_serialize};
 
 mod duplicate;
 mod extra;
+mod foreign;
 mod internal;
 mod location;
 mod macros;
@@ -0,0 +1,21 @@
+use alloc::borrow::{Cow, ToOwned};
+
+use error_stack::Report;
+
+use crate::{Deserialize, Deserializer, error::DeserializeError};
+
+// `Cow` is always deserialized into the owned variant, borrowing from the input would require
+// `T::Owned` to be specialized over `&'de T`.
+impl<'de, T> Deserialize<'de> for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Deserialize<'de>,
+{
+    type Reflection = <T::Owned as Deserialize<'de>>::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        T::Owned::deserialize(deserializer).map(Cow::Owned)
+    }
+}
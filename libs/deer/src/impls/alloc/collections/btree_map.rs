@@ -0,0 +1,47 @@
+use alloc::collections::BTreeMap;
+use core::marker::PhantomData;
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    Deserialize, Deserializer, Document, ObjectAccess, Visitor,
+    error::{DeserializeError, VisitorError},
+    impls::{MapReflection, visit_map},
+};
+
+struct BTreeMapVisitor<K, V>(PhantomData<fn() -> *const BTreeMap<K, V>>);
+
+impl<'de, K, V> Visitor<'de> for BTreeMapVisitor<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    type Value = BTreeMap<K, V>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ObjectAccess<'de>,
+    {
+        visit_map(object, BTreeMap::new())
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for BTreeMap<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    type Reflection = MapReflection<K::Reflection, V::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_object(BTreeMapVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
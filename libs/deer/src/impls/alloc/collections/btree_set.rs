@@ -0,0 +1,39 @@
+use alloc::collections::BTreeSet;
+use core::marker::PhantomData;
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    ArrayAccess, Deserialize, Deserializer, Document, Visitor,
+    error::{DeserializeError, VisitorError},
+    impls::{SetReflection, visit_sequence},
+};
+
+struct BTreeSetVisitor<T>(PhantomData<fn() -> *const T>);
+
+impl<'de, T: Deserialize<'de> + Ord> Visitor<'de> for BTreeSetVisitor<T> {
+    type Value = BTreeSet<T>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ArrayAccess<'de>,
+    {
+        visit_sequence(array, BTreeSet::new())
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Ord> Deserialize<'de> for BTreeSet<T> {
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_array(BTreeSetVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
@@ -0,0 +1,7 @@
+mod borrow;
+mod boxed;
+mod collections;
+mod rc;
+mod string;
+mod sync;
+mod vec;
@@ -0,0 +1,38 @@
+use alloc::{borrow::ToOwned as _, string::String};
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    Deserialize, Deserializer, Document, Reflection as _, Visitor,
+    error::{DeserializeError, VisitorError},
+};
+
+struct StringVisitor;
+
+impl Visitor<'_> for StringVisitor {
+    type Value = String;
+
+    fn expecting(&self) -> Document {
+        str::document()
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(value.to_owned())
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for String {
+    type Reflection = str;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_string(StringVisitor)
+            .change_context(DeserializeError)
+    }
+}
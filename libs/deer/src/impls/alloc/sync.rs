@@ -0,0 +1,36 @@
+use alloc::{string::String, sync::Arc, vec::Vec};
+
+use error_stack::Report;
+
+use crate::{Deserialize, Deserializer, error::DeserializeError};
+
+// Shared ownership is lost when deserializing, every value is deserialized into a new allocation.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Arc<T> {
+    type Reflection = T::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<'de> Deserialize<'de> for Arc<str> {
+    type Reflection = <String as Deserialize<'de>>::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Arc<[T]> {
+    type Reflection = <Vec<T> as Deserialize<'de>>::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
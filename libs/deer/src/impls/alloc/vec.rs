@@ -0,0 +1,41 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    ArrayAccess, Deserialize, Deserializer, Document, Visitor,
+    error::{DeserializeError, VisitorError},
+    impls::{SequenceReflection, preallocation, visit_sequence},
+};
+
+struct VecVisitor<T>(PhantomData<fn() -> *const T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for VecVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let vec = Vec::with_capacity(preallocation(array.size_hint()));
+
+        visit_sequence(array, vec)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_array(VecVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
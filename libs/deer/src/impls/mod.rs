@@ -1,8 +1,23 @@
-use error_stack::Report;
+use ::core::marker::PhantomData;
+use error_stack::{Report, ReportSink, ResultExt as _};
 
-use crate::{Deserialize, Document, OptionalVisitor, error::VisitorError};
+use crate::{
+    ArrayAccess, Deserialize, Document, ObjectAccess, OptionalVisitor, Reflection, Schema,
+    error::{Location, VisitorError},
+};
 
+mod alloc;
 mod core;
+#[cfg(feature = "std")]
+mod std;
+
+/// Upper bound for the amount of items preallocated from a size hint, this ensures that a
+/// malicious size hint cannot exhaust memory before a single item has been deserialized.
+const MAX_PREALLOCATION: usize = 4096;
+
+pub(crate) fn preallocation(size_hint: Option<usize>) -> usize {
+    size_hint.unwrap_or(0).min(MAX_PREALLOCATION)
+}
 
 pub(crate) struct UnitVariantVisitor;
 
@@ -24,3 +39,99 @@ impl OptionalVisitor<'_> for UnitVariantVisitor {
 
     // we do not implement `visit_some` because we do not allow for some values
 }
+
+/// Deserializes every item of `array` into `collection`.
+///
+/// Every item is visited, even if a previous item failed to deserialize, all errors are reported
+/// together.
+pub(crate) fn visit_sequence<'de, A, T, C>(
+    mut array: A,
+    mut collection: C,
+) -> Result<C, Report<VisitorError>>
+where
+    A: ArrayAccess<'de>,
+    T: Deserialize<'de>,
+    C: Extend<T>,
+{
+    let mut errors = ReportSink::new();
+    let mut index = 0;
+
+    while let Some(value) = array.next::<T>() {
+        match value {
+            Ok(value) => collection.extend(Some(value)),
+            Err(error) => errors.append(error.attach(Location::Array(index))),
+        }
+
+        index += 1;
+    }
+
+    if let Err(error) = array.end() {
+        errors.append(error);
+    }
+
+    errors
+        .finish()
+        .map(|()| collection)
+        .change_context(VisitorError)
+}
+
+/// Deserializes every entry of `object` into `collection`.
+///
+/// Every entry is visited, even if a previous entry failed to deserialize, all errors are
+/// reported together.
+pub(crate) fn visit_map<'de, A, K, V, C>(
+    mut object: A,
+    mut collection: C,
+) -> Result<C, Report<VisitorError>>
+where
+    A: ObjectAccess<'de>,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+    C: Extend<(K, V)>,
+{
+    let mut errors = ReportSink::new();
+
+    while let Some(entry) = object.next::<K, V>() {
+        match entry {
+            Ok(entry) => collection.extend(Some(entry)),
+            Err(error) => errors.append(error),
+        }
+    }
+
+    if let Err(error) = object.end() {
+        errors.append(error);
+    }
+
+    errors
+        .finish()
+        .map(|()| collection)
+        .change_context(VisitorError)
+}
+
+pub struct SequenceReflection<T: ?Sized>(PhantomData<fn() -> *const T>);
+
+impl<T: Reflection + ?Sized> Reflection for SequenceReflection<T> {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("array").with("items", doc.add::<T>())
+    }
+}
+
+pub struct SetReflection<T: ?Sized>(PhantomData<fn() -> *const T>);
+
+impl<T: Reflection + ?Sized> Reflection for SetReflection<T> {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("array")
+            .with("items", doc.add::<T>())
+            .with("uniqueItems", true)
+    }
+}
+
+pub struct MapReflection<K: ?Sized, V: ?Sized>(PhantomData<fn() -> *const (*const K, *const V)>);
+
+impl<K: Reflection + ?Sized, V: Reflection + ?Sized> Reflection for MapReflection<K, V> {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("object")
+            .with("propertyNames", doc.add::<K>())
+            .with("additionalProperties", doc.add::<V>())
+    }
+}
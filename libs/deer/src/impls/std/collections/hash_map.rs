@@ -0,0 +1,56 @@
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+use std::collections::HashMap;
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    Deserialize, Deserializer, Document, ObjectAccess, Visitor,
+    error::{DeserializeError, VisitorError},
+    impls::{MapReflection, preallocation, visit_map},
+};
+
+#[expect(clippy::type_complexity)]
+struct HashMapVisitor<K, V, S>(PhantomData<fn() -> *const HashMap<K, V, S>>);
+
+impl<'de, K, V, S> Visitor<'de> for HashMapVisitor<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    type Value = HashMap<K, V, S>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let map =
+            HashMap::with_capacity_and_hasher(preallocation(object.size_hint()), S::default());
+
+        visit_map(object, map)
+    }
+}
+
+impl<'de, K, V, S> Deserialize<'de> for HashMap<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    type Reflection = MapReflection<K::Reflection, V::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_object(HashMapVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
@@ -0,0 +1,52 @@
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+use std::collections::HashSet;
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    ArrayAccess, Deserialize, Deserializer, Document, Visitor,
+    error::{DeserializeError, VisitorError},
+    impls::{SetReflection, preallocation, visit_sequence},
+};
+
+struct HashSetVisitor<T, S>(PhantomData<fn() -> *const HashSet<T, S>>);
+
+impl<'de, T, S> Visitor<'de> for HashSetVisitor<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Value = HashSet<T, S>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let set = HashSet::with_capacity_and_hasher(preallocation(array.size_hint()), S::default());
+
+        visit_sequence(array, set)
+    }
+}
+
+impl<'de, T, S> Deserialize<'de> for HashSet<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_array(HashSetVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
@@ -0,0 +1,176 @@
+use core::str::FromStr;
+use std::net::{
+    AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
+};
+
+use error_stack::{Report, ResultExt as _, TryReportTupleExt as _};
+
+use crate::{
+    ArrayAccess, Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    error::{
+        ArrayAccessError, DeserializeError, ExpectedType, Location, ReceivedValue, ValueError,
+        Variant as _, VisitorError,
+    },
+    value::NoneDeserializer,
+};
+
+fn parse<T>(value: &str, expected: Document) -> Result<T, Report<VisitorError>>
+where
+    T: FromStr<Err = AddrParseError>,
+{
+    value.parse().map_err(|error| {
+        Report::new(error)
+            .change_context(ValueError.into_error())
+            .attach(ExpectedType::new(expected))
+            .attach(ReceivedValue::new(value.to_owned()))
+            .change_context(VisitorError)
+    })
+}
+
+impl Reflection for Ipv4Addr {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("string").with("format", "ipv4")
+    }
+}
+
+impl Reflection for Ipv6Addr {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("string").with("format", "ipv6")
+    }
+}
+
+impl Reflection for IpAddr {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("string").with("anyOf", [doc.add::<Ipv4Addr>(), doc.add::<Ipv6Addr>()])
+    }
+}
+
+macro_rules! impl_ip {
+    ($visitor:ident, $ty:ty) => {
+        struct $visitor;
+
+        impl Visitor<'_> for $visitor {
+            type Value = $ty;
+
+            fn expecting(&self) -> Document {
+                Self::Value::document()
+            }
+
+            fn visit_str(self, value: &str) -> Result<Self::Value, Report<VisitorError>> {
+                parse(value, self.expecting())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            type Reflection = Self;
+
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, Report<DeserializeError>> {
+                deserializer
+                    .deserialize_str($visitor)
+                    .change_context(DeserializeError)
+            }
+        }
+    };
+}
+
+impl_ip!(Ipv4AddrVisitor, Ipv4Addr);
+impl_ip!(Ipv6AddrVisitor, Ipv6Addr);
+impl_ip!(IpAddrVisitor, IpAddr);
+
+/// Socket addresses are deserialized from either their string representation (`ip:port`) or an
+/// array of exactly two items, the ip address and the port.
+macro_rules! impl_socket {
+    ($visitor:ident, $ty:ty, $ip:ty, $new:expr) => {
+        impl Reflection for $ty {
+            /// # Schema
+            ///
+            /// ```json
+            /// {
+            ///     "type": "string",
+            ///     "anyOf": [
+            ///         {"type": "string"},
+            ///         {"type": "array", "prefixItems": [<ip>, <port>], "items": false}
+            ///     ]
+            /// }
+            /// ```
+            fn schema(doc: &mut Document) -> Schema {
+                Schema::new("string").with(
+                    "anyOf",
+                    [
+                        doc.add::<str>(),
+                        doc.add::<<($ip, u16) as Deserialize<'static>>::Reflection>(),
+                    ],
+                )
+            }
+        }
+
+        struct $visitor;
+
+        impl<'de> Visitor<'de> for $visitor {
+            type Value = $ty;
+
+            fn expecting(&self) -> Document {
+                Self::Value::document()
+            }
+
+            fn visit_str(self, value: &str) -> Result<Self::Value, Report<VisitorError>> {
+                parse(value, self.expecting())
+            }
+
+            fn visit_array<A>(self, array: A) -> Result<Self::Value, Report<VisitorError>>
+            where
+                A: ArrayAccess<'de>,
+            {
+                let mut array = array.into_bound(2).change_context(VisitorError)?;
+
+                let ip = array
+                    .next::<$ip>()
+                    .unwrap_or_else(|| {
+                        Deserialize::deserialize(NoneDeserializer::new(array.context()))
+                            .attach(Location::Tuple(0))
+                            .change_context(ArrayAccessError)
+                    })
+                    .attach(Location::Tuple(0));
+                let port = array
+                    .next::<u16>()
+                    .unwrap_or_else(|| {
+                        Deserialize::deserialize(NoneDeserializer::new(array.context()))
+                            .attach(Location::Tuple(1))
+                            .change_context(ArrayAccessError)
+                    })
+                    .attach(Location::Tuple(1));
+
+                let (ip, port, ()) = (ip, port, array.end())
+                    .try_collect()
+                    .change_context(VisitorError)?;
+
+                Ok($new(ip, port))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            type Reflection = Self;
+
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, Report<DeserializeError>> {
+                deserializer
+                    .deserialize_any($visitor)
+                    .change_context(DeserializeError)
+            }
+        }
+    };
+}
+
+impl_socket!(SocketAddrVisitor, SocketAddr, IpAddr, SocketAddr::new);
+impl_socket!(
+    SocketAddrV4Visitor,
+    SocketAddrV4,
+    Ipv4Addr,
+    SocketAddrV4::new
+);
+impl_socket!(SocketAddrV6Visitor, SocketAddrV6, Ipv6Addr, |ip, port| {
+    SocketAddrV6::new(ip, port, 0, 0)
+});
@@ -0,0 +1,48 @@
+use std::path::{Path, PathBuf};
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    Deserialize, Deserializer, Document, Reflection as _, Visitor,
+    error::{DeserializeError, VisitorError},
+};
+
+struct PathBufVisitor;
+
+impl Visitor<'_> for PathBufVisitor {
+    type Value = PathBuf;
+
+    fn expecting(&self) -> Document {
+        str::document()
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(PathBuf::from(value))
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(PathBuf::from(value))
+    }
+}
+
+impl<'de> Deserialize<'de> for PathBuf {
+    type Reflection = str;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_string(PathBufVisitor)
+            .change_context(DeserializeError)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<Path> {
+    type Reflection = str;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        PathBuf::deserialize(deserializer).map(PathBuf::into_boxed_path)
+    }
+}
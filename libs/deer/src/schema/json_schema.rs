@@ -0,0 +1,95 @@
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{format, string::String};
+use core::fmt::Write as _;
+
+use serde::{Serialize, Serializer, ser::SerializeMap as _};
+
+use super::{Document, ExportSchema};
+
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Encodes the name of a definition as a fragment of a JSON pointer.
+///
+/// Type names may contain characters that are not valid in a URI fragment (like `<`, `>` or
+/// spaces), these are percent encoded.
+fn encode(name: &str) -> String {
+    let mut encoded = String::with_capacity(name.len());
+
+    for byte in name.bytes() {
+        match byte {
+            // JSON pointer escapes
+            b'~' => encoded.push_str("~0"),
+            b'/' => encoded.push_str("~1"),
+            // unreserved, sub-delims, `:` and `@` are allowed in a fragment
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'!'
+            | b'$'
+            | b'&'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b'+'
+            | b','
+            | b';'
+            | b'='
+            | b':'
+            | b'@' => encoded.push(char::from(byte)),
+            byte => {
+                write!(encoded, "%{byte:02X}").expect("writing to a string is infallible");
+            }
+        }
+    }
+
+    encoded
+}
+
+fn resolve(name: &str) -> String {
+    format!("#/$defs/{}", encode(name))
+}
+
+/// A [`Document`] exported as JSON Schema (draft 2020-12).
+///
+/// Created through [`Document::json_schema`].
+pub struct JsonSchema<'a> {
+    document: &'a Document,
+}
+
+impl<'a> JsonSchema<'a> {
+    pub(super) const fn new(document: &'a Document) -> Self {
+        Self { document }
+    }
+}
+
+impl Serialize for JsonSchema<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Definitions<'a>(&'a Document);
+
+        impl Serialize for Definitions<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_map(self.0.definitions().map(|(reference, schema)| {
+                    (reference.as_bare(), ExportSchema { schema, resolve })
+                }))
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(3))?;
+
+        map.serialize_entry("$schema", DIALECT)?;
+        map.serialize_entry("$ref", &resolve(&self.document.root().as_bare()))?;
+        map.serialize_entry("$defs", &Definitions(self.document))?;
+
+        map.end()
+    }
+}
@@ -8,6 +8,13 @@ use core::any::{TypeId, type_name};
 
 use serde::{Serialize, Serializer, ser::SerializeMap as _};
 
+use self::rewrite::{Resolve, Rewrite};
+pub use self::{json_schema::JsonSchema, openapi::Components};
+
+mod json_schema;
+mod openapi;
+mod rewrite;
+
 pub trait Reflection: 'static {
     fn schema(doc: &mut Document) -> Schema;
 
@@ -37,6 +44,53 @@ impl Serialize for SerializeSchema<'_> {
     }
 }
 
+struct Nothing;
+
+impl Serialize for Nothing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+/// Serializes a schema as a JSON Schema (draft 2020-12) schema object.
+///
+/// Types that do not exist in JSON Schema are translated and references are rewritten through
+/// `resolve`.
+struct ExportSchema<'a> {
+    schema: &'a Schema,
+    resolve: Resolve,
+}
+
+impl Serialize for ExportSchema<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Self { schema, resolve } = *self;
+
+        let mut map = serializer.serialize_map(None)?;
+
+        for (key, value) in &schema.other {
+            map.serialize_entry(key, &Rewrite::new(value, resolve))?;
+        }
+
+        match schema.ty.as_str() {
+            "bytes" | "binary" => {
+                map.serialize_entry("contentEncoding", "base64")?;
+                map.serialize_entry("type", "string")?;
+            }
+            // the absence of a value, no value is valid
+            "none" => map.serialize_entry("not", &Nothing)?,
+            ty => map.serialize_entry("type", ty)?,
+        }
+
+        map.end()
+    }
+}
+
 // TODO: most likely (in 0.2) we want to actually have a proper schema
 // TODO: this is currently completely untyped, we might want to adhere to a standard, like
 //  JSON-Schema or OpenAPI
@@ -98,9 +152,11 @@ pub struct Reference {
 }
 
 impl Reference {
+    const PREFIX: &'static str = "#/$defs/";
+
     fn as_path(&self) -> String {
         let bare = self.as_bare();
-        format!("#/$defs/{bare}")
+        format!("{}{bare}", Self::PREFIX)
     }
 
     fn as_bare(&self) -> String {
@@ -184,6 +240,25 @@ impl Document {
         this
     }
 
+    /// Exports the document as JSON Schema (draft 2020-12).
+    ///
+    /// Every referenced schema is added to `$defs`, the schema of the document itself is referenced
+    /// from the root.
+    #[must_use]
+    pub const fn json_schema(&self) -> JsonSchema<'_> {
+        JsonSchema::new(self)
+    }
+
+    fn root(&self) -> Reference {
+        self.references[&self.id]
+    }
+
+    fn definitions(&self) -> impl Iterator<Item = (Reference, &Schema)> {
+        self.references
+            .iter()
+            .filter_map(|(key, reference)| self.schemas.get(key).map(|schema| (*reference, schema)))
+    }
+
     // new() ensures that an item of reference always exists
     #[must_use]
     pub fn schema(&self) -> &Schema {
@@ -306,11 +381,12 @@ mod tests {
     use alloc::collections::BTreeMap;
     #[cfg_attr(feature = "std", allow(unused_imports))]
     use alloc::{boxed::Box, vec::Vec};
+    use core::marker::PhantomData;
 
     use serde_json::{json, to_value};
     use similar_asserts::assert_serde_eq;
 
-    use crate::{Document, Reflection, Schema};
+    use crate::{Document, Reflection, Schema, schema::Components};
 
     struct U8;
 
@@ -526,4 +602,98 @@ mod tests {
             })
         );
     }
+
+    struct Bytes;
+
+    impl Reflection for Bytes {
+        fn schema(_: &mut Document) -> Schema {
+            Schema::new("bytes")
+        }
+    }
+
+    struct Generic<T>(PhantomData<fn() -> T>);
+
+    impl<T: Reflection> Reflection for Generic<T> {
+        fn schema(doc: &mut Document) -> Schema {
+            Schema::new("array").with("prefixItems", [doc.add::<T>(), doc.add::<Bytes>()])
+        }
+    }
+
+    #[test]
+    fn json_schema() {
+        let document = Generic::<Node>::document();
+        let document = to_value(document.json_schema()).expect("should be valid json");
+
+        assert_serde_eq!(
+            document,
+            json!({
+              "$schema": "https://json-schema.org/draft/2020-12/schema",
+              "$ref": "#/$defs/0000-deer::schema::tests::Generic%3Cdeer::schema::tests::Node%3E",
+              "$defs": {
+                "0000-deer::schema::tests::Generic<deer::schema::tests::Node>": {
+                  "prefixItems": [
+                    {"$ref": "#/$defs/0001-deer::schema::tests::Node"},
+                    {"$ref": "#/$defs/0002-deer::schema::tests::Bytes"}
+                  ],
+                  "type": "array"
+                },
+                "0001-deer::schema::tests::Node": {
+                  "additionalProperties": false,
+                  "properties": {
+                    "child": {"$ref": "#/$defs/0001-deer::schema::tests::Node"}
+                  },
+                  "type": "object"
+                },
+                "0002-deer::schema::tests::Bytes": {
+                  "contentEncoding": "base64",
+                  "type": "string"
+                }
+              }
+            })
+        );
+    }
+
+    #[test]
+    fn openapi_components() {
+        let generic = Generic::<Node>::document();
+        let node = Node::document();
+
+        let mut components = Components::new();
+        assert_eq!(
+            components.add(&generic),
+            "#/components/schemas/deer.schema.tests.Generic_deer.schema.tests.Node_"
+        );
+        assert_eq!(
+            components.add(&node),
+            "#/components/schemas/deer.schema.tests.Node"
+        );
+
+        let components = to_value(components).expect("should be valid json");
+
+        assert_serde_eq!(
+            components,
+            json!({
+              "schemas": {
+                "deer.schema.tests.Generic_deer.schema.tests.Node_": {
+                  "prefixItems": [
+                    {"$ref": "#/components/schemas/deer.schema.tests.Node"},
+                    {"$ref": "#/components/schemas/deer.schema.tests.Bytes"}
+                  ],
+                  "type": "array"
+                },
+                "deer.schema.tests.Node": {
+                  "additionalProperties": false,
+                  "properties": {
+                    "child": {"$ref": "#/components/schemas/deer.schema.tests.Node"}
+                  },
+                  "type": "object"
+                },
+                "deer.schema.tests.Bytes": {
+                  "contentEncoding": "base64",
+                  "type": "string"
+                }
+              }
+            })
+        );
+    }
 }
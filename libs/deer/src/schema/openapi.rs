@@ -0,0 +1,105 @@
+use alloc::collections::BTreeMap;
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{format, string::String};
+
+use serde::{Serialize, Serializer, ser::SerializeMap as _};
+
+use super::{Document, ExportSchema, Schema};
+
+/// Converts the bare name of a definition into the name of a component.
+///
+/// The identifier of a reference is only unique within a single document, components are shared
+/// between documents and are therefore named after the type only. OpenAPI restricts component
+/// names to `^[a-zA-Z0-9.\-_]+$`, paths are separated with `.`, every other character is replaced
+/// with `_`.
+fn name(bare: &str) -> String {
+    let name = bare.split_once('-').map_or(bare, |(_, name)| name);
+
+    name.replace("::", ".")
+        .chars()
+        .map(|char| {
+            if char.is_ascii_alphanumeric() || matches!(char, '.' | '-' | '_') {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn resolve(bare: &str) -> String {
+    format!("#/components/schemas/{}", name(bare))
+}
+
+/// Schemas of one or more [`Document`]s, exported as the [components] of an OpenAPI 3.1 document.
+///
+/// OpenAPI 3.1 uses JSON Schema (draft 2020-12) as its schema dialect, references between
+/// schemas point to `#/components/schemas`. Schemas referenced by multiple documents are only
+/// included once.
+///
+/// # Example
+///
+/// ```
+/// use deer::{Deserialize as _, schema::Components};
+///
+/// let document = Vec::<u8>::reflection();
+///
+/// let mut components = Components::new();
+/// let reference = components.add(&document);
+///
+/// assert_eq!(
+///     reference,
+///     "#/components/schemas/deer.impls.SequenceReflection_u8_"
+/// );
+/// ```
+///
+/// [components]: https://spec.openapis.org/oas/v3.1.0#components-object
+#[derive(Default)]
+pub struct Components<'a> {
+    schemas: BTreeMap<String, &'a Schema>,
+}
+
+impl<'a> Components<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every schema of `document`.
+    ///
+    /// Returns the reference to the schema of the document itself, which can be used as the
+    /// schema of a request or response body.
+    pub fn add(&mut self, document: &'a Document) -> String {
+        for (reference, schema) in document.definitions() {
+            self.schemas.insert(name(&reference.as_bare()), schema);
+        }
+
+        resolve(&document.root().as_bare())
+    }
+}
+
+impl Serialize for Components<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Schemas<'a>(&'a BTreeMap<String, &'a Schema>);
+
+        impl Serialize for Schemas<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_map(
+                    self.0
+                        .iter()
+                        .map(|(name, schema)| (name, ExportSchema { schema, resolve })),
+                )
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("schemas", &Schemas(&self.schemas))?;
+        map.end()
+    }
+}
@@ -0,0 +1,361 @@
+//! Serializer adapter that rewrites the target of references.
+//!
+//! Schemas store their values type-erased, references can therefore be nested arbitrarily deep
+//! inside of them. Instead of requiring every value to know about the export format, the
+//! serializer is wrapped and every `$ref` that points to a definition is rewritten on the fly.
+
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::string::String;
+use core::fmt::Display;
+
+use serde::{
+    Serialize, Serializer,
+    ser::{
+        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+};
+
+use super::Reference;
+
+/// Converts the bare name of a reference into the path it should point to.
+pub(super) type Resolve = fn(&str) -> String;
+
+pub(super) struct Rewrite<'a, T: ?Sized> {
+    value: &'a T,
+    resolve: Resolve,
+    is_reference: bool,
+}
+
+impl<'a, T: ?Sized> Rewrite<'a, T> {
+    pub(super) const fn new(value: &'a T, resolve: Resolve) -> Self {
+        Self {
+            value,
+            resolve,
+            is_reference: false,
+        }
+    }
+
+    fn field(value: &'a T, resolve: Resolve, key: &str) -> Self {
+        Self {
+            value,
+            resolve,
+            is_reference: key == "$ref",
+        }
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for Rewrite<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(RewriteSerializer {
+            inner: serializer,
+            resolve: self.resolve,
+            is_reference: self.is_reference,
+        })
+    }
+}
+
+struct RewriteSerializer<S> {
+    inner: S,
+    resolve: Resolve,
+    is_reference: bool,
+}
+
+struct Compound<C> {
+    inner: C,
+    resolve: Resolve,
+}
+
+impl<C> Compound<C> {
+    const fn new(inner: C, resolve: Resolve) -> Self {
+        Self { inner, resolve }
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for RewriteSerializer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+
+    forward! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_i128(i128);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_u128(u128);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_bytes(&[u8]);
+        serialize_unit_struct(&'static str);
+    }
+
+    #[expect(
+        clippy::renamed_function_params,
+        reason = "`v` is not a descriptive name"
+    )]
+    fn serialize_str(self, value: &str) -> Result<Self::Ok, Self::Error> {
+        match value.strip_prefix(Reference::PREFIX) {
+            Some(bare) if self.is_reference => self.inner.serialize_str(&(self.resolve)(bare)),
+            _ => self.inner.serialize_str(value),
+        }
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_some(&Rewrite {
+            value,
+            resolve: self.resolve,
+            is_reference: self.is_reference,
+        })
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_newtype_struct(
+            name,
+            &Rewrite {
+                value,
+                resolve: self.resolve,
+                is_reference: self.is_reference,
+            },
+        )
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &Rewrite::new(value, self.resolve),
+        )
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.inner
+            .serialize_seq(len)
+            .map(|inner| Compound::new(inner, self.resolve))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.inner
+            .serialize_tuple(len)
+            .map(|inner| Compound::new(inner, self.resolve))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.inner
+            .serialize_tuple_struct(name, len)
+            .map(|inner| Compound::new(inner, self.resolve))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.inner
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(|inner| Compound::new(inner, self.resolve))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.inner
+            .serialize_map(len)
+            .map(|inner| Compound::new(inner, self.resolve))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.inner
+            .serialize_struct(name, len)
+            .map(|inner| Compound::new(inner, self.resolve))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.inner
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(|inner| Compound::new(inner, self.resolve))
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Display + ?Sized,
+    {
+        self.inner.collect_str(value)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! compound {
+    ($($trait:ident::$method:ident;)*) => {
+        $(
+            impl<C: $trait> $trait for Compound<C> {
+                type Error = C::Error;
+                type Ok = C::Ok;
+
+                fn $method<T>(&mut self, value: &T) -> Result<(), Self::Error>
+                where
+                    T: Serialize + ?Sized,
+                {
+                    self.inner.$method(&Rewrite::new(value, self.resolve))
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+compound! {
+    SerializeSeq::serialize_element;
+    SerializeTuple::serialize_element;
+    SerializeTupleStruct::serialize_field;
+    SerializeTupleVariant::serialize_field;
+}
+
+impl<C: SerializeMap> SerializeMap for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_key(&Rewrite::new(key, self.resolve))
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner
+            .serialize_value(&Rewrite::new(value, self.resolve))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStruct> SerializeStruct for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner
+            .serialize_field(key, &Rewrite::field(value, self.resolve, key))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner
+            .serialize_field(key, &Rewrite::field(value, self.resolve, key))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
@@ -0,0 +1,257 @@
+use error_stack::Report;
+use serde::de::IgnoredAny;
+
+use crate::{
+    Context, Deserialize, FieldVisitor,
+    error::{
+        ArrayAccessError, ArrayLengthError, ExpectedLength, ObjectAccessError, ObjectLengthError,
+        ReceivedLength, Variant as _,
+    },
+    serde::call::{DeserializeCall, KeyCall, Seed, ValueCall, foreign, recover},
+};
+
+/// [`ArrayAccess`] over a `serde` [`SeqAccess`].
+///
+/// Once the underlying format reports an error (instead of an error of a `deer` implementation)
+/// the input can no longer be trusted and no further items are returned.
+///
+/// [`ArrayAccess`]: crate::ArrayAccess
+/// [`SeqAccess`]: serde::de::SeqAccess
+pub(crate) struct SeqAccess<'a, A> {
+    context: &'a Context,
+    access: A,
+
+    dirty: bool,
+    poisoned: bool,
+    consumed: usize,
+}
+
+impl<'a, A> SeqAccess<'a, A> {
+    pub(crate) const fn new(context: &'a Context, access: A) -> Self {
+        Self {
+            context,
+            access,
+            dirty: false,
+            poisoned: false,
+            consumed: 0,
+        }
+    }
+}
+
+impl<'de, A> crate::ArrayAccess<'de> for SeqAccess<'_, A>
+where
+    A: serde::de::SeqAccess<'de>,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, Report<ArrayAccessError>>>
+    where
+        T: Deserialize<'de>,
+    {
+        self.dirty = true;
+
+        if self.poisoned {
+            return None;
+        }
+
+        let mut slot = None;
+        let result = self.access.next_element_seed(Seed::new(
+            self.context,
+            DeserializeCall::<T>::new(),
+            &mut slot,
+        ));
+
+        self.poisoned = result.is_err() && slot.is_none();
+
+        let value = recover(result, slot, ArrayAccessError).transpose()?;
+        self.consumed += 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.access.size_hint()
+    }
+
+    fn end(mut self) -> Result<(), Report<ArrayAccessError>> {
+        if self.poisoned {
+            // the error has already been reported through `next`
+            return Ok(());
+        }
+
+        let mut remaining = 0;
+
+        while self
+            .access
+            .next_element::<IgnoredAny>()
+            .map_err(|error| foreign(&error, ArrayAccessError))?
+            .is_some()
+        {
+            remaining += 1;
+        }
+
+        if remaining == 0 {
+            return Ok(());
+        }
+
+        Err(Report::new(ArrayLengthError.into_error())
+            .attach(ExpectedLength::new(self.consumed))
+            .attach(ReceivedLength::new(self.consumed + remaining))
+            .change_context(ArrayAccessError))
+    }
+}
+
+/// [`ObjectAccess`] over a `serde` [`MapAccess`].
+///
+/// Once the underlying format reports an error (instead of an error of a `deer` implementation)
+/// the input can no longer be trusted and no further entries are returned.
+///
+/// [`ObjectAccess`]: crate::ObjectAccess
+/// [`MapAccess`]: serde::de::MapAccess
+pub(crate) struct MapAccess<'a, A> {
+    context: &'a Context,
+    access: A,
+
+    dirty: bool,
+    poisoned: bool,
+    consumed: usize,
+}
+
+impl<'a, A> MapAccess<'a, A> {
+    pub(crate) const fn new(context: &'a Context, access: A) -> Self {
+        Self {
+            context,
+            access,
+            dirty: false,
+            poisoned: false,
+            consumed: 0,
+        }
+    }
+}
+
+impl<'de, A> MapAccess<'_, A>
+where
+    A: serde::de::MapAccess<'de>,
+{
+    fn value<F>(&mut self, visitor: F, key: F::Key) -> Result<F::Value, Report<ObjectAccessError>>
+    where
+        F: FieldVisitor<'de>,
+    {
+        let mut slot = None;
+        let result = self.access.next_value_seed(Seed::new(
+            self.context,
+            ValueCall::new(visitor, key),
+            &mut slot,
+        ));
+
+        self.poisoned = result.is_err() && slot.is_none();
+
+        recover(result, slot, ObjectAccessError)
+    }
+
+    fn skip_value(&mut self) -> Result<(), Report<ObjectAccessError>> {
+        let result = self.access.next_value::<IgnoredAny>();
+
+        self.poisoned = result.is_err();
+
+        result
+            .map(|_| ())
+            .map_err(|error| foreign(&error, ObjectAccessError))
+    }
+}
+
+impl<'de, A> crate::ObjectAccess<'de> for MapAccess<'_, A>
+where
+    A: serde::de::MapAccess<'de>,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn try_field<F>(&mut self, visitor: F) -> Result<Result<F::Value, Report<ObjectAccessError>>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        self.dirty = true;
+
+        if self.poisoned {
+            return Err(visitor);
+        }
+
+        let mut slot = None;
+        let key =
+            self.access
+                .next_key_seed(Seed::new(self.context, KeyCall::new(&visitor), &mut slot));
+
+        let key = match key {
+            Ok(None) => return Err(visitor),
+            Ok(Some(key)) => Ok(key),
+            Err(error) => match slot {
+                None => {
+                    self.poisoned = true;
+
+                    Err(foreign(&error, ObjectAccessError))
+                }
+                Some(report) => {
+                    // the key is an error, but the input is still intact, we need to swallow the
+                    // value
+                    let mut report = report.change_context(ObjectAccessError).expand();
+
+                    if let Err(error) = self.skip_value() {
+                        report.push(error);
+                    }
+
+                    Err(report.change_context(ObjectAccessError))
+                }
+            },
+        };
+
+        self.consumed += 1;
+
+        Ok(match key {
+            Ok(key) => self.value(visitor, key),
+            Err(error) => Err(error),
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.access.size_hint()
+    }
+
+    fn end(mut self) -> Result<(), Report<ObjectAccessError>> {
+        if self.poisoned {
+            // the error has already been reported through `try_field`
+            return Ok(());
+        }
+
+        let mut remaining = 0;
+
+        while self
+            .access
+            .next_entry::<IgnoredAny, IgnoredAny>()
+            .map_err(|error| foreign(&error, ObjectAccessError))?
+            .is_some()
+        {
+            remaining += 1;
+        }
+
+        if remaining == 0 {
+            return Ok(());
+        }
+
+        Err(Report::new(ObjectLengthError.into_error())
+            .attach(ExpectedLength::new(self.consumed))
+            .attach(ReceivedLength::new(self.consumed + remaining))
+            .change_context(ObjectAccessError))
+    }
+}
@@ -0,0 +1,300 @@
+use core::{fmt::Display, marker::PhantomData};
+
+use error_stack::Report;
+use serde::de::DeserializeSeed;
+
+use crate::{
+    Context, Deserialize, Deserializer, EnumVisitor, FieldVisitor, IdentifierVisitor, Visitor,
+    error::{DeserializeError, DeserializerError, ForeignError, Variant as _, VisitorError},
+    serde::SerdeDeserializer,
+};
+
+/// Records `result` in `slot` if it is an error.
+///
+/// `serde` errors are only able to carry a message, the report is therefore recorded out of band
+/// and retrieved through [`recover`] once `serde` has propagated the error.
+pub(crate) fn stash<T, C, E>(
+    slot: &mut Option<Report<C>>,
+    result: Result<T, Report<C>>,
+) -> Result<T, E>
+where
+    E: serde::de::Error,
+{
+    result.map_err(|report| {
+        *slot = Some(report);
+
+        E::custom("deserialization failed, the error has been recorded by `deer`")
+    })
+}
+
+/// Retrieves the report recorded through [`stash`], if the error didn't originate from `deer` it
+/// is reported as [`ForeignError`].
+pub(crate) fn recover<T, C, E, R>(
+    result: Result<T, E>,
+    slot: Option<Report<C>>,
+    context: R,
+) -> Result<T, Report<R>>
+where
+    E: Display,
+    R: core::error::Error + Send + Sync + 'static,
+{
+    result.map_err(|error| match slot {
+        Some(report) => report.change_context(context),
+        None => foreign(&error, context),
+    })
+}
+
+pub(crate) fn foreign<R>(error: &impl Display, context: R) -> Report<R>
+where
+    R: core::error::Error + Send + Sync + 'static,
+{
+    Report::new(ForeignError::new(error).into_error()).change_context(context)
+}
+
+/// Operation executed on a `deer` [`Deserializer`] once `serde` provides the underlying
+/// deserializer.
+pub(crate) trait Call<'de> {
+    type Value;
+    type Error;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>;
+}
+
+/// [`DeserializeSeed`] which wraps the `serde` deserializer in a [`SerdeDeserializer`] and executes
+/// the [`Call`] on it.
+pub(crate) struct Seed<'a, 'b, C, E> {
+    context: &'a Context,
+    call: C,
+    slot: &'b mut Option<Report<E>>,
+}
+
+impl<'a, 'b, C, E> Seed<'a, 'b, C, E> {
+    pub(crate) const fn new(
+        context: &'a Context,
+        call: C,
+        slot: &'b mut Option<Report<E>>,
+    ) -> Self {
+        Self {
+            context,
+            call,
+            slot,
+        }
+    }
+}
+
+impl<'de, C> DeserializeSeed<'de> for Seed<'_, '_, C, C::Error>
+where
+    C: Call<'de>,
+{
+    type Value = C::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let result = self
+            .call
+            .call(SerdeDeserializer::new(self.context, deserializer));
+
+        stash(self.slot, result)
+    }
+}
+
+pub(crate) struct DeserializeCall<T>(PhantomData<fn() -> *const T>);
+
+impl<T> DeserializeCall<T> {
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, T> Call<'de> for DeserializeCall<T>
+where
+    T: Deserialize<'de>,
+{
+    type Error = DeserializeError;
+    type Value = T;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer)
+    }
+}
+
+pub(crate) struct KeyCall<'a, F>(&'a F);
+
+impl<'a, F> KeyCall<'a, F> {
+    pub(crate) const fn new(visitor: &'a F) -> Self {
+        Self(visitor)
+    }
+}
+
+impl<'de, F> Call<'de> for KeyCall<'_, F>
+where
+    F: FieldVisitor<'de>,
+{
+    type Error = VisitorError;
+    type Value = F::Key;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.visit_key(deserializer)
+    }
+}
+
+pub(crate) struct ValueCall<'de, F: FieldVisitor<'de>> {
+    visitor: F,
+    key: F::Key,
+}
+
+impl<'de, F: FieldVisitor<'de>> ValueCall<'de, F> {
+    pub(crate) const fn new(visitor: F, key: F::Key) -> Self {
+        Self { visitor, key }
+    }
+}
+
+impl<'de, F> Call<'de> for ValueCall<'de, F>
+where
+    F: FieldVisitor<'de>,
+{
+    type Error = VisitorError;
+    type Value = F::Value;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>,
+    {
+        self.visitor.visit_value(self.key, deserializer)
+    }
+}
+
+pub(crate) struct DiscriminantCall<'a, V>(&'a V);
+
+impl<'a, V> DiscriminantCall<'a, V> {
+    pub(crate) const fn new(visitor: &'a V) -> Self {
+        Self(visitor)
+    }
+}
+
+impl<'de, V> Call<'de> for DiscriminantCall<'_, V>
+where
+    V: EnumVisitor<'de>,
+{
+    type Error = VisitorError;
+    type Value = V::Discriminant;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.visit_discriminant(deserializer)
+    }
+}
+
+/// `deserialize_*` method of a [`Deserializer`] which takes a [`Visitor`].
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum Method {
+    Any,
+    Null,
+    Bool,
+    Number,
+    Char,
+    String,
+    Str,
+    Bytes,
+    BytesBuffer,
+    Array,
+    Object,
+}
+
+pub(crate) struct VisitorCall<V> {
+    method: Method,
+    visitor: V,
+}
+
+impl<V> VisitorCall<V> {
+    pub(crate) const fn new(method: Method, visitor: V) -> Self {
+        Self { method, visitor }
+    }
+}
+
+impl<'de, V> Call<'de> for VisitorCall<V>
+where
+    V: Visitor<'de>,
+{
+    type Error = DeserializerError;
+    type Value = V::Value;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = self.visitor;
+
+        match self.method {
+            Method::Any => deserializer.deserialize_any(visitor),
+            Method::Null => deserializer.deserialize_null(visitor),
+            Method::Bool => deserializer.deserialize_bool(visitor),
+            Method::Number => deserializer.deserialize_number(visitor),
+            Method::Char => deserializer.deserialize_char(visitor),
+            Method::String => deserializer.deserialize_string(visitor),
+            Method::Str => deserializer.deserialize_str(visitor),
+            Method::Bytes => deserializer.deserialize_bytes(visitor),
+            Method::BytesBuffer => deserializer.deserialize_bytes_buffer(visitor),
+            Method::Array => deserializer.deserialize_array(visitor),
+            Method::Object => deserializer.deserialize_object(visitor),
+        }
+    }
+}
+
+pub(crate) struct EnumCall<V>(V);
+
+impl<V> EnumCall<V> {
+    pub(crate) const fn new(visitor: V) -> Self {
+        Self(visitor)
+    }
+}
+
+impl<'de, V> Call<'de> for EnumCall<V>
+where
+    V: EnumVisitor<'de>,
+{
+    type Error = DeserializerError;
+    type Value = V::Value;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(self.0)
+    }
+}
+
+pub(crate) struct IdentifierCall<V>(V);
+
+impl<V> IdentifierCall<V> {
+    pub(crate) const fn new(visitor: V) -> Self {
+        Self(visitor)
+    }
+}
+
+impl<'de, V> Call<'de> for IdentifierCall<V>
+where
+    V: IdentifierVisitor<'de>,
+{
+    type Error = DeserializerError;
+    type Value = V::Value;
+
+    fn call<D>(self, deserializer: D) -> Result<Self::Value, Report<Self::Error>>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self.0)
+    }
+}
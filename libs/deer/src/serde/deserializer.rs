@@ -0,0 +1,280 @@
+use error_stack::{Report, ResultExt as _};
+use serde::de::VariantAccess;
+
+use crate::{
+    Context, Deserializer, EnumVisitor, IdentifierVisitor, OptionalVisitor, StructVisitor, Visitor,
+    error::{DeserializerError, VisitorError},
+    serde::{
+        call::{EnumCall, IdentifierCall, Method, Seed, VisitorCall, foreign, recover},
+        visitor::{
+            EnumVisitorAdapter, IdentifierVisitorAdapter, OptionalVisitorAdapter,
+            StructVisitorAdapter, VisitorAdapter,
+        },
+    },
+};
+
+macro_rules! forward {
+    ($($method:ident => $serde:ident),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+            where
+                V: Visitor<'de>,
+            {
+                let mut slot = None;
+                let result = self
+                    .deserializer
+                    .$serde(VisitorAdapter::new(self.context, visitor, &mut slot));
+
+                recover(result, slot, DeserializerError)
+            }
+        )*
+    };
+}
+
+/// `deer` [`Deserializer`] which is driven by any [`serde::Deserializer`].
+///
+/// This allows `deer` to consume every data format that has been implemented for `serde`.
+///
+/// Errors raised by `deer` implementations are reported unchanged, while errors raised by the
+/// data format itself are reported as [`ForeignError`]. Once the data format has reported an
+/// error, the input can no longer be trusted, and no further errors are collected from the
+/// surrounding array or object.
+///
+/// `serde` has no concept of a missing value, `none` in `serde` (like `null` in JSON) is
+/// interpreted as `null`.
+///
+/// [`ForeignError`]: crate::error::ForeignError
+///
+/// # Example
+///
+/// ```
+/// use deer::{Context, Deserialize as _, serde::SerdeDeserializer};
+///
+/// let context = Context::new();
+/// let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3]");
+///
+/// let value = Vec::<u8>::deserialize(SerdeDeserializer::new(&context, &mut deserializer))
+///     .expect("should be able to deserialize");
+///
+/// assert_eq!(value, [1, 2, 3]);
+/// ```
+pub struct SerdeDeserializer<'a, D> {
+    context: &'a Context,
+    deserializer: D,
+}
+
+impl<'a, D> SerdeDeserializer<'a, D> {
+    #[must_use]
+    pub const fn new(context: &'a Context, deserializer: D) -> Self {
+        Self {
+            context,
+            deserializer,
+        }
+    }
+}
+
+impl<'de, D> Deserializer<'de> for SerdeDeserializer<'_, D>
+where
+    D: serde::Deserializer<'de>,
+{
+    forward![
+        deserialize_any => deserialize_any,
+        deserialize_null => deserialize_unit,
+        deserialize_bool => deserialize_bool,
+        // `serde` has no notion of a number, the format decides which type it is
+        deserialize_number => deserialize_any,
+        deserialize_char => deserialize_char,
+        deserialize_string => deserialize_string,
+        deserialize_str => deserialize_str,
+        deserialize_bytes => deserialize_bytes,
+        deserialize_bytes_buffer => deserialize_byte_buf,
+        deserialize_array => deserialize_seq,
+        deserialize_object => deserialize_map,
+        deserialize_i8 => deserialize_i8,
+        deserialize_i16 => deserialize_i16,
+        deserialize_i32 => deserialize_i32,
+        deserialize_i64 => deserialize_i64,
+        deserialize_i128 => deserialize_i128,
+        deserialize_u8 => deserialize_u8,
+        deserialize_u16 => deserialize_u16,
+        deserialize_u32 => deserialize_u32,
+        deserialize_u64 => deserialize_u64,
+        deserialize_u128 => deserialize_u128,
+        deserialize_f32 => deserialize_f32,
+        deserialize_f64 => deserialize_f64,
+    ];
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        let mut slot = None;
+        let result = self
+            .deserializer
+            .deserialize_option(OptionalVisitorAdapter::new(
+                self.context,
+                visitor,
+                &mut slot,
+            ));
+
+        recover(result, slot, DeserializerError)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: EnumVisitor<'de>,
+    {
+        let mut slot = None;
+        // `deer` does not know the name of the enum or its variants, formats that require them
+        // (instead of using them as a hint) are unable to deserialize enums.
+        let result = self.deserializer.deserialize_enum(
+            "",
+            &[],
+            EnumVisitorAdapter::new(self.context, visitor, &mut slot),
+        );
+
+        recover(result, slot, DeserializerError)
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: StructVisitor<'de>,
+    {
+        let mut slot = None;
+        let result = self.deserializer.deserialize_struct(
+            "",
+            &[],
+            StructVisitorAdapter::new(self.context, visitor, &mut slot),
+        );
+
+        recover(result, slot, DeserializerError)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        let mut slot = None;
+        let result = self
+            .deserializer
+            .deserialize_identifier(IdentifierVisitorAdapter::new(visitor, &mut slot));
+
+        recover(result, slot, DeserializerError)
+    }
+}
+
+macro_rules! forward_variant {
+    ($($method:ident => $variant:ident),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+            where
+                V: Visitor<'de>,
+            {
+                self.newtype(VisitorCall::new(Method::$variant, visitor))
+            }
+        )*
+    };
+}
+
+/// `deer` [`Deserializer`] over the content of an enum variant.
+///
+/// `deer` does not distinguish between unit, newtype, tuple and struct variants, the kind is
+/// therefore inferred from the method called: [`Deserializer::deserialize_optional`] is used for
+/// unit variants, [`Deserializer::deserialize_struct`] for struct variants and every other method
+/// is forwarded to the content of a newtype variant.
+pub(crate) struct VariantDeserializer<'a, A> {
+    context: &'a Context,
+    access: A,
+}
+
+impl<'a, A> VariantDeserializer<'a, A> {
+    pub(crate) const fn new(context: &'a Context, access: A) -> Self {
+        Self { context, access }
+    }
+}
+
+impl<'de, A> VariantDeserializer<'_, A>
+where
+    A: VariantAccess<'de>,
+{
+    fn newtype<C>(self, call: C) -> Result<C::Value, Report<DeserializerError>>
+    where
+        C: crate::serde::call::Call<'de, Error = DeserializerError>,
+    {
+        let mut slot = None;
+        let result = self
+            .access
+            .newtype_variant_seed(Seed::new(self.context, call, &mut slot));
+
+        recover(result, slot, DeserializerError)
+    }
+}
+
+impl<'de, A> Deserializer<'de> for VariantDeserializer<'_, A>
+where
+    A: VariantAccess<'de>,
+{
+    forward_variant![
+        deserialize_any => Any,
+        deserialize_null => Null,
+        deserialize_bool => Bool,
+        deserialize_number => Number,
+        deserialize_char => Char,
+        deserialize_string => String,
+        deserialize_str => Str,
+        deserialize_bytes => Bytes,
+        deserialize_bytes_buffer => BytesBuffer,
+        deserialize_array => Array,
+        deserialize_object => Object,
+    ];
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        self.access
+            .unit_variant()
+            .map_err(|error| foreign(&error, DeserializerError))?;
+
+        visitor.visit_none().change_context(DeserializerError)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: EnumVisitor<'de>,
+    {
+        self.newtype(EnumCall::new(visitor))
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: StructVisitor<'de>,
+    {
+        let mut slot: Option<Report<VisitorError>> = None;
+        let result = self.access.struct_variant(
+            &[],
+            StructVisitorAdapter::new(self.context, visitor, &mut slot),
+        );
+
+        recover(result, slot, DeserializerError)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        self.newtype(IdentifierCall::new(visitor))
+    }
+}
@@ -0,0 +1,100 @@
+//! Interoperability with [`serde`].
+//!
+//! * [`SerdeDeserializer`] drives `deer` through any [`serde::Deserializer`], which makes every
+//!   data format implemented for `serde` available to `deer`.
+//! * [`Compat`] and [`CompatSeed`] use a `deer` [`Deserialize`] implementation where `serde`
+//!   expects a [`serde::Deserialize`] implementation, which allows types to be migrated one at a
+//!   time.
+//!
+//! [`Deserialize`]: crate::Deserialize
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::format;
+use core::marker::PhantomData;
+
+use serde::de::{DeserializeSeed, Error as _};
+
+pub use self::deserializer::SerdeDeserializer;
+use crate::{Context, Deserialize};
+
+mod access;
+mod call;
+mod deserializer;
+mod visitor;
+
+/// [`DeserializeSeed`] which deserializes `T` through its `deer` [`Deserialize`] implementation.
+///
+/// The report of a failed deserialization is converted into a `serde` error, the machine readable
+/// properties of the report are lost in the process.
+pub struct CompatSeed<'a, T> {
+    context: &'a Context,
+    _marker: PhantomData<fn() -> *const T>,
+}
+
+impl<'a, T> CompatSeed<'a, T> {
+    #[must_use]
+    pub const fn new(context: &'a Context) -> Self {
+        Self {
+            context,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for CompatSeed<'_, T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(SerdeDeserializer::new(self.context, deserializer))
+            .map_err(|report| D::Error::custom(format!("{report:#}")))
+    }
+}
+
+/// Wrapper which implements [`serde::Deserialize`] for every type implementing `deer`'s
+/// [`Deserialize`].
+///
+/// An empty [`Context`] is used, use [`CompatSeed`] to supply a context.
+///
+/// # Example
+///
+/// ```
+/// use deer::serde::Compat;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     ports: Compat<Vec<u16>>,
+/// }
+///
+/// let config: Config =
+///     serde_json::from_str(r#"{"ports": [80, 443]}"#).expect("should be able to deserialize");
+///
+/// assert_eq!(config.ports.into_inner(), [80, 443]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Compat<T>(pub T);
+
+impl<T> Compat<T> {
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Compat<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        CompatSeed::new(&Context::new())
+            .deserialize(deserializer)
+            .map(Self)
+    }
+}
@@ -0,0 +1,357 @@
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Formatter};
+
+use error_stack::Report;
+use serde::de::EnumAccess;
+
+use crate::{
+    Context, EnumVisitor, IdentifierVisitor, OptionalVisitor, StructVisitor, Visitor,
+    error::VisitorError,
+    serde::{
+        SerdeDeserializer,
+        access::{MapAccess, SeqAccess},
+        call::{DiscriminantCall, Seed, stash},
+        deserializer::VariantDeserializer,
+    },
+};
+
+fn expecting(fmt: &mut Formatter, document: &crate::Document) -> fmt::Result {
+    write!(fmt, "value of type {}", document.schema().ty())
+}
+
+macro_rules! forward {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E>(self, value: $ty) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                stash(self.slot, self.visitor.$method(value))
+            }
+        )*
+    };
+}
+
+/// `serde` [`Visitor`] which drives a `deer` [`Visitor`].
+///
+/// [`Visitor`]: serde::de::Visitor
+pub(crate) struct VisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    slot: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'a, 'b, V> VisitorAdapter<'a, 'b, V> {
+    pub(crate) const fn new(
+        context: &'a Context,
+        visitor: V,
+        slot: &'b mut Option<Report<VisitorError>>,
+    ) -> Self {
+        Self {
+            context,
+            visitor,
+            slot,
+        }
+    }
+}
+
+impl<'de, V> serde::de::Visitor<'de> for VisitorAdapter<'_, '_, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    forward![
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+    ];
+
+    fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+        expecting(fmt, &self.visitor.expecting())
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        stash(self.slot, self.visitor.visit_bytes_buffer(value))
+    }
+
+    // `serde` has no concept of a missing value, `none` is the explicit absence of a value
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        stash(self.slot, self.visitor.visit_null())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        stash(self.slot, self.visitor.visit_null())
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        stash(
+            self.slot,
+            self.visitor.visit_array(SeqAccess::new(self.context, seq)),
+        )
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        stash(
+            self.slot,
+            self.visitor.visit_object(MapAccess::new(self.context, map)),
+        )
+    }
+}
+
+/// `serde` [`Visitor`] which drives a `deer` [`OptionalVisitor`].
+///
+/// [`Visitor`]: serde::de::Visitor
+pub(crate) struct OptionalVisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    slot: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'a, 'b, V> OptionalVisitorAdapter<'a, 'b, V> {
+    pub(crate) const fn new(
+        context: &'a Context,
+        visitor: V,
+        slot: &'b mut Option<Report<VisitorError>>,
+    ) -> Self {
+        Self {
+            context,
+            visitor,
+            slot,
+        }
+    }
+}
+
+impl<'de, V> serde::de::Visitor<'de> for OptionalVisitorAdapter<'_, '_, V>
+where
+    V: OptionalVisitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+        expecting(fmt, &self.visitor.expecting())
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        stash(self.slot, self.visitor.visit_null())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        stash(
+            self.slot,
+            self.visitor
+                .visit_some(SerdeDeserializer::new(self.context, deserializer)),
+        )
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        stash(self.slot, self.visitor.visit_null())
+    }
+}
+
+/// `serde` [`Visitor`] which drives a `deer` [`StructVisitor`].
+///
+/// [`Visitor`]: serde::de::Visitor
+pub(crate) struct StructVisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    slot: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'a, 'b, V> StructVisitorAdapter<'a, 'b, V> {
+    pub(crate) const fn new(
+        context: &'a Context,
+        visitor: V,
+        slot: &'b mut Option<Report<VisitorError>>,
+    ) -> Self {
+        Self {
+            context,
+            visitor,
+            slot,
+        }
+    }
+}
+
+impl<'de, V> serde::de::Visitor<'de> for StructVisitorAdapter<'_, '_, V>
+where
+    V: StructVisitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+        expecting(fmt, &self.visitor.expecting())
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        stash(
+            self.slot,
+            self.visitor.visit_array(SeqAccess::new(self.context, seq)),
+        )
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        stash(
+            self.slot,
+            self.visitor.visit_object(MapAccess::new(self.context, map)),
+        )
+    }
+}
+
+/// `serde` [`Visitor`] which drives a `deer` [`IdentifierVisitor`].
+///
+/// [`Visitor`]: serde::de::Visitor
+pub(crate) struct IdentifierVisitorAdapter<'b, V> {
+    visitor: V,
+    slot: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'b, V> IdentifierVisitorAdapter<'b, V> {
+    pub(crate) const fn new(visitor: V, slot: &'b mut Option<Report<VisitorError>>) -> Self {
+        Self { visitor, slot }
+    }
+}
+
+impl<'de, V> serde::de::Visitor<'de> for IdentifierVisitorAdapter<'_, V>
+where
+    V: IdentifierVisitor<'de>,
+{
+    type Value = V::Value;
+
+    forward![
+        visit_u8(u8),
+        visit_u64(u64),
+        visit_str(&str),
+        visit_bytes(&[u8])
+    ];
+
+    fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+        expecting(fmt, &self.visitor.expecting())
+    }
+
+    fn visit_u16<E>(self, value: u16) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_u64(u64::from(value))
+    }
+
+    fn visit_u32<E>(self, value: u32) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_u64(u64::from(value))
+    }
+}
+
+/// `serde` [`Visitor`] which drives a `deer` [`EnumVisitor`].
+///
+/// [`Visitor`]: serde::de::Visitor
+pub(crate) struct EnumVisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    slot: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'a, 'b, V> EnumVisitorAdapter<'a, 'b, V> {
+    pub(crate) const fn new(
+        context: &'a Context,
+        visitor: V,
+        slot: &'b mut Option<Report<VisitorError>>,
+    ) -> Self {
+        Self {
+            context,
+            visitor,
+            slot,
+        }
+    }
+}
+
+impl<'de, V> serde::de::Visitor<'de> for EnumVisitorAdapter<'_, '_, V>
+where
+    V: EnumVisitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+        expecting(fmt, &self.visitor.expecting())
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (discriminant, variant) = data.variant_seed(Seed::new(
+            self.context,
+            DiscriminantCall::new(&self.visitor),
+            &mut *self.slot,
+        ))?;
+
+        let value = self.visitor.visit_value(
+            discriminant,
+            VariantDeserializer::new(self.context, variant),
+        );
+
+        stash(self.slot, value)
+    }
+}
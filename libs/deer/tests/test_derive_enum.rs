@@ -0,0 +1,342 @@
+#![cfg(feature = "derive")]
+#![expect(clippy::min_ident_chars, reason = "Simplifies test cases")]
+
+use deer::Deserialize;
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum Unit {
+    Red,
+    #[deer(rename = "green")]
+    Green,
+}
+
+#[test]
+fn unit_ok() {
+    assert_tokens(&Unit::Red, &[Token::Str("Red")]);
+    assert_tokens(&Unit::Green, &[Token::Str("green")]);
+}
+
+#[test]
+fn unit_unknown_err() {
+    assert_tokens_error::<Unit>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "value"],
+            properties: {
+                "expected": ["Red", "green"],
+                "received": "Blue",
+                "location": []
+            }
+        }]),
+        &[Token::Str("Blue")],
+    );
+}
+
+#[test]
+fn unit_reflection() {
+    let document = serde_json::to_value(Unit::reflection()).expect("should be serializable");
+
+    assert_eq!(
+        document["$defs"]["0000-test_derive_enum::Unit"],
+        json!({
+            "type": "string",
+            "enum": ["Red", "green"]
+        })
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum External {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, u16),
+    Struct { a: u8, b: u16 },
+}
+
+#[test]
+fn external_ok() {
+    assert_tokens(&External::Unit, &[Token::Str("Unit")]);
+    assert_tokens(
+        &External::Newtype(2),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Newtype"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+    assert_tokens(
+        &External::Tuple(2, 3),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Tuple"),
+            Token::Array { length: Some(2) },
+            Token::Number(2.into()),
+            Token::Number(3.into()),
+            Token::ArrayEnd,
+            Token::ObjectEnd,
+        ],
+    );
+    assert_tokens(
+        &External::Struct { a: 2, b: 3 },
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Struct"),
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::Str("b"),
+            Token::Number(3.into()),
+            Token::ObjectEnd,
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+/// The content of a unit variant may be omitted or be `null`, which is how `serde` data formats
+/// represent it.
+#[test]
+fn external_unit_null_ok() {
+    assert_tokens(
+        &External::Unit,
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Unit"),
+            Token::Null,
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn external_struct_err() {
+    assert_tokens_error::<External>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "field"],
+            properties: {
+                "expected": ["a", "b"],
+                "received": ["c"],
+                "location": [{"type": "variant", "value": "Struct"}]
+            }
+        }, {
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u16::reflection(),
+                "location": [
+                    {"type": "variant", "value": "Struct"},
+                    {"type": "field", "value": "b"}
+                ]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Struct"),
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::Str("c"),
+            Token::Number(3.into()),
+            Token::ObjectEnd,
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[deer(tag = "type")]
+enum Internal {
+    Unit,
+    Struct { a: u8, b: Option<u16> },
+}
+
+#[test]
+fn internal_ok() {
+    assert_tokens(
+        &Internal::Unit,
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("type"),
+            Token::Str("Unit"),
+            Token::ObjectEnd,
+        ],
+    );
+    assert_tokens(
+        &Internal::Struct { a: 2, b: None },
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("type"),
+            Token::Str("Struct"),
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn internal_tag_missing_err() {
+    assert_tokens_error::<Internal>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": {
+                    "$ref": "#/$defs/0000-test_derive_enum::_::__Discriminant",
+                    "$defs": {
+                        "0000-test_derive_enum::_::__Discriminant": {
+                            "type": "string",
+                            "enum": ["Unit", "Struct"]
+                        }
+                    }
+                },
+                "location": [{"type": "field", "value": "type"}]
+            }
+        }]),
+        &[Token::Object { length: Some(0) }, Token::ObjectEnd],
+    );
+}
+
+#[test]
+fn internal_struct_err() {
+    assert_tokens_error::<Internal>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u8::reflection(),
+                "location": [
+                    {"type": "variant", "value": "Struct"},
+                    {"type": "field", "value": "a"}
+                ]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("type"),
+            Token::Str("Struct"),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[deer(tag = "type", content = "value")]
+enum Adjacent {
+    Unit,
+    Newtype(u8),
+    Struct { a: u8 },
+}
+
+#[test]
+fn adjacent_ok() {
+    assert_tokens(
+        &Adjacent::Unit,
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("type"),
+            Token::Str("Unit"),
+            Token::ObjectEnd,
+        ],
+    );
+    assert_tokens(
+        &Adjacent::Newtype(2),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("type"),
+            Token::Str("Newtype"),
+            Token::Str("value"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+    assert_tokens(
+        &Adjacent::Struct { a: 2 },
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("type"),
+            Token::Str("Struct"),
+            Token::Str("value"),
+            Token::Object { length: Some(1) },
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn adjacent_content_missing_err() {
+    assert_tokens_error::<Adjacent>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u8::reflection(),
+                "location": [
+                    {"type": "field", "value": "value"},
+                    {"type": "variant", "value": "Newtype"}
+                ]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("type"),
+            Token::Str("Newtype"),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn adjacent_reflection() {
+    let document = serde_json::to_value(Adjacent::reflection()).expect("should be serializable");
+    let schema = &document["$defs"]["0000-test_derive_enum::Adjacent"];
+
+    assert_eq!(schema["type"], json!("object"));
+    assert_eq!(
+        schema["oneOf"]
+            .as_array()
+            .expect("variants should be an array")
+            .len(),
+        3
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum Generic<T, U> {
+    Tuple(T, U),
+    Struct { value: T },
+}
+
+#[test]
+fn generic_ok() {
+    assert_tokens(
+        &Generic::<u8, u16>::Tuple(2, 3),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Tuple"),
+            Token::Array { length: Some(2) },
+            Token::Number(2.into()),
+            Token::Number(3.into()),
+            Token::ArrayEnd,
+            Token::ObjectEnd,
+        ],
+    );
+    assert_tokens(
+        &Generic::<u8, u16>::Struct { value: 2 },
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Struct"),
+            Token::Object { length: Some(1) },
+            Token::Str("value"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+            Token::ObjectEnd,
+        ],
+    );
+}
@@ -0,0 +1,274 @@
+#![cfg(feature = "derive")]
+#![expect(clippy::min_ident_chars, reason = "Simplifies test cases")]
+
+use deer::Deserialize;
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Example {
+    a: u8,
+    b: u16,
+    c: u32,
+}
+
+#[test]
+fn named_object_ok() {
+    assert_tokens(
+        &Example { a: 2, b: 3, c: 4 },
+        &[
+            Token::Object { length: Some(3) },
+            Token::Str("c"),
+            Token::Number(4.into()),
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::Str("b"),
+            Token::Number(3.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_array_ok() {
+    assert_tokens(
+        &Example { a: 2, b: 3, c: 4 },
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(2.into()),
+            Token::Number(3.into()),
+            Token::Number(4.into()),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_object_missing_unknown_err() {
+    assert_tokens_error::<Example>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "field"],
+            properties: {
+                "expected": ["a", "b", "c"],
+                "received": ["d"],
+                "location": []
+            }
+        }, {
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u16::reflection(),
+                "location": [{"type": "field", "value": "b"}]
+            }
+        }, {
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u32::reflection(),
+                "location": [{"type": "field", "value": "c"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::Str("d"),
+            Token::Number(5.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_object_duplicate_err() {
+    assert_tokens_error::<Example>(
+        &error!([{
+            ns: "deer",
+            id: ["duplicate", "field"],
+            properties: {
+                "field": "b",
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(4) },
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::Str("b"),
+            Token::Number(3.into()),
+            Token::Str("b"),
+            Token::Number(4.into()),
+            Token::Str("c"),
+            Token::Number(5.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_object_value_err() {
+    assert_tokens_error::<Example>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": bool::reflection(),
+                "location": [{"type": "field", "value": "a"}]
+            }
+        }, {
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u32::reflection(),
+                "location": [{"type": "field", "value": "c"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Bool(true),
+            Token::Str("b"),
+            Token::Number(3.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_array_missing_err() {
+    assert_tokens_error::<Example>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u32::reflection(),
+                "location": [{"type": "tuple", "value": 2}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(2) },
+            Token::Number(2.into()),
+            Token::Number(3.into()),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Renamed {
+    #[deer(rename = "type")]
+    kind: u8,
+    value: Option<u16>,
+}
+
+#[test]
+fn named_rename_optional_ok() {
+    assert_tokens(
+        &Renamed {
+            kind: 1,
+            value: None,
+        },
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("type"),
+            Token::Number(1.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_reflection() {
+    let document = serde_json::to_value(Renamed::reflection()).expect("should be serializable");
+
+    assert_eq!(
+        document["$defs"]["0000-test_derive_struct::Renamed"],
+        json!({
+            "type": "object",
+            "properties": {
+                "type": {"$ref": "#/$defs/0001-u8"},
+                "value": {"$ref": "#/$defs/0002-deer::impls::core::option::OptionReflection<u16>"}
+            },
+            "additionalProperties": false
+        })
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Tuple(u8, u16, u32);
+
+#[test]
+fn tuple_ok() {
+    assert_tokens(
+        &Tuple(2, 3, 4),
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(2.into()),
+            Token::Number(3.into()),
+            Token::Number(4.into()),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn tuple_missing_err() {
+    assert_tokens_error::<Tuple>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u16::reflection(),
+                "location": [{"type": "tuple", "value": 1}]
+            }
+        }, {
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u32::reflection(),
+                "location": [{"type": "tuple", "value": 2}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(1) },
+            Token::Number(2.into()),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Newtype(u8);
+
+#[test]
+fn newtype_ok() {
+    assert_tokens(&Newtype(2), &[Token::Number(2.into())]);
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Unit;
+
+#[test]
+fn unit_ok() {
+    assert_tokens(&Unit, &[Token::Null]);
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn generic_ok() {
+    assert_tokens(
+        &Generic { value: 2_u8 },
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("value"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
@@ -0,0 +1,25 @@
+extern crate alloc;
+
+use alloc::{rc::Rc, sync::Arc};
+
+use deer::Number;
+use deer_desert::{Token, assert_tokens};
+use proptest::prelude::*;
+
+#[cfg(not(miri))]
+proptest! {
+    #[test]
+    fn box_ok(value in any::<u8>()) {
+        assert_tokens(&Box::new(value), &[Token::Number(Number::from(value))]);
+    }
+
+    #[test]
+    fn rc_ok(value in any::<u8>()) {
+        assert_tokens(&Rc::new(value), &[Token::Number(Number::from(value))]);
+    }
+
+    #[test]
+    fn arc_ok(value in any::<u8>()) {
+        assert_tokens(&Arc::new(value), &[Token::Number(Number::from(value))]);
+    }
+}
@@ -0,0 +1,103 @@
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use deer::{Deserialize as _, Number};
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use serde_json::json;
+
+#[test]
+fn btree_map_ok() {
+    let expected = BTreeMap::from([("a".to_owned(), 1_u8), ("b".to_owned(), 2)]);
+
+    assert_tokens(
+        &expected,
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("b"),
+            Token::Number(Number::from(2)),
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_map_err() {
+    assert_tokens_error::<BTreeMap<String, u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": []
+            }
+        }, {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 257,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(3) },
+            Token::Str("a"),
+            Token::Number(Number::from(256)),
+            Token::Str("b"),
+            Token::Number(Number::from(1)),
+            Token::Str("c"),
+            Token::Number(Number::from(257)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_map_reflection() {
+    let document =
+        serde_json::to_value(BTreeMap::<String, u8>::reflection()).expect("should be serializable");
+
+    assert_eq!(
+        document["$defs"]["0000-deer::impls::MapReflection<str, u8>"],
+        json!({
+            "type": "object",
+            "propertyNames": {"$ref": "#/$defs/0001-str"},
+            "additionalProperties": {"$ref": "#/$defs/0002-u8"}
+        })
+    );
+}
+
+#[test]
+fn btree_set_ok() {
+    let expected = BTreeSet::from([1_u8, 2, 3]);
+
+    assert_tokens(
+        &expected,
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(3)),
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(2)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_set_reflection() {
+    let document =
+        serde_json::to_value(BTreeSet::<u8>::reflection()).expect("should be serializable");
+
+    assert_eq!(
+        document["$defs"]["0000-deer::impls::SetReflection<u8>"],
+        json!({
+            "type": "array",
+            "items": {"$ref": "#/$defs/0001-u8"},
+            "uniqueItems": true
+        })
+    );
+}
@@ -0,0 +1,70 @@
+extern crate alloc;
+
+use alloc::{borrow::Cow, rc::Rc, sync::Arc};
+
+use deer::Deserialize as _;
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use proptest::prelude::*;
+use serde_json::json;
+
+#[cfg(not(miri))]
+proptest! {
+    #[test]
+    fn string_ok(value in any::<String>()) {
+        let tokens = [Token::String(Box::leak(value.clone().into_boxed_str()))];
+
+        assert_tokens(&value, &tokens);
+    }
+}
+
+#[test]
+fn string_str_ok() {
+    let expected = String::from("example");
+
+    assert_tokens(&expected, &[Token::Str("example")]);
+    assert_tokens(&expected, &[Token::BorrowedStr("example")]);
+    assert_tokens(&expected, &[Token::String("example")]);
+}
+
+#[test]
+fn string_char_ok() {
+    assert_tokens(&String::from("A"), &[Token::Char('A')]);
+}
+
+#[test]
+fn string_err() {
+    assert_tokens_error::<String>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": <String as deer::Deserialize>::reflection(),
+                "received": bool::reflection(),
+                "location": []
+            }
+        }]),
+        &[Token::Bool(true)],
+    );
+}
+
+#[test]
+fn boxed_str_ok() {
+    assert_tokens(&Box::<str>::from("example"), &[Token::Str("example")]);
+}
+
+#[test]
+fn rc_str_ok() {
+    assert_tokens(&Rc::<str>::from("example"), &[Token::Str("example")]);
+}
+
+#[test]
+fn arc_str_ok() {
+    assert_tokens(&Arc::<str>::from("example"), &[Token::Str("example")]);
+}
+
+#[test]
+fn cow_str_ok() {
+    let expected: Cow<'_, str> = Cow::Owned(String::from("example"));
+
+    assert_tokens(&expected, &[Token::Str("example")]);
+}
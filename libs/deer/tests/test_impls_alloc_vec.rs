@@ -0,0 +1,96 @@
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+
+use deer::{Deserialize as _, Number};
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use proptest::prelude::*;
+use serde_json::json;
+
+fn tokens(values: &[u8]) -> Vec<Token> {
+    let mut tokens = vec![Token::Array {
+        length: Some(values.len()),
+    }];
+    tokens.extend(
+        values
+            .iter()
+            .map(|value| Token::Number(Number::from(*value))),
+    );
+    tokens.push(Token::ArrayEnd);
+
+    tokens
+}
+
+#[cfg(not(miri))]
+proptest! {
+    #[test]
+    fn vec_ok(value in any::<Vec<u8>>()) {
+        assert_tokens(&value, &tokens(&value));
+    }
+
+    #[test]
+    fn vec_deque_ok(value in any::<VecDeque<u8>>()) {
+        let values: Vec<_> = value.iter().copied().collect();
+
+        assert_tokens(&value, &tokens(&values));
+    }
+}
+
+#[test]
+fn vec_empty_ok() {
+    assert_tokens(
+        &Vec::<u8>::new(),
+        &[Token::Array { length: Some(0) }, Token::ArrayEnd],
+    );
+}
+
+#[test]
+fn vec_err() {
+    assert_tokens_error::<Vec<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "array", "value": 1}]
+            }
+        }, {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 300,
+                "location": [{"type": "array", "value": 3}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(4) },
+            Token::Number(Number::from(0)),
+            Token::Number(Number::from(256)),
+            Token::Number(Number::from(2)),
+            Token::Number(Number::from(300)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn boxed_slice_ok() {
+    let expected: Box<[u8]> = vec![0, 1, 2].into_boxed_slice();
+
+    assert_tokens(&expected, &tokens(&[0, 1, 2]));
+}
+
+#[test]
+fn vec_reflection() {
+    let document = serde_json::to_value(Vec::<u8>::reflection()).expect("should be serializable");
+
+    assert_eq!(
+        document["$defs"]["0000-deer::impls::SequenceReflection<u8>"],
+        json!({
+            "type": "array",
+            "items": {"$ref": "#/$defs/0001-u8"}
+        })
+    );
+}
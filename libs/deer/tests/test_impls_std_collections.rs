@@ -0,0 +1,37 @@
+use std::collections::{HashMap, HashSet};
+
+use deer::Number;
+use deer_desert::{Token, assert_tokens};
+
+#[test]
+fn hash_map_ok() {
+    let expected = HashMap::from([("a".to_owned(), 1_u8), ("b".to_owned(), 2)]);
+
+    assert_tokens(
+        &expected,
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("b"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn hash_set_ok() {
+    let expected = HashSet::from([1_u8, 2, 3]);
+
+    assert_tokens(
+        &expected,
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(2)),
+            Token::Number(Number::from(3)),
+            Token::ArrayEnd,
+        ],
+    );
+}
@@ -0,0 +1,104 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use deer::{Deserialize as _, Number};
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use serde_json::json;
+
+#[test]
+fn ip_addr_ok() {
+    assert_tokens(&Ipv4Addr::LOCALHOST, &[Token::Str("127.0.0.1")]);
+    assert_tokens(&Ipv6Addr::LOCALHOST, &[Token::Str("::1")]);
+
+    assert_tokens(
+        &IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+        &[Token::Str("192.168.0.1")],
+    );
+    assert_tokens(&IpAddr::V6(Ipv6Addr::LOCALHOST), &[Token::Str("::1")]);
+}
+
+#[test]
+fn ip_addr_err() {
+    assert_tokens_error::<Ipv4Addr>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": Ipv4Addr::reflection(),
+                "received": "::1",
+                "location": []
+            }
+        }]),
+        &[Token::Str("::1")],
+    );
+}
+
+#[test]
+fn socket_addr_str_ok() {
+    let expected = SocketAddr::from((Ipv4Addr::LOCALHOST, 8080));
+
+    assert_tokens(&expected, &[Token::Str("127.0.0.1:8080")]);
+    assert_tokens(
+        &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080),
+        &[Token::Str("127.0.0.1:8080")],
+    );
+    assert_tokens(
+        &SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0, 0),
+        &[Token::Str("[::1]:8080")],
+    );
+}
+
+#[test]
+fn socket_addr_array_ok() {
+    let expected = SocketAddr::from((Ipv6Addr::LOCALHOST, 8080));
+
+    assert_tokens(
+        &expected,
+        &[
+            Token::Array { length: Some(2) },
+            Token::Str("::1"),
+            Token::Number(Number::from(8080)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn socket_addr_array_too_many_err() {
+    assert_tokens_error::<SocketAddr>(
+        &error!([{
+            ns: "deer",
+            id: ["array", "length"],
+            properties: {
+                "expected": 2,
+                "received": 3,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Array { length: Some(3) },
+            Token::Str("::1"),
+            Token::Number(Number::from(8080)),
+            Token::Number(Number::from(8081)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn socket_addr_array_missing_err() {
+    assert_tokens_error::<SocketAddr>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u16::reflection(),
+                "location": [{"type": "tuple", "value": 1}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(1) },
+            Token::Str("::1"),
+            Token::ArrayEnd,
+        ],
+    );
+}
@@ -0,0 +1,18 @@
+use std::path::{Path, PathBuf};
+
+use deer_desert::{Token, assert_tokens};
+
+#[test]
+fn path_buf_ok() {
+    let expected = PathBuf::from("/usr/local/bin");
+
+    assert_tokens(&expected, &[Token::Str("/usr/local/bin")]);
+    assert_tokens(&expected, &[Token::String("/usr/local/bin")]);
+}
+
+#[test]
+fn boxed_path_ok() {
+    let expected: Box<Path> = Path::new("/usr/local/bin").into();
+
+    assert_tokens(&expected, &[Token::Str("/usr/local/bin")]);
+}
@@ -0,0 +1,220 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use core::fmt::Debug;
+
+use deer::{
+    Context, Deserialize,
+    error::{DeserializeError, ReportExt as _},
+    serde::SerdeDeserializer,
+};
+use deer_desert::{assert_error, error, error::ErrorVec};
+use error_stack::Report;
+use serde_json::json;
+
+fn from_json<T>(input: &str) -> Result<T, Report<DeserializeError>>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let context = Context::new();
+    let mut deserializer = serde_json::Deserializer::from_str(input);
+
+    T::deserialize(SerdeDeserializer::new(&context, &mut deserializer))
+}
+
+fn assert_json<T>(expected: &T, input: &str)
+where
+    T: for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    let received = from_json::<T>(input).expect("should be able to deserialize");
+
+    assert_eq!(received, *expected);
+}
+
+fn assert_json_error<T>(expected: &ErrorVec, input: &str)
+where
+    T: for<'de> Deserialize<'de> + Debug,
+{
+    let received = from_json::<T>(input).expect_err("should fail to deserialize");
+
+    assert_error(expected, received);
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Example {
+    name: String,
+    port: u16,
+    tags: Option<Vec<String>>,
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum Shape {
+    Point,
+    Circle(u8),
+    Rectangle { width: u8, height: u8 },
+}
+
+#[test]
+fn primitive_ok() {
+    assert_json(&true, "true");
+    assert_json(&12_u8, "12");
+    assert_json(&-12_i64, "-12");
+    assert_json(&1.5_f64, "1.5");
+    assert_json(&'a', r#""a""#);
+    assert_json(&String::from("example"), r#""example""#);
+    assert_json(&(), "null");
+}
+
+#[test]
+fn option_ok() {
+    assert_json(&None::<u8>, "null");
+    assert_json(&Some(12_u8), "12");
+}
+
+#[test]
+fn collection_ok() {
+    assert_json(&vec![1_u8, 2, 3], "[1, 2, 3]");
+    assert_json(&(1_u8, String::from("a")), r#"[1, "a"]"#);
+    assert_json(
+        &BTreeMap::from([(String::from("a"), 1_u8), (String::from("b"), 2)]),
+        r#"{"a": 1, "b": 2}"#,
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn struct_ok() {
+    assert_json(
+        &Example {
+            name: String::from("example"),
+            port: 8080,
+            tags: None,
+        },
+        r#"{"name": "example", "port": 8080}"#,
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn enum_ok() {
+    assert_json(&Shape::Point, r#""Point""#);
+    assert_json(&Shape::Circle(2), r#"{"Circle": 2}"#);
+    assert_json(
+        &Shape::Rectangle {
+            width: 2,
+            height: 3,
+        },
+        r#"{"Rectangle": {"width": 2, "height": 3}}"#,
+    );
+}
+
+#[test]
+fn array_err() {
+    assert_json_error::<Vec<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "array", "value": 1}]
+            }
+        }, {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 300,
+                "location": [{"type": "array", "value": 3}]
+            }
+        }]),
+        "[0, 256, 2, 300]",
+    );
+}
+
+#[test]
+fn array_too_many_err() {
+    assert_json_error::<(u8, u8)>(
+        &error!([{
+            ns: "deer",
+            id: ["array", "length"],
+            properties: {
+                "expected": 2,
+                "received": 3,
+                "location": []
+            }
+        }]),
+        "[1, 2, 3]",
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn struct_err() {
+    assert_json_error::<Example>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "field"],
+            properties: {
+                "expected": ["name", "port", "tags"],
+                "received": ["host"],
+                "location": []
+            }
+        }, {
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u16::reflection(),
+                "location": [{"type": "field", "value": "port"}]
+            }
+        }]),
+        r#"{"name": "example", "host": "localhost"}"#,
+    );
+}
+
+#[test]
+fn syntax_err() {
+    let report = from_json::<Vec<u8>>("[1, 2").expect_err("should fail to deserialize");
+
+    let received = serde_json::to_value(report.export()).expect("error should serialize");
+
+    assert_eq!(received[0]["namespace"], json!("deer"));
+    assert_eq!(received[0]["id"], json!(["foreign"]));
+    assert_eq!(
+        received[0]["message"],
+        json!("EOF while parsing a list at line 1 column 5")
+    );
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, serde::Deserialize)]
+struct Outer {
+    inner: deer::serde::Compat<Example>,
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn compat_ok() {
+    let outer: Outer = serde_json::from_str(r#"{"inner": {"name": "example", "port": 8080}}"#)
+        .expect("should be able to deserialize");
+
+    assert_eq!(
+        outer.inner.into_inner(),
+        Example {
+            name: String::from("example"),
+            port: 8080,
+            tags: None
+        }
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn compat_err() {
+    let error = serde_json::from_str::<Outer>(r#"{"inner": {"name": "example"}}"#)
+        .expect_err("should fail to deserialize");
+
+    assert!(error.to_string().contains("unexpected missing value"));
+}
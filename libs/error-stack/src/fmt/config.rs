@@ -1,25 +1,47 @@
-use crate::fmt::{Charset, ColorMode};
+use crate::fmt::{Charset, ColorMode, OutputFormat};
 #[cfg(any(feature = "std", feature = "hooks"))]
 use crate::fmt::{Format, HookContext};
 
+/// Structured output is consumed by machines, which is why colors are never emitted.
+fn load_color_mode(output_format: OutputFormat) -> ColorMode {
+    match output_format {
+        OutputFormat::Tree => ColorMode::load(),
+        OutputFormat::Json | OutputFormat::Logfmt => ColorMode::None,
+    }
+}
+
 #[cfg(any(feature = "std", feature = "hooks"))]
 pub(crate) struct Config {
     context: HookContext<()>,
+    output_format: OutputFormat,
 }
 
 #[cfg(any(feature = "std", feature = "hooks"))]
 impl Config {
-    pub(crate) const fn new(color_mode: ColorMode, charset: Charset, alternate: bool) -> Self {
+    pub(crate) const fn new(
+        output_format: OutputFormat,
+        color_mode: ColorMode,
+        charset: Charset,
+        alternate: bool,
+    ) -> Self {
         let context = HookContext::new(Format::new(alternate, color_mode, charset));
 
-        Self { context }
+        Self {
+            context,
+            output_format,
+        }
     }
 
     pub(crate) fn load(alternate: bool) -> Self {
-        let color_mode = ColorMode::load();
+        let output_format = OutputFormat::load();
+        let color_mode = load_color_mode(output_format);
         let charset = Charset::load();
 
-        Self::new(color_mode, charset, alternate)
+        Self::new(output_format, color_mode, charset, alternate)
+    }
+
+    pub(crate) const fn output_format(&self) -> OutputFormat {
+        self.output_format
     }
 
     pub(crate) fn context<T>(&mut self) -> &mut HookContext<T> {
@@ -37,24 +59,36 @@ impl Config {
 
 #[cfg(not(any(feature = "std", feature = "hooks")))]
 pub(crate) struct Config {
+    output_format: OutputFormat,
     color_mode: ColorMode,
     charset: Charset,
 }
 
 #[cfg(not(any(feature = "std", feature = "hooks")))]
 impl Config {
-    pub(crate) const fn new(color_mode: ColorMode, charset: Charset, _alternate: bool) -> Self {
+    pub(crate) const fn new(
+        output_format: OutputFormat,
+        color_mode: ColorMode,
+        charset: Charset,
+        _alternate: bool,
+    ) -> Self {
         Self {
+            output_format,
             color_mode,
             charset,
         }
     }
 
     pub(crate) fn load(alternate: bool) -> Self {
-        let color_mode = ColorMode::load();
+        let output_format = OutputFormat::load();
+        let color_mode = load_color_mode(output_format);
         let charset = Charset::load();
 
-        Self::new(color_mode, charset, alternate)
+        Self::new(output_format, color_mode, charset, alternate)
+    }
+
+    pub(crate) const fn output_format(&self) -> OutputFormat {
+        self.output_format
     }
 
     pub(crate) const fn color_mode(&self) -> ColorMode {
@@ -135,6 +135,14 @@
 #![cfg_attr(doc, doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/doc/fmt_doc_alt.snap")))]
 //! </pre>
 //!
+//! ## Structured Output
+//!
+//! The tree output is meant to be read by humans. For log aggregation,
+//! [`Report::set_output_format`] selects a machine-readable [`OutputFormat`] instead, which renders
+//! every context with its type name, location, attachments and sources as JSON or logfmt.
+//! Attachments are rendered using the installed hooks, as is the appendix, while colors are never
+//! emitted.
+//!
 //! ## Implementation Details
 //!
 //! Nothing explained here is under any semver guarantee. This section explains the algorithm used
@@ -299,7 +307,9 @@ mod config;
 #[cfg(any(feature = "std", feature = "hooks"))]
 mod hook;
 mod location;
+mod output;
 mod r#override;
+mod structured;
 
 use alloc::{
     borrow::ToOwned,
@@ -323,6 +333,7 @@ pub use hook::HookContext;
 pub(crate) use hook::{Format, Hooks, install_builtin_hooks};
 #[cfg(not(any(feature = "std", feature = "hooks")))]
 use location::LocationAttachment;
+pub use output::OutputFormat;
 
 use crate::{
     AttachmentKind, Context, Frame, FrameKind, Report,
@@ -1097,6 +1108,16 @@ impl<C: ?Sized> Debug for Report<C> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         let mut config = Config::load(fmt.alternate());
 
+        match config.output_format() {
+            OutputFormat::Tree => {}
+            OutputFormat::Json => {
+                return structured::debug_json(self.current_frames_unchecked(), &mut config, fmt);
+            }
+            OutputFormat::Logfmt => {
+                return structured::debug_logfmt(self.current_frames_unchecked(), &mut config, fmt);
+            }
+        }
+
         let color = config.color_mode();
         let charset = config.charset();
 
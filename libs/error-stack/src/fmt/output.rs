@@ -0,0 +1,111 @@
+use crate::{
+    Report,
+    fmt::r#override::{AtomicOverride, AtomicPreference},
+};
+
+/// The available output formats of the [`Debug`] implementation of [`Report`]
+///
+/// Set via [`Report::set_output_format`].
+///
+/// [`Debug`]: core::fmt::Debug
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum OutputFormat {
+    /// Render the report as a tree, meant to be read by humans in a terminal
+    ///
+    /// This is the default if no output format has been explicitly set.
+    #[default]
+    Tree,
+
+    /// Render the report as a single line of JSON, meant to be consumed by log aggregators
+    ///
+    /// The output is an object with the fields `contexts` and `appendix`. Each context is an
+    /// object containing its `context`, `type`, `location`, `attachments`, the number of `opaque`
+    /// attachments and its `sources`, which are contexts themselves.
+    Json,
+
+    /// Render the report in the logfmt format, meant to be consumed by log aggregators
+    ///
+    /// Every context is emitted on its own line with the keys `id`, `parent` (omitted for the
+    /// current contexts), `context`, `type`, `location`, `attachment` (once for every attachment)
+    /// and `opaque`. Every entry of the appendix is emitted on its own line with the key
+    /// `appendix`.
+    Logfmt,
+}
+
+impl OutputFormat {
+    pub(super) fn load() -> Self {
+        OUTPUT_FORMAT_OVERRIDE.load()
+    }
+}
+
+/// Value layout:
+/// `0x00`: `OutputFormat::Tree`
+/// `0x01`: `OutputFormat::Json`
+/// `0x02`: `OutputFormat::Logfmt`
+///
+/// all others: default to [`Self::default`]
+impl AtomicPreference for OutputFormat {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0x00 => Self::Tree,
+            0x01 => Self::Json,
+            0x02 => Self::Logfmt,
+            _ => Self::default(),
+        }
+    }
+
+    fn into_u8(self) -> u8 {
+        match self {
+            Self::Tree => 0x00,
+            Self::Json => 0x01,
+            Self::Logfmt => 0x02,
+        }
+    }
+}
+
+static OUTPUT_FORMAT_OVERRIDE: AtomicOverride<OutputFormat> = AtomicOverride::new();
+
+impl Report<()> {
+    /// Set the output format preference
+    ///
+    /// The value defaults to [`OutputFormat::Tree`]. Structured output formats render every
+    /// context with its type name, location, attachments and sources. Installed debug hooks are
+    /// used to render attachments, while the [`ColorMode`] is ignored and colors are never
+    /// emitted.
+    ///
+    /// [`ColorMode`]: crate::fmt::ColorMode
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{IntoReport, Report, fmt::OutputFormat};
+    ///
+    /// struct Suggestion(&'static str);
+    ///
+    /// Report::install_debug_hook::<Suggestion>(|Suggestion(value), context| {
+    ///     context.push_body(format!("suggestion: {value}"));
+    /// });
+    ///
+    /// let report = Error::from(ErrorKind::InvalidInput)
+    ///     .into_report()
+    ///     .attach(Suggestion("oh no, try again"));
+    ///
+    /// Report::set_output_format(OutputFormat::Json);
+    /// let output = format!("{report:?}");
+    /// assert!(output.starts_with(r#"{"contexts":[{"context":"invalid input parameter","#));
+    /// assert!(output.contains(r#""suggestion: oh no, try again""#));
+    ///
+    /// Report::set_output_format(OutputFormat::Logfmt);
+    /// let output = format!("{report:?}");
+    /// assert!(
+    ///     output.starts_with(r#"id=0 context="invalid input parameter" type=std::io::error::Error"#)
+    /// );
+    /// assert!(output.contains(r#"attachment="suggestion: oh no, try again""#));
+    /// # Report::set_output_format(OutputFormat::Tree);
+    /// ```
+    pub fn set_output_format(format: OutputFormat) {
+        OUTPUT_FORMAT_OVERRIDE.store(format);
+    }
+}
@@ -0,0 +1,287 @@
+//! Structured output of a [`Report`], selected through [`OutputFormat::Json`] and
+//! [`OutputFormat::Logfmt`].
+//!
+//! Unlike the tree output, contexts are not grouped into lists, instead every context is rendered
+//! with all attachments leading up to it and all of its sources. If attachments are shared between
+//! multiple contexts, they are duplicated and output twice.
+//!
+//! [`Report`]: crate::Report
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+use alloc::borrow::ToOwned as _;
+use alloc::{
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter, Write as _},
+    iter::once,
+    panic::Location,
+};
+
+use crate::{
+    Frame, FrameKind,
+    fmt::{config::Config, debug_attachments_invoke},
+};
+
+/// A context and everything leading up to it, ready to be rendered.
+struct Node<'a> {
+    context: String,
+    type_name: &'static str,
+    location: Option<&'a Location<'static>>,
+    attachments: Vec<String>,
+    opaque: usize,
+    sources: Vec<Node<'a>>,
+}
+
+/// Collect the contexts of the tree starting at `frame`.
+///
+/// `attachments` are the attachments which have been encountered since the last context, ordered
+/// from the most recent one.
+fn collect<'a>(
+    frame: &'a Frame,
+    mut attachments: Vec<&'a Frame>,
+    config: &mut Config,
+) -> Vec<Node<'a>> {
+    let FrameKind::Context(context) = frame.kind() else {
+        attachments.push(frame);
+
+        return frame
+            .sources()
+            .iter()
+            .flat_map(|source| collect(source, attachments.clone(), config))
+            .collect();
+    };
+
+    // reverse all attachments, to output them in the order they have been attached
+    attachments.reverse();
+
+    // the location is attached right after the context has been created, therefore the first
+    // location is the one of the context, which is output separately
+    let location = attachments
+        .iter()
+        .position(|attachment| attachment.is::<Location<'static>>())
+        .map(|index| attachments.remove(index))
+        .and_then(|attachment| attachment.downcast_ref::<Location<'static>>());
+
+    let (opaque, attachments) = debug_attachments_invoke(once(frame).chain(attachments), config);
+
+    let sources = frame
+        .sources()
+        .iter()
+        .flat_map(|source| collect(source, Vec::new(), config))
+        .collect();
+
+    vec![Node {
+        context: context.to_string(),
+        type_name: frame.type_name(),
+        location,
+        attachments,
+        opaque: opaque.0,
+        sources,
+    }]
+}
+
+/// Escapes a value as JSON string, including the surrounding quotes.
+struct JsonString<'a>(&'a str);
+
+impl Display for JsonString<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_char('"')?;
+
+        for char in self.0.chars() {
+            match char {
+                '"' => fmt.write_str("\\\"")?,
+                '\\' => fmt.write_str("\\\\")?,
+                '\n' => fmt.write_str("\\n")?,
+                '\r' => fmt.write_str("\\r")?,
+                '\t' => fmt.write_str("\\t")?,
+                char if char.is_control() => write!(fmt, "\\u{:04x}", u32::from(char))?,
+                char => fmt.write_char(char)?,
+            }
+        }
+
+        fmt.write_char('"')
+    }
+}
+
+/// Writes a JSON array, using `write` to render every value.
+fn json_array<T>(
+    fmt: &mut Formatter<'_>,
+    values: &[T],
+    mut write: impl FnMut(&mut Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    fmt.write_char('[')?;
+
+    for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+            fmt.write_char(',')?;
+        }
+
+        write(fmt, value)?;
+    }
+
+    fmt.write_char(']')
+}
+
+fn json_node(fmt: &mut Formatter<'_>, node: &Node) -> fmt::Result {
+    write!(
+        fmt,
+        r#"{{"context":{},"type":{},"location":"#,
+        JsonString(&node.context),
+        JsonString(node.type_name)
+    )?;
+
+    match node.location {
+        Some(location) => write!(
+            fmt,
+            r#"{{"file":{},"line":{},"column":{}}}"#,
+            JsonString(location.file()),
+            location.line(),
+            location.column()
+        )?,
+        None => fmt.write_str("null")?,
+    }
+
+    fmt.write_str(r#","attachments":"#)?;
+    json_array(fmt, &node.attachments, |fmt, attachment| {
+        Display::fmt(&JsonString(attachment), fmt)
+    })?;
+
+    write!(fmt, r#","opaque":{},"sources":"#, node.opaque)?;
+    json_array(fmt, &node.sources, json_node)?;
+
+    fmt.write_char('}')
+}
+
+fn json(fmt: &mut Formatter<'_>, nodes: &[Node], appendix: &[String]) -> fmt::Result {
+    fmt.write_str(r#"{"contexts":"#)?;
+    json_array(fmt, nodes, json_node)?;
+
+    fmt.write_str(r#","appendix":"#)?;
+    json_array(fmt, appendix, |fmt, snippet| {
+        Display::fmt(&JsonString(snippet), fmt)
+    })?;
+
+    fmt.write_char('}')
+}
+
+/// Escapes a logfmt value, the value is only quoted if necessary.
+struct LogfmtValue<'a>(&'a str);
+
+impl Display for LogfmtValue<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let quote = self.0.is_empty()
+            || self
+                .0
+                .chars()
+                .any(|char| matches!(char, ' ' | '=' | '"' | '\\') || char.is_control());
+
+        if !quote {
+            return fmt.write_str(self.0);
+        }
+
+        // logfmt does not specify escaping, JSON escaping is understood by most consumers
+        Display::fmt(&JsonString(self.0), fmt)
+    }
+}
+
+/// Writes a line for every node in the tree, returns the next free id.
+fn logfmt_node(
+    fmt: &mut Formatter<'_>,
+    node: &Node,
+    id: usize,
+    parent: Option<usize>,
+) -> Result<usize, fmt::Error> {
+    if id > 0 {
+        fmt.write_char('\n')?;
+    }
+
+    write!(fmt, "id={id}")?;
+    if let Some(parent) = parent {
+        write!(fmt, " parent={parent}")?;
+    }
+    write!(
+        fmt,
+        " context={} type={}",
+        LogfmtValue(&node.context),
+        LogfmtValue(node.type_name)
+    )?;
+
+    if let Some(location) = node.location {
+        write!(fmt, " location={}", LogfmtValue(&location.to_string()))?;
+    }
+
+    for attachment in &node.attachments {
+        write!(fmt, " attachment={}", LogfmtValue(attachment))?;
+    }
+
+    write!(fmt, " opaque={}", node.opaque)?;
+
+    node.sources.iter().try_fold(id + 1, |next, source| {
+        logfmt_node(fmt, source, next, Some(id))
+    })
+}
+
+fn logfmt(fmt: &mut Formatter<'_>, nodes: &[Node], appendix: &[String]) -> fmt::Result {
+    let next = nodes
+        .iter()
+        .try_fold(0, |next, node| logfmt_node(fmt, node, next, None))?;
+
+    for (index, snippet) in appendix.iter().enumerate() {
+        if next > 0 || index > 0 {
+            fmt.write_char('\n')?;
+        }
+
+        write!(fmt, "appendix={}", LogfmtValue(snippet))?;
+    }
+
+    Ok(())
+}
+
+/// Collect the contexts of all `frames` of a report and the appendix of the installed hooks.
+fn collect_report<'a>(frames: &'a [Frame], config: &mut Config) -> (Vec<Node<'a>>, Vec<String>) {
+    let nodes = frames
+        .iter()
+        .flat_map(|frame| collect(frame, Vec::new(), config))
+        .collect();
+
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    let appendix = config
+        .context::<Frame>()
+        .appendix()
+        .iter()
+        .map(
+            // remove all trailing newlines for a more uniform look
+            |snippet| snippet.trim_end_matches('\n').to_owned(),
+        )
+        .collect();
+
+    #[cfg(not(any(feature = "std", feature = "hooks")))]
+    let appendix = Vec::new();
+
+    (nodes, appendix)
+}
+
+/// Renders the `frames` of a report as JSON.
+pub(super) fn debug_json(
+    frames: &[Frame],
+    config: &mut Config,
+    fmt: &mut Formatter<'_>,
+) -> fmt::Result {
+    let (nodes, appendix) = collect_report(frames, config);
+
+    json(fmt, &nodes, &appendix)
+}
+
+/// Renders the `frames` of a report as logfmt.
+pub(super) fn debug_logfmt(
+    frames: &[Frame],
+    config: &mut Config,
+    fmt: &mut Formatter<'_>,
+) -> fmt::Result {
+    let (nodes, appendix) = collect_report(frames, config);
+
+    logfmt(fmt, &nodes, &appendix)
+}
@@ -3,7 +3,11 @@
 use alloc::boxed::Box;
 #[cfg(nightly)]
 use core::error::Request;
-use core::{any::Any, error::Error, fmt};
+use core::{
+    any::{Any, type_name},
+    error::Error,
+    fmt,
+};
 
 use crate::{AttachmentKind, Context, Frame, FrameKind};
 
@@ -11,6 +15,8 @@ use crate::{AttachmentKind, Context, Frame, FrameKind};
 pub(super) trait FrameImpl: Send + Sync + 'static {
     fn kind(&self) -> FrameKind<'_>;
 
+    fn type_name(&self) -> &'static str;
+
     fn as_any(&self) -> &dyn Any;
 
     fn as_any_mut(&mut self) -> &mut dyn Any;
@@ -48,6 +54,10 @@ impl<C: Context> FrameImpl for ContextFrame<C> {
         FrameKind::Context(&self.context)
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<C>()
+    }
+
     fn as_any(&self) -> &dyn Any {
         &self.context
     }
@@ -71,6 +81,10 @@ impl<A: 'static + Send + Sync> FrameImpl for AttachmentFrame<A> {
         FrameKind::Attachment(AttachmentKind::Opaque(&self.attachment))
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<A>()
+    }
+
     fn as_any(&self) -> &dyn Any {
         &self.attachment
     }
@@ -96,6 +110,10 @@ impl<A: 'static + fmt::Debug + fmt::Display + Send + Sync> FrameImpl
         FrameKind::Attachment(AttachmentKind::Printable(&self.attachment))
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<A>()
+    }
+
     fn as_any(&self) -> &dyn Any {
         &self.attachment
     }
@@ -142,6 +160,10 @@ impl FrameImpl for AnyhowContext {
         FrameKind::Context(self)
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<anyhow::Error>()
+    }
+
     fn as_any(&self) -> &dyn Any {
         &self.0
     }
@@ -189,6 +211,10 @@ impl FrameImpl for EyreContext {
         FrameKind::Context(self)
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<eyre::Report>()
+    }
+
     fn as_any(&self) -> &dyn Any {
         &self.0
     }
@@ -96,6 +96,15 @@ impl Frame {
         self.frame.as_any().type_id()
     }
 
+    /// Returns the name of the type of the held context or attachment by this frame.
+    ///
+    /// The name is obtained by [`core::any::type_name`] and, as such, is meant for diagnostic
+    /// purposes only.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.frame.type_name()
+    }
+
     pub(crate) fn as_error(&self) -> &impl Error {
         &self.frame
     }
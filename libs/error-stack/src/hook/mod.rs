@@ -2,6 +2,8 @@ pub(crate) mod context;
 
 use alloc::vec::Vec;
 
+#[cfg(feature = "serde")]
+use crate::serde::SerdeHooks;
 use crate::{
     Report,
     fmt::{Hooks, install_builtin_hooks},
@@ -16,6 +18,8 @@ type RwLock<T> = std::sync::RwLock<T>;
 type RwLock<T> = spin::rwlock::RwLock<T>;
 
 static FMT_HOOK: RwLock<Hooks> = RwLock::new(Hooks { inner: Vec::new() });
+#[cfg(feature = "serde")]
+static SERDE_HOOK: RwLock<SerdeHooks> = RwLock::new(SerdeHooks::new());
 
 impl Report<()> {
     /// Can be used to globally set a [`Debug`] format hook, for a specific type `T`.
@@ -237,4 +241,203 @@ impl Report<()> {
 
         closure(&hook)
     }
+
+    /// Can be used to globally set a serialization hook, for a specific type `T`.
+    ///
+    /// When a [`Report`] is serialized, contexts and attachments of type `T` are serialized using
+    /// the [`Serialize`] implementation of `T`, in addition to their type name. Without a hook,
+    /// only the [`Display`] output of contexts and printable attachments is serialized, while
+    /// opaque attachments are skipped.
+    ///
+    /// Installing a hook for a type, which already has a hook installed, replaces the previous
+    /// hook.
+    ///
+    /// [`Serialize`]: serde::Serialize
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{IntoReport, Report};
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct ErrorCode(u16);
+    ///
+    /// Report::install_serde_hook::<ErrorCode>();
+    ///
+    /// let report = Error::from(ErrorKind::NotFound)
+    ///     .into_report()
+    ///     .attach(ErrorCode(404));
+    /// let value = serde_json::to_value(&report)?;
+    ///
+    /// let attachment = &value[0]["attachments"][0];
+    /// assert!(
+    ///     attachment["type"]
+    ///         .as_str()
+    ///         .is_some_and(|name| name.ends_with("ErrorCode"))
+    /// );
+    /// assert_eq!(attachment["value"], 404);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn install_serde_hook<T: serde::Serialize + Send + Sync + 'static>() {
+        #[cfg(feature = "std")]
+        let mut lock = SERDE_HOOK.write().unwrap_or_else(|_| {
+            unreachable!(
+                "Hook is poisoned. This is considered a bug and should be reported to \
+                https://github.com/hashintel/hash/issues/new/choose"
+            )
+        });
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let mut lock = SERDE_HOOK.write();
+
+        lock.insert_serialize::<T>();
+    }
+
+    /// Can be used to globally set a deserialization hook for contexts of type `C`.
+    ///
+    /// When a [`Report`] is deserialized, contexts with the type name of `C`, which have been
+    /// serialized with a hook installed by [`install_serde_hook`], are restored as `C` using its
+    /// [`Deserialize`] implementation. All other contexts are restored as [`UnknownContext`].
+    ///
+    /// Installing a hook for a type, which already has a hook installed, replaces the previous
+    /// hook.
+    ///
+    /// [`install_serde_hook`]: Self::install_serde_hook
+    /// [`Deserialize`]: serde::Deserialize
+    /// [`UnknownContext`]: crate::UnknownContext
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::{error::Error, fmt};
+    ///
+    /// use error_stack::Report;
+    ///
+    /// #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    /// struct RequestError {
+    ///     status: u16,
+    /// }
+    ///
+    /// impl fmt::Display for RequestError {
+    ///     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(fmt, "request failed with status {}", self.status)
+    ///     }
+    /// }
+    ///
+    /// impl Error for RequestError {}
+    ///
+    /// Report::install_serde_hook::<RequestError>();
+    /// Report::install_context_deserialize_hook::<RequestError>();
+    ///
+    /// let report = Report::new(RequestError { status: 503 });
+    /// let serialized = serde_json::to_string(&report)?;
+    ///
+    /// let report: Report<RequestError> = serde_json::from_str(&serialized)?;
+    /// assert_eq!(report.current_context().status, 503);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn install_context_deserialize_hook<
+        C: core::error::Error + serde::de::DeserializeOwned + Send + Sync + 'static,
+    >() {
+        #[cfg(feature = "std")]
+        let mut lock = SERDE_HOOK.write().unwrap_or_else(|_| {
+            unreachable!(
+                "Hook is poisoned. This is considered a bug and should be reported to \
+                https://github.com/hashintel/hash/issues/new/choose"
+            )
+        });
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let mut lock = SERDE_HOOK.write();
+
+        lock.insert_deserialize_context::<C>();
+    }
+
+    /// Can be used to globally set a deserialization hook for attachments of type `A`.
+    ///
+    /// When a [`Report`] is deserialized, attachments with the type name of `A`, which have been
+    /// serialized with a hook installed by [`install_serde_hook`], are restored as `A` using its
+    /// [`Deserialize`] implementation. Without a hook, printable attachments are restored as
+    /// [`String`]s, while opaque attachments are skipped.
+    ///
+    /// Installing a hook for a type, which already has a hook installed, replaces the previous
+    /// hook.
+    ///
+    /// [`install_serde_hook`]: Self::install_serde_hook
+    /// [`Deserialize`]: serde::Deserialize
+    /// [`String`]: alloc::string::String
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{IntoReport, Report, UnknownContext};
+    ///
+    /// #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// struct ErrorCode(u16);
+    ///
+    /// Report::install_serde_hook::<ErrorCode>();
+    /// Report::install_attachment_deserialize_hook::<ErrorCode>();
+    ///
+    /// let report = Error::from(ErrorKind::NotFound)
+    ///     .into_report()
+    ///     .attach(ErrorCode(404));
+    /// let serialized = serde_json::to_string(&report)?;
+    ///
+    /// let report: Report<UnknownContext> = serde_json::from_str(&serialized)?;
+    /// assert_eq!(
+    ///     report.request_ref::<ErrorCode>().next(),
+    ///     Some(&ErrorCode(404))
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn install_attachment_deserialize_hook<
+        A: serde::de::DeserializeOwned + Send + Sync + 'static,
+    >() {
+        #[cfg(feature = "std")]
+        let mut lock = SERDE_HOOK.write().unwrap_or_else(|_| {
+            unreachable!(
+                "Hook is poisoned. This is considered a bug and should be reported to \
+                https://github.com/hashintel/hash/issues/new/choose"
+            )
+        });
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let mut lock = SERDE_HOOK.write();
+
+        lock.insert_deserialize_attachment::<A>();
+    }
+
+    /// Returns the hooks that were previously set by [`install_serde_hook`],
+    /// [`install_context_deserialize_hook`] and [`install_attachment_deserialize_hook`]
+    ///
+    /// [`install_serde_hook`]: Self::install_serde_hook
+    /// [`install_context_deserialize_hook`]: Self::install_context_deserialize_hook
+    /// [`install_attachment_deserialize_hook`]: Self::install_attachment_deserialize_hook
+    #[cfg(feature = "serde")]
+    pub(crate) fn invoke_serde_hook<T>(closure: impl FnOnce(&SerdeHooks) -> T) -> T {
+        #[cfg(feature = "std")]
+        let hook = SERDE_HOOK.read().unwrap_or_else(|_| {
+            unreachable!(
+                "Hook is poisoned. This is considered a bug and should be reported to \
+                https://github.com/hashintel/hash/issues/new/choose"
+            )
+        });
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let hook = SERDE_HOOK.read();
+
+        closure(&hook)
+    }
 }
@@ -431,6 +431,19 @@
 //! You can add new hooks with [`Report::install_debug_hook`]. Refer to the module-level
 //! documentation of [`fmt`] for further information.
 //!
+//! ### Serialization Hooks
+//!
+//! With the `serde` feature enabled, a [`Report`] can be serialized. By default only the
+//! [`Display`] output of contexts and printable attachments is serialized. Types implementing
+//! `Serialize` can be registered with `Report::install_serde_hook`, so that contexts and
+//! attachments of that type are serialized as structured values alongside their type name.
+//!
+//! A serialized [`Report`] can be deserialized again, which rebuilds its frames. Contexts and
+//! attachments are restored as their original type if a deserialization hook has been installed
+//! with `Report::install_context_deserialize_hook` or
+//! `Report::install_attachment_deserialize_hook`. Other contexts are restored as `UnknownContext`,
+//! which retains their type name and message.
+//!
 //! ### Additional Adaptors
 //!
 //! [`ResultExt`] is a convenient wrapper around `Result<_, impl Error>` and `Result<_, Report<impl
@@ -452,6 +465,14 @@
 //! To automatically detect support if your target output supports unicode and colors you can check
 //! out the `detect.rs` example.
 //!
+//! For log aggregation, the [`Debug`] output can be switched to a machine-readable format with
+//! [`Report::set_output_format`]. [`OutputFormat::Json`] and [`OutputFormat::Logfmt`] emit every
+//! context with its type name, location, attachments and sources, while still respecting the
+//! installed debug hooks.
+//!
+//! [`OutputFormat::Json`]: fmt::OutputFormat::Json
+//! [`OutputFormat::Logfmt`]: fmt::OutputFormat::Logfmt
+//!
 //! ### Feature Flags
 //!
 //!  Feature       | Description                                                         | default
@@ -460,7 +481,7 @@
 //! `backtrace`    | Enables automatic capturing of [`Backtrace`]s (requires Rust 1.65+) | enabled
 //! `spantrace`    | Enables automatic capturing of [`SpanTrace`]s                       | disabled
 //! `hooks`        | Enables hooks on `no-std` platforms using spin locks                | disabled
-//! `serde`        | Enables serialization support for [`Report`]                        | disabled
+//! `serde`        | Enables (de)serialization support for [`Report`]                    | disabled
 //! `anyhow`       | Provides `into_report` to convert [`anyhow::Error`] to [`Report`]   | disabled
 //! `eyre`         | Provides `into_report` to convert [`eyre::Report`] to [`Report`]    | disabled
 //! `futures`      | Enables support for [`Stream`], requires `unstable`                 | disabled
@@ -522,6 +543,8 @@ pub use self::ext::stream::TryReportStreamExt;
 pub use self::ext::{iter::TryReportIteratorExt, tuple::TryReportTupleExt};
 #[expect(deprecated, reason = "We are moving to a more explicit API")]
 pub use self::result::Result;
+#[cfg(feature = "serde")]
+pub use self::serde::UnknownContext;
 #[cfg(feature = "unstable")]
 pub use self::sink::ReportSink;
 pub use self::{
@@ -494,6 +494,17 @@ impl<C: ?Sized> Report<C> {
         &self.frames
     }
 
+    /// Creates a `Report` from its current frames without capturing a location or backtrace.
+    ///
+    /// The caller is responsible for the current frames being contexts of type `C`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_frames_unchecked(frames: Vec<Frame>) -> Self {
+        Self {
+            frames: Box::new(frames),
+            _context: PhantomData,
+        }
+    }
+
     /// Adds additional information to the [`Frame`] stack.
     ///
     /// This behaves like [`attach_printable()`] but will not be shown when printing the [`Report`].
@@ -0,0 +1,393 @@
+#![expect(deprecated, reason = "We use `Context` to maintain compatibility")]
+
+//! Implementation of [`Report`] deserialization.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    any::{TypeId, type_name},
+    error::Error,
+    fmt,
+};
+
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+};
+
+use crate::{
+    Context, Frame, Report,
+    serde::{DeserializeHook, FrameBuilder},
+};
+
+/// A context of a deserialized [`Report`] whose type is not known on the receiving side.
+///
+/// A context is restored as `UnknownContext` if no deserialization hook has been installed for its
+/// type, or if its value has not been serialized. It retains the type name and the [`Display`]
+/// output of the original context.
+///
+/// [`Display`]: core::fmt::Display
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::{Error, ErrorKind};
+///
+/// use error_stack::{IntoReport, Report, UnknownContext};
+///
+/// let report = Error::from(ErrorKind::NotFound).into_report();
+/// let serialized = serde_json::to_string(&report)?;
+///
+/// let report: Report<UnknownContext> = serde_json::from_str(&serialized)?;
+/// let context = report.current_context();
+///
+/// assert_eq!(context.type_name(), "std::io::error::Error");
+/// assert_eq!(context.to_string(), "entity not found");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownContext {
+    type_name: String,
+    message: String,
+}
+
+impl UnknownContext {
+    /// Returns the type name of the original context.
+    #[must_use]
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the [`Display`] output of the original context.
+    ///
+    /// [`Display`]: core::fmt::Display
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for UnknownContext {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.message)
+    }
+}
+
+impl Error for UnknownContext {}
+
+fn context_hook(type_name: &str) -> Option<DeserializeHook> {
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    {
+        Report::invoke_serde_hook(|hooks| hooks.deserialize_context(type_name))
+    }
+
+    #[cfg(not(any(feature = "std", feature = "hooks")))]
+    {
+        let _: &str = type_name;
+        None
+    }
+}
+
+fn attachment_hook(type_name: &str) -> Option<DeserializeHook> {
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    {
+        Report::invoke_serde_hook(|hooks| hooks.deserialize_attachment(type_name))
+    }
+
+    #[cfg(not(any(feature = "std", feature = "hooks")))]
+    {
+        let _: &str = type_name;
+        None
+    }
+}
+
+/// Deserializes the value of a context or attachment through the hook installed for its type.
+struct DeserializeValue(DeserializeHook);
+
+impl<'de> DeserializeSeed<'de> for DeserializeValue {
+    type Value = FrameBuilder;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+
+        (self.0)(&mut deserializer).map_err(de::Error::custom)
+    }
+}
+
+/// Reads the `value` field, if the type is already known and a hook has been installed for it.
+///
+/// The value can only be restored if the `type` field precedes it, which is the case for the
+/// output of the serializer as well as for formats which order fields alphabetically.
+fn next_value<'de, A>(
+    map: &mut A,
+    type_name: Option<&str>,
+    hook: fn(&str) -> Option<DeserializeHook>,
+) -> Result<Option<FrameBuilder>, A::Error>
+where
+    A: MapAccess<'de>,
+{
+    match type_name.and_then(hook) {
+        Some(hook) => map.next_value_seed(DeserializeValue(hook)).map(Some),
+        None => map.next_value::<IgnoredAny>().map(|_| None),
+    }
+}
+
+enum Field {
+    Context,
+    Attachment,
+    Type,
+    Value,
+    Attachments,
+    Sources,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a field identifier")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(match value {
+                    "context" => Field::Context,
+                    "attachment" => Field::Attachment,
+                    "type" => Field::Type,
+                    "value" => Field::Value,
+                    "attachments" => Field::Attachments,
+                    "sources" => Field::Sources,
+                    _ => Field::Other,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// An attachment as emitted by the serializer.
+struct SerializedAttachment {
+    attachment: Option<String>,
+    value: Option<FrameBuilder>,
+}
+
+impl SerializedAttachment {
+    /// Attaches this attachment to `source`.
+    ///
+    /// Opaque attachments, which cannot be restored, are skipped.
+    fn attach_to(self, source: Frame) -> Frame {
+        match (self.value, self.attachment) {
+            (Some(builder), _) => builder(Box::new([source])),
+            (None, Some(attachment)) => {
+                Frame::from_printable_attachment(attachment, Box::new([source]))
+            }
+            (None, None) => source,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SerializedAttachment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AttachmentVisitor;
+
+        impl<'de> Visitor<'de> for AttachmentVisitor {
+            type Value = SerializedAttachment;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a serialized attachment")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attachment = None;
+                let mut type_name: Option<String> = None;
+                let mut value = None;
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Attachment => attachment = Some(map.next_value()?),
+                        Field::Type => type_name = Some(map.next_value()?),
+                        Field::Value => {
+                            value = next_value(&mut map, type_name.as_deref(), attachment_hook)?;
+                        }
+                        Field::Context | Field::Attachments | Field::Sources | Field::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                if type_name.is_none() {
+                    return Err(de::Error::missing_field("type"));
+                }
+
+                Ok(SerializedAttachment { attachment, value })
+            }
+        }
+
+        deserializer.deserialize_map(AttachmentVisitor)
+    }
+}
+
+/// A context, its attachments and its sources as emitted by the serializer.
+struct SerializedContext {
+    context: String,
+    type_name: String,
+    value: Option<FrameBuilder>,
+    attachments: Vec<SerializedAttachment>,
+    sources: Vec<SerializedContext>,
+}
+
+impl SerializedContext {
+    /// Rebuilds the frames of this context.
+    ///
+    /// If `placeholder` is set, the context is restored as [`UnknownContext`] even if it could be
+    /// restored as its original type.
+    fn into_frame(self, placeholder: bool) -> Frame {
+        let sources = self
+            .sources
+            .into_iter()
+            .map(|source| source.into_frame(false))
+            .collect();
+
+        let frame = match self.value {
+            Some(builder) if !placeholder => builder(sources),
+            _ => Frame::from_context(
+                UnknownContext {
+                    type_name: self.type_name,
+                    message: self.context,
+                },
+                sources,
+            ),
+        };
+
+        self.attachments
+            .into_iter()
+            .fold(frame, |frame, attachment| attachment.attach_to(frame))
+    }
+
+    /// Rebuilds the frames of this context, which is expected to be of type `C`.
+    fn into_current_frame<C: Context, E: de::Error>(self) -> Result<Frame, E> {
+        if TypeId::of::<C>() == TypeId::of::<UnknownContext>() {
+            return Ok(self.into_frame(true));
+        }
+
+        if self.value.is_none() || self.type_name != type_name::<C>() {
+            return Err(E::custom(format_args!(
+                "expected a context of type `{}` with a value, found `{}`",
+                type_name::<C>(),
+                self.type_name
+            )));
+        }
+
+        Ok(self.into_frame(false))
+    }
+}
+
+impl<'de> Deserialize<'de> for SerializedContext {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ContextVisitor;
+
+        impl<'de> Visitor<'de> for ContextVisitor {
+            type Value = SerializedContext;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a serialized context")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut context = None;
+                let mut type_name: Option<String> = None;
+                let mut value = None;
+                let mut attachments = Vec::new();
+                let mut sources = Vec::new();
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Context => context = Some(map.next_value()?),
+                        Field::Type => type_name = Some(map.next_value()?),
+                        Field::Value => {
+                            value = next_value(&mut map, type_name.as_deref(), context_hook)?;
+                        }
+                        Field::Attachments => attachments = map.next_value()?,
+                        Field::Sources => sources = map.next_value()?,
+                        Field::Attachment | Field::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                Ok(SerializedContext {
+                    context: context.ok_or_else(|| de::Error::missing_field("context"))?,
+                    type_name: type_name.ok_or_else(|| de::Error::missing_field("type"))?,
+                    value,
+                    attachments,
+                    sources,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(ContextVisitor)
+    }
+}
+
+impl<'de, C: Context> Deserialize<'de> for Report<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let contexts = Vec::<SerializedContext>::deserialize(deserializer)?;
+        let [context] = <[SerializedContext; 1]>::try_from(contexts).map_err(|contexts| {
+            de::Error::invalid_length(contexts.len(), &"exactly one current context")
+        })?;
+
+        Ok(Self::from_frames_unchecked(alloc::vec![
+            context.into_current_frame::<C, D::Error>()?
+        ]))
+    }
+}
+
+impl<'de, C: Context> Deserialize<'de> for Report<[C]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let contexts = Vec::<SerializedContext>::deserialize(deserializer)?;
+        if contexts.is_empty() {
+            return Err(de::Error::invalid_length(
+                0,
+                &"at least one current context",
+            ));
+        }
+
+        let frames = contexts
+            .into_iter()
+            .map(SerializedContext::into_current_frame::<C, D::Error>)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::from_frames_unchecked(frames))
+    }
+}
@@ -0,0 +1,156 @@
+//! Implementation of general [`Report`] serialization and deserialization.
+//!
+//! Contexts are serialized with their [`Display`] output, printable attachments with their
+//! [`Display`] output as well. Values of any other type are only serialized if a hook has been
+//! installed for their type through [`Report::install_serde_hook`], in which case the value is
+//! serialized using its [`Serialize`] implementation. Opaque attachments without a hook are
+//! skipped.
+//!
+//! The serialized [`Report`] is a list of all current sources with the following output:
+//!
+//! ```json
+//! {
+//!     "context": "context display output",
+//!     "type": "type name of the context",
+//!     "value": {}, // only present if a hook has been installed for the context
+//!     "attachments": [
+//!         {
+//!             "attachment": "attachment display output", // only present if printable
+//!             "type": "type name of the attachment",
+//!             "value": {} // only present if a hook has been installed for the attachment
+//!         }
+//!     ], // all attachments leading up to this context
+//!     "sources": [] // recursive render using `frame.sources()`
+//! }
+//! ```
+//!
+//! When deserializing, the frame tree is rebuilt from this output. Contexts and attachments are
+//! restored as their original type if a deserialization hook has been installed for the type name
+//! through [`Report::install_context_deserialize_hook`] or
+//! [`Report::install_attachment_deserialize_hook`], and `value` is present. Otherwise, contexts
+//! are restored as [`UnknownContext`] and printable attachments as [`String`]s, while opaque
+//! attachments are skipped. Attachments which are shared by multiple sources are serialized once
+//! for every source and are therefore restored once for every source as well.
+//!
+//! [`Display`]: core::fmt::Display
+//! [`String`]: alloc::string::String
+
+mod de;
+mod ser;
+
+use alloc::boxed::Box;
+#[cfg(any(feature = "std", feature = "hooks"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(any(feature = "std", feature = "hooks"))]
+use core::any::{TypeId, type_name};
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+use serde::{Serialize, de::DeserializeOwned};
+
+pub use self::de::UnknownContext;
+#[cfg(any(feature = "std", feature = "hooks"))]
+#[expect(deprecated, reason = "We use `Context` to maintain compatibility")]
+use crate::Context;
+use crate::Frame;
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+type SerializeHook = for<'a> fn(&'a Frame) -> Option<&'a dyn erased_serde::Serialize>;
+
+/// Creates the [`Frame`] of a deserialized context or attachment from its sources.
+pub(crate) type FrameBuilder = Box<dyn FnOnce(Box<[Frame]>) -> Frame>;
+
+pub(crate) type DeserializeHook = for<'de> fn(
+    &mut dyn erased_serde::Deserializer<'de>,
+) -> Result<FrameBuilder, erased_serde::Error>;
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+fn serialize_hook<T: Serialize + Send + Sync + 'static>(
+    frame: &Frame,
+) -> Option<&dyn erased_serde::Serialize> {
+    frame
+        .downcast_ref::<T>()
+        .map(|value| value as &dyn erased_serde::Serialize)
+}
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+#[expect(deprecated, reason = "We use `Context` to maintain compatibility")]
+fn deserialize_context_hook<C: Context + DeserializeOwned>(
+    deserializer: &mut dyn erased_serde::Deserializer<'_>,
+) -> Result<FrameBuilder, erased_serde::Error> {
+    let context = erased_serde::deserialize::<C>(deserializer)?;
+
+    Ok(Box::new(move |sources| {
+        Frame::from_context(context, sources)
+    }))
+}
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+fn deserialize_attachment_hook<A: DeserializeOwned + Send + Sync + 'static>(
+    deserializer: &mut dyn erased_serde::Deserializer<'_>,
+) -> Result<FrameBuilder, erased_serde::Error> {
+    let attachment = erased_serde::deserialize::<A>(deserializer)?;
+
+    Ok(Box::new(move |sources| {
+        Frame::from_attachment(attachment, sources)
+    }))
+}
+
+/// Holds the list of serialization and deserialization hooks.
+///
+/// A serialization hook is selected by the [`TypeId`] of the context or attachment of a [`Frame`],
+/// a deserialization hook by the type name in the serialized output. At most one hook of each kind
+/// is installed per type.
+#[cfg(any(feature = "std", feature = "hooks"))]
+pub(crate) struct SerdeHooks {
+    serialize: Vec<(TypeId, SerializeHook)>,
+    deserialize_context: BTreeMap<&'static str, DeserializeHook>,
+    deserialize_attachment: BTreeMap<&'static str, DeserializeHook>,
+}
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+impl SerdeHooks {
+    pub(crate) const fn new() -> Self {
+        Self {
+            serialize: Vec::new(),
+            deserialize_context: BTreeMap::new(),
+            deserialize_attachment: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn insert_serialize<T: Serialize + Send + Sync + 'static>(&mut self) {
+        let type_id = TypeId::of::<T>();
+
+        self.serialize.retain(|(id, _)| *id != type_id);
+        self.serialize.push((type_id, serialize_hook::<T>));
+    }
+
+    #[expect(deprecated, reason = "We use `Context` to maintain compatibility")]
+    pub(crate) fn insert_deserialize_context<C: Context + DeserializeOwned>(&mut self) {
+        self.deserialize_context
+            .insert(type_name::<C>(), deserialize_context_hook::<C>);
+    }
+
+    pub(crate) fn insert_deserialize_attachment<A: DeserializeOwned + Send + Sync + 'static>(
+        &mut self,
+    ) {
+        self.deserialize_attachment
+            .insert(type_name::<A>(), deserialize_attachment_hook::<A>);
+    }
+
+    pub(crate) fn serialize(&self, frame: &Frame) -> Option<SerializeHook> {
+        let type_id = frame.type_id();
+
+        self.serialize
+            .iter()
+            .find(|(id, _)| *id == type_id)
+            .map(|&(_, hook)| hook)
+    }
+
+    pub(crate) fn deserialize_context(&self, type_name: &str) -> Option<DeserializeHook> {
+        self.deserialize_context.get(type_name).copied()
+    }
+
+    pub(crate) fn deserialize_attachment(&self, type_name: &str) -> Option<DeserializeHook> {
+        self.deserialize_attachment.get(type_name).copied()
+    }
+}
@@ -1,25 +1,46 @@
 #![expect(deprecated, reason = "We use `Context` to maintain compatibility")]
 
-//! Implementation of general [`Report`] serialization.
-//!
-//! The value can be of any type, currently only printable attachments and context are supported, in
-//! the near future any values will be supported through the use of hooks.
-//!
-//! The serialized [`Report`] is a list of all current sources with the following output:
-//!
-//! ```json
-//! {
-//!     "context": "context display output",
-//!     "attachments": ["all", "attachments", "leading", "up", "to", "this", "context"],
-//!     "sources": [] // recursive render using `frame.sources()`
-//! }
-//! ```
+//! Implementation of [`Report`] serialization.
 
 use alloc::{format, vec, vec::Vec};
 
 use serde::{Serialize, Serializer, ser::SerializeMap as _};
 
-use crate::{AttachmentKind, Context, Frame, FrameKind, Report};
+use crate::{AttachmentKind, Context, Frame, FrameKind, Report, serde::UnknownContext};
+
+/// Serializes the value of the frame through the hook installed for its type, if any.
+struct SerializeValue<'a>(&'a dyn erased_serde::Serialize);
+
+impl Serialize for SerializeValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        erased_serde::serialize(self.0, serializer)
+    }
+}
+
+#[cfg_attr(
+    not(any(feature = "std", feature = "hooks")),
+    expect(
+        clippy::missing_const_for_fn,
+        reason = "Hooks are only available with `std` or `hooks`"
+    )
+)]
+fn hooked_value(frame: &Frame) -> Option<SerializeValue<'_>> {
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    {
+        let hook = Report::invoke_serde_hook(|hooks| hooks.serialize(frame))?;
+
+        hook(frame).map(SerializeValue)
+    }
+
+    #[cfg(not(any(feature = "std", feature = "hooks")))]
+    {
+        let _: &Frame = frame;
+        None
+    }
+}
 
 struct SerializeAttachment<'a>(&'a Frame);
 
@@ -30,21 +51,24 @@ impl Serialize for SerializeAttachment<'_> {
     {
         let Self(frame) = self;
 
-        match frame.kind() {
-            FrameKind::Context(_) => {
-                // TODO: for now `Error` is unsupported, upcoming PR will fix via hooks
-                // `SerializeAttachmentList` ensures that no context is ever serialized
-                unimplemented!()
-            }
-            FrameKind::Attachment(AttachmentKind::Opaque(_)) => {
-                // TODO: for now opaque attachments are unsupported, upcoming PR will fix that
-                // `SerializeAttachmentList` ensures that no such attachment is added
-                unimplemented!()
-            }
-            FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
-                format!("{attachment}").serialize(serializer)
-            }
+        let attachment = match frame.kind() {
+            FrameKind::Attachment(AttachmentKind::Printable(attachment)) => Some(attachment),
+            FrameKind::Context(_) | FrameKind::Attachment(AttachmentKind::Opaque(_)) => None,
+        };
+        let value = hooked_value(frame);
+
+        let mut map = serializer.serialize_map(Some(
+            1 + usize::from(attachment.is_some()) + usize::from(value.is_some()),
+        ))?;
+        if let Some(attachment) = attachment {
+            map.serialize_entry("attachment", &format!("{attachment}"))?;
+        }
+        map.serialize_entry("type", frame.type_name())?;
+        if let Some(value) = value {
+            map.serialize_entry("value", &value)?;
         }
+
+        map.end()
     }
 }
 
@@ -59,12 +83,13 @@ impl Serialize for SerializeAttachmentList<'_, '_> {
             self.0
                 .iter()
                 .copied()
-                .filter(|attachment| {
-                    // for now opaque attachments are ignored
-                    !matches!(
-                        attachment.kind(),
-                        FrameKind::Attachment(AttachmentKind::Opaque(_))
-                    )
+                .filter(|attachment| match attachment.kind() {
+                    FrameKind::Context(_) => false,
+                    // opaque attachments are only serialized if a hook has been installed
+                    FrameKind::Attachment(AttachmentKind::Opaque(_)) => {
+                        hooked_value(attachment).is_some()
+                    }
+                    FrameKind::Attachment(AttachmentKind::Printable(_)) => true,
                 })
                 .map(SerializeAttachment),
         )
@@ -73,6 +98,7 @@ impl Serialize for SerializeAttachmentList<'_, '_> {
 
 struct SerializeContext<'a> {
     attachments: Vec<&'a Frame>,
+    frame: &'a Frame,
     context: &'a dyn Context,
     sources: &'a [Frame],
 }
@@ -83,13 +109,25 @@ impl Serialize for SerializeContext<'_> {
         S: Serializer,
     {
         let Self {
-            context,
             attachments,
+            frame,
+            context,
             sources,
         } = self;
 
-        let mut map = serializer.serialize_map(Some(3))?;
+        let value = hooked_value(frame);
+
+        let mut map = serializer.serialize_map(Some(4 + usize::from(value.is_some())))?;
+        // Contexts which were unknown when deserializing retain their original type name
+        let type_name = frame
+            .downcast_ref::<UnknownContext>()
+            .map_or_else(|| frame.type_name(), UnknownContext::type_name);
+
         map.serialize_entry("context", &format!("{context}").as_str())?;
+        map.serialize_entry("type", type_name)?;
+        if let Some(value) = value {
+            map.serialize_entry("value", &value)?;
+        }
         map.serialize_entry("attachments", &SerializeAttachmentList(attachments))?;
         map.serialize_entry("sources", &SerializeSources(sources))?;
 
@@ -120,6 +158,7 @@ fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SerializeCon
 
             return vec![SerializeContext {
                 attachments,
+                frame: current,
                 context,
                 sources: current.sources(),
             }];
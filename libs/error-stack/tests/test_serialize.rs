@@ -6,12 +6,45 @@
 #![cfg(not(miri))]
 #![cfg_attr(nightly, feature(error_generic_member_access))]
 
+use core::{error::Error, fmt};
+
+use error_stack::{Report, UnknownContext};
 use insta::assert_ron_snapshot;
+use serde::{Deserialize, Serialize};
 
-use crate::common::{ContextA, PrintableA, PrintableC, create_report};
+use crate::common::{
+    AttachmentA, ContextA, PrintableA, PrintableC, create_report, messages, remove_builtin_messages,
+};
 
 mod common;
 
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct ErrorCode(u16);
+
+#[derive(Debug, Serialize)]
+struct Retry {
+    attempts: u32,
+}
+
+impl fmt::Display for Retry {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "retry after {} attempts", self.attempts)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RequestError {
+    status: u16,
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "request failed with status {}", self.status)
+    }
+}
+
+impl Error for RequestError {}
+
 fn prepare() -> impl Drop {
     std::env::set_var("RUST_LIB_BACKTRACE", "0");
 
@@ -68,3 +101,147 @@ fn multiple_sources_at_root() {
 
     assert_ron_snapshot!(report_a);
 }
+
+#[test]
+fn hooked_attachments() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<ErrorCode>();
+    Report::install_serde_hook::<Retry>();
+
+    let report = create_report()
+        .attach(ErrorCode(404))
+        .attach(AttachmentA(1))
+        .attach_printable(Retry { attempts: 3 })
+        .attach_printable(PrintableA(2));
+
+    assert_ron_snapshot!(report);
+}
+
+#[test]
+fn hooked_context() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<RequestError>();
+
+    let report = create_report()
+        .attach_printable(PrintableA(1))
+        .change_context(RequestError { status: 503 });
+
+    assert_ron_snapshot!(report);
+}
+
+/// Returns the messages of the report, which are expected to survive a serialization round trip.
+fn serialized_messages<C>(report: &Report<C>) -> Vec<String> {
+    remove_builtin_messages(messages(report))
+        .into_iter()
+        .filter(|message| message != "opaque")
+        .collect()
+}
+
+#[test]
+fn deserialize_unknown_context() {
+    let _guard = prepare();
+
+    let mut report = create_report().attach_printable(PrintableC(1)).expand();
+    report.push(create_report().attach_printable(PrintableC(2)));
+    let report = report
+        .attach(AttachmentA(1))
+        .attach_printable(PrintableC(3))
+        .change_context(ContextA(2))
+        .attach_printable(PrintableC(4));
+
+    let serialized = serde_json::to_value(&report).expect("report should be serializable");
+    let deserialized: Report<UnknownContext> =
+        serde_json::from_value(serialized.clone()).expect("report should be deserializable");
+
+    let context = deserialized.current_context();
+    assert_eq!(context.type_name(), core::any::type_name::<ContextA>());
+    assert_eq!(context.message(), "context A");
+
+    // The attachment shared by both sources is restored once for every source
+    let mut expected = serialized_messages(&report);
+    expected.insert(5, String::from("printable C: 3"));
+    assert_eq!(messages(&deserialized), expected);
+    assert_eq!(
+        deserialized
+            .frames()
+            .filter(|frame| frame.is::<UnknownContext>())
+            .count(),
+        3
+    );
+
+    // Unknown contexts retain their original type name when serialized again
+    let reserialized = serde_json::to_value(&deserialized).expect("report should be serializable");
+    assert_eq!(reserialized[0]["type"], serialized[0]["type"]);
+    assert_eq!(
+        reserialized[0]["sources"][1]["type"],
+        serialized[0]["sources"][1]["type"]
+    );
+}
+
+#[test]
+fn deserialize_multiple_sources_at_root() {
+    let _guard = prepare();
+
+    let mut report = create_report().attach_printable(PrintableC(1)).expand();
+    report.push(create_report().attach_printable(PrintableC(2)));
+
+    let serialized = serde_json::to_string(&report).expect("report should be serializable");
+    let deserialized: Report<[UnknownContext]> =
+        serde_json::from_str(&serialized).expect("report should be deserializable");
+
+    let contexts = deserialized.current_contexts().collect::<Vec<_>>();
+    assert_eq!(contexts.len(), 2);
+    for context in contexts {
+        assert_eq!(context.message(), "root error");
+    }
+
+    let printable = deserialized
+        .frames()
+        .filter_map(|frame| frame.downcast_ref::<String>())
+        .collect::<Vec<_>>();
+    assert_eq!(printable, ["printable C: 1", "printable C: 2"]);
+
+    serde_json::from_str::<Report<UnknownContext>>(&serialized)
+        .expect_err("report should have exactly one current context");
+}
+
+#[test]
+fn deserialize_hooked() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<ErrorCode>();
+    Report::install_serde_hook::<RequestError>();
+    Report::install_attachment_deserialize_hook::<ErrorCode>();
+    Report::install_context_deserialize_hook::<RequestError>();
+
+    let report = create_report()
+        .attach(ErrorCode(404))
+        .attach_printable(PrintableA(1))
+        .change_context(RequestError { status: 503 });
+
+    let serialized = serde_json::to_string(&report).expect("report should be serializable");
+    let deserialized: Report<RequestError> =
+        serde_json::from_str(&serialized).expect("report should be deserializable");
+
+    assert_eq!(deserialized.current_context().status, 503);
+    assert_eq!(
+        deserialized.downcast_ref::<ErrorCode>(),
+        Some(&ErrorCode(404))
+    );
+    assert_eq!(
+        deserialized
+            .downcast_ref::<UnknownContext>()
+            .map(UnknownContext::message),
+        Some("root error")
+    );
+    assert_eq!(
+        deserialized.downcast_ref::<String>().map(String::as_str),
+        Some("printable A")
+    );
+
+    let error = serde_json::from_str::<Report<ContextA>>(&serialized)
+        .expect_err("current context should not be restored as a different type");
+    assert!(error.to_string().contains("expected a context of type"));
+}
@@ -0,0 +1,203 @@
+#![cfg(feature = "std")]
+#![cfg(not(miri))] // debug formatting does not utilize any unsafe code
+#![cfg_attr(nightly, feature(error_generic_member_access))]
+
+mod common;
+
+use core::{error::Error, fmt};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use common::*;
+use error_stack::{Report, fmt::OutputFormat};
+use serde_json::Value;
+
+#[derive(Debug)]
+struct MultilineError;
+
+impl fmt::Display for MultilineError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("first \"line\"\nsecond line")
+    }
+}
+
+impl Error for MultilineError {}
+
+struct Suggestion(&'static str);
+
+/// The output format is a global preference, tests selecting different formats must not run
+/// concurrently.
+fn prepare(format: OutputFormat) -> MutexGuard<'static, ()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    let guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+    std::env::set_var("RUST_LIB_BACKTRACE", "0");
+    Report::set_output_format(format);
+
+    guard
+}
+
+fn json<C: ?Sized>(report: &Report<C>) -> Value {
+    serde_json::from_str(&format!("{report:?}")).expect("output should be valid JSON")
+}
+
+#[test]
+fn json_linear() {
+    let _guard = prepare(OutputFormat::Json);
+
+    let report = create_report()
+        .attach_printable(PrintableA(1))
+        .attach(AttachmentA(2))
+        .change_context(ContextA(3))
+        .attach_printable(PrintableB(4));
+
+    let value = json(&report);
+    let contexts = value["contexts"]
+        .as_array()
+        .expect("contexts should be a list");
+    assert_eq!(contexts.len(), 1);
+
+    let context = &contexts[0];
+    assert_eq!(context["context"], "context A");
+    assert_eq!(context["type"], core::any::type_name::<ContextA>());
+    assert_eq!(context["attachments"], serde_json::json!(["printable B"]));
+    assert_eq!(context["opaque"], 0);
+    assert!(
+        context["location"]["file"]
+            .as_str()
+            .is_some_and(|file| file.ends_with("test_structured.rs"))
+    );
+
+    let source = &context["sources"][0];
+    assert_eq!(source["context"], "root error");
+    assert_eq!(source["type"], core::any::type_name::<RootError>());
+    assert_eq!(source["attachments"], serde_json::json!(["printable A"]));
+    assert_eq!(source["opaque"], 1);
+    assert!(
+        source["location"]["file"]
+            .as_str()
+            .is_some_and(|file| file.ends_with("common.rs"))
+    );
+    assert_eq!(source["sources"], serde_json::json!([]));
+}
+
+#[test]
+fn json_sources() {
+    let _guard = prepare(OutputFormat::Json);
+
+    let mut report = create_report().attach_printable(PrintableC(1)).expand();
+    report.push(create_report().attach_printable(PrintableC(2)));
+    let report = report.attach_printable(PrintableC(3));
+
+    let value = json(&report);
+    let contexts = value["contexts"]
+        .as_array()
+        .expect("contexts should be a list");
+    assert_eq!(contexts.len(), 2);
+
+    // shared attachments are output for every context
+    assert_eq!(
+        contexts[0]["attachments"],
+        serde_json::json!(["printable C: 1", "printable C: 3"])
+    );
+    assert_eq!(
+        contexts[1]["attachments"],
+        serde_json::json!(["printable C: 2", "printable C: 3"])
+    );
+}
+
+#[test]
+fn json_escape() {
+    let _guard = prepare(OutputFormat::Json);
+
+    let report = Report::new(MultilineError).attach_printable("tab\tseparated \\ value");
+
+    let value = json(&report);
+    assert_eq!(
+        value["contexts"][0]["context"],
+        "first \"line\"\nsecond line"
+    );
+    assert_eq!(
+        value["contexts"][0]["attachments"],
+        serde_json::json!(["tab\tseparated \\ value"])
+    );
+}
+
+#[test]
+fn hooks() {
+    let _guard = prepare(OutputFormat::Json);
+
+    Report::install_debug_hook::<Suggestion>(|Suggestion(value), context| {
+        context.push_body(format!("suggestion: {value}"));
+        if context.alternate() {
+            context.push_appendix(format!("full suggestion: {value}"));
+        }
+    });
+
+    let report = create_report().attach(Suggestion("try again"));
+
+    let value = json(&report);
+    assert_eq!(
+        value["contexts"][0]["attachments"],
+        serde_json::json!(["suggestion: try again"])
+    );
+    assert_eq!(value["contexts"][0]["opaque"], 0);
+    assert_eq!(value["appendix"], serde_json::json!([]));
+
+    let value: Value =
+        serde_json::from_str(&format!("{report:#?}")).expect("output should be valid JSON");
+    assert_eq!(
+        value["appendix"],
+        serde_json::json!(["full suggestion: try again"])
+    );
+}
+
+#[test]
+fn logfmt() {
+    let _guard = prepare(OutputFormat::Logfmt);
+
+    let mut report = create_report()
+        .attach_printable(PrintableC(1))
+        .change_context(MultilineError)
+        .expand();
+    report.push(Report::new(MultilineError).attach(AttachmentA(2)));
+    let report = report.change_context(ContextA(3));
+
+    let output = format!("{report:?}");
+    let lines = output
+        .lines()
+        .map(|line| {
+            // locations are different for every line, they are checked separately
+            let (start, end) = line
+                .split_once(" location=")
+                .expect("line should have a location");
+            let end = end.split_once(' ').map_or("", |(_, end)| end);
+
+            format!("{start} {end}")
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        lines,
+        [
+            format!(
+                "id=0 context=\"context A\" type={} opaque=0",
+                core::any::type_name::<ContextA>()
+            ),
+            format!(
+                "id=1 parent=0 context=\"first \\\"line\\\"\\nsecond line\" type={} opaque=0",
+                core::any::type_name::<MultilineError>()
+            ),
+            format!(
+                "id=2 parent=1 context=\"root error\" type={} attachment=\"printable C: 1\" \
+                 opaque=0",
+                core::any::type_name::<RootError>()
+            ),
+            format!(
+                "id=3 parent=0 context=\"first \\\"line\\\"\\nsecond line\" type={} opaque=1",
+                core::any::type_name::<MultilineError>()
+            ),
+        ]
+    );
+    assert!(output.contains("location=libs/error-stack/tests/test_structured.rs:"));
+}
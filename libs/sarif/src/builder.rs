@@ -0,0 +1,418 @@
+//! Fluent construction of SARIF logs.
+//!
+//! The types in [`schema`] mirror the SARIF specification and can be composed directly. When a
+//! tool reports results as it goes, however, it also has to keep track of the rules it has
+//! already described, so that each result can refer to its rule by index ([§3.27.6]).
+//! [`RunBuilder`] takes care of this bookkeeping.
+//!
+//! [`schema`]: crate::schema
+//! [§3.27.6]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317644
+
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+use crate::schema::{
+    Message, ReportingDescriptor, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+};
+
+/// The index of a rule registered with a [`RunBuilder`].
+///
+/// The index refers to the position of the rule in the rules of the tool's driver and is only
+/// meaningful for the builder which returned it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RuleIndex(usize);
+
+impl RuleIndex {
+    /// Returns the position of the rule in the rules of the tool's driver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{ReportingDescriptor, ToolComponent},
+    /// };
+    ///
+    /// let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+    /// let rule = run.register_rule(ReportingDescriptor::new("unused-variable"));
+    ///
+    /// assert_eq!(rule.index(), 0);
+    /// ```
+    #[must_use]
+    pub const fn index(self) -> u64 {
+        self.0 as u64
+    }
+}
+
+/// A builder for a single [`Run`], which registers rules and attaches them to results.
+///
+/// Rules are identified by their [`id`]. Registering a rule whose identifier is already known
+/// returns the index of the existing rule, so a rule can be registered every time a result for
+/// it is reported.
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     builder::RunBuilder,
+///     schema::{Message, ReportingDescriptor, ToolComponent},
+/// };
+///
+/// let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+///
+/// let unused = run.register_rule(ReportingDescriptor::new("unused-variable"));
+/// run.push(run.result(unused, Message::new("`x` is never used")));
+///
+/// let unused_again = run.register_rule(ReportingDescriptor::new("unused-variable"));
+/// run.push(run.result(unused_again, Message::new("`y` is never used")));
+///
+/// let log = run.finish();
+/// let run = &log.runs.as_ref().expect("log should contain a run")[0];
+///
+/// assert_eq!(unused, unused_again);
+/// assert_eq!(run.tool.driver.rules.len(), 1);
+/// assert_eq!(run.results.as_ref().map(Vec::len), Some(2));
+/// ```
+///
+/// [`id`]: ReportingDescriptor::id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunBuilder<'s> {
+    run: Run<'s>,
+    rules: BTreeMap<Cow<'s, str>, RuleIndex>,
+}
+
+impl<'s> RunBuilder<'s> {
+    /// Create a new `RunBuilder` for a run of the tool with the given driver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{builder::RunBuilder, schema::ToolComponent};
+    ///
+    /// let run = RunBuilder::new(ToolComponent::new("hashql")).build();
+    ///
+    /// assert_eq!(run.tool.driver.name, "hashql");
+    /// ```
+    #[must_use]
+    pub fn new(driver: ToolComponent<'s>) -> Self {
+        Self::from(Run::new(Tool::new(driver)))
+    }
+
+    /// Modifies the run which is being built.
+    ///
+    /// Rules which are added to the driver of the tool are registered with the builder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Invocation, ReportingDescriptor, ToolComponent},
+    /// };
+    ///
+    /// let mut run = RunBuilder::new(ToolComponent::new("hashql")).configure(|run| {
+    ///     run.with_language("en-US")
+    ///         .with_invocation(Invocation::new(true))
+    /// });
+    ///
+    /// let rule = run.register_rule(ReportingDescriptor::new("unused-variable"));
+    /// let run = run.build();
+    ///
+    /// assert_eq!(rule.index(), 0);
+    /// assert_eq!(run.language.as_deref(), Some("en-US"));
+    /// assert_eq!(run.invocations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn configure(self, configure: impl FnOnce(Run<'s>) -> Run<'s>) -> Self {
+        Self::from(configure(self.run))
+    }
+
+    /// Returns the run which is being built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{builder::RunBuilder, schema::ToolComponent};
+    ///
+    /// let run = RunBuilder::new(ToolComponent::new("hashql"));
+    ///
+    /// assert_eq!(run.run().tool.driver.name, "hashql");
+    /// ```
+    #[must_use]
+    pub const fn run(&self) -> &Run<'s> {
+        &self.run
+    }
+
+    /// Register a rule with the driver of the tool and return its index.
+    ///
+    /// If a rule with the same identifier has already been registered, the existing rule is kept
+    /// and its index is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{ReportingDescriptor, ToolComponent},
+    /// };
+    ///
+    /// let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+    ///
+    /// let unused = run.register_rule(ReportingDescriptor::new("unused-variable"));
+    /// let unknown = run.register_rule(ReportingDescriptor::new("unknown-variable"));
+    ///
+    /// assert_eq!(unused.index(), 0);
+    /// assert_eq!(unknown.index(), 1);
+    /// assert_eq!(
+    ///     run.register_rule(ReportingDescriptor::new("unused-variable")),
+    ///     unused
+    /// );
+    /// ```
+    pub fn register_rule(&mut self, rule: ReportingDescriptor<'s>) -> RuleIndex {
+        if let Some(&index) = self.rules.get(&rule.id) {
+            return index;
+        }
+
+        let index = RuleIndex(self.run.tool.driver.rules.len());
+        self.rules.insert(rule.id.clone(), index);
+        self.run.tool.driver.rules.push(rule);
+
+        index
+    }
+
+    /// Returns the rule which was registered at the given index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{ReportingDescriptor, ToolComponent},
+    /// };
+    ///
+    /// let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+    /// let rule = run.register_rule(ReportingDescriptor::new("unused-variable"));
+    ///
+    /// assert_eq!(
+    ///     run.rule(rule).map(|rule| rule.id.as_ref()),
+    ///     Some("unused-variable")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn rule(&self, index: RuleIndex) -> Option<&ReportingDescriptor<'s>> {
+        self.run.tool.driver.rules.get(index.0)
+    }
+
+    /// Create a new [`Result`] for the rule at the given index.
+    ///
+    /// The result refers to the rule by both its identifier and its index. The result is not
+    /// added to the run, which allows it to be refined before it is [pushed].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Message, ReportingDescriptor, ResultLevel, ToolComponent},
+    /// };
+    ///
+    /// let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+    /// let rule = run.register_rule(ReportingDescriptor::new("unused-variable"));
+    ///
+    /// let result = run
+    ///     .result(rule, Message::new("`x` is never used"))
+    ///     .with_level(ResultLevel::Warning);
+    ///
+    /// assert_eq!(result.rule_id.as_deref(), Some("unused-variable"));
+    /// assert_eq!(result.rule_index, Some(0));
+    /// ```
+    ///
+    /// [pushed]: Self::push
+    #[must_use]
+    pub fn result(&self, rule: RuleIndex, message: Message<'s>) -> Result<'s> {
+        let result = Result::new(message).with_rule_index(rule.index());
+
+        match self.rule(rule) {
+            Some(descriptor) => result.with_rule_id(descriptor.id.clone()),
+            None => result,
+        }
+    }
+
+    /// Add a result to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Message, Result, ToolComponent},
+    /// };
+    ///
+    /// let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+    /// run.push(Result::new(Message::new("`x` is never used")));
+    ///
+    /// assert_eq!(run.build().results.map(|results| results.len()), Some(1));
+    /// ```
+    pub fn push(&mut self, result: Result<'s>) {
+        self.run.extend([result]);
+    }
+
+    /// Add a result to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Message, Result, ToolComponent},
+    /// };
+    ///
+    /// let run = RunBuilder::new(ToolComponent::new("hashql"))
+    ///     .with_result(Result::new(Message::new("`x` is never used")))
+    ///     .with_result(Result::new(Message::new("`y` is never used")))
+    ///     .build();
+    ///
+    /// assert_eq!(run.results.map(|results| results.len()), Some(2));
+    /// ```
+    #[must_use]
+    pub fn with_result(mut self, result: Result<'s>) -> Self {
+        self.push(result);
+        self
+    }
+
+    /// Returns the results which have been added to the run so far, leaving none behind.
+    #[cfg(feature = "std")]
+    pub(crate) fn take_results(&mut self) -> Vec<Result<'s>> {
+        self.run.results.take().unwrap_or_default()
+    }
+
+    /// Finish the run.
+    ///
+    /// A run built with a `RunBuilder` always contains a list of results, which is empty if no
+    /// result has been added, as the tool is considered to have run successfully.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{builder::RunBuilder, schema::ToolComponent};
+    ///
+    /// let run = RunBuilder::new(ToolComponent::new("hashql")).build();
+    ///
+    /// assert_eq!(run.results, Some(Vec::new()));
+    /// ```
+    #[must_use]
+    pub fn build(self) -> Run<'s> {
+        self.run.with_results([])
+    }
+
+    /// Finish the run and wrap it in a [`SarifLog`] of the latest [`SchemaVersion`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{SchemaVersion, ToolComponent},
+    /// };
+    ///
+    /// let log = RunBuilder::new(ToolComponent::new("hashql")).finish();
+    ///
+    /// assert_eq!(log.version, SchemaVersion::V2_1_0);
+    /// assert_eq!(log.runs.map(|runs| runs.len()), Some(1));
+    /// ```
+    #[must_use]
+    pub fn finish(self) -> SarifLog<'s> {
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(self.build())
+    }
+}
+
+impl<'s> From<Run<'s>> for RunBuilder<'s> {
+    fn from(run: Run<'s>) -> Self {
+        let mut rules = BTreeMap::new();
+        for (index, rule) in run.tool.driver.rules.iter().enumerate() {
+            rules.entry(rule.id.clone()).or_insert(RuleIndex(index));
+        }
+
+        Self { run, rules }
+    }
+}
+
+impl<'s> Extend<Result<'s>> for RunBuilder<'s> {
+    fn extend<T: IntoIterator<Item = Result<'s>>>(&mut self, iter: T) {
+        self.run.extend(iter);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        builder::RunBuilder,
+        schema::{
+            Message, ReportingDescriptor, ResultLevel, Run, Tool, ToolComponent,
+            tests::validate_schema,
+        },
+    };
+
+    #[test]
+    fn registered_rules() {
+        let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+
+        let unused = run.register_rule(ReportingDescriptor::new("unused-variable"));
+        let unknown = run.register_rule(ReportingDescriptor::new("unknown-variable"));
+        run.push(run.result(unused, Message::new("`x` is never used")));
+        run.push(
+            run.result(unknown, Message::new("`y` is not defined"))
+                .with_level(ResultLevel::Error),
+        );
+        let unused_again = run.register_rule(ReportingDescriptor::new("unused-variable"));
+        run.push(run.result(unused_again, Message::new("`z` is never used")));
+
+        let log = run.finish();
+        validate_schema(&log);
+
+        let run = &log.runs.as_ref().expect("log should contain a run")[0];
+        assert_eq!(run.tool.driver.rules.len(), 2);
+
+        let rules: Vec<_> = run
+            .results
+            .iter()
+            .flatten()
+            .map(|result| (result.rule_id.as_deref(), result.rule_index))
+            .collect();
+        assert_eq!(
+            rules,
+            [
+                (Some("unused-variable"), Some(0)),
+                (Some("unknown-variable"), Some(1)),
+                (Some("unused-variable"), Some(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn predefined_rules() {
+        let mut run = RunBuilder::from(Run::new(Tool::new(
+            ToolComponent::new("hashql")
+                .with_rule(ReportingDescriptor::new("unused-variable"))
+                .with_rule(ReportingDescriptor::new("unknown-variable")),
+        )));
+
+        let unknown = run.register_rule(ReportingDescriptor::new("unknown-variable"));
+        let shadowed = run.register_rule(ReportingDescriptor::new("shadowed-variable"));
+
+        assert_eq!(unknown.index(), 1);
+        assert_eq!(shadowed.index(), 2);
+
+        let mut run = run.configure(|run| run.with_language("en-US"));
+        assert_eq!(
+            run.register_rule(ReportingDescriptor::new("shadowed-variable")),
+            shadowed
+        );
+
+        validate_schema(&run.finish());
+    }
+}
@@ -20,11 +20,16 @@
 #![forbid(unsafe_code)]
 
 extern crate alloc;
-#[cfg(test)]
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 
+#[warn(missing_docs)]
+pub mod builder;
 #[warn(missing_docs)]
 pub mod schema;
+#[cfg(feature = "std")]
+#[warn(missing_docs)]
+pub mod writer;
 
 #[cfg(feature = "serde")]
 pub(crate) mod serde;
@@ -0,0 +1,205 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::PropertyBag;
+
+/// A physical or virtual address, or a range of addresses, in an addressable region such as
+/// memory or a binary file ([§3.32]).
+///
+/// [§3.32]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317705
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+#[expect(
+    clippy::struct_field_names,
+    reason = "The field names are defined by the SARIF specification"
+)]
+pub struct Address<'s> {
+    /// The address expressed as a byte offset from the start of the addressable region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub absolute_address: Option<u64>,
+
+    /// The address expressed as a byte offset from the absolute address of the top-most parent
+    /// object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub relative_address: Option<i64>,
+
+    /// The number of bytes in this range of addresses.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub length: Option<i64>,
+
+    /// The kind of the address, such as `module`, `section` or `function`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<Cow<'s, str>>,
+
+    /// A name that is associated with the address, such as `.text`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub name: Option<Cow<'s, str>>,
+
+    /// A human-readable fully qualified name that is associated with the address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fully_qualified_name: Option<Cow<'s, str>>,
+
+    /// The byte offset of this address from the absolute or relative address of the parent
+    /// object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub offset_from_parent: Option<i64>,
+
+    /// The index within the run addresses array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// The index within the run addresses array of the parent object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<u64>,
+
+    /// Key/value pairs that provide additional information about the address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Address<'s> {
+    /// Create a new `Address` at the given absolute address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Address;
+    ///
+    /// let address = Address::new(4_229_178);
+    ///
+    /// assert_eq!(address.absolute_address, Some(4_229_178));
+    /// ```
+    #[must_use]
+    pub const fn new(absolute_address: u64) -> Self {
+        Self {
+            absolute_address: Some(absolute_address),
+            relative_address: None,
+            length: None,
+            kind: None,
+            name: None,
+            fully_qualified_name: None,
+            offset_from_parent: None,
+            index: None,
+            parent_index: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the number of bytes in the range of addresses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Address;
+    ///
+    /// let address = Address::new(4_229_178).with_length(16);
+    ///
+    /// assert_eq!(address.length, Some(16));
+    /// ```
+    #[must_use]
+    pub const fn with_length(mut self, length: i64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the kind of the address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Address;
+    ///
+    /// let address = Address::new(0x0040_0000).with_kind("module");
+    ///
+    /// assert_eq!(address.kind.as_deref(), Some("module"));
+    /// ```
+    #[must_use]
+    pub fn with_kind(mut self, kind: impl Into<Cow<'s, str>>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    /// Sets the fully qualified name associated with the address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Address;
+    ///
+    /// let address = Address::new(0x0040_0000).with_fully_qualified_name("collections.dll");
+    ///
+    /// assert_eq!(
+    ///     address.fully_qualified_name.as_deref(),
+    ///     Some("collections.dll")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_fully_qualified_name(mut self, name: impl Into<Cow<'s, str>>) -> Self {
+        self.fully_qualified_name = Some(name.into());
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        Address, Location, Message, PhysicalLocation, Result, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn physical_location() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("scanner"))).with_result(
+                    Result::new(Message::new("weak cryptographic algorithm")).with_location(
+                        Location::new().with_physical_location(PhysicalLocation::from_address(
+                            Address::new(0x0040_0000)
+                                .with_length(64)
+                                .with_kind("function")
+                                .with_fully_qualified_name("collections.dll!add"),
+                        )),
+                    ),
+                ),
+            ),
+        );
+    }
+}
@@ -0,0 +1,370 @@
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, Message, MultiformatMessageString, PropertyBag};
+
+/// A single artifact, such as a source file, that was analyzed ([§3.24]).
+///
+/// [§3.24]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317611
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Artifact<'s> {
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<ArtifactLocation<'s>>,
+
+    /// A short description of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// The index within the run artifacts array of the artifact which contains this artifact,
+    /// such as an archive containing a file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<u64>,
+
+    /// The offset in bytes of the artifact within its containing artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub offset: Option<u64>,
+
+    /// The length of the artifact in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub length: Option<u64>,
+
+    /// The MIME type ([RFC 2045]) of the artifact.
+    ///
+    /// [RFC 2045]: https://tools.ietf.org/html/rfc2045
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub mime_type: Option<Cow<'s, str>>,
+
+    /// The contents of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub contents: Option<ArtifactContent<'s>>,
+
+    /// The name of the encoding used by the artifact, such as `utf-8`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub encoding: Option<Cow<'s, str>>,
+
+    /// The programming language in which the artifact is written, such as `rust`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// A dictionary, each of whose keys is the name of a hash function, such as `sha-256`, and
+    /// each of whose values is the hashed value of the artifact produced by that function.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub hashes: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// The date and time at which the artifact was most recently modified, in the format
+    /// specified by [ISO 8601] and in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub last_modified_time_utc: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Artifact<'s> {
+    /// Create a new `Artifact` at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(
+    ///     artifact
+    ///         .location
+    ///         .and_then(|location| location.uri)
+    ///         .as_deref(),
+    ///     Some("src/lib.rs")
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn new(location: ArtifactLocation<'s>) -> Self {
+        Self {
+            location: Some(location),
+            description: None,
+            parent_index: None,
+            offset: None,
+            length: None,
+            mime_type: None,
+            contents: None,
+            encoding: None,
+            source_language: None,
+            hashes: BTreeMap::new(),
+            last_modified_time_utc: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the length of the artifact in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs")).with_length(1742);
+    ///
+    /// assert_eq!(artifact.length, Some(1742));
+    /// ```
+    #[must_use]
+    pub const fn with_length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the MIME type of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact =
+    ///     Artifact::new(ArtifactLocation::new("main.jsonc")).with_mime_type("application/json");
+    ///
+    /// assert_eq!(artifact.mime_type.as_deref(), Some("application/json"));
+    /// ```
+    #[must_use]
+    pub fn with_mime_type(mut self, mime_type: impl Into<Cow<'s, str>>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Sets the contents of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactContent, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("main.jsonc"))
+    ///     .with_contents(ArtifactContent::new(r#"["let", "x", 1, "x"]"#));
+    ///
+    /// assert_eq!(
+    ///     artifact
+    ///         .contents
+    ///         .and_then(|contents| contents.text)
+    ///         .as_deref(),
+    ///     Some(r#"["let", "x", 1, "x"]"#)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_contents(mut self, contents: ArtifactContent<'s>) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+
+    /// Sets the programming language in which the artifact is written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs")).with_source_language("rust");
+    ///
+    /// assert_eq!(artifact.source_language.as_deref(), Some("rust"));
+    /// ```
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Add the hashed value of the artifact produced by the given hash function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_hash("sha-256", "b13ce2678a8807ba0765ab94a0ecd394f869bc81");
+    ///
+    /// assert_eq!(
+    ///     artifact.hashes["sha-256"],
+    ///     "b13ce2678a8807ba0765ab94a0ecd394f869bc81"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_hash(
+        mut self,
+        algorithm: impl Into<Cow<'s, str>>,
+        value: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.hashes.insert(algorithm.into(), value.into());
+        self
+    }
+}
+
+/// The content of an artifact, or of a portion of it ([§3.3]).
+///
+/// [§3.3]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317422
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactContent<'s> {
+    /// The content, if the artifact is a text file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub text: Option<Cow<'s, str>>,
+
+    /// The MIME Base64-encoded content, if the artifact is a binary file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub binary: Option<Cow<'s, str>>,
+
+    /// A rendered representation of the content, for artifacts such as images.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rendered: Option<MultiformatMessageString<'s>>,
+
+    /// Key/value pairs that provide additional information about the content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactContent<'s> {
+    /// Create a new `ArtifactContent` from the given text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::new("let x = 1;");
+    ///
+    /// assert_eq!(content.text.as_deref(), Some("let x = 1;"));
+    /// assert_eq!(content.binary, None);
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            binary: None,
+            rendered: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Create a new `ArtifactContent` from MIME Base64-encoded binary content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::binary("SGVsbG8=");
+    ///
+    /// assert_eq!(content.text, None);
+    /// assert_eq!(content.binary.as_deref(), Some("SGVsbG8="));
+    /// ```
+    #[must_use]
+    pub fn binary(binary: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: None,
+            binary: Some(binary.into()),
+            rendered: None,
+            properties: PropertyBag::new(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        Artifact, ArtifactContent, ArtifactLocation, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn text() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_artifact(
+                    Artifact::new(ArtifactLocation::new("main.jsonc").with_uri_base_id("SRCROOT"))
+                        .with_length(20)
+                        .with_mime_type("application/json")
+                        .with_source_language("hashql")
+                        .with_contents(ArtifactContent::new(r#"["let", "x", 1, "x"]"#))
+                        .with_hash("sha-256", "cc8e6a99f3eff00adc649fee132ba80fe333ea5a"),
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn binary() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_artifact(
+                    Artifact::new(ArtifactLocation::new("app.zip"))
+                        .with_contents(ArtifactContent::binary("SGVsbG8=")),
+                ),
+            ),
+        );
+    }
+}
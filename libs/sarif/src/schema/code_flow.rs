@@ -0,0 +1,458 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Location, Message, MultiformatMessageString, PropertyBag, Stack};
+
+/// A set of threads of execution, each of which is an ordered sequence of locations which were
+/// visited by the program ([§3.36]).
+///
+/// [§3.36]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317740
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct CodeFlow<'s> {
+    /// A message relevant to the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// The threads of execution of the code flow.
+    ///
+    /// A code flow must contain at least one thread flow.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thread_flows: Vec<ThreadFlow<'s>>,
+
+    /// Key/value pairs that provide additional information about the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> CodeFlow<'s> {
+    /// Create a new `CodeFlow` consisting of the given thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, ThreadFlow};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new());
+    ///
+    /// assert_eq!(code_flow.thread_flows.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(thread_flow: ThreadFlow<'s>) -> Self {
+        Self {
+            message: None,
+            thread_flows: vec![thread_flow],
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the message relevant to the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Message, ThreadFlow};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new())
+    ///     .with_message(Message::new("path from declaration to usage"));
+    ///
+    /// assert!(code_flow.message.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Add a thread flow to the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, ThreadFlow};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new().with_id("main"))
+    ///     .with_thread_flow(ThreadFlow::new().with_id("worker"));
+    ///
+    /// assert_eq!(code_flow.thread_flows.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_thread_flow(mut self, thread_flow: ThreadFlow<'s>) -> Self {
+        self.thread_flows.push(thread_flow);
+        self
+    }
+}
+
+/// A sequence of code locations that specify a possible path through a single thread of
+/// execution ([§3.37]).
+///
+/// [§3.37]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317744
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlow<'s> {
+    /// An identifier for the thread flow, such as a thread identifier.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<Cow<'s, str>>,
+
+    /// A message relevant to the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// Values of relevant expressions at the start of the thread flow that may change during
+    /// its execution.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub initial_state: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
+    /// Values of relevant expressions at the start of the thread flow that remain constant.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub immutable_state: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
+    /// The locations visited by the thread of execution, in the order they were visited.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locations: Vec<ThreadFlowLocation<'s>>,
+
+    /// Key/value pairs that provide additional information about the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlow<'s> {
+    /// Create a new, empty `ThreadFlow`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlow;
+    ///
+    /// let thread_flow = ThreadFlow::new();
+    ///
+    /// assert!(thread_flow.locations.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: None,
+            message: None,
+            initial_state: BTreeMap::new(),
+            immutable_state: BTreeMap::new(),
+            locations: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlow;
+    ///
+    /// let thread_flow = ThreadFlow::new().with_id("thread-52");
+    ///
+    /// assert_eq!(thread_flow.id.as_deref(), Some("thread-52"));
+    /// ```
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<Cow<'s, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Add a location visited by the thread of execution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new()
+    ///     .with_location(ThreadFlowLocation::new(Location::new().with_id(0)))
+    ///     .with_location(ThreadFlowLocation::new(Location::new().with_id(1)));
+    ///
+    /// assert_eq!(thread_flow.locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: ThreadFlowLocation<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+}
+
+/// The importance of a [`ThreadFlowLocation`] ([§3.38.13]).
+///
+/// [§3.38.13]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317763
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Importance {
+    /// The location is important to understand the code flow.
+    Important,
+    /// The location is essential to understand the code flow.
+    Essential,
+    /// The location is not important to understand the code flow.
+    Unimportant,
+}
+
+/// A location visited by an analysis tool while simulating or monitoring the execution of a
+/// program ([§3.38]).
+///
+/// [§3.38]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317751
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlowLocation<'s> {
+    /// The index within the run thread flow locations array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// The code location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<Location<'s>>,
+
+    /// The call stack leading to this location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stack: Option<Stack<'s>>,
+
+    /// A set of distinct strings that categorize the thread flow location, such as `call` or
+    /// `return`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub kinds: Vec<Cow<'s, str>>,
+
+    /// The name of the module that contains the code that is executing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub module: Option<Cow<'s, str>>,
+
+    /// Values of relevant expressions at this point in the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub state: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
+    /// An integer representing a containment hierarchy within the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub nesting_level: Option<u64>,
+
+    /// An integer representing the temporal order in which execution reached this location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub execution_order: Option<i64>,
+
+    /// The time at which this location was executed, in the format specified by [ISO 8601] and
+    /// in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub execution_time_utc: Option<Cow<'s, str>>,
+
+    /// How important this location is to understand the code flow.
+    ///
+    /// If absent, the location is considered [`Importance::Important`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub importance: Option<Importance>,
+
+    /// Key/value pairs that provide additional information about the thread flow location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlowLocation<'s> {
+    /// Create a new `ThreadFlowLocation` at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new(Location::new());
+    ///
+    /// assert!(location.location.is_some());
+    /// assert_eq!(location.importance, None);
+    /// ```
+    #[must_use]
+    pub const fn new(location: Location<'s>) -> Self {
+        Self {
+            index: None,
+            location: Some(location),
+            stack: None,
+            kinds: Vec::new(),
+            module: None,
+            state: BTreeMap::new(),
+            nesting_level: None,
+            execution_order: None,
+            execution_time_utc: None,
+            importance: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the name of the module that contains the code that is executing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new(Location::new()).with_module("platform");
+    ///
+    /// assert_eq!(location.module.as_deref(), Some("platform"));
+    /// ```
+    #[must_use]
+    pub fn with_module(mut self, module: impl Into<Cow<'s, str>>) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+
+    /// Add the value of an expression at this point in the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, MultiformatMessageString, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new(Location::new())
+    ///     .with_state("y + z", MultiformatMessageString::new("6"));
+    ///
+    /// assert_eq!(location.state["y + z"].text, "6");
+    /// ```
+    #[must_use]
+    pub fn with_state(
+        mut self,
+        expression: impl Into<Cow<'s, str>>,
+        value: MultiformatMessageString<'s>,
+    ) -> Self {
+        self.state.insert(expression.into(), value);
+        self
+    }
+
+    /// Sets how important the location is to understand the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Importance, Location, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new(Location::new()).with_importance(Importance::Essential);
+    ///
+    /// assert_eq!(location.importance, Some(Importance::Essential));
+    /// ```
+    #[must_use]
+    pub const fn with_importance(mut self, importance: Importance) -> Self {
+        self.importance = Some(importance);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactLocation, CodeFlow, Importance, Location, Message, MultiformatMessageString,
+        PhysicalLocation, Region, Result, Run, SarifLog, SchemaVersion, ThreadFlow,
+        ThreadFlowLocation, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    fn location(line: u64) -> Location<'static> {
+        Location::new().with_physical_location(
+            PhysicalLocation::new(ArtifactLocation::new("main.jsonc"))
+                .with_region(Region::new().with_start(line, 1)),
+        )
+    }
+
+    #[test]
+    fn thread_flows() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_result(
+                    Result::new(Message::new("division by zero")).with_code_flow(
+                        CodeFlow::new(
+                            ThreadFlow::new()
+                                .with_id("main")
+                                .with_location(
+                                    ThreadFlowLocation::new(
+                                        location(1).with_message(Message::new("`y` is bound")),
+                                    )
+                                    .with_importance(Importance::Essential),
+                                )
+                                .with_location(
+                                    ThreadFlowLocation::new(location(2))
+                                        .with_module("main")
+                                        .with_state("y", MultiformatMessageString::new("0"))
+                                        .with_importance(Importance::Unimportant),
+                                ),
+                        )
+                        .with_message(Message::new("path from binding to usage")),
+                    ),
+                ),
+            ),
+        );
+    }
+}
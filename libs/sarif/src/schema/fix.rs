@@ -0,0 +1,275 @@
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, ArtifactLocation, Message, PropertyBag, Region};
+
+/// A proposed fix for the problem represented by a result ([§3.55]).
+///
+/// [§3.55]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317881
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Fix<'s> {
+    /// A message that describes the proposed fix, enabling viewers to present the proposed
+    /// change to an end user.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// The changes to each artifact required to apply the fix.
+    ///
+    /// A fix must change at least one artifact.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub artifact_changes: Vec<ArtifactChange<'s>>,
+
+    /// Key/value pairs that provide additional information about the fix.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Fix<'s> {
+    /// Create a new `Fix` consisting of the given artifact change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix};
+    ///
+    /// let fix = Fix::new(ArtifactChange::new(ArtifactLocation::new("main.jsonc")));
+    ///
+    /// assert_eq!(fix.artifact_changes.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(artifact_change: ArtifactChange<'s>) -> Self {
+        Self {
+            description: None,
+            artifact_changes: vec![artifact_change],
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the message that describes the fix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix, Message};
+    ///
+    /// let fix = Fix::new(ArtifactChange::new(ArtifactLocation::new("main.jsonc")))
+    ///     .with_description(Message::new("remove the unused variable"));
+    ///
+    /// assert!(fix.description.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Add a change to an artifact required to apply the fix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix};
+    ///
+    /// let fix = Fix::new(ArtifactChange::new(ArtifactLocation::new("main.jsonc")))
+    ///     .with_artifact_change(ArtifactChange::new(ArtifactLocation::new("lib.jsonc")));
+    ///
+    /// assert_eq!(fix.artifact_changes.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_artifact_change(mut self, artifact_change: ArtifactChange<'s>) -> Self {
+        self.artifact_changes.push(artifact_change);
+        self
+    }
+}
+
+/// A change to a single artifact ([§3.56]).
+///
+/// [§3.56]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317885
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactChange<'s> {
+    /// The location of the artifact to change.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub artifact_location: ArtifactLocation<'s>,
+
+    /// The replacements to apply to the artifact.
+    ///
+    /// The replacements must not overlap and are applied in order.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub replacements: Vec<Replacement<'s>>,
+
+    /// Key/value pairs that provide additional information about the change.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactChange<'s> {
+    /// Create a new `ArtifactChange` to the artifact at the given location without any
+    /// replacements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation};
+    ///
+    /// let change = ArtifactChange::new(ArtifactLocation::new("main.jsonc"));
+    ///
+    /// assert!(change.replacements.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new(artifact_location: ArtifactLocation<'s>) -> Self {
+        Self {
+            artifact_location,
+            replacements: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Add a replacement to apply to the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Region, Replacement};
+    ///
+    /// let change = ArtifactChange::new(ArtifactLocation::new("main.jsonc"))
+    ///     .with_replacement(Replacement::new(Region::new().with_char_range(4, 8)));
+    ///
+    /// assert_eq!(change.replacements.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_replacement(mut self, replacement: Replacement<'s>) -> Self {
+        self.replacements.push(replacement);
+        self
+    }
+}
+
+/// The replacement of a single region of an artifact ([§3.57]).
+///
+/// [§3.57]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317889
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Replacement<'s> {
+    /// The region of the artifact to delete.
+    ///
+    /// An empty region denotes an insertion point.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub deleted_region: Region<'s>,
+
+    /// The content to insert at the location specified by [`deleted_region`].
+    ///
+    /// If absent, the region is only deleted.
+    ///
+    /// [`deleted_region`]: Self::deleted_region
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub inserted_content: Option<ArtifactContent<'s>>,
+
+    /// Key/value pairs that provide additional information about the replacement.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Replacement<'s> {
+    /// Create a new `Replacement` which deletes the given region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Region, Replacement};
+    ///
+    /// let replacement = Replacement::new(Region::new().with_char_range(4, 8));
+    ///
+    /// assert_eq!(replacement.inserted_content, None);
+    /// ```
+    #[must_use]
+    pub const fn new(deleted_region: Region<'s>) -> Self {
+        Self {
+            deleted_region,
+            inserted_content: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the content to insert in place of the deleted region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, Region, Replacement};
+    ///
+    /// let replacement = Replacement::new(Region::new().with_char_range(4, 8))
+    ///     .with_inserted_content(ArtifactContent::new("_x"));
+    ///
+    /// assert!(replacement.inserted_content.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_inserted_content(mut self, inserted_content: ArtifactContent<'s>) -> Self {
+        self.inserted_content = Some(inserted_content);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactChange, ArtifactContent, ArtifactLocation, Fix, Message, Region, Replacement,
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn replacements() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_result(
+                    Result::new(Message::new("unused variable")).with_fix(
+                        Fix::new(
+                            ArtifactChange::new(ArtifactLocation::new("main.jsonc"))
+                                .with_replacement(
+                                    Replacement::new(Region::new().with_char_range(8, 1))
+                                        .with_inserted_content(ArtifactContent::new("_x")),
+                                )
+                                .with_replacement(Replacement::new(
+                                    Region::new().with_char_range(12, 0),
+                                )),
+                        )
+                        .with_description(Message::new("prefix the variable with an underscore")),
+                    ),
+                ),
+            ),
+        );
+    }
+}
@@ -0,0 +1,443 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, Notification, PropertyBag};
+
+/// The runtime environment of the analysis tool run ([§3.20]).
+///
+/// [§3.20]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317567
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Invocation<'s> {
+    /// The command line used to invoke the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub command_line: Option<Cow<'s, str>>,
+
+    /// The individual command line arguments used to invoke the tool, excluding the name of the
+    /// executable.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub arguments: Vec<Cow<'s, str>>,
+
+    /// The locations of any response files specified on the command line.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub response_files: Vec<ArtifactLocation<'s>>,
+
+    /// The date and time at which the invocation started, in the format specified by
+    /// [ISO 8601] and in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_time_utc: Option<Cow<'s, str>>,
+
+    /// The date and time at which the invocation ended, in the format specified by [ISO 8601]
+    /// and in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_time_utc: Option<Cow<'s, str>>,
+
+    /// The process exit code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code: Option<i64>,
+
+    /// The reason for the process exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code_description: Option<Cow<'s, str>>,
+
+    /// The name of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_signal_name: Option<Cow<'s, str>>,
+
+    /// The numeric value of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_signal_number: Option<i64>,
+
+    /// The reason given by the operating system that the process failed to start.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_start_failure_message: Option<Cow<'s, str>>,
+
+    /// Specifies whether the tool's execution completed successfully.
+    ///
+    /// This is `false` if the tool failed to run, for example because of an internal error, but
+    /// not if the tool ran and produced results.
+    pub execution_successful: bool,
+
+    /// The machine on which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub machine: Option<Cow<'s, str>>,
+
+    /// The account under which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub account: Option<Cow<'s, str>>,
+
+    /// The id of the process in which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_id: Option<i64>,
+
+    /// The location of the executable that was invoked.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub executable_location: Option<ArtifactLocation<'s>>,
+
+    /// The working directory of the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub working_directory: Option<ArtifactLocation<'s>>,
+
+    /// The environment variables associated with the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub environment_variables: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// Conditions encountered during the execution of the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_execution_notifications: Vec<Notification<'s>>,
+
+    /// Conditions relevant to the configuration of the tool, such as an unknown rule that was
+    /// requested to be disabled.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_configuration_notifications: Vec<Notification<'s>>,
+
+    /// The location of a file containing the standard input stream of the process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stdin: Option<ArtifactLocation<'s>>,
+
+    /// The location of a file containing the standard output stream of the process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stdout: Option<ArtifactLocation<'s>>,
+
+    /// The location of a file containing the standard error stream of the process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stderr: Option<ArtifactLocation<'s>>,
+
+    /// The location of a file containing the interleaved standard output and standard error
+    /// streams of the process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stdout_stderr: Option<ArtifactLocation<'s>>,
+
+    /// Key/value pairs that provide additional information about the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Invocation<'s> {
+    /// Create a new `Invocation` which either completed successfully or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true);
+    ///
+    /// assert!(invocation.execution_successful);
+    /// ```
+    #[must_use]
+    pub const fn new(execution_successful: bool) -> Self {
+        Self {
+            command_line: None,
+            arguments: Vec::new(),
+            response_files: Vec::new(),
+            start_time_utc: None,
+            end_time_utc: None,
+            exit_code: None,
+            exit_code_description: None,
+            exit_signal_name: None,
+            exit_signal_number: None,
+            process_start_failure_message: None,
+            execution_successful,
+            machine: None,
+            account: None,
+            process_id: None,
+            executable_location: None,
+            working_directory: None,
+            environment_variables: BTreeMap::new(),
+            tool_execution_notifications: Vec::new(),
+            tool_configuration_notifications: Vec::new(),
+            stdin: None,
+            stdout: None,
+            stderr: None,
+            stdout_stderr: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the command line used to invoke the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_command_line("hashql check main.jsonc");
+    ///
+    /// assert_eq!(
+    ///     invocation.command_line.as_deref(),
+    ///     Some("hashql check main.jsonc")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_command_line(mut self, command_line: impl Into<Cow<'s, str>>) -> Self {
+        self.command_line = Some(command_line.into());
+        self
+    }
+
+    /// Add a command line argument used to invoke the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_argument("check")
+    ///     .with_argument("main.jsonc");
+    ///
+    /// assert!(invocation.arguments.iter().eq(["check", "main.jsonc"]));
+    /// ```
+    #[must_use]
+    pub fn with_argument(mut self, argument: impl Into<Cow<'s, str>>) -> Self {
+        self.arguments.push(argument.into());
+        self
+    }
+
+    /// Sets the date and time at which the invocation started and ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation =
+    ///     Invocation::new(true).with_time_utc("2016-07-16T14:18:25Z", "2016-07-16T14:19:01Z");
+    ///
+    /// assert_eq!(
+    ///     invocation.start_time_utc.as_deref(),
+    ///     Some("2016-07-16T14:18:25Z")
+    /// );
+    /// assert_eq!(
+    ///     invocation.end_time_utc.as_deref(),
+    ///     Some("2016-07-16T14:19:01Z")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_time_utc(
+        mut self,
+        start: impl Into<Cow<'s, str>>,
+        end: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.start_time_utc = Some(start.into());
+        self.end_time_utc = Some(end.into());
+        self
+    }
+
+    /// Sets the process exit code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(false).with_exit_code(101);
+    ///
+    /// assert_eq!(invocation.exit_code, Some(101));
+    /// ```
+    #[must_use]
+    pub const fn with_exit_code(mut self, exit_code: i64) -> Self {
+        self.exit_code = Some(exit_code);
+        self
+    }
+
+    /// Sets the working directory of the invocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_working_directory(ArtifactLocation::new("file:///home/user/project/"));
+    ///
+    /// assert!(invocation.working_directory.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_working_directory(mut self, working_directory: ArtifactLocation<'s>) -> Self {
+        self.working_directory = Some(working_directory);
+        self
+    }
+
+    /// Add an environment variable associated with the invocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_environment_variable("TZ", "UTC");
+    ///
+    /// assert_eq!(invocation.environment_variables["TZ"], "UTC");
+    /// ```
+    #[must_use]
+    pub fn with_environment_variable(
+        mut self,
+        name: impl Into<Cow<'s, str>>,
+        value: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.environment_variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add a condition encountered during the execution of the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Message, Notification};
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_tool_execution_notification(Notification::new(Message::new("Run started.")));
+    ///
+    /// assert_eq!(invocation.tool_execution_notifications.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_tool_execution_notification(mut self, notification: Notification<'s>) -> Self {
+        self.tool_execution_notifications.push(notification);
+        self
+    }
+
+    /// Add a condition relevant to the configuration of the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Message, Notification};
+    ///
+    /// let invocation = Invocation::new(true).with_tool_configuration_notification(Notification::new(
+    ///     Message::new("unknown rule `ABC0001`"),
+    /// ));
+    ///
+    /// assert_eq!(invocation.tool_configuration_notifications.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_tool_configuration_notification(mut self, notification: Notification<'s>) -> Self {
+        self.tool_configuration_notifications.push(notification);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactLocation, Invocation, Message, Notification, ReportingDescriptorReference,
+        ResultLevel, Run, SarifLog, SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn successful() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_invocation(
+                    Invocation::new(true)
+                        .with_command_line("hashql check main.jsonc")
+                        .with_argument("check")
+                        .with_argument("main.jsonc")
+                        .with_time_utc("2016-07-16T14:18:25Z", "2016-07-16T14:19:01Z")
+                        .with_exit_code(0)
+                        .with_working_directory(ArtifactLocation::new("file:///home/user/project/"))
+                        .with_environment_variable("TZ", "UTC"),
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn configuration_notification() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_invocation(
+                    Invocation::new(false)
+                        .with_exit_code(1)
+                        .with_tool_configuration_notification(
+                            Notification::new(Message::new("unknown rule `ABC0001`"))
+                                .with_level(ResultLevel::Warning)
+                                .with_descriptor(ReportingDescriptorReference::new("unknown-rule")),
+                        ),
+                ),
+            ),
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Address, LogicalLocation, Message, PropertyBag, Region};
+
+/// A location within a programming artifact ([§3.28]).
+///
+/// [§3.28]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317670
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+#[expect(
+    clippy::struct_field_names,
+    reason = "The field names are defined by the SARIF specification"
+)]
+pub struct Location<'s> {
+    /// Value that distinguishes this location from all other locations within a single result
+    /// object.
+    ///
+    /// Embedded links in messages refer to locations through this identifier.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<u64>,
+
+    /// Identifies the artifact and region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub physical_location: Option<PhysicalLocation<'s>>,
+
+    /// The logical locations associated with the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub logical_locations: Vec<LogicalLocation<'s>>,
+
+    /// A message relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// A set of regions relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub annotations: Vec<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Location<'s> {
+    /// Create a new, empty `Location`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new();
+    ///
+    /// assert_eq!(location.id, None);
+    /// assert_eq!(location.physical_location, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: None,
+            physical_location: None,
+            logical_locations: Vec::new(),
+            message: None,
+            annotations: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the location within its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new().with_id(0);
+    ///
+    /// assert_eq!(location.id, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the artifact and region of the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, PhysicalLocation};
+    ///
+    /// let location = Location::new()
+    ///     .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs")));
+    ///
+    /// assert_eq!(
+    ///     location
+    ///         .physical_location
+    ///         .and_then(|location| location.artifact_location)
+    ///         .and_then(|artifact| artifact.uri)
+    ///         .as_deref(),
+    ///     Some("src/lib.rs")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_physical_location(mut self, physical_location: PhysicalLocation<'s>) -> Self {
+        self.physical_location = Some(physical_location);
+        self
+    }
+
+    /// Add a logical location associated with the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, LogicalLocation};
+    ///
+    /// let location = Location::new()
+    ///     .with_logical_location(LogicalLocation::new().with_fully_qualified_name("::math::add"));
+    ///
+    /// assert_eq!(location.logical_locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_logical_location(mut self, logical_location: LogicalLocation<'s>) -> Self {
+        self.logical_locations.push(logical_location);
+        self
+    }
+
+    /// Add a region relevant to the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Region};
+    ///
+    /// let location = Location::new().with_annotation(
+    ///     Region::new()
+    ///         .with_start(15, 13)
+    ///         .with_message(Message::new("(y + z) = 42")),
+    /// );
+    ///
+    /// assert_eq!(location.annotations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_annotation(mut self, annotation: Region<'s>) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Sets the message relevant to the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message};
+    ///
+    /// let location = Location::new().with_message(Message::new("declared here"));
+    ///
+    /// assert_eq!(
+    ///     location.message.and_then(|message| message.text).as_deref(),
+    ///     Some("declared here")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
+
+/// A physical location such as a file and a region within it ([§3.29]).
+///
+/// A physical location refers to an artifact, to an address, or to both.
+///
+/// [§3.29]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317678
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct PhysicalLocation<'s> {
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub artifact_location: Option<ArtifactLocation<'s>>,
+
+    /// The address of the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub address: Option<Address<'s>>,
+
+    /// The region within the artifact.
+    ///
+    /// If absent, the location refers to the entire artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub region: Option<Region<'s>>,
+
+    /// A superset of the [`region`] which provides context, such as the surrounding lines.
+    ///
+    /// [`region`]: Self::region
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub context_region: Option<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the physical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> PhysicalLocation<'s> {
+    /// Create a new `PhysicalLocation` referring to the given artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(
+    ///     location
+    ///         .artifact_location
+    ///         .and_then(|artifact| artifact.uri)
+    ///         .as_deref(),
+    ///     Some("src/lib.rs")
+    /// );
+    /// assert_eq!(location.region, None);
+    /// ```
+    #[must_use]
+    pub const fn new(artifact_location: ArtifactLocation<'s>) -> Self {
+        Self {
+            artifact_location: Some(artifact_location),
+            address: None,
+            region: None,
+            context_region: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Create a new `PhysicalLocation` referring to the given address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Address, PhysicalLocation};
+    ///
+    /// let location = PhysicalLocation::from_address(Address::new(4_229_178));
+    ///
+    /// assert_eq!(location.artifact_location, None);
+    /// assert_eq!(
+    ///     location
+    ///         .address
+    ///         .and_then(|address| address.absolute_address),
+    ///     Some(4_229_178)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn from_address(address: Address<'s>) -> Self {
+        Self {
+            artifact_location: None,
+            address: Some(address),
+            region: None,
+            context_region: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the address of the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Address, ArtifactLocation, PhysicalLocation};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("bin/app"))
+    ///     .with_address(Address::new(4_229_178));
+    ///
+    /// assert!(location.artifact_location.is_some());
+    /// assert!(location.address.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_address(mut self, address: Address<'s>) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the region within the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation, Region};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_region(Region::new().with_start(12, 4));
+    ///
+    /// assert_eq!(location.region.unwrap().start_line, Some(12));
+    /// ```
+    #[must_use]
+    pub fn with_region(mut self, region: Region<'s>) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Sets the region which provides context for the [`region`].
+    ///
+    /// [`region`]: Self::region
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation, Region};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_region(Region::new().with_start(12, 4))
+    ///     .with_context_region(Region::new().with_start(11, 1).with_end(13, 1));
+    ///
+    /// assert_eq!(location.context_region.unwrap().start_line, Some(11));
+    /// ```
+    #[must_use]
+    pub fn with_context_region(mut self, region: Region<'s>) -> Self {
+        self.context_region = Some(region);
+        self
+    }
+}
+
+/// The location of an artifact ([§3.4]).
+///
+/// [§3.4]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317427
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactLocation<'s> {
+    /// A string containing a valid relative or absolute URI.
+    ///
+    /// Relative references are resolved against the base URI named by [`uri_base_id`].
+    ///
+    /// [`uri_base_id`]: Self::uri_base_id
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri: Option<Cow<'s, str>>,
+
+    /// A string which indirectly specifies the absolute URI with respect to which a relative
+    /// [`uri`] is interpreted.
+    ///
+    /// [`uri`]: Self::uri
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri_base_id: Option<Cow<'s, str>>,
+
+    /// The index within the run artifacts array of the artifact object associated with the
+    /// artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// Key/value pairs that provide additional information about the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactLocation<'s> {
+    /// Create a new `ArtifactLocation` with the given URI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs");
+    ///
+    /// assert_eq!(location.uri.as_deref(), Some("src/lib.rs"));
+    /// ```
+    #[must_use]
+    pub fn new(uri: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            uri: Some(uri.into()),
+            uri_base_id: None,
+            index: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the base URI against which the URI is resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT");
+    ///
+    /// assert_eq!(location.uri_base_id.as_deref(), Some("SRCROOT"));
+    /// ```
+    #[must_use]
+    pub fn with_uri_base_id(mut self, uri_base_id: impl Into<Cow<'s, str>>) -> Self {
+        self.uri_base_id = Some(uri_base_id.into());
+        self
+    }
+
+    /// Sets the index of the artifact within the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_index(3);
+    ///
+    /// assert_eq!(location.index, Some(3));
+    /// ```
+    #[must_use]
+    pub const fn with_index(mut self, index: u64) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactLocation, Location, LogicalLocation, Message, PhysicalLocation, Region, Result,
+        Run, SarifLog, SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    fn log_with_location(location: Location<'_>) -> SarifLog<'_> {
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(
+            Run::new(Tool::new(ToolComponent::new("hashql")))
+                .with_result(Result::new(Message::new("unused variable")).with_location(location)),
+        )
+    }
+
+    #[test]
+    fn empty() {
+        validate_schema(&log_with_location(Location::new()));
+    }
+
+    #[test]
+    fn artifact() {
+        validate_schema(&log_with_location(
+            Location::new().with_physical_location(PhysicalLocation::new(
+                ArtifactLocation::new("src/main.jsonc")
+                    .with_uri_base_id("SRCROOT")
+                    .with_index(0),
+            )),
+        ));
+    }
+
+    #[test]
+    fn full() {
+        validate_schema(&log_with_location(
+            Location::new()
+                .with_id(0)
+                .with_physical_location(
+                    PhysicalLocation::new(ArtifactLocation::new("file:///main.jsonc"))
+                        .with_region(Region::new().with_start(1, 2).with_end(1, 8))
+                        .with_context_region(Region::new().with_start(1, 1).with_end(2, 1)),
+                )
+                .with_logical_location(LogicalLocation::new().with_fully_qualified_name("::x"))
+                .with_message(Message::new("declared here"))
+                .with_annotation(
+                    Region::new()
+                        .with_start(1, 4)
+                        .with_message(Message::new("`x` is bound here")),
+                ),
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::PropertyBag;
+
+/// A logical location such as a function, a namespace or a type ([§3.33]).
+///
+/// [§3.33]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317719
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct LogicalLocation<'s> {
+    /// Identifies the construct in which the result occurred, such as the name of a function.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub name: Option<Cow<'s, str>>,
+
+    /// The index within the run logical locations array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// The human-readable fully qualified name of the logical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fully_qualified_name: Option<Cow<'s, str>>,
+
+    /// The machine-readable name for the logical location, such as a mangled function name.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub decorated_name: Option<Cow<'s, str>>,
+
+    /// The index within the run logical locations array of the logical location which contains
+    /// this one.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<u64>,
+
+    /// The type of construct this logical location component refers to, such as `function`,
+    /// `module` or `namespace`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the logical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> LogicalLocation<'s> {
+    /// Create a new, empty `LogicalLocation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new();
+    ///
+    /// assert_eq!(location.name, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            index: None,
+            fully_qualified_name: None,
+            decorated_name: None,
+            parent_index: None,
+            kind: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the name of the construct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_name("add");
+    ///
+    /// assert_eq!(location.name.as_deref(), Some("add"));
+    /// ```
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<Cow<'s, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the fully qualified name of the logical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_fully_qualified_name("collections::list::add");
+    ///
+    /// assert_eq!(
+    ///     location.fully_qualified_name.as_deref(),
+    ///     Some("collections::list::add")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_fully_qualified_name(mut self, name: impl Into<Cow<'s, str>>) -> Self {
+        self.fully_qualified_name = Some(name.into());
+        self
+    }
+
+    /// Sets the kind of construct the logical location refers to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_kind("function");
+    ///
+    /// assert_eq!(location.kind.as_deref(), Some("function"));
+    /// ```
+    #[must_use]
+    pub fn with_kind(mut self, kind: impl Into<Cow<'s, str>>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    /// Sets the index of the logical location within the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_index(2);
+    ///
+    /// assert_eq!(location.index, Some(2));
+    /// ```
+    #[must_use]
+    pub const fn with_index(mut self, index: u64) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the index of the logical location which contains this one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_parent_index(0);
+    ///
+    /// assert_eq!(location.parent_index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_parent_index(mut self, parent_index: u64) -> Self {
+        self.parent_index = Some(parent_index);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        Location, LogicalLocation, Message, Result, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn hierarchy() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql")))
+                    .with_logical_locations([
+                        LogicalLocation::new().with_name("math").with_kind("module"),
+                        LogicalLocation::new()
+                            .with_name("add")
+                            .with_fully_qualified_name("::math::add")
+                            .with_kind("function")
+                            .with_parent_index(0),
+                    ])
+                    .with_result(
+                        Result::new(Message::new("unused variable")).with_location(
+                            Location::new().with_logical_location(
+                                LogicalLocation::new()
+                                    .with_fully_qualified_name("::math::add")
+                                    .with_index(1),
+                            ),
+                        ),
+                    ),
+            ),
+        );
+    }
+}
@@ -480,3 +480,28 @@ pub struct Message<'s> {
     )]
     pub properties: PropertyBag<'s>,
 }
+
+impl<'s> Message<'s> {
+    /// Create a new plain text `Message`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable");
+    ///
+    /// assert_eq!(message.text.as_deref(), Some("unused variable"));
+    /// assert!(message.arguments.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            markdown: None,
+            id: None,
+            arguments: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+}
@@ -1,24 +1,58 @@
 //! The JSON schema of the SARIF log file format as a Rust module.
 
+mod address;
+mod artifact;
+mod code_flow;
+mod fix;
+mod invocation;
+mod location;
 mod log;
+mod logical_location;
 mod message;
 mod multiformat_message_string;
+mod notification;
 mod properties;
+mod rank;
+mod region;
+mod reporting_configuration;
 mod reporting_descriptor;
+mod reporting_descriptor_reference;
+mod result;
 mod run;
+mod run_automation_details;
+mod stack;
+mod suppression;
 mod tool;
+mod version_control;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub use self::{
+    address::Address,
+    artifact::{Artifact, ArtifactContent},
+    code_flow::{CodeFlow, Importance, ThreadFlow, ThreadFlowLocation},
+    fix::{ArtifactChange, Fix, Replacement},
+    invocation::Invocation,
+    location::{ArtifactLocation, Location, PhysicalLocation},
     log::SarifLog,
+    logical_location::LogicalLocation,
     message::Message,
     multiformat_message_string::MultiformatMessageString,
+    notification::{Exception, Notification},
     properties::PropertyBag,
+    rank::Rank,
+    region::Region,
+    reporting_configuration::ReportingConfiguration,
     reporting_descriptor::ReportingDescriptor,
-    run::Run,
+    reporting_descriptor_reference::{ReportingDescriptorReference, ToolComponentReference},
+    result::{BaselineState, Result, ResultKind, ResultLevel, ResultProvenance},
+    run::{ColumnKind, Run},
+    run_automation_details::RunAutomationDetails,
+    stack::{Stack, StackFrame},
+    suppression::{Suppression, SuppressionKind, SuppressionStatus},
     tool::{Tool, ToolComponent},
+    version_control::VersionControlDetails,
 };
 
 /// The schema version of the log file.
@@ -72,40 +106,116 @@ pub(crate) mod tests {
         }
     }
 
-    #[test]
-    fn example_k1() {
-        let file_content =
-            fs::read("tests/example_reports/k1_minimal.sarif.json").expect("could not read file");
-        let log = serde_json::from_slice(&file_content).expect("could not parse SARIF log");
+    /// Reads one of the example logs of the SARIF specification and checks that it round-trips
+    /// exactly.
+    ///
+    /// The `deviations` are JSON pointers to parts of the example which do not conform to the
+    /// SARIF 2.1.0 schema and are removed before the comparison.
+    fn round_trip_example(path: &str, deviations: &[&str]) {
+        let file_content = fs::read(path).expect("could not read file");
+        let mut expected: serde_json::Value =
+            serde_json::from_slice(&file_content).expect("could not parse JSON");
+
+        for pointer in deviations {
+            let (parent, key) = pointer.rsplit_once('/').expect("invalid JSON pointer");
+            let removed = match expected.pointer_mut(parent) {
+                Some(serde_json::Value::Object(object)) => object.remove(key),
+                Some(serde_json::Value::Array(array)) => {
+                    let index = key.parse().expect("invalid array index");
+                    (index < array.len()).then(|| array.remove(index))
+                }
+                _ => None,
+            };
+            assert!(removed.is_some(), "deviation `{pointer}` does not exist");
+        }
 
+        // GUIDs are serialized in lowercase, the examples partly use uppercase.
+        lowercase_guids(&mut expected);
+
+        let log = SarifLog::deserialize(&expected).expect("could not parse SARIF log");
         validate_schema(&log);
+
+        let actual = serde_json::to_value(&log).expect("could not serialize SARIF log");
+        drop(log);
+
+        // An absent `$schema` is filled in from the version.
+        if let serde_json::Value::Object(object) = &mut expected {
+            object
+                .entry("$schema")
+                .or_insert_with(|| SchemaVersion::V2_1_0.schema_id().into());
+        }
+
+        assert_eq!(actual, expected, "SARIF log does not round-trip");
+    }
+
+    fn lowercase_guids(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::String(string) => {
+                if uuid::Uuid::try_parse(string).is_ok() {
+                    string.make_ascii_lowercase();
+                }
+            }
+            serde_json::Value::Array(array) => array.iter_mut().for_each(lowercase_guids),
+            serde_json::Value::Object(object) => object.values_mut().for_each(lowercase_guids),
+            _ => {}
+        }
     }
 
     #[test]
-    fn example_k2() {
-        let file_content = fs::read("tests/example_reports/k2_recommended_with_source.sarif.json")
-            .expect("could not read file");
-        let log = serde_json::from_slice(&file_content).expect("could not parse SARIF log");
+    fn example_k1() {
+        round_trip_example("tests/example_reports/k1_minimal.sarif.json", &[]);
+    }
 
-        validate_schema(&log);
+    #[test]
+    fn example_k2() {
+        round_trip_example(
+            "tests/example_reports/k2_recommended_with_source.sarif.json",
+            &[],
+        );
     }
 
     #[test]
     fn example_k3() {
-        let file_content =
-            fs::read("tests/example_reports/k3_recommended_without_source.sarif.json")
-                .expect("could not read file");
-        let log = serde_json::from_slice(&file_content).expect("could not parse SARIF log");
-
-        validate_schema(&log);
+        round_trip_example(
+            "tests/example_reports/k3_recommended_without_source.sarif.json",
+            // `artifact` is not a property of `run`, it's a typo of `artifacts`
+            &["/runs/0/artifact"],
+        );
     }
 
     #[test]
     fn example_k4() {
-        let file_content = fs::read("tests/example_reports/k4_comprehensive.sarif.json")
-            .expect("could not read file");
-        let log = serde_json::from_slice(&file_content).expect("could not parse SARIF log");
-
-        validate_schema(&log);
+        round_trip_example(
+            "tests/example_reports/k4_comprehensive.sarif.json",
+            &[
+                // renamed to `automationDetails`
+                "/runs/0/automationId",
+                // superseded by `executableLocation`
+                "/runs/0/invocations/0/fileName",
+                // `associatedRule` requires `id`, `index` or `guid` instead of `ruleId`
+                "/runs/0/invocations/0/toolConfigurationNotifications/0/associatedRule",
+                // the message of an exception is a plain string
+                "/runs/0/invocations/0/toolExecutionNotifications/1/exception/message",
+                // `offset` was renamed to `offsetFromParent`
+                "/runs/0/invocations/0/toolExecutionNotifications/1/exception/stack/frames/0/\
+                 location/physicalLocation/address/offset",
+                "/runs/0/invocations/0/toolExecutionNotifications/1/exception/stack/frames/1/\
+                 location/physicalLocation/address/offset",
+                "/runs/0/results/0/stacks/0/frames/0/location/physicalLocation/address/offset",
+                "/runs/0/results/0/stacks/0/frames/1/location/physicalLocation/address/offset",
+                "/runs/0/results/0/stacks/0/frames/2/location/physicalLocation/address/offset",
+                // URI references must not start with whitespace
+                "/runs/0/originalUriBaseIds/SRCROOT/uri",
+                "/runs/0/originalUriBaseIds/BINROOT/uri",
+                // typo of `fullyQualifiedName`
+                "/runs/0/logicalLocations/3/fullyQualfiedName",
+                // addresses are part of the run, not of the result
+                "/runs/0/results/0/addresses",
+                // renamed to `status`
+                "/runs/0/results/0/suppressions/0/state",
+                // `existing` was split into `unchanged` and `updated`
+                "/runs/0/results/0/baselineState",
+            ],
+        );
     }
 }
@@ -87,3 +87,26 @@ pub struct MultiformatMessageString<'s> {
     )]
     pub properties: PropertyBag<'s>,
 }
+
+impl<'s> MultiformatMessageString<'s> {
+    /// Create a new `MultiformatMessageString` with the given plain text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::MultiformatMessageString;
+    ///
+    /// let message = MultiformatMessageString::new("Uninitialized variable.");
+    ///
+    /// assert_eq!(message.text, "Uninitialized variable.");
+    /// assert_eq!(message.markdown, None);
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: text.into(),
+            markdown: None,
+            properties: PropertyBag::new(),
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{
+    Location, Message, PropertyBag, ReportingDescriptorReference, ResultLevel, Stack,
+};
+
+/// A condition relevant to a tool or its configuration, which is reported outside of the
+/// results ([§3.58]).
+///
+/// [§3.58]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317894
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Notification<'s> {
+    /// The locations relevant to this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A message that describes the condition that was encountered.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// The severity of the notification.
+    ///
+    /// If absent, the level defaults to [`ResultLevel::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<ResultLevel>,
+
+    /// The thread identifier of the code that generated the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thread_id: Option<i64>,
+
+    /// The date and time at which the analysis tool generated the notification, in the format
+    /// specified by [ISO 8601] and in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub time_utc: Option<Cow<'s, str>>,
+
+    /// The runtime exception, if any, relevant to this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exception: Option<Exception<'s>>,
+
+    /// A reference used to locate the descriptor relevant to this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub descriptor: Option<ReportingDescriptorReference<'s>>,
+
+    /// A reference used to locate the rule descriptor associated with this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub associated_rule: Option<ReportingDescriptorReference<'s>>,
+
+    /// Key/value pairs that provide additional information about the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Notification<'s> {
+    /// Create a new `Notification` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("Run started."));
+    ///
+    /// assert_eq!(notification.message.text.as_deref(), Some("Run started."));
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            locations: Vec::new(),
+            message,
+            level: None,
+            thread_id: None,
+            time_utc: None,
+            exception: None,
+            descriptor: None,
+            associated_rule: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Add a location relevant to the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, Notification, PhysicalLocation};
+    ///
+    /// let notification = Notification::new(Message::new("could not read file")).with_location(
+    ///     Location::new()
+    ///         .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("main.jsonc"))),
+    /// );
+    ///
+    /// assert_eq!(notification.locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Sets the severity of the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification, ResultLevel};
+    ///
+    /// let notification =
+    ///     Notification::new(Message::new("Run started.")).with_level(ResultLevel::Note);
+    ///
+    /// assert_eq!(notification.level, Some(ResultLevel::Note));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: ResultLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the date and time at which the notification was generated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification =
+    ///     Notification::new(Message::new("Run started.")).with_time_utc("2016-07-16T14:18:43.119Z");
+    ///
+    /// assert_eq!(
+    ///     notification.time_utc.as_deref(),
+    ///     Some("2016-07-16T14:18:43.119Z")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_time_utc(mut self, time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.time_utc = Some(time_utc.into());
+        self
+    }
+
+    /// Sets the runtime exception relevant to the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Exception, Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("rule failed"))
+    ///     .with_exception(Exception::new().with_kind("ArithmeticError"));
+    ///
+    /// assert!(notification.exception.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_exception(mut self, exception: Exception<'s>) -> Self {
+        self.exception = Some(exception);
+        self
+    }
+
+    /// Sets the reference to the descriptor relevant to the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification, ReportingDescriptorReference};
+    ///
+    /// let notification = Notification::new(Message::new("Run started."))
+    ///     .with_descriptor(ReportingDescriptorReference::new("start"));
+    ///
+    /// assert!(notification.descriptor.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_descriptor(mut self, descriptor: ReportingDescriptorReference<'s>) -> Self {
+        self.descriptor = Some(descriptor);
+        self
+    }
+
+    /// Sets the reference to the rule associated with the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification, ReportingDescriptorReference};
+    ///
+    /// let notification = Notification::new(Message::new("rule failed"))
+    ///     .with_associated_rule(ReportingDescriptorReference::new("unused-variable"));
+    ///
+    /// assert!(notification.associated_rule.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_associated_rule(mut self, rule: ReportingDescriptorReference<'s>) -> Self {
+        self.associated_rule = Some(rule);
+        self
+    }
+}
+
+/// A runtime exception which occurred during the execution of an analysis tool ([§3.59]).
+///
+/// [§3.59]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317904
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Exception<'s> {
+    /// The name of the exception type, for example, the fully qualified name of the exception
+    /// class.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<Cow<'s, str>>,
+
+    /// A message that describes the exception.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Cow<'s, str>>,
+
+    /// The sequence of function calls leading to the exception.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stack: Option<Stack<'s>>,
+
+    /// The exceptions which caused this exception.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub inner_exceptions: Vec<Exception<'s>>,
+
+    /// Key/value pairs that provide additional information about the exception.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Exception<'s> {
+    /// Create a new, empty `Exception`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Exception;
+    ///
+    /// let exception = Exception::new();
+    ///
+    /// assert_eq!(exception.kind, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            kind: None,
+            message: None,
+            stack: None,
+            inner_exceptions: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the name of the exception type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Exception;
+    ///
+    /// let exception = Exception::new().with_kind("ArithmeticError");
+    ///
+    /// assert_eq!(exception.kind.as_deref(), Some("ArithmeticError"));
+    /// ```
+    #[must_use]
+    pub fn with_kind(mut self, kind: impl Into<Cow<'s, str>>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    /// Sets the message that describes the exception.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Exception;
+    ///
+    /// let exception = Exception::new().with_message("division by zero");
+    ///
+    /// assert_eq!(exception.message.as_deref(), Some("division by zero"));
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: impl Into<Cow<'s, str>>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Sets the sequence of function calls leading to the exception.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Exception, Stack, StackFrame};
+    ///
+    /// let exception = Exception::new().with_stack(Stack::new().with_frame(StackFrame::new()));
+    ///
+    /// assert!(exception.stack.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_stack(mut self, stack: Stack<'s>) -> Self {
+        self.stack = Some(stack);
+        self
+    }
+
+    /// Add an exception which caused this exception.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Exception;
+    ///
+    /// let exception = Exception::new()
+    ///     .with_kind("RuleFailure")
+    ///     .with_inner_exception(Exception::new().with_kind("ArithmeticError"));
+    ///
+    /// assert_eq!(exception.inner_exceptions.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_inner_exception(mut self, exception: Self) -> Self {
+        self.inner_exceptions.push(exception);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactLocation, Exception, Invocation, Location, LogicalLocation, Message, Notification,
+        PhysicalLocation, ReportingDescriptorReference, ResultLevel, Run, SarifLog, SchemaVersion,
+        Stack, StackFrame, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn exception() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_invocation(
+                    Invocation::new(false).with_tool_execution_notification(
+                        Notification::new(Message::new("evaluation of `main.jsonc` failed"))
+                            .with_level(ResultLevel::Error)
+                            .with_descriptor(ReportingDescriptorReference::new("internal-error"))
+                            .with_location(Location::new().with_physical_location(
+                                PhysicalLocation::new(ArtifactLocation::new("main.jsonc")),
+                            ))
+                            .with_time_utc("2016-07-16T14:18:43.119Z")
+                            .with_exception(
+                                Exception::new()
+                                    .with_kind("EvaluationError")
+                                    .with_message("could not evaluate the entry point")
+                                    .with_stack(
+                                        Stack::new().with_frame(
+                                            StackFrame::new().with_location(
+                                                Location::new().with_logical_location(
+                                                    LogicalLocation::new()
+                                                        .with_fully_qualified_name("::main"),
+                                                ),
+                                            ),
+                                        ),
+                                    )
+                                    .with_inner_exception(
+                                        Exception::new()
+                                            .with_kind("ArithmeticError")
+                                            .with_message("division by zero"),
+                                    ),
+                            ),
+                    ),
+                ),
+            ),
+        );
+    }
+}
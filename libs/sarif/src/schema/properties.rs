@@ -16,7 +16,7 @@ pub struct PropertyBag<'s> {
     /// A set of distinct strings that provide additional information.
     #[cfg_attr(
         feature = "serde",
-        serde(borrow, skip_serializing_if = "BTreeSet::is_empty")
+        serde(borrow, default, skip_serializing_if = "BTreeSet::is_empty")
     )]
     pub tags: BTreeSet<Cow<'s, str>>,
 
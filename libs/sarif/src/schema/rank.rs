@@ -0,0 +1,98 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// The relative priority of a result or a rule, in the range `0.0..=100.0` ([§3.27.28]).
+///
+/// Higher values indicate a higher priority. The special value `-1.0` indicates that the rank is
+/// unknown.
+///
+/// [§3.27.28]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317676
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Rank(f64);
+
+impl Rank {
+    /// The rank of a result or rule whose priority is unknown.
+    pub const UNKNOWN: Self = Self(-1.0);
+
+    /// Create a new `Rank` from the given value.
+    ///
+    /// Returns [`None`] if the value is neither in the range `0.0..=100.0` nor `-1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Rank;
+    ///
+    /// assert_eq!(Rank::new(95.0).map(Rank::value), Some(95.0));
+    /// assert_eq!(Rank::new(-1.0), Some(Rank::UNKNOWN));
+    ///
+    /// assert_eq!(Rank::new(100.5), None);
+    /// assert_eq!(Rank::new(f64::NAN), None);
+    /// ```
+    #[must_use]
+    pub fn new(value: f64) -> Option<Self> {
+        #[expect(clippy::float_cmp, reason = "`-1.0` is exactly representable")]
+        ((0.0..=100.0).contains(&value) || value == -1.0).then_some(Self(value))
+    }
+
+    /// Returns the value of the rank.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Rank;
+    ///
+    /// assert_eq!(Rank::UNKNOWN.value(), -1.0);
+    /// ```
+    #[must_use]
+    pub const fn value(self) -> f64 {
+        self.0
+    }
+}
+
+// `Rank::new` rejects `NaN`, so the comparison is reflexive.
+impl Eq for Rank {}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Rank {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Integral ranks are emitted as integers, so `95` does not turn into `95.0`.
+        if self.0.fract() == 0.0 {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "the rank is integral and within `-1..=100`"
+            )]
+            serializer.serialize_i64(self.0 as i64)
+        } else {
+            serializer.serialize_f64(self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Rank {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = f64::deserialize(deserializer)?;
+
+        Self::new(value).ok_or_else(|| {
+            de::Error::invalid_value(
+                de::Unexpected::Float(value),
+                &"a rank between 0.0 and 100.0, or -1.0",
+            )
+        })
+    }
+}
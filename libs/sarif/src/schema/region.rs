@@ -0,0 +1,315 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, Message, PropertyBag};
+
+/// A region within an artifact where a result was detected ([§3.30]).
+///
+/// A region can be specified by line and column numbers, by character offsets, or by byte
+/// offsets. Line and column numbers are 1-based, offsets are 0-based. Columns and character
+/// offsets are counted in UTF-16 code units.
+///
+/// [§3.30]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317685
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Region<'s> {
+    /// The line number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_line: Option<u64>,
+
+    /// The column number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_column: Option<u64>,
+
+    /// The line number of the last character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_line: Option<u64>,
+
+    /// The column number of the character following the end of the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_column: Option<u64>,
+
+    /// The zero-based offset from the beginning of the artifact of the first character in the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_offset: Option<u64>,
+
+    /// The length of the region in characters.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_length: Option<u64>,
+
+    /// The zero-based offset from the beginning of the artifact of the first byte in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_offset: Option<u64>,
+
+    /// The length of the region in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_length: Option<u64>,
+
+    /// The portion of the artifact contents within the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub snippet: Option<ArtifactContent<'s>>,
+
+    /// A message relevant to the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// The programming language in which the region is written, if it differs from the one of
+    /// the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Region<'s> {
+    /// Create a new, empty `Region`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new();
+    ///
+    /// assert_eq!(region.start_line, None);
+    /// assert_eq!(region.byte_offset, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            start_line: None,
+            start_column: None,
+            end_line: None,
+            end_column: None,
+            char_offset: None,
+            char_length: None,
+            byte_offset: None,
+            byte_length: None,
+            snippet: None,
+            message: None,
+            source_language: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the line and column of the first character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start(3, 5);
+    ///
+    /// assert_eq!(region.start_line, Some(3));
+    /// assert_eq!(region.start_column, Some(5));
+    /// ```
+    #[must_use]
+    pub const fn with_start(mut self, line: u64, column: u64) -> Self {
+        self.start_line = Some(line);
+        self.start_column = Some(column);
+        self
+    }
+
+    /// Sets the line of the last character and the column following the end of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start(3, 5).with_end(3, 9);
+    ///
+    /// assert_eq!(region.end_line, Some(3));
+    /// assert_eq!(region.end_column, Some(9));
+    /// ```
+    #[must_use]
+    pub const fn with_end(mut self, line: u64, column: u64) -> Self {
+        self.end_line = Some(line);
+        self.end_column = Some(column);
+        self
+    }
+
+    /// Sets the offset and length of the region in characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_char_range(42, 4);
+    ///
+    /// assert_eq!(region.char_offset, Some(42));
+    /// assert_eq!(region.char_length, Some(4));
+    /// ```
+    #[must_use]
+    pub const fn with_char_range(mut self, offset: u64, length: u64) -> Self {
+        self.char_offset = Some(offset);
+        self.char_length = Some(length);
+        self
+    }
+
+    /// Sets the offset and length of the region in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_byte_range(42, 4);
+    ///
+    /// assert_eq!(region.byte_offset, Some(42));
+    /// assert_eq!(region.byte_length, Some(4));
+    /// ```
+    #[must_use]
+    pub const fn with_byte_range(mut self, offset: u64, length: u64) -> Self {
+        self.byte_offset = Some(offset);
+        self.byte_length = Some(length);
+        self
+    }
+
+    /// Sets the portion of the artifact contents within the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, Region};
+    ///
+    /// let region = Region::new()
+    ///     .with_start(15, 1)
+    ///     .with_snippet(ArtifactContent::new("int *ptr;"));
+    ///
+    /// assert_eq!(
+    ///     region.snippet.and_then(|snippet| snippet.text).as_deref(),
+    ///     Some("int *ptr;")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_snippet(mut self, snippet: ArtifactContent<'s>) -> Self {
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// Sets the message relevant to the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Region};
+    ///
+    /// let region = Region::new()
+    ///     .with_start(3, 5)
+    ///     .with_message(Message::new("variable is declared here"));
+    ///
+    /// assert_eq!(
+    ///     region.message.and_then(|message| message.text).as_deref(),
+    ///     Some("variable is declared here")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactContent, ArtifactLocation, Location, Message, PhysicalLocation, Region, Result,
+        Run, SarifLog, SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    fn log_with_region(region: Region<'_>) -> SarifLog<'_> {
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(
+            Run::new(Tool::new(ToolComponent::new("hashql"))).with_result(
+                Result::new(Message::new("unused variable")).with_location(
+                    Location::new().with_physical_location(
+                        PhysicalLocation::new(ArtifactLocation::new("main.jsonc"))
+                            .with_region(region),
+                    ),
+                ),
+            ),
+        )
+    }
+
+    #[test]
+    fn lines() {
+        validate_schema(&log_with_region(
+            Region::new().with_start(1, 1).with_end(2, 12),
+        ));
+    }
+
+    #[test]
+    fn offsets() {
+        validate_schema(&log_with_region(
+            Region::new().with_char_range(0, 4).with_byte_range(0, 4),
+        ));
+    }
+
+    #[test]
+    fn message() {
+        validate_schema(&log_with_region(
+            Region::new()
+                .with_start(1, 1)
+                .with_message(Message::new("declared here")),
+        ));
+    }
+
+    #[test]
+    fn snippet() {
+        validate_schema(&log_with_region(
+            Region::new()
+                .with_start(1, 1)
+                .with_end(1, 21)
+                .with_snippet(ArtifactContent::new(r#"["let", "x", 1, "x"]"#)),
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{PropertyBag, Rank, ResultLevel};
+
+/// Information about a rule or notification that can be configured at runtime ([§3.50]).
+///
+/// [§3.50]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317852
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ReportingConfiguration<'s> {
+    /// Specifies whether the report may be produced during the scan.
+    ///
+    /// If absent, the report is enabled.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub enabled: Option<bool>,
+
+    /// Specifies the failure level for the report.
+    ///
+    /// If absent, the level defaults to [`ResultLevel::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<ResultLevel>,
+
+    /// Specifies the relative priority of the report.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rank: Option<Rank>,
+
+    /// Contains configuration information specific to a report.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub parameters: PropertyBag<'s>,
+
+    /// Key/value pairs that provide additional information about the reporting configuration.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl ReportingConfiguration<'_> {
+    /// Create a new, empty `ReportingConfiguration`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ReportingConfiguration;
+    ///
+    /// let configuration = ReportingConfiguration::new();
+    ///
+    /// assert_eq!(configuration.level, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            enabled: None,
+            level: None,
+            rank: None,
+            parameters: PropertyBag::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets whether the report may be produced during the scan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ReportingConfiguration;
+    ///
+    /// let configuration = ReportingConfiguration::new().with_enabled(false);
+    ///
+    /// assert_eq!(configuration.enabled, Some(false));
+    /// ```
+    #[must_use]
+    pub const fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the failure level for the report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ReportingConfiguration, ResultLevel};
+    ///
+    /// let configuration = ReportingConfiguration::new().with_level(ResultLevel::Error);
+    ///
+    /// assert_eq!(configuration.level, Some(ResultLevel::Error));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: ResultLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the relative priority of the report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Rank, ReportingConfiguration};
+    ///
+    /// let configuration = ReportingConfiguration::new().with_rank(Rank::UNKNOWN);
+    ///
+    /// assert_eq!(configuration.rank, Some(Rank::UNKNOWN));
+    /// ```
+    #[must_use]
+    pub const fn with_rank(mut self, rank: Rank) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        Rank, ReportingConfiguration, ReportingDescriptor, ResultLevel, Run, SarifLog,
+        SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn default_configuration() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(Run::new(Tool::new(
+                ToolComponent::new("hashql").with_rules([
+                    ReportingDescriptor::new("unused-variable").with_default_configuration(
+                        ReportingConfiguration::new()
+                            .with_level(ResultLevel::Note)
+                            .with_rank(Rank::new(12.5).expect("rank should be in range")),
+                    ),
+                    ReportingDescriptor::new("type-mismatch").with_default_configuration(
+                        ReportingConfiguration::new()
+                            .with_enabled(false)
+                            .with_rank(Rank::UNKNOWN),
+                    ),
+                ]),
+            ))),
+        );
+    }
+
+    #[test]
+    fn integral_rank() {
+        let configuration = ReportingConfiguration::new()
+            .with_rank(Rank::new(95.0).expect("rank should be in range"));
+
+        assert_eq!(
+            serde_json::to_string(&configuration).expect("could not serialize configuration"),
+            r#"{"rank":95}"#
+        );
+    }
+
+    #[test]
+    fn out_of_range_rank() {
+        serde_json::from_str::<ReportingConfiguration>(r#"{"rank":101}"#)
+            .expect_err("rank should be out of range");
+    }
+}
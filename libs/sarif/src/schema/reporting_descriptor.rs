@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 use uuid::Uuid;
 
-use crate::schema::MultiformatMessageString;
+use crate::schema::{MultiformatMessageString, PropertyBag, ReportingConfiguration};
 
 /// Metadata that describes a specific report produced by the tool, as part of the analysis it
 /// provides or its runtime reporting.
@@ -108,6 +108,20 @@ pub struct ReportingDescriptor<'s> {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub help_uri: Option<Url>,
+
+    /// Default reporting configuration information.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub default_configuration: Option<ReportingConfiguration<'s>>,
+
+    /// Key/value pairs that provide additional information about the report.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
 }
 
 impl<'s> ReportingDescriptor<'s> {
@@ -136,6 +150,8 @@ impl<'s> ReportingDescriptor<'s> {
             message_strings: BTreeMap::new(),
             help: None,
             help_uri: None,
+            default_configuration: None,
+            properties: PropertyBag::new(),
         }
     }
 
@@ -283,6 +299,29 @@ impl<'s> ReportingDescriptor<'s> {
         self.help_uri = Some(help_uri.into());
         self
     }
+
+    /// Sets the default reporting configuration of the report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ReportingConfiguration, ReportingDescriptor, ResultLevel};
+    ///
+    /// let descriptor = ReportingDescriptor::new("E0308")
+    ///     .with_default_configuration(ReportingConfiguration::new().with_level(ResultLevel::Error));
+    ///
+    /// assert_eq!(
+    ///     descriptor
+    ///         .default_configuration
+    ///         .and_then(|configuration| configuration.level),
+    ///     Some(ResultLevel::Error)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_default_configuration(mut self, configuration: ReportingConfiguration<'s>) -> Self {
+        self.default_configuration = Some(configuration);
+        self
+    }
 }
 
 #[cfg(all(feature = "serde", test))]
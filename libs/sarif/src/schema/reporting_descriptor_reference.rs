@@ -0,0 +1,255 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::PropertyBag;
+
+/// Information about how to locate a relevant [`ReportingDescriptor`] ([§3.52]).
+///
+/// [`ReportingDescriptor`]: crate::schema::ReportingDescriptor
+///
+/// [§3.52]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317862
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ReportingDescriptorReference<'s> {
+    /// The id of the descriptor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<Cow<'s, str>>,
+
+    /// The index into an array of descriptors in the tool component referenced by
+    /// [`tool_component`].
+    ///
+    /// [`tool_component`]: Self::tool_component
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// A GUID that identifies the descriptor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// The tool component containing the descriptor.
+    ///
+    /// If absent, the descriptor is contained in the driver of the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub tool_component: Option<ToolComponentReference<'s>>,
+
+    /// Key/value pairs that provide additional information about the reference.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ReportingDescriptorReference<'s> {
+    /// Create a new `ReportingDescriptorReference` to the descriptor with the given id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ReportingDescriptorReference;
+    ///
+    /// let reference = ReportingDescriptorReference::new("unused-variable");
+    ///
+    /// assert_eq!(reference.id.as_deref(), Some("unused-variable"));
+    /// ```
+    #[must_use]
+    pub fn new(id: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            id: Some(id.into()),
+            index: None,
+            guid: None,
+            tool_component: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the index of the descriptor within the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ReportingDescriptorReference;
+    ///
+    /// let reference = ReportingDescriptorReference::new("unused-variable").with_index(0);
+    ///
+    /// assert_eq!(reference.index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_index(mut self, index: u64) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the tool component containing the descriptor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ReportingDescriptorReference, ToolComponentReference};
+    ///
+    /// let reference = ReportingDescriptorReference::new("unused-variable")
+    ///     .with_tool_component(ToolComponentReference::new().with_name("hashql-lints"));
+    ///
+    /// assert!(reference.tool_component.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_tool_component(mut self, tool_component: ToolComponentReference<'s>) -> Self {
+        self.tool_component = Some(tool_component);
+        self
+    }
+}
+
+/// Identifies a particular [`ToolComponent`] object, either the driver or an extension ([§3.54]).
+///
+/// [`ToolComponent`]: crate::schema::ToolComponent
+///
+/// [§3.54]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317871
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ToolComponentReference<'s> {
+    /// The name of the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub name: Option<Cow<'s, str>>,
+
+    /// The index within the tool extensions array of the tool component.
+    ///
+    /// If absent, the reference refers to the driver.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// The GUID of the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// Key/value pairs that provide additional information about the reference.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ToolComponentReference<'s> {
+    /// Create a new, empty `ToolComponentReference`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponentReference;
+    ///
+    /// let reference = ToolComponentReference::new();
+    ///
+    /// assert_eq!(reference.index, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            index: None,
+            guid: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the name of the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponentReference;
+    ///
+    /// let reference = ToolComponentReference::new().with_name("hashql-lints");
+    ///
+    /// assert_eq!(reference.name.as_deref(), Some("hashql-lints"));
+    /// ```
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<Cow<'s, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the index of the tool component within the tool extensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponentReference;
+    ///
+    /// let reference = ToolComponentReference::new().with_index(0);
+    ///
+    /// assert_eq!(reference.index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_index(mut self, index: u64) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        Message, ReportingDescriptor, ReportingDescriptorReference, Result, Run, SarifLog,
+        SchemaVersion, Tool, ToolComponent, ToolComponentReference, tests::validate_schema,
+    };
+
+    #[test]
+    fn extension_rule() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(
+                    Tool::new(ToolComponent::new("hashql")).with_extension(
+                        ToolComponent::new("hashql-lints")
+                            .with_rule(ReportingDescriptor::new("unused-variable")),
+                    ),
+                )
+                .with_result(
+                    Result::new(Message::new("unused variable")).with_rule(
+                        ReportingDescriptorReference::new("unused-variable")
+                            .with_index(0)
+                            .with_tool_component(
+                                ToolComponentReference::new()
+                                    .with_name("hashql-lints")
+                                    .with_index(0),
+                            ),
+                    ),
+                ),
+            ),
+        );
+    }
+}
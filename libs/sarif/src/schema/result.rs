@@ -0,0 +1,1031 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+
+use crate::schema::{
+    ArtifactLocation, CodeFlow, Fix, Location, Message, PropertyBag, Rank,
+    ReportingDescriptorReference, Stack, Suppression,
+};
+
+/// The severity of a [`Result`] ([§3.27.10]).
+///
+/// [§3.27.10]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317648
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ResultLevel {
+    /// The concept of “severity” does not apply to this result.
+    None,
+    /// A minor problem or an opportunity to improve the code was found.
+    Note,
+    /// A problem was found.
+    Warning,
+    /// A serious problem was found.
+    Error,
+}
+
+/// The nature of a [`Result`] ([§3.27.9]).
+///
+/// [§3.27.9]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317647
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ResultKind {
+    /// The rule was evaluated, and no problem was found.
+    Pass,
+    /// The rule was evaluated, and a problem was found.
+    Fail,
+    /// The rule was not evaluated, because it does not apply to the analysis target.
+    NotApplicable,
+    /// The rule was evaluated, and the tool could not determine whether a problem was found.
+    Review,
+    /// The result represents a problem whose correctness cannot be determined by the tool, for
+    /// example because it requires additional information.
+    Open,
+    /// The result is purely informational and does not represent a problem.
+    Informational,
+}
+
+/// The state of a [`Result`] relative to a baseline of a previous run ([§3.27.24]).
+///
+/// [§3.27.24]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317672
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum BaselineState {
+    /// The result was not detected in the baseline run.
+    New,
+    /// The result was detected in the baseline run and did not change.
+    Unchanged,
+    /// The result was detected in the baseline run, but some of its properties changed.
+    Updated,
+    /// The result was detected in the baseline run, but not in the current one.
+    Absent,
+}
+
+/// A result produced by an analysis tool ([§3.27]).
+///
+/// [§3.27]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317638
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Result<'s> {
+    /// The stable, unique identifier of the rule, if any, to which this result is relevant.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_id: Option<Cow<'s, str>>,
+
+    /// The index within the tool component rules array of the rule object associated with this
+    /// result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_index: Option<u64>,
+
+    /// A reference used to locate the rule descriptor relevant to this result.
+    ///
+    /// This is required to refer to a rule which is defined by an extension rather than the
+    /// driver of the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule: Option<ReportingDescriptorReference<'s>>,
+
+    /// The nature of the result.
+    ///
+    /// If absent, the result is [`ResultKind::Fail`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<ResultKind>,
+
+    /// The severity of the result.
+    ///
+    /// If absent, the severity is taken from the configuration of the rule, and defaults to
+    /// [`ResultLevel::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<ResultLevel>,
+
+    /// A message that describes the result.
+    ///
+    /// The first sentence of the message only will be displayed when visible space is limited.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// Identifies the artifact that the analysis tool was instructed to scan.
+    ///
+    /// Only required if this differs from the artifact in which the result was detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub analysis_target: Option<ArtifactLocation<'s>>,
+
+    /// The set of locations where the result was detected.
+    ///
+    /// Should only contain multiple elements if the condition can only be corrected by making a
+    /// change at every location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A set of locations relevant to this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub related_locations: Vec<Location<'s>>,
+
+    /// A stable, unique identifier for the result in the form of a GUID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// A stable, unique identifier for the equivalence class of logically identical results to
+    /// which this result belongs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub correlation_guid: Option<Uuid>,
+
+    /// The number of times this result was observed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub occurrence_count: Option<u64>,
+
+    /// A set of strings that contribute to the stable, unique identity of the result, keyed by
+    /// the name of the fingerprint algorithm.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// A set of strings each of which individually defines a stable, unique identity for the
+    /// result, keyed by the name of the fingerprint algorithm.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub partial_fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// The code flows relevant to this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub code_flows: Vec<CodeFlow<'s>>,
+
+    /// The call stacks relevant to this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub stacks: Vec<Stack<'s>>,
+
+    /// The proposed fixes for the problem represented by this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub fixes: Vec<Fix<'s>>,
+
+    /// The requests to suppress this result.
+    ///
+    /// If empty, the result is not suppressed. If absent, it is unknown whether the result is
+    /// suppressed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub suppressions: Option<Vec<Suppression<'s>>>,
+
+    /// The state of the result relative to a baseline of a previous run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub baseline_state: Option<BaselineState>,
+
+    /// The priority or importance of the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rank: Option<Rank>,
+
+    /// An absolute URI at which the result can be viewed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub hosted_viewer_uri: Option<Url>,
+
+    /// The URIs of the work items associated with this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub work_item_uris: Vec<Url>,
+
+    /// Information about how and when the result was detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub provenance: Option<ResultProvenance<'s>>,
+
+    /// Key/value pairs that provide additional information about the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Result<'s> {
+    /// Create a new `Result` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable"));
+    ///
+    /// assert_eq!(result.message.text.as_deref(), Some("unused variable"));
+    /// assert!(result.locations.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            rule_id: None,
+            rule_index: None,
+            rule: None,
+            kind: None,
+            level: None,
+            message,
+            analysis_target: None,
+            locations: Vec::new(),
+            related_locations: Vec::new(),
+            guid: None,
+            correlation_guid: None,
+            occurrence_count: None,
+            fingerprints: BTreeMap::new(),
+            partial_fingerprints: BTreeMap::new(),
+            code_flows: Vec::new(),
+            stacks: Vec::new(),
+            fixes: Vec::new(),
+            suppressions: None,
+            baseline_state: None,
+            rank: None,
+            hosted_viewer_uri: None,
+            work_item_uris: Vec::new(),
+            provenance: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the rule to which this result is relevant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_rule_id("unused-variable");
+    ///
+    /// assert_eq!(result.rule_id.as_deref(), Some("unused-variable"));
+    /// ```
+    #[must_use]
+    pub fn with_rule_id(mut self, rule_id: impl Into<Cow<'s, str>>) -> Self {
+        self.rule_id = Some(rule_id.into());
+        self
+    }
+
+    /// Sets the index of the rule within the rules of the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_rule_index(0);
+    ///
+    /// assert_eq!(result.rule_index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_rule_index(mut self, rule_index: u64) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    /// Sets the reference to the rule descriptor relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, ReportingDescriptorReference, Result, ToolComponentReference};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_rule(
+    ///     ReportingDescriptorReference::new("unused-variable")
+    ///         .with_tool_component(ToolComponentReference::new().with_index(0)),
+    /// );
+    ///
+    /// assert!(result.rule.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_rule(mut self, rule: ReportingDescriptorReference<'s>) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    /// Sets the nature of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, ResultKind};
+    ///
+    /// let result = Result::new(Message::new("all variables are used")).with_kind(ResultKind::Pass);
+    ///
+    /// assert_eq!(result.kind, Some(ResultKind::Pass));
+    /// ```
+    #[must_use]
+    pub const fn with_kind(mut self, kind: ResultKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Sets the severity of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, ResultLevel};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_level(ResultLevel::Note);
+    ///
+    /// assert_eq!(result.level, Some(ResultLevel::Note));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: ResultLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Add a location where the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, PhysicalLocation, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_location(
+    ///     Location::new()
+    ///         .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))),
+    /// );
+    ///
+    /// assert_eq!(result.locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Add multiple locations where the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_locations([Location::new().with_id(0), Location::new().with_id(1)]);
+    ///
+    /// assert_eq!(result.locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_locations(mut self, locations: impl IntoIterator<Item = Location<'s>>) -> Self {
+        self.locations.extend(locations);
+        self
+    }
+
+    /// Add a location relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_related_location(Location::new().with_message(Message::new("declared here")));
+    ///
+    /// assert_eq!(result.related_locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_related_location(mut self, location: Location<'s>) -> Self {
+        self.related_locations.push(location);
+        self
+    }
+
+    /// Add multiple locations relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_related_locations([Location::new().with_id(0), Location::new().with_id(1)]);
+    ///
+    /// assert_eq!(result.related_locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_related_locations(
+        mut self,
+        locations: impl IntoIterator<Item = Location<'s>>,
+    ) -> Self {
+        self.related_locations.extend(locations);
+        self
+    }
+
+    /// Sets the artifact that the analysis tool was instructed to scan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_analysis_target(ArtifactLocation::new("main.jsonc"));
+    ///
+    /// assert!(result.analysis_target.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_analysis_target(mut self, analysis_target: ArtifactLocation<'s>) -> Self {
+        self.analysis_target = Some(analysis_target);
+        self
+    }
+
+    /// Sets the stable, unique identifier of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    /// use uuid::Uuid;
+    ///
+    /// let guid = Uuid::from_u128(0x3918_D370_C636_40D8_BF23_8C17_6043_A2DF);
+    /// let result = Result::new(Message::new("unused variable")).with_guid(guid);
+    ///
+    /// assert_eq!(result.guid, Some(guid));
+    /// ```
+    #[must_use]
+    pub const fn with_guid(mut self, guid: Uuid) -> Self {
+        self.guid = Some(guid);
+        self
+    }
+
+    /// Add a partial fingerprint, computed by the given algorithm, to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_partial_fingerprint("primaryLocationLineHash/v1", "39fa2ee980eb94b0:1");
+    ///
+    /// assert_eq!(
+    ///     result.partial_fingerprints["primaryLocationLineHash/v1"],
+    ///     "39fa2ee980eb94b0:1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_partial_fingerprint(
+        mut self,
+        algorithm: impl Into<Cow<'s, str>>,
+        fingerprint: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.partial_fingerprints
+            .insert(algorithm.into(), fingerprint.into());
+        self
+    }
+
+    /// Add a code flow relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Location, Message, Result, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let result = Result::new(Message::new("uninitialized variable")).with_code_flow(CodeFlow::new(
+    ///     ThreadFlow::new().with_location(ThreadFlowLocation::new(Location::new())),
+    /// ));
+    ///
+    /// assert_eq!(result.code_flows.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_code_flow(mut self, code_flow: CodeFlow<'s>) -> Self {
+        self.code_flows.push(code_flow);
+        self
+    }
+
+    /// Add a call stack relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Stack, StackFrame};
+    ///
+    /// let result = Result::new(Message::new("division by zero"))
+    ///     .with_stack(Stack::new().with_frame(StackFrame::new().with_module("math")));
+    ///
+    /// assert_eq!(result.stacks.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_stack(mut self, stack: Stack<'s>) -> Self {
+        self.stacks.push(stack);
+        self
+    }
+
+    /// Add a proposed fix for the problem represented by the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_fix(Fix::new(
+    ///     ArtifactChange::new(ArtifactLocation::new("main.jsonc")),
+    /// ));
+    ///
+    /// assert_eq!(result.fixes.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_fix(mut self, fix: Fix<'s>) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+
+    /// Add a request to suppress the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Suppression, SuppressionKind};
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_suppression(Suppression::new(SuppressionKind::InSource));
+    ///
+    /// assert_eq!(
+    ///     result.suppressions.map(|suppressions| suppressions.len()),
+    ///     Some(1)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_suppression(mut self, suppression: Suppression<'s>) -> Self {
+        match self.suppressions {
+            Some(ref mut suppressions) => suppressions.push(suppression),
+            None => self.suppressions = Some(vec![suppression]),
+        }
+        self
+    }
+
+    /// Add multiple requests to suppress the result.
+    ///
+    /// An empty iterator marks the result as not suppressed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_suppressions([]);
+    ///
+    /// assert_eq!(result.suppressions, Some(vec![]));
+    /// ```
+    #[must_use]
+    pub fn with_suppressions(
+        mut self,
+        suppressions: impl IntoIterator<Item = Suppression<'s>>,
+    ) -> Self {
+        match self.suppressions {
+            Some(ref mut existing_suppressions) => existing_suppressions.extend(suppressions),
+            None => self.suppressions = Some(suppressions.into_iter().collect()),
+        }
+        self
+    }
+
+    /// Sets the state of the result relative to a baseline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{BaselineState, Message, Result};
+    ///
+    /// let result =
+    ///     Result::new(Message::new("unused variable")).with_baseline_state(BaselineState::New);
+    ///
+    /// assert_eq!(result.baseline_state, Some(BaselineState::New));
+    /// ```
+    #[must_use]
+    pub const fn with_baseline_state(mut self, baseline_state: BaselineState) -> Self {
+        self.baseline_state = Some(baseline_state);
+        self
+    }
+
+    /// Sets the priority of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Rank, Result};
+    ///
+    /// let rank = Rank::new(95.0).expect("rank should be in range");
+    /// let result = Result::new(Message::new("unused variable")).with_rank(rank);
+    ///
+    /// assert_eq!(result.rank, Some(rank));
+    /// ```
+    #[must_use]
+    pub const fn with_rank(mut self, rank: Rank) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+
+    /// Add the URI of a work item associated with the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    /// use url::Url;
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_work_item_uri(Url::parse("https://github.com/hashintel/hash/issues/42")?);
+    ///
+    /// assert_eq!(result.work_item_uris.len(), 1);
+    /// # Ok::<(), url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn with_work_item_uri(mut self, uri: Url) -> Self {
+        self.work_item_uris.push(uri);
+        self
+    }
+
+    /// Sets the information about how and when the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, ResultProvenance};
+    ///
+    /// let result = Result::new(Message::new("unused variable")).with_provenance(
+    ///     ResultProvenance::new().with_first_detection_time_utc("2016-07-15T14:20:42Z"),
+    /// );
+    ///
+    /// assert!(result.provenance.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_provenance(mut self, provenance: ResultProvenance<'s>) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Add a property to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable"))
+    ///     .with_properties(|properties| properties.with_tag("maintainability"));
+    ///
+    /// assert!(result.properties.tags.iter().eq(["maintainability"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+/// Information about how and when a [`Result`] was detected ([§3.48]).
+///
+/// [§3.48]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317831
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ResultProvenance<'s> {
+    /// The date and time at which the result was first detected, in the format specified by
+    /// [ISO 8601] and in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub first_detection_time_utc: Option<Cow<'s, str>>,
+
+    /// The date and time at which the result was most recently detected, in the format
+    /// specified by [ISO 8601] and in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub last_detection_time_utc: Option<Cow<'s, str>>,
+
+    /// The GUID of the run in which the result was first detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub first_detection_run_guid: Option<Uuid>,
+
+    /// The GUID of the run in which the result was most recently detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub last_detection_run_guid: Option<Uuid>,
+
+    /// The index within the run invocations array of the invocation during which the result was
+    /// detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub invocation_index: Option<u64>,
+
+    /// Key/value pairs that provide additional information about the provenance.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ResultProvenance<'s> {
+    /// Create a new, empty `ResultProvenance`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ResultProvenance;
+    ///
+    /// let provenance = ResultProvenance::new();
+    ///
+    /// assert_eq!(provenance.invocation_index, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            first_detection_time_utc: None,
+            last_detection_time_utc: None,
+            first_detection_run_guid: None,
+            last_detection_run_guid: None,
+            invocation_index: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the date and time at which the result was first detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ResultProvenance;
+    ///
+    /// let provenance = ResultProvenance::new().with_first_detection_time_utc("2016-07-15T14:20:42Z");
+    ///
+    /// assert_eq!(
+    ///     provenance.first_detection_time_utc.as_deref(),
+    ///     Some("2016-07-15T14:20:42Z")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_first_detection_time_utc(mut self, time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.first_detection_time_utc = Some(time_utc.into());
+        self
+    }
+
+    /// Sets the date and time at which the result was most recently detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ResultProvenance;
+    ///
+    /// let provenance = ResultProvenance::new().with_last_detection_time_utc("2016-07-16T14:20:42Z");
+    ///
+    /// assert_eq!(
+    ///     provenance.last_detection_time_utc.as_deref(),
+    ///     Some("2016-07-16T14:20:42Z")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_last_detection_time_utc(mut self, time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.last_detection_time_utc = Some(time_utc.into());
+        self
+    }
+
+    /// Sets the index of the invocation during which the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ResultProvenance;
+    ///
+    /// let provenance = ResultProvenance::new().with_invocation_index(0);
+    ///
+    /// assert_eq!(provenance.invocation_index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_invocation_index(mut self, invocation_index: u64) -> Self {
+        self.invocation_index = Some(invocation_index);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use url::Url;
+
+    use crate::schema::{
+        ArtifactLocation, BaselineState, Location, Message, PhysicalLocation, Rank, Region,
+        ReportingDescriptor, Result, ResultKind, ResultLevel, ResultProvenance, Run, SarifLog,
+        SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    #[test]
+    fn minimal() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql")))
+                    .with_result(Result::new(Message::new("unused variable"))),
+            ),
+        );
+    }
+
+    #[test]
+    fn levels() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_results(
+                    [
+                        ResultLevel::None,
+                        ResultLevel::Note,
+                        ResultLevel::Warning,
+                        ResultLevel::Error,
+                    ]
+                    .map(|level| Result::new(Message::new("unused variable")).with_level(level)),
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn full() {
+        let location = |line| {
+            PhysicalLocation::new(ArtifactLocation::new("main.jsonc"))
+                .with_region(Region::new().with_start(line, 2).with_end(line, 5))
+        };
+
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(
+                    ToolComponent::new("hashql")
+                        .with_rule(ReportingDescriptor::new("unused-variable")),
+                ))
+                .with_result(
+                    Result::new(Message::new("unused variable"))
+                        .with_rule_id("unused-variable")
+                        .with_rule_index(0)
+                        .with_level(ResultLevel::Warning)
+                        .with_location(
+                            Location::new()
+                                .with_physical_location(location(3))
+                                .with_message(Message::new("`x` is never used")),
+                        )
+                        .with_related_location(
+                            Location::new()
+                                .with_id(0)
+                                .with_physical_location(location(1))
+                                .with_message(Message::new("declared here")),
+                        )
+                        .with_properties(|properties| properties.with_tag("maintainability")),
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn kinds() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_results(
+                    [
+                        ResultKind::Pass,
+                        ResultKind::Fail,
+                        ResultKind::NotApplicable,
+                        ResultKind::Review,
+                        ResultKind::Open,
+                        ResultKind::Informational,
+                    ]
+                    .map(|kind| Result::new(Message::new("unused variable")).with_kind(kind)),
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn baseline() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_results(
+                    [
+                        BaselineState::New,
+                        BaselineState::Unchanged,
+                        BaselineState::Updated,
+                        BaselineState::Absent,
+                    ]
+                    .map(|state| {
+                        Result::new(Message::new("unused variable"))
+                            .with_baseline_state(state)
+                            .with_partial_fingerprint(
+                                "primaryLocationLineHash/v1",
+                                "39fa2ee980eb94b0:1",
+                            )
+                            .with_provenance(
+                                ResultProvenance::new()
+                                    .with_first_detection_time_utc("2016-07-15T14:20:42Z")
+                                    .with_last_detection_time_utc("2016-07-16T14:20:42Z")
+                                    .with_invocation_index(0),
+                            )
+                    }),
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn triage() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_result(
+                    Result::new(Message::new("unused variable"))
+                        .with_analysis_target(ArtifactLocation::new("main.jsonc"))
+                        .with_rank(Rank::new(42.5).expect("rank should be in range"))
+                        .with_work_item_uri(
+                            Url::parse("https://github.com/hashintel/hash/issues/42")
+                                .expect("valid URL"),
+                        ),
+                ),
+            ),
+        );
+    }
+}
@@ -1,7 +1,29 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec, vec::Vec};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use crate::schema::Tool;
+use crate::schema::{
+    Artifact, ArtifactLocation, Invocation, LogicalLocation, PropertyBag, Result,
+    RunAutomationDetails, Tool, VersionControlDetails,
+};
+
+/// The unit in which a [`Run`] measures columns ([§3.14.25]).
+///
+/// [§3.14.25]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317509
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ColumnKind {
+    /// Each UTF-16 code unit is a column, as used by JavaScript, Java and .NET.
+    Utf16CodeUnits,
+    /// Each Unicode code point is a column.
+    UnicodeCodePoints,
+}
 
 /// Describes a single run of an analysis tool, and contains the reported output of that run.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,6 +33,10 @@ use crate::schema::Tool;
     serde(rename_all = "camelCase")
 )]
 #[non_exhaustive]
+#[expect(
+    clippy::struct_field_names,
+    reason = "The field names are defined by the SARIF specification"
+)]
 pub struct Run<'s> {
     /// Information about the tool or tool pipeline that generated the results in this run
     ///
@@ -19,6 +45,117 @@ pub struct Run<'s> {
     /// command-line arguments and the like) is identical for all aggregated files.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub tool: Tool<'s>,
+
+    /// The runtime environments of the tool, one for each time it was invoked during the run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub invocations: Vec<Invocation<'s>>,
+
+    /// The language of the messages emitted into the log file during the run, in the format
+    /// specified by [RFC 5646], for example `en-US`.
+    ///
+    /// [RFC 5646]: https://tools.ietf.org/html/rfc5646
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub language: Option<Cow<'s, str>>,
+
+    /// Information about the version control systems from which the analyzed artifacts were
+    /// retrieved.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub version_control_provenance: Vec<VersionControlDetails<'s>>,
+
+    /// The absolute locations denoted by the URI base ids used in the run, keyed by the URI base
+    /// id.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub original_uri_base_ids: BTreeMap<Cow<'s, str>, ArtifactLocation<'s>>,
+
+    /// The artifacts relevant to the run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub artifacts: Vec<Artifact<'s>>,
+
+    /// The logical locations relevant to the run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub logical_locations: Vec<LogicalLocation<'s>>,
+
+    /// Information that describes the run's identity and role within an engineering system.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub automation_details: Option<RunAutomationDetails<'s>>,
+
+    /// Information about the aggregations of runs, such as a build, to which the run belongs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub run_aggregates: Vec<RunAutomationDetails<'s>>,
+
+    /// The GUID of the run against which the results of this run were compared to determine
+    /// their baseline state.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub baseline_guid: Option<Uuid>,
+
+    /// The unit in which the run measures columns.
+    ///
+    /// If absent, the unit is [`ColumnKind::UnicodeCodePoints`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub column_kind: Option<ColumnKind>,
+
+    /// The name of the encoding used by the artifacts of the run, unless an artifact specifies
+    /// otherwise.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub default_encoding: Option<Cow<'s, str>>,
+
+    /// The programming language in which the artifacts of the run are written, unless an
+    /// artifact specifies otherwise.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub default_source_language: Option<Cow<'s, str>>,
+
+    /// The set of results contained in a SARIF log.
+    ///
+    /// If the tool ran and found no results, this must be an empty array. If the tool did not
+    /// run, for example because it failed, this must be absent.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub results: Option<Vec<Result<'s>>>,
+
+    /// Key/value pairs that provide additional information about the run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
 }
 
 impl<'s> Run<'s> {
@@ -32,10 +169,286 @@ impl<'s> Run<'s> {
     /// let run = Run::new(Tool::new(ToolComponent::new("clippy")));
     ///
     /// assert_eq!(run.tool.driver.name, "clippy");
+    /// assert_eq!(run.results, None);
     /// ```
     #[must_use]
     pub const fn new(tool: Tool<'s>) -> Self {
-        Self { tool }
+        Self {
+            tool,
+            invocations: Vec::new(),
+            language: None,
+            version_control_provenance: Vec::new(),
+            original_uri_base_ids: BTreeMap::new(),
+            artifacts: Vec::new(),
+            logical_locations: Vec::new(),
+            automation_details: None,
+            run_aggregates: Vec::new(),
+            baseline_guid: None,
+            column_kind: None,
+            default_encoding: None,
+            default_source_language: None,
+            results: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Add an invocation of the tool to this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Run, Tool, ToolComponent};
+    ///
+    /// let run =
+    ///     Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(Invocation::new(true));
+    ///
+    /// assert_eq!(run.invocations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_invocation(mut self, invocation: Invocation<'s>) -> Self {
+        self.invocations.push(invocation);
+        self
+    }
+
+    /// Sets the language of the messages emitted during this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_language("en-US");
+    ///
+    /// assert_eq!(run.language.as_deref(), Some("en-US"));
+    /// ```
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Cow<'s, str>>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Add information about a version control system the analyzed artifacts were retrieved
+    /// from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Run, Tool, ToolComponent, VersionControlDetails};
+    /// use url::Url;
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_version_control_provenance(
+    ///     VersionControlDetails::new(Url::parse("https://github.com/hashintel/hash")?),
+    /// );
+    ///
+    /// assert_eq!(run.version_control_provenance.len(), 1);
+    /// # Ok::<(), url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn with_version_control_provenance(mut self, details: VersionControlDetails<'s>) -> Self {
+        self.version_control_provenance.push(details);
+        self
+    }
+
+    /// Sets the absolute location denoted by a URI base id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_original_uri_base_id(
+    ///     "SRCROOT",
+    ///     ArtifactLocation::new("file:///home/user/project/"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     run.original_uri_base_ids["SRCROOT"].uri.as_deref(),
+    ///     Some("file:///home/user/project/")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_original_uri_base_id(
+        mut self,
+        uri_base_id: impl Into<Cow<'s, str>>,
+        location: ArtifactLocation<'s>,
+    ) -> Self {
+        self.original_uri_base_ids
+            .insert(uri_base_id.into(), location);
+        self
+    }
+
+    /// Add an artifact relevant to this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs")));
+    ///
+    /// assert_eq!(run.artifacts.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_artifact(mut self, artifact: Artifact<'s>) -> Self {
+        self.artifacts.push(artifact);
+        self
+    }
+
+    /// Add multiple artifacts relevant to this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_artifacts([
+    ///     Artifact::new(ArtifactLocation::new("src/lib.rs")),
+    ///     Artifact::new(ArtifactLocation::new("src/main.rs")),
+    /// ]);
+    ///
+    /// assert_eq!(run.artifacts.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_artifacts(mut self, artifacts: impl IntoIterator<Item = Artifact<'s>>) -> Self {
+        self.artifacts.extend(artifacts);
+        self
+    }
+
+    /// Add multiple logical locations relevant to this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{LogicalLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_logical_locations([
+    ///     LogicalLocation::new().with_name("math").with_kind("module"),
+    ///     LogicalLocation::new().with_name("add").with_parent_index(0),
+    /// ]);
+    ///
+    /// assert_eq!(run.logical_locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_logical_locations(
+        mut self,
+        logical_locations: impl IntoIterator<Item = LogicalLocation<'s>>,
+    ) -> Self {
+        self.logical_locations.extend(logical_locations);
+        self
+    }
+
+    /// Sets the information that describes the identity of this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Run, RunAutomationDetails, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_automation_details(RunAutomationDetails::new("Nightly code scan/2018-10-08"));
+    ///
+    /// assert!(run.automation_details.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_automation_details(mut self, details: RunAutomationDetails<'s>) -> Self {
+        self.automation_details = Some(details);
+        self
+    }
+
+    /// Add information about an aggregation of runs this run belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Run, RunAutomationDetails, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_run_aggregate(RunAutomationDetails::new("Build/14.0.1.2/Release"));
+    ///
+    /// assert_eq!(run.run_aggregates.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_run_aggregate(mut self, details: RunAutomationDetails<'s>) -> Self {
+        self.run_aggregates.push(details);
+        self
+    }
+
+    /// Sets the unit in which this run measures columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ColumnKind, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_column_kind(ColumnKind::Utf16CodeUnits);
+    ///
+    /// assert_eq!(run.column_kind, Some(ColumnKind::Utf16CodeUnits));
+    /// ```
+    #[must_use]
+    pub const fn with_column_kind(mut self, column_kind: ColumnKind) -> Self {
+        self.column_kind = Some(column_kind);
+        self
+    }
+
+    /// Add a result to this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_result(Result::new(Message::new("unused variable")));
+    ///
+    /// assert_eq!(run.results.unwrap().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_result(mut self, result: Result<'s>) -> Self {
+        match self.results {
+            Some(ref mut results) => results.push(result),
+            None => self.results = Some(vec![result]),
+        }
+        self
+    }
+
+    /// Add multiple results to this run.
+    ///
+    /// An empty iterator marks the run as having found no results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([
+    ///     Result::new(Message::new("unused variable")),
+    ///     Result::new(Message::new("unused import")),
+    /// ]);
+    ///
+    /// assert_eq!(run.results.unwrap().len(), 2);
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([]);
+    ///
+    /// assert_eq!(run.results, Some(vec![]));
+    /// ```
+    #[must_use]
+    pub fn with_results(mut self, results: impl IntoIterator<Item = Result<'s>>) -> Self {
+        match self.results {
+            Some(ref mut existing_results) => existing_results.extend(results),
+            None => self.results = Some(results.into_iter().collect()),
+        }
+        self
+    }
+}
+
+impl<'s> Extend<Result<'s>> for Run<'s> {
+    fn extend<T: IntoIterator<Item = Result<'s>>>(&mut self, iter: T) {
+        match self.results {
+            Some(ref mut results) => results.extend(iter),
+            None => self.results = Some(iter.into_iter().collect()),
+        }
     }
 }
 
@@ -45,7 +458,8 @@ impl<'s> Run<'s> {
 pub(crate) mod tests {
 
     use crate::schema::{
-        Run, SarifLog, SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+        ArtifactLocation, ColumnKind, Message, Result, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent, tests::validate_schema,
     };
 
     #[test]
@@ -54,4 +468,39 @@ pub(crate) mod tests {
 
         validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
     }
+
+    #[test]
+    fn no_results() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([]);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn extend() {
+        let mut run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_result(Result::new(Message::new("unused variable")));
+
+        run.extend([Result::new(Message::new("unused import"))]);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run.clone()));
+        assert_eq!(run.results.expect("no results found").len(), 2);
+    }
+
+    #[test]
+    fn uri_base_ids() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_language("en-US")
+            .with_column_kind(ColumnKind::Utf16CodeUnits)
+            .with_original_uri_base_id(
+                "PROJECTROOT",
+                ArtifactLocation::new("file:///home/user/project/"),
+            )
+            .with_original_uri_base_id(
+                "SRCROOT",
+                ArtifactLocation::new("src/").with_uri_base_id("PROJECTROOT"),
+            );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
 }
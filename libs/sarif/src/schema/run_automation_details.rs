@@ -0,0 +1,171 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{Message, PropertyBag};
+
+/// Information that describes a run's identity and role within an engineering system
+/// ([§3.17]).
+///
+/// [§3.17]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317515
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct RunAutomationDetails<'s> {
+    /// A description of the identity and role played within the engineering system by this
+    /// object's containing run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// A hierarchical string that uniquely identifies this object's containing run.
+    ///
+    /// The components of the string are separated by `/`, and the last component identifies the
+    /// run itself, for example `Nightly code scan/2018-10-08`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<Cow<'s, str>>,
+
+    /// A stable, unique identifier for this object's containing run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// A stable, unique identifier for the equivalence class of runs to which this object's
+    /// containing run belongs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub correlation_guid: Option<Uuid>,
+
+    /// Key/value pairs that provide additional information about the automation details.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> RunAutomationDetails<'s> {
+    /// Create a new `RunAutomationDetails` with the given identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::RunAutomationDetails;
+    ///
+    /// let details = RunAutomationDetails::new("Nightly code scan/2018-10-08");
+    ///
+    /// assert_eq!(details.id.as_deref(), Some("Nightly code scan/2018-10-08"));
+    /// ```
+    #[must_use]
+    pub fn new(id: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            description: None,
+            id: Some(id.into()),
+            guid: None,
+            correlation_guid: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the description of the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, RunAutomationDetails};
+    ///
+    /// let details = RunAutomationDetails::new("Nightly code scan/2018-10-08")
+    ///     .with_description(Message::new("Nightly scan of the main branch"));
+    ///
+    /// assert!(details.description.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the unique identifier of the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::RunAutomationDetails;
+    /// use uuid::Uuid;
+    ///
+    /// let details = RunAutomationDetails::new("Nightly code scan/2018-10-08")
+    ///     .with_guid(Uuid::from_u128(0xBC65_0830_A9FE_44CB_8818_AD6C_3872_79A0));
+    ///
+    /// assert!(details.guid.is_some());
+    /// ```
+    #[must_use]
+    pub const fn with_guid(mut self, guid: Uuid) -> Self {
+        self.guid = Some(guid);
+        self
+    }
+
+    /// Sets the identifier of the equivalence class of runs the run belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::RunAutomationDetails;
+    /// use uuid::Uuid;
+    ///
+    /// let details = RunAutomationDetails::new("Nightly code scan/2018-10-08")
+    ///     .with_correlation_guid(Uuid::from_u128(0x26F1_38B6_6014_4D3D_B174_6E1A_CE94_39F3));
+    ///
+    /// assert!(details.correlation_guid.is_some());
+    /// ```
+    #[must_use]
+    pub const fn with_correlation_guid(mut self, correlation_guid: Uuid) -> Self {
+        self.correlation_guid = Some(correlation_guid);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use uuid::Uuid;
+
+    use crate::schema::{
+        Message, Run, RunAutomationDetails, SarifLog, SchemaVersion, Tool, ToolComponent,
+        tests::validate_schema,
+    };
+
+    #[test]
+    fn automation_details() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql")))
+                    .with_automation_details(
+                        RunAutomationDetails::new("Nightly code scan/2018-10-08")
+                            .with_description(Message::new("Nightly scan of the main branch"))
+                            .with_guid(Uuid::from_u128(0xBC65_0830_A9FE_44CB_8818_AD6C_3872_79A0)),
+                    )
+                    .with_run_aggregate(
+                        RunAutomationDetails::new("Build/14.0.1.2/Release/20160716-13:22:18")
+                            .with_correlation_guid(Uuid::from_u128(
+                                0x26F1_38B6_6014_4D3D_B174_6E1A_CE94_39F3,
+                            )),
+                    ),
+            ),
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Location, Message, PropertyBag};
+
+/// A call stack that is relevant to a result ([§3.44]).
+///
+/// [§3.44]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317891
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Stack<'s> {
+    /// A message relevant to this call stack.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// The frames of the call stack, starting with the innermost one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub frames: Vec<StackFrame<'s>>,
+
+    /// Key/value pairs that provide additional information about the stack.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Stack<'s> {
+    /// Create a new, empty `Stack`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Stack;
+    ///
+    /// let stack = Stack::new();
+    ///
+    /// assert!(stack.frames.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            message: None,
+            frames: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the message relevant to the call stack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Stack};
+    ///
+    /// let stack = Stack::new().with_message(Message::new("call stack of the closure"));
+    ///
+    /// assert!(stack.message.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Add a frame to the call stack.
+    ///
+    /// Frames are added from the innermost to the outermost one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Stack, StackFrame};
+    ///
+    /// let stack = Stack::new()
+    ///     .with_frame(StackFrame::new().with_module("platform"))
+    ///     .with_frame(StackFrame::new().with_module("application"));
+    ///
+    /// assert_eq!(stack.frames.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_frame(mut self, frame: StackFrame<'s>) -> Self {
+        self.frames.push(frame);
+        self
+    }
+}
+
+/// A function call within a stack trace ([§3.45]).
+///
+/// [§3.45]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317896
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct StackFrame<'s> {
+    /// The location to which this stack frame refers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<Location<'s>>,
+
+    /// The name of the module that contains the code of this stack frame.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub module: Option<Cow<'s, str>>,
+
+    /// The thread identifier of the stack frame.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thread_id: Option<i64>,
+
+    /// The parameters of the call that is executing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub parameters: Vec<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the stack frame.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> StackFrame<'s> {
+    /// Create a new, empty `StackFrame`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::StackFrame;
+    ///
+    /// let frame = StackFrame::new();
+    ///
+    /// assert_eq!(frame.location, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            location: None,
+            module: None,
+            thread_id: None,
+            parameters: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the location to which the stack frame refers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, LogicalLocation, StackFrame};
+    ///
+    /// let frame = StackFrame::new().with_location(
+    ///     Location::new()
+    ///         .with_logical_location(LogicalLocation::new().with_fully_qualified_name("main")),
+    /// );
+    ///
+    /// assert!(frame.location.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the name of the module that contains the code of the stack frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::StackFrame;
+    ///
+    /// let frame = StackFrame::new().with_module("platform");
+    ///
+    /// assert_eq!(frame.module.as_deref(), Some("platform"));
+    /// ```
+    #[must_use]
+    pub fn with_module(mut self, module: impl Into<Cow<'s, str>>) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+
+    /// Sets the thread identifier of the stack frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::StackFrame;
+    ///
+    /// let frame = StackFrame::new().with_thread_id(52);
+    ///
+    /// assert_eq!(frame.thread_id, Some(52));
+    /// ```
+    #[must_use]
+    pub const fn with_thread_id(mut self, thread_id: i64) -> Self {
+        self.thread_id = Some(thread_id);
+        self
+    }
+
+    /// Add a parameter of the call that is executing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::StackFrame;
+    ///
+    /// let frame = StackFrame::new()
+    ///     .with_parameter("null")
+    ///     .with_parameter("14");
+    ///
+    /// assert!(frame.parameters.iter().eq(["null", "14"]));
+    /// ```
+    #[must_use]
+    pub fn with_parameter(mut self, parameter: impl Into<Cow<'s, str>>) -> Self {
+        self.parameters.push(parameter.into());
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactLocation, Location, LogicalLocation, Message, PhysicalLocation, Region, Result,
+        Run, SarifLog, SchemaVersion, Stack, StackFrame, Tool, ToolComponent,
+        tests::validate_schema,
+    };
+
+    #[test]
+    fn frames() {
+        let frame = |line, name| {
+            StackFrame::new()
+                .with_location(
+                    Location::new()
+                        .with_physical_location(
+                            PhysicalLocation::new(ArtifactLocation::new("main.jsonc"))
+                                .with_region(Region::new().with_start(line, 1)),
+                        )
+                        .with_logical_location(
+                            LogicalLocation::new().with_fully_qualified_name(name),
+                        ),
+                )
+                .with_module("main")
+                .with_thread_id(1)
+        };
+
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_result(
+                    Result::new(Message::new("division by zero")).with_stack(
+                        Stack::new()
+                            .with_message(Message::new("call stack of the evaluation"))
+                            .with_frame(frame(3, "::math::div").with_parameter("0"))
+                            .with_frame(frame(10, "::main")),
+                    ),
+                ),
+            ),
+        );
+    }
+}
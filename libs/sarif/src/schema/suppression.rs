@@ -0,0 +1,223 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{Location, PropertyBag};
+
+/// The way in which a [`Suppression`] is represented ([§3.35.3]).
+///
+/// [§3.35.3]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317735
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum SuppressionKind {
+    /// The suppression is expressed in the source code, for example as an attribute.
+    InSource,
+    /// The suppression is persisted outside of the source code, for example in a database.
+    External,
+}
+
+/// The state of a [`Suppression`] ([§3.35.4]).
+///
+/// [§3.35.4]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317736
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum SuppressionStatus {
+    /// The suppression has been approved and applies to the result.
+    Accepted,
+    /// The suppression is pending review and does not yet apply to the result.
+    UnderReview,
+    /// The suppression was rejected and does not apply to the result.
+    Rejected,
+}
+
+/// A request to suppress a result ([§3.35]).
+///
+/// [§3.35]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317733
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Suppression<'s> {
+    /// A stable, unique identifier for the suppression in the form of a GUID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// The way in which the suppression is represented.
+    pub kind: SuppressionKind,
+
+    /// The state of the suppression.
+    ///
+    /// If absent, the suppression is [`SuppressionStatus::Accepted`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub status: Option<SuppressionStatus>,
+
+    /// A string representing the justification for the suppression.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub justification: Option<Cow<'s, str>>,
+
+    /// The location of an in-source suppression.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<Location<'s>>,
+
+    /// Key/value pairs that provide additional information about the suppression.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Suppression<'s> {
+    /// Create a new `Suppression` of the given kind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Suppression, SuppressionKind};
+    ///
+    /// let suppression = Suppression::new(SuppressionKind::InSource);
+    ///
+    /// assert_eq!(suppression.kind, SuppressionKind::InSource);
+    /// assert_eq!(suppression.status, None);
+    /// ```
+    #[must_use]
+    pub const fn new(kind: SuppressionKind) -> Self {
+        Self {
+            guid: None,
+            kind,
+            status: None,
+            justification: None,
+            location: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the state of the suppression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Suppression, SuppressionKind, SuppressionStatus};
+    ///
+    /// let suppression =
+    ///     Suppression::new(SuppressionKind::External).with_status(SuppressionStatus::UnderReview);
+    ///
+    /// assert_eq!(suppression.status, Some(SuppressionStatus::UnderReview));
+    /// ```
+    #[must_use]
+    pub const fn with_status(mut self, status: SuppressionStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the justification for the suppression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Suppression, SuppressionKind};
+    ///
+    /// let suppression = Suppression::new(SuppressionKind::InSource)
+    ///     .with_justification("the variable is used by the generated code");
+    ///
+    /// assert!(suppression.justification.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_justification(mut self, justification: impl Into<Cow<'s, str>>) -> Self {
+        self.justification = Some(justification.into());
+        self
+    }
+
+    /// Sets the location of the in-source suppression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     ArtifactLocation, Location, PhysicalLocation, Suppression, SuppressionKind,
+    /// };
+    ///
+    /// let suppression = Suppression::new(SuppressionKind::InSource).with_location(
+    ///     Location::new()
+    ///         .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("main.jsonc"))),
+    /// );
+    ///
+    /// assert!(suppression.location.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.location = Some(location);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use crate::schema::{
+        ArtifactLocation, Location, Message, PhysicalLocation, Region, Result, Run, SarifLog,
+        SchemaVersion, Suppression, SuppressionKind, SuppressionStatus, Tool, ToolComponent,
+        tests::validate_schema,
+    };
+
+    #[test]
+    fn suppressions() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_result(
+                    Result::new(Message::new("unused variable"))
+                        .with_suppression(
+                            Suppression::new(SuppressionKind::InSource)
+                                .with_justification("used by the generated code")
+                                .with_location(
+                                    Location::new().with_physical_location(
+                                        PhysicalLocation::new(ArtifactLocation::new("main.jsonc"))
+                                            .with_region(Region::new().with_start(1, 1)),
+                                    ),
+                                ),
+                        )
+                        .with_suppression(
+                            Suppression::new(SuppressionKind::External)
+                                .with_status(SuppressionStatus::Rejected),
+                        ),
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn not_suppressed() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_result(
+                    Result::new(Message::new("unused variable")).with_suppressions([]),
+                ),
+            ),
+        );
+    }
+}
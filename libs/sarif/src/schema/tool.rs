@@ -1,9 +1,11 @@
-use alloc::{borrow::Cow, vec::Vec};
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
 
-use crate::schema::{PropertyBag, ReportingDescriptor};
+use crate::schema::{MultiformatMessageString, PropertyBag, ReportingDescriptor};
 
 /// The analysis tool that was run.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -161,6 +163,21 @@ pub struct ToolComponent<'s> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub name: Cow<'s, str>,
 
+    /// A unique identifier for the tool component in the form of a GUID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// The name of the tool component along with its version and any other useful identifying
+    /// information, such as its locale.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub full_name: Option<Cow<'s, str>>,
+
     /// The tool component version, in whatever format the component natively provides.
     #[cfg_attr(
         feature = "serde",
@@ -175,6 +192,92 @@ pub struct ToolComponent<'s> {
     )]
     pub semantic_version: Option<semver::Version>,
 
+    /// The binary version of the tool component's primary executable file expressed as four
+    /// non-negative integers separated by a period, for example `2.1.0.0`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub dotted_quad_file_version: Option<Cow<'s, str>>,
+
+    /// A string specifying the UTC date (and optionally, the time) of the component's release,
+    /// in the format specified by [ISO 8601].
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub release_date_utc: Option<Cow<'s, str>>,
+
+    /// The organization or company that produced the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub organization: Option<Cow<'s, str>>,
+
+    /// A product suite to which the tool component belongs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub product: Option<Cow<'s, str>>,
+
+    /// A localizable string containing the name of the suite of products to which the tool
+    /// component belongs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub product_suite: Option<Cow<'s, str>>,
+
+    /// A brief description of the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub short_description: Option<MultiformatMessageString<'s>>,
+
+    /// A comprehensive description of the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub full_description: Option<MultiformatMessageString<'s>>,
+
+    /// The absolute URI from which the tool component can be downloaded.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub download_uri: Option<Url>,
+
+    /// The absolute URI at which information about the tool component can be found.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub information_uri: Option<Url>,
+
+    /// The language of the messages emitted by the tool component, in the format specified by
+    /// [RFC 5646], for example `en-US`.
+    ///
+    /// [RFC 5646]: https://tools.ietf.org/html/rfc5646
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub language: Option<Cow<'s, str>>,
+
+    /// Message strings used by the tool component which are not specific to a rule or
+    /// notification, keyed by their message id.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub global_message_strings: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
     /// An array of [`ReportingDescriptor`]s relevant to the analysis performed by the tool
     /// component.
     #[cfg_attr(
@@ -182,6 +285,21 @@ pub struct ToolComponent<'s> {
         serde(borrow, default, skip_serializing_if = "Vec::is_empty")
     )]
     pub rules: Vec<ReportingDescriptor<'s>>,
+
+    /// An array of [`ReportingDescriptor`]s for the notifications the tool component can
+    /// produce.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub notifications: Vec<ReportingDescriptor<'s>>,
+
+    /// Key/value pairs that provide additional information about the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
 }
 
 impl<'s> ToolComponent<'s> {
@@ -199,9 +317,24 @@ impl<'s> ToolComponent<'s> {
     pub fn new(name: impl Into<Cow<'s, str>>) -> Self {
         Self {
             name: name.into(),
+            guid: None,
+            full_name: None,
             version: None,
             semantic_version: None,
+            dotted_quad_file_version: None,
+            release_date_utc: None,
+            organization: None,
+            product: None,
+            product_suite: None,
+            short_description: None,
+            full_description: None,
+            download_uri: None,
+            information_uri: None,
+            language: None,
+            global_message_strings: BTreeMap::new(),
             rules: Vec::new(),
+            notifications: Vec::new(),
+            properties: PropertyBag::new(),
         }
     }
 
@@ -243,6 +376,87 @@ impl<'s> ToolComponent<'s> {
         self
     }
 
+    /// Set the full name of the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponent;
+    ///
+    /// let tool_component = ToolComponent::new("rustc").with_full_name("rustc 1.70.0 (en-US)");
+    ///
+    /// assert_eq!(
+    ///     tool_component.full_name.as_deref(),
+    ///     Some("rustc 1.70.0 (en-US)")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_full_name(mut self, full_name: impl Into<Cow<'s, str>>) -> Self {
+        self.full_name = Some(full_name.into());
+        self
+    }
+
+    /// Set the organization that produced the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponent;
+    ///
+    /// let tool_component = ToolComponent::new("hashql").with_organization("HASH");
+    ///
+    /// assert_eq!(tool_component.organization.as_deref(), Some("HASH"));
+    /// ```
+    #[must_use]
+    pub fn with_organization(mut self, organization: impl Into<Cow<'s, str>>) -> Self {
+        self.organization = Some(organization.into());
+        self
+    }
+
+    /// Set the brief description of the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{MultiformatMessageString, ToolComponent};
+    ///
+    /// let tool_component = ToolComponent::new("hashql")
+    ///     .with_short_description(MultiformatMessageString::new("The HashQL compiler"));
+    ///
+    /// assert!(tool_component.short_description.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_short_description(
+        mut self,
+        short_description: MultiformatMessageString<'s>,
+    ) -> Self {
+        self.short_description = Some(short_description);
+        self
+    }
+
+    /// Set the URI at which information about the tool component can be found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponent;
+    /// use url::Url;
+    ///
+    /// let tool_component =
+    ///     ToolComponent::new("hashql").with_information_uri(Url::parse("https://hash.ai")?);
+    ///
+    /// assert_eq!(
+    ///     tool_component.information_uri.as_ref().map(Url::as_str),
+    ///     Some("https://hash.ai/")
+    /// );
+    /// # Ok::<(), url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn with_information_uri(mut self, information_uri: Url) -> Self {
+        self.information_uri = Some(information_uri);
+        self
+    }
+
     /// Add a rule to the tool component.
     ///
     /// # Example
@@ -287,4 +501,43 @@ impl<'s> ToolComponent<'s> {
         self.rules.extend(rules);
         self
     }
+
+    /// Add a notification descriptor to the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ReportingDescriptor, ToolComponent};
+    ///
+    /// let tool_component =
+    ///     ToolComponent::new("hashql").with_notification(ReportingDescriptor::new("internal-error"));
+    ///
+    /// assert_eq!(tool_component.notifications[0].id, "internal-error");
+    /// ```
+    #[must_use]
+    pub fn with_notification(mut self, notification: ReportingDescriptor<'s>) -> Self {
+        self.notifications.push(notification);
+        self
+    }
+
+    /// Add a property to the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponent;
+    ///
+    /// let tool_component =
+    ///     ToolComponent::new("hashql").with_properties(|properties| properties.with_tag("compiler"));
+    ///
+    /// assert!(tool_component.properties.tags.iter().eq(["compiler"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
 }
@@ -0,0 +1,190 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::schema::{ArtifactLocation, PropertyBag};
+
+/// Information about the version control system from which the analyzed artifacts were
+/// retrieved ([§3.23]).
+///
+/// [§3.23]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317600
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct VersionControlDetails<'s> {
+    /// The absolute URI of the repository.
+    pub repository_uri: Url,
+
+    /// A string that uniquely and permanently identifies the revision within the repository.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub revision_id: Option<Cow<'s, str>>,
+
+    /// The name of a branch containing the revision.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub branch: Option<Cow<'s, str>>,
+
+    /// A tag that has been applied to the revision.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub revision_tag: Option<Cow<'s, str>>,
+
+    /// A date and time at which the revision was the latest one of the branch, in the format
+    /// specified by [ISO 8601] and in UTC.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub as_of_time_utc: Option<Cow<'s, str>>,
+
+    /// The location in the local file system to which the root of the repository was mapped at
+    /// the time of the analysis.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub mapped_to: Option<ArtifactLocation<'s>>,
+
+    /// Key/value pairs that provide additional information about the version control details.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> VersionControlDetails<'s> {
+    /// Create a new `VersionControlDetails` for the repository at the given URI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::VersionControlDetails;
+    /// use url::Url;
+    ///
+    /// let details = VersionControlDetails::new(Url::parse("https://github.com/hashintel/hash")?);
+    ///
+    /// assert_eq!(
+    ///     details.repository_uri.as_str(),
+    ///     "https://github.com/hashintel/hash"
+    /// );
+    /// # Ok::<(), url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub const fn new(repository_uri: Url) -> Self {
+        Self {
+            repository_uri,
+            revision_id: None,
+            branch: None,
+            revision_tag: None,
+            as_of_time_utc: None,
+            mapped_to: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the revision within the repository.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::VersionControlDetails;
+    /// use url::Url;
+    ///
+    /// let details = VersionControlDetails::new(Url::parse("https://github.com/hashintel/hash")?)
+    ///     .with_revision_id("5da53fbb2a0aaa12d648b73984acc9aac2e11c2a");
+    ///
+    /// assert_eq!(
+    ///     details.revision_id.as_deref(),
+    ///     Some("5da53fbb2a0aaa12d648b73984acc9aac2e11c2a")
+    /// );
+    /// # Ok::<(), url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn with_revision_id(mut self, revision_id: impl Into<Cow<'s, str>>) -> Self {
+        self.revision_id = Some(revision_id.into());
+        self
+    }
+
+    /// Sets the branch containing the revision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::VersionControlDetails;
+    /// use url::Url;
+    ///
+    /// let details = VersionControlDetails::new(Url::parse("https://github.com/hashintel/hash")?)
+    ///     .with_branch("main");
+    ///
+    /// assert_eq!(details.branch.as_deref(), Some("main"));
+    /// # Ok::<(), url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn with_branch(mut self, branch: impl Into<Cow<'s, str>>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    /// Sets the location to which the root of the repository was mapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, VersionControlDetails};
+    /// use url::Url;
+    ///
+    /// let details = VersionControlDetails::new(Url::parse("https://github.com/hashintel/hash")?)
+    ///     .with_mapped_to(ArtifactLocation::default().with_uri_base_id("SRCROOT"));
+    ///
+    /// assert!(details.mapped_to.is_some());
+    /// # Ok::<(), url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn with_mapped_to(mut self, mapped_to: ArtifactLocation<'s>) -> Self {
+        self.mapped_to = Some(mapped_to);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[coverage(off)]
+pub(crate) mod tests {
+    use url::Url;
+
+    use crate::schema::{
+        ArtifactLocation, Run, SarifLog, SchemaVersion, Tool, ToolComponent, VersionControlDetails,
+        tests::validate_schema,
+    };
+
+    #[test]
+    fn provenance() {
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("hashql"))).with_version_control_provenance(
+                    VersionControlDetails::new(
+                        Url::parse("https://github.com/hashintel/hash").expect("valid URL"),
+                    )
+                    .with_revision_id("5da53fbb2a0aaa12d648b73984acc9aac2e11c2a")
+                    .with_branch("main")
+                    .with_mapped_to(ArtifactLocation::default().with_uri_base_id("SRCROOT")),
+                ),
+            ),
+        );
+    }
+}
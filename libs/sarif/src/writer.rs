@@ -0,0 +1,360 @@
+//! Incremental output of SARIF logs.
+//!
+//! Analyses of large code bases can produce more results than should be kept in memory at once.
+//! [`SarifWriter`] writes a log consisting of a single run to an [`io::Write`], emitting each
+//! result as soon as it is reported. The remaining properties of the run, including the rules of
+//! the tool, are written when the writer is finished, so rules can still be registered while
+//! results are being written.
+
+use std::io::{self, Write};
+
+use crate::{
+    builder::{RuleIndex, RunBuilder},
+    schema::{Message, ReportingDescriptor, Result, Run, SchemaVersion},
+};
+
+/// Writes a [`SarifLog`] consisting of a single run, one result at a time.
+///
+/// The output is a valid SARIF document only once the writer has been [finished]. Results are
+/// written verbatim, so they should be created with [`SarifWriter::result`] to refer to their
+/// rules by index.
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     builder::RunBuilder,
+///     schema::{Message, ReportingDescriptor, SarifLog, ToolComponent},
+///     writer::SarifWriter,
+/// };
+///
+/// let mut writer = SarifWriter::new(Vec::new(), RunBuilder::new(ToolComponent::new("hashql")))?;
+///
+/// for variable in ["x", "y"] {
+///     let rule = writer.register_rule(ReportingDescriptor::new("unused-variable"));
+///     let result = writer.result(rule, Message::new(format!("`{variable}` is never used")));
+///
+///     writer.write(&result)?;
+/// }
+///
+/// let output = writer.finish()?;
+/// let log: SarifLog = serde_json::from_slice(&output)?;
+/// let run = &log.runs.as_ref().expect("log should contain a run")[0];
+///
+/// assert_eq!(run.tool.driver.rules.len(), 1);
+/// assert_eq!(run.results.as_ref().map(Vec::len), Some(2));
+/// # Ok::<(), Box<dyn core::error::Error>>(())
+/// ```
+///
+/// [`SarifLog`]: crate::schema::SarifLog
+/// [finished]: Self::finish
+#[derive(Debug)]
+pub struct SarifWriter<'s, W> {
+    writer: W,
+    run: RunBuilder<'s>,
+    has_results: bool,
+}
+
+impl<'s, W: Write> SarifWriter<'s, W> {
+    /// Create a new `SarifWriter` and write the start of the log.
+    ///
+    /// Results which have already been added to the run are written immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Message, Result, SarifLog, ToolComponent},
+    ///     writer::SarifWriter,
+    /// };
+    ///
+    /// let run = RunBuilder::new(ToolComponent::new("hashql"))
+    ///     .with_result(Result::new(Message::new("`x` is never used")));
+    ///
+    /// let output = SarifWriter::new(Vec::new(), run)?.finish()?;
+    /// let log: SarifLog = serde_json::from_slice(&output)?;
+    ///
+    /// assert_eq!(
+    ///     log.runs.expect("log should contain a run")[0]
+    ///         .results
+    ///         .as_ref()
+    ///         .map(Vec::len),
+    ///     Some(1)
+    /// );
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn new(mut writer: W, mut run: RunBuilder<'s>) -> io::Result<Self> {
+        let version = SchemaVersion::V2_1_0;
+
+        writer.write_all(b"{\"$schema\":")?;
+        serde_json::to_writer(&mut writer, version.schema_id())?;
+        writer.write_all(b",\"version\":")?;
+        serde_json::to_writer(&mut writer, &version)?;
+        writer.write_all(b",\"runs\":[{\"results\":[")?;
+
+        let results = run.take_results();
+        let mut this = Self {
+            writer,
+            run,
+            has_results: false,
+        };
+        for result in &results {
+            this.write(result)?;
+        }
+
+        Ok(this)
+    }
+
+    /// Register a rule with the driver of the tool and return its index.
+    ///
+    /// See [`RunBuilder::register_rule`] for details.
+    pub fn register_rule(&mut self, rule: ReportingDescriptor<'s>) -> RuleIndex {
+        self.run.register_rule(rule)
+    }
+
+    /// Create a new [`Result`] for the rule at the given index.
+    ///
+    /// See [`RunBuilder::result`] for details.
+    #[must_use]
+    pub fn result(&self, rule: RuleIndex, message: Message<'s>) -> Result<'s> {
+        self.run.result(rule, message)
+    }
+
+    /// Write a result to the log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result cannot be serialized or writing to the underlying writer
+    /// fails.
+    pub fn write(&mut self, result: &Result<'_>) -> io::Result<()> {
+        if self.has_results {
+            self.writer.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut self.writer, result)?;
+        self.has_results = true;
+
+        Ok(())
+    }
+
+    /// Write the remaining properties of the run and close the log.
+    ///
+    /// The underlying writer is flushed and returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the run cannot be serialized or writing to the underlying writer fails.
+    pub fn finish(self) -> io::Result<W> {
+        self.finish_with(|run| run)
+    }
+
+    /// Modify the run, write its remaining properties and close the log.
+    ///
+    /// This allows properties which are only known at the end of the analysis, like the exit code
+    /// of an [`Invocation`], to be added to the run. Results which are added to the run are
+    /// written as well.
+    ///
+    /// Results which have been written already refer to the rules of the tool's driver by index,
+    /// `configure` may therefore only append rules, but must not change or remove any rule.
+    ///
+    /// The underlying writer is flushed and returned.
+    ///
+    /// [`Invocation`]: crate::schema::Invocation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `configure` changed or removed any of the rules of the tool's driver, or
+    /// - the run cannot be serialized or writing to the underlying writer fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Invocation, SarifLog, ToolComponent},
+    ///     writer::SarifWriter,
+    /// };
+    ///
+    /// let writer = SarifWriter::new(Vec::new(), RunBuilder::new(ToolComponent::new("hashql")))?;
+    /// let output =
+    ///     writer.finish_with(|run| run.with_invocation(Invocation::new(false).with_exit_code(1)))?;
+    ///
+    /// let log: SarifLog = serde_json::from_slice(&output)?;
+    /// let run = &log.runs.as_ref().expect("log should contain a run")[0];
+    ///
+    /// assert_eq!(run.invocations[0].exit_code, Some(1));
+    /// assert_eq!(run.results, Some(Vec::new()));
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn finish_with(mut self, configure: impl FnOnce(Run<'s>) -> Run<'s>) -> io::Result<W> {
+        let rules = self.run.run().tool.driver.rules.clone();
+        self.run = self.run.configure(configure);
+
+        if !self.run.run().tool.driver.rules.starts_with(&rules) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "rules referred to by results must not be changed or removed",
+            ));
+        }
+
+        for result in self.run.take_results() {
+            self.write(&result)?;
+        }
+
+        // The run is serialized without its results, which have been written already, and is
+        // always an object with at least the `tool` property. Its properties are appended to the
+        // `results` property by replacing the opening brace.
+        let run = serde_json::to_vec(self.run.run())?;
+        let properties = run.get(1..).unwrap_or_default();
+
+        self.writer.write_all(b"],")?;
+        self.writer.write_all(properties)?;
+        self.writer.write_all(b"]}")?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+pub(crate) mod tests {
+    use alloc::vec::Vec;
+    use std::io;
+
+    use crate::{
+        builder::RunBuilder,
+        schema::{
+            Invocation, Message, ReportingDescriptor, ResultLevel, SarifLog, ToolComponent,
+            tests::validate_schema,
+        },
+        writer::SarifWriter,
+    };
+
+    fn build(results: usize) -> RunBuilder<'static> {
+        let mut run = RunBuilder::new(ToolComponent::new("hashql"));
+        for id in ["unused-variable", "unknown-variable"]
+            .into_iter()
+            .cycle()
+            .take(results)
+        {
+            let rule = run.register_rule(ReportingDescriptor::new(id));
+            run.push(
+                run.result(rule, Message::new("variable"))
+                    .with_level(ResultLevel::Warning),
+            );
+        }
+
+        run
+    }
+
+    fn stream(results: usize) -> Vec<u8> {
+        let mut writer =
+            SarifWriter::new(Vec::new(), RunBuilder::new(ToolComponent::new("hashql")))
+                .expect("writing to a vector should not fail");
+
+        for result in build(results).build().results.into_iter().flatten() {
+            let rule = writer.register_rule(ReportingDescriptor::new(
+                result.rule_id.clone().expect("result should have a rule"),
+            ));
+            assert_eq!(Some(rule.index()), result.rule_index);
+
+            writer
+                .write(&result)
+                .expect("writing to a vector should not fail");
+        }
+
+        writer
+            .finish()
+            .expect("writing to a vector should not fail")
+    }
+
+    #[test]
+    fn empty() {
+        let output = stream(0);
+        let log: SarifLog = serde_json::from_slice(&output).expect("output should be valid JSON");
+
+        validate_schema(&log);
+        assert_eq!(log, build(0).finish());
+    }
+
+    #[test]
+    fn results() {
+        let output = stream(5);
+        let log: SarifLog = serde_json::from_slice(&output).expect("output should be valid JSON");
+
+        validate_schema(&log);
+        assert_eq!(log, build(5).finish());
+    }
+
+    #[test]
+    fn buffered_results() {
+        let writer =
+            SarifWriter::new(Vec::new(), build(2)).expect("writing to a vector should not fail");
+        let output = writer
+            .finish_with(|run| {
+                run.with_invocation(Invocation::new(true).with_exit_code(0))
+                    .with_result(crate::schema::Result::new(Message::new("late result")))
+            })
+            .expect("writing to a vector should not fail");
+        let log: SarifLog = serde_json::from_slice(&output).expect("output should be valid JSON");
+
+        validate_schema(&log);
+
+        let run = &log.runs.as_ref().expect("log should contain a run")[0];
+        assert_eq!(run.invocations.len(), 1);
+        assert_eq!(run.results.as_ref().map(Vec::len), Some(3));
+    }
+
+    #[test]
+    fn appended_rules() {
+        let writer =
+            SarifWriter::new(Vec::new(), build(2)).expect("writing to a vector should not fail");
+        let output = writer
+            .finish_with(|mut run| {
+                run.tool
+                    .driver
+                    .rules
+                    .push(ReportingDescriptor::new("unused-import"));
+                run
+            })
+            .expect("writing to a vector should not fail");
+        let log: SarifLog = serde_json::from_slice(&output).expect("output should be valid JSON");
+
+        validate_schema(&log);
+
+        let run = &log.runs.as_ref().expect("log should contain a run")[0];
+        assert_eq!(run.tool.driver.rules.len(), 3);
+    }
+
+    #[test]
+    fn modified_rules() {
+        let writer =
+            SarifWriter::new(Vec::new(), build(2)).expect("writing to a vector should not fail");
+        let error = writer
+            .finish_with(|mut run| {
+                run.tool.driver.rules.reverse();
+                run
+            })
+            .expect_err("reordering rules should be rejected");
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        let writer =
+            SarifWriter::new(Vec::new(), build(2)).expect("writing to a vector should not fail");
+        let error = writer
+            .finish_with(|mut run| {
+                run.tool.driver.rules.pop();
+                run
+            })
+            .expect_err("removing rules should be rejected");
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
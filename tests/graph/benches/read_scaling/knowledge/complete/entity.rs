@@ -253,10 +253,7 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                                 None,
                             ),
                         },
-                        sorting: EntityQuerySorting {
-                            paths: Vec::new(),
-                            cursor: None,
-                        },
+                        sorting: EntityQuerySorting::default(),
                         limit: None,
                         conversions: Vec::new(),
                         include_count: false,
@@ -56,10 +56,7 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                             pinned: PinnedTemporalAxisUnresolved::new(None),
                             variable: VariableTemporalAxisUnresolved::new(None, None),
                         },
-                        sorting: EntityQuerySorting {
-                            paths: Vec::new(),
-                            cursor: None,
-                        },
+                        sorting: EntityQuerySorting::default(),
                         limit: None,
                         conversions: Vec::new(),
                         include_count: false,
@@ -114,10 +111,7 @@ pub fn bench_get_entities_by_property<A: AuthorizationApi>(
                             None,
                         ),
                     },
-                    sorting: EntityQuerySorting {
-                        paths: Vec::new(),
-                        cursor: None,
-                    },
+                    sorting: EntityQuerySorting::default(),
                     limit: None,
                     conversions: Vec::new(),
                     include_count: false,
@@ -174,10 +168,7 @@ pub fn bench_get_link_by_target_by_property<A: AuthorizationApi>(
                             None,
                         ),
                     },
-                    sorting: EntityQuerySorting {
-                        paths: Vec::new(),
-                        cursor: None,
-                    },
+                    sorting: EntityQuerySorting::default(),
                     limit: None,
                     conversions: Vec::new(),
                     include_count: false,
@@ -0,0 +1,535 @@
+use alloc::borrow::Cow;
+use std::collections::HashSet;
+
+use hash_graph_authorization::{AuthorizationApi, policies::store::LocalPrincipalStore as _};
+use hash_graph_store::{
+    account::{AccountStore as _, CreateOrgWebParams},
+    entity::{
+        AggregateEntitiesParams, AggregateEntitiesResponse, CreateEntityParams, EntityAggregation,
+        EntityAggregationFunction, EntityQueryPath, EntityStore as _, PatchEntityParams,
+    },
+    filter::{Filter, JsonPath, PathToken},
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use hash_graph_temporal_versioning::{ClosedTemporalBound, LimitedTemporalBound, TemporalBound};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    knowledge::{
+        entity::{Entity, provenance::ProvidedEntityEditionProvenance},
+        property::{
+            Property, PropertyObject, PropertyObjectWithMetadata, PropertyPatchOperation,
+            PropertyPath, PropertyWithMetadata,
+        },
+    },
+    ontology::{
+        VersionedUrl,
+        id::{BaseUrl, OntologyTypeVersion},
+    },
+    principal::{actor::ActorType, actor_group::WebId},
+    provenance::{OriginProvenance, OriginType},
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper, HiddenEntities};
+
+const PERSON_TYPE: &str = "https://blockprotocol.org/@alice/types/entity-type/person/";
+const PAGE_TYPE: &str = "https://blockprotocol.org/@alice/types/entity-type/page/";
+
+/// An aggregation group with the values converted to `f64` to compare them independently of the
+/// precision of the returned numbers.
+#[derive(Debug, PartialEq)]
+struct Group {
+    key: Vec<serde_json::Value>,
+    count: usize,
+    values: Vec<Option<f64>>,
+}
+
+/// Converts the groups of the response and sorts them by their key as the order of the groups is
+/// not specified.
+fn groups(response: AggregateEntitiesResponse) -> Vec<Group> {
+    let mut groups = response
+        .groups
+        .into_iter()
+        .map(|group| Group {
+            key: group.key,
+            count: group.count,
+            values: group
+                .values
+                .into_iter()
+                .map(|value| value.map(|value| value.to_f64()))
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    groups.sort_by_key(|group| serde_json::Value::Array(group.key.clone()).to_string());
+    groups
+}
+
+fn age_property_path() -> EntityQueryPath<'static> {
+    EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+        Cow::Borrowed("https://blockprotocol.org/@alice/types/property-type/age/"),
+    )])))
+}
+
+fn all_aggregations() -> Vec<EntityAggregation<'static>> {
+    [
+        EntityAggregationFunction::Count,
+        EntityAggregationFunction::Sum,
+        EntityAggregationFunction::Min,
+        EntityAggregationFunction::Max,
+        EntityAggregationFunction::Avg,
+    ]
+    .into_iter()
+    .map(|function| EntityAggregation {
+        function,
+        path: age_property_path(),
+    })
+    .collect()
+}
+
+fn type_key(base_url: &str) -> Vec<serde_json::Value> {
+    vec![json!([base_url])]
+}
+
+fn person(name: &str, age: Option<u32>) -> PropertyObject {
+    let mut properties = json!({
+        "https://blockprotocol.org/@alice/types/property-type/name/": name,
+    });
+    if let Some(age) = age {
+        properties["https://blockprotocol.org/@alice/types/property-type/age/"] = json!(age);
+    }
+    serde_json::from_value(properties).expect("could not parse entity")
+}
+
+async fn create_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    web_id: WebId,
+    entity_type: &str,
+    properties: PropertyObject,
+) -> Entity {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            web_id,
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([VersionedUrl {
+                base_url: BaseUrl::new(entity_type.to_owned())
+                    .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }]),
+            properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+struct Entities {
+    user_web: WebId,
+    org_web: WebId,
+    /// The person in the organization's web, which is the oldest person.
+    org_person: Entity,
+}
+
+/// Creates three persons and a page in the web of the user and a person and a page in the web of
+/// an organization.
+async fn insert<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> (DatabaseApi<'_, &mut A>, Entities) {
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::TEXT_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::PERSON_V1,
+                entity_type::PAGE_V1,
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let system_account_id = api
+        .store
+        .get_or_create_system_machine("h")
+        .await
+        .expect("could not get system machine");
+    let org_web = api
+        .store
+        .create_org_web(
+            system_account_id.into(),
+            CreateOrgWebParams {
+                shortname: "aggregation-org".to_owned(),
+                administrator: Some(api.account_id),
+            },
+        )
+        .await
+        .expect("could not create web")
+        .web_id;
+    let user_web = WebId::new(api.account_id);
+
+    let page: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+
+    create_entity(&mut api, user_web, PERSON_TYPE, person("Alice", None)).await;
+    create_entity(&mut api, user_web, PERSON_TYPE, person("Bob", Some(20))).await;
+    create_entity(&mut api, user_web, PERSON_TYPE, person("Charles", Some(30))).await;
+    create_entity(&mut api, user_web, PAGE_TYPE, page.clone()).await;
+    let org_person = create_entity(&mut api, org_web, PERSON_TYPE, person("Dave", Some(40))).await;
+    create_entity(&mut api, org_web, PAGE_TYPE, page).await;
+
+    (
+        api,
+        Entities {
+            user_web,
+            org_web,
+            org_person,
+        },
+    )
+}
+
+async fn aggregate<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    temporal_axes: QueryTemporalAxesUnresolved,
+    group_by: Vec<EntityQueryPath<'static>>,
+    aggregations: Vec<EntityAggregation<'static>>,
+) -> Vec<Group> {
+    groups(
+        api.aggregate_entities(
+            api.account_id,
+            AggregateEntitiesParams {
+                filter: Filter::All(Vec::new()),
+                temporal_axes,
+                include_drafts: false,
+                group_by,
+                aggregations,
+            },
+        )
+        .await
+        .expect("could not aggregate entities"),
+    )
+}
+
+fn current_time() -> QueryTemporalAxesUnresolved {
+    QueryTemporalAxesUnresolved::DecisionTime {
+        pinned: PinnedTemporalAxisUnresolved::new(None),
+        variable: VariableTemporalAxisUnresolved::new(None, None),
+    }
+}
+
+#[tokio::test]
+async fn without_grouping() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = insert(&mut database).await;
+
+    assert_eq!(
+        aggregate(&api, current_time(), Vec::new(), all_aggregations()).await,
+        [Group {
+            key: Vec::new(),
+            count: 6,
+            values: vec![Some(3.0), Some(90.0), Some(20.0), Some(40.0), Some(30.0)],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn group_by_type() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = insert(&mut database).await;
+
+    assert_eq!(
+        aggregate(
+            &api,
+            current_time(),
+            vec![EntityQueryPath::TypeBaseUrls],
+            all_aggregations(),
+        )
+        .await,
+        [
+            Group {
+                key: type_key(PAGE_TYPE),
+                count: 2,
+                values: vec![Some(0.0), None, None, None, None],
+            },
+            Group {
+                key: type_key(PERSON_TYPE),
+                count: 4,
+                values: vec![Some(3.0), Some(90.0), Some(20.0), Some(40.0), Some(30.0)],
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn group_by_web() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, entities) = insert(&mut database).await;
+
+    let mut expected = vec![
+        Group {
+            key: vec![json!(entities.user_web)],
+            count: 4,
+            values: vec![Some(2.0), Some(50.0), Some(20.0), Some(30.0), Some(25.0)],
+        },
+        Group {
+            key: vec![json!(entities.org_web)],
+            count: 2,
+            values: vec![Some(1.0), Some(40.0), Some(40.0), Some(40.0), Some(40.0)],
+        },
+    ];
+    expected.sort_by_key(|group| serde_json::Value::Array(group.key.clone()).to_string());
+
+    assert_eq!(
+        aggregate(
+            &api,
+            current_time(),
+            vec![EntityQueryPath::WebId],
+            all_aggregations(),
+        )
+        .await,
+        expected
+    );
+}
+
+#[tokio::test]
+async fn group_by_property() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = insert(&mut database).await;
+
+    // Entities without the property form a group with a `null` key
+    assert_eq!(
+        aggregate(
+            &api,
+            current_time(),
+            vec![age_property_path()],
+            vec![EntityAggregation {
+                function: EntityAggregationFunction::Sum,
+                path: age_property_path(),
+            }],
+        )
+        .await,
+        [
+            Group {
+                key: vec![json!(20)],
+                count: 1,
+                values: vec![Some(20.0)],
+            },
+            Group {
+                key: vec![json!(30)],
+                count: 1,
+                values: vec![Some(30.0)],
+            },
+            Group {
+                key: vec![json!(40)],
+                count: 1,
+                values: vec![Some(40.0)],
+            },
+            Group {
+                key: vec![serde_json::Value::Null],
+                count: 3,
+                values: vec![None],
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn group_by_multiple_paths() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, entities) = insert(&mut database).await;
+
+    let mut expected = vec![
+        Group {
+            key: vec![json!(entities.user_web), json!([PAGE_TYPE])],
+            count: 1,
+            values: vec![None],
+        },
+        Group {
+            key: vec![json!(entities.user_web), json!([PERSON_TYPE])],
+            count: 3,
+            values: vec![Some(30.0)],
+        },
+        Group {
+            key: vec![json!(entities.org_web), json!([PAGE_TYPE])],
+            count: 1,
+            values: vec![None],
+        },
+        Group {
+            key: vec![json!(entities.org_web), json!([PERSON_TYPE])],
+            count: 1,
+            values: vec![Some(40.0)],
+        },
+    ];
+    expected.sort_by_key(|group| serde_json::Value::Array(group.key.clone()).to_string());
+
+    assert_eq!(
+        aggregate(
+            &api,
+            current_time(),
+            vec![EntityQueryPath::WebId, EntityQueryPath::TypeBaseUrls],
+            vec![EntityAggregation {
+                function: EntityAggregationFunction::Max,
+                path: age_property_path(),
+            }],
+        )
+        .await,
+        expected
+    );
+}
+
+#[tokio::test]
+async fn temporal_axes() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, entities) = insert(&mut database).await;
+
+    let updated_person = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: entities.org_person.metadata.record_id.entity_id,
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    property: PropertyWithMetadata::from_parts(
+                        Property::Object(person("Dave", Some(50))),
+                        None,
+                    )
+                    .expect("could not create property with metadata"),
+                }],
+                entity_type_ids: HashSet::new(),
+                archived: None,
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let at = |entity: &Entity| {
+        let ClosedTemporalBound::Inclusive(timestamp) =
+            *entity.metadata.temporal_versioning.decision_time.start();
+        QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(
+                Some(TemporalBound::Inclusive(timestamp)),
+                Some(LimitedTemporalBound::Inclusive(timestamp)),
+            ),
+        }
+    };
+    let max_age = || {
+        vec![EntityAggregation {
+            function: EntityAggregationFunction::Max,
+            path: age_property_path(),
+        }]
+    };
+
+    assert_eq!(
+        aggregate(
+            &api,
+            at(&entities.org_person),
+            vec![EntityQueryPath::WebId],
+            max_age(),
+        )
+        .await
+        .into_iter()
+        .find(|group| group.key == [json!(entities.org_web)])
+        .map(|group| group.values),
+        Some(vec![Some(40.0)])
+    );
+
+    assert_eq!(
+        aggregate(
+            &api,
+            at(&updated_person),
+            vec![EntityQueryPath::WebId],
+            max_age(),
+        )
+        .await
+        .into_iter()
+        .find(|group| group.key == [json!(entities.org_web)])
+        .map(|group| group.values),
+        Some(vec![Some(50.0)])
+    );
+
+    // Over the whole decision time both editions of the updated person are aggregated
+    assert_eq!(
+        aggregate(
+            &api,
+            QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(Some(TemporalBound::Unbounded), None),
+            },
+            vec![age_property_path()],
+            Vec::new(),
+        )
+        .await
+        .into_iter()
+        .filter(|group| group.key == [json!(40)] || group.key == [json!(50)])
+        .map(|group| group.count)
+        .collect::<Vec<_>>(),
+        [1, 1]
+    );
+}
+
+#[tokio::test]
+async fn filter_by_permissions() {
+    let authorization = HiddenEntities::default();
+    let mut database = DatabaseTestWrapper::with_authorization(authorization.clone()).await;
+    let (api, entities) = insert(&mut database).await;
+
+    authorization.hide(entities.org_person.metadata.record_id.entity_id.entity_uuid);
+
+    assert_eq!(
+        aggregate(
+            &api,
+            current_time(),
+            vec![EntityQueryPath::TypeBaseUrls],
+            all_aggregations(),
+        )
+        .await,
+        [
+            Group {
+                key: type_key(PAGE_TYPE),
+                count: 2,
+                values: vec![Some(0.0), None, None, None, None],
+            },
+            Group {
+                key: type_key(PERSON_TYPE),
+                count: 3,
+                values: vec![Some(2.0), Some(50.0), Some(20.0), Some(30.0), Some(25.0)],
+            },
+        ]
+    );
+}
@@ -0,0 +1,357 @@
+use alloc::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+};
+
+use error_stack::Report;
+use hash_graph_authorization::{
+    AuthorizationApi, NoAuthorization,
+    backend::{
+        CheckError, CheckResponse, ModifyRelationError, ModifyRelationshipOperation, ReadError,
+    },
+    schema::{
+        AccountGroupPermission, AccountGroupRelationAndSubject, ActorIdOrPublic,
+        DataTypePermission, DataTypeRelationAndSubject, EntityPermission, EntityRelationAndSubject,
+        EntityTypePermission, EntityTypeRelationAndSubject, PropertyTypePermission,
+        PropertyTypeRelationAndSubject, WebPermission, WebRelationAndSubject,
+    },
+    zanzibar::{Consistency, Zookie},
+};
+use type_system::{
+    knowledge::entity::id::{EntityId, EntityUuid},
+    ontology::{
+        data_type::DataTypeUuid, entity_type::EntityTypeUuid, property_type::PropertyTypeUuid,
+    },
+    principal::{
+        actor::ActorEntityUuid,
+        actor_group::{ActorGroupEntityUuid, WebId},
+    },
+};
+
+/// Authorization API which permits everything except access to hidden entities.
+///
+/// Clones share the set of hidden entities, so entities can be hidden after the API was passed to
+/// the store.
+#[derive(Debug, Default, Clone)]
+pub struct HiddenEntities {
+    inner: NoAuthorization,
+    hidden: Arc<Mutex<HashSet<EntityUuid>>>,
+}
+
+impl HiddenEntities {
+    pub fn hide(&self, entity: EntityUuid) {
+        self.hidden
+            .lock()
+            .expect("lock should not be poisoned")
+            .insert(entity);
+    }
+
+    fn is_hidden(&self, entity: EntityUuid) -> bool {
+        self.hidden
+            .lock()
+            .expect("lock should not be poisoned")
+            .contains(&entity)
+    }
+}
+
+impl AuthorizationApi for HiddenEntities {
+    async fn seed(&mut self) -> Result<Zookie<'static>, Report<ModifyRelationError>> {
+        self.inner.seed().await
+    }
+
+    async fn check_account_group_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: AccountGroupPermission,
+        account_group: ActorGroupEntityUuid,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>> {
+        self.inner
+            .check_account_group_permission(actor, permission, account_group, consistency)
+            .await
+    }
+
+    async fn get_account_group_relations(
+        &self,
+        account_group: ActorGroupEntityUuid,
+        consistency: Consistency<'_>,
+    ) -> Result<Vec<AccountGroupRelationAndSubject>, Report<ReadError>> {
+        self.inner
+            .get_account_group_relations(account_group, consistency)
+            .await
+    }
+
+    async fn modify_account_group_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                ActorGroupEntityUuid,
+                AccountGroupRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, Report<ModifyRelationError>> {
+        self.inner
+            .modify_account_group_relations(relationships)
+            .await
+    }
+
+    async fn check_web_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: WebPermission,
+        web: WebId,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>> {
+        self.inner
+            .check_web_permission(actor, permission, web, consistency)
+            .await
+    }
+
+    async fn modify_web_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (ModifyRelationshipOperation, WebId, WebRelationAndSubject),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, Report<ModifyRelationError>> {
+        self.inner.modify_web_relations(relationships).await
+    }
+
+    async fn get_web_relations(
+        &self,
+        web: WebId,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<WebRelationAndSubject>, Report<ReadError>> {
+        self.inner.get_web_relations(web, consistency).await
+    }
+
+    async fn check_entity_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: EntityPermission,
+        entity: EntityId,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>> {
+        let response = self
+            .inner
+            .check_entity_permission(actor, permission, entity, consistency)
+            .await?;
+
+        Ok(CheckResponse {
+            has_permission: response.has_permission && !self.is_hidden(entity.entity_uuid),
+            checked_at: response.checked_at,
+        })
+    }
+
+    async fn modify_entity_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                EntityId,
+                EntityRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, Report<ModifyRelationError>> {
+        self.inner.modify_entity_relations(relationships).await
+    }
+
+    async fn check_entities_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: EntityPermission,
+        entities: impl IntoIterator<Item = EntityId, IntoIter: Send + Sync> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<(HashMap<EntityUuid, bool>, Zookie<'static>), Report<CheckError>> {
+        let (mut permissions, zookie) = self
+            .inner
+            .check_entities_permission(actor, permission, entities, consistency)
+            .await?;
+
+        for (entity_uuid, has_permission) in &mut permissions {
+            *has_permission &= !self.is_hidden(*entity_uuid);
+        }
+
+        Ok((permissions, zookie))
+    }
+
+    async fn get_entity_relations(
+        &self,
+        entity: EntityId,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<EntityRelationAndSubject>, Report<ReadError>> {
+        self.inner.get_entity_relations(entity, consistency).await
+    }
+
+    async fn check_entity_type_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: EntityTypePermission,
+        entity_type: EntityTypeUuid,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>> {
+        self.inner
+            .check_entity_type_permission(actor, permission, entity_type, consistency)
+            .await
+    }
+
+    async fn modify_entity_type_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                EntityTypeUuid,
+                EntityTypeRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, Report<ModifyRelationError>> {
+        self.inner.modify_entity_type_relations(relationships).await
+    }
+
+    async fn check_entity_types_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: EntityTypePermission,
+        entity_types: impl IntoIterator<Item = EntityTypeUuid, IntoIter: Send + Sync> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<(HashMap<EntityTypeUuid, bool>, Zookie<'static>), Report<CheckError>> {
+        self.inner
+            .check_entity_types_permission(actor, permission, entity_types, consistency)
+            .await
+    }
+
+    async fn get_entity_type_relations(
+        &self,
+        entity_type: EntityTypeUuid,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<EntityTypeRelationAndSubject>, Report<ReadError>> {
+        self.inner
+            .get_entity_type_relations(entity_type, consistency)
+            .await
+    }
+
+    async fn check_property_type_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: PropertyTypePermission,
+        property_type: PropertyTypeUuid,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>> {
+        self.inner
+            .check_property_type_permission(actor, permission, property_type, consistency)
+            .await
+    }
+
+    async fn modify_property_type_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                PropertyTypeUuid,
+                PropertyTypeRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, Report<ModifyRelationError>> {
+        self.inner
+            .modify_property_type_relations(relationships)
+            .await
+    }
+
+    async fn check_property_types_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: PropertyTypePermission,
+        property_types: impl IntoIterator<Item = PropertyTypeUuid, IntoIter: Send + Sync> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<(HashMap<PropertyTypeUuid, bool>, Zookie<'static>), Report<CheckError>> {
+        self.inner
+            .check_property_types_permission(actor, permission, property_types, consistency)
+            .await
+    }
+
+    async fn get_property_type_relations(
+        &self,
+        property_type: PropertyTypeUuid,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<PropertyTypeRelationAndSubject>, Report<ReadError>> {
+        self.inner
+            .get_property_type_relations(property_type, consistency)
+            .await
+    }
+
+    async fn check_data_type_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: DataTypePermission,
+        data_type: DataTypeUuid,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>> {
+        self.inner
+            .check_data_type_permission(actor, permission, data_type, consistency)
+            .await
+    }
+
+    async fn modify_data_type_relations(
+        &mut self,
+        relationships: impl IntoIterator<
+            Item = (
+                ModifyRelationshipOperation,
+                DataTypeUuid,
+                DataTypeRelationAndSubject,
+            ),
+            IntoIter: Send,
+        > + Send,
+    ) -> Result<Zookie<'static>, Report<ModifyRelationError>> {
+        self.inner.modify_data_type_relations(relationships).await
+    }
+
+    async fn check_data_types_permission(
+        &self,
+        actor: ActorEntityUuid,
+        permission: DataTypePermission,
+        data_types: impl IntoIterator<Item = DataTypeUuid, IntoIter: Send + Sync> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<(HashMap<DataTypeUuid, bool>, Zookie<'static>), Report<CheckError>> {
+        self.inner
+            .check_data_types_permission(actor, permission, data_types, consistency)
+            .await
+    }
+
+    async fn get_data_type_relations(
+        &self,
+        data_type: DataTypeUuid,
+        consistency: Consistency<'static>,
+    ) -> Result<Vec<DataTypeRelationAndSubject>, Report<ReadError>> {
+        self.inner
+            .get_data_type_relations(data_type, consistency)
+            .await
+    }
+
+    async fn get_entities(
+        &self,
+        actor: ActorEntityUuid,
+        permission: EntityPermission,
+        consistency: Consistency<'_>,
+    ) -> Result<Vec<EntityUuid>, Report<ReadError>> {
+        self.inner
+            .get_entities(actor, permission, consistency)
+            .await
+    }
+
+    async fn get_entity_accounts(
+        &self,
+        entity: EntityUuid,
+        permission: EntityPermission,
+        consistency: Consistency<'_>,
+    ) -> Result<Vec<ActorIdOrPublic>, Report<ReadError>> {
+        self.inner
+            .get_entity_accounts(entity, permission, consistency)
+            .await
+    }
+}
@@ -0,0 +1,392 @@
+use core::str::FromStr as _;
+use std::collections::HashSet;
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_postgres_store::store::AsClient as _;
+use hash_graph_store::{
+    change::{
+        ChangeCursor, ChangeKind, ChangeStore as _, ChangedRecord, GetChangesParams,
+        GetChangesResponse,
+    },
+    entity::{CreateEntityParams, EntityStore as _, PatchEntityParams},
+    entity_type::{EntityTypeStore as _, UpdateEntityTypesParams},
+};
+use hash_graph_temporal_versioning::ClosedTemporalBound;
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tokio_postgres::GenericClient as _;
+use type_system::{
+    knowledge::{
+        Entity,
+        entity::{EntityId, provenance::ProvidedEntityEditionProvenance},
+        property::{
+            Property, PropertyObject, PropertyObjectWithMetadata, PropertyPatchOperation,
+            PropertyPath, PropertyWithMetadata,
+        },
+    },
+    ontology::{
+        VersionedUrl, data_type::DataType, entity_type::EntityType, id::OntologyTypeVersion,
+        property_type::PropertyType, provenance::ProvidedOntologyEditionProvenance,
+    },
+    principal::{actor::ActorType, actor_group::WebId},
+    provenance::{OriginProvenance, OriginType},
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper, HiddenEntities, entity_type_relationships};
+
+const DATA_TYPES: [&str; 3] = [
+    data_type::VALUE_V1,
+    data_type::TEXT_V1,
+    data_type::NUMBER_V1,
+];
+const PROPERTY_TYPES: [&str; 7] = [
+    property_type::NAME_V1,
+    property_type::AGE_V1,
+    property_type::TEXT_V1,
+    property_type::FAVORITE_SONG_V1,
+    property_type::FAVORITE_FILM_V1,
+    property_type::HOBBY_V1,
+    property_type::INTERESTS_V1,
+];
+const ENTITY_TYPES: [&str; 6] = [
+    entity_type::ORGANIZATION_V1,
+    entity_type::PERSON_V1,
+    entity_type::PAGE_V1,
+    entity_type::LINK_V1,
+    entity_type::link::FRIEND_OF_V1,
+    entity_type::link::ACQUAINTANCE_OF_V1,
+];
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(DATA_TYPES, PROPERTY_TYPES, ENTITY_TYPES)
+        .await
+        .expect("could not seed database")
+}
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/person/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn org_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/organization/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn entity_record(entity: &Entity) -> ChangedRecord {
+    ChangedRecord::Entity {
+        entity_id: entity.metadata.record_id.entity_id,
+        edition_id: entity.metadata.record_id.edition_id,
+    }
+}
+
+async fn read_changes<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    after: Option<ChangeCursor>,
+    limit: Option<usize>,
+) -> GetChangesResponse {
+    api.get_changes(api.account_id, GetChangesParams { after, limit })
+        .await
+        .expect("could not read changes")
+}
+
+async fn create_person<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    properties: &str,
+) -> Entity {
+    let properties: PropertyObject =
+        serde_json::from_str(properties).expect("could not parse entity");
+
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            web_id: WebId::new(api.account_id),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+async fn patch_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    entity_id: EntityId,
+    properties: Vec<PropertyPatchOperation>,
+    entity_type_ids: HashSet<VersionedUrl>,
+    archived: Option<bool>,
+) -> Entity {
+    api.patch_entity(
+        api.account_id,
+        PatchEntityParams {
+            entity_id,
+            properties,
+            entity_type_ids,
+            archived,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not patch entity")
+}
+
+#[tokio::test]
+async fn ontology_events() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let mut page: EntityType =
+        serde_json::from_str(entity_type::PAGE_V1).expect("could not parse entity type");
+    page.id.version = OntologyTypeVersion::new(2);
+    api.update_entity_type(
+        api.account_id,
+        UpdateEntityTypesParams {
+            schema: page.clone(),
+            relationships: entity_type_relationships(),
+            provenance: ProvidedOntologyEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not update entity type");
+
+    let expected = DATA_TYPES
+        .into_iter()
+        .map(|data_type| {
+            let data_type: DataType =
+                serde_json::from_str(data_type).expect("could not parse data type");
+            (
+                ChangeKind::Created,
+                ChangedRecord::DataType {
+                    data_type_id: data_type.id,
+                },
+            )
+        })
+        .chain(PROPERTY_TYPES.into_iter().map(|property_type| {
+            let property_type: PropertyType =
+                serde_json::from_str(property_type).expect("could not parse property type");
+            (
+                ChangeKind::Created,
+                ChangedRecord::PropertyType {
+                    property_type_id: property_type.id,
+                },
+            )
+        }))
+        .chain(ENTITY_TYPES.into_iter().map(|entity_type| {
+            let entity_type: EntityType =
+                serde_json::from_str(entity_type).expect("could not parse entity type");
+            (
+                ChangeKind::Created,
+                ChangedRecord::EntityType {
+                    entity_type_id: entity_type.id,
+                },
+            )
+        }))
+        .chain([(
+            ChangeKind::Updated,
+            ChangedRecord::EntityType {
+                entity_type_id: page.id,
+            },
+        )])
+        .collect::<Vec<_>>();
+
+    let changes = read_changes(&api, None, None)
+        .await
+        .changes
+        .into_iter()
+        .map(|change| (change.kind, change.record))
+        .collect::<Vec<_>>();
+
+    // All types are created in the same transaction, so their order is not known in advance
+    assert_eq!(changes.len(), expected.len());
+    for change in &expected {
+        assert!(changes.contains(change), "missing change {change:?}");
+    }
+}
+
+#[tokio::test]
+async fn entity_events() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let cursor = read_changes(&api, None, None).await.cursor;
+
+    let created = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let entity_id = created.metadata.record_id.entity_id;
+    let updated = patch_entity(
+        &mut api,
+        entity_id,
+        vec![PropertyPatchOperation::Replace {
+            path: PropertyPath::default(),
+            property: PropertyWithMetadata::from_parts(
+                Property::Object(
+                    serde_json::from_value(json!({
+                        "https://blockprotocol.org/@alice/types/property-type/name/": "Alice Smith"
+                    }))
+                    .expect("could not parse entity"),
+                ),
+                None,
+            )
+            .expect("could not create property with metadata"),
+        }],
+        HashSet::new(),
+        None,
+    )
+    .await;
+    let type_changed = patch_entity(
+        &mut api,
+        entity_id,
+        Vec::new(),
+        HashSet::from([person_entity_type_id(), org_entity_type_id()]),
+        None,
+    )
+    .await;
+    let archived = patch_entity(&mut api, entity_id, Vec::new(), HashSet::new(), Some(true)).await;
+
+    let response = read_changes(&api, cursor, None).await;
+    assert_eq!(
+        response
+            .changes
+            .iter()
+            .map(|change| (change.kind, change.record.clone()))
+            .collect::<Vec<_>>(),
+        [
+            (ChangeKind::Created, entity_record(&created)),
+            (ChangeKind::Updated, entity_record(&updated)),
+            (ChangeKind::TypeChanged, entity_record(&type_changed)),
+            (ChangeKind::Archived, entity_record(&archived)),
+        ]
+    );
+    assert_eq!(
+        response.cursor,
+        response.changes.last().map(|change| change.cursor)
+    );
+
+    // Reading after the last change neither returns changes nor moves the cursor
+    let response = read_changes(&api, response.cursor, None).await;
+    assert!(response.changes.is_empty());
+    assert_eq!(
+        response.cursor.map(|cursor| cursor.record_id),
+        Some(archived.metadata.record_id.edition_id.into_uuid())
+    );
+}
+
+#[tokio::test]
+async fn pagination() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    create_person(&mut api, entity::PERSON_BOB_V1).await;
+
+    // All changes of a test are recorded in the same transaction. Ontology types and entities are
+    // not recorded at the same time by the store, so the ontology types are moved to the
+    // transaction time of the first entity.
+    let ClosedTemporalBound::Inclusive(transaction_time) =
+        alice.metadata.temporal_versioning.transaction_time.start();
+    api.store
+        .as_client()
+        .execute(
+            "UPDATE ontology_changes SET transaction_time = $1;",
+            &[transaction_time],
+        )
+        .await
+        .expect("could not update transaction time of ontology types");
+
+    let changes = read_changes(&api, None, None).await.changes;
+    assert_eq!(
+        changes.len(),
+        DATA_TYPES.len() + PROPERTY_TYPES.len() + ENTITY_TYPES.len() + 2
+    );
+    assert!(changes.is_sorted_by(|lhs, rhs| lhs.cursor < rhs.cursor));
+    assert!(changes.iter().any(|change| {
+        change.transaction_time == *transaction_time
+            && matches!(change.record, ChangedRecord::Entity { .. })
+    }));
+    assert!(changes.iter().any(|change| {
+        change.transaction_time == *transaction_time
+            && matches!(change.record, ChangedRecord::EntityType { .. })
+    }));
+
+    for limit in [1, 2, 5] {
+        let mut pages = Vec::new();
+        let mut cursor = None;
+        loop {
+            let response = read_changes(&api, cursor, Some(limit)).await;
+            assert!(response.changes.len() <= limit);
+            if response.changes.is_empty() {
+                assert_eq!(response.cursor, cursor);
+                break;
+            }
+
+            pages.extend(response.changes);
+            cursor = response.cursor;
+        }
+
+        assert_eq!(
+            pages, changes,
+            "unexpected changes when reading {limit} at once"
+        );
+    }
+}
+
+#[tokio::test]
+async fn filter_by_permissions() {
+    let authorization = HiddenEntities::default();
+    let mut database = DatabaseTestWrapper::with_authorization(authorization.clone()).await;
+    let mut api = seed(&mut database).await;
+
+    let cursor = read_changes(&api, None, None).await.cursor;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    authorization.hide(bob.metadata.record_id.entity_id.entity_uuid);
+
+    let response = read_changes(&api, cursor, None).await;
+    assert_eq!(
+        response
+            .changes
+            .iter()
+            .map(|change| (change.kind, change.record.clone()))
+            .collect::<Vec<_>>(),
+        [(ChangeKind::Created, entity_record(&alice))]
+    );
+    assert_eq!(
+        response.cursor.map(|cursor| cursor.record_id),
+        Some(bob.metadata.record_id.edition_id.into_uuid())
+    );
+
+    // A page which only consists of hidden changes still advances the cursor
+    let response = read_changes(&api, Some(response.changes[0].cursor), Some(1)).await;
+    assert!(response.changes.is_empty());
+    assert_eq!(
+        response.cursor.map(|cursor| cursor.record_id),
+        Some(bob.metadata.record_id.edition_id.into_uuid())
+    );
+}
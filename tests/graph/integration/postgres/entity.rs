@@ -100,10 +100,7 @@ async fn insert() {
                         None,
                     ),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -182,10 +179,7 @@ async fn query() {
                         None,
                     ),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -310,10 +304,7 @@ async fn update() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
@@ -349,10 +340,7 @@ async fn update() {
                         Some(LimitedTemporalBound::Inclusive(entity_v1_timestamp)),
                     ),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -385,10 +373,7 @@ async fn update() {
                         Some(LimitedTemporalBound::Inclusive(entity_v2_timestamp)),
                     ),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -0,0 +1,603 @@
+use alloc::collections::BTreeMap;
+use core::hash::Hash;
+use std::collections::HashSet;
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    data_type::{DataTypeStore as _, GetDataTypeSubgraphParams},
+    entity::{CreateEntityParams, EntityStore as _},
+    entity_type::{EntityTypeStore as _, GetEntityTypeSubgraphParams},
+    filter::Filter,
+    subgraph::{
+        Subgraph,
+        edges::{
+            EdgeDirection, GraphResolveDepths, OntologyEdgeKind, OutgoingEdgeResolveDepth,
+            OutwardEdge, SharedEdgeKind,
+        },
+        identifier::{
+            DataTypeVertexId, EntityIdWithInterval, EntityTypeVertexId, PropertyTypeVertexId,
+        },
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use pretty_assertions::assert_eq;
+use type_system::{
+    knowledge::{
+        entity::{EntityId, provenance::ProvidedEntityEditionProvenance},
+        property::{PropertyObject, PropertyObjectWithMetadata},
+    },
+    ontology::{
+        VersionedUrl,
+        id::{BaseUrl, OntologyTypeVersion},
+    },
+    principal::{actor::ActorType, actor_group::WebId},
+    provenance::{OriginProvenance, OriginType},
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper, HiddenEntities};
+
+fn versioned_url(url: &str) -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(url.to_owned()).expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn link() -> VersionedUrl {
+    versioned_url("https://blockprotocol.org/@blockprotocol/types/entity-type/link/")
+}
+
+fn friend_of() -> VersionedUrl {
+    versioned_url("https://blockprotocol.org/@alice/types/entity-type/friend-of/")
+}
+
+fn acquaintance_of() -> VersionedUrl {
+    versioned_url("https://blockprotocol.org/@alice/types/entity-type/acquaintance-of/")
+}
+
+fn person() -> VersionedUrl {
+    versioned_url("https://blockprotocol.org/@alice/types/entity-type/person/")
+}
+
+fn page() -> VersionedUrl {
+    versioned_url("https://blockprotocol.org/@alice/types/entity-type/page/")
+}
+
+fn text() -> VersionedUrl {
+    versioned_url("https://blockprotocol.org/@blockprotocol/types/data-type/text/")
+}
+
+fn property_type(name: &str) -> VersionedUrl {
+    versioned_url(&format!(
+        "https://blockprotocol.org/@alice/types/property-type/{name}/"
+    ))
+}
+
+const fn incoming(depth: u8) -> OutgoingEdgeResolveDepth {
+    OutgoingEdgeResolveDepth {
+        incoming: depth,
+        outgoing: 0,
+    }
+}
+
+fn current_time() -> QueryTemporalAxesUnresolved {
+    QueryTemporalAxesUnresolved::DecisionTime {
+        pinned: PinnedTemporalAxisUnresolved::new(None),
+        variable: VariableTemporalAxisUnresolved::new(None, None),
+    }
+}
+
+/// Flattens the edges of an adjacency list into `(source, kind, direction, target)` tuples.
+fn flatten_edges<B, R, K, E>(
+    edges: impl Iterator<Item = (B, BTreeMap<R, Vec<OutwardEdge<K, E>>>)>,
+) -> HashSet<(B, K, EdgeDirection, E)>
+where
+    B: Clone + Eq + Hash,
+    K: Eq + Hash,
+    E: Eq + Hash,
+{
+    edges
+        .flat_map(|(base_id, revisions)| {
+            revisions.into_values().flatten().map(move |edge| {
+                (
+                    base_id.clone(),
+                    edge.kind,
+                    edge.direction,
+                    edge.right_endpoint,
+                )
+            })
+        })
+        .collect()
+}
+
+fn entity_types(subgraph: &Subgraph) -> HashSet<VersionedUrl> {
+    subgraph
+        .vertices
+        .entity_types
+        .keys()
+        .cloned()
+        .map(VersionedUrl::from)
+        .collect()
+}
+
+fn property_types(subgraph: &Subgraph) -> HashSet<VersionedUrl> {
+    subgraph
+        .vertices
+        .property_types
+        .keys()
+        .cloned()
+        .map(VersionedUrl::from)
+        .collect()
+}
+
+fn data_types(subgraph: &Subgraph) -> HashSet<VersionedUrl> {
+    subgraph
+        .vertices
+        .data_types
+        .keys()
+        .cloned()
+        .map(VersionedUrl::from)
+        .collect()
+}
+
+fn entities(subgraph: &Subgraph) -> HashSet<EntityId> {
+    subgraph
+        .vertices
+        .entities
+        .keys()
+        .map(|vertex_id| vertex_id.base_id)
+        .collect()
+}
+
+fn entity_type_edges(
+    subgraph: Subgraph,
+) -> HashSet<(BaseUrl, OntologyEdgeKind, EdgeDirection, EntityTypeVertexId)> {
+    flatten_edges(
+        subgraph
+            .edges
+            .entity_type_to_entity_type
+            .into_flattened::<OutwardEdge<OntologyEdgeKind, EntityTypeVertexId>>(),
+    )
+}
+
+fn is_of_type_edges(
+    subgraph: Subgraph,
+) -> HashSet<(BaseUrl, SharedEdgeKind, EdgeDirection, EntityId)> {
+    flatten_edges(
+        subgraph
+            .edges
+            .entity_type_to_entity
+            .into_flattened::<OutwardEdge<SharedEdgeKind, EntityIdWithInterval>>(),
+    )
+    .into_iter()
+    .map(|(base_url, kind, direction, endpoint)| (base_url, kind, direction, endpoint.entity_id))
+    .collect()
+}
+
+struct Entities {
+    alice: EntityId,
+    bob: EntityId,
+    page: EntityId,
+}
+
+async fn create_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    properties: &str,
+    entity_type: VersionedUrl,
+    draft: bool,
+) -> EntityId {
+    let properties: PropertyObject =
+        serde_json::from_str(properties).expect("could not parse entity");
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            web_id: WebId::new(api.account_id),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([entity_type]),
+            properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity")
+    .metadata
+    .record_id
+    .entity_id
+}
+
+async fn insert<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> (DatabaseApi<'_, &mut A>, Entities) {
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::TEXT_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+                entity_type::PAGE_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let mut entity_ids = Vec::new();
+    for (properties, entity_type) in [
+        (entity::PERSON_ALICE_V1, person()),
+        (entity::PERSON_BOB_V1, person()),
+        (entity::PAGE_V1, page()),
+    ] {
+        entity_ids.push(create_entity(&mut api, properties, entity_type, false).await);
+    }
+
+    let [alice, bob, page] = entity_ids[..] else {
+        unreachable!("three entities were created");
+    };
+    (api, Entities { alice, bob, page })
+}
+
+async fn entity_type_subgraph<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    entity_type: &VersionedUrl,
+    graph_resolve_depths: GraphResolveDepths,
+    include_drafts: bool,
+) -> Subgraph {
+    api.get_entity_type_subgraph(
+        api.account_id,
+        GetEntityTypeSubgraphParams {
+            filter: Filter::for_versioned_url(entity_type),
+            graph_resolve_depths,
+            temporal_axes: current_time(),
+            after: None,
+            limit: None,
+            include_drafts,
+            include_count: false,
+            include_web_ids: false,
+            include_edition_created_by_ids: false,
+        },
+    )
+    .await
+    .expect("could not get entity type subgraph")
+    .subgraph
+}
+
+async fn data_type_subgraph<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    data_type: &VersionedUrl,
+    graph_resolve_depths: GraphResolveDepths,
+) -> Subgraph {
+    api.get_data_type_subgraph(
+        api.account_id,
+        GetDataTypeSubgraphParams {
+            filter: Filter::for_versioned_url(data_type),
+            graph_resolve_depths,
+            temporal_axes: current_time(),
+            include_drafts: false,
+            after: None,
+            limit: None,
+            include_count: false,
+        },
+    )
+    .await
+    .expect("could not get data type subgraph")
+    .subgraph
+}
+
+#[tokio::test]
+async fn entity_types_inheriting_from() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = insert(&mut database).await;
+
+    let subgraph = entity_type_subgraph(
+        &api,
+        &link(),
+        GraphResolveDepths {
+            inherits_from: incoming(1),
+            ..GraphResolveDepths::default()
+        },
+        false,
+    )
+    .await;
+
+    assert_eq!(
+        entity_types(&subgraph),
+        HashSet::from([link(), friend_of(), acquaintance_of()])
+    );
+    assert!(property_types(&subgraph).is_empty());
+    assert_eq!(
+        entity_type_edges(subgraph),
+        HashSet::from([
+            (
+                link().base_url,
+                OntologyEdgeKind::InheritsFrom,
+                EdgeDirection::Incoming,
+                EntityTypeVertexId::from(friend_of()),
+            ),
+            (
+                link().base_url,
+                OntologyEdgeKind::InheritsFrom,
+                EdgeDirection::Incoming,
+                EntityTypeVertexId::from(acquaintance_of()),
+            ),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn property_types_using_data_type() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = insert(&mut database).await;
+
+    let subgraph = data_type_subgraph(
+        &api,
+        &text(),
+        GraphResolveDepths {
+            constrains_values_on: incoming(1),
+            ..GraphResolveDepths::default()
+        },
+    )
+    .await;
+
+    let expected = ["name", "text", "favorite-song", "favorite-film", "hobby"];
+    assert_eq!(data_types(&subgraph), HashSet::from([text()]));
+    assert_eq!(
+        property_types(&subgraph),
+        expected.into_iter().map(property_type).collect()
+    );
+    assert!(entity_types(&subgraph).is_empty());
+    assert_eq!(
+        flatten_edges(
+            subgraph
+                .edges
+                .data_type_to_property_type
+                .into_flattened::<OutwardEdge<OntologyEdgeKind, PropertyTypeVertexId>>(),
+        ),
+        expected
+            .into_iter()
+            .map(|name| (
+                text().base_url,
+                OntologyEdgeKind::ConstrainsValuesOn,
+                EdgeDirection::Incoming,
+                PropertyTypeVertexId::from(property_type(name)),
+            ))
+            .collect()
+    );
+}
+
+#[tokio::test]
+async fn entities_of_type() {
+    let authorization = HiddenEntities::default();
+    let mut database = DatabaseTestWrapper::with_authorization(authorization.clone()).await;
+    let (api, entities) = insert(&mut database).await;
+
+    let depths = GraphResolveDepths {
+        is_of_type: incoming(1),
+        ..GraphResolveDepths::default()
+    };
+
+    let subgraph = entity_type_subgraph(&api, &person(), depths, false).await;
+    assert_eq!(entity_types(&subgraph), HashSet::from([person()]));
+    assert_eq!(
+        entities(&subgraph),
+        HashSet::from([entities.alice, entities.bob])
+    );
+    assert_eq!(
+        is_of_type_edges(subgraph),
+        HashSet::from([
+            (
+                person().base_url,
+                SharedEdgeKind::IsOfType,
+                EdgeDirection::Incoming,
+                entities.alice,
+            ),
+            (
+                person().base_url,
+                SharedEdgeKind::IsOfType,
+                EdgeDirection::Incoming,
+                entities.bob,
+            ),
+        ])
+    );
+
+    // Entities the actor may not view are neither added as vertices nor as edges
+    authorization.hide(entities.bob.entity_uuid);
+    let subgraph = entity_type_subgraph(&api, &person(), depths, false).await;
+    assert_eq!(entities(&subgraph), HashSet::from([entities.alice]));
+    assert_eq!(
+        is_of_type_edges(subgraph),
+        HashSet::from([(
+            person().base_url,
+            SharedEdgeKind::IsOfType,
+            EdgeDirection::Incoming,
+            entities.alice,
+        )])
+    );
+}
+
+#[tokio::test]
+async fn draft_entities_of_type() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, entities) = insert(&mut database).await;
+    let draft = create_entity(&mut api, entity::PERSON_CHARLES_V1, person(), true).await;
+    assert!(draft.draft_id.is_some());
+
+    let depths = GraphResolveDepths {
+        is_of_type: incoming(1),
+        ..GraphResolveDepths::default()
+    };
+    let edge = |entity_id| {
+        (
+            person().base_url,
+            SharedEdgeKind::IsOfType,
+            EdgeDirection::Incoming,
+            entity_id,
+        )
+    };
+
+    // Drafts are only traversed if they are requested
+    let subgraph = entity_type_subgraph(&api, &person(), depths, false).await;
+    assert_eq!(
+        entities(&subgraph),
+        HashSet::from([entities.alice, entities.bob])
+    );
+    assert_eq!(
+        is_of_type_edges(subgraph),
+        HashSet::from([edge(entities.alice), edge(entities.bob)])
+    );
+
+    let subgraph = entity_type_subgraph(&api, &person(), depths, true).await;
+    assert_eq!(
+        entities(&subgraph),
+        HashSet::from([entities.alice, entities.bob, draft])
+    );
+    assert_eq!(
+        is_of_type_edges(subgraph),
+        HashSet::from([edge(entities.alice), edge(entities.bob), edge(draft)])
+    );
+}
+
+#[tokio::test]
+async fn traverse_upwards_from_data_type() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, entities) = insert(&mut database).await;
+
+    // Resolving the incoming edges of a data type re-enters the traversal of property types, which
+    // re-enters the traversal of entity types, which re-enters the traversal of entities.
+    let subgraph = data_type_subgraph(
+        &api,
+        &text(),
+        GraphResolveDepths {
+            constrains_values_on: incoming(1),
+            constrains_properties_on: incoming(1),
+            is_of_type: incoming(1),
+            ..GraphResolveDepths::default()
+        },
+    )
+    .await;
+
+    assert_eq!(data_types(&subgraph), HashSet::from([text()]));
+    assert_eq!(
+        property_types(&subgraph),
+        [
+            "name",
+            "text",
+            "favorite-song",
+            "favorite-film",
+            "hobby",
+            "interests"
+        ]
+        .into_iter()
+        .map(property_type)
+        .collect()
+    );
+    assert_eq!(entity_types(&subgraph), HashSet::from([person(), page()]));
+    assert_eq!(
+        entities(&subgraph),
+        HashSet::from([entities.alice, entities.bob, entities.page])
+    );
+}
+
+#[tokio::test]
+async fn without_incoming_edges() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = insert(&mut database).await;
+
+    // Only outgoing edges are resolved, so neither the link types inheriting from `Link` nor the
+    // entities of type `Person` are part of the subgraph.
+    let depths = GraphResolveDepths {
+        constrains_properties_on: OutgoingEdgeResolveDepth {
+            incoming: 0,
+            outgoing: 1,
+        },
+        constrains_links_on: OutgoingEdgeResolveDepth {
+            incoming: 0,
+            outgoing: 1,
+        },
+        ..GraphResolveDepths::default()
+    };
+
+    let subgraph = entity_type_subgraph(&api, &person(), depths, false).await;
+    assert_eq!(
+        entity_types(&subgraph),
+        HashSet::from([person(), friend_of(), acquaintance_of()])
+    );
+    assert_eq!(
+        property_types(&subgraph),
+        ["name", "age", "interests"]
+            .into_iter()
+            .map(property_type)
+            .collect()
+    );
+    assert!(entities(&subgraph).is_empty());
+
+    let edges = subgraph.edges;
+    assert!(
+        edges
+            .entity_type_to_entity
+            .into_flattened::<OutwardEdge<SharedEdgeKind, EntityIdWithInterval>>()
+            .next()
+            .is_none()
+    );
+    assert!(
+        flatten_edges(
+            edges
+                .entity_type_to_entity_type
+                .into_flattened::<OutwardEdge<OntologyEdgeKind, EntityTypeVertexId>>()
+        )
+        .iter()
+        .all(|(_, _, direction, _)| *direction == EdgeDirection::Outgoing)
+    );
+    assert!(
+        flatten_edges(
+            edges
+                .entity_type_to_property_type
+                .into_flattened::<OutwardEdge<OntologyEdgeKind, PropertyTypeVertexId>>()
+        )
+        .iter()
+        .all(|(_, _, direction, _)| *direction == EdgeDirection::Outgoing)
+    );
+
+    let subgraph = entity_type_subgraph(
+        &api,
+        &link(),
+        GraphResolveDepths {
+            inherits_from: OutgoingEdgeResolveDepth {
+                incoming: 0,
+                outgoing: 1,
+            },
+            ..depths
+        },
+        false,
+    )
+    .await;
+    assert_eq!(entity_types(&subgraph), HashSet::from([link()]));
+    assert!(entity_type_edges(subgraph).is_empty());
+}
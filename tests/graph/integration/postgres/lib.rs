@@ -11,10 +11,14 @@
 
 extern crate alloc;
 
+mod aggregation;
+mod authorization;
+mod change;
 mod data_type;
 mod drafts;
 mod entity;
 mod entity_type;
+mod incoming_edges;
 mod interconnected_graph;
 mod links;
 mod multi_type;
@@ -22,6 +26,7 @@ mod partial_updates;
 mod property_metadata;
 mod property_type;
 mod sorting;
+mod text_search;
 
 use alloc::borrow::Cow;
 use std::collections::HashMap;
@@ -47,6 +52,7 @@ use hash_graph_postgres_store::{
 };
 use hash_graph_store::{
     account::{AccountStore as _, CreateUserActorParams},
+    change::{ChangeStore, GetChangesParams, GetChangesResponse},
     data_type::{
         ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeStore,
         GetDataTypeConversionTargetsParams, GetDataTypeConversionTargetsResponse,
@@ -55,9 +61,10 @@ use hash_graph_store::{
         UpdateDataTypesParams,
     },
     entity::{
-        CountEntitiesParams, CreateEntityParams, EntityStore, EntityValidationReport,
-        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
-        PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityStore, EntityValidationReport, GetEntitiesParams,
+        GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
+        UpdateEntityEmbeddingsParams, ValidateEntityParams,
     },
     entity_type::{
         ArchiveEntityTypeParams, CountEntityTypesParams, CreateEntityTypeParams, EntityTypeStore,
@@ -94,6 +101,8 @@ use type_system::{
     provenance::{OriginProvenance, OriginType},
 };
 
+pub use self::authorization::HiddenEntities;
+
 pub struct DatabaseTestWrapper<A: AuthorizationApi> {
     _pool: PostgresStorePool,
     connection: <PostgresStorePool as StorePool>::Store<'static, A>,
@@ -159,6 +168,12 @@ pub fn init_logging() {
 
 impl DatabaseTestWrapper<NoAuthorization> {
     pub async fn new() -> Self {
+        Self::with_authorization(NoAuthorization).await
+    }
+}
+
+impl<A: AuthorizationApi> DatabaseTestWrapper<A> {
+    pub async fn with_authorization(authorization_api: A) -> Self {
         load_env(Environment::Test);
         init_logging();
 
@@ -191,7 +206,7 @@ impl DatabaseTestWrapper<NoAuthorization> {
         .expect("could not connect to database");
 
         let connection = pool
-            .acquire_owned(NoAuthorization, None)
+            .acquire_owned(authorization_api, None)
             .await
             .expect("could not acquire a database connection");
 
@@ -200,9 +215,7 @@ impl DatabaseTestWrapper<NoAuthorization> {
             connection,
         }
     }
-}
 
-impl<A: AuthorizationApi> DatabaseTestWrapper<A> {
     pub async fn seed<D, P, E>(
         &mut self,
         data_types: D,
@@ -870,6 +883,14 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
     async fn get_entity_by_id(
         &self,
         actor_id: ActorEntityUuid,
@@ -903,6 +924,16 @@ where
     }
 }
 
+impl<A: AuthorizationApi> ChangeStore for DatabaseApi<'_, A> {
+    async fn get_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: GetChangesParams,
+    ) -> Result<GetChangesResponse, Report<QueryError>> {
+        self.store.get_changes(actor_id, params).await
+    }
+}
+
 #[tokio::test]
 async fn can_connect() {
     DatabaseTestWrapper::new().await;
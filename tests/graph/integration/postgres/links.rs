@@ -228,10 +228,7 @@ async fn insert() {
                         None,
                     ),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -477,10 +474,7 @@ async fn get_entity_links() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
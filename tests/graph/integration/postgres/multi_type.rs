@@ -153,10 +153,7 @@ async fn initial_person() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -218,10 +215,7 @@ async fn initial_person() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -247,10 +241,7 @@ async fn initial_person() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -330,10 +321,7 @@ async fn create_multi() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -359,10 +347,7 @@ async fn create_multi() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
@@ -419,10 +404,7 @@ async fn create_multi() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: true,
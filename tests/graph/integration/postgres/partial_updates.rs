@@ -172,10 +172,7 @@ async fn properties_add() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
@@ -267,10 +264,7 @@ async fn properties_remove() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
@@ -364,10 +358,7 @@ async fn properties_replace() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
@@ -454,10 +445,7 @@ async fn type_ids() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
@@ -512,10 +500,7 @@ async fn type_ids() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
@@ -572,10 +557,7 @@ async fn type_ids() {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
-                sorting: EntityQuerySorting {
-                    paths: Vec::new(),
-                    cursor: None,
-                },
+                sorting: EntityQuerySorting::default(),
                 limit: None,
                 conversions: Vec::new(),
                 include_count: false,
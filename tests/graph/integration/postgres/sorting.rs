@@ -90,6 +90,7 @@ async fn test_root_sorting<A: AuthorizationApi>(
                     sorting: EntityQuerySorting {
                         paths: sorting_paths.clone(),
                         cursor: Option::take(&mut cursor),
+                        ..EntityQuerySorting::default()
                     },
                     limit: Some(chunk_size),
                     conversions: Vec::new(),
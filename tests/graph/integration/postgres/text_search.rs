@@ -0,0 +1,281 @@
+use alloc::borrow::Cow;
+use std::collections::HashSet;
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    entity::{
+        CreateEntityParams, EntityQueryPath, EntityQueryRanking, EntityQuerySorting,
+        EntityQuerySortingRecord, EntityStore as _, GetEntitiesParams, PatchEntityParams,
+    },
+    filter::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+    query::Ordering,
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use hash_graph_test_data::{data_type, entity_type, property_type};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    knowledge::{
+        entity::{
+            id::{EntityId, EntityUuid},
+            provenance::ProvidedEntityEditionProvenance,
+        },
+        property::{
+            Property, PropertyObject, PropertyObjectWithMetadata, PropertyPatchOperation,
+            PropertyPath, PropertyWithMetadata,
+        },
+    },
+    ontology::{
+        VersionedUrl,
+        id::{BaseUrl, OntologyTypeVersion},
+    },
+    principal::{actor::ActorType, actor_group::WebId},
+    provenance::{OriginProvenance, OriginType},
+};
+use uuid::Uuid;
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+const TEXT_PROPERTY: &str = "https://blockprotocol.org/@alice/types/property-type/text/";
+
+fn page(text: &str) -> PropertyObject {
+    serde_json::from_value(json!({ TEXT_PROPERTY: text })).expect("could not parse entity")
+}
+
+fn text_property_path() -> EntityQueryPath<'static> {
+    EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+        Cow::Borrowed(TEXT_PROPERTY),
+    )])))
+}
+
+/// Creates a page for each of the texts, the UUID of a page is its position in the list plus one.
+async fn insert<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+    texts: &[&str],
+) -> DatabaseApi<'_, &mut A> {
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    for (idx, text) in texts.iter().enumerate() {
+        api.create_entity(
+            api.account_id,
+            CreateEntityParams {
+                web_id: WebId::new(api.account_id),
+                entity_uuid: Some(uuid(idx as u128 + 1)),
+                decision_time: None,
+                entity_type_ids: HashSet::from([VersionedUrl {
+                    base_url: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    version: OntologyTypeVersion::new(1),
+                }]),
+                properties: PropertyObjectWithMetadata::from_parts(page(text), None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    api
+}
+
+const fn uuid(value: u128) -> EntityUuid {
+    EntityUuid::new(Uuid::from_u128(value))
+}
+
+/// Returns the UUIDs of the entities matching `query` in the order they were returned.
+///
+/// If `rank` is set, the entities are ranked by the relevance for `query`, otherwise and for
+/// entities of equal rank, the entities are sorted by their UUID.
+async fn search<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    path: EntityQueryPath<'static>,
+    query: &str,
+    rank: bool,
+) -> Vec<EntityUuid> {
+    api.get_entities(
+        api.account_id,
+        GetEntitiesParams {
+            filter: Filter::TextSearch(
+                FilterExpression::Path { path: path.clone() },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed(query)),
+                    convert: None,
+                },
+            ),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            sorting: EntityQuerySorting {
+                paths: vec![EntityQuerySortingRecord {
+                    path: EntityQueryPath::Uuid,
+                    ordering: Ordering::Ascending,
+                    nulls: None,
+                }],
+                cursor: None,
+                ranking: rank.then(|| EntityQueryRanking {
+                    path,
+                    query: Cow::Owned(query.to_owned()),
+                }),
+            },
+            limit: None,
+            conversions: Vec::new(),
+            include_count: false,
+            include_entity_types: None,
+            include_drafts: false,
+            include_web_ids: false,
+            include_created_by_ids: false,
+            include_edition_created_by_ids: false,
+            include_type_ids: false,
+            include_type_titles: false,
+        },
+    )
+    .await
+    .expect("could not search entities")
+    .entities
+    .into_iter()
+    .map(|entity| entity.metadata.record_id.entity_id.entity_uuid)
+    .collect()
+}
+
+#[tokio::test]
+async fn web_search_syntax() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(
+        &mut database,
+        &[
+            "The quick brown fox jumps over the lazy dog",
+            "A lazy afternoon",
+            "The dog is lazy",
+            "Brown bread",
+        ],
+    )
+    .await;
+
+    for (query, expected) in [
+        ("lazy", vec![uuid(1), uuid(2), uuid(3)]),
+        ("LAZY DOG", vec![uuid(1), uuid(3)]),
+        ("\"lazy dog\"", vec![uuid(1)]),
+        ("lazy -dog", vec![uuid(2)]),
+        ("fox or bread", vec![uuid(1), uuid(4)]),
+        ("cat", vec![]),
+    ] {
+        // The whole properties object is searched as a JSON document as well as the text property
+        // on its own
+        assert_eq!(
+            search(&api, EntityQueryPath::Properties(None), query, false).await,
+            expected,
+            "unexpected result for `{query}` in the properties"
+        );
+        assert_eq!(
+            search(&api, text_property_path(), query, false).await,
+            expected,
+            "unexpected result for `{query}` in the text property"
+        );
+    }
+}
+
+#[tokio::test]
+async fn property_keys_are_not_searched() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database, &["Intentionally blank page"]).await;
+
+    // Only the values of a JSON document are tokenized, the property type URL is not
+    assert_eq!(
+        search(
+            &api,
+            EntityQueryPath::Properties(None),
+            "blockprotocol",
+            false
+        )
+        .await,
+        Vec::<EntityUuid>::new()
+    );
+    assert_eq!(
+        search(&api, EntityQueryPath::Properties(None), "blank", false).await,
+        [uuid(1)]
+    );
+}
+
+#[tokio::test]
+async fn rank_by_relevance() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = insert(
+        &mut database,
+        &[
+            "fox",
+            "fox fox fox",
+            "The quick brown fox jumps over the lazy dog",
+            "lazy dog",
+            "fox fox",
+        ],
+    )
+    .await;
+
+    // Entities with the same rank are ordered by the sorting paths
+    assert_eq!(
+        search(&api, EntityQueryPath::Properties(None), "fox", true).await,
+        [uuid(2), uuid(5), uuid(1), uuid(3)]
+    );
+    assert_eq!(
+        search(&api, EntityQueryPath::Properties(None), "fox", false).await,
+        [uuid(1), uuid(2), uuid(3), uuid(5)]
+    );
+
+    // Only the current edition of an updated entity is ranked and returned once
+    api.patch_entity(
+        api.account_id,
+        PatchEntityParams {
+            entity_id: EntityId {
+                web_id: WebId::new(api.account_id),
+                entity_uuid: uuid(1),
+                draft_id: None,
+            },
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                property: PropertyWithMetadata::from_parts(
+                    Property::Object(page("fox fox fox fox")),
+                    None,
+                )
+                .expect("could not create property with metadata"),
+            }],
+            entity_type_ids: HashSet::new(),
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not update entity");
+
+    assert_eq!(
+        search(&api, text_property_path(), "fox", true).await,
+        [uuid(1), uuid(2), uuid(5), uuid(3)]
+    );
+}